@@ -5,17 +5,39 @@ use clap::{ArgGroup, Args, Subcommand};
 use log::*;
 use nym_bandwidth_controller::acquire::state::State;
 use nym_bin_common::completions::ArgShell;
+use nym_client_core::config::disk_persistence::CommonClientPaths;
+use nym_config::DEFAULT_DATA_DIR;
+use nym_credential_storage::management::{self, CredentialBundle};
 use nym_credential_storage::persistent_storage::PersistentStorage;
+use nym_credential_storage::storage::Storage;
+use nym_credential_storage::CredentialStorageKey;
+use nym_network_defaults::NymNetworkDetails;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
+use nym_validator_client::Config;
 
-use crate::error::Result;
+use crate::error::{CredentialClientError, Result};
 use crate::recovery_storage::RecoveryStorage;
 
+/// Name of the environment variable holding the passphrase used for (de)crypting credential bundles.
+const BUNDLE_PASSPHRASE_ENV_VAR: &str = "NYM_CREDENTIAL_BUNDLE_PASSPHRASE";
+
 #[derive(Subcommand)]
 pub(crate) enum Command {
     /// Run the binary to obtain a credential
     Run(Run),
 
+    /// List all the credentials held by the client
+    List(List),
+
+    /// Export all the unspent credentials of the client into an encrypted bundle
+    Export(Export),
+
+    /// Import credentials from an encrypted bundle
+    Import(Import),
+
+    /// Remove all the consumed credentials and the ones issued during past DKG epochs
+    Prune(Prune),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -23,6 +45,41 @@ pub(crate) enum Command {
     GenerateFigSpec,
 }
 
+#[derive(Args)]
+pub(crate) struct StorageArgs {
+    /// Home directory of the client that is supposed to use the credential.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Path to a file whose content is used for encrypting the stored credentials. If the existing
+    /// credential storage has not been encrypted before, it will get encrypted in place.
+    /// Alternatively, a passphrase can be provided via the `NYM_CREDENTIAL_STORAGE_PASSPHRASE`
+    /// environment variable.
+    #[clap(long)]
+    pub(crate) key_file: Option<std::path::PathBuf>,
+}
+
+impl StorageArgs {
+    pub(crate) fn storage_key(&self) -> Option<CredentialStorageKey> {
+        match &self.key_file {
+            Some(key_file) => Some(CredentialStorageKey::key_file(key_file)),
+            None => CredentialStorageKey::from_env(),
+        }
+    }
+
+    pub(crate) async fn open_storage(&self) -> Result<PersistentStorage> {
+        // we assume the structure of <home-dir>/data
+        let data_dir = self.client_home_directory.join(DEFAULT_DATA_DIR);
+        let paths = CommonClientPaths::new_default(data_dir);
+
+        Ok(PersistentStorage::init_with_key(
+            paths.credentials_database,
+            self.storage_key().as_ref(),
+        )
+        .await?)
+    }
+}
+
 #[derive(Args)]
 #[clap(group(
 ArgGroup::new("recov")
@@ -30,9 +87,8 @@ ArgGroup::new("recov")
 .args(&["amount", "recovery_mode"]),
 ))]
 pub(crate) struct Run {
-    /// Home directory of the client that is supposed to use the credential.
-    #[clap(long)]
-    pub(crate) client_home_directory: std::path::PathBuf,
+    #[clap(flatten)]
+    pub(crate) storage: StorageArgs,
 
     /// A mnemonic for the account that buys the credential
     #[clap(long)]
@@ -43,6 +99,11 @@ pub(crate) struct Run {
     #[clap(long, default_value = "0")]
     pub(crate) amount: u64,
 
+    /// Obtain a divisible credential, which can be spent in multiple parts across different
+    /// gateways instead of being redeemed with a single one
+    #[clap(long)]
+    pub(crate) divisible: bool,
+
     /// Path to a directory used to store recovery files for unconsumed deposits
     #[clap(long)]
     pub(crate) recovery_dir: std::path::PathBuf,
@@ -52,6 +113,151 @@ pub(crate) struct Run {
     pub(crate) recovery_mode: bool,
 }
 
+#[derive(Args)]
+pub(crate) struct List {
+    #[clap(flatten)]
+    pub(crate) storage: StorageArgs,
+
+    /// Also include the credentials that have already been consumed
+    #[clap(long)]
+    pub(crate) include_consumed: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct Export {
+    #[clap(flatten)]
+    pub(crate) storage: StorageArgs,
+
+    /// Path to the file the encrypted bundle is going to be written to.
+    /// The bundle is encrypted with the passphrase set in the `NYM_CREDENTIAL_BUNDLE_PASSPHRASE`
+    /// environment variable
+    #[clap(long)]
+    pub(crate) output: std::path::PathBuf,
+
+    /// Keep the exported credentials in the local storage. Note that any credential must only
+    /// ever be spent on one of the machines, otherwise it will get rejected as double spending
+    #[clap(long)]
+    pub(crate) keep_local: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct Import {
+    #[clap(flatten)]
+    pub(crate) storage: StorageArgs,
+
+    /// Path to the file containing the encrypted bundle.
+    /// The bundle is decrypted with the passphrase set in the `NYM_CREDENTIAL_BUNDLE_PASSPHRASE`
+    /// environment variable
+    #[clap(long)]
+    pub(crate) input: std::path::PathBuf,
+}
+
+#[derive(Args)]
+pub(crate) struct Prune {
+    #[clap(flatten)]
+    pub(crate) storage: StorageArgs,
+}
+
+fn bundle_key() -> Result<CredentialStorageKey> {
+    // the passphrase is deliberately not accepted as a command line argument as those are
+    // visible to other users of the system and end up in the shell history
+    match std::env::var(BUNDLE_PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) if !passphrase.is_empty() => {
+            Ok(CredentialStorageKey::passphrase(passphrase))
+        }
+        _ => Err(CredentialClientError::MissingBundlePassphrase),
+    }
+}
+
+async fn current_dkg_epoch() -> Result<u64> {
+    let network_details = NymNetworkDetails::new_from_env();
+    let config = Config::try_from_nym_network_details(&network_details)
+        .expect("failed to construct valid validator client config with the provided network");
+    let client = nym_validator_client::Client::new_query(config)?;
+
+    Ok(client.nyxd.get_current_epoch().await?.epoch_id)
+}
+
+pub(crate) async fn list_credentials(args: List) -> Result<()> {
+    let storage = args.storage.open_storage().await?;
+    let current_epoch = current_dkg_epoch().await?;
+
+    let mut total_value = 0;
+    for listed in management::list_credentials(&storage, current_epoch).await? {
+        let credential = listed.credential;
+        if credential.consumed && !args.include_consumed {
+            continue;
+        }
+
+        let value = credential.value()?;
+        if !credential.consumed && !listed.expired {
+            total_value += value;
+        }
+
+        println!(
+            "id: {}, value: {value}, type: {}, epoch: {}, consumed: {}, expired: {}",
+            credential.id,
+            credential.voucher_info,
+            credential.epoch_id,
+            credential.consumed,
+            listed.expired
+        );
+    }
+    println!("total spendable value: {total_value}");
+
+    Ok(())
+}
+
+pub(crate) async fn export_credentials(args: Export) -> Result<()> {
+    let key = bundle_key()?;
+    let storage = args.storage.open_storage().await?;
+
+    let (bundle, exported) = management::export_credentials(&storage, &key).await?;
+    let file = std::fs::File::create(&args.output)?;
+    serde_json::to_writer(file, &bundle)?;
+    info!(
+        "exported {} credentials to {:?}",
+        exported.len(),
+        args.output
+    );
+
+    if args.keep_local {
+        warn!("the exported credentials have been kept in the local storage - make sure not to spend them on both machines");
+    } else {
+        for id in exported {
+            storage.remove_coconut_credential(id).await?;
+        }
+        info!("removed the exported credentials from the local storage");
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn import_credentials(args: Import) -> Result<()> {
+    let key = bundle_key()?;
+    let storage = args.storage.open_storage().await?;
+
+    let file = std::fs::File::open(&args.input)?;
+    let bundle: CredentialBundle = serde_json::from_reader(file)?;
+    let imported = management::import_credentials(&storage, bundle, &key).await?;
+    info!("imported {imported} new credentials from {:?}", args.input);
+
+    Ok(())
+}
+
+pub(crate) async fn prune_credentials(args: Prune) -> Result<()> {
+    let storage = args.storage.open_storage().await?;
+    let current_epoch = current_dkg_epoch().await?;
+
+    let pruned = management::prune_credentials(&storage, current_epoch).await?;
+    info!(
+        "removed {} consumed and {} expired credentials",
+        pruned.consumed, pruned.expired
+    );
+
+    Ok(())
+}
+
 pub(crate) async fn recover_credentials<C: DkgQueryClient + Send + Sync>(
     client: &C,
     recovery_storage: &RecoveryStorage,
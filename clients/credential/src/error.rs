@@ -31,6 +31,12 @@ pub enum CredentialClientError {
     #[error("Could not use shared storage")]
     SharedStorageError(#[from] StorageError),
 
+    #[error("Could not (de)serialize the credential bundle: {0}")]
+    BundleSerializationError(#[from] serde_json::Error),
+
+    #[error("The passphrase of the credential bundle has to be set in the NYM_CREDENTIAL_BUNDLE_PASSPHRASE environment variable")]
+    MissingBundlePassphrase,
+
     #[error("Could not get system time")]
     SysTimeError(#[from] SystemTimeError),
 }
@@ -9,14 +9,12 @@ use commands::*;
 use error::Result;
 use log::*;
 use nym_bin_common::completions::fig_generate;
-use nym_config::DEFAULT_DATA_DIR;
 use nym_network_defaults::{setup_env, NymNetworkDetails};
 use std::process::exit;
 use std::time::{Duration, SystemTime};
 
 use clap::{CommandFactory, Parser};
 use nym_bin_common::logging::setup_logging;
-use nym_client_core::config::disk_persistence::CommonClientPaths;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 use nym_validator_client::nyxd::{Coin, CosmWasmClient};
 use nym_validator_client::Config;
@@ -72,13 +70,7 @@ async fn main() -> Result<()> {
 
     match args.command {
         Command::Run(r) => {
-            // we assume the structure of <home-dir>/data
-            let data_dir = r.client_home_directory.join(DEFAULT_DATA_DIR);
-            let paths = CommonClientPaths::new_default(data_dir);
-            let db_path = paths.credentials_database;
-
-            let shared_storage =
-                nym_credential_storage::initialise_persistent_storage(db_path).await;
+            let shared_storage = r.storage.open_storage().await?;
             let recovery_storage = recovery_storage::RecoveryStorage::new(r.recovery_dir)?;
 
             let network_details = NymNetworkDetails::new_from_env();
@@ -96,8 +88,12 @@ async fn main() -> Result<()> {
             info!("Starting depositing funds, don't kill the process");
 
             if !r.recovery_mode {
-                let state =
-                    nym_bandwidth_controller::acquire::deposit(&client.nyxd, amount).await?;
+                let state = if r.divisible {
+                    nym_bandwidth_controller::acquire::deposit_divisible(&client.nyxd, amount)
+                        .await?
+                } else {
+                    nym_bandwidth_controller::acquire::deposit(&client.nyxd, amount).await?
+                };
                 if nym_bandwidth_controller::acquire::get_credential(
                     &state,
                     &client,
@@ -120,6 +116,10 @@ async fn main() -> Result<()> {
                 recover_credentials(&client.nyxd, &recovery_storage, &shared_storage).await?;
             }
         }
+        Command::List(l) => list_credentials(l).await?,
+        Command::Export(e) => export_credentials(e).await?,
+        Command::Import(i) => import_credentials(i).await?,
+        Command::Prune(p) => prune_credentials(p).await?,
         Command::Completions(c) => c.generate(&mut Cli::command(), bin_name),
         Command::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
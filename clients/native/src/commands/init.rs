@@ -14,7 +14,7 @@ use clap::Args;
 use nym_bin_common::output_format::OutputFormat;
 use nym_client_core::client::base_client::storage::gateway_details::OnDiskGatewayDetails;
 use nym_client_core::client::key_manager::persistence::OnDiskKeys;
-use nym_client_core::config::GatewayEndpointConfig;
+use nym_client_core::config::{GatewayEndpointConfig, TrafficProfile};
 use nym_client_core::init::GatewaySetup;
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::addressing::clients::Recipient;
@@ -65,6 +65,12 @@ pub(crate) struct Init {
     #[clap(long)]
     host: Option<IpAddr>,
 
+    /// Traffic profile determining the rates of real and cover traffic as well as the packet delays,
+    /// i.e. the trade-off between anonymity, latency and bandwidth usage.
+    /// One of `high_anonymity`, `balanced` or `low_latency_bulk`.
+    #[clap(long)]
+    traffic_profile: Option<TrafficProfile>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -90,6 +96,7 @@ impl From<Init> for OverrideConfig {
             disable_socket: init_config.disable_socket,
             port: init_config.port,
             host: init_config.host,
+            traffic_profile: init_config.traffic_profile,
             fastmode: init_config.fastmode,
             no_cover: init_config.no_cover,
 
@@ -108,7 +115,11 @@ pub struct InitResults {
 }
 
 impl InitResults {
-    fn new(config: &Config, address: &Recipient, gateway: &GatewayEndpointConfig) -> Self {
+    pub(crate) fn new(
+        config: &Config,
+        address: &Recipient,
+        gateway: &GatewayEndpointConfig,
+    ) -> Self {
         Self {
             client_core: nym_client_core::init::InitResults::new(&config.base, address, gateway),
             client_listening_port: config.socket.listening_port,
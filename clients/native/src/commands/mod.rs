@@ -16,7 +16,7 @@ use nym_client_core::client::base_client::storage::gateway_details::{
     OnDiskGatewayDetails, PersistedGatewayDetails,
 };
 use nym_client_core::client::key_manager::persistence::OnDiskKeys;
-use nym_client_core::config::GatewayEndpointConfig;
+use nym_client_core::config::{GatewayEndpointConfig, TrafficProfile};
 use nym_client_core::error::ClientCoreError;
 use nym_config::OptionalSet;
 use std::error::Error;
@@ -24,6 +24,7 @@ use std::net::IpAddr;
 
 pub(crate) mod init;
 pub(crate) mod run;
+pub(crate) mod switch_gateway;
 
 lazy_static! {
     pub static ref PRETTY_BUILD_INFORMATION: String =
@@ -54,6 +55,9 @@ pub(crate) enum Commands {
     /// Run the Nym client with provided configuration client optionally overriding set parameters
     Run(run::Run),
 
+    /// Register the already initialised client with a different gateway, keeping all of its keys
+    SwitchGateway(switch_gateway::SwitchGateway),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -67,6 +71,7 @@ pub(crate) struct OverrideConfig {
     disable_socket: Option<bool>,
     port: Option<u16>,
     host: Option<IpAddr>,
+    traffic_profile: Option<TrafficProfile>,
     fastmode: bool,
     no_cover: bool,
     nyxd_urls: Option<Vec<url::Url>>,
@@ -79,6 +84,7 @@ pub(crate) async fn execute(args: &Cli) -> Result<(), Box<dyn Error + Send + Syn
     match &args.command {
         Commands::Init(m) => init::execute(m).await?,
         Commands::Run(m) => run::execute(m).await?,
+        Commands::SwitchGateway(m) => switch_gateway::execute(m).await?,
         Commands::Completions(s) => s.generate(&mut Cli::command(), bin_name),
         Commands::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
@@ -88,6 +94,8 @@ pub(crate) async fn execute(args: &Cli) -> Result<(), Box<dyn Error + Send + Syn
 pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
     config
         .with_optional(Config::with_disabled_socket, args.disable_socket)
+        // NOTE: the profile has to be applied before any of the debug traffic flags so that they could override it
+        .with_optional_ext(BaseClientConfig::with_traffic_profile, args.traffic_profile)
         .with_base(
             BaseClientConfig::with_high_default_traffic_volume,
             args.fastmode,
@@ -10,6 +10,7 @@ use crate::{
 use clap::Args;
 use log::*;
 use nym_bin_common::version_checker::is_minor_version_compatible;
+use nym_client_core::config::TrafficProfile;
 use nym_crypto::asymmetric::identity;
 use std::error::Error;
 use std::net::IpAddr;
@@ -46,6 +47,12 @@ pub(crate) struct Run {
     #[clap(long)]
     host: Option<IpAddr>,
 
+    /// Traffic profile determining the rates of real and cover traffic as well as the packet delays,
+    /// i.e. the trade-off between anonymity, latency and bandwidth usage.
+    /// One of `high_anonymity`, `balanced` or `low_latency_bulk`.
+    #[clap(long)]
+    traffic_profile: Option<TrafficProfile>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -68,6 +75,7 @@ impl From<Run> for OverrideConfig {
             disable_socket: run_config.disable_socket,
             port: run_config.port,
             host: run_config.host,
+            traffic_profile: run_config.traffic_profile,
             fastmode: run_config.fastmode,
             no_cover: run_config.no_cover,
             nyxd_urls: run_config.nyxd_urls,
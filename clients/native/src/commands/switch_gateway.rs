@@ -0,0 +1,62 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::init::InitResults;
+use crate::commands::try_load_current_config;
+use crate::error::ClientError;
+use clap::Args;
+use nym_bin_common::output_format::OutputFormat;
+use nym_crypto::asymmetric::identity;
+use tap::TapFallible;
+
+#[derive(Args, Clone)]
+pub(crate) struct SwitchGateway {
+    /// Id of the nym-mixnet-client whose gateway we want to change.
+    #[clap(long)]
+    id: String,
+
+    /// Id of the gateway we want to switch to.
+    #[clap(long)]
+    gateway: Option<identity::PublicKey>,
+
+    /// Specifies whether the new gateway should be determined based by latency as opposed to being chosen
+    /// uniformly.
+    #[clap(long, conflicts_with = "gateway")]
+    latency_based_selection: bool,
+
+    /// Comma separated list of rest endpoints of the API validators
+    #[clap(long, alias = "api_validators", value_delimiter = ',')]
+    nym_apis: Option<Vec<url::Url>>,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+pub(crate) async fn execute(args: &SwitchGateway) -> Result<(), ClientError> {
+    eprintln!("Switching gateway of client \"{}\"...", args.id);
+
+    let config = try_load_current_config(&args.id)?;
+
+    let nym_apis = args
+        .nym_apis
+        .clone()
+        .unwrap_or_else(|| config.base.client.nym_api_urls.clone());
+
+    let init_details = nym_client_core::init::switch_on_disk_gateway(
+        &config.storage_paths.common_paths,
+        args.gateway,
+        args.latency_based_selection,
+        &nym_apis,
+    )
+    .await
+    .tap_err(|err| eprintln!("Failed to switch gateway\nError: {err}"))?;
+
+    let address = init_details.client_address()?;
+
+    eprintln!("Gateway switch completed. Make sure to share the new address of this client.\n");
+
+    let results = InitResults::new(&config, &address, &init_details.gateway_details);
+    println!("{}", args.output.format(&results));
+
+    Ok(())
+}
@@ -13,7 +13,8 @@ use clap::Args;
 use nym_bin_common::output_format::OutputFormat;
 use nym_client_core::client::base_client::storage::gateway_details::OnDiskGatewayDetails;
 use nym_client_core::client::key_manager::persistence::OnDiskKeys;
-use nym_client_core::config::GatewayEndpointConfig;
+use nym_client_core::client::name_resolver::NameResolver;
+use nym_client_core::config::{GatewayEndpointConfig, TrafficProfile};
 use nym_client_core::init::GatewaySetup;
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::addressing::clients::Recipient;
@@ -29,8 +30,15 @@ pub(crate) struct Init {
     id: String,
 
     /// Address of the socks5 provider to send messages to.
+    /// Alternatively, a name registered in the name service can be used in its place.
+    #[clap(long, required_unless_present = "auto_select_provider")]
+    provider: Option<String>,
+
+    /// Specifies whether the network requester should be chosen automatically from the service
+    /// provider directory and replaced with a different one if it stops responding.
+    /// If the provider is also specified, it's going to be tried first.
     #[clap(long)]
-    provider: Recipient,
+    auto_select_provider: bool,
 
     /// Specifies whether this client is going to use an anonymous sender tag for communication with the service provider.
     /// While this is going to hide its actual address information, it will make the actual communication
@@ -68,6 +76,12 @@ pub(crate) struct Init {
     #[clap(short, long)]
     port: Option<u16>,
 
+    /// Traffic profile determining the rates of real and cover traffic as well as the packet delays,
+    /// i.e. the trade-off between anonymity, latency and bandwidth usage.
+    /// One of `high_anonymity`, `balanced` or `low_latency_bulk`.
+    #[clap(long)]
+    traffic_profile: Option<TrafficProfile>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -90,8 +104,11 @@ impl From<Init> for OverrideConfig {
     fn from(init_config: Init) -> Self {
         OverrideConfig {
             nym_apis: init_config.nym_apis,
+            provider: None,
+            auto_select_provider: Some(init_config.auto_select_provider),
             port: init_config.port,
             use_anonymous_replies: init_config.use_reply_surbs,
+            traffic_profile: init_config.traffic_profile,
             fastmode: init_config.fastmode,
             no_cover: init_config.no_cover,
             medium_toggle: false,
@@ -111,7 +128,11 @@ pub struct InitResults {
 }
 
 impl InitResults {
-    fn new(config: &Config, address: &Recipient, gateway: &GatewayEndpointConfig) -> Self {
+    pub(crate) fn new(
+        config: &Config,
+        address: &Recipient,
+        gateway: &GatewayEndpointConfig,
+    ) -> Self {
         Self {
             client_core: nym_client_core::init::InitResults::new(
                 &config.core.base,
@@ -141,7 +162,7 @@ pub(crate) async fn execute(args: &Init) -> Result<(), Socks5ClientError> {
     eprintln!("Initialising client...");
 
     let id = &args.id;
-    let provider_address = &args.provider;
+    let provider_address = args.provider.clone().unwrap_or_default();
 
     let already_init = if default_config_filepath(id).exists() {
         // in case we're using old config, try to upgrade it
@@ -175,10 +196,22 @@ pub(crate) async fn execute(args: &Init) -> Result<(), Socks5ClientError> {
 
     // Load and potentially override config
     let config = override_config(
-        Config::new(id, &provider_address.to_string()),
+        Config::new(id, &provider_address),
         OverrideConfig::from(args.clone()),
     );
 
+    // make sure the provider is going to be reachable before we persist it. note that names are
+    // kept in the config as they are and get resolved again on every startup
+    if let Some(provider_address) = &args.provider {
+        let resolved_provider = NameResolver::new(config.core.base.client.nym_api_urls.clone())
+            .resolve_recipient(provider_address)
+            .await
+            .tap_err(|err| eprintln!("Failed to resolve the provider address\nError: {err}"))?;
+        if resolved_provider.to_string() != *provider_address {
+            eprintln!("Provider name '{provider_address}' resolved to {resolved_provider}");
+        }
+    }
+
     // Setup gateway by either registering a new one, or creating a new config from the selected
     // one but with keys kept, or reusing the gateway configuration.
     let key_store = OnDiskKeys::new(config.storage_paths.common_paths.keys.clone());
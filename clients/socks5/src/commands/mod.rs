@@ -16,7 +16,7 @@ use nym_client_core::client::base_client::storage::gateway_details::{
     OnDiskGatewayDetails, PersistedGatewayDetails,
 };
 use nym_client_core::client::key_manager::persistence::OnDiskKeys;
-use nym_client_core::config::GatewayEndpointConfig;
+use nym_client_core::config::{GatewayEndpointConfig, TrafficProfile};
 use nym_client_core::error::ClientCoreError;
 use nym_config::OptionalSet;
 use nym_sphinx::params::{PacketSize, PacketType};
@@ -24,6 +24,7 @@ use std::error::Error;
 
 pub mod init;
 pub(crate) mod run;
+pub(crate) mod switch_gateway;
 
 lazy_static! {
     pub static ref PRETTY_BUILD_INFORMATION: String =
@@ -54,6 +55,9 @@ pub(crate) enum Commands {
     /// Run the Nym client with provided configuration client optionally overriding set parameters
     Run(run::Run),
 
+    /// Register the already initialised client with a different gateway, keeping all of its keys
+    SwitchGateway(switch_gateway::SwitchGateway),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -64,8 +68,11 @@ pub(crate) enum Commands {
 // Configuration that can be overridden.
 pub(crate) struct OverrideConfig {
     nym_apis: Option<Vec<url::Url>>,
+    provider: Option<String>,
+    auto_select_provider: Option<bool>,
     port: Option<u16>,
     use_anonymous_replies: Option<bool>,
+    traffic_profile: Option<TrafficProfile>,
     fastmode: bool,
     no_cover: bool,
     medium_toggle: bool,
@@ -80,6 +87,7 @@ pub(crate) async fn execute(args: &Cli) -> Result<(), Box<dyn Error + Send + Syn
     match &args.command {
         Commands::Init(m) => init::execute(m).await?,
         Commands::Run(m) => run::execute(m).await?,
+        Commands::SwitchGateway(m) => switch_gateway::execute(m).await?,
         Commands::Completions(s) => s.generate(&mut Cli::command(), bin_name),
         Commands::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
@@ -97,6 +105,8 @@ pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
         PacketType::Mix
     };
     config
+        // NOTE: the profile has to be applied before any of the debug traffic flags so that they could override it
+        .with_optional_base(BaseClientConfig::with_traffic_profile, args.traffic_profile)
         .with_base(
             BaseClientConfig::with_high_default_traffic_volume,
             args.fastmode,
@@ -114,6 +124,11 @@ pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
         // NOTE: see comment above about the order of the other disble cover traffic config
         .with_base(BaseClientConfig::with_disabled_cover_traffic, args.no_cover)
         .with_base(BaseClientConfig::with_packet_type, packet_type)
+        .with_optional(Config::with_provider_mix_address, args.provider)
+        .with_optional(
+            Config::with_auto_selected_provider,
+            args.auto_select_provider,
+        )
         .with_optional(Config::with_anonymous_replies, args.use_anonymous_replies)
         .with_optional(Config::with_port, args.port)
         .with_optional_base_custom_env(
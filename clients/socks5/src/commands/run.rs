@@ -11,9 +11,9 @@ use clap::Args;
 use log::*;
 use nym_bin_common::version_checker::is_minor_version_compatible;
 use nym_client_core::client::base_client::storage::OnDiskPersistent;
+use nym_client_core::config::TrafficProfile;
 use nym_crypto::asymmetric::identity;
 use nym_socks5_client_core::NymClient;
-use nym_sphinx::addressing::clients::Recipient;
 
 #[derive(Args, Clone)]
 pub(crate) struct Run {
@@ -31,8 +31,14 @@ pub(crate) struct Run {
     use_anonymous_replies: Option<bool>,
 
     /// Address of the socks5 provider to send messages to.
+    /// Alternatively, a name registered in the name service can be used in its place.
     #[clap(long)]
-    provider: Option<Recipient>,
+    provider: Option<String>,
+
+    /// Specifies whether the network requester should be chosen automatically from the service
+    /// provider directory and replaced with a different one if it stops responding.
+    #[clap(long)]
+    auto_select_provider: Option<bool>,
 
     /// Id of the gateway we want to connect to. If overridden, it is user's responsibility to
     /// ensure prior registration happened
@@ -51,6 +57,12 @@ pub(crate) struct Run {
     #[clap(short, long)]
     port: Option<u16>,
 
+    /// Traffic profile determining the rates of real and cover traffic as well as the packet delays,
+    /// i.e. the trade-off between anonymity, latency and bandwidth usage.
+    /// One of `high_anonymity`, `balanced` or `low_latency_bulk`.
+    #[clap(long)]
+    traffic_profile: Option<TrafficProfile>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -78,8 +90,11 @@ impl From<Run> for OverrideConfig {
     fn from(run_config: Run) -> Self {
         OverrideConfig {
             nym_apis: run_config.nym_apis,
+            provider: run_config.provider,
+            auto_select_provider: run_config.auto_select_provider,
             port: run_config.port,
             use_anonymous_replies: run_config.use_anonymous_replies,
+            traffic_profile: run_config.traffic_profile,
             fastmode: run_config.fastmode,
             no_cover: run_config.no_cover,
             medium_toggle: run_config.medium_toggle,
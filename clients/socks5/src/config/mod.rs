@@ -107,6 +107,16 @@ impl Config {
         self
     }
 
+    pub fn with_provider_mix_address(mut self, provider_mix_address: String) -> Self {
+        self.core.socks5.provider_mix_address = provider_mix_address;
+        self
+    }
+
+    pub fn with_auto_selected_provider(mut self, auto_select_provider: bool) -> Self {
+        self.core.socks5.auto_select_provider = auto_select_provider;
+        self
+    }
+
     pub fn with_anonymous_replies(mut self, anonymous_replies: bool) -> Self {
         self.core.socks5.send_anonymously = anonymous_replies;
         self
@@ -73,8 +73,14 @@ gateway_details = '{{ storage_paths.gateway_details }}'
 [core.socks5]
 
 # The mix address of the provider to which all requests are going to be sent.
+# It can also be a name registered in the name service that is going to be resolved on startup.
 provider_mix_address = '{{ core.socks5.provider_mix_address }}'
 
+# Specifies whether the network requester should be chosen automatically from the service provider directory.
+# If enabled, the client is also going to switch to a different network requester once the current one
+# stops responding. If `provider_mix_address` is set, it's going to be tried first.
+auto_select_provider = {{ core.socks5.auto_select_provider }}
+
 # The port on which the client will be listening for incoming requests
 listening_port = {{ core.socks5.listening_port }}
 
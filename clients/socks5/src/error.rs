@@ -1,3 +1,4 @@
+use nym_client_core::client::name_resolver::NameResolutionError;
 use nym_client_core::error::ClientCoreError;
 
 #[derive(thiserror::Error, Debug)]
@@ -20,4 +21,7 @@ pub enum Socks5ClientError {
 
     #[error("client-core error: {0}")]
     ClientCoreError(#[from] ClientCoreError),
+
+    #[error("Failed to resolve the provider address: {0}")]
+    ProviderResolution(#[from] NameResolutionError),
 }
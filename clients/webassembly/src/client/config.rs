@@ -87,6 +87,7 @@ impl From<TrafficWasm> for ConfigTraffic {
 
         ConfigTraffic {
             average_packet_delay: Duration::from_millis(traffic.average_packet_delay_ms),
+            per_hop_delay_distribution: Default::default(),
             message_sending_average_delay: Duration::from_millis(
                 traffic.message_sending_average_delay_ms,
             ),
@@ -159,6 +160,10 @@ pub struct GatewayConnectionWasm {
     /// How long we're willing to wait for a response to a message sent to the gateway,
     /// before giving up on it.
     pub gateway_response_timeout_ms: u64,
+
+    /// Number of additional gateways the client is going to register with and keep the connections open to,
+    /// so that it could fail over to them if the connection to the active gateway dies.
+    pub standby_gateways: usize,
 }
 
 impl From<GatewayConnectionWasm> for ConfigGatewayConnection {
@@ -167,6 +172,7 @@ impl From<GatewayConnectionWasm> for ConfigGatewayConnection {
             gateway_response_timeout: Duration::from_millis(
                 gateway_connection.gateway_response_timeout_ms,
             ),
+            standby_gateways: gateway_connection.standby_gateways,
         }
     }
 }
@@ -176,6 +182,7 @@ impl From<ConfigGatewayConnection> for GatewayConnectionWasm {
         GatewayConnectionWasm {
             gateway_response_timeout_ms: gateway_connection.gateway_response_timeout.as_millis()
                 as u64,
+            standby_gateways: gateway_connection.standby_gateways,
         }
     }
 }
@@ -37,6 +37,10 @@ mod v1 {
     // TODO: for those we could actually use the subtle crypto storage
     pub const AES128CTR_ACK_KEY: &str = "aes128ctr_ack_key";
     pub const AES128CTR_BLAKE3_HMAC_GATEWAY_KEYS: &str = "aes128ctr_blake3_hmac_gateway_keys";
+
+    // prefix of the keys shared with each of the standby gateways, followed by the gateway identity
+    pub const AES128CTR_BLAKE3_HMAC_STANDBY_GATEWAY_KEYS_PREFIX: &str =
+        "aes128ctr_blake3_hmac_standby_gateway_keys";
 }
 
 #[wasm_bindgen]
@@ -178,6 +182,26 @@ impl ClientStorage {
             .map_err(Into::into)
     }
 
+    fn standby_gateway_shared_key_name(gateway_id: &str) -> String {
+        format!(
+            "{}-{gateway_id}",
+            v1::AES128CTR_BLAKE3_HMAC_STANDBY_GATEWAY_KEYS_PREFIX
+        )
+    }
+
+    async fn may_read_standby_gateway_shared_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<Option<SharedKeys>, ClientStorageError> {
+        self.inner
+            .read_value(
+                v1::KEYS_STORE,
+                JsValue::from_str(&Self::standby_gateway_shared_key_name(gateway_id)),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     async fn must_read_identity_keypair(&self) -> Result<identity::KeyPair, ClientStorageError> {
         self.may_read_identity_keypair()
             .await?
@@ -212,6 +236,17 @@ impl ClientStorage {
             })
     }
 
+    async fn must_read_standby_gateway_shared_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<SharedKeys, ClientStorageError> {
+        self.may_read_standby_gateway_shared_key(gateway_id)
+            .await?
+            .ok_or_else(|| ClientStorageError::CryptoKeyNotInStorage {
+                typ: Self::standby_gateway_shared_key_name(gateway_id),
+            })
+    }
+
     async fn store_identity_keypair(
         &self,
         keypair: &identity::KeyPair,
@@ -262,6 +297,21 @@ impl ClientStorage {
             .map_err(Into::into)
     }
 
+    async fn store_standby_gateway_shared_key(
+        &self,
+        gateway_id: &str,
+        key: &SharedKeys,
+    ) -> Result<(), ClientStorageError> {
+        self.inner
+            .store_value(
+                v1::KEYS_STORE,
+                JsValue::from_str(&Self::standby_gateway_shared_key_name(gateway_id)),
+                key,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     pub(crate) async fn store_gateway_details(
         &self,
         gateway_endpoint: &PersistedGatewayDetails,
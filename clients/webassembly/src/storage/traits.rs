@@ -12,6 +12,8 @@ use nym_client_core::client::key_manager::persistence::KeyStore;
 use nym_client_core::client::key_manager::KeyManager;
 use nym_client_core::client::replies::reply_storage::browser_backend;
 use nym_credential_storage::ephemeral_storage::EphemeralStorage as EphemeralCredentialStorage;
+use nym_gateway_client::SharedKeys;
+use std::sync::Arc;
 use wasm_utils::console_log;
 
 // temporary until other variants are properly implemented (probably it should get changed into `ClientStorage`
@@ -82,6 +84,24 @@ impl KeyStore for ClientStorage {
         self.store_gateway_shared_key(&keys.gateway_shared_key())
             .await
     }
+
+    async fn load_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<Arc<SharedKeys>, Self::StorageError> {
+        self.must_read_standby_gateway_shared_key(gateway_id)
+            .await
+            .map(Arc::new)
+    }
+
+    async fn store_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+        key: Arc<SharedKeys>,
+    ) -> Result<(), Self::StorageError> {
+        self.store_standby_gateway_shared_key(gateway_id, &key)
+            .await
+    }
 }
 
 #[async_trait(?Send)]
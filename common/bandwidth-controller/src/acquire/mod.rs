@@ -7,7 +7,7 @@ use nym_credential_storage::storage::Storage;
 use nym_credentials::coconut::bandwidth::{BandwidthVoucher, TOTAL_ATTRIBUTES};
 use nym_credentials::coconut::utils::obtain_aggregate_signature;
 use nym_crypto::asymmetric::{encryption, identity};
-use nym_network_defaults::VOUCHER_INFO;
+use nym_network_defaults::{DIVISIBLE_VOUCHER_INFO, VOUCHER_INFO};
 use nym_validator_client::nyxd::traits::CoconutBandwidthSigningClient;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 use nym_validator_client::nyxd::tx::Hash;
@@ -20,6 +20,28 @@ use std::str::FromStr;
 pub mod state;
 
 pub async fn deposit<C>(client: &C, amount: Coin) -> Result<State, BandwidthControllerError>
+where
+    C: CoconutBandwidthSigningClient,
+{
+    deposit_with_info(client, amount, VOUCHER_INFO).await
+}
+
+/// Deposits funds for a divisible credential, which can be spent in parts across multiple gateways.
+pub async fn deposit_divisible<C>(
+    client: &C,
+    amount: Coin,
+) -> Result<State, BandwidthControllerError>
+where
+    C: CoconutBandwidthSigningClient,
+{
+    deposit_with_info(client, amount, DIVISIBLE_VOUCHER_INFO).await
+}
+
+async fn deposit_with_info<C>(
+    client: &C,
+    amount: Coin,
+    voucher_info: &str,
+) -> Result<State, BandwidthControllerError>
 where
     C: CoconutBandwidthSigningClient,
 {
@@ -32,7 +54,7 @@ where
     let tx_hash = client
         .deposit(
             amount,
-            String::from(VOUCHER_INFO),
+            String::from(voucher_info),
             signing_keypair.public_key.clone(),
             encryption_keypair.public_key.clone(),
             None,
@@ -44,7 +66,7 @@ where
     let voucher = BandwidthVoucher::new(
         &params,
         voucher_value,
-        VOUCHER_INFO.to_string(),
+        voucher_info.to_string(),
         Hash::from_str(&tx_hash).map_err(|_| BandwidthControllerError::InvalidTxHash)?,
         identity::PrivateKey::from_base58_string(&signing_keypair.private_key)?,
         encryption::PrivateKey::from_base58_string(&encryption_keypair.private_key)?,
@@ -82,7 +104,7 @@ where
     storage
         .insert_coconut_credential(
             state.voucher.get_voucher_value(),
-            VOUCHER_INFO.to_string(),
+            state.voucher.get_voucher_info(),
             state.voucher.get_private_attributes()[0].to_bs58(),
             state.voucher.get_private_attributes()[1].to_bs58(),
             signature.to_bs58(),
@@ -6,11 +6,13 @@ use crate::error::BandwidthControllerError;
 use nym_credential_storage::error::StorageError;
 use nym_credential_storage::storage::Storage;
 
+use nym_network_defaults::{DIVISIBLE_VOUCHER_INFO, DIVISIBLE_VOUCHER_SPENDS};
 use std::str::FromStr;
 use {
     nym_coconut_interface::Base58,
     nym_credentials::coconut::{
-        bandwidth::prepare_for_spending, utils::obtain_aggregate_verification_key,
+        bandwidth::{prepare_for_spending, prepare_partial_for_spending},
+        utils::obtain_aggregate_verification_key,
     },
 };
 
@@ -73,7 +75,19 @@ impl<C, St: Storage> BandwidthController<C, St> {
         let verification_key = obtain_aggregate_verification_key(&coconut_api_clients).await?;
 
         // the below would only be executed once we know where we want to spend it (i.e. which gateway and stuff)
-        Ok((
+        let credential = if voucher_info == DIVISIBLE_VOUCHER_INFO {
+            // divisible credentials are spent one part at a time, each with a fresh serial number tag
+            prepare_partial_for_spending(
+                voucher_value,
+                voucher_info,
+                serial_number,
+                binding_number,
+                epoch_id,
+                bandwidth_credential.spent_parts as u64,
+                &signature,
+                &verification_key,
+            )?
+        } else {
             prepare_for_spending(
                 voucher_value,
                 voucher_info,
@@ -82,21 +96,30 @@ impl<C, St: Storage> BandwidthController<C, St> {
                 epoch_id,
                 &signature,
                 &verification_key,
-            )?,
-            bandwidth_credential.id,
-        ))
+            )?
+        };
+
+        Ok((credential, bandwidth_credential.id))
     }
 
-    pub async fn consume_credential(&self, id: i64) -> Result<(), BandwidthControllerError>
+    pub async fn consume_credential(
+        &self,
+        id: i64,
+        partial_spend: bool,
+    ) -> Result<(), BandwidthControllerError>
     where
         <St as Storage>::StorageError: Send + Sync + 'static,
     {
         // JS: shouldn't we send some contract/validator/gateway message here to actually, you know,
         // consume it?
-        self.storage
-            .consume_coconut_credential(id)
-            .await
-            .map_err(|err| BandwidthControllerError::CredentialStorageError(Box::new(err)))
+        if partial_spend {
+            self.storage
+                .spend_coconut_credential_part(id, DIVISIBLE_VOUCHER_SPENDS)
+                .await
+        } else {
+            self.storage.consume_coconut_credential(id).await
+        }
+        .map_err(|err| BandwidthControllerError::CredentialStorageError(Box::new(err)))
     }
 }
 
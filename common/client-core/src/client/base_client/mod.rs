@@ -8,6 +8,9 @@ use crate::client::inbound_messages::{InputMessage, InputMessageReceiver, InputM
 use crate::client::key_manager::persistence::KeyStore;
 use crate::client::key_manager::ManagedKeys;
 use crate::client::mix_traffic::{BatchMixMessageSender, MixTrafficController};
+use crate::client::reachability::{
+    ReachableAddresses, ReachableAddressesHandle, ReachableAddressesReceiver,
+};
 use crate::client::real_messages_control;
 use crate::client::real_messages_control::RealMessagesController;
 use crate::client::received_buffer::{
@@ -22,17 +25,20 @@ use crate::client::topology_control::nym_api_provider::NymApiTopologyProvider;
 use crate::client::topology_control::{
     TopologyAccessor, TopologyRefresher, TopologyRefresherConfig,
 };
-use crate::config::{Config, DebugConfig, GatewayEndpointConfig};
+use crate::client::traffic_shaping::{
+    TrafficShapingHandle, TrafficShapingParameters, TrafficShapingReceiver,
+};
+use crate::config::{Config, DebugConfig, GatewayEndpointConfig, TrafficProfile};
 use crate::error::ClientCoreError;
 use crate::{config, spawn_future};
 use futures::channel::mpsc;
-use log::{debug, info};
+use log::{debug, info, warn};
 use nym_bandwidth_controller::BandwidthController;
 use nym_credential_storage::storage::Storage as CredentialStorage;
 use nym_crypto::asymmetric::{encryption, identity};
 use nym_gateway_client::{
     AcknowledgementReceiver, AcknowledgementSender, GatewayClient, MixnetMessageReceiver,
-    MixnetMessageSender,
+    MixnetMessageSender, SharedKeys,
 };
 use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
@@ -49,8 +55,10 @@ use url::Url;
 #[cfg(target_arch = "wasm32")]
 use nym_bandwidth_controller::wasm_mockups::DkgQueryClient;
 
-use crate::client::base_client::storage::gateway_details::GatewayDetailsStore;
-use crate::init::{setup_gateway, GatewaySetup, InitialisationDetails};
+use crate::client::base_client::storage::gateway_details::{
+    GatewayDetailsStore, PersistedStandbyGateway,
+};
+use crate::init::{setup_gateway, setup_standby_gateways, GatewaySetup, InitialisationDetails};
 #[cfg(not(target_arch = "wasm32"))]
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 
@@ -60,6 +68,9 @@ pub mod non_wasm_helpers;
 pub mod helpers;
 pub mod storage;
 
+// if we have somewhere to fail over to, don't spend too much time trying to reconnect to a dead gateway
+const RECONNECTION_ATTEMPTS_WITH_STANDBY: usize = 2;
+
 #[derive(Clone)]
 pub struct ClientInput {
     pub connection_command_sender: ConnectionCommandSender,
@@ -101,6 +112,8 @@ pub struct ClientState {
     pub shared_lane_queue_lengths: LaneQueueLengths,
     pub reply_controller_sender: ReplyControllerSender,
     pub topology_accessor: TopologyAccessor,
+    pub traffic_shaping: TrafficShapingHandle,
+    pub reachable_addresses: ReachableAddressesHandle,
 }
 
 pub enum ClientInputStatus {
@@ -214,12 +227,16 @@ where
 
     // future constantly pumping loop cover traffic at some specified average rate
     // the pumped traffic goes to the MixTrafficController
+    // note: the stream is started even if it's disabled, in case it got re-enabled
+    // by changing the traffic profile at runtime
     fn start_cover_traffic_stream(
         debug_config: &DebugConfig,
         ack_key: Arc<AckKey>,
         self_address: Recipient,
         topology_accessor: TopologyAccessor,
         mix_tx: BatchMixMessageSender,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
         shutdown: TaskClient,
     ) {
         info!("Starting loop cover traffic stream...");
@@ -232,6 +249,8 @@ where
             topology_accessor,
             debug_config.traffic,
             debug_config.cover_traffic,
+            traffic_shaping,
+            reachable_addresses,
         );
 
         stream.start_with_shutdown(shutdown);
@@ -249,6 +268,8 @@ where
         reply_controller_receiver: ReplyControllerReceiver,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
         shutdown: TaskClient,
         packet_type: PacketType,
     ) {
@@ -265,6 +286,8 @@ where
             reply_controller_receiver,
             lane_queue_lengths,
             client_connection_rx,
+            traffic_shaping,
+            reachable_addresses,
         )
         .start_with_shutdown(shutdown, packet_type);
     }
@@ -324,6 +347,9 @@ where
         );
 
         gateway_client.set_disabled_credentials_mode(config.client.disabled_credentials_mode);
+        if config.debug.gateway_connection.standby_gateways > 0 {
+            gateway_client.with_reconnection_attempts(RECONNECTION_ATTEMPTS_WITH_STANDBY);
+        }
 
         let shared_key = gateway_client
             .authenticate_and_start()
@@ -337,6 +363,56 @@ where
         Ok(gateway_client)
     }
 
+    // connections to the standby gateways are established without any bandwidth controller
+    // as it's only going to be moved over if the client ever fails over to any of them.
+    // Failure to connect to any of them is not fatal.
+    async fn start_standby_gateway_clients(
+        config: &Config,
+        standby_gateways: Vec<(PersistedStandbyGateway, Arc<SharedKeys>)>,
+        managed_keys: &ManagedKeys,
+        mixnet_message_sender: MixnetMessageSender,
+        ack_sender: AcknowledgementSender,
+        task_manager: &TaskManager,
+    ) -> Vec<(Recipient, GatewayClient<C, S::CredentialStore>)> {
+        let mut gateway_clients = Vec::with_capacity(standby_gateways.len());
+
+        for (standby, shared_key) in standby_gateways {
+            let gateway_id = &standby.details().gateway_id;
+            let gateway_identity = match identity::PublicKey::from_base58_string(gateway_id) {
+                Ok(identity) => identity,
+                Err(err) => {
+                    warn!("the identity of the standby gateway {gateway_id} is malformed: {err}");
+                    continue;
+                }
+            };
+
+            let mut gateway_client = GatewayClient::new(
+                standby.details().gateway_listener.clone(),
+                managed_keys.identity_keypair(),
+                gateway_identity,
+                Some(shared_key),
+                mixnet_message_sender.clone(),
+                ack_sender.clone(),
+                config.debug.gateway_connection.gateway_response_timeout,
+                None,
+                task_manager.subscribe(),
+            );
+            gateway_client.set_disabled_credentials_mode(config.client.disabled_credentials_mode);
+            gateway_client.with_reconnection_attempts(RECONNECTION_ATTEMPTS_WITH_STANDBY);
+
+            match gateway_client.authenticate_and_listen().await {
+                Ok(_) => {
+                    info!("connected to the standby gateway {gateway_id}");
+                    let address = Self::mix_address(managed_keys, standby.details());
+                    gateway_clients.push((address, gateway_client))
+                }
+                Err(err) => warn!("could not connect to the standby gateway {gateway_id}: {err}"),
+            }
+        }
+
+        gateway_clients
+    }
+
     fn setup_topology_provider(
         custom_provider: Option<Box<dyn TopologyProvider + Send + Sync>>,
         nym_api_urls: Vec<Url>,
@@ -399,13 +475,16 @@ where
     // requests?
     fn start_mix_traffic_controller(
         gateway_client: GatewayClient<C, S::CredentialStore>,
+        standby_gateway_clients: Vec<GatewayClient<C, S::CredentialStore>>,
+        reachable_addresses: ReachableAddressesHandle,
         shutdown: TaskClient,
     ) -> BatchMixMessageSender
     where
         <S::CredentialStore as CredentialStorage>::StorageError: Send + Sync + 'static,
     {
         info!("Starting mix traffic controller...");
-        let (mix_traffic_controller, mix_tx) = MixTrafficController::new(gateway_client);
+        let (mix_traffic_controller, mix_tx) =
+            MixTrafficController::new(gateway_client, standby_gateway_clients, reachable_addresses);
         mix_traffic_controller.start_with_shutdown(shutdown);
         mix_tx
     }
@@ -453,6 +532,51 @@ where
         .await
     }
 
+    async fn initialise_standby_gateways(
+        &self,
+        managed_keys: &ManagedKeys,
+    ) -> Vec<(PersistedStandbyGateway, Arc<SharedKeys>)>
+    where
+        <S::KeyStore as KeyStore>::StorageError: Sync + Send,
+        <S::GatewayDetailsStore as GatewayDetailsStore>::StorageError: Sync + Send,
+    {
+        let standby_gateways = self.config.debug.gateway_connection.standby_gateways;
+        if standby_gateways == 0 {
+            return Vec::new();
+        }
+
+        let key_store = self.client_store.key_store();
+        let standby_gateways = setup_standby_gateways(
+            key_store,
+            self.client_store.gateway_details_store(),
+            managed_keys.identity_keypair(),
+            standby_gateways,
+            &self.config.client.nym_api_urls,
+        )
+        .await
+        .unwrap_or_else(|err| {
+            warn!("failed to set up the standby gateways: {err}. The client is going to rely on a single gateway");
+            Vec::new()
+        });
+
+        let mut with_keys = Vec::with_capacity(standby_gateways.len());
+        for standby in standby_gateways {
+            let gateway_id = &standby.details().gateway_id;
+            match key_store.load_standby_gateway_key(gateway_id).await {
+                Ok(shared_key) if standby.verify(&shared_key) => {
+                    with_keys.push((standby, shared_key))
+                }
+                Ok(_) => warn!(
+                    "the stored key for the standby gateway {gateway_id} does not match its details"
+                ),
+                Err(err) => {
+                    warn!("failed to load the key for the standby gateway {gateway_id}: {err}")
+                }
+            }
+        }
+        with_keys
+    }
+
     pub async fn start_base(mut self) -> Result<BaseClient, ClientCoreError>
     where
         S::ReplyStore: Send + Sync,
@@ -467,6 +591,7 @@ where
         let details = self.initialise_keys_and_gateway().await?;
         let gateway_config = details.gateway_details;
         let managed_keys = details.managed_keys;
+        let standby_gateways = self.initialise_standby_gateways(&managed_keys).await;
 
         let (reply_storage_backend, credential_store) = self.client_store.into_runtime_stores();
 
@@ -509,12 +634,30 @@ where
             gateway_config,
             &managed_keys,
             bandwidth_controller,
-            mixnet_messages_sender,
-            ack_sender,
+            mixnet_messages_sender.clone(),
+            ack_sender.clone(),
             task_manager.subscribe(),
         )
         .await?;
 
+        let (standby_addresses, standby_gateway_clients): (Vec<_>, Vec<_>) =
+            Self::start_standby_gateway_clients(
+                self.config,
+                standby_gateways,
+                &managed_keys,
+                mixnet_messages_sender,
+                ack_sender,
+                &task_manager,
+            )
+            .await
+            .into_iter()
+            .unzip();
+
+        // Shared set of addresses the client can be reached at. The active one changes whenever
+        // the client fails over to one of its standby gateways.
+        let (reachable_addresses, reachable_addresses_receiver) =
+            ReachableAddressesHandle::new(ReachableAddresses::new(self_address, standby_addresses));
+
         let reply_storage =
             Self::setup_persistent_reply_storage(reply_storage_backend, task_manager.subscribe())
                 .await?;
@@ -544,8 +687,12 @@ where
         // that are to be sent to the mixnet. They are used by cover traffic stream and real
         // traffic stream.
         // The MixTrafficController then sends the actual traffic
-        let message_sender =
-            Self::start_mix_traffic_controller(gateway_client, task_manager.subscribe());
+        let message_sender = Self::start_mix_traffic_controller(
+            gateway_client,
+            standby_gateway_clients,
+            reachable_addresses.clone(),
+            task_manager.subscribe(),
+        );
 
         // Channels that the websocket listener can use to signal downstream to the real traffic
         // controller that connections are closed.
@@ -555,6 +702,11 @@ where
         // primarily to throttle incoming connections (e.g socks5 for attached network-requesters)
         let shared_lane_queue_lengths = LaneQueueLengths::new();
 
+        // Shared traffic shaping parameters that can be updated at runtime by switching
+        // the traffic profile of the client.
+        let (traffic_shaping, traffic_shaping_receiver) =
+            TrafficShapingHandle::new(TrafficShapingParameters::new(&self.config.debug));
+
         let controller_config = real_messages_control::Config::new(
             &self.config.debug,
             managed_keys.ack_key(),
@@ -572,25 +724,22 @@ where
             reply_controller_receiver,
             shared_lane_queue_lengths.clone(),
             client_connection_rx,
+            traffic_shaping_receiver.clone(),
+            reachable_addresses_receiver.clone(),
             task_manager.subscribe(),
             self.config.debug.traffic.packet_type,
         );
 
-        if !self
-            .config
-            .debug
-            .cover_traffic
-            .disable_loop_cover_traffic_stream
-        {
-            Self::start_cover_traffic_stream(
-                &self.config.debug,
-                managed_keys.ack_key(),
-                self_address,
-                shared_topology_accessor.clone(),
-                message_sender,
-                task_manager.subscribe(),
-            );
-        }
+        Self::start_cover_traffic_stream(
+            &self.config.debug,
+            managed_keys.ack_key(),
+            self_address,
+            shared_topology_accessor.clone(),
+            message_sender,
+            traffic_shaping_receiver,
+            reachable_addresses_receiver,
+            task_manager.subscribe(),
+        );
 
         debug!("Core client startup finished!");
         debug!("The address of this client is: {self_address}");
@@ -612,6 +761,8 @@ where
                 shared_lane_queue_lengths,
                 reply_controller_sender,
                 topology_accessor: shared_topology_accessor,
+                traffic_shaping,
+                reachable_addresses,
             },
             task_manager,
         })
@@ -626,3 +777,18 @@ pub struct BaseClient {
 
     pub task_manager: TaskManager,
 }
+
+impl BaseClient {
+    /// Changes the traffic profile of the running client.
+    pub fn switch_traffic_profile(&self, traffic_profile: TrafficProfile) {
+        self.client_state
+            .traffic_shaping
+            .switch_profile(traffic_profile)
+    }
+
+    /// Returns all the addresses the client can currently be reached at, i.e. the address behind
+    /// the active gateway followed by the ones behind the connected standby gateways.
+    pub fn reachable_addresses(&self) -> ReachableAddresses {
+        self.client_state.reachable_addresses.current_addresses()
+    }
+}
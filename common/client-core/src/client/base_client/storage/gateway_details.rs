@@ -7,7 +7,6 @@ use nym_gateway_requests::registration::handshake::SharedKeys;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::error::Error;
-use std::ops::Deref;
 use tokio::sync::Mutex;
 use zeroize::Zeroizing;
 
@@ -36,6 +35,11 @@ pub struct PersistedGatewayDetails {
 
     /// Actual gateway details being persisted.
     pub(crate) details: GatewayEndpointConfig,
+
+    /// Details of any additional gateways the client has registered with,
+    /// alongside the keys it shares with each of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) standby: Vec<PersistedStandbyGateway>,
 }
 
 impl From<PersistedGatewayDetails> for GatewayEndpointConfig {
@@ -44,25 +48,72 @@ impl From<PersistedGatewayDetails> for GatewayEndpointConfig {
     }
 }
 
+fn shared_key_hash(shared_key: &SharedKeys) -> Vec<u8> {
+    let key_bytes = Zeroizing::new(shared_key.to_bytes());
+
+    let mut key_hasher = Sha256::new();
+    key_hasher.update(&key_bytes);
+    key_hasher.finalize().to_vec()
+}
+
 impl PersistedGatewayDetails {
     pub fn new(details: GatewayEndpointConfig, shared_key: &SharedKeys) -> Self {
-        let key_bytes = Zeroizing::new(shared_key.to_bytes());
+        PersistedGatewayDetails {
+            key_hash: shared_key_hash(shared_key),
+            details,
+            standby: Vec::new(),
+        }
+    }
+
+    pub fn verify(&self, shared_key: &SharedKeys) -> bool {
+        self.key_hash == shared_key_hash(shared_key)
+    }
 
-        let mut key_hasher = Sha256::new();
-        key_hasher.update(&key_bytes);
-        let key_hash = key_hasher.finalize().to_vec();
+    pub fn standby_gateways(&self) -> &[PersistedStandbyGateway] {
+        &self.standby
+    }
 
-        PersistedGatewayDetails { key_hash, details }
+    pub fn add_standby_gateway(&mut self, details: GatewayEndpointConfig, shared_key: &SharedKeys) {
+        self.standby
+            .push(PersistedStandbyGateway::new(details, shared_key))
     }
 
-    pub fn verify(&self, shared_key: &SharedKeys) -> bool {
-        let key_bytes = Zeroizing::new(shared_key.to_bytes());
+    /// Checks whether the client has registered with the specified gateway,
+    /// either as its primary or as one of the standby gateways.
+    pub fn is_registered_with(&self, gateway_id: &str) -> bool {
+        self.details.gateway_id == gateway_id
+            || self
+                .standby
+                .iter()
+                .any(|standby| standby.details.gateway_id == gateway_id)
+    }
+}
 
-        let mut key_hasher = Sha256::new();
-        key_hasher.update(&key_bytes);
-        let key_hash = key_hasher.finalize();
+/// Gateway the client has registered with in addition to its primary one, so that it could fail over to it.
+/// Just like with the primary gateway, the shared key itself is held in the `KeyStore`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedStandbyGateway {
+    /// The hash of the shared keys to ensure the correct ones are used with those gateway details.
+    #[serde(with = "base64")]
+    key_hash: Vec<u8>,
+
+    pub(crate) details: GatewayEndpointConfig,
+}
+
+impl PersistedStandbyGateway {
+    pub fn new(details: GatewayEndpointConfig, shared_key: &SharedKeys) -> Self {
+        PersistedStandbyGateway {
+            key_hash: shared_key_hash(shared_key),
+            details,
+        }
+    }
+
+    pub fn verify(&self, shared_key: &SharedKeys) -> bool {
+        self.key_hash == shared_key_hash(shared_key)
+    }
 
-        self.key_hash == key_hash.deref()
+    pub fn details(&self) -> &GatewayEndpointConfig {
+        &self.details
     }
 }
 
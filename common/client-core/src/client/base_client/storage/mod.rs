@@ -27,6 +27,8 @@ use crate::config::{self, disk_persistence::CommonClientPaths};
 use crate::error::ClientCoreError;
 #[cfg(all(not(target_arch = "wasm32"), feature = "fs-surb-storage"))]
 use nym_credential_storage::persistent_storage::PersistentStorage as PersistentCredentialStorage;
+#[cfg(all(not(target_arch = "wasm32"), feature = "fs-surb-storage"))]
+use nym_credential_storage::CredentialStorageKey;
 
 pub mod gateway_details;
 
@@ -114,9 +116,23 @@ impl OnDiskPersistent {
         }
     }
 
+    /// Creates the storage using the provided paths. If the credential storage key is set in the
+    /// environment (see [`CredentialStorageKey::from_env`]), the secrets of the stored bandwidth
+    /// credentials are going to be encrypted with it.
     pub async fn from_paths(
         paths: CommonClientPaths,
         debug_config: &config::DebugConfig,
+    ) -> Result<Self, ClientCoreError> {
+        let credentials_key = CredentialStorageKey::from_env();
+        Self::from_paths_with_credentials_key(paths, debug_config, credentials_key.as_ref()).await
+    }
+
+    /// Same as [`Self::from_paths`], but the credentials key is explicitly provided rather than
+    /// read from the environment.
+    pub async fn from_paths_with_credentials_key(
+        paths: CommonClientPaths,
+        debug_config: &config::DebugConfig,
+        credentials_key: Option<&CredentialStorageKey>,
     ) -> Result<Self, ClientCoreError> {
         let key_store = OnDiskKeys::new(paths.keys);
 
@@ -127,7 +143,11 @@ impl OnDiskPersistent {
         .await?;
 
         let credential_store =
-            nym_credential_storage::initialise_persistent_storage(paths.credentials_database).await;
+            PersistentCredentialStorage::init_with_key(paths.credentials_database, credentials_key)
+                .await
+                .map_err(|source| ClientCoreError::CredentialStoreError {
+                    source: Box::new(source),
+                })?;
 
         let gateway_details_store = OnDiskGatewayDetails::new(paths.gateway_details);
 
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::client::mix_traffic::BatchMixMessageSender;
+use crate::client::reachability::ReachableAddressesReceiver;
 use crate::client::topology_control::TopologyAccessor;
+use crate::client::traffic_shaping::{TrafficShapingParameters, TrafficShapingReceiver};
 use crate::{config, spawn_future};
 use futures::task::{Context, Poll};
 use futures::{Future, Stream, StreamExt};
@@ -11,6 +13,7 @@ use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::cover::generate_loop_cover_packet;
 use nym_sphinx::params::{PacketSize, PacketType};
+use nym_sphinx::routing::DelayDistribution;
 use nym_sphinx::utils::sample_poisson_duration;
 use rand::{rngs::OsRng, CryptoRng, Rng};
 use std::pin::Pin;
@@ -37,6 +40,9 @@ where
     /// Defines configuration options related to cover traffic.
     cover_traffic: config::CoverTraffic,
 
+    /// Distribution from which the per-hop delays of the cover packets are sampled.
+    per_hop_delay_distribution: DelayDistribution,
+
     /// Internal state, determined by `average_message_sending_delay`,
     /// used to keep track of when a next packet should be sent out.
     #[cfg(not(target_arch = "wasm32"))]
@@ -65,6 +71,13 @@ where
     secondary_packet_size: Option<PacketSize>,
 
     packet_type: PacketType,
+
+    /// Channel used for receiving updated traffic shaping parameters at runtime.
+    traffic_shaping: TrafficShapingReceiver,
+
+    /// Channel used for receiving the updated address of this client after it failed over
+    /// to a standby gateway.
+    reachable_addresses: ReachableAddressesReceiver,
 }
 
 impl<R> Stream for LoopCoverTrafficStream<R>
@@ -109,6 +122,7 @@ where
 // obviously when we finally make shared rng that is on 'higher' level, this should become
 // generic `R`
 impl LoopCoverTrafficStream<OsRng> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         ack_key: Arc<AckKey>,
         average_ack_delay: Duration,
@@ -117,6 +131,8 @@ impl LoopCoverTrafficStream<OsRng> {
         topology_access: TopologyAccessor,
         traffic_config: config::Traffic,
         cover_config: config::CoverTraffic,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
     ) -> Self {
         let rng = OsRng;
 
@@ -130,6 +146,7 @@ impl LoopCoverTrafficStream<OsRng> {
             ack_key,
             average_ack_delay,
             cover_traffic: cover_config,
+            per_hop_delay_distribution: traffic_config.per_hop_delay_distribution,
             next_delay,
             mix_tx,
             our_full_destination,
@@ -138,9 +155,44 @@ impl LoopCoverTrafficStream<OsRng> {
             primary_packet_size: traffic_config.primary_packet_size,
             secondary_packet_size: traffic_config.secondary_packet_size,
             packet_type: traffic_config.packet_type,
+            traffic_shaping,
+            reachable_addresses,
+        }
+    }
+
+    fn refresh_self_address(&mut self) {
+        if self.reachable_addresses.has_changed().unwrap_or_default() {
+            self.our_full_destination = self.reachable_addresses.borrow_and_update().active;
         }
     }
 
+    fn update_traffic_shaping(&mut self, updated: TrafficShapingParameters) {
+        let previous = self.cover_traffic;
+
+        self.average_ack_delay = updated.acknowledgements.average_ack_delay;
+        self.cover_traffic = updated.cover_traffic;
+        self.secondary_packet_size = updated.traffic.secondary_packet_size;
+
+        // if the stream got re-enabled or its rate has changed, don't wait for the currently
+        // scheduled delay as it might have been sampled from a completely different average
+        if !self.cover_traffic.disable_loop_cover_traffic_stream
+            && (previous.disable_loop_cover_traffic_stream
+                || previous.loop_cover_traffic_average_delay
+                    != self.cover_traffic.loop_cover_traffic_average_delay)
+        {
+            debug!("resetting the loop cover traffic stream delay");
+            self.reset_initial_delay();
+        }
+    }
+
+    fn reset_initial_delay(&mut self) {
+        let sampled = sample_poisson_duration(
+            &mut self.rng,
+            self.cover_traffic.loop_cover_traffic_average_delay,
+        );
+        self.set_next_delay(sampled);
+    }
+
     fn set_next_delay(&mut self, amount: Duration) {
         #[cfg(not(target_arch = "wasm32"))]
         let next_delay = Box::pin(time::sleep(amount));
@@ -169,6 +221,7 @@ impl LoopCoverTrafficStream<OsRng> {
 
     async fn on_new_message(&mut self) {
         trace!("next cover message!");
+        self.refresh_self_address();
 
         let cover_traffic_packet_size = self.loop_cover_message_size();
         trace!("the next loop cover message will be put in a {cover_traffic_packet_size} packet");
@@ -196,6 +249,8 @@ impl LoopCoverTrafficStream<OsRng> {
             &self.our_full_destination,
             self.average_ack_delay,
             self.cover_traffic.loop_cover_traffic_average_delay,
+            &self.per_hop_delay_distribution,
+            topology_ref.mix_layers(),
             cover_traffic_packet_size,
             self.packet_type,
         )
@@ -228,21 +283,18 @@ impl LoopCoverTrafficStream<OsRng> {
     }
 
     pub fn start_with_shutdown(mut self, mut shutdown: nym_task::TaskClient) {
-        if self.cover_traffic.disable_loop_cover_traffic_stream {
-            // we should have never got here in the first place - the task should have never been created to begin with
-            // so panic and review the code that lead to this branch
-            panic!("attempted to start LoopCoverTrafficStream while config explicitly disabled it.")
-        }
-
         // we should set initial delay only when we actually start the stream
-        let sampled = sample_poisson_duration(
-            &mut self.rng,
-            self.cover_traffic.loop_cover_traffic_average_delay,
-        );
-        self.set_next_delay(sampled);
+        self.reset_initial_delay();
+
+        let mut traffic_shaping = self.traffic_shaping.clone();
 
         spawn_future(async move {
             debug!("Started LoopCoverTrafficStream with graceful shutdown support");
+            if self.cover_traffic.disable_loop_cover_traffic_stream {
+                debug!(
+                    "The LoopCoverTrafficStream is disabled and will remain idle until re-enabled"
+                );
+            }
 
             while !shutdown.is_shutdown() {
                 tokio::select! {
@@ -250,7 +302,11 @@ impl LoopCoverTrafficStream<OsRng> {
                     _ = shutdown.recv() => {
                         log::trace!("LoopCoverTrafficStream: Received shutdown");
                     }
-                    next = self.next() => {
+                    Ok(_) = traffic_shaping.changed() => {
+                        let updated = *traffic_shaping.borrow_and_update();
+                        self.update_traffic_shaping(updated);
+                    }
+                    next = self.next(), if !self.cover_traffic.disable_loop_cover_traffic_stream => {
                         if next.is_some() {
                             self.on_new_message().await;
                         } else {
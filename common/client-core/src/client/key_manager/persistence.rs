@@ -3,7 +3,10 @@
 
 use crate::client::key_manager::KeyManager;
 use async_trait::async_trait;
+use nym_gateway_requests::registration::handshake::SharedKeys;
+use std::collections::HashMap;
 use std::error::Error;
+use std::sync::Arc;
 use tokio::sync::Mutex;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -11,8 +14,6 @@ use crate::config::disk_persistence::keys_paths::ClientKeysPaths;
 #[cfg(not(target_arch = "wasm32"))]
 use nym_crypto::asymmetric::{encryption, identity};
 #[cfg(not(target_arch = "wasm32"))]
-use nym_gateway_requests::registration::handshake::SharedKeys;
-#[cfg(not(target_arch = "wasm32"))]
 use nym_pemstore::traits::{PemStorableKey, PemStorableKeyPair};
 #[cfg(not(target_arch = "wasm32"))]
 use nym_pemstore::KeyPairPath;
@@ -28,6 +29,17 @@ pub trait KeyStore {
     async fn load_keys(&self) -> Result<KeyManager, Self::StorageError>;
 
     async fn store_keys(&self, keys: &KeyManager) -> Result<(), Self::StorageError>;
+
+    async fn load_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<Arc<SharedKeys>, Self::StorageError>;
+
+    async fn store_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+        key: Arc<SharedKeys>,
+    ) -> Result<(), Self::StorageError>;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -196,6 +208,25 @@ impl OnDiskKeys {
 
         Ok(())
     }
+
+    fn load_standby_gateway_key(&self, gateway_id: &str) -> Result<SharedKeys, OnDiskKeysError> {
+        self.load_key(
+            &self.paths.standby_gateway_shared_key(gateway_id),
+            "standby gateway shared keys",
+        )
+    }
+
+    fn store_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+        key: &SharedKeys,
+    ) -> Result<(), OnDiskKeysError> {
+        self.store_key(
+            key,
+            &self.paths.standby_gateway_shared_key(gateway_id),
+            "standby gateway shared keys",
+        )
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -210,11 +241,27 @@ impl KeyStore for OnDiskKeys {
     async fn store_keys(&self, keys: &KeyManager) -> Result<(), Self::StorageError> {
         self.store_keys(keys)
     }
+
+    async fn load_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<Arc<SharedKeys>, Self::StorageError> {
+        self.load_standby_gateway_key(gateway_id).map(Arc::new)
+    }
+
+    async fn store_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+        key: Arc<SharedKeys>,
+    ) -> Result<(), Self::StorageError> {
+        self.store_standby_gateway_key(gateway_id, &key)
+    }
 }
 
 #[derive(Default)]
 pub struct InMemEphemeralKeys {
     keys: Mutex<Option<KeyManager>>,
+    standby_gateway_keys: Mutex<HashMap<String, Arc<SharedKeys>>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -234,4 +281,28 @@ impl KeyStore for InMemEphemeralKeys {
         *self.keys.lock().await = Some(keys.clone());
         Ok(())
     }
+
+    async fn load_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+    ) -> Result<Arc<SharedKeys>, Self::StorageError> {
+        self.standby_gateway_keys
+            .lock()
+            .await
+            .get(gateway_id)
+            .cloned()
+            .ok_or(EphemeralKeysError)
+    }
+
+    async fn store_standby_gateway_key(
+        &self,
+        gateway_id: &str,
+        key: Arc<SharedKeys>,
+    ) -> Result<(), Self::StorageError> {
+        self.standby_gateway_keys
+            .lock()
+            .await
+            .insert(gateway_id.to_string(), key);
+        Ok(())
+    }
 }
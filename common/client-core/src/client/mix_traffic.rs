@@ -1,10 +1,13 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::reachability::ReachableAddressesHandle;
 use crate::spawn_future;
 use log::*;
+use nym_gateway_client::error::GatewayClientError;
 use nym_gateway_client::GatewayClient;
 use nym_sphinx::forwarding::packet::MixPacket;
+use std::collections::VecDeque;
 
 use nym_credential_storage::storage::Storage;
 #[cfg(not(target_arch = "wasm32"))]
@@ -24,6 +27,14 @@ pub struct MixTrafficController<C, St: Storage> {
     // TODO: most likely to be replaced by some higher level construct as
     // later on gateway_client will need to be accessible by other entities
     gateway_client: GatewayClient<C, St>,
+
+    /// Connections to additional gateways the client is listening on, that are going to be used
+    /// for sending packets if the current gateway becomes unavailable.
+    standby_gateways: VecDeque<GatewayClient<C, St>>,
+
+    /// Handle used for announcing change of the client address after failing over to a standby gateway.
+    reachable_addresses: ReachableAddressesHandle,
+
     mix_rx: BatchMixMessageReceiver,
 
     // TODO: this is temporary work-around.
@@ -31,6 +42,17 @@ pub struct MixTrafficController<C, St: Storage> {
     consecutive_gateway_failure_count: usize,
 }
 
+// the gateway client has already tried to reconnect on its own before returning any of those
+fn is_connection_failure(err: &GatewayClientError) -> bool {
+    err.is_closed_connection()
+        || matches!(
+            err,
+            GatewayClientError::ConnectionNotEstablished
+                | GatewayClientError::NotAuthenticated
+                | GatewayClientError::ConnectionInInvalidState
+        )
+}
+
 impl<C, St> MixTrafficController<C, St>
 where
     C: DkgQueryClient + Sync + Send + 'static,
@@ -39,12 +61,16 @@ where
 {
     pub fn new(
         gateway_client: GatewayClient<C, St>,
+        standby_gateways: Vec<GatewayClient<C, St>>,
+        reachable_addresses: ReachableAddressesHandle,
     ) -> (MixTrafficController<C, St>, BatchMixMessageSender) {
         let (message_sender, message_receiver) =
             tokio::sync::mpsc::channel(MIX_MESSAGE_RECEIVER_BUFFER_SIZE);
         (
             MixTrafficController {
                 gateway_client,
+                standby_gateways: standby_gateways.into(),
+                reachable_addresses,
                 mix_rx: message_receiver,
                 consecutive_gateway_failure_count: 0,
             },
@@ -52,6 +78,47 @@ where
         )
     }
 
+    /// Attempts to switch to the first usable standby gateway. The failed gateway is put at the back
+    /// of the standby queue, so that it could be reused if it ever comes back up.
+    async fn fail_over(&mut self) -> bool {
+        for _ in 0..self.standby_gateways.len() {
+            let Some(mut candidate) = self.standby_gateways.pop_front() else {
+                break;
+            };
+            let candidate_id = candidate.gateway_identity();
+
+            if !candidate.is_connected() {
+                if let Err(err) = candidate.try_reconnect().await {
+                    warn!("standby gateway {candidate_id} is also unavailable: {err}");
+                    self.standby_gateways.push_back(candidate);
+                    continue;
+                }
+            }
+
+            // the bandwidth controller always follows the active connection
+            candidate.set_bandwidth_controller(self.gateway_client.take_bandwidth_controller());
+            if let Err(err) = candidate.ensure_enough_bandwidth().await {
+                warn!("failed to obtain bandwidth with standby gateway {candidate_id}: {err}");
+                self.gateway_client
+                    .set_bandwidth_controller(candidate.take_bandwidth_controller());
+                self.standby_gateways.push_back(candidate);
+                continue;
+            }
+
+            let failed = std::mem::replace(&mut self.gateway_client, candidate);
+            warn!(
+                "failed over from gateway {} to the standby gateway {candidate_id}",
+                failed.gateway_identity()
+            );
+            self.standby_gateways.push_back(failed);
+            self.reachable_addresses.fail_over(candidate_id);
+            self.consecutive_gateway_failure_count = 0;
+            return true;
+        }
+
+        false
+    }
+
     async fn on_messages(&mut self, mut mix_packets: Vec<MixPacket>) {
         debug_assert!(!mix_packets.is_empty());
 
@@ -68,6 +135,15 @@ where
             Err(err) => {
                 error!("Failed to send sphinx packet(s) to the gateway! - {err}");
                 self.consecutive_gateway_failure_count += 1;
+
+                // any dropped packets are going to be retransmitted once their acks time out
+                if (is_connection_failure(&err)
+                    || self.consecutive_gateway_failure_count == MAX_FAILURE_COUNT)
+                    && self.fail_over().await
+                {
+                    return;
+                }
+
                 if self.consecutive_gateway_failure_count == MAX_FAILURE_COUNT {
                     // todo: in the future this should initiate a 'graceful' shutdown or try
                     // to reconnect?
@@ -7,8 +7,11 @@ pub(crate) mod helpers;
 pub mod inbound_messages;
 pub mod key_manager;
 pub mod mix_traffic;
+pub mod name_resolver;
+pub mod reachability;
 pub mod real_messages_control;
 pub mod received_buffer;
 pub mod replies;
 pub mod topology_control;
+pub mod traffic_shaping;
 pub(crate) mod transmission_buffer;
@@ -0,0 +1,403 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use log::{debug, warn};
+use nym_name_service_common::{NymName, RegisteredName};
+use nym_sphinx::addressing::clients::{Recipient, RecipientFormattingError};
+use nym_validator_client::client::NymApiClient;
+use nym_validator_client::ValidatorClientError;
+use std::collections::HashMap;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use url::Url;
+
+/// How long the list of registered names retrieved from the nym-api is considered valid for.
+pub const DEFAULT_NAMES_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum NameResolutionError {
+    #[error("'{name}' is neither a valid nym address nor a valid name")]
+    MalformedName { name: String },
+
+    #[error("name '{name}' is not registered")]
+    UnknownName { name: String },
+
+    #[error("registration of name '{name}' has expired")]
+    ExpiredName { name: String },
+
+    #[error("name '{name}' points to a malformed nym address: {source}")]
+    MalformedRegisteredAddress {
+        name: String,
+        #[source]
+        source: RecipientFormattingError,
+    },
+
+    #[error(
+        "name '{name}' points to an address with identity {address_identity}, \
+         but it was registered with identity key {registered_identity}"
+    )]
+    IdentityKeyMismatch {
+        name: String,
+        address_identity: String,
+        registered_identity: String,
+    },
+
+    #[error("the list of nym apis is empty")]
+    NoNymApisAvailable,
+
+    #[error("failed to retrieve the registered names: {source}")]
+    NymApiFailure {
+        #[from]
+        source: ValidatorClientError,
+    },
+}
+
+struct CachedNames {
+    retrieved_at: OffsetDateTime,
+    names: HashMap<String, RegisteredName>,
+}
+
+struct ResolverInner {
+    // only created once a name actually has to be looked up
+    nym_api_client: Option<NymApiClient>,
+    nym_api_urls: Vec<Url>,
+    currently_used_api: usize,
+    cached: Option<CachedNames>,
+    // names that weren't registered when we last looked them up, alongside the time of the lookup
+    unknown_names: HashMap<String, OffsetDateTime>,
+}
+
+impl ResolverInner {
+    /// Determines whether the registered names have to be retrieved again in order to resolve
+    /// the provided name, i.e. whether the cache has gone stale or the name is unknown and
+    /// hasn't already been looked up within the ttl.
+    fn requires_refresh(&self, name: &str, now: OffsetDateTime, cache_ttl: Duration) -> bool {
+        let Some(cached) = &self.cached else {
+            return true;
+        };
+        if cached.retrieved_at + cache_ttl <= now {
+            return true;
+        }
+        if cached.names.contains_key(name) {
+            return false;
+        }
+
+        // if the name isn't known, it might have been registered since we last checked
+        match self.unknown_names.get(name) {
+            Some(looked_up_at) => *looked_up_at + cache_ttl <= now,
+            None => true,
+        }
+    }
+
+    fn use_next_nym_api(&mut self) {
+        if self.nym_api_urls.len() > 1 {
+            self.currently_used_api = (self.currently_used_api + 1) % self.nym_api_urls.len();
+            if let Some(client) = &mut self.nym_api_client {
+                client.change_nym_api(self.nym_api_urls[self.currently_used_api].clone())
+            }
+        }
+    }
+
+    async fn refresh(&mut self) -> Result<(), NameResolutionError> {
+        if self.nym_api_urls.is_empty() {
+            return Err(NameResolutionError::NoNymApisAvailable);
+        }
+
+        let mut last_err = None;
+        for _ in 0..self.nym_api_urls.len() {
+            let nym_api_url = &self.nym_api_urls[self.currently_used_api];
+            let nym_api_client = self
+                .nym_api_client
+                .get_or_insert_with(|| NymApiClient::new(nym_api_url.clone()));
+
+            match nym_api_client.get_cached_registered_names().await {
+                Ok(names) => {
+                    debug!("retrieved {} registered names", names.len());
+                    self.cached = Some(CachedNames {
+                        retrieved_at: OffsetDateTime::now_utc(),
+                        names: names
+                            .into_iter()
+                            .map(|name| (name.entry().to_string(), name))
+                            .collect(),
+                    });
+                    return Ok(());
+                }
+                Err(err) => {
+                    warn!("failed to retrieve the registered names: {err}");
+                    last_err = Some(err);
+                    self.use_next_nym_api();
+                }
+            }
+        }
+
+        // we have made at least a single attempt since the list of apis can't be empty
+        Err(last_err.expect("no nym api query was attempted").into())
+    }
+}
+
+/// Resolves names registered in the name service contract into nym addresses,
+/// caching the registrations retrieved from the nym-api.
+pub struct NameResolver {
+    cache_ttl: Duration,
+    inner: Mutex<ResolverInner>,
+}
+
+impl NameResolver {
+    /// Creates a new resolver using the provided nym apis for looking up the names.
+    /// Note that they're only going to be contacted once a name (rather than a plain nym address)
+    /// has to be resolved, so the list can be empty if no names are ever going to be used.
+    pub fn new(nym_api_urls: Vec<Url>) -> Self {
+        NameResolver {
+            cache_ttl: DEFAULT_NAMES_CACHE_TTL,
+            inner: Mutex::new(ResolverInner {
+                nym_api_client: None,
+                nym_api_urls,
+                currently_used_api: 0,
+                cached: None,
+                unknown_names: HashMap::new(),
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Resolves the provided string into a nym address. If it's not a valid nym address by itself,
+    /// it's treated as a registered name and is looked up in the name service.
+    pub async fn resolve_recipient(
+        &self,
+        name_or_address: &str,
+    ) -> Result<Recipient, NameResolutionError> {
+        match Recipient::try_from_base58_string(name_or_address) {
+            Ok(recipient) => Ok(recipient),
+            Err(_) => self.resolve(name_or_address).await,
+        }
+    }
+
+    /// Looks up the provided name in the name service and returns the nym address it points to.
+    pub async fn resolve(&self, name: &str) -> Result<Recipient, NameResolutionError> {
+        let name = NymName::new(name)
+            .map_err(|_| NameResolutionError::MalformedName {
+                name: name.to_string(),
+            })?
+            .to_string();
+
+        let mut inner = self.inner.lock().await;
+        let now = OffsetDateTime::now_utc();
+        let cache_ttl = self.cache_ttl;
+        inner
+            .unknown_names
+            .retain(|_, looked_up_at| *looked_up_at + cache_ttl > now);
+
+        if inner.requires_refresh(&name, now, cache_ttl) {
+            inner.refresh().await?;
+        }
+
+        let Some(registered) = inner
+            .cached
+            .as_ref()
+            .and_then(|cached| cached.names.get(&name))
+        else {
+            inner.unknown_names.entry(name.clone()).or_insert(now);
+            return Err(NameResolutionError::UnknownName { name });
+        };
+
+        if registered.is_expired(now.unix_timestamp() as u64) {
+            return Err(NameResolutionError::ExpiredName { name });
+        }
+
+        verified_recipient(registered)
+    }
+}
+
+/// Parses the address the name is pointing to and makes sure it belongs to the same identity
+/// that has signed the registration.
+fn verified_recipient(registered: &RegisteredName) -> Result<Recipient, NameResolutionError> {
+    let name = registered.entry().to_string();
+    let recipient =
+        Recipient::try_from_base58_string(registered.name.address.as_str()).map_err(|source| {
+            NameResolutionError::MalformedRegisteredAddress {
+                name: name.clone(),
+                source,
+            }
+        })?;
+
+    let address_identity = recipient.identity().to_base58_string();
+    if address_identity != registered.name.identity_key {
+        return Err(NameResolutionError::IdentityKeyMismatch {
+            name,
+            address_identity,
+            registered_identity: registered.name.identity_key.clone(),
+        });
+    }
+
+    Ok(recipient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_name_service_common::{Addr, Address, Coin, NameDetails};
+
+    fn registered(address: &Recipient, identity_key: String) -> RegisteredName {
+        registered_until(address, identity_key, 1000)
+    }
+
+    fn registered_until(
+        address: &Recipient,
+        identity_key: String,
+        expires_at: u64,
+    ) -> RegisteredName {
+        RegisteredName {
+            id: 1,
+            name: NameDetails {
+                name: NymName::new("steves-server").unwrap(),
+                address: Address::new(&address.to_string()),
+                identity_key,
+            },
+            owner: Addr::unchecked("owner"),
+            block_height: 1,
+            deposit: Coin::new(100, "unym"),
+            expires_at,
+        }
+    }
+
+    fn random_recipient() -> Recipient {
+        let mut rng = rand::thread_rng();
+        Recipient::new(
+            *identity::KeyPair::new(&mut rng).public_key(),
+            *encryption::KeyPair::new(&mut rng).public_key(),
+            *identity::KeyPair::new(&mut rng).public_key(),
+        )
+    }
+
+    #[test]
+    fn registration_with_matching_identity_is_resolved() {
+        let recipient = random_recipient();
+        let entry = registered(&recipient, recipient.identity().to_base58_string());
+        assert_eq!(verified_recipient(&entry).unwrap(), recipient);
+    }
+
+    #[test]
+    fn registration_with_different_identity_is_rejected() {
+        let recipient = random_recipient();
+        let other = random_recipient();
+        let entry = registered(&recipient, other.identity().to_base58_string());
+        assert!(matches!(
+            verified_recipient(&entry),
+            Err(NameResolutionError::IdentityKeyMismatch { .. })
+        ));
+    }
+
+    // the resolver has no nym apis available, so any attempt at refreshing the cache is going
+    // to fail with `NoNymApisAvailable`
+    fn offline_resolver(cache_ttl: Duration, cached: Option<CachedNames>) -> NameResolver {
+        let resolver = NameResolver::new(Vec::new()).with_cache_ttl(cache_ttl);
+        resolver.inner.try_lock().unwrap().cached = cached;
+        resolver
+    }
+
+    fn cached_names(retrieved_at: OffsetDateTime, entries: Vec<RegisteredName>) -> CachedNames {
+        CachedNames {
+            retrieved_at,
+            names: entries
+                .into_iter()
+                .map(|name| (name.entry().to_string(), name))
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn registered_names_are_resolved_from_the_cache_until_the_ttl_expires() {
+        let recipient = random_recipient();
+        let entry = registered_until(
+            &recipient,
+            recipient.identity().to_base58_string(),
+            u64::MAX,
+        );
+        let now = OffsetDateTime::now_utc();
+
+        let fresh = offline_resolver(
+            Duration::from_secs(60),
+            Some(cached_names(now, vec![entry.clone()])),
+        );
+        assert_eq!(fresh.resolve("steves-server").await.unwrap(), recipient);
+
+        let stale = offline_resolver(
+            Duration::from_secs(60),
+            Some(cached_names(now - Duration::from_secs(61), vec![entry])),
+        );
+        assert!(matches!(
+            stale.resolve("steves-server").await,
+            Err(NameResolutionError::NoNymApisAvailable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn unknown_names_are_only_looked_up_again_after_the_ttl() {
+        let now = OffsetDateTime::now_utc();
+        let resolver =
+            offline_resolver(Duration::from_secs(60), Some(cached_names(now, Vec::new())));
+
+        // the first lookup has to check whether the name has been registered in the meantime
+        assert!(matches!(
+            resolver.resolve("steves-server").await,
+            Err(NameResolutionError::NoNymApisAvailable)
+        ));
+
+        // pretend that lookup has succeeded without finding the name
+        resolver
+            .inner
+            .lock()
+            .await
+            .unknown_names
+            .insert("steves-server".to_string(), now);
+        assert!(matches!(
+            resolver.resolve("steves-server").await,
+            Err(NameResolutionError::UnknownName { .. })
+        ));
+        assert!(matches!(
+            resolver.resolve("steves-server").await,
+            Err(NameResolutionError::UnknownName { .. })
+        ));
+
+        // and once the negative result is old enough, the name is looked up again
+        resolver
+            .inner
+            .lock()
+            .await
+            .unknown_names
+            .insert("steves-server".to_string(), now - Duration::from_secs(61));
+        assert!(matches!(
+            resolver.resolve("steves-server").await,
+            Err(NameResolutionError::NoNymApisAvailable)
+        ));
+    }
+
+    #[test]
+    fn unknown_names_are_recorded_once_looked_up() {
+        let now = OffsetDateTime::now_utc();
+        let ttl = Duration::from_secs(60);
+        let mut inner = ResolverInner {
+            nym_api_client: None,
+            nym_api_urls: Vec::new(),
+            currently_used_api: 0,
+            cached: Some(cached_names(now, Vec::new())),
+            unknown_names: HashMap::new(),
+        };
+        assert!(inner.requires_refresh("steves-server", now, ttl));
+
+        inner.unknown_names.insert("steves-server".to_string(), now);
+        assert!(!inner.requires_refresh("steves-server", now, ttl));
+        assert!(!inner.requires_refresh("steves-server", now + Duration::from_secs(59), ttl));
+        assert!(inner.requires_refresh("steves-server", now + Duration::from_secs(60), ttl));
+
+        // other names are unaffected
+        assert!(inner.requires_refresh("other-server", now, ttl));
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use log::info;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::addressing::nodes::NodeIdentity;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Set of addresses under which the client can currently be reached, one for each gateway
+/// it's connected to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachableAddresses {
+    /// Address behind the gateway that is currently used for sending packets.
+    /// It's the one embedded in all constructed acks and reply SURBs.
+    pub active: Recipient,
+
+    /// Addresses behind the standby gateways the client is also listening on.
+    pub standby: Vec<Recipient>,
+}
+
+impl ReachableAddresses {
+    pub fn new(active: Recipient, standby: Vec<Recipient>) -> Self {
+        ReachableAddresses { active, standby }
+    }
+
+    /// Returns all the addresses of the client, starting with the active one.
+    pub fn all(&self) -> Vec<Recipient> {
+        std::iter::once(self.active)
+            .chain(self.standby.iter().copied())
+            .collect()
+    }
+
+    fn fail_over(&self, new_gateway: NodeIdentity) -> Self {
+        let active = Recipient::new(
+            *self.active.identity(),
+            *self.active.encryption_key(),
+            new_gateway,
+        );
+
+        // the previously active gateway is kept as a fallback in case it becomes available again
+        let standby = self
+            .standby
+            .iter()
+            .chain(std::iter::once(&self.active))
+            .filter(|address| address.gateway() != &new_gateway)
+            .copied()
+            .collect();
+
+        ReachableAddresses { active, standby }
+    }
+}
+
+pub type ReachableAddressesReceiver = watch::Receiver<ReachableAddresses>;
+
+/// Handle used for publishing changes to the addresses of the client, for example after it failed over
+/// to one of its standby gateways.
+#[derive(Debug, Clone)]
+pub struct ReachableAddressesHandle {
+    sender: Arc<watch::Sender<ReachableAddresses>>,
+}
+
+impl ReachableAddressesHandle {
+    pub fn new(initial: ReachableAddresses) -> (Self, ReachableAddressesReceiver) {
+        let (sender, receiver) = watch::channel(initial);
+        (
+            ReachableAddressesHandle {
+                sender: Arc::new(sender),
+            },
+            receiver,
+        )
+    }
+
+    pub fn current_addresses(&self) -> ReachableAddresses {
+        self.sender.borrow().clone()
+    }
+
+    pub fn active_address(&self) -> Recipient {
+        self.sender.borrow().active
+    }
+
+    pub fn subscribe(&self) -> ReachableAddressesReceiver {
+        self.sender.subscribe()
+    }
+
+    /// Makes the address behind the provided gateway the active one.
+    pub(crate) fn fail_over(&self, new_gateway: NodeIdentity) {
+        let updated = self.current_addresses().fail_over(new_gateway);
+        info!("the address of this client is now: {}", updated.active);
+        self.sender.send_replace(updated);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use rand::rngs::OsRng;
+
+    fn gateway() -> NodeIdentity {
+        *identity::KeyPair::new(&mut OsRng).public_key()
+    }
+
+    #[test]
+    fn failing_over_replaces_active_address() {
+        let identity = *identity::KeyPair::new(&mut OsRng).public_key();
+        let encryption = *encryption::KeyPair::new(&mut OsRng).public_key();
+        let primary = Recipient::new(identity, encryption, gateway());
+        let standby1 = Recipient::new(identity, encryption, gateway());
+        let standby2 = Recipient::new(identity, encryption, gateway());
+
+        let addresses = ReachableAddresses::new(primary, vec![standby1, standby2]);
+        assert_eq!(addresses.all(), vec![primary, standby1, standby2]);
+
+        let updated = addresses.fail_over(*standby2.gateway());
+        assert_eq!(updated.active, standby2);
+        assert_eq!(updated.standby, vec![standby1, primary]);
+
+        // and it's possible to go back to the original gateway
+        let updated = updated.fail_over(*primary.gateway());
+        assert_eq!(updated.active, primary);
+        assert_eq!(updated.standby, vec![standby1, standby2]);
+
+        // failing over to the already active gateway does not duplicate it
+        let unchanged = updated.fail_over(*primary.gateway());
+        assert_eq!(unchanged, updated);
+    }
+}
@@ -3,6 +3,7 @@
 
 use super::PendingAcknowledgement;
 use crate::client::real_messages_control::acknowledgement_control::RetransmissionRequestSender;
+use crate::client::traffic_shaping::{TrafficShapingParameters, TrafficShapingReceiver};
 use futures::channel::mpsc;
 use futures::StreamExt;
 use log::*;
@@ -100,6 +101,9 @@ pub(super) struct ActionController {
 
     /// Channel for notifying `RetransmissionRequestListener` about expired acknowledgements.
     retransmission_sender: RetransmissionRequestSender,
+
+    /// Channel used for receiving updated traffic shaping parameters at runtime.
+    traffic_shaping: TrafficShapingReceiver,
 }
 
 impl ActionController {
@@ -107,6 +111,7 @@ impl ActionController {
         config: Config,
         retransmission_sender: RetransmissionRequestSender,
         incoming_actions: AckActionReceiver,
+        traffic_shaping: TrafficShapingReceiver,
     ) -> Self {
         ActionController {
             config,
@@ -114,9 +119,16 @@ impl ActionController {
             pending_acks_timers: NonExhaustiveDelayQueue::new(),
             incoming_actions,
             retransmission_sender,
+            traffic_shaping,
         }
     }
 
+    fn update_traffic_shaping(&mut self, updated: TrafficShapingParameters) {
+        // note: this only affects timers started after the update
+        self.config.ack_wait_addition = updated.acknowledgements.ack_wait_addition;
+        self.config.ack_wait_multiplier = updated.acknowledgements.ack_wait_multiplier;
+    }
+
     fn handle_insert(&mut self, pending_acks: Vec<PendingAcknowledgement>) {
         for pending_ack in pending_acks {
             let frag_id = pending_ack.message_chunk.fragment_identifier();
@@ -263,6 +275,8 @@ impl ActionController {
     pub(super) async fn run_with_shutdown(&mut self, mut shutdown: nym_task::TaskClient) {
         debug!("Started ActionController with graceful shutdown support");
 
+        let mut traffic_shaping = self.traffic_shaping.clone();
+
         while !shutdown.is_shutdown() {
             tokio::select! {
                 action = self.incoming_actions.next() => match action {
@@ -281,6 +295,10 @@ impl ActionController {
                         break;
                     }
                 },
+                Ok(_) = traffic_shaping.changed() => {
+                    let updated = *traffic_shaping.borrow_and_update();
+                    self.update_traffic_shaping(updated);
+                }
                 _ = shutdown.recv_with_delay() => {
                     log::trace!("ActionController: Received shutdown");
                 }
@@ -10,6 +10,7 @@ use self::{
 use crate::client::inbound_messages::InputMessageReceiver;
 use crate::client::real_messages_control::message_handler::MessageHandler;
 use crate::client::replies::reply_controller::ReplyControllerSender;
+use crate::client::traffic_shaping::TrafficShapingReceiver;
 use crate::spawn_future;
 use action_controller::AckActionReceiver;
 use futures::channel::mpsc;
@@ -202,6 +203,7 @@ where
         connectors: AcknowledgementControllerConnectors,
         message_handler: MessageHandler<R>,
         reply_controller_sender: ReplyControllerSender,
+        traffic_shaping: TrafficShapingReceiver,
     ) -> Self {
         let (retransmission_tx, retransmission_rx) = mpsc::unbounded();
 
@@ -211,6 +213,7 @@ where
             action_config,
             retransmission_tx,
             connectors.ack_action_receiver,
+            traffic_shaping,
         );
 
         // will listen for any acks coming from the network
@@ -1,6 +1,7 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::reachability::ReachableAddressesReceiver;
 use crate::client::real_messages_control::acknowledgement_control::PendingAcknowledgement;
 use crate::client::real_messages_control::real_traffic_stream::{
     BatchRealMessageSender, RealMessage,
@@ -8,6 +9,7 @@ use crate::client::real_messages_control::real_traffic_stream::{
 use crate::client::real_messages_control::{AckActionSender, Action};
 use crate::client::replies::reply_storage::{ReceivedReplySurbsMap, SentReplyKeys, UsedSenderTags};
 use crate::client::topology_control::{TopologyAccessor, TopologyReadPermit};
+use crate::client::traffic_shaping::TrafficShapingReceiver;
 use log::{debug, error, info, trace, warn};
 use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
@@ -17,6 +19,7 @@ use nym_sphinx::chunking::fragment::{Fragment, FragmentIdentifier};
 use nym_sphinx::message::NymMessage;
 use nym_sphinx::params::{PacketSize, PacketType, DEFAULT_NUM_MIX_HOPS};
 use nym_sphinx::preparer::{MessagePreparer, PreparedFragment};
+use nym_sphinx::routing::DelayDistribution;
 use nym_sphinx::Delay;
 use nym_task::connections::TransmissionLane;
 use nym_topology::{NymTopology, NymTopologyError};
@@ -97,6 +100,9 @@ pub(crate) struct Config {
     /// Average delay an acknowledgement packet is going to get delay at a single mixnode.
     average_ack_delay: Duration,
 
+    /// Distribution from which the per-hop delays of all constructed packets are sampled.
+    delay_distribution: DelayDistribution,
+
     /// Number of mix hops each packet ('real' message, ack, reply) is expected to take.
     /// Note that it does not include gateway hops.
     num_mix_hops: u8,
@@ -120,6 +126,7 @@ impl Config {
             sender_address,
             average_packet_delay,
             average_ack_delay,
+            delay_distribution: DelayDistribution::default(),
             num_mix_hops: DEFAULT_NUM_MIX_HOPS,
             primary_packet_size: PacketSize::default(),
             secondary_packet_size: None,
@@ -133,6 +140,12 @@ impl Config {
         self
     }
 
+    /// Allows setting non-default distribution of the per-hop delays.
+    pub fn with_custom_delay_distribution(mut self, delay_distribution: DelayDistribution) -> Self {
+        self.delay_distribution = delay_distribution;
+        self
+    }
+
     /// Allows setting non-default size of the sphinx packets sent out.
     pub fn with_custom_primary_packet_size(mut self, packet_size: PacketSize) -> Self {
         self.primary_packet_size = packet_size;
@@ -156,12 +169,15 @@ pub(crate) struct MessageHandler<R> {
     topology_access: TopologyAccessor,
     reply_key_storage: SentReplyKeys,
     tag_storage: UsedSenderTags,
+    traffic_shaping: TrafficShapingReceiver,
+    reachable_addresses: ReachableAddressesReceiver,
 }
 
 impl<R> MessageHandler<R>
 where
     R: CryptoRng + Rng,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         config: Config,
         rng: R,
@@ -170,6 +186,8 @@ where
         topology_access: TopologyAccessor,
         reply_key_storage: SentReplyKeys,
         tag_storage: UsedSenderTags,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
     ) -> Self
     where
         R: Copy,
@@ -180,6 +198,7 @@ where
             config.average_packet_delay,
             config.average_ack_delay,
         )
+        .with_delay_distribution(config.delay_distribution)
         .with_mix_hops(config.num_mix_hops);
 
         MessageHandler {
@@ -191,6 +210,53 @@ where
             topology_access,
             reply_key_storage,
             tag_storage,
+            traffic_shaping,
+            reachable_addresses,
+        }
+    }
+
+    /// Applies the new traffic shaping parameters, if they got changed since the last packets
+    /// have been prepared.
+    fn refresh_traffic_shaping(&mut self) {
+        if !self.traffic_shaping.has_changed().unwrap_or_default() {
+            return;
+        }
+
+        let updated = *self.traffic_shaping.borrow_and_update();
+        self.config.average_packet_delay = updated.traffic.average_packet_delay;
+        self.config.average_ack_delay = updated.acknowledgements.average_ack_delay;
+        self.config.secondary_packet_size = updated.traffic.secondary_packet_size;
+
+        self.message_preparer
+            .set_average_packet_delay(self.config.average_packet_delay);
+        self.message_preparer
+            .set_average_ack_delay(self.config.average_ack_delay);
+    }
+
+    /// Makes sure all new packets are constructed with our current address, in case we failed over
+    /// to a standby gateway since the last packets have been prepared.
+    fn refresh_self_address(&mut self) {
+        if !self.reachable_addresses.has_changed().unwrap_or_default() {
+            return;
+        }
+
+        self.config.sender_address = self.reachable_addresses.borrow_and_update().active;
+        self.message_preparer
+            .set_sender_address(self.config.sender_address);
+    }
+
+    /// Makes sure all new packets are constructed for the number of mix layers of the current
+    /// network topology, in case it got changed in the mixnet contract since the last packets
+    /// have been prepared.
+    async fn refresh_mix_hops(&mut self) {
+        let Some(mix_layers) = self.topology_access.current_mix_layers().await else {
+            return;
+        };
+
+        if mix_layers != self.config.num_mix_hops {
+            debug!("the network now consists of {mix_layers} mix layers");
+            self.config.num_mix_hops = mix_layers;
+            self.message_preparer.set_mix_hops(mix_layers);
         }
     }
 
@@ -246,6 +312,10 @@ where
         &mut self,
         amount: usize,
     ) -> Result<(Vec<ReplySurb>, Vec<SurbEncryptionKey>), PreparationError> {
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         let topology_permit = self.topology_access.get_read_permit().await;
         let topology = self.get_topology(&topology_permit)?;
 
@@ -268,6 +338,10 @@ where
         reply_surb: ReplySurb,
         is_extra_surb_request: bool,
     ) -> Result<(), SurbWrappedPreparationError> {
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         let msg = NymMessage::new_reply(message);
         let packet_size = self.optimal_packet_size(&msg);
         debug!("Using {packet_size} packets for {msg}");
@@ -326,6 +400,9 @@ where
 
     // // TODO: this will require additional argument to make it use different variant of `ReplyMessage`
     pub(crate) fn split_reply_message(&mut self, message: Vec<u8>) -> Vec<Fragment> {
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+
         let msg = NymMessage::new_reply(ReplyMessage::new_data_message(message));
         let packet_size = self.optimal_packet_size(&msg);
         debug!("Using {packet_size} packets for {msg}");
@@ -435,6 +512,10 @@ where
         // TODO: I really dislike existence of this assertion, it implies code has to be re-organised
         debug_assert!(!matches!(message, NymMessage::Reply(_)));
 
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         // TODO2: it's really annoying we have to get topology permit again here due to borrow-checker
         let topology_permit = self.topology_access.get_read_permit().await;
         let topology = self.get_topology(&topology_permit)?;
@@ -543,6 +624,10 @@ where
         packet_type: PacketType,
     ) -> Result<PreparedFragment, PreparationError> {
         debug!("Sending single chunk with packet type {packet_type}");
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         let topology_permit = self.topology_access.get_read_permit().await;
         let topology = self.get_topology(&topology_permit)?;
 
@@ -573,6 +658,10 @@ where
             reply_surbs.len()
         );
 
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         let topology_permit = self.topology_access.get_read_permit().await;
         let topology = match self.get_topology(&topology_permit) {
             Ok(topology) => topology,
@@ -602,6 +691,10 @@ where
         reply_surb: ReplySurb,
         chunk: Fragment,
     ) -> Result<PreparedFragment, SurbWrappedPreparationError> {
+        self.refresh_traffic_shaping();
+        self.refresh_self_address();
+        self.refresh_mix_hops().await;
+
         let topology_permit = self.topology_access.get_read_permit().await;
         let topology = match self.get_topology(&topology_permit) {
             Ok(topology) => topology,
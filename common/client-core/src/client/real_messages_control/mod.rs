@@ -9,10 +9,12 @@ use self::{
     acknowledgement_control::AcknowledgementController, real_traffic_stream::OutQueueControl,
 };
 use crate::client::real_messages_control::message_handler::MessageHandler;
+use crate::client::reachability::ReachableAddressesReceiver;
 use crate::client::replies::reply_controller::{
     ReplyController, ReplyControllerReceiver, ReplyControllerSender,
 };
 use crate::client::replies::reply_storage::CombinedReplyStorage;
+use crate::client::traffic_shaping::TrafficShapingReceiver;
 use crate::{
     client::{
         inbound_messages::InputMessageReceiver, mix_traffic::BatchMixMessageSender,
@@ -96,6 +98,7 @@ impl<'a> From<&'a Config> for message_handler::Config {
             cfg.traffic.average_packet_delay,
             cfg.acks.average_ack_delay,
         )
+        .with_custom_delay_distribution(cfg.traffic.per_hop_delay_distribution)
         .with_custom_primary_packet_size(cfg.traffic.primary_packet_size)
         .with_custom_secondary_packet_size(cfg.traffic.secondary_packet_size)
     }
@@ -143,6 +146,8 @@ impl RealMessagesController<OsRng> {
         reply_controller_receiver: ReplyControllerReceiver,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
     ) -> Self {
         let rng = OsRng;
 
@@ -173,6 +178,8 @@ impl RealMessagesController<OsRng> {
             topology_access.clone(),
             reply_storage.key_storage(),
             reply_storage.tags_storage(),
+            traffic_shaping.clone(),
+            reachable_addresses.clone(),
         );
 
         let ack_control = AcknowledgementController::new(
@@ -181,6 +188,7 @@ impl RealMessagesController<OsRng> {
             ack_controller_connectors,
             message_handler.clone(),
             reply_controller_sender,
+            traffic_shaping.clone(),
         );
 
         let reply_control = ReplyController::new(
@@ -199,6 +207,8 @@ impl RealMessagesController<OsRng> {
             topology_access,
             lane_queue_lengths,
             client_connection_rx,
+            traffic_shaping,
+            reachable_addresses,
         );
 
         RealMessagesController {
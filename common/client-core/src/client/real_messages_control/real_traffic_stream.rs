@@ -3,8 +3,10 @@
 
 use self::sending_delay_controller::SendingDelayController;
 use crate::client::mix_traffic::BatchMixMessageSender;
+use crate::client::reachability::ReachableAddressesReceiver;
 use crate::client::real_messages_control::acknowledgement_control::SentPacketNotificationSender;
 use crate::client::topology_control::TopologyAccessor;
+use crate::client::traffic_shaping::{TrafficShapingParameters, TrafficShapingReceiver};
 use crate::client::transmission_buffer::TransmissionBuffer;
 use crate::config;
 use futures::task::{Context, Poll};
@@ -116,6 +118,13 @@ where
 
     /// Report queue lengths so that upstream can backoff sending data, and keep connections open.
     lane_queue_lengths: LaneQueueLengths,
+
+    /// Channel used for receiving updated traffic shaping parameters at runtime.
+    traffic_shaping: TrafficShapingReceiver,
+
+    /// Channel used for receiving the updated address of this client after it failed over
+    /// to a standby gateway.
+    reachable_addresses: ReachableAddressesReceiver,
 }
 
 #[derive(Debug)]
@@ -174,6 +183,8 @@ where
         topology_access: TopologyAccessor,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        traffic_shaping: TrafficShapingReceiver,
+        reachable_addresses: ReachableAddressesReceiver,
     ) -> Self {
         OutQueueControl {
             config,
@@ -187,9 +198,29 @@ where
             transmission_buffer: TransmissionBuffer::new(),
             client_connection_rx,
             lane_queue_lengths,
+            traffic_shaping,
+            reachable_addresses,
+        }
+    }
+
+    fn refresh_self_address(&mut self) {
+        if self.reachable_addresses.has_changed().unwrap_or_default() {
+            self.config.our_full_destination = self.reachable_addresses.borrow_and_update().active;
         }
     }
 
+    fn update_traffic_shaping(&mut self, updated: TrafficShapingParameters) {
+        debug!("updating the traffic shaping parameters of the real traffic stream");
+        self.config.average_ack_delay = updated.acknowledgements.average_ack_delay;
+        self.config.traffic = updated.traffic;
+        self.config.cover_traffic_primary_size_ratio =
+            updated.cover_traffic.cover_traffic_primary_size_ratio;
+
+        // make sure the new sending rate is applied immediately rather than after the
+        // currently scheduled delay (which might have been sampled from a much larger average)
+        self.next_delay = None;
+    }
+
     fn sent_notify(&self, frag_id: FragmentIdentifier) {
         // well technically the message was not sent just yet, but now it's up to internal
         // queues and client load rather than the required delay. So realistically we can treat
@@ -219,6 +250,7 @@ where
 
         let (next_message, fragment_id) = match next_message {
             StreamMessage::Cover => {
+                self.refresh_self_address();
                 let cover_traffic_packet_size = self.loop_cover_message_size();
                 trace!("the next loop cover message will be put in a {cover_traffic_packet_size} packet");
 
@@ -246,6 +278,8 @@ where
                         &self.config.our_full_destination,
                         self.config.average_ack_delay,
                         self.config.traffic.average_packet_delay,
+                        &self.config.traffic.per_hop_delay_distribution,
+                        topology_ref.mix_layers(),
                         cover_traffic_packet_size,
                         self.config.traffic.packet_type,
                     )
@@ -512,6 +546,8 @@ where
     pub(super) async fn run_with_shutdown(&mut self, mut shutdown: nym_task::TaskClient) {
         debug!("Started OutQueueControl with graceful shutdown support");
 
+        let mut traffic_shaping = self.traffic_shaping.clone();
+
         #[cfg(not(target_arch = "wasm32"))]
         {
             let mut status_timer = tokio::time::interval(Duration::from_secs(5));
@@ -525,6 +561,10 @@ where
                     _ = status_timer.tick() => {
                         self.log_status(&mut shutdown);
                     }
+                    Ok(_) = traffic_shaping.changed() => {
+                        let updated = *traffic_shaping.borrow_and_update();
+                        self.update_traffic_shaping(updated);
+                    }
                     next_message = self.next() => if let Some(next_message) = next_message {
                         self.on_message(next_message).await;
                     } else {
@@ -544,6 +584,10 @@ where
                     _ = shutdown.recv() => {
                         log::trace!("OutQueueControl: Received shutdown");
                     }
+                    Ok(_) = traffic_shaping.changed() => {
+                        let updated = *traffic_shaping.borrow_and_update();
+                        self.update_traffic_shaping(updated);
+                    }
                     next_message = self.next() => if let Some(next_message) = next_message {
                         self.on_message(next_message).await;
                     } else {
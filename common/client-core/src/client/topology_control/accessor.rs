@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use nym_sphinx::addressing::clients::Recipient;
-use nym_sphinx::params::DEFAULT_NUM_MIX_HOPS;
 use nym_topology::{NymTopology, NymTopologyError};
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -63,7 +62,7 @@ impl<'a> TopologyReadPermit<'a> {
         // 2. does it have any mixnode at all?
         // 3. does it have any gateways at all?
         // 4. does it have a mixnode on each layer?
-        topology.ensure_can_construct_path_through(DEFAULT_NUM_MIX_HOPS)?;
+        topology.ensure_can_construct_path_through(topology.mix_layers())?;
 
         // 5. does it contain OUR gateway (so that we could create an ack packet)?
         if !topology.gateway_exists(ack_recipient.gateway()) {
@@ -125,6 +124,16 @@ impl TopologyAccessor {
         self.inner.topology.read().await.clone()
     }
 
+    /// Number of mix layers of the current network topology, if it's already known.
+    pub async fn current_mix_layers(&self) -> Option<u8> {
+        self.inner
+            .topology
+            .read()
+            .await
+            .as_ref()
+            .map(|topology| topology.mix_layers())
+    }
+
     pub async fn manually_change_topology(&self, new_topology: NymTopology) {
         self.inner.controlled_manually.store(true, Ordering::SeqCst);
         self.inner.update(Some(new_topology)).await;
@@ -142,7 +151,7 @@ impl TopologyAccessor {
     pub async fn ensure_is_routable(&self) -> Result<(), NymTopologyError> {
         match self.inner.topology.read().await.deref() {
             None => Err(NymTopologyError::EmptyNetworkTopology),
-            Some(ref topology) => topology.ensure_can_construct_path_through(DEFAULT_NUM_MIX_HOPS),
+            Some(ref topology) => topology.ensure_can_construct_path_through(topology.mix_layers()),
         }
     }
 }
@@ -44,9 +44,11 @@ impl NymApiTopologyProvider {
 
     /// Verifies whether nodes a reasonably distributed among all mix layers.
     ///
-    /// In ideal world we would have 33% nodes on layer 1, 33% on layer 2 and 33% on layer 3.
+    /// In ideal world each of the `n` layers would hold exactly `1/n` of all nodes, i.e. with
+    /// 3 layers we would have 33% nodes on layer 1, 33% on layer 2 and 33% on layer 3.
     /// However, this is a rather unrealistic expectation, instead we check whether there exists
-    /// a layer with more than 66% of nodes or with fewer than 15% and if so, we trigger a failure.
+    /// a layer with more than twice its fair share of nodes or with fewer than 45% of it
+    /// (i.e. more than 66% or fewer than 15% with 3 layers) and if so, we trigger a failure.
     ///
     /// # Arguments
     ///
@@ -55,8 +57,9 @@ impl NymApiTopologyProvider {
         &self,
         active_topology: &NymTopology,
     ) -> Result<(), NymTopologyError> {
-        let lower_threshold = 0.15;
-        let upper_threshold = 0.66;
+        let fair_share = 1.0 / active_topology.mix_layers().max(1) as f32;
+        let lower_threshold = 0.45 * fair_share;
+        let upper_threshold = 2.0 * fair_share;
         active_topology.ensure_even_layer_distribution(lower_threshold, upper_threshold)
     }
 
@@ -77,9 +80,20 @@ impl NymApiTopologyProvider {
             Ok(gateways) => gateways,
         };
 
-        let topology = nym_topology_from_detailed(mixnodes, gateways)
+        let mut topology = nym_topology_from_detailed(mixnodes, gateways)
             .filter_system_version(&self.client_version);
 
+        // the number of layers is a network-wide parameter set in the mixnet contract,
+        // so every client has to construct its paths through exactly that many mixnodes.
+        // nym-apis that don't expose it yet predate the parameter, i.e. the network uses the default
+        match self.validator_client.get_cached_mix_layers().await {
+            Ok(mix_layers) => topology = topology.with_mix_layers(mix_layers),
+            Err(err) => warn!(
+                "failed to get the number of network mix layers - {err}. Going to assume the default of {}",
+                topology.mix_layers()
+            ),
+        }
+
         if let Err(err) = self.check_layer_distribution(&topology) {
             warn!("The current filtered active topology has extremely skewed layer distribution. It cannot be used: {err}");
             self.use_next_nym_api();
@@ -0,0 +1,67 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::{self, TrafficProfile};
+use log::info;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Subset of the client configuration that can be adjusted whilst the client is already running.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrafficShapingParameters {
+    pub traffic: config::Traffic,
+    pub cover_traffic: config::CoverTraffic,
+    pub acknowledgements: config::Acknowledgements,
+}
+
+impl TrafficShapingParameters {
+    pub fn new(debug_config: &config::DebugConfig) -> Self {
+        TrafficShapingParameters {
+            traffic: debug_config.traffic,
+            cover_traffic: debug_config.cover_traffic,
+            acknowledgements: debug_config.acknowledgements,
+        }
+    }
+
+    #[must_use]
+    pub fn with_profile(mut self, traffic_profile: TrafficProfile) -> Self {
+        traffic_profile.apply(
+            &mut self.traffic,
+            &mut self.cover_traffic,
+            &mut self.acknowledgements,
+        );
+        self
+    }
+}
+
+pub type TrafficShapingReceiver = watch::Receiver<TrafficShapingParameters>;
+
+/// Handle used for changing the traffic shaping parameters of all the tasks of a running client.
+#[derive(Debug, Clone)]
+pub struct TrafficShapingHandle {
+    sender: Arc<watch::Sender<TrafficShapingParameters>>,
+}
+
+impl TrafficShapingHandle {
+    pub fn new(initial: TrafficShapingParameters) -> (Self, TrafficShapingReceiver) {
+        let (sender, receiver) = watch::channel(initial);
+        (
+            TrafficShapingHandle {
+                sender: Arc::new(sender),
+            },
+            receiver,
+        )
+    }
+
+    pub fn current_parameters(&self) -> TrafficShapingParameters {
+        *self.sender.borrow()
+    }
+
+    /// Switches all the traffic streams of the client to the provided profile.
+    /// Any packets that have already been prepared are not affected.
+    pub fn switch_profile(&self, traffic_profile: TrafficProfile) {
+        info!("switching to the '{traffic_profile}' traffic profile");
+        let updated = self.current_parameters().with_profile(traffic_profile);
+        self.sender.send_replace(updated);
+    }
+}
@@ -107,6 +107,13 @@ impl ClientKeysPaths {
     pub fn ack_key(&self) -> &Path {
         &self.ack_key_file
     }
+
+    /// Path to file containing shared key derived with the specified standby gateway.
+    /// It's placed alongside the key shared with the primary gateway.
+    pub fn standby_gateway_shared_key(&self, gateway_id: &str) -> PathBuf {
+        self.gateway_shared_key_file
+            .with_file_name(format!("standby_gateway_shared_{gateway_id}.pem"))
+    }
 }
 
 fn file_exists(path: &Path) -> Option<PathBuf> {
@@ -4,6 +4,7 @@
 use nym_config::defaults::NymNetworkDetails;
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::params::{PacketSize, PacketType};
+use nym_sphinx::routing::DelayDistribution;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use url::Url;
@@ -16,6 +17,9 @@ pub mod disk_persistence;
 pub mod old_config_v1_1_13;
 pub mod old_config_v1_1_20;
 pub mod old_config_v1_1_20_2;
+pub mod traffic_profile;
+
+pub use traffic_profile::TrafficProfile;
 
 // 'DEBUG'
 const DEFAULT_ACK_WAIT_MULTIPLIER: f64 = 1.5;
@@ -31,6 +35,8 @@ const DEFAULT_TOPOLOGY_RESOLUTION_TIMEOUT: Duration = Duration::from_millis(5_00
 // bandwidth bridging protocol, we can come back to a smaller timeout value
 const DEFAULT_GATEWAY_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
+const DEFAULT_STANDBY_GATEWAYS: usize = 0;
+
 const DEFAULT_COVER_TRAFFIC_PRIMARY_SIZE_RATIO: f64 = 0.70;
 
 // reply-surbs related:
@@ -170,6 +176,20 @@ impl Config {
         self.debug.acknowledgements.average_ack_delay = Duration::ZERO;
     }
 
+    pub fn with_traffic_profile(mut self, traffic_profile: TrafficProfile) -> Self {
+        self.set_traffic_profile(traffic_profile);
+        self
+    }
+
+    pub fn set_traffic_profile(&mut self, traffic_profile: TrafficProfile) {
+        traffic_profile.apply_to_debug_config(&mut self.debug)
+    }
+
+    pub fn with_delay_distribution(mut self, delay_distribution: DelayDistribution) -> Self {
+        self.debug.traffic.per_hop_delay_distribution = delay_distribution;
+        self
+    }
+
     pub fn with_secondary_packet_size(mut self, secondary_packet_size: Option<PacketSize>) -> Self {
         self.set_secondary_packet_size(secondary_packet_size);
         self
@@ -323,6 +343,12 @@ pub struct Traffic {
     #[serde(with = "humantime_serde")]
     pub average_packet_delay: Duration,
 
+    /// Specifies the distribution from which the per-hop delays are sampled.
+    /// It is applied to all constructed packets, i.e. data packets, acknowledgements,
+    /// reply SURBs and loop cover messages, with their respective average delays as the parameter.
+    /// Do not change it unless you understand the consequences of that change.
+    pub per_hop_delay_distribution: DelayDistribution,
+
     /// The parameter of Poisson distribution determining how long, on average,
     /// it is going to take another 'real traffic stream' message to be sent.
     /// If no real packets are available and cover traffic is enabled,
@@ -363,6 +389,7 @@ impl Default for Traffic {
     fn default() -> Self {
         Traffic {
             average_packet_delay: DEFAULT_AVERAGE_PACKET_DELAY,
+            per_hop_delay_distribution: DelayDistribution::default(),
             message_sending_average_delay: DEFAULT_MESSAGE_STREAM_AVERAGE_DELAY,
             disable_main_poisson_packet_distribution: false,
             primary_packet_size: PacketSize::RegularPacket,
@@ -406,12 +433,18 @@ pub struct GatewayConnection {
     /// before giving up on it.
     #[serde(with = "humantime_serde")]
     pub gateway_response_timeout: Duration,
+
+    /// Number of additional gateways, apart from the primary one, the client is going to register
+    /// with and keep the connections open to. If the connection to the active gateway dies,
+    /// the client automatically fails over to one of them.
+    pub standby_gateways: usize,
 }
 
 impl Default for GatewayConnection {
     fn default() -> Self {
         GatewayConnection {
             gateway_response_timeout: DEFAULT_GATEWAY_RESPONSE_TIMEOUT,
+            standby_gateways: DEFAULT_STANDBY_GATEWAYS,
         }
     }
 }
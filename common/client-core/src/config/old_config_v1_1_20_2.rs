@@ -136,6 +136,7 @@ impl From<TrafficV1_1_20_2> for Traffic {
     fn from(value: TrafficV1_1_20_2) -> Self {
         Traffic {
             average_packet_delay: value.average_packet_delay,
+            per_hop_delay_distribution: Default::default(),
             message_sending_average_delay: value.message_sending_average_delay,
             disable_main_poisson_packet_distribution: value
                 .disable_main_poisson_packet_distribution,
@@ -199,6 +200,7 @@ impl From<GatewayConnectionV1_1_20_2> for GatewayConnection {
     fn from(value: GatewayConnectionV1_1_20_2) -> Self {
         GatewayConnection {
             gateway_response_timeout: value.gateway_response_timeout,
+            standby_gateways: Default::default(),
         }
     }
 }
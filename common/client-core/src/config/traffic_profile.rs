@@ -0,0 +1,182 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::{Acknowledgements, CoverTraffic, DebugConfig, Traffic};
+use nym_sphinx::params::PacketSize;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+
+// 'HIGH ANONYMITY'
+const HIGH_ANONYMITY_AVERAGE_PACKET_DELAY: Duration = Duration::from_millis(100);
+const HIGH_ANONYMITY_MESSAGE_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(40);
+const HIGH_ANONYMITY_LOOP_COVER_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(100);
+const HIGH_ANONYMITY_ACK_WAIT_ADDITION: Duration = Duration::from_millis(3_000);
+
+// 'LOW LATENCY BULK'
+const LOW_LATENCY_AVERAGE_PACKET_DELAY: Duration = Duration::from_millis(10);
+const LOW_LATENCY_MESSAGE_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(4);
+// only used as a keepalive
+const LOW_LATENCY_LOOP_COVER_STREAM_AVERAGE_DELAY: Duration = Duration::from_secs(5);
+const LOW_LATENCY_ACK_WAIT_ADDITION: Duration = Duration::from_millis(800);
+const LOW_LATENCY_SECONDARY_PACKET_SIZE: PacketSize = PacketSize::ExtendedPacket32;
+
+#[derive(Debug, Error)]
+#[error("{received} is not a valid traffic profile")]
+pub struct UnknownTrafficProfile {
+    received: String,
+}
+
+/// Named set of consistent values for all the traffic shaping knobs of the client,
+/// i.e. the relevant parts of the [`Traffic`], [`CoverTraffic`] and [`Acknowledgements`] sections.
+///
+/// Note that the profile does not touch the primary packet size, packet type
+/// nor the per-hop delay distribution, as those have to be agreed upon with the other party.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrafficProfile {
+    /// Longer per-hop delays and more frequent loop cover traffic at the cost of
+    /// higher latency and bandwidth usage.
+    HighAnonymity,
+
+    /// The default values of the client.
+    #[default]
+    Balanced,
+
+    /// Minimal per-hop delays, no rate limiting of real messages and cover traffic reduced
+    /// to a keepalive. Bigger messages are additionally sent using extended packets.
+    /// This significantly decreases the anonymity guarantees.
+    LowLatencyBulk,
+}
+
+impl TrafficProfile {
+    pub fn apply(
+        &self,
+        traffic: &mut Traffic,
+        cover_traffic: &mut CoverTraffic,
+        acknowledgements: &mut Acknowledgements,
+    ) {
+        let default_traffic = Traffic::default();
+        let default_cover = CoverTraffic::default();
+        let default_acks = Acknowledgements::default();
+
+        match self {
+            TrafficProfile::HighAnonymity => {
+                traffic.average_packet_delay = HIGH_ANONYMITY_AVERAGE_PACKET_DELAY;
+                traffic.message_sending_average_delay =
+                    HIGH_ANONYMITY_MESSAGE_STREAM_AVERAGE_DELAY;
+                traffic.disable_main_poisson_packet_distribution = false;
+                traffic.secondary_packet_size = None;
+
+                cover_traffic.loop_cover_traffic_average_delay =
+                    HIGH_ANONYMITY_LOOP_COVER_STREAM_AVERAGE_DELAY;
+                cover_traffic.disable_loop_cover_traffic_stream = false;
+
+                acknowledgements.average_ack_delay = HIGH_ANONYMITY_AVERAGE_PACKET_DELAY;
+                acknowledgements.ack_wait_multiplier = default_acks.ack_wait_multiplier;
+                acknowledgements.ack_wait_addition = HIGH_ANONYMITY_ACK_WAIT_ADDITION;
+            }
+            TrafficProfile::Balanced => {
+                traffic.average_packet_delay = default_traffic.average_packet_delay;
+                traffic.message_sending_average_delay =
+                    default_traffic.message_sending_average_delay;
+                traffic.disable_main_poisson_packet_distribution = false;
+                traffic.secondary_packet_size = None;
+
+                cover_traffic.loop_cover_traffic_average_delay =
+                    default_cover.loop_cover_traffic_average_delay;
+                cover_traffic.disable_loop_cover_traffic_stream = false;
+
+                acknowledgements.average_ack_delay = default_acks.average_ack_delay;
+                acknowledgements.ack_wait_multiplier = default_acks.ack_wait_multiplier;
+                acknowledgements.ack_wait_addition = default_acks.ack_wait_addition;
+            }
+            TrafficProfile::LowLatencyBulk => {
+                traffic.average_packet_delay = LOW_LATENCY_AVERAGE_PACKET_DELAY;
+                traffic.message_sending_average_delay = LOW_LATENCY_MESSAGE_STREAM_AVERAGE_DELAY;
+                traffic.disable_main_poisson_packet_distribution = true;
+                // make sure we don't end up with an invalid configuration
+                traffic.secondary_packet_size =
+                    if traffic.primary_packet_size != LOW_LATENCY_SECONDARY_PACKET_SIZE {
+                        Some(LOW_LATENCY_SECONDARY_PACKET_SIZE)
+                    } else {
+                        None
+                    };
+
+                cover_traffic.loop_cover_traffic_average_delay =
+                    LOW_LATENCY_LOOP_COVER_STREAM_AVERAGE_DELAY;
+                cover_traffic.disable_loop_cover_traffic_stream = false;
+
+                acknowledgements.average_ack_delay = LOW_LATENCY_AVERAGE_PACKET_DELAY;
+                acknowledgements.ack_wait_multiplier = default_acks.ack_wait_multiplier;
+                acknowledgements.ack_wait_addition = LOW_LATENCY_ACK_WAIT_ADDITION;
+            }
+        }
+    }
+
+    pub fn apply_to_debug_config(&self, debug: &mut DebugConfig) {
+        self.apply(
+            &mut debug.traffic,
+            &mut debug.cover_traffic,
+            &mut debug.acknowledgements,
+        )
+    }
+}
+
+impl FromStr for TrafficProfile {
+    type Err = UnknownTrafficProfile;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high_anonymity" | "high-anonymity" => Ok(TrafficProfile::HighAnonymity),
+            "balanced" => Ok(TrafficProfile::Balanced),
+            "low_latency_bulk" | "low-latency-bulk" => Ok(TrafficProfile::LowLatencyBulk),
+            s => Err(UnknownTrafficProfile {
+                received: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for TrafficProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrafficProfile::HighAnonymity => write!(f, "high_anonymity"),
+            TrafficProfile::Balanced => write!(f, "balanced"),
+            TrafficProfile::LowLatencyBulk => write!(f, "low_latency_bulk"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced_profile_restores_defaults() {
+        let mut debug = DebugConfig::default();
+        TrafficProfile::LowLatencyBulk.apply_to_debug_config(&mut debug);
+        assert_ne!(debug, DebugConfig::default());
+
+        TrafficProfile::Balanced.apply_to_debug_config(&mut debug);
+        assert_eq!(debug, DebugConfig::default());
+    }
+
+    #[test]
+    fn profiles_produce_valid_configs() {
+        for profile in [
+            TrafficProfile::HighAnonymity,
+            TrafficProfile::Balanced,
+            TrafficProfile::LowLatencyBulk,
+        ] {
+            let mut debug = DebugConfig::default();
+            debug.traffic.primary_packet_size = LOW_LATENCY_SECONDARY_PACKET_SIZE;
+            profile.apply_to_debug_config(&mut debug);
+            assert!(debug.validate());
+
+            assert_eq!(profile, profile.to_string().parse().unwrap());
+        }
+    }
+}
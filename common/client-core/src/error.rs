@@ -50,6 +50,11 @@ pub enum ClientCoreError {
         source: Box<dyn Error + Send + Sync>,
     },
 
+    #[error("experienced a failure with our credentials storage: {source}")]
+    CredentialStoreError {
+        source: Box<dyn Error + Send + Sync>,
+    },
+
     #[error("experienced a failure with our gateway details storage: {source}")]
     GatewayDetailsStoreError {
         source: Box<dyn Error + Send + Sync>,
@@ -116,6 +121,9 @@ pub enum ClientCoreError {
 
     #[error("the provided gateway details (for gateway {gateway_id}) do not correspond to the shared keys")]
     MismatchedGatewayDetails { gateway_id: String },
+
+    #[error("the client is already using gateway {gateway_id}")]
+    AlreadyUsingGateway { gateway_id: String },
 }
 
 /// Set of messages that the client can send to listeners via the task manager
@@ -4,22 +4,30 @@
 //! Collection of initialization steps used by client implementations
 
 use crate::client::base_client::storage::gateway_details::{
-    GatewayDetailsStore, PersistedGatewayDetails,
+    GatewayDetailsStore, PersistedGatewayDetails, PersistedStandbyGateway,
 };
 use crate::client::key_manager::persistence::KeyStore;
-use crate::client::key_manager::ManagedKeys;
+use crate::client::key_manager::{KeyManager, ManagedKeys};
 use crate::init::helpers::{choose_gateway_by_latency, current_gateways, uniformly_random_gateway};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    client::base_client::storage::gateway_details::OnDiskGatewayDetails,
+    client::key_manager::persistence::OnDiskKeys, config::disk_persistence::CommonClientPaths,
+};
 use crate::{
     config::{Config, GatewayEndpointConfig},
     error::ClientCoreError,
 };
+use log::{info, warn};
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::addressing::{clients::Recipient, nodes::NodeIdentity};
 use nym_topology::gateway;
 use nym_validator_client::client::IdentityKey;
 use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
 use serde::Serialize;
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 use url::Url;
 
 pub mod helpers;
@@ -418,6 +426,192 @@ where
     .await
 }
 
+/// Registers an already initialised client with a different gateway whilst retaining all of its keys,
+/// so that, apart from the gateway component of its address, its identity remains unchanged.
+/// The new gateway replaces the previous one in the provided details store.
+pub async fn switch_gateway_from<K, D>(
+    setup: &GatewaySetup,
+    key_store: &K,
+    details_store: &D,
+    gateways: &[gateway::Node],
+) -> Result<InitialisationDetails, ClientCoreError>
+where
+    K: KeyStore,
+    D: GatewayDetailsStore,
+    K::StorageError: Send + Sync + 'static,
+    D::StorageError: Send + Sync + 'static,
+{
+    let current_details = _load_gateway_details(details_store).await?;
+    let current_keys = KeyManager::load_keys(key_store).await.map_err(|source| {
+        ClientCoreError::KeyStoreError {
+            source: Box::new(source),
+        }
+    })?;
+
+    // make sure we don't end up 'switching' to the very same gateway
+    let current_gateway = &current_details.details.gateway_id;
+    if let GatewaySetup::Specified { gateway_identity } = setup {
+        if gateway_identity == current_gateway {
+            return Err(ClientCoreError::AlreadyUsingGateway {
+                gateway_id: current_gateway.clone(),
+            });
+        }
+    }
+    let candidates = gateways
+        .iter()
+        .filter(|gateway| &gateway.identity_key.to_base58_string() != current_gateway)
+        .cloned()
+        .collect::<Vec<_>>();
+    let gateway_details = setup.choose_gateway(&candidates).await?;
+    info!(
+        "switching from gateway {current_gateway} to {}",
+        gateway_details.gateway_id
+    );
+
+    let shared_keys =
+        helpers::register_with_gateway(&gateway_details, current_keys.identity_keypair()).await?;
+
+    let mut persisted_details = PersistedGatewayDetails::new(gateway_details, &shared_keys);
+    // retain all the standby gateways, apart from the one we might have just switched to
+    persisted_details.standby = current_details
+        .standby
+        .iter()
+        .filter(|standby| !persisted_details.is_registered_with(&standby.details.gateway_id))
+        .cloned()
+        .collect();
+
+    // persist the new details before touching the keys, so that if anything goes wrong,
+    // we could still restore the previous, consistent, state
+    _store_gateway_details(details_store, &persisted_details).await?;
+
+    // replace the old gateway key with the new one
+    let mut managed_keys = ManagedKeys::Initial(current_keys.remove_gateway_key());
+    if let Err(source) = managed_keys
+        .deal_with_gateway_key(shared_keys, key_store)
+        .await
+    {
+        warn!("failed to persist the new gateway key. restoring the previous gateway details");
+        _store_gateway_details(details_store, &current_details).await?;
+        return Err(ClientCoreError::KeyStoreError {
+            source: Box::new(source),
+        });
+    }
+
+    Ok(InitialisationDetails::new(
+        persisted_details.into(),
+        managed_keys,
+    ))
+}
+
+pub async fn switch_gateway<K, D>(
+    setup: &GatewaySetup,
+    key_store: &K,
+    details_store: &D,
+    validator_servers: &[Url],
+) -> Result<InitialisationDetails, ClientCoreError>
+where
+    K: KeyStore,
+    D: GatewayDetailsStore,
+    K::StorageError: Send + Sync + 'static,
+    D::StorageError: Send + Sync + 'static,
+{
+    let mut rng = OsRng;
+    let gateways = current_gateways(&mut rng, validator_servers).await?;
+
+    switch_gateway_from(setup, key_store, details_store, &gateways).await
+}
+
+/// Switches the gateway of a client that keeps its keys and the gateway details on disk,
+/// at the locations specified by the provided paths. See [switch_gateway] for more details.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn switch_on_disk_gateway(
+    paths: &CommonClientPaths,
+    gateway: Option<identity::PublicKey>,
+    latency_based_selection: bool,
+    validator_servers: &[Url],
+) -> Result<InitialisationDetails, ClientCoreError> {
+    let setup = GatewaySetup::new_fresh(
+        gateway.map(|id| id.to_base58_string()),
+        Some(latency_based_selection),
+    );
+
+    // the identity and encryption keys as well as all the reply data remain untouched
+    let key_store = OnDiskKeys::new(paths.keys.clone());
+    let details_store = OnDiskGatewayDetails::new(&paths.gateway_details);
+
+    switch_gateway(&setup, &key_store, &details_store, validator_servers).await
+}
+
+/// Ensures the client is registered with the specified number of standby gateways in addition to its
+/// primary one, by registering with new, randomly chosen, gateways if required.
+/// Any newly registered gateways are persisted in the provided details store
+/// whilst the keys shared with them are persisted in the provided key store.
+pub async fn setup_standby_gateways<K, D>(
+    key_store: &K,
+    details_store: &D,
+    our_identity: Arc<identity::KeyPair>,
+    standby_gateways: usize,
+    validator_servers: &[Url],
+) -> Result<Vec<PersistedStandbyGateway>, ClientCoreError>
+where
+    K: KeyStore,
+    D: GatewayDetailsStore,
+    D::StorageError: Send + Sync + 'static,
+{
+    let mut details = _load_gateway_details(details_store).await?;
+    let existing = details.standby_gateways().len();
+    if existing >= standby_gateways {
+        return Ok(details.standby_gateways()[..standby_gateways].to_vec());
+    }
+
+    let mut rng = OsRng;
+    let mut candidates = current_gateways(&mut rng, validator_servers).await?;
+    candidates
+        .retain(|gateway| !details.is_registered_with(&gateway.identity_key.to_base58_string()));
+    candidates.shuffle(&mut rng);
+
+    let mut registered = 0;
+    for candidate in candidates {
+        if existing + registered == standby_gateways {
+            break;
+        }
+
+        let gateway_details = GatewayEndpointConfig::from(candidate);
+        match helpers::register_with_gateway(&gateway_details, our_identity.clone()).await {
+            Ok(shared_keys) => {
+                info!(
+                    "registered with {} as a standby gateway",
+                    gateway_details.gateway_id
+                );
+                if let Err(err) = key_store
+                    .store_standby_gateway_key(&gateway_details.gateway_id, shared_keys.clone())
+                    .await
+                {
+                    warn!(
+                        "failed to persist the key shared with the standby gateway {}: {err}",
+                        gateway_details.gateway_id
+                    );
+                    continue;
+                }
+                details.add_standby_gateway(gateway_details, &shared_keys);
+                registered += 1;
+            }
+            Err(err) => warn!(
+                "failed to register with {} as a standby gateway: {err}",
+                gateway_details.gateway_id
+            ),
+        }
+    }
+
+    if registered == 0 {
+        warn!("could not register with any new standby gateway")
+    } else {
+        _store_gateway_details(details_store, &details).await?;
+    }
+
+    Ok(details.standby_gateways().to_vec())
+}
+
 pub fn output_to_json<T: Serialize>(init_results: &T, output_file: &str) {
     match std::fs::File::create(output_file) {
         Ok(file) => match serde_json::to_writer_pretty(file, init_results) {
@@ -128,6 +128,25 @@ impl<C, St> GatewayClient<C, St> {
         self.bandwidth_remaining
     }
 
+    /// Checks whether the client is authenticated and has an established connection with the gateway.
+    /// Note that it does not guarantee the underlying socket is still alive.
+    pub fn is_connected(&self) -> bool {
+        self.authenticated && self.connection.is_established()
+    }
+
+    /// Removes the bandwidth controller from this client, so that it could be passed
+    /// to a different gateway connection.
+    pub fn take_bandwidth_controller(&mut self) -> Option<BandwidthController<C, St>> {
+        self.bandwidth_controller.take()
+    }
+
+    pub fn set_bandwidth_controller(
+        &mut self,
+        bandwidth_controller: Option<BandwidthController<C, St>>,
+    ) {
+        self.bandwidth_controller = bandwidth_controller
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     async fn _close_connection(&mut self) -> Result<(), GatewayClientError> {
         match std::mem::replace(&mut self.connection, SocketState::NotConnected) {
@@ -558,11 +577,12 @@ impl<C, St> GatewayClient<C, St> {
             .prepare_coconut_credential()
             .await?;
 
+        let partial_spend = credential.spend_index().is_some();
         self.claim_coconut_bandwidth(credential).await?;
         self.bandwidth_controller
             .as_ref()
             .unwrap()
-            .consume_credential(credential_id)
+            .consume_credential(credential_id, partial_spend)
             .await?;
 
         Ok(())
@@ -744,6 +764,21 @@ impl<C, St> GatewayClient<C, St> {
         Ok(())
     }
 
+    /// Claims more bandwidth if the remaining amount fell below the threshold.
+    pub async fn ensure_enough_bandwidth(&mut self) -> Result<(), GatewayClientError>
+    where
+        C: DkgQueryClient + Send + Sync,
+        St: CredentialStorage,
+        <St as CredentialStorage>::StorageError: Send + Sync + 'static,
+    {
+        if self.bandwidth_remaining < REMAINING_BANDWIDTH_THRESHOLD {
+            info!("Claiming more bandwidth for your tokens. This will use {} token(s) from your wallet. \
+            Stop the process now if you don't want that to happen.", TOKENS_TO_BURN);
+            self.claim_bandwidth().await?;
+        }
+        Ok(())
+    }
+
     pub async fn authenticate_and_start(&mut self) -> Result<Arc<SharedKeys>, GatewayClientError>
     where
         C: DkgQueryClient + Send + Sync,
@@ -755,11 +790,22 @@ impl<C, St> GatewayClient<C, St> {
         }
         let shared_key = self.perform_initial_authentication().await?;
 
-        if self.bandwidth_remaining < REMAINING_BANDWIDTH_THRESHOLD {
-            info!("Claiming more bandwidth for your tokens. This will use {} token(s) from your wallet. \
-            Stop the process now if you don't want that to happen.", TOKENS_TO_BURN);
-            self.claim_bandwidth().await?;
+        self.ensure_enough_bandwidth().await?;
+
+        // this call is NON-blocking
+        self.start_listening_for_mixnet_messages()?;
+
+        Ok(shared_key)
+    }
+
+    /// Authenticates with the gateway and starts listening for mixnet messages without claiming
+    /// any bandwidth, i.e. the client is going to be reachable through this gateway,
+    /// but it won't be able to send any packets through it yet.
+    pub async fn authenticate_and_listen(&mut self) -> Result<Arc<SharedKeys>, GatewayClientError> {
+        if !self.connection.is_established() {
+            self.establish_connection().await?;
         }
+        let shared_key = self.perform_initial_authentication().await?;
 
         // this call is NON-blocking
         self.start_listening_for_mixnet_messages()?;
@@ -3,17 +3,23 @@
 
 use crate::{nym_api, ValidatorClientError};
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchBlindSignRequestBody, BatchBlindedSignatureResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, SpentCredentialReportBody, SpentCredentialReportResponse,
+    VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_api_requests::models::{
-    GatewayCoreStatusResponse, MixnodeCoreStatusResponse, MixnodeStatusResponse,
-    RewardEstimationResponse, StakeSaturationResponse,
+    GatewayCoreStatusResponse, GatewayLatencyResponse, GatewayStatusTimeSeriesResponse,
+    MixnodeCoreStatusResponse, MixnodeLatencyResponse, MixnodeStatusResponse,
+    MixnodeStatusTimeSeriesResponse, RewardEstimationResponse, SignedMonitorReportsResponse,
+    StakeSaturationResponse, TimeSeriesResolution,
 };
 use nym_coconut_dkg_common::types::NodeIndex;
 use nym_coconut_interface::VerificationKey;
 pub use nym_mixnet_contract_common::{
     mixnode::MixNodeDetails, GatewayBond, IdentityKey, IdentityKeyRef, MixId,
 };
+use nym_name_service_common::RegisteredName;
+use nym_service_provider_directory_common::{Service, ServiceType};
 use url::Url;
 
 #[cfg(feature = "nyxd-client")]
@@ -628,6 +634,13 @@ impl<C> Client<C> {
     ) -> Result<BlindedSignatureResponse, ValidatorClientError> {
         Ok(self.nym_api.blind_sign(request_body).await?)
     }
+
+    pub async fn batch_blind_sign(
+        &self,
+        request_body: &BatchBlindSignRequestBody,
+    ) -> Result<BatchBlindedSignatureResponse, ValidatorClientError> {
+        Ok(self.nym_api.batch_blind_sign(request_body).await?)
+    }
 }
 
 #[derive(Clone)]
@@ -713,6 +726,27 @@ impl NymApiClient {
         Ok(self.nym_api_client.get_gateways().await?)
     }
 
+    pub async fn get_cached_mix_layers(&self) -> Result<u8, ValidatorClientError> {
+        Ok(self.nym_api_client.get_mix_layers().await?)
+    }
+
+    pub async fn get_cached_registered_names(
+        &self,
+    ) -> Result<Vec<RegisteredName>, ValidatorClientError> {
+        Ok(self.nym_api_client.get_registered_names().await?.names)
+    }
+
+    pub async fn get_cached_services_by_type(
+        &self,
+        service_type: &ServiceType,
+    ) -> Result<Vec<Service>, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_service_providers_filtered(Some(service_type), None)
+            .await?
+            .services)
+    }
+
     pub async fn get_gateway_core_status_count(
         &self,
         identity: IdentityKeyRef<'_>,
@@ -735,6 +769,56 @@ impl NymApiClient {
             .await?)
     }
 
+    pub async fn get_signed_monitor_reports(
+        &self,
+        since: Option<i64>,
+    ) -> Result<SignedMonitorReportsResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_signed_monitor_reports(since)
+            .await?)
+    }
+
+    pub async fn get_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<MixnodeLatencyResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_mixnode_latency(mix_id).await?)
+    }
+
+    pub async fn get_gateway_latency(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<GatewayLatencyResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_gateway_latency(identity).await?)
+    }
+
+    pub async fn get_mixnode_status_time_series(
+        &self,
+        mix_id: MixId,
+        since: Option<i64>,
+        until: Option<i64>,
+        resolution: Option<TimeSeriesResolution>,
+    ) -> Result<MixnodeStatusTimeSeriesResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_mixnode_status_time_series(mix_id, since, until, resolution)
+            .await?)
+    }
+
+    pub async fn get_gateway_status_time_series(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+        until: Option<i64>,
+        resolution: Option<TimeSeriesResolution>,
+    ) -> Result<GatewayStatusTimeSeriesResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_gateway_status_time_series(identity, since, until, resolution)
+            .await?)
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
@@ -769,6 +853,13 @@ impl NymApiClient {
         Ok(self.nym_api_client.blind_sign(request_body).await?)
     }
 
+    pub async fn batch_blind_sign(
+        &self,
+        request_body: &BatchBlindSignRequestBody,
+    ) -> Result<BatchBlindedSignatureResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.batch_blind_sign(request_body).await?)
+    }
+
     pub async fn verify_bandwidth_credential(
         &self,
         request_body: &VerifyCredentialBody,
@@ -778,4 +869,14 @@ impl NymApiClient {
             .verify_bandwidth_credential(request_body)
             .await?)
     }
+
+    pub async fn report_spent_credential(
+        &self,
+        request_body: &SpentCredentialReportBody,
+    ) -> Result<SpentCredentialReportResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .report_spent_credential(request_body)
+            .await?)
+    }
 }
@@ -2,21 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::nym_api::error::NymAPIError;
-use crate::nym_api::routes::{CORE_STATUS_COUNT, SINCE_ARG};
+use crate::nym_api::routes::{CORE_STATUS_COUNT, RESOLUTION_ARG, SINCE_ARG, UNTIL_ARG};
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchBlindSignRequestBody, BatchBlindedSignatureResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, SpentCredentialReportBody, SpentCredentialReportResponse,
+    VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_api_requests::models::{
-    ComputeRewardEstParam, GatewayCoreStatusResponse, GatewayStatusReportResponse,
-    GatewayUptimeHistoryResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RequestError, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    ComputeRewardEstParam, GatewayCoreStatusResponse, GatewayLatencyResponse,
+    GatewayStatusReportResponse, GatewayStatusTimeSeriesResponse, GatewayUptimeHistoryResponse,
+    InclusionProbabilityResponse, MixNodeBondAnnotated, MixnodeCoreStatusResponse,
+    MixnodeLatencyResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
+    MixnodeStatusTimeSeriesResponse, MixnodeUptimeHistoryResponse, RequestError,
+    RewardEstimationResponse, SignedMonitorReportsResponse, StakeSaturationResponse,
+    TimeSeriesResolution, UptimeResponse,
 };
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId};
 use nym_name_service_common::response::NamesListResponse;
 use nym_service_provider_directory_common::response::ServicesListResponse;
+use nym_service_provider_directory_common::ServiceType;
 use reqwest::{Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -168,6 +173,14 @@ impl Client {
             .await
     }
 
+    pub async fn get_mix_layers(&self) -> Result<u8, NymAPIError> {
+        self.query_nym_api(
+            &[routes::API_VERSION, routes::EPOCH, routes::MIX_LAYERS],
+            NO_PARAMS,
+        )
+        .await
+    }
+
     pub async fn get_active_mixnodes(&self) -> Result<Vec<MixNodeDetails>, NymAPIError> {
         self.query_nym_api(
             &[routes::API_VERSION, routes::MIXNODES, routes::ACTIVE],
@@ -347,6 +360,25 @@ impl Client {
         }
     }
 
+    pub async fn get_signed_monitor_reports(
+        &self,
+        since: Option<i64>,
+    ) -> Result<SignedMonitorReportsResponse, NymAPIError> {
+        let params = since
+            .map(|since| vec![(SINCE_ARG, since.to_string())])
+            .unwrap_or_default();
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::NETWORK_MONITOR,
+                routes::REPORTS,
+            ],
+            &params,
+        )
+        .await
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
@@ -451,6 +483,80 @@ impl Client {
         .await
     }
 
+    pub async fn get_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<MixnodeLatencyResponse, NymAPIError> {
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                &mix_id.to_string(),
+                routes::LATENCY,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_gateway_latency(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<GatewayLatencyResponse, NymAPIError> {
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::LATENCY,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_mixnode_status_time_series(
+        &self,
+        mix_id: MixId,
+        since: Option<i64>,
+        until: Option<i64>,
+        resolution: Option<TimeSeriesResolution>,
+    ) -> Result<MixnodeStatusTimeSeriesResponse, NymAPIError> {
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                &mix_id.to_string(),
+                routes::TIME_SERIES,
+            ],
+            &time_series_params(since, until, resolution),
+        )
+        .await
+    }
+
+    pub async fn get_gateway_status_time_series(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+        until: Option<i64>,
+        resolution: Option<TimeSeriesResolution>,
+    ) -> Result<GatewayStatusTimeSeriesResponse, NymAPIError> {
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::TIME_SERIES,
+            ],
+            &time_series_params(since, until, resolution),
+        )
+        .await
+    }
+
     pub async fn blind_sign(
         &self,
         request_body: &BlindSignRequestBody,
@@ -468,6 +574,23 @@ impl Client {
         .await
     }
 
+    pub async fn batch_blind_sign(
+        &self,
+        request_body: &BatchBlindSignRequestBody,
+    ) -> Result<BatchBlindedSignatureResponse, NymAPIError> {
+        self.post_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::COCONUT_ROUTES,
+                routes::BANDWIDTH,
+                routes::COCONUT_BATCH_BLIND_SIGN,
+            ],
+            NO_PARAMS,
+            request_body,
+        )
+        .await
+    }
+
     pub async fn verify_bandwidth_credential(
         &self,
         request_body: &VerifyCredentialBody,
@@ -485,12 +608,46 @@ impl Client {
         .await
     }
 
+    pub async fn report_spent_credential(
+        &self,
+        request_body: &SpentCredentialReportBody,
+    ) -> Result<SpentCredentialReportResponse, NymAPIError> {
+        self.post_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::COCONUT_ROUTES,
+                routes::BANDWIDTH,
+                routes::COCONUT_REPORT_SPENT_CREDENTIAL,
+            ],
+            NO_PARAMS,
+            request_body,
+        )
+        .await
+    }
+
     pub async fn get_service_providers(&self) -> Result<ServicesListResponse, NymAPIError> {
         log::trace!("Getting service providers");
         self.query_nym_api(&[routes::API_VERSION, routes::SERVICE_PROVIDERS], NO_PARAMS)
             .await
     }
 
+    pub async fn get_service_providers_filtered(
+        &self,
+        service_type: Option<&ServiceType>,
+        owner: Option<&str>,
+    ) -> Result<ServicesListResponse, NymAPIError> {
+        log::trace!("Getting filtered service providers");
+        let mut params = Vec::new();
+        if let Some(service_type) = service_type {
+            params.push((routes::SERVICE_TYPE_ARG, service_type.to_string()));
+        }
+        if let Some(owner) = owner {
+            params.push((routes::OWNER_ARG, owner.to_string()));
+        }
+        self.query_nym_api(&[routes::API_VERSION, routes::SERVICE_PROVIDERS], &params)
+            .await
+    }
+
     //pub async fn get_registered_names(&self) -> Result<Vec<NameEntry>, NymAPIError> {
     pub async fn get_registered_names(&self) -> Result<NamesListResponse, NymAPIError> {
         log::trace!("Getting registered names");
@@ -499,6 +656,24 @@ impl Client {
     }
 }
 
+fn time_series_params(
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<TimeSeriesResolution>,
+) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    if let Some(since) = since {
+        params.push((SINCE_ARG, since.to_string()))
+    }
+    if let Some(until) = until {
+        params.push((UNTIL_ARG, until.to_string()))
+    }
+    if let Some(resolution) = resolution {
+        params.push((RESOLUTION_ARG, resolution.to_string()))
+    }
+    params
+}
+
 // utility function that should solve the double slash problem in validator API forever.
 fn create_api_url<K: AsRef<str>, V: AsRef<str>>(
     base: &Url,
@@ -6,6 +6,8 @@ use nym_network_defaults::NYM_API_VERSION;
 pub const API_VERSION: &str = NYM_API_VERSION;
 pub const MIXNODES: &str = "mixnodes";
 pub const GATEWAYS: &str = "gateways";
+pub const EPOCH: &str = "epoch";
+pub const MIX_LAYERS: &str = "mix_layers";
 
 pub const DETAILED: &str = "detailed";
 pub const DETAILED_UNFILTERED: &str = "detailed-unfiltered";
@@ -15,7 +17,9 @@ pub const COCONUT_ROUTES: &str = "coconut";
 pub const BANDWIDTH: &str = "bandwidth";
 
 pub const COCONUT_BLIND_SIGN: &str = "blind-sign";
+pub const COCONUT_BATCH_BLIND_SIGN: &str = "batch-blind-sign";
 pub const COCONUT_VERIFY_BANDWIDTH_CREDENTIAL: &str = "verify-bandwidth-credential";
+pub const COCONUT_REPORT_SPENT_CREDENTIAL: &str = "report-spent-credential";
 
 pub const STATUS_ROUTES: &str = "status";
 pub const MIXNODE: &str = "mixnode";
@@ -23,6 +27,11 @@ pub const GATEWAY: &str = "gateway";
 
 pub const CORE_STATUS_COUNT: &str = "core-status-count";
 pub const SINCE_ARG: &str = "since";
+pub const UNTIL_ARG: &str = "until";
+pub const RESOLUTION_ARG: &str = "resolution";
+
+pub const NETWORK_MONITOR: &str = "network-monitor";
+pub const REPORTS: &str = "reports";
 
 pub const STATUS: &str = "status";
 pub const REPORT: &str = "report";
@@ -30,8 +39,12 @@ pub const HISTORY: &str = "history";
 pub const REWARD_ESTIMATION: &str = "reward-estimation";
 pub const COMPUTE_REWARD_ESTIMATION: &str = "compute-reward-estimation";
 pub const AVG_UPTIME: &str = "avg_uptime";
+pub const LATENCY: &str = "latency";
+pub const TIME_SERIES: &str = "timeseries";
 pub const STAKE_SATURATION: &str = "stake-saturation";
 pub const INCLUSION_CHANCE: &str = "inclusion-probability";
 
 pub const SERVICE_PROVIDERS: &str = "services";
+pub const SERVICE_TYPE_ARG: &str = "service_type";
+pub const OWNER_ARG: &str = "owner";
 pub const REGISTERED_NAMES: &str = "names";
@@ -15,18 +15,22 @@ use nym_mixnet_contract_common::mixnode::{
 };
 use nym_mixnet_contract_common::reward_params::{Performance, RewardingParams};
 use nym_mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    EstimatedCurrentEpochRewardResponse, PagedMixnodeRewardHistoryResponse,
+    PendingGatewayRewardResponse, PendingRewardResponse,
 };
 use nym_mixnet_contract_common::{
     delegation, ContractBuildInformation, ContractState, ContractStateParams,
-    CurrentIntervalResponse, EpochEventId, EpochStatus, GatewayBondResponse,
-    GatewayOwnershipResponse, IdentityKey, IntervalEventId, LayerDistribution, MixId,
+    CurrentIntervalResponse, EpochEventId, EpochId, EpochStatus, GatewayBondResponse,
+    GatewayDelegationResponse, GatewayOwnershipResponse, GatewayRewardingDetailsResponse,
+    GatewayRewardingParams, IdentityKey, IntervalEventId, LayerDistribution, MixId,
     MixOwnershipResponse, MixnodeDetailsResponse, NumberOfPendingEventsResponse,
-    PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse, PagedFamiliesResponse,
-    PagedGatewayResponse, PagedMembersResponse, PagedMixNodeDelegationsResponse,
-    PagedMixnodeBondsResponse, PagedRewardedSetResponse, PendingEpochEventResponse,
-    PendingEpochEventsResponse, PendingIntervalEventResponse, PendingIntervalEventsResponse,
-    QueryMsg as MixnetQueryMsg,
+    PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
+    PagedDelegatorGatewayDelegationsResponse, PagedFamiliesResponse,
+    PagedGatewayDelegationsResponse, PagedGatewayResponse, PagedGatewaySlashingHistoryResponse,
+    PagedMembersResponse, PagedMixNodeDelegationsResponse, PagedMixnodeBondsResponse,
+    PagedMixnodeSlashingHistoryResponse, PagedRewardedSetResponse, PagedSlashingHistoryResponse,
+    PendingEpochEventResponse, PendingEpochEventsResponse, PendingIntervalEventResponse,
+    PendingIntervalEventsResponse, QueryMsg as MixnetQueryMsg, SlashingId, SlashingRecordResponse,
 };
 use serde::Deserialize;
 
@@ -245,6 +249,19 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    async fn get_gateway_rewarding_params(&self) -> Result<GatewayRewardingParams, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetGatewayRewardingParams {})
+            .await
+    }
+
+    async fn get_gateway_rewarding_details(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<GatewayRewardingDetailsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetGatewayRewardingDetails { identity })
+            .await
+    }
+
     // delegation-related:
 
     /// Gets list of all delegations towards particular mixnode on particular page.
@@ -302,6 +319,49 @@ pub trait MixnetQueryClient {
             .await
     }
 
+    /// Gets list of all delegations towards particular gateway on particular page.
+    async fn get_gateway_delegations_paged(
+        &self,
+        identity: IdentityKey,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<PagedGatewayDelegationsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetGatewayDelegations {
+            identity,
+            start_after,
+            limit,
+        })
+        .await
+    }
+
+    /// Gets list of all the gateways to which a particular address delegated.
+    async fn get_delegator_gateway_delegations_paged(
+        &self,
+        delegator: String,
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    ) -> Result<PagedDelegatorGatewayDelegationsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetDelegatorGatewayDelegations {
+            delegator,
+            start_after,
+            limit,
+        })
+        .await
+    }
+
+    /// Checks value of delegation of given client towards particular gateway.
+    async fn get_gateway_delegation_details(
+        &self,
+        identity: IdentityKey,
+        delegator: &AccountId,
+    ) -> Result<GatewayDelegationResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetGatewayDelegationDetails {
+            identity,
+            delegator: delegator.to_string(),
+        })
+        .await
+    }
+
     // rewards related
     async fn get_pending_operator_reward(
         &self,
@@ -335,6 +395,42 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    async fn get_pending_gateway_operator_reward(
+        &self,
+        operator: &AccountId,
+    ) -> Result<PendingGatewayRewardResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingGatewayOperatorReward {
+            address: operator.to_string(),
+        })
+        .await
+    }
+
+    async fn get_pending_gateway_delegator_reward(
+        &self,
+        delegator: &AccountId,
+        identity: IdentityKey,
+    ) -> Result<PendingGatewayRewardResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingGatewayDelegatorReward {
+            address: delegator.to_string(),
+            identity,
+        })
+        .await
+    }
+
+    async fn get_mixnode_reward_history_paged(
+        &self,
+        mix_id: MixId,
+        start_after: Option<EpochId>,
+        limit: Option<u32>,
+    ) -> Result<PagedMixnodeRewardHistoryResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetMixnodeRewardHistory {
+            mix_id,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
     // given the provided performance, estimate the reward at the end of the current epoch
     async fn get_estimated_current_epoch_operator_reward(
         &self,
@@ -408,6 +504,51 @@ pub trait MixnetQueryClient {
             .await
     }
 
+    async fn get_slashing_record(
+        &self,
+        slashing_id: SlashingId,
+    ) -> Result<SlashingRecordResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetSlashingRecord { slashing_id })
+            .await
+    }
+
+    async fn get_slashing_history_paged(
+        &self,
+        start_after: Option<SlashingId>,
+        limit: Option<u32>,
+    ) -> Result<PagedSlashingHistoryResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetSlashingHistory { limit, start_after })
+            .await
+    }
+
+    async fn get_mixnode_slashing_history_paged(
+        &self,
+        mix_id: MixId,
+        start_after: Option<SlashingId>,
+        limit: Option<u32>,
+    ) -> Result<PagedMixnodeSlashingHistoryResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetMixnodeSlashingHistory {
+            mix_id,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
+    async fn get_gateway_slashing_history_paged(
+        &self,
+        identity: IdentityKey,
+        start_after: Option<SlashingId>,
+        limit: Option<u32>,
+    ) -> Result<PagedGatewaySlashingHistoryResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetGatewaySlashingHistory {
+            identity,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
     async fn get_signing_nonce(&self, address: &AccountId) -> Result<Nonce, NyxdError> {
         self.query_mixnet_contract(MixnetQueryMsg::GetSigningNonce {
             address: address.to_string(),
@@ -10,11 +10,12 @@ use async_trait::async_trait;
 use cosmrs::AccountId;
 use nym_contracts_common::signing::MessageSignature;
 use nym_mixnet_contract_common::families::FamilyHead;
-use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
+use nym_mixnet_contract_common::gateway::{GatewayConfigUpdate, GatewayRewardingParams};
 use nym_mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use nym_mixnet_contract_common::reward_params::{IntervalRewardingParamsUpdate, Performance};
 use nym_mixnet_contract_common::{
-    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, LayerAssignment, MixId, MixNode,
+    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, IdentityKey, LayerAssignment,
+    MixId, MixNode, Percent, SlashingEvidence,
 };
 
 #[async_trait]
@@ -533,6 +534,19 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn update_gateway_rewarding_params(
+        &self,
+        updated_params: GatewayRewardingParams,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::UpdateGatewayRewardingParams { updated_params },
+            vec![],
+        )
+        .await
+    }
+
     // delegation-related:
 
     async fn delegate_to_mixnode(
@@ -597,6 +611,108 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn redelegate_from_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RedelegateFromMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn redelegate_from_mixnode_on_behalf(
+        &self,
+        delegate: AccountId,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RedelegateFromMixnodeOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+                delegate: delegate.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn delegate_to_gateway(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::DelegateToGateway { identity },
+            vec![amount],
+        )
+        .await
+    }
+
+    async fn undelegate_from_gateway(
+        &self,
+        identity: IdentityKey,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::UndelegateFromGateway { identity },
+            vec![],
+        )
+        .await
+    }
+
+    async fn delegate_to_gateway_on_behalf(
+        &self,
+        delegate: AccountId,
+        identity: IdentityKey,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::DelegateToGatewayOnBehalf {
+                identity,
+                delegate: delegate.to_string(),
+            },
+            vec![amount],
+        )
+        .await
+    }
+
+    async fn undelegate_from_gateway_on_behalf(
+        &self,
+        delegate: AccountId,
+        identity: IdentityKey,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::UndelegateFromGatewayOnBehalf {
+                identity,
+                delegate: delegate.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     // reward-related
 
     async fn reward_mixnode(
@@ -665,6 +781,123 @@ pub trait MixnetSigningClient {
         )
         .await
     }
+
+    async fn set_delegation_auto_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetDelegationAutoCompounding { mix_id, enabled },
+            vec![],
+        )
+        .await
+    }
+
+    async fn set_delegation_auto_compounding_on_behalf(
+        &self,
+        owner: AccountId,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetDelegationAutoCompoundingOnBehalf {
+                mix_id,
+                enabled,
+                owner: owner.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn reward_gateway(
+        &self,
+        identity: IdentityKey,
+        performance: Performance,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RewardGateway {
+                identity,
+                performance,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn withdraw_gateway_operator_reward(
+        &self,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::WithdrawGatewayOperatorReward {},
+            vec![],
+        )
+        .await
+    }
+
+    async fn withdraw_gateway_delegator_reward(
+        &self,
+        identity: IdentityKey,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::WithdrawGatewayDelegatorReward { identity },
+            vec![],
+        )
+        .await
+    }
+
+    async fn slash_mixnode(
+        &self,
+        mix_id: MixId,
+        slash_percent: Percent,
+        evidence: SlashingEvidence,
+        redistribute: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SlashMixnode {
+                mix_id,
+                slash_percent,
+                evidence,
+                redistribute,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn slash_gateway(
+        &self,
+        identity: IdentityKey,
+        slash_percent: Percent,
+        evidence: SlashingEvidence,
+        redistribute: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SlashGateway {
+                identity,
+                slash_percent,
+                evidence,
+                redistribute,
+            },
+            vec![],
+        )
+        .await
+    }
 }
 
 #[async_trait]
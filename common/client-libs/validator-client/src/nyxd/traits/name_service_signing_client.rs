@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
-use nym_contracts_common::signing::MessageSignature;
-use nym_name_service_common::{msg::ExecuteMsg as NameExecuteMsg, NameDetails, NameId, NymName};
+use nym_contracts_common::{signing::MessageSignature, IdentityKey};
+use nym_name_service_common::{
+    msg::ExecuteMsg as NameExecuteMsg, Address, NameDetails, NameId, NymName,
+};
 
 use crate::nyxd::{
     coin::Coin, cosmwasm_client::types::ExecuteResult, error::NyxdError, Fee, NyxdClient,
@@ -55,6 +57,50 @@ pub trait NameServiceSigningClient {
             .await
     }
 
+    async fn renew_name(
+        &self,
+        name_id: NameId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(fee, NameExecuteMsg::Renew { name_id }, vec![])
+            .await
+    }
+
+    async fn update_name_address(
+        &self,
+        name_id: NameId,
+        address: Address,
+        identity_key: IdentityKey,
+        owner_signature: MessageSignature,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::UpdateAddress {
+                name_id,
+                address,
+                identity_key,
+                owner_signature,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn transfer_name(
+        &self,
+        name_id: NameId,
+        new_owner: String,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::Transfer { name_id, new_owner },
+            vec![],
+        )
+        .await
+    }
+
     async fn update_deposit_required(
         &self,
         deposit_required: Coin,
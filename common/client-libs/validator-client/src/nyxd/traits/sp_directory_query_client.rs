@@ -6,7 +6,7 @@ use nym_service_provider_directory_common::{
     response::{
         ConfigResponse, PagedServicesListResponse, ServiceInfoResponse, ServicesListResponse,
     },
-    NymAddress, Service, ServiceId,
+    NymAddress, Service, ServiceId, ServiceType,
 };
 use serde::Deserialize;
 
@@ -58,6 +58,20 @@ pub trait SpDirectoryQueryClient {
             .await
     }
 
+    async fn get_services_by_type_paged(
+        &self,
+        service_type: ServiceType,
+        start_after: Option<ServiceId>,
+        limit: Option<u32>,
+    ) -> Result<PagedServicesListResponse, NyxdError> {
+        self.query_service_provider_contract(SpQueryMsg::ByType {
+            service_type,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
     async fn get_sp_contract_version(&self) -> Result<ContractBuildInformation, NyxdError> {
         self.query_service_provider_contract(SpQueryMsg::GetContractVersion {})
             .await
@@ -80,6 +94,28 @@ pub trait SpDirectoryQueryClient {
         Ok(services)
     }
 
+    async fn get_all_services_by_type(
+        &self,
+        service_type: ServiceType,
+    ) -> Result<Vec<Service>, NyxdError> {
+        let mut services = Vec::new();
+        let mut start_after = None;
+        loop {
+            let mut paged_response = self
+                .get_services_by_type_paged(service_type.clone(), start_after.take(), None)
+                .await?;
+            services.append(&mut paged_response.services);
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(services)
+    }
+
     async fn get_service_signing_nonce(&self, address: &AccountId) -> Result<Nonce, NyxdError> {
         self.query_service_provider_contract(SpQueryMsg::SigningNonce {
             address: address.to_string(),
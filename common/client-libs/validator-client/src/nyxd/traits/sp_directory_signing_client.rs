@@ -4,7 +4,8 @@
 use async_trait::async_trait;
 use nym_contracts_common::signing::MessageSignature;
 use nym_service_provider_directory_common::{
-    msg::ExecuteMsg as SpExecuteMsg, NymAddress, ServiceDetails, ServiceId,
+    msg::ExecuteMsg as SpExecuteMsg, NymAddress, ServiceDetails, ServiceId, ServiceMetadata,
+    ServiceType,
 };
 
 use crate::nyxd::{
@@ -65,6 +66,25 @@ pub trait SpDirectorySigningClient {
         .await
     }
 
+    async fn update_service_provider(
+        &self,
+        service_id: ServiceId,
+        service_type: ServiceType,
+        metadata: ServiceMetadata,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_service_provider_directory_contract(
+            fee,
+            SpExecuteMsg::UpdateService {
+                service_id,
+                service_type,
+                metadata,
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn update_deposit_required(
         &self,
         deposit_required: Coin,
@@ -11,7 +11,7 @@ use nym_contracts_common::signing::MessageSignature;
 use nym_mixnet_contract_common::families::FamilyHead;
 use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
 use nym_mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
-use nym_mixnet_contract_common::{Gateway, MixId, MixNode};
+use nym_mixnet_contract_common::{Gateway, IdentityKey, MixId, MixNode};
 use nym_vesting_contract_common::messages::{
     ExecuteMsg as VestingExecuteMsg, VestingSpecification,
 };
@@ -144,6 +144,47 @@ pub trait VestingSigningClient {
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError>;
 
+    async fn vesting_redelegate_from_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vesting_track_redelegation(
+        &self,
+        address: &str,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vesting_delegate_to_gateway(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vesting_undelegate_from_gateway(
+        &self,
+        identity: IdentityKey,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vesting_track_gateway_undelegation(
+        &self,
+        address: &str,
+        identity: IdentityKey,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -175,6 +216,20 @@ pub trait VestingSigningClient {
         .await
     }
 
+    async fn vesting_set_delegation_auto_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::SetDelegationAutoCompounding { mix_id, enabled },
+            Vec::new(),
+        )
+        .await
+    }
+
     async fn update_locked_pledge_cap(
         &self,
         address: AccountId,
@@ -523,6 +578,103 @@ impl<C: SigningCosmWasmClient + Sync + Send> VestingSigningClient for NyxdClient
         .await
     }
 
+    async fn vesting_redelegate_from_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::RedelegateFromMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+                on_behalf_of,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn vesting_track_redelegation(
+        &self,
+        address: &str,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::TrackRedelegation {
+                owner: address.to_string(),
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn vesting_delegate_to_gateway(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::DelegateToGateway {
+                identity,
+                amount: amount.into(),
+                on_behalf_of,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn vesting_undelegate_from_gateway(
+        &self,
+        identity: IdentityKey,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::UndelegateFromGateway {
+                identity,
+                on_behalf_of,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn vesting_track_gateway_undelegation(
+        &self,
+        address: &str,
+        identity: IdentityKey,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::TrackGatewayUndelegation {
+                owner: address.to_string(),
+                identity,
+                amount: amount.into(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -7,6 +7,7 @@ use getset::{CopyGetters, Getters};
 use serde::{Deserialize, Serialize};
 
 use error::CoconutInterfaceError;
+use nym_network_defaults::{DIVISIBLE_VOUCHER_INFO, DIVISIBLE_VOUCHER_SPENDS};
 
 pub use nym_coconut::*;
 
@@ -20,6 +21,10 @@ pub struct Credential {
     voucher_info: String,
     #[getset(get = "pub")]
     epoch_id: u64,
+    /// Index of the spent part of a divisible credential. `None` if the credential is spent whole.
+    #[getset(get_copy = "pub")]
+    #[serde(default)]
+    spend_index: Option<u64>,
 }
 impl Credential {
     pub fn new(
@@ -35,6 +40,25 @@ impl Credential {
             voucher_value,
             voucher_info,
             epoch_id,
+            spend_index: None,
+        }
+    }
+
+    pub fn new_partial(
+        n_params: u32,
+        theta: Theta,
+        voucher_value: u64,
+        voucher_info: String,
+        epoch_id: u64,
+        spend_index: u64,
+    ) -> Credential {
+        Credential {
+            n_params,
+            theta,
+            voucher_value,
+            voucher_info,
+            epoch_id,
+            spend_index: Some(spend_index),
         }
     }
 
@@ -55,7 +79,36 @@ impl Credential {
         self.voucher_value
     }
 
+    /// Value redeemed by this particular spend, i.e. either the full value of the credential
+    /// or the value of a single part of a divisible one.
+    /// If the value can't be evenly divided, the last part also redeems the remainder,
+    /// so that all parts combined always add up to the full value of the credential.
+    pub fn spend_value(&self) -> u64 {
+        match self.spend_index {
+            Some(spend_index) => {
+                let part_value = self.voucher_value / DIVISIBLE_VOUCHER_SPENDS;
+                if spend_index == DIVISIBLE_VOUCHER_SPENDS - 1 {
+                    part_value + self.voucher_value % DIVISIBLE_VOUCHER_SPENDS
+                } else {
+                    part_value
+                }
+            }
+            None => self.voucher_value,
+        }
+    }
+
     pub fn verify(&self, verification_key: &VerificationKey) -> bool {
+        // divisible credentials must only ever be spent in parts, otherwise they could be spent
+        // both whole and partially as the resultant blinded serial numbers are unlinkable
+        let divisible = self.voucher_info == DIVISIBLE_VOUCHER_INFO;
+        match self.spend_index {
+            None if divisible => return false,
+            Some(spend_index) if !divisible || spend_index >= DIVISIBLE_VOUCHER_SPENDS => {
+                return false
+            }
+            _ => (),
+        }
+
         let params = Parameters::new(self.n_params).unwrap();
         let public_attributes = vec![
             self.voucher_value.to_string().as_bytes(),
@@ -64,7 +117,21 @@ impl Credential {
         .iter()
         .map(hash_to_scalar)
         .collect::<Vec<Attribute>>();
-        nym_coconut::verify_credential(&params, verification_key, &self.theta, &public_attributes)
+        match self.spend_index {
+            Some(spend_index) => nym_coconut::verify_partial_credential(
+                &params,
+                verification_key,
+                &self.theta,
+                &public_attributes,
+                spend_index,
+            ),
+            None => nym_coconut::verify_credential(
+                &params,
+                verification_key,
+                &self.theta,
+                &public_attributes,
+            ),
+        }
     }
 
     pub fn as_bytes(&self) -> Vec<u8> {
@@ -76,13 +143,19 @@ impl Credential {
         let voucher_info_bytes = self.voucher_info.as_bytes();
         let voucher_info_len = voucher_info_bytes.len();
 
-        let mut bytes = Vec::with_capacity(28 + theta_bytes_len + voucher_info_len);
+        let mut bytes = Vec::with_capacity(36 + theta_bytes_len + voucher_info_len);
         bytes.extend_from_slice(&n_params_bytes);
         bytes.extend_from_slice(&(theta_bytes_len as u64).to_be_bytes());
         bytes.extend_from_slice(&theta_bytes);
         bytes.extend_from_slice(&voucher_value_bytes);
         bytes.extend_from_slice(&epoch_id_bytes);
         bytes.extend_from_slice(voucher_info_bytes);
+        // the encoding of whole credentials is left unchanged so that they would remain compatible
+        // with older gateways and clients. partial spends (which are always made with the divisible
+        // voucher info) have their spend index appended at the very end instead
+        if let Some(spend_index) = self.spend_index {
+            bytes.extend_from_slice(&spend_index.to_be_bytes());
+        }
 
         bytes
     }
@@ -111,7 +184,18 @@ impl Credential {
         let voucher_value = u64::from_be_bytes(eight_byte);
         eight_byte.copy_from_slice(&bytes[20 + theta_len as usize..28 + theta_len as usize]);
         let epoch_id = u64::from_be_bytes(eight_byte);
-        let voucher_info = String::from_utf8(bytes[28 + theta_len as usize..].to_vec())
+
+        let remaining = &bytes[28 + theta_len as usize..];
+        let divisible_info = DIVISIBLE_VOUCHER_INFO.as_bytes();
+        let (voucher_info_bytes, spend_index) = if remaining.len() == divisible_info.len() + 8
+            && remaining.starts_with(divisible_info)
+        {
+            eight_byte.copy_from_slice(&remaining[divisible_info.len()..]);
+            (divisible_info, Some(u64::from_be_bytes(eight_byte)))
+        } else {
+            (remaining, None)
+        };
+        let voucher_info = String::from_utf8(voucher_info_bytes.to_vec())
             .map_err(|e| CoconutError::Deserialization(e.to_string()))?;
 
         Ok(Credential {
@@ -120,6 +204,7 @@ impl Credential {
             voucher_value,
             voucher_info,
             epoch_id,
+            spend_index,
         })
     }
 }
@@ -181,5 +266,59 @@ mod tests {
         let deserialized_credential = Credential::from_bytes(&serialized_credential).unwrap();
 
         assert_eq!(credential, deserialized_credential);
+
+        // whole credentials must remain decodable by (and from) the older gateways and clients
+        let theta_bytes = credential.theta.to_bytes();
+        let mut baseline_bytes = Vec::new();
+        baseline_bytes.extend_from_slice(&4u32.to_be_bytes());
+        baseline_bytes.extend_from_slice(&(theta_bytes.len() as u64).to_be_bytes());
+        baseline_bytes.extend_from_slice(&theta_bytes);
+        baseline_bytes.extend_from_slice(&voucher_value.to_be_bytes());
+        baseline_bytes.extend_from_slice(&42u64.to_be_bytes());
+        baseline_bytes.extend_from_slice(b"BandwidthVoucher");
+        assert_eq!(serialized_credential, baseline_bytes);
+        assert_eq!(Credential::from_bytes(&baseline_bytes).unwrap(), credential);
+
+        let theta = prove_partial_bandwidth_credential(
+            &params,
+            &verification_key,
+            &signature,
+            serial_number,
+            binding_number,
+            3,
+        )
+        .unwrap();
+        let credential = Credential::new_partial(
+            4,
+            theta,
+            voucher_value,
+            DIVISIBLE_VOUCHER_INFO.to_string(),
+            42,
+            3,
+        );
+        assert_eq!(
+            credential.spend_value(),
+            voucher_value / DIVISIBLE_VOUCHER_SPENDS
+        );
+
+        // the last part also redeems whatever couldn't have been evenly divided
+        let uneven_value = voucher_value + 7;
+        let total_spent: u64 = (0..DIVISIBLE_VOUCHER_SPENDS)
+            .map(|spend_index| {
+                Credential {
+                    voucher_value: uneven_value,
+                    spend_index: Some(spend_index),
+                    ..credential.clone()
+                }
+                .spend_value()
+            })
+            .sum();
+        assert_eq!(total_spent, uneven_value);
+
+        let serialized_credential = credential.as_bytes();
+        let deserialized_credential = Credential::from_bytes(&serialized_credential).unwrap();
+
+        assert_eq!(credential, deserialized_credential);
+        assert_eq!(deserialized_credential.spend_index(), Some(3));
     }
 }
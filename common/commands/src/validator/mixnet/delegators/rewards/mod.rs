@@ -4,7 +4,9 @@
 use clap::{Args, Subcommand};
 
 pub mod claim_delegator_reward;
+pub mod set_auto_compounding;
 pub mod vesting_claim_delegator_reward;
+pub mod vesting_set_auto_compounding;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -19,4 +21,8 @@ pub enum MixnetDelegatorsRewardCommands {
     Claim(claim_delegator_reward::Args),
     /// Claim rewards accumulated during the delegation of locked tokens
     VestingClaim(vesting_claim_delegator_reward::Args),
+    /// Enable or disable automatic compounding of the rewards of delegation of unlocked tokens
+    AutoCompound(set_auto_compounding::Args),
+    /// Enable or disable automatic compounding of the rewards of delegation of locked tokens
+    VestingAutoCompound(vesting_set_auto_compounding::Args),
 }
@@ -0,0 +1,47 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::nyxd::traits::{MixnetQueryClient, MixnetSigningClient};
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub mix_id: Option<MixId>,
+
+    #[clap(long)]
+    pub identity_key: Option<String>,
+
+    /// Stop compounding the rewards and let them accumulate until they're claimed instead
+    #[clap(long)]
+    pub disable: bool,
+}
+
+pub async fn set_auto_compounding(args: Args, client: SigningClient) {
+    info!("Set delegation reward auto-compounding");
+
+    let mix_id = match args.mix_id {
+        Some(mix_id) => mix_id,
+        None => {
+            let identity_key = args
+                .identity_key
+                .expect("either mix_id or mix_identity has to be specified");
+            let node_details = client
+                .get_mixnode_details_by_identity(identity_key)
+                .await
+                .expect("contract query failed")
+                .expect("mixnode with the specified identity doesnt exist");
+            node_details.mix_id()
+        }
+    };
+
+    let res = client
+        .set_delegation_auto_compounding(mix_id, !args.disable, None)
+        .await
+        .expect("failed to set delegation reward auto-compounding");
+
+    info!("Setting delegation reward auto-compounding: {:?}", res)
+}
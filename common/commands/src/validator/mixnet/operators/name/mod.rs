@@ -2,6 +2,9 @@ use clap::{Args, Subcommand};
 
 pub mod delete;
 pub mod register;
+pub mod renew;
+pub mod transfer;
+pub mod update_address;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -16,4 +19,10 @@ pub enum MixnetOperatorsNameCommands {
     Register(register::Args),
     /// Delete name alias for a nym address
     Delete(delete::Args),
+    /// Renew the registration of a name alias before it expires
+    Renew(renew::Args),
+    /// Point a name alias to a different nym address
+    UpdateAddress(update_address::Args),
+    /// Transfer the ownership of a name alias to a different account
+    Transfer(transfer::Args),
 }
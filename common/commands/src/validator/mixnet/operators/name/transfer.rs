@@ -0,0 +1,32 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::NameId;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: NameId,
+
+    /// Account that is going to become the new owner of the name alias
+    #[clap(long)]
+    pub new_owner: String,
+}
+
+pub async fn transfer(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!(
+        "Transferring registered name alias with id {} to {}",
+        args.id, args.new_owner
+    );
+
+    let res = client
+        .transfer_name(args.id, args.new_owner, None)
+        .await
+        .tap_err(|err| error!("Failed to transfer name: {err:#?}"))?;
+
+    info!("Transferred: {res:?}");
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use clap::Parser;
+use log::{error, info};
+use nym_contracts_common::signing::MessageSignature;
+use nym_name_service_common::{Address, NameId};
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: NameId,
+
+    /// New nym address that the alias is going to point to
+    #[clap(long)]
+    pub nym_address: String,
+
+    /// Signature of the update payload, made with the identity key of the new nym address
+    #[clap(long)]
+    pub signature: MessageSignature,
+
+    #[clap(long)]
+    pub identity_key: String,
+}
+
+pub async fn update_address(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!(
+        "Updating registered name alias with id {} to point to '{}'",
+        args.id, args.nym_address
+    );
+
+    let res = client
+        .update_name_address(
+            args.id,
+            Address::new(&args.nym_address),
+            args.identity_key,
+            args.signature,
+            None,
+        )
+        .await
+        .tap_err(|err| error!("Failed to update name address: {err:#?}"))?;
+
+    info!("Updated: {res:?}");
+    Ok(())
+}
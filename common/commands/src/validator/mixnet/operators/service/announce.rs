@@ -1,10 +1,11 @@
 use clap::Parser;
 use log::info;
 use nym_contracts_common::signing::MessageSignature;
-use nym_service_provider_directory_common::{Coin, NymAddress, ServiceDetails, ServiceType};
+use nym_service_provider_directory_common::{Coin, NymAddress, ServiceDetails};
 use nym_validator_client::nyxd::traits::SpDirectorySigningClient;
 
 use crate::context::SigningClient;
+use crate::validator::mixnet::operators::service::ServiceDescriptionArgs;
 
 #[derive(Debug, Parser)]
 pub struct Args {
@@ -20,17 +21,20 @@ pub struct Args {
 
     #[clap(long)]
     pub identity_key: String,
+
+    #[clap(flatten)]
+    pub description: ServiceDescriptionArgs,
 }
 
 pub async fn announce(args: Args, client: SigningClient) {
     info!("Annoucing service provider");
 
     let nym_address = NymAddress::Address(args.nym_address);
-    let service_type = ServiceType::NetworkRequester;
     let service = ServiceDetails {
         nym_address,
-        service_type,
+        service_type: args.description.service_type.clone(),
         identity_key: args.identity_key,
+        metadata: args.description.metadata(),
     };
 
     let denom = client.current_chain_details().mix_denom.base.as_str();
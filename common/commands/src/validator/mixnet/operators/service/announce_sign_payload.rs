@@ -4,6 +4,7 @@
 use crate::{
     context::SigningClient,
     utils::{account_id_to_cw_addr, DataWrapper},
+    validator::mixnet::operators::service::ServiceDescriptionArgs,
 };
 
 use clap::Parser;
@@ -12,7 +13,6 @@ use cosmwasm_std::Coin;
 use nym_bin_common::output_format::OutputFormat;
 use nym_service_provider_directory_common::{
     signing_types::construct_service_provider_announce_sign_payload, NymAddress,
-    ServiceType::NetworkRequester,
 };
 use nym_sphinx::addressing::clients::Recipient;
 use nym_validator_client::nyxd::traits::SpDirectoryQueryClient;
@@ -28,6 +28,9 @@ pub struct Args {
     #[clap(long)]
     pub identity_key: String,
 
+    #[clap(flatten)]
+    pub description: ServiceDescriptionArgs,
+
     #[clap(short, long, default_value_t = OutputFormat::default())]
     output: OutputFormat,
 }
@@ -35,8 +38,9 @@ pub struct Args {
 pub async fn create_payload(args: Args, client: SigningClient) {
     let service = nym_service_provider_directory_common::ServiceDetails {
         nym_address: NymAddress::new(&args.nym_address.to_string()),
-        service_type: NetworkRequester,
+        service_type: args.description.service_type.clone(),
         identity_key: args.identity_key,
+        metadata: args.description.metadata(),
     };
 
     let denom = client.current_chain_details().mix_denom.base.as_str();
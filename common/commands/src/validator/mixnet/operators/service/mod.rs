@@ -1,8 +1,10 @@
 use clap::{Args, Subcommand};
+use nym_service_provider_directory_common::{ServiceMetadata, ServiceType};
 
 pub mod announce;
 pub mod announce_sign_payload;
 pub mod delete;
+pub mod update;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -18,6 +20,42 @@ pub enum MixnetOperatorsServiceCommands {
     Announce(announce::Args),
     /// Delete entry for service provider from the directory
     Delete(delete::Args),
+    /// Update the type and metadata of an announced service provider
+    Update(update::Args),
     /// Create base58-encoded payload required for producing valid announce signature.
     CreateServiceAnnounceSignPayload(announce_sign_payload::Args),
 }
+
+#[derive(Debug, Args)]
+pub struct ServiceDescriptionArgs {
+    /// Type of the service, either 'network_requester', 'generic', 'echo' or any custom name
+    #[clap(long, default_value = "network_requester")]
+    pub service_type: ServiceType,
+
+    /// Human readable description of the service
+    #[clap(long)]
+    pub description: Option<String>,
+
+    /// Version of the request interface supported by the service (can be repeated)
+    #[clap(long = "supported-request-version")]
+    pub supported_request_versions: Vec<u8>,
+
+    /// URL of the policy describing which hosts the service is willing to reach
+    #[clap(long)]
+    pub allowed_hosts_policy_url: Option<String>,
+
+    /// Geographical region the service is operating from
+    #[clap(long)]
+    pub region: Option<String>,
+}
+
+impl ServiceDescriptionArgs {
+    pub fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            description: self.description.clone(),
+            supported_request_versions: self.supported_request_versions.clone(),
+            allowed_hosts_policy_url: self.allowed_hosts_policy_url.clone(),
+            region: self.region.clone(),
+        }
+    }
+}
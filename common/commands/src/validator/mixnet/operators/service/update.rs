@@ -0,0 +1,35 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_service_provider_directory_common::ServiceId;
+use nym_validator_client::nyxd::traits::SpDirectorySigningClient;
+
+use crate::context::SigningClient;
+use crate::validator::mixnet::operators::service::ServiceDescriptionArgs;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: ServiceId,
+
+    #[clap(flatten)]
+    pub description: ServiceDescriptionArgs,
+}
+
+pub async fn update(args: Args, client: SigningClient) {
+    info!("Updating service provider with id {}", args.id);
+
+    let res = client
+        .update_service_provider(
+            args.id,
+            args.description.service_type.clone(),
+            args.description.metadata(),
+            None,
+        )
+        .await
+        .expect("Failed to update service provider");
+
+    info!("Updated: {res:?}");
+}
@@ -3,6 +3,7 @@
 
 use clap::Parser;
 use comfy_table::Table;
+use nym_service_provider_directory_common::ServiceType;
 use nym_validator_client::nym_api::error::NymAPIError;
 
 use crate::context::QueryClientWithNyxd;
@@ -13,10 +14,22 @@ pub struct Args {
     #[clap(value_parser)]
     #[clap(help = "Optionally, the service provider to display")]
     pub nym_address: Option<String>,
+
+    /// Only display the service providers of the specified type
+    #[clap(long)]
+    pub service_type: Option<ServiceType>,
+
+    /// Only display the service providers announced by the specified address
+    #[clap(long)]
+    pub owner: Option<String>,
 }
 
 pub async fn query(args: Args, client: &QueryClientWithNyxd) {
-    match client.nym_api.get_service_providers().await {
+    match client
+        .nym_api
+        .get_service_providers_filtered(args.service_type.as_ref(), args.owner.as_deref())
+        .await
+    {
         Ok(res) => {
             if let Some(nym_address) = args.nym_address {
                 let service = res.services.iter().find(|service| {
@@ -33,13 +46,20 @@ pub async fn query(args: Args, client: &QueryClientWithNyxd) {
             } else {
                 let mut table = Table::new();
 
-                table.set_header(vec!["Service Id", "Announcer", "Type", "Nym Address"]);
+                table.set_header(vec![
+                    "Service Id",
+                    "Announcer",
+                    "Type",
+                    "Nym Address",
+                    "Description",
+                ]);
                 for service in res.services {
                     table.add_row(vec![
                         service.service_id.to_string(),
                         service.announcer.to_string(),
                         service.service.service_type.to_string(),
                         service.service.nym_address.to_string(),
+                        service.service.metadata.description.unwrap_or_default(),
                     ]);
                 }
 
@@ -6,7 +6,7 @@
 
 use crate::constants::TOKEN_SUPPLY;
 use crate::helpers::IntoBaseDecimal;
-use crate::{Addr, MixId};
+use crate::{Addr, IdentityKey, MixId};
 use cosmwasm_std::{Coin, Decimal, StdResult};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,7 @@ use serde::{Deserialize, Serialize};
 // and trying to figure out whether they're valid, etc
 pub type OwnerProxySubKey = String;
 pub type StorageKey = (MixId, OwnerProxySubKey);
+pub type GatewayStorageKey = (IdentityKey, Addr);
 
 // throughout the contract we ensure that our proxy can ONLY ever be the vesting contract
 // thus this method is equivalent to either using the existing address (for when there's no proxy)
@@ -50,7 +51,8 @@ pub struct Delegation {
     /// Value of the "unit delegation" associated with the mixnode at the time of delegation.
     pub cumulative_reward_ratio: Decimal,
 
-    /// Original delegation amount. Note that it is never mutated as delegation accumulates rewards.
+    /// Original delegation amount. Note that it is never mutated as delegation accumulates rewards,
+    /// unless they're being automatically compounded.
     pub amount: Coin,
 
     /// Block height where this delegation occurred.
@@ -114,6 +116,66 @@ impl Delegation {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct GatewayDelegation {
+    /// Address of the owner of this delegation.
+    pub owner: Addr,
+
+    /// Identity key of the gateway that this delegation was performed against.
+    pub gateway_identity: IdentityKey,
+
+    /// Value of the "unit delegation" associated with the gateway at the time of delegation.
+    pub cumulative_reward_ratio: Decimal,
+
+    /// Original delegation amount. Note that it is never mutated as delegation accumulates rewards.
+    pub amount: Coin,
+
+    /// Block height where this delegation occurred.
+    pub height: u64,
+
+    /// Proxy address used to delegate the funds on behalf of another address.
+    /// Unlike with mixnodes, it's not part of the storage key, so an address can only have
+    /// a single delegation towards a gateway, either made with liquid or with vesting tokens.
+    pub proxy: Option<Addr>,
+}
+
+impl GatewayDelegation {
+    pub fn new(
+        owner: Addr,
+        gateway_identity: IdentityKey,
+        cumulative_reward_ratio: Decimal,
+        amount: Coin,
+        height: u64,
+        proxy: Option<Addr>,
+    ) -> Self {
+        assert!(
+            amount.amount <= TOKEN_SUPPLY,
+            "delegation cannot be larger than the token supply"
+        );
+
+        GatewayDelegation {
+            owner,
+            gateway_identity,
+            cumulative_reward_ratio,
+            amount,
+            height,
+            proxy,
+        }
+    }
+
+    pub fn generate_storage_key(gateway_identity: &str, owner_address: &Addr) -> GatewayStorageKey {
+        (gateway_identity.to_string(), owner_address.clone())
+    }
+
+    pub fn dec_amount(&self) -> StdResult<Decimal> {
+        self.amount.amount.into_base_decimal()
+    }
+
+    pub fn storage_key(&self) -> GatewayStorageKey {
+        Self::generate_storage_key(&self.gateway_identity, &self.owner)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
 pub struct PagedMixNodeDelegationsResponse {
     pub delegations: Vec<Delegation>,
@@ -151,13 +213,22 @@ impl PagedDelegatorDelegationsResponse {
 pub struct MixNodeDelegationResponse {
     pub delegation: Option<Delegation>,
     pub mixnode_still_bonded: bool,
+
+    /// Indicates whether the rewards of this delegation are folded into it at the end of every epoch.
+    #[serde(default)]
+    pub auto_compounding: bool,
 }
 
 impl MixNodeDelegationResponse {
-    pub fn new(delegation: Option<Delegation>, mixnode_still_bonded: bool) -> Self {
+    pub fn new(
+        delegation: Option<Delegation>,
+        mixnode_still_bonded: bool,
+        auto_compounding: bool,
+    ) -> Self {
         MixNodeDelegationResponse {
             delegation,
             mixnode_still_bonded,
+            auto_compounding,
         }
     }
 }
@@ -176,3 +247,48 @@ impl PagedAllDelegationsResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PagedGatewayDelegationsResponse {
+    pub delegations: Vec<GatewayDelegation>,
+    pub start_next_after: Option<Addr>,
+}
+
+impl PagedGatewayDelegationsResponse {
+    pub fn new(delegations: Vec<GatewayDelegation>, start_next_after: Option<Addr>) -> Self {
+        PagedGatewayDelegationsResponse {
+            delegations,
+            start_next_after,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PagedDelegatorGatewayDelegationsResponse {
+    pub delegations: Vec<GatewayDelegation>,
+    pub start_next_after: Option<IdentityKey>,
+}
+
+impl PagedDelegatorGatewayDelegationsResponse {
+    pub fn new(delegations: Vec<GatewayDelegation>, start_next_after: Option<IdentityKey>) -> Self {
+        PagedDelegatorGatewayDelegationsResponse {
+            delegations,
+            start_next_after,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct GatewayDelegationResponse {
+    pub delegation: Option<GatewayDelegation>,
+    pub gateway_still_bonded: bool,
+}
+
+impl GatewayDelegationResponse {
+    pub fn new(delegation: Option<GatewayDelegation>, gateway_still_bonded: bool) -> Self {
+        GatewayDelegationResponse {
+            delegation,
+            gateway_still_bonded,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EpochEventId, EpochState, IdentityKey, MixId};
+use crate::{EpochEventId, EpochState, IdentityKey, MixId, Percent, SlashingId};
 use contracts_common::signing::verifier::ApiVerifierError;
 use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 use thiserror::Error;
@@ -46,6 +46,9 @@ pub enum MixnetContractError {
     #[error("Mixnode ({mix_id}) does not exist")]
     MixNodeBondNotFound { mix_id: MixId },
 
+    #[error("Gateway ({identity}) does not exist")]
+    GatewayBondNotFound { identity: IdentityKey },
+
     #[error("{owner} does not seem to own any mixnodes")]
     NoAssociatedMixNodeBond { owner: Addr },
 
@@ -138,6 +141,24 @@ pub enum MixnetContractError {
         proxy: Option<String>,
     },
 
+    #[error(
+        "Could not find any delegation information associated with gateway {identity} for {address}"
+    )]
+    NoGatewayDelegationFound {
+        identity: IdentityKey,
+        address: String,
+    },
+
+    #[error("Attempted to redelegate tokens from mixnode {mix_id} back to the same node")]
+    RedelegationToSameMixnode { mix_id: MixId },
+
+    #[error("Attempted to redelegate {requested} from mixnode {mix_id}, but the delegation (alongside its rewards) is only worth {available}")]
+    RedelegationAmountExceedsDelegation {
+        mix_id: MixId,
+        requested: Coin,
+        available: Coin,
+    },
+
     #[error("Provided message to update rewarding params did not contain any updates")]
     EmptyParamsChangeMsg,
 
@@ -168,9 +189,19 @@ pub enum MixnetContractError {
     #[error("Family with label '{0}' already exists")]
     FamilyWithLabelExists(String),
 
-    #[error("Invalid layer expected 1, 2 or 3, got {0}")]
+    #[error(
+        "Invalid layer {0}, expected value between 1 and {}",
+        crate::MAX_MIX_LAYERS
+    )]
     InvalidLayer(u8),
 
+    #[error(
+        "Invalid number of mix layers {0}, expected value between {} and {}",
+        crate::MIN_MIX_LAYERS,
+        crate::MAX_MIX_LAYERS
+    )]
+    InvalidNumberOfMixLayers(u8),
+
     #[error("Head already has a family")]
     FamilyCanHaveOnlyOne,
 
@@ -231,6 +262,26 @@ pub enum MixnetContractError {
     #[error("the epoch is currently not in the 'epoch advancement' state. (the state is {current_state})")]
     EpochNotInAdvancementState { current_state: EpochState },
 
+    #[error("gateways can only be rewarded while the mixnodes are being rewarded. (the state is {current_state})")]
+    EpochNotInGatewayRewardingState { current_state: EpochState },
+
+    #[error("gateway stake saturation point can't be zero")]
+    ZeroGatewayStakeSaturationPoint,
+
+    #[error(
+        "the slashing percentage has to be non-zero and strictly lower than 100%. Got {value}"
+    )]
+    InvalidSlashingPercentage { value: Percent },
+
+    #[error("the provided slashing evidence is invalid: {reason}")]
+    InvalidSlashingEvidence { reason: String },
+
+    #[error("slashing record with id {slashing_id} does not exist")]
+    SlashingRecordNotFound { slashing_id: SlashingId },
+
+    #[error("the node is waiting for the slashing with id {slashing_id} to be applied")]
+    PendingSlashing { slashing_id: SlashingId },
+
     #[error("failed to parse {value} into a valid SemVer version: {error_message}")]
     SemVerFailure {
         value: String,
@@ -1,11 +1,13 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::gateway::GatewayConfigUpdate;
+use crate::gateway::{GatewayConfigUpdate, GatewayRewardingParams};
 use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{IntervalRewardParams, IntervalRewardingParamsUpdate};
 use crate::rewarding::RewardDistribution;
-use crate::{BlockHeight, ContractStateParams, IdentityKeyRef, Interval, Layer, MixId};
+use crate::{
+    BlockHeight, ContractStateParams, IdentityKeyRef, Interval, Layer, MixId, Percent, SlashingId,
+};
 pub use contracts_common::events::*;
 use cosmwasm_std::{Addr, Coin, Decimal, Event};
 
@@ -27,15 +29,20 @@ pub enum MixnetEventType {
     MixnodeRewarding,
     WithdrawDelegatorReward,
     WithdrawOperatorReward,
+    DelegationAutoCompoundingUpdate,
+    CompoundDelegatorRewards,
     PendingActiveSetUpdate,
     ActiveSetUpdate,
     PendingIntervalRewardingParamsUpdate,
     IntervalRewardingParamsUpdate,
     PendingDelegation,
     PendingUndelegation,
+    PendingRedelegation,
     Delegation,
     DelegationOnUnbonding,
     Undelegation,
+    Redelegation,
+    RedelegationToUnbondedNode,
     ContractSettingsUpdate,
     RewardingValidatorUpdate,
     BeginEpochTransition,
@@ -46,6 +53,18 @@ pub enum MixnetEventType {
     PendingIntervalConfigUpdate,
     IntervalConfigUpdate,
     GatewayConfigUpdate,
+    GatewayRewardingParamsUpdate,
+    GatewayRewarding,
+    WithdrawGatewayDelegatorReward,
+    WithdrawGatewayOperatorReward,
+    PendingGatewayDelegation,
+    PendingGatewayUndelegation,
+    GatewayDelegation,
+    GatewayUndelegation,
+    PendingMixnodeSlashing,
+    MixnodeSlashing,
+    PendingGatewaySlashing,
+    GatewaySlashing,
 }
 
 impl From<MixnetEventType> for String {
@@ -72,6 +91,10 @@ impl ToString for MixnetEventType {
             MixnetEventType::MixnodeRewarding => "mix_rewarding",
             MixnetEventType::WithdrawDelegatorReward => "withdraw_delegator_reward",
             MixnetEventType::WithdrawOperatorReward => "withdraw_operator_reward",
+            MixnetEventType::DelegationAutoCompoundingUpdate => {
+                "delegation_auto_compounding_update"
+            }
+            MixnetEventType::CompoundDelegatorRewards => "compound_delegator_rewards",
             MixnetEventType::PendingActiveSetUpdate => "pending_active_set_update",
             MixnetEventType::ActiveSetUpdate => "active_set_update",
             MixnetEventType::PendingIntervalRewardingParamsUpdate => {
@@ -80,8 +103,11 @@ impl ToString for MixnetEventType {
             MixnetEventType::IntervalRewardingParamsUpdate => "interval_rewarding_params_update",
             MixnetEventType::PendingDelegation => "pending_delegation",
             MixnetEventType::PendingUndelegation => "pending_undelegation",
+            MixnetEventType::PendingRedelegation => "pending_redelegation",
             MixnetEventType::Delegation => "delegation",
             MixnetEventType::Undelegation => "undelegation",
+            MixnetEventType::Redelegation => "redelegation",
+            MixnetEventType::RedelegationToUnbondedNode => "redelegation_to_unbonded_node",
             MixnetEventType::ContractSettingsUpdate => "settings_update",
             MixnetEventType::RewardingValidatorUpdate => "rewarding_validator_address_update",
             MixnetEventType::BeginEpochTransition => "beginning_epoch_transition",
@@ -93,6 +119,18 @@ impl ToString for MixnetEventType {
             MixnetEventType::IntervalConfigUpdate => "interval_config_update",
             MixnetEventType::DelegationOnUnbonding => "delegation_on_unbonding_node",
             MixnetEventType::GatewayConfigUpdate => "gateway_config_update",
+            MixnetEventType::GatewayRewardingParamsUpdate => "gateway_rewarding_params_update",
+            MixnetEventType::GatewayRewarding => "gateway_rewarding",
+            MixnetEventType::WithdrawGatewayDelegatorReward => "withdraw_gateway_delegator_reward",
+            MixnetEventType::WithdrawGatewayOperatorReward => "withdraw_gateway_operator_reward",
+            MixnetEventType::PendingGatewayDelegation => "pending_gateway_delegation",
+            MixnetEventType::PendingGatewayUndelegation => "pending_gateway_undelegation",
+            MixnetEventType::GatewayDelegation => "gateway_delegation",
+            MixnetEventType::GatewayUndelegation => "gateway_undelegation",
+            MixnetEventType::PendingMixnodeSlashing => "pending_mixnode_slashing",
+            MixnetEventType::MixnodeSlashing => "mixnode_slashing",
+            MixnetEventType::PendingGatewaySlashing => "pending_gateway_slashing",
+            MixnetEventType::GatewaySlashing => "gateway_slashing",
         };
 
         format!("{EVENT_VERSION_PREFIX}{event_name}")
@@ -110,6 +148,8 @@ pub const PROXY_KEY: &str = "proxy";
 pub const DELEGATOR_KEY: &str = "delegator";
 pub const DELEGATION_TARGET_KEY: &str = "delegation_target";
 pub const UNIT_REWARD_KEY: &str = "unit_reward";
+pub const REDELEGATION_SOURCE_KEY: &str = "redelegation_source";
+pub const AUTO_COMPOUNDING_KEY: &str = "auto_compounding";
 
 // bonding/unbonding
 pub const MIX_ID_KEY: &str = "mix_id";
@@ -120,10 +160,12 @@ pub const ASSIGNED_LAYER_KEY: &str = "assigned_layer";
 pub const OLD_MINIMUM_MIXNODE_PLEDGE_KEY: &str = "old_minimum_mixnode_pledge";
 pub const OLD_MINIMUM_GATEWAY_PLEDGE_KEY: &str = "old_minimum_gateway_pledge";
 pub const OLD_MINIMUM_DELEGATION_KEY: &str = "old_minimum_delegation";
+pub const OLD_MIX_LAYERS_KEY: &str = "old_mix_layers";
 
 pub const NEW_MINIMUM_MIXNODE_PLEDGE_KEY: &str = "new_minimum_mixnode_pledge";
 pub const NEW_MINIMUM_GATEWAY_PLEDGE_KEY: &str = "new_minimum_gateway_pledge";
 pub const NEW_MINIMUM_DELEGATION_KEY: &str = "new_minimum_delegation";
+pub const NEW_MIX_LAYERS_KEY: &str = "new_mix_layers";
 
 pub const OLD_REWARDING_VALIDATOR_ADDRESS_KEY: &str = "old_rewarding_validator_address";
 pub const NEW_REWARDING_VALIDATOR_ADDRESS_KEY: &str = "new_rewarding_validator_address";
@@ -131,6 +173,7 @@ pub const NEW_REWARDING_VALIDATOR_ADDRESS_KEY: &str = "new_rewarding_validator_a
 pub const UPDATED_MIXNODE_CONFIG_KEY: &str = "updated_mixnode_config";
 pub const UPDATED_GATEWAY_CONFIG_KEY: &str = "updated_gateway_config";
 pub const UPDATED_MIXNODE_COST_PARAMS_KEY: &str = "updated_mixnode_cost_params";
+pub const UPDATED_GATEWAY_REWARDING_PARAMS_KEY: &str = "updated_gateway_rewarding_params";
 
 // rewarding
 pub const INTERVAL_KEY: &str = "interval_details";
@@ -145,6 +188,7 @@ pub const PRIOR_UNIT_REWARD_KEY: &str = "prior_unit_reward";
 pub const NO_REWARD_REASON_KEY: &str = "no_reward_reason";
 pub const BOND_NOT_FOUND_VALUE: &str = "bond_not_found";
 pub const ZERO_PERFORMANCE_VALUE: &str = "zero_performance";
+pub const ALREADY_REWARDED_VALUE: &str = "already_rewarded";
 
 // rewarded set update
 pub const ACTIVE_SET_SIZE_KEY: &str = "active_set_size";
@@ -154,11 +198,21 @@ pub const NEW_CURRENT_EPOCH_KEY: &str = "new_current_epoch";
 
 // interval
 pub const EVENTS_EXECUTED_KEY: &str = "number_of_events_executed";
+pub const COMPOUNDED_DELEGATIONS_KEY: &str = "number_of_compounded_delegations";
+pub const COMPOUNDING_FINISHED_KEY: &str = "compounding_finished";
 pub const EVENT_CREATION_HEIGHT_KEY: &str = "created_at";
 pub const REWARDED_SET_NODES_KEY: &str = "rewarded_set_nodes";
 pub const NEW_EPOCHS_DURATION_SECS_KEY: &str = "new_epoch_durations_secs";
 pub const NEW_EPOCHS_IN_INTERVAL: &str = "new_epochs_in_interval";
 
+// slashing
+pub const SLASHING_ID_KEY: &str = "slashing_id";
+pub const SLASHING_SUBMITTER_KEY: &str = "submitted_by";
+pub const SLASH_PERCENT_KEY: &str = "slash_percent";
+pub const REDISTRIBUTE_KEY: &str = "redistribute";
+pub const OPERATOR_SLASHED_KEY: &str = "operator_slashed";
+pub const DELEGATES_SLASHED_KEY: &str = "delegates_slashed";
+
 pub fn new_delegation_event(
     created_at: BlockHeight,
     delegator: &Addr,
@@ -226,6 +280,19 @@ pub fn new_withdraw_delegator_reward_event(
         .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
 }
 
+pub fn new_delegation_auto_compounding_update_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    mix_id: MixId,
+    enabled: bool,
+) -> Event {
+    Event::new(MixnetEventType::DelegationAutoCompoundingUpdate)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
+        .add_attribute(AUTO_COMPOUNDING_KEY, enabled.to_string())
+}
+
 pub fn new_active_set_update_event(created_at: BlockHeight, new_size: u32) -> Event {
     Event::new(MixnetEventType::ActiveSetUpdate)
         .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
@@ -301,6 +368,55 @@ pub fn new_pending_undelegation_event(
         .add_attribute(MIX_ID_KEY, mix_id.to_string())
 }
 
+pub fn new_redelegation_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    unit_reward: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::Redelegation)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+        .add_attribute(UNIT_REWARD_KEY, unit_reward.to_string())
+}
+
+pub fn new_redelegation_to_unbonded_node_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::RedelegationToUnbondedNode)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+}
+
+pub fn new_pending_redelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::PendingRedelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+}
+
 pub fn new_gateway_bonding_event(
     owner: &Addr,
     proxy: &Option<Addr>,
@@ -327,6 +443,102 @@ pub fn new_gateway_unbonding_event(
         .add_attribute(AMOUNT_KEY, amount.to_string())
 }
 
+pub fn new_gateway_rewarding_params_update_event(updated: GatewayRewardingParams) -> Event {
+    Event::new(MixnetEventType::GatewayRewardingParamsUpdate).add_attribute(
+        UPDATED_GATEWAY_REWARDING_PARAMS_KEY,
+        updated.to_inline_json(),
+    )
+}
+
+pub fn new_pending_gateway_delegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::PendingGatewayDelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, identity)
+}
+
+pub fn new_pending_gateway_undelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::PendingGatewayUndelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
+pub fn new_gateway_delegation_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    identity: IdentityKeyRef<'_>,
+    unit_reward: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::GatewayDelegation)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, identity)
+        .add_attribute(UNIT_REWARD_KEY, unit_reward.to_string())
+}
+
+pub fn new_gateway_delegation_on_unbonded_node_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::GatewayDelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(DELEGATION_TARGET_KEY, identity)
+}
+
+pub fn new_gateway_undelegation_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::GatewayUndelegation)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
+pub fn new_withdraw_gateway_operator_reward_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::WithdrawGatewayOperatorReward)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
+pub fn new_withdraw_gateway_delegator_reward_event(
+    delegator: &Addr,
+    amount: Coin,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::WithdrawGatewayDelegatorReward)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, identity)
+}
+
 pub fn new_mixnode_bonding_event(
     owner: &Addr,
     proxy: &Option<Addr>,
@@ -487,6 +699,12 @@ pub fn new_settings_update_event(
         }
     }
 
+    if old_params.mix_layers != new_params.mix_layers {
+        event = event
+            .add_attribute(OLD_MIX_LAYERS_KEY, old_params.mix_layers.to_string())
+            .add_attribute(NEW_MIX_LAYERS_KEY, new_params.mix_layers.to_string())
+    }
+
     event
 }
 
@@ -535,6 +753,66 @@ pub fn new_mix_rewarding_event(
         )
 }
 
+fn new_no_reward_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+    reason: &str,
+) -> Event {
+    Event::new(MixnetEventType::GatewayRewarding)
+        .add_attribute(
+            INTERVAL_KEY,
+            interval.current_epoch_absolute_id().to_string(),
+        )
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(NO_REWARD_REASON_KEY, reason)
+}
+
+pub fn new_not_found_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    new_no_reward_gateway_rewarding_event(interval, identity, BOND_NOT_FOUND_VALUE)
+}
+
+pub fn new_zero_uptime_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    new_no_reward_gateway_rewarding_event(interval, identity, ZERO_PERFORMANCE_VALUE)
+}
+
+pub fn new_already_rewarded_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    new_no_reward_gateway_rewarding_event(interval, identity, ALREADY_REWARDED_VALUE)
+}
+
+pub fn new_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+    reward_distribution: RewardDistribution,
+    prior_delegates: Decimal,
+    prior_unit_reward: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::GatewayRewarding)
+        .add_attribute(
+            INTERVAL_KEY,
+            interval.current_epoch_absolute_id().to_string(),
+        )
+        .add_attribute(PRIOR_DELEGATES_KEY, prior_delegates.to_string())
+        .add_attribute(PRIOR_UNIT_REWARD_KEY, prior_unit_reward.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(
+            OPERATOR_REWARD_KEY,
+            reward_distribution.operator.to_string(),
+        )
+        .add_attribute(
+            DELEGATES_REWARD_KEY,
+            reward_distribution.delegates.to_string(),
+        )
+}
+
 pub fn new_epoch_transition_start_event(current_interval: Interval) -> Event {
     Event::new(MixnetEventType::BeginEpochTransition).add_attribute(
         CURRENT_EPOCH_KEY,
@@ -561,6 +839,12 @@ pub fn new_pending_interval_events_execution_event(executed: u32) -> Event {
         .add_attribute(EVENTS_EXECUTED_KEY, executed.to_string())
 }
 
+pub fn new_delegator_rewards_compounding_event(compounded: u32, finished: bool) -> Event {
+    Event::new(MixnetEventType::CompoundDelegatorRewards)
+        .add_attribute(COMPOUNDED_DELEGATIONS_KEY, compounded.to_string())
+        .add_attribute(COMPOUNDING_FINISHED_KEY, finished.to_string())
+}
+
 pub fn new_reconcile_pending_events() -> Event {
     Event::new(MixnetEventType::ReconcilePendingEvents)
 }
@@ -600,3 +884,67 @@ pub fn new_pending_interval_config_update_event(
             approximate_time_remaining_secs.to_string(),
         )
 }
+
+pub fn new_pending_mixnode_slashing_event(
+    submitted_by: &Addr,
+    mix_id: MixId,
+    slashing_id: SlashingId,
+    slash_percent: Percent,
+    redistribute: bool,
+) -> Event {
+    Event::new(MixnetEventType::PendingMixnodeSlashing)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(SLASHING_ID_KEY, slashing_id.to_string())
+        .add_attribute(SLASH_PERCENT_KEY, slash_percent.to_string())
+        .add_attribute(REDISTRIBUTE_KEY, redistribute.to_string())
+        .add_attribute(SLASHING_SUBMITTER_KEY, submitted_by)
+}
+
+pub fn new_mixnode_slashing_event(
+    created_at: BlockHeight,
+    mix_id: MixId,
+    slashing_id: SlashingId,
+    operator_slashed: &Coin,
+    delegates_slashed: &Coin,
+    redistribute: bool,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeSlashing)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(SLASHING_ID_KEY, slashing_id.to_string())
+        .add_attribute(OPERATOR_SLASHED_KEY, operator_slashed.to_string())
+        .add_attribute(DELEGATES_SLASHED_KEY, delegates_slashed.to_string())
+        .add_attribute(REDISTRIBUTE_KEY, redistribute.to_string())
+}
+
+pub fn new_pending_gateway_slashing_event(
+    submitted_by: &Addr,
+    identity: IdentityKeyRef<'_>,
+    slashing_id: SlashingId,
+    slash_percent: Percent,
+    redistribute: bool,
+) -> Event {
+    Event::new(MixnetEventType::PendingGatewaySlashing)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(SLASHING_ID_KEY, slashing_id.to_string())
+        .add_attribute(SLASH_PERCENT_KEY, slash_percent.to_string())
+        .add_attribute(REDISTRIBUTE_KEY, redistribute.to_string())
+        .add_attribute(SLASHING_SUBMITTER_KEY, submitted_by)
+}
+
+pub fn new_gateway_slashing_event(
+    created_at: BlockHeight,
+    identity: IdentityKeyRef<'_>,
+    slashing_id: SlashingId,
+    operator_slashed: &Coin,
+    delegates_slashed: &Coin,
+    redistribute: bool,
+) -> Event {
+    Event::new(MixnetEventType::GatewaySlashing)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(SLASHING_ID_KEY, slashing_id.to_string())
+        .add_attribute(OPERATOR_SLASHED_KEY, operator_slashed.to_string())
+        .add_attribute(DELEGATES_SLASHED_KEY, delegates_slashed.to_string())
+        .add_attribute(REDISTRIBUTE_KEY, redistribute.to_string())
+}
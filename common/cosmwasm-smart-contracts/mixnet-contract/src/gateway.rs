@@ -4,8 +4,15 @@
 // due to code generated by JsonSchema
 #![allow(clippy::field_reassign_with_default)]
 
-use crate::{IdentityKey, SphinxKey};
-use cosmwasm_std::{Addr, Coin};
+use crate::constants::{TOKEN_SUPPLY, UNIT_DELEGATION_BASE};
+use crate::delegation::GatewayDelegation;
+use crate::error::MixnetContractError;
+use crate::helpers::IntoBaseDecimal;
+use crate::reward_params::Performance;
+use crate::rewarding::helpers::{truncate_reward, truncate_reward_amount};
+use crate::rewarding::RewardDistribution;
+use crate::{EpochId, IdentityKey, Percent, SphinxKey};
+use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -112,6 +119,326 @@ impl Display for GatewayBond {
     }
 }
 
+/// Parameters used for determining the rewards of all bonded gateways.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct GatewayRewardingParams {
+    /// Share of the epoch reward budget that forms the gateway reward pool.
+    /// The pool is split equally between all the gateways bonded at the time of rewarding.
+    pub reward_pool_share: Percent,
+
+    /// Total stake of a gateway at which it becomes saturated and its reward stops increasing.
+    pub stake_saturation_point: Decimal,
+}
+
+impl GatewayRewardingParams {
+    /// Parameters under which no gateway gets rewarded.
+    pub fn new_disabled(stake_saturation_point: Decimal) -> Self {
+        GatewayRewardingParams {
+            reward_pool_share: Percent::zero(),
+            stake_saturation_point,
+        }
+    }
+
+    /// Part of the epoch reward budget that is left for rewarding mixnodes once the gateway reward pool
+    /// has been taken out of it, so that the total emission would never exceed the budget.
+    pub fn mixnode_epoch_reward_budget(&self, epoch_reward_budget: Decimal) -> Decimal {
+        epoch_reward_budget - self.reward_pool_share * epoch_reward_budget
+    }
+
+    /// Reward distributed to a single gateway (and its delegators) at the end of an epoch
+    /// if it had perfect performance and its stake was fully saturated.
+    pub fn epoch_reward_per_gateway(
+        &self,
+        epoch_reward_budget: Decimal,
+        bonded_gateways: u32,
+    ) -> Decimal {
+        if bonded_gateways == 0 {
+            return Decimal::zero();
+        }
+        self.reward_pool_share * epoch_reward_budget / Decimal::from_ratio(bonded_gateways, 1u32)
+    }
+
+    pub fn to_inline_json(&self) -> String {
+        serde_json_wasm::to_string(self).unwrap_or_else(|_| "serialisation failure".into())
+    }
+
+    pub fn validate(&self) -> Result<(), MixnetContractError> {
+        if self.stake_saturation_point.is_zero() {
+            return Err(MixnetContractError::ZeroGatewayStakeSaturationPoint);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct GatewayRewarding {
+    /// Total pledge and compounded reward earned by the gateway operator.
+    pub operator: Decimal,
+
+    /// Total delegation and compounded reward earned by all gateway delegators.
+    pub delegates: Decimal,
+
+    /// Cumulative reward earned by the "unit delegation" since the block 0.
+    pub total_unit_reward: Decimal,
+
+    /// Value of the theoretical "unit delegation" that has delegated to this gateway at block 0.
+    pub unit_delegation: Decimal,
+
+    /// Marks the epoch when this gateway was last rewarded so that we wouldn't accidentally attempt
+    /// to reward it multiple times in the same epoch.
+    pub last_rewarded_epoch: EpochId,
+
+    /// Number of delegations made towards this gateway.
+    pub unique_delegations: u32,
+}
+
+impl GatewayRewarding {
+    pub fn initialise_new(
+        initial_pledge: &Coin,
+        current_epoch: EpochId,
+    ) -> Result<Self, MixnetContractError> {
+        assert!(
+            initial_pledge.amount <= TOKEN_SUPPLY,
+            "pledge cannot be larger than the token supply"
+        );
+
+        Ok(GatewayRewarding {
+            operator: initial_pledge.amount.into_base_decimal()?,
+            delegates: Decimal::zero(),
+            total_unit_reward: Decimal::zero(),
+            unit_delegation: UNIT_DELEGATION_BASE,
+            last_rewarded_epoch: current_epoch,
+            unique_delegations: 0,
+        })
+    }
+
+    /// Determines whether this gateway is still bonded. Once the operator unbonds, those params
+    /// only exist for the purposes of calculating rewards for delegators that
+    /// have not yet removed their tokens.
+    pub fn still_bonded(&self) -> bool {
+        self.operator != Decimal::zero()
+    }
+
+    pub fn pending_operator_reward(&self, original_pledge: &Coin) -> Coin {
+        let reward_with_pledge = truncate_reward(self.operator, &original_pledge.denom);
+        Coin {
+            denom: reward_with_pledge.denom,
+            amount: reward_with_pledge.amount - original_pledge.amount,
+        }
+    }
+
+    pub fn pending_delegator_reward(&self, delegation: &GatewayDelegation) -> StdResult<Coin> {
+        let delegator_reward = self.determine_delegation_reward(delegation)?;
+        Ok(truncate_reward(delegator_reward, &delegation.amount.denom))
+    }
+
+    pub fn withdraw_operator_reward(
+        &mut self,
+        original_pledge: &Coin,
+    ) -> Result<Coin, MixnetContractError> {
+        let initial_dec = original_pledge.amount.into_base_decimal()?;
+        if initial_dec > self.operator {
+            return Err(MixnetContractError::OverflowDecimalSubtraction {
+                minuend: self.operator,
+                subtrahend: initial_dec,
+            });
+        }
+        let diff = self.operator - initial_dec;
+        self.operator = initial_dec;
+
+        Ok(truncate_reward(diff, &original_pledge.denom))
+    }
+
+    /// Removes the entire operator stake, i.e. the original pledge alongside all earned rewards.
+    pub fn remove_operator_stake(&mut self, denom: impl Into<String>) -> Coin {
+        let stake = truncate_reward(self.operator, denom);
+        self.operator = Decimal::zero();
+        stake
+    }
+
+    pub fn withdraw_delegator_reward(
+        &mut self,
+        delegation: &mut GatewayDelegation,
+    ) -> Result<Coin, MixnetContractError> {
+        let reward = self.determine_delegation_reward(delegation)?;
+        self.decrease_delegates_decimal(reward)?;
+
+        delegation.cumulative_reward_ratio = self.total_unit_reward;
+        Ok(truncate_reward(reward, &delegation.amount.denom))
+    }
+
+    /// Removes the specified portion of the operator's stake, including its unclaimed rewards.
+    /// Returns the amount that got removed.
+    pub fn slash_operator(&mut self, slash_percent: Percent) -> Decimal {
+        let slashed = slash_percent * self.operator;
+        self.operator -= slashed;
+        slashed
+    }
+
+    /// Removes the specified portion of the delegation, including its unclaimed rewards,
+    /// and folds whatever remains into its base amount.
+    /// Returns the amount that got removed.
+    pub fn slash_delegation(
+        &mut self,
+        delegation: &mut GatewayDelegation,
+        slash_percent: Percent,
+    ) -> Result<Decimal, MixnetContractError> {
+        let reward = self.determine_delegation_reward(delegation)?;
+        let full_amount = reward + delegation.dec_amount()?;
+
+        // any decimal dust is slashed alongside the rest
+        let remaining = truncate_reward_amount(full_amount - slash_percent * full_amount);
+        let slashed = full_amount - remaining.into_base_decimal()?;
+        self.decrease_delegates_decimal(slashed)?;
+
+        delegation.amount.amount = remaining;
+        delegation.cumulative_reward_ratio = self.total_unit_reward;
+        Ok(slashed)
+    }
+
+    pub fn gateway_bond(&self) -> Decimal {
+        self.operator + self.delegates
+    }
+
+    /// Saturation over all the tokens staked on this gateway.
+    pub fn bond_saturation(&self, params: &GatewayRewardingParams) -> Decimal {
+        // make sure our saturation is never greater than 1
+        if self.gateway_bond() > params.stake_saturation_point {
+            Decimal::one()
+        } else {
+            self.gateway_bond() / params.stake_saturation_point
+        }
+    }
+
+    pub fn gateway_reward(
+        &self,
+        params: &GatewayRewardingParams,
+        epoch_reward_per_gateway: Decimal,
+        performance: Performance,
+    ) -> Decimal {
+        epoch_reward_per_gateway * performance.value() * self.bond_saturation(params)
+    }
+
+    /// Unlike mixnodes, gateways do not declare any costs, so the reward is split
+    /// proportionally to the stake of the operator and the delegators.
+    pub fn determine_reward_split(&self, gateway_reward: Decimal) -> RewardDistribution {
+        if self.delegates.is_zero() {
+            return RewardDistribution {
+                operator: gateway_reward,
+                delegates: Decimal::zero(),
+            };
+        }
+
+        let operator = gateway_reward * self.operator / self.gateway_bond();
+        RewardDistribution {
+            operator,
+            delegates: gateway_reward - operator,
+        }
+    }
+
+    pub fn calculate_epoch_reward(
+        &self,
+        params: &GatewayRewardingParams,
+        epoch_reward_per_gateway: Decimal,
+        performance: Performance,
+    ) -> RewardDistribution {
+        let gateway_reward = self.gateway_reward(params, epoch_reward_per_gateway, performance);
+        self.determine_reward_split(gateway_reward)
+    }
+
+    pub fn distribute_rewards(
+        &mut self,
+        distribution: RewardDistribution,
+        absolute_epoch_id: EpochId,
+    ) {
+        let unit_delegation_reward = if self.delegates.is_zero() {
+            Decimal::zero()
+        } else {
+            distribution.delegates * (self.unit_delegation + self.total_unit_reward)
+                / self.delegates
+        };
+
+        self.operator += distribution.operator;
+        self.delegates += distribution.delegates;
+        self.total_unit_reward += unit_delegation_reward;
+        self.last_rewarded_epoch = absolute_epoch_id;
+    }
+
+    pub fn determine_delegation_reward(
+        &self,
+        delegation: &GatewayDelegation,
+    ) -> StdResult<Decimal> {
+        let starting_ratio = delegation.cumulative_reward_ratio;
+        let ending_ratio = self.total_unit_reward;
+        let adjust = starting_ratio + self.unit_delegation;
+
+        Ok((ending_ratio - starting_ratio) * delegation.dec_amount()? / adjust)
+    }
+
+    // this updates `unique_delegations` field
+    pub fn add_base_delegation(&mut self, amount: Uint128) -> Result<(), MixnetContractError> {
+        self.delegates += amount.into_base_decimal()?;
+        self.unique_delegations += 1;
+        Ok(())
+    }
+
+    pub fn increase_operator_uint128(
+        &mut self,
+        amount: Uint128,
+    ) -> Result<(), MixnetContractError> {
+        self.operator += amount.into_base_decimal()?;
+        Ok(())
+    }
+
+    pub fn undelegate(
+        &mut self,
+        delegation: &GatewayDelegation,
+    ) -> Result<Coin, MixnetContractError> {
+        let reward = self.determine_delegation_reward(delegation)?;
+        let full_amount = reward + delegation.dec_amount()?;
+        self.remove_delegation_decimal(full_amount)?;
+        Ok(truncate_reward(full_amount, &delegation.amount.denom))
+    }
+
+    pub fn remove_delegation_decimal(
+        &mut self,
+        amount: Decimal,
+    ) -> Result<(), MixnetContractError> {
+        self.decrease_delegates_decimal(amount)?;
+
+        if self.unique_delegations == 0 {
+            return Err(MixnetContractError::OverflowSubtraction {
+                minuend: 0,
+                subtrahend: 1,
+            });
+        }
+        self.unique_delegations -= 1;
+
+        // if this was last delegation, move all leftover decimal tokens to the operator
+        // (this is literally in the order of a millionth of a micronym)
+        if self.unique_delegations == 0 {
+            self.operator += self.delegates;
+            self.delegates = Decimal::zero();
+        }
+        Ok(())
+    }
+
+    pub fn decrease_delegates_decimal(
+        &mut self,
+        amount: Decimal,
+    ) -> Result<(), MixnetContractError> {
+        if self.delegates < amount {
+            return Err(MixnetContractError::OverflowDecimalSubtraction {
+                minuend: self.delegates,
+                subtrahend: amount,
+            });
+        }
+
+        self.delegates -= amount;
+        Ok(())
+    }
+}
+
 #[cfg_attr(feature = "generate-ts", derive(ts_rs::TS))]
 #[cfg_attr(
     feature = "generate-ts",
@@ -165,6 +492,12 @@ pub struct GatewayBondResponse {
     pub gateway: Option<GatewayBond>,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct GatewayRewardingDetailsResponse {
+    pub identity: IdentityKey,
+    pub rewarding_details: Option<GatewayRewarding>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
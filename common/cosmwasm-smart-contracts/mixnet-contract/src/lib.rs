@@ -18,16 +18,19 @@ pub mod pending_events;
 pub mod reward_params;
 pub mod rewarding;
 pub mod signing_types;
+pub mod slashing;
 mod types;
 
 pub use contracts_common::types::*;
 pub use cosmwasm_std::{Addr, Coin, Decimal, Fraction};
 pub use delegation::{
-    Delegation, PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
-    PagedMixNodeDelegationsResponse,
+    Delegation, GatewayDelegation, GatewayDelegationResponse, PagedAllDelegationsResponse,
+    PagedDelegatorDelegationsResponse, PagedDelegatorGatewayDelegationsResponse,
+    PagedGatewayDelegationsResponse, PagedMixNodeDelegationsResponse,
 };
 pub use gateway::{
     Gateway, GatewayBond, GatewayBondResponse, GatewayConfigUpdate, GatewayOwnershipResponse,
+    GatewayRewarding, GatewayRewardingDetailsResponse, GatewayRewardingParams,
     PagedGatewayResponse,
 };
 pub use interval::{
@@ -38,7 +41,7 @@ pub use interval::{
 pub use mixnode::{
     Layer, MixNode, MixNodeBond, MixNodeConfigUpdate, MixNodeCostParams, MixNodeDetails,
     MixNodeRewarding, MixOwnershipResponse, MixnodeDetailsResponse, PagedMixnodeBondsResponse,
-    RewardedSetNodeStatus, UnbondedMixnode,
+    RewardedSetNodeStatus, UnbondedMixnode, DEFAULT_MIX_LAYERS, MAX_MIX_LAYERS, MIN_MIX_LAYERS,
 };
 pub use msg::*;
 pub use pending_events::{
@@ -47,4 +50,9 @@ pub use pending_events::{
 };
 pub use reward_params::{IntervalRewardParams, IntervalRewardingParamsUpdate, RewardingParams};
 pub use signing_types::*;
+pub use slashing::{
+    ForwardingReceipt, PagedGatewaySlashingHistoryResponse, PagedMixnodeSlashingHistoryResponse,
+    PagedSlashingHistoryResponse, SlashedNode, SlashingEvidence, SlashingRecord,
+    SlashingRecordResponse, SlashingStatus,
+};
 pub use types::*;
@@ -8,9 +8,11 @@ use crate::constants::{TOKEN_SUPPLY, UNIT_DELEGATION_BASE};
 use crate::error::MixnetContractError;
 use crate::helpers::IntoBaseDecimal;
 use crate::reward_params::{NodeRewardParams, RewardingParams};
-use crate::rewarding::helpers::truncate_reward;
+use crate::rewarding::helpers::{truncate_reward, truncate_reward_amount};
 use crate::rewarding::RewardDistribution;
-use crate::{Delegation, EpochEventId, EpochId, IdentityKey, MixId, Percent, SphinxKey};
+use crate::{
+    Delegation, EpochEventId, EpochId, IdentityKey, MixId, Percent, SlashingId, SphinxKey,
+};
 use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -155,7 +157,7 @@ impl MixNodeRewarding {
         let initial_dec = original_pledge.amount.into_base_decimal()?;
         if initial_dec > self.operator {
             panic!(
-                "seems slashing has occurred while it has not been implemented nor accounted for!"
+                "the operator has been slashed without its original pledge getting adjusted accordingly!"
             )
         }
         Ok(self.operator - initial_dec)
@@ -177,7 +179,7 @@ impl MixNodeRewarding {
         let initial_dec = original_pledge.amount.into_base_decimal()?;
         if initial_dec > self.operator {
             panic!(
-                "seems slashing has occurred while it has not been implemented nor accounted for!"
+                "the operator has been slashed without its original pledge getting adjusted accordingly!"
             )
         }
         let diff = self.operator - initial_dec;
@@ -197,6 +199,47 @@ impl MixNodeRewarding {
         Ok(truncate_reward(reward, &delegation.amount.denom))
     }
 
+    /// Folds the reward accumulated by the delegation into its base amount.
+    /// Returns the amount of tokens that got compounded.
+    pub fn compound_delegator_reward(
+        &mut self,
+        delegation: &mut Delegation,
+    ) -> Result<Coin, MixnetContractError> {
+        let reward = self.withdraw_delegator_reward(delegation)?;
+        self.increase_delegates_uint128(reward.amount)?;
+        delegation.amount.amount += reward.amount;
+        Ok(reward)
+    }
+
+    /// Removes the specified portion of the operator's stake, including its unclaimed rewards.
+    /// Returns the amount that got removed.
+    pub fn slash_operator(&mut self, slash_percent: Percent) -> Decimal {
+        let slashed = slash_percent * self.operator;
+        self.operator -= slashed;
+        slashed
+    }
+
+    /// Removes the specified portion of the delegation, including its unclaimed rewards,
+    /// and folds whatever remains into its base amount.
+    /// Returns the amount that got removed.
+    pub fn slash_delegation(
+        &mut self,
+        delegation: &mut Delegation,
+        slash_percent: Percent,
+    ) -> Result<Decimal, MixnetContractError> {
+        let reward = self.determine_delegation_reward(delegation)?;
+        let full_amount = reward + delegation.dec_amount()?;
+
+        // any decimal dust is slashed alongside the rest
+        let remaining = truncate_reward_amount(full_amount - slash_percent * full_amount);
+        let slashed = full_amount - remaining.into_base_decimal()?;
+        self.decrease_delegates_decimal(slashed)?;
+
+        delegation.amount.amount = remaining;
+        delegation.cumulative_reward_ratio = self.full_reward_ratio();
+        Ok(slashed)
+    }
+
     pub fn node_bond(&self) -> Decimal {
         self.operator + self.delegates
     }
@@ -230,11 +273,7 @@ impl MixNodeRewarding {
         reward_params: &RewardingParams,
         node_params: NodeRewardParams,
     ) -> Decimal {
-        let work = if node_params.in_active_set {
-            reward_params.active_node_work()
-        } else {
-            reward_params.standby_node_work()
-        };
+        let work = reward_params.node_work(node_params.in_active_set);
 
         let alpha = reward_params.interval.sybil_resistance;
 
@@ -597,6 +636,27 @@ pub enum Layer {
     One = 1,
     Two = 2,
     Three = 3,
+    Four = 4,
+}
+
+/// Number of mix layers used by the network unless configured otherwise in the contract state.
+pub const DEFAULT_MIX_LAYERS: u8 = 3;
+
+/// Minimum number of mix layers the network can be configured with.
+pub const MIN_MIX_LAYERS: u8 = 3;
+
+/// Maximum number of mix layers the network can be configured with.
+/// It's bound by the sphinx `MAX_PATH_LENGTH` (5) as every route also has to include the egress gateway.
+/// Note: supporting 5 mix layers requires a `sphinx-packet` release with a longer maximum path.
+/// Since the path length determines the size of every sphinx header, that's a packet format change
+/// that all the nodes and clients have to be upgraded to before this value could be increased.
+pub const MAX_MIX_LAYERS: u8 = 4;
+
+impl Layer {
+    /// Returns all layers, in order, of a network consisting of `mix_layers` layers.
+    pub fn all(mix_layers: u8) -> impl Iterator<Item = Layer> {
+        (1..=mix_layers.min(MAX_MIX_LAYERS)).filter_map(|layer| Layer::try_from(layer).ok())
+    }
 }
 
 impl From<Layer> for String {
@@ -613,6 +673,7 @@ impl TryFrom<u8> for Layer {
             1 => Ok(Layer::One),
             2 => Ok(Layer::Two),
             3 => Ok(Layer::Three),
+            4 => Ok(Layer::Four),
             _ => Err(MixnetContractError::InvalidLayer(i)),
         }
     }
@@ -624,6 +685,7 @@ impl From<Layer> for u8 {
             Layer::One => 1,
             Layer::Two => 2,
             Layer::Three => 3,
+            Layer::Four => 4,
         }
     }
 }
@@ -637,12 +699,17 @@ impl From<Layer> for u8 {
 pub struct PendingMixNodeChanges {
     pub pledge_change: Option<EpochEventId>,
     // pub cost_params_change: Option<IntervalEventId>,
+    /// Id of the slashing that is going to be applied to this node at the end of the current epoch.
+    /// It takes precedence over any pending pledge change.
+    #[serde(default)]
+    pub slashing: Option<SlashingId>,
 }
 
 impl PendingMixNodeChanges {
     pub fn new_empty() -> PendingMixNodeChanges {
         PendingMixNodeChanges {
             pledge_change: None,
+            slashing: None,
         }
     }
 }
@@ -4,15 +4,16 @@
 use crate::delegation::OwnerProxySubKey;
 use crate::error::MixnetContractError;
 use crate::families::FamilyHead;
-use crate::gateway::GatewayConfigUpdate;
+use crate::gateway::{GatewayConfigUpdate, GatewayRewardingParams};
 use crate::helpers::IntoBaseDecimal;
 use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{
     IntervalRewardParams, IntervalRewardingParamsUpdate, Performance, RewardingParams,
 };
+use crate::slashing::SlashingEvidence;
 use crate::{
-    delegation, ContractStateParams, EpochEventId, IntervalEventId, Layer, LayerAssignment, MixId,
-    Percent,
+    delegation, ContractStateParams, EpochEventId, EpochId, IntervalEventId, Layer,
+    LayerAssignment, MixId, Percent, SlashingId,
 };
 use crate::{Gateway, IdentityKey, MixNode};
 use contracts_common::signing::MessageSignature;
@@ -211,6 +212,9 @@ pub enum ExecuteMsg {
         new_config: GatewayConfigUpdate,
         owner: String,
     },
+    UpdateGatewayRewardingParams {
+        updated_params: GatewayRewardingParams,
+    },
 
     // delegation-related:
     DelegateToMixnode {
@@ -227,6 +231,31 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         delegate: String,
     },
+    RedelegateFromMixnode {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    },
+    RedelegateFromMixnodeOnBehalf {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        delegate: String,
+    },
+    DelegateToGateway {
+        identity: IdentityKey,
+    },
+    DelegateToGatewayOnBehalf {
+        identity: IdentityKey,
+        delegate: String,
+    },
+    UndelegateFromGateway {
+        identity: IdentityKey,
+    },
+    UndelegateFromGatewayOnBehalf {
+        identity: IdentityKey,
+        delegate: String,
+    },
 
     // reward-related
     RewardMixnode {
@@ -244,6 +273,39 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         owner: String,
     },
+    SetDelegationAutoCompounding {
+        mix_id: MixId,
+        enabled: bool,
+    },
+    SetDelegationAutoCompoundingOnBehalf {
+        mix_id: MixId,
+        enabled: bool,
+        owner: String,
+    },
+    RewardGateway {
+        identity: IdentityKey,
+        performance: Performance,
+    },
+    WithdrawGatewayOperatorReward {},
+    WithdrawGatewayDelegatorReward {
+        identity: IdentityKey,
+    },
+
+    // slashing-related
+    SlashMixnode {
+        mix_id: MixId,
+        slash_percent: Percent,
+        evidence: SlashingEvidence,
+        // if set, the slashed tokens are put back into the reward pool rather than being burned
+        redistribute: bool,
+    },
+    SlashGateway {
+        identity: IdentityKey,
+        slash_percent: Percent,
+        evidence: SlashingEvidence,
+        // if set, the slashed tokens are put back into the reward pool rather than being burned
+        redistribute: bool,
+    },
 
     // testing-only
     #[cfg(feature = "contract-testing")]
@@ -333,6 +395,9 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateGatewayConfigOnBehalf { .. } => {
                 "updating gateway configuration on behalf".into()
             }
+            ExecuteMsg::UpdateGatewayRewardingParams { .. } => {
+                "updating gateway rewarding parameters".into()
+            }
             ExecuteMsg::DelegateToMixnode { mix_id } => format!("delegating to mixnode {mix_id}"),
             ExecuteMsg::DelegateToMixnodeOnBehalf { mix_id, .. } => {
                 format!("delegating to mixnode {mix_id} on behalf")
@@ -343,6 +408,29 @@ impl ExecuteMsg {
             ExecuteMsg::UndelegateFromMixnodeOnBehalf { mix_id, .. } => {
                 format!("removing delegation from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::RedelegateFromMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount,
+            } => format!("redelegating {amount} from mixnode {from_mix_id} to mixnode {to_mix_id}"),
+            ExecuteMsg::RedelegateFromMixnodeOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                amount,
+                ..
+            } => format!(
+                "redelegating {amount} from mixnode {from_mix_id} to mixnode {to_mix_id} on behalf"
+            ),
+            ExecuteMsg::DelegateToGateway { identity } => format!("delegating to gateway {identity}"),
+            ExecuteMsg::DelegateToGatewayOnBehalf { identity, .. } => {
+                format!("delegating to gateway {identity} on behalf")
+            }
+            ExecuteMsg::UndelegateFromGateway { identity } => {
+                format!("removing delegation from gateway {identity}")
+            }
+            ExecuteMsg::UndelegateFromGatewayOnBehalf { identity, .. } => {
+                format!("removing delegation from gateway {identity} on behalf")
+            }
             ExecuteMsg::RewardMixnode {
                 mix_id,
                 performance,
@@ -357,6 +445,34 @@ impl ExecuteMsg {
             ExecuteMsg::WithdrawDelegatorRewardOnBehalf { mix_id, .. } => {
                 format!("withdrawing delegator reward from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::SetDelegationAutoCompounding { mix_id, enabled } => {
+                format!("setting reward auto-compounding of delegation to mixnode {mix_id} to {enabled}")
+            }
+            ExecuteMsg::SetDelegationAutoCompoundingOnBehalf {
+                mix_id, enabled, ..
+            } => format!(
+                "setting reward auto-compounding of delegation to mixnode {mix_id} to {enabled} on behalf"
+            ),
+            ExecuteMsg::RewardGateway {
+                identity,
+                performance,
+            } => format!("rewarding gateway {identity} for performance {performance}"),
+            ExecuteMsg::WithdrawGatewayOperatorReward {} => {
+                "withdrawing gateway operator reward".into()
+            }
+            ExecuteMsg::WithdrawGatewayDelegatorReward { identity } => {
+                format!("withdrawing delegator reward from gateway {identity}")
+            }
+            ExecuteMsg::SlashMixnode {
+                mix_id,
+                slash_percent,
+                ..
+            } => format!("slashing mixnode {mix_id} by {slash_percent}"),
+            ExecuteMsg::SlashGateway {
+                identity,
+                slash_percent,
+                ..
+            } => format!("slashing gateway {identity} by {slash_percent}"),
             #[cfg(feature = "contract-testing")]
             ExecuteMsg::TestingResolveAllPendingEvents { .. } => {
                 "resolving all pending events".into()
@@ -457,6 +573,10 @@ pub enum QueryMsg {
     GetOwnedGateway {
         address: String,
     },
+    GetGatewayRewardingParams {},
+    GetGatewayRewardingDetails {
+        identity: IdentityKey,
+    },
 
     // delegation-related:
     // gets all [paged] delegations associated with particular mixnode
@@ -486,6 +606,23 @@ pub enum QueryMsg {
         start_after: Option<delegation::StorageKey>,
         limit: Option<u32>,
     },
+    // gets all [paged] delegations associated with particular gateway
+    GetGatewayDelegations {
+        identity: IdentityKey,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] gateway delegations associated with particular delegator
+    GetDelegatorGatewayDelegations {
+        delegator: String,
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
+    // gets delegation associated with particular gateway, delegator pair
+    GetGatewayDelegationDetails {
+        identity: IdentityKey,
+        delegator: String,
+    },
 
     // rewards related
     GetPendingOperatorReward {
@@ -510,6 +647,19 @@ pub enum QueryMsg {
         proxy: Option<String>,
         estimated_performance: Performance,
     },
+    GetPendingGatewayOperatorReward {
+        address: String,
+    },
+    GetPendingGatewayDelegatorReward {
+        address: String,
+        identity: IdentityKey,
+    },
+    // gets the [paged] per-epoch rewarding history of particular mixnode
+    GetMixnodeRewardHistory {
+        mix_id: MixId,
+        limit: Option<u32>,
+        start_after: Option<EpochId>,
+    },
 
     // interval-related
     GetPendingEpochEvents {
@@ -528,6 +678,25 @@ pub enum QueryMsg {
     },
     GetNumberOfPendingEvents {},
 
+    // slashing-related
+    GetSlashingRecord {
+        slashing_id: SlashingId,
+    },
+    GetSlashingHistory {
+        limit: Option<u32>,
+        start_after: Option<SlashingId>,
+    },
+    GetMixnodeSlashingHistory {
+        mix_id: MixId,
+        limit: Option<u32>,
+        start_after: Option<SlashingId>,
+    },
+    GetGatewaySlashingHistory {
+        identity: IdentityKey,
+        limit: Option<u32>,
+        start_after: Option<SlashingId>,
+    },
+
     // signing-related
     GetSigningNonce {
         address: String,
@@ -3,7 +3,7 @@
 
 use crate::mixnode::MixNodeCostParams;
 use crate::reward_params::IntervalRewardingParamsUpdate;
-use crate::{BlockHeight, EpochEventId, IntervalEventId, MixId};
+use crate::{BlockHeight, EpochEventId, IdentityKey, IntervalEventId, MixId, SlashingId};
 use cosmwasm_std::{Addr, Coin};
 use serde::{Deserialize, Serialize};
 
@@ -34,6 +34,13 @@ pub enum PendingEpochEventKind {
         mix_id: MixId,
         proxy: Option<Addr>,
     },
+    Redelegate {
+        owner: Addr,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        proxy: Option<Addr>,
+    },
     PledgeMore {
         mix_id: MixId,
         amount: Coin,
@@ -48,6 +55,25 @@ pub enum PendingEpochEventKind {
     UpdateActiveSetSize {
         new_size: u32,
     },
+    DelegateToGateway {
+        owner: Addr,
+        identity: IdentityKey,
+        amount: Coin,
+        proxy: Option<Addr>,
+    },
+    UndelegateFromGateway {
+        owner: Addr,
+        identity: IdentityKey,
+        proxy: Option<Addr>,
+    },
+    SlashMixnode {
+        mix_id: MixId,
+        slashing_id: SlashingId,
+    },
+    SlashGateway {
+        identity: IdentityKey,
+        slashing_id: SlashingId,
+    },
 }
 
 impl PendingEpochEventKind {
@@ -93,6 +93,14 @@ pub struct RewardingParams {
 }
 
 impl RewardingParams {
+    pub fn node_work(&self, in_active_set: bool) -> Decimal {
+        if in_active_set {
+            self.active_node_work()
+        } else {
+            self.standby_node_work()
+        }
+    }
+
     pub fn active_node_work(&self) -> Decimal {
         self.interval.active_set_work_factor * self.standby_node_work()
     }
@@ -1,6 +1,8 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::reward_params::Performance;
+use crate::{EpochId, MixId};
 use cosmwasm_std::{Coin, Decimal};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -35,6 +37,32 @@ pub struct RewardDistribution {
     pub delegates: Decimal,
 }
 
+/// Result of rewarding a mixnode for a single epoch.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct EpochRewardRecord {
+    /// Absolute id of the epoch for which the node got rewarded.
+    pub absolute_epoch_id: EpochId,
+
+    /// Reward distributed to the operator (including the operating cost).
+    pub operator: Decimal,
+
+    /// Reward distributed to all of the node's delegators.
+    pub delegates: Decimal,
+
+    /// Performance of the node during the epoch.
+    pub performance: Performance,
+
+    /// Work factor of the node during the epoch, i.e. whether it was in the active or the standby set.
+    pub work_factor: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct PagedMixnodeRewardHistoryResponse {
+    pub mix_id: MixId,
+    pub records: Vec<EpochRewardRecord>,
+    pub start_next_after: Option<EpochId>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
 pub struct PendingRewardResponse {
     pub amount_staked: Option<Coin>,
@@ -46,6 +74,16 @@ pub struct PendingRewardResponse {
     pub mixnode_still_fully_bonded: bool,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub struct PendingGatewayRewardResponse {
+    pub amount_staked: Option<Coin>,
+    pub amount_earned: Option<Coin>,
+    pub amount_earned_detailed: Option<Decimal>,
+
+    /// The associated gateway is still bonded.
+    pub gateway_still_bonded: bool,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
 pub struct EstimatedCurrentEpochRewardResponse {
     pub original_stake: Option<Coin>,
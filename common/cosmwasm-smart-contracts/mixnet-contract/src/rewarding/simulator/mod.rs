@@ -3,20 +3,25 @@
 
 use crate::error::MixnetContractError;
 use crate::helpers::IntoBaseDecimal;
-use crate::reward_params::NodeRewardParams;
+use crate::reward_params::{NodeRewardParams, Performance};
+use crate::rewarding::simulator::simulated_gateway::SimulatedGateway;
 use crate::rewarding::simulator::simulated_node::SimulatedNode;
 use crate::rewarding::RewardDistribution;
 use crate::{
-    Delegation, Interval, IntervalRewardParams, MixId, MixNodeCostParams, RewardingParams,
+    Delegation, GatewayRewardingParams, IdentityKey, Interval, IntervalRewardParams, MixId,
+    MixNodeCostParams, RewardingParams,
 };
 use cosmwasm_std::{Coin, Decimal};
 use std::collections::BTreeMap;
 
+pub mod simulated_gateway;
 pub mod simulated_node;
 
 pub struct Simulator {
     pub nodes: BTreeMap<MixId, SimulatedNode>,
+    pub gateways: BTreeMap<IdentityKey, SimulatedGateway>,
     pub system_rewarding_params: RewardingParams,
+    pub gateway_rewarding_params: GatewayRewardingParams,
     pub interval: Interval,
 
     next_mix_id: MixId,
@@ -27,6 +32,10 @@ impl Simulator {
     pub fn new(system_rewarding_params: RewardingParams, interval: Interval) -> Self {
         Simulator {
             nodes: Default::default(),
+            gateways: Default::default(),
+            gateway_rewarding_params: GatewayRewardingParams::new_disabled(
+                system_rewarding_params.interval.stake_saturation_point,
+            ),
             system_rewarding_params,
             interval,
             next_mix_id: 0,
@@ -128,6 +137,103 @@ impl Simulator {
         node.undelegate(delegator)
     }
 
+    pub fn set_auto_compounding<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        mix_id: MixId,
+        enabled: bool,
+    ) -> Result<(), MixnetContractError> {
+        let node = self
+            .nodes
+            .get_mut(&mix_id)
+            .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+        node.set_auto_compounding(delegator, enabled)
+    }
+
+    pub fn bond_gateway<S: Into<IdentityKey>>(
+        &mut self,
+        identity: S,
+        pledge: Coin,
+    ) -> Result<(), MixnetContractError> {
+        let identity = identity.into();
+        let gateway = SimulatedGateway::new(
+            identity.clone(),
+            &pledge,
+            self.interval.current_epoch_absolute_id(),
+        )?;
+        self.gateways.insert(identity, gateway);
+        Ok(())
+    }
+
+    pub fn delegate_to_gateway<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        delegation: Coin,
+        identity: &str,
+    ) -> Result<(), MixnetContractError> {
+        let gateway =
+            self.gateways
+                .get_mut(identity)
+                .ok_or(MixnetContractError::GatewayBondNotFound {
+                    identity: identity.to_string(),
+                })?;
+        gateway.delegate(delegator, delegation)
+    }
+
+    pub fn undelegate_from_gateway<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        identity: &str,
+    ) -> Result<(Coin, Coin), MixnetContractError> {
+        let gateway =
+            self.gateways
+                .get_mut(identity)
+                .ok_or(MixnetContractError::GatewayBondNotFound {
+                    identity: identity.to_string(),
+                })?;
+        gateway.undelegate(delegator)
+    }
+
+    /// Rewards all the gateways for the current epoch without advancing it,
+    /// so it's expected to be followed by `simulate_epoch`, just like the contract
+    /// expects gateways to be rewarded before the epoch is advanced.
+    pub fn simulate_gateway_rewarding(
+        &mut self,
+        gateway_performance: &BTreeMap<IdentityKey, Performance>,
+    ) -> Result<BTreeMap<IdentityKey, RewardDistribution>, MixnetContractError> {
+        if gateway_performance.len() != self.gateways.len()
+            || !gateway_performance
+                .keys()
+                .all(|identity| self.gateways.contains_key(identity))
+        {
+            panic!("invalid gateway performance provided");
+        }
+
+        let mut dist = BTreeMap::new();
+        let epoch_reward_per_gateway = self.gateway_rewarding_params.epoch_reward_per_gateway(
+            self.system_rewarding_params.interval.epoch_reward_budget,
+            self.gateways.len() as u32,
+        );
+
+        for (identity, gateway) in self.gateways.iter_mut() {
+            let reward_distribution = gateway.rewarding_details.calculate_epoch_reward(
+                &self.gateway_rewarding_params,
+                epoch_reward_per_gateway,
+                gateway_performance[identity],
+            );
+            gateway.rewarding_details.distribute_rewards(
+                reward_distribution,
+                self.interval.current_epoch_absolute_id(),
+            );
+            self.pending_reward_pool_emission += reward_distribution.operator;
+            self.pending_reward_pool_emission += reward_distribution.delegates;
+
+            dist.insert(identity.clone(), reward_distribution);
+        }
+
+        Ok(dist)
+    }
+
     pub fn simulate_epoch_single_node(
         &mut self,
         params: NodeRewardParams,
@@ -159,11 +265,17 @@ impl Simulator {
             panic!("invalid node rewarding params provided");
         }
 
+        // just like in the contract, the gateway reward pool is not available for mixnodes
+        let mut mixnode_rewarding_params = self.system_rewarding_params;
+        mixnode_rewarding_params.interval.epoch_reward_budget = self
+            .gateway_rewarding_params
+            .mixnode_epoch_reward_budget(self.system_rewarding_params.interval.epoch_reward_budget);
+
         let mut dist = BTreeMap::new();
 
         for (mix_id, node) in self.nodes.iter_mut() {
             let reward_distribution = node.rewarding_details.calculate_epoch_reward(
-                &self.system_rewarding_params,
+                &mixnode_rewarding_params,
                 node_params[mix_id],
                 self.interval.epochs_in_interval(),
             );
@@ -174,6 +286,9 @@ impl Simulator {
             self.pending_reward_pool_emission += reward_distribution.operator;
             self.pending_reward_pool_emission += reward_distribution.delegates;
 
+            // just like the contract does it during epoch reconciliation
+            node.compound_rewards()?;
+
             dist.insert(*mix_id, reward_distribution);
         }
 
@@ -525,6 +640,65 @@ mod tests {
             );
         }
 
+        #[test]
+        fn auto_compounding_delegator_reward() {
+            let mut simulator = base_simulator(10000_000000);
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+
+            simulator
+                .delegate("alice", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .delegate("bob", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator.set_auto_compounding("alice", 0, true).unwrap();
+            assert!(simulator.set_auto_compounding("carol", 0, true).is_err());
+
+            let epochs = 10;
+            for _ in 0..epochs {
+                simulator.simulate_epoch_single_node(node_params).unwrap();
+                check_rewarding_invariant(&simulator);
+            }
+
+            let node = &simulator.nodes[&0];
+            let alice = &node.delegations["alice"];
+            let bob = &node.delegations["bob"];
+
+            // alice's rewards got folded into her delegation whilst bob's are still pending
+            assert!(alice.amount.amount.u128() > 18000_000000);
+            assert_eq!(bob.amount.amount.u128(), 18000_000000);
+            assert_eq!(
+                node.rewarding_details
+                    .determine_delegation_reward(alice)
+                    .unwrap(),
+                Decimal::zero()
+            );
+
+            // and compounding is equivalent to lazily accumulating the rewards
+            // (apart from the truncated decimal dust)
+            let bob_reward = node
+                .rewarding_details
+                .determine_delegation_reward(bob)
+                .unwrap();
+            compare_decimals(
+                alice.dec_amount().unwrap(),
+                bob.dec_amount().unwrap() + bob_reward,
+                Some(Decimal::from_atomics(epochs as u128, 0).unwrap()),
+            );
+
+            // disabling it stops the compounding
+            let compounded = alice.amount.clone();
+            simulator.set_auto_compounding("alice", 0, false).unwrap();
+            simulator.simulate_epoch_single_node(node_params).unwrap();
+            let node = &simulator.nodes[&0];
+            assert_eq!(node.delegations["alice"].amount, compounded);
+            check_rewarding_invariant(&simulator);
+
+            let (delegation, _reward) = simulator.undelegate("alice", 0).unwrap();
+            assert_eq!(delegation, compounded);
+        }
+
         #[test]
         fn simulating_multiple_epochs() {
             let mut simulator = base_simulator(10000_000000);
@@ -596,6 +770,117 @@ mod tests {
             let node = &simulator.nodes[&0];
             assert_eq!(Decimal::zero(), node.rewarding_details.delegates);
         }
+
+        #[test]
+        fn total_epoch_emission_does_not_exceed_the_budget() {
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+            let mut without_gateways = base_simulator(10000_000000);
+            let mixnode_only_reward = without_gateways
+                .simulate_epoch_single_node(node_params)
+                .unwrap();
+
+            let mut simulator = base_simulator(10000_000000);
+            let reward_pool_share = Percent::from_percentage_value(20).unwrap();
+            simulator.gateway_rewarding_params = GatewayRewardingParams {
+                reward_pool_share,
+                stake_saturation_point: Decimal::from_atomics(100_000_000_000u128, 0).unwrap(),
+            };
+            let epoch_reward_budget = simulator
+                .system_rewarding_params
+                .interval
+                .epoch_reward_budget;
+
+            let mut performance = BTreeMap::new();
+            for i in 0..5 {
+                let identity = format!("gateway{i}");
+                simulator
+                    .bond_gateway(identity.clone(), Coin::new(100_000_000000, "unym"))
+                    .unwrap();
+                performance.insert(identity, Percent::from_percentage_value(100).unwrap());
+            }
+
+            // fully saturated gateways with perfect performance use up the entire gateway pool
+            simulator.simulate_gateway_rewarding(&performance).unwrap();
+            compare_decimals(
+                simulator.pending_reward_pool_emission,
+                reward_pool_share * epoch_reward_budget,
+                None,
+            );
+
+            // and the pool is taken out of the budget available for mixnodes
+            let node_rewards = simulator.simulate_epoch_single_node(node_params).unwrap();
+            compare_decimals(
+                node_rewards.operator + node_rewards.delegates,
+                (Decimal::one() - reward_pool_share.value())
+                    * (mixnode_only_reward.operator + mixnode_only_reward.delegates),
+                None,
+            );
+            assert!(simulator.pending_reward_pool_emission <= epoch_reward_budget);
+            assert_eq!(
+                simulator
+                    .gateway_rewarding_params
+                    .mixnode_epoch_reward_budget(epoch_reward_budget)
+                    + reward_pool_share * epoch_reward_budget,
+                epoch_reward_budget
+            );
+        }
+
+        #[test]
+        fn gateway_rewarding_and_delegation() {
+            let mut simulator = base_simulator(10000_000000);
+            simulator.gateway_rewarding_params = GatewayRewardingParams {
+                reward_pool_share: Percent::from_percentage_value(10).unwrap(),
+                stake_saturation_point: Decimal::from_atomics(100_000_000_000u128, 0).unwrap(),
+            };
+            let gateway_pool = Percent::from_percentage_value(10).unwrap()
+                * simulator
+                    .system_rewarding_params
+                    .interval
+                    .epoch_reward_budget;
+            simulator
+                .bond_gateway("gateway", Coin::new(25000_000000, "unym"))
+                .unwrap();
+            simulator
+                .delegate_to_gateway("alice", Coin::new(25000_000000, "unym"), "gateway")
+                .unwrap();
+
+            let mut performance = BTreeMap::new();
+            performance.insert(
+                "gateway".to_string(),
+                Percent::from_percentage_value(100).unwrap(),
+            );
+
+            // half-saturated gateway with equal split between the operator and the delegator,
+            // while being the only gateway, it gets the entire pool if it's fully saturated
+            let rewards = simulator.simulate_gateway_rewarding(&performance).unwrap();
+            let expected = gateway_pool / Decimal::from_atomics(4u32, 0).unwrap();
+            assert!(!expected.is_zero());
+            assert_eq!(rewards["gateway"].operator, expected);
+            assert_eq!(rewards["gateway"].delegates, expected);
+
+            // gateway rewards are accounted for in the reward pool emission
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+            let node_rewards = simulator.simulate_epoch_single_node(node_params).unwrap();
+            assert_eq!(
+                simulator.pending_reward_pool_emission,
+                expected + expected + node_rewards.operator
+            );
+
+            let (delegation, reward) = simulator
+                .undelegate_from_gateway("alice", "gateway")
+                .unwrap();
+            assert_eq!(delegation.amount.u128(), 25000_000000);
+            assert_eq!(reward.amount, truncate_reward_amount(expected));
+
+            let gateway = &simulator.gateways["gateway"].rewarding_details;
+            assert_eq!(gateway.delegates, Decimal::zero());
+            assert_eq!(
+                gateway.operator,
+                Decimal::from_atomics(25000_000000u128, 0).unwrap() + expected
+            );
+        }
     }
 
     #[test]
@@ -0,0 +1,72 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{EpochId, GatewayDelegation, GatewayRewarding, IdentityKey};
+use cosmwasm_std::{Addr, Coin};
+use std::collections::HashMap;
+
+use crate::error::MixnetContractError;
+
+pub struct SimulatedGateway {
+    pub identity: IdentityKey,
+    pub rewarding_details: GatewayRewarding,
+    pub delegations: HashMap<String, GatewayDelegation>,
+}
+
+impl SimulatedGateway {
+    pub fn new(
+        identity: IdentityKey,
+        initial_pledge: &Coin,
+        current_epoch: EpochId,
+    ) -> Result<Self, MixnetContractError> {
+        Ok(SimulatedGateway {
+            identity,
+            rewarding_details: GatewayRewarding::initialise_new(initial_pledge, current_epoch)?,
+            delegations: HashMap::new(),
+        })
+    }
+
+    pub fn delegate<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        delegation: Coin,
+    ) -> Result<(), MixnetContractError> {
+        self.rewarding_details
+            .add_base_delegation(delegation.amount)?;
+
+        let delegator = delegator.into();
+        let delegation = GatewayDelegation::new(
+            Addr::unchecked(&delegator),
+            self.identity.clone(),
+            self.rewarding_details.total_unit_reward,
+            delegation,
+            42,
+            None,
+        );
+
+        self.delegations.insert(delegator, delegation);
+        Ok(())
+    }
+
+    pub fn undelegate<S: Into<String>>(
+        &mut self,
+        delegator: S,
+    ) -> Result<(Coin, Coin), MixnetContractError> {
+        let delegator = delegator.into();
+        let delegation = self.delegations.remove(&delegator).ok_or(
+            MixnetContractError::NoGatewayDelegationFound {
+                identity: self.identity.clone(),
+                address: delegator,
+            },
+        )?;
+
+        // this also moves any leftover dust to the operator if it was the last delegation
+        let returned = self.rewarding_details.undelegate(&delegation)?;
+        let reward = Coin {
+            denom: returned.denom,
+            amount: returned.amount - delegation.amount.amount,
+        };
+
+        Ok((delegation.amount, reward))
+    }
+}
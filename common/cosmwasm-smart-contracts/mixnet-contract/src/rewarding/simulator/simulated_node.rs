@@ -3,7 +3,7 @@
 
 use crate::{Delegation, EpochId, MixId, MixNodeCostParams, MixNodeRewarding};
 use cosmwasm_std::{Addr, Coin};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::error::MixnetContractError;
 use crate::rewarding::helpers::truncate_reward;
@@ -12,6 +12,9 @@ pub struct SimulatedNode {
     pub mix_id: MixId,
     pub rewarding_details: MixNodeRewarding,
     pub delegations: HashMap<String, Delegation>,
+
+    /// Delegators whose rewards are folded into their delegations at the end of every epoch.
+    pub auto_compounding: HashSet<String>,
 }
 
 impl SimulatedNode {
@@ -29,6 +32,7 @@ impl SimulatedNode {
                 current_epoch,
             )?,
             delegations: HashMap::new(),
+            auto_compounding: HashSet::new(),
         })
     }
 
@@ -59,6 +63,7 @@ impl SimulatedNode {
         delegator: S,
     ) -> Result<(Coin, Coin), MixnetContractError> {
         let delegator = delegator.into();
+        self.auto_compounding.remove(&delegator);
         let delegation = self.delegations.remove(&delegator).ok_or(
             MixnetContractError::NoMixnodeDelegationFound {
                 mix_id: MixId::MAX,
@@ -78,4 +83,37 @@ impl SimulatedNode {
 
         Ok((delegation.amount, truncated_reward))
     }
+
+    pub fn set_auto_compounding<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        enabled: bool,
+    ) -> Result<(), MixnetContractError> {
+        let delegator = delegator.into();
+        if !self.delegations.contains_key(&delegator) {
+            return Err(MixnetContractError::NoMixnodeDelegationFound {
+                mix_id: self.mix_id,
+                address: delegator,
+                proxy: None,
+            });
+        }
+
+        if enabled {
+            self.auto_compounding.insert(delegator);
+        } else {
+            self.auto_compounding.remove(&delegator);
+        }
+        Ok(())
+    }
+
+    /// Folds the pending rewards of all auto-compounding delegations into their base amounts.
+    pub fn compound_rewards(&mut self) -> Result<(), MixnetContractError> {
+        for delegator in &self.auto_compounding {
+            if let Some(delegation) = self.delegations.get_mut(delegator) {
+                self.rewarding_details
+                    .compound_delegator_reward(delegation)?;
+            }
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,272 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::MixnetContractError;
+use crate::{BlockHeight, EpochId, IdentityKey, MixId, Percent, SlashingId};
+use cosmwasm_std::{Addr, Coin};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Proof of misbehaviour of a node submitted alongside the slashing request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashingEvidence {
+    /// The node has forwarded a sphinx packet it has already processed before,
+    /// as proven by two of its receipts for packets with the same replay tag.
+    ReplayedPacket {
+        /// Receipt of the original forwarding of the packet.
+        original: ForwardingReceipt,
+
+        /// Receipt of the forwarding of the replayed packet.
+        replayed: ForwardingReceipt,
+    },
+
+    /// The node has repeatedly failed the network monitor tests.
+    NetworkMonitorFailures {
+        /// Absolute ids of the epochs during which the node has failed the tests.
+        failed_epochs: Vec<EpochId>,
+    },
+}
+
+/// Statement signed by a node (with its identity key) upon forwarding a sphinx packet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ForwardingReceipt {
+    /// Base58-encoded replay tag of the forwarded packet.
+    pub replay_tag: String,
+
+    /// Absolute id of the epoch during which the packet has been forwarded.
+    pub epoch_id: EpochId,
+
+    /// Unix timestamp of the moment the packet has been forwarded.
+    pub timestamp: u64,
+
+    /// Base58-encoded ed25519 signature of the node over the [`ForwardingReceipt::plaintext`].
+    pub signature: String,
+}
+
+impl ForwardingReceipt {
+    /// Bytes a node signs upon forwarding a packet with the provided replay tag.
+    pub fn plaintext(replay_tag: &str, epoch_id: EpochId, timestamp: u64) -> Vec<u8> {
+        format!("forwarding-receipt:{epoch_id}:{timestamp}:{replay_tag}").into_bytes()
+    }
+
+    pub fn signed_plaintext(&self) -> Vec<u8> {
+        Self::plaintext(&self.replay_tag, self.epoch_id, self.timestamp)
+    }
+
+    fn validate(&self, current_epoch: EpochId) -> Result<(), MixnetContractError> {
+        if self.replay_tag.is_empty() || self.signature.is_empty() {
+            return Err(MixnetContractError::InvalidSlashingEvidence {
+                reason: "the replay tag and the signature of the receipt must be provided".into(),
+            });
+        }
+        if self.epoch_id > current_epoch {
+            return Err(MixnetContractError::InvalidSlashingEvidence {
+                reason: format!(
+                    "the receipt is from epoch {} which is in the future (current epoch is {current_epoch})",
+                    self.epoch_id
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl SlashingEvidence {
+    pub fn validate(&self, current_epoch: EpochId) -> Result<(), MixnetContractError> {
+        match self {
+            SlashingEvidence::ReplayedPacket { original, replayed } => {
+                original.validate(current_epoch)?;
+                replayed.validate(current_epoch)?;
+
+                if original.replay_tag != replayed.replay_tag {
+                    return Err(MixnetContractError::InvalidSlashingEvidence {
+                        reason: "the receipts are for packets with different replay tags".into(),
+                    });
+                }
+                // a single forwarding cannot be used as evidence of a replay
+                if original.epoch_id == replayed.epoch_id
+                    && original.timestamp == replayed.timestamp
+                {
+                    return Err(MixnetContractError::InvalidSlashingEvidence {
+                        reason: "the receipts do not refer to distinct forwardings of the packet"
+                            .into(),
+                    });
+                }
+            }
+            SlashingEvidence::NetworkMonitorFailures { failed_epochs } => {
+                if failed_epochs.is_empty() {
+                    return Err(MixnetContractError::InvalidSlashingEvidence {
+                        reason: "at least a single failed epoch must be provided".into(),
+                    });
+                }
+                if let Some(future_epoch) = failed_epochs.iter().find(|e| **e > current_epoch) {
+                    return Err(MixnetContractError::InvalidSlashingEvidence {
+                        reason: format!(
+                            "epoch {future_epoch} is in the future (current epoch is {current_epoch})"
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashingStatus {
+    /// The slashing has been accepted and is waiting for the end of the current epoch.
+    Pending,
+
+    /// The slashing has been applied to the node's pledge and all of its delegations.
+    Executed {
+        /// Absolute id of the epoch at the end of which the slashing got applied.
+        absolute_epoch_id: EpochId,
+
+        /// Amount of tokens removed from the operator (including its unclaimed rewards).
+        operator_slashed: Coin,
+
+        /// Amount of tokens removed from all the delegators (including their unclaimed rewards).
+        delegates_slashed: Coin,
+    },
+}
+
+impl SlashingStatus {
+    pub fn is_pending(&self) -> bool {
+        matches!(self, SlashingStatus::Pending)
+    }
+}
+
+/// The node targeted by the slashing request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashedNode {
+    Mixnode { mix_id: MixId },
+    Gateway { identity: IdentityKey },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct SlashingRecord {
+    pub id: SlashingId,
+    pub node: SlashedNode,
+
+    /// Address of the party that has submitted the slashing request.
+    pub submitted_by: Addr,
+
+    /// Block height at which the slashing request has been submitted.
+    pub submitted_at_height: BlockHeight,
+    pub evidence: SlashingEvidence,
+
+    /// Portion of the pledge and the delegations that gets removed.
+    pub slash_percent: Percent,
+
+    /// Indicates whether the slashed tokens are returned to the reward pool instead of being burned.
+    pub redistribute: bool,
+    pub status: SlashingStatus,
+}
+
+/// Ensures the provided slashing percentage is non-zero and does not wipe out the node entirely.
+pub fn validate_slash_percent(value: Percent) -> Result<(), MixnetContractError> {
+    if value.is_zero() || value == Percent::hundred() {
+        return Err(MixnetContractError::InvalidSlashingPercentage { value });
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct SlashingRecordResponse {
+    pub slashing_id: SlashingId,
+    pub record: Option<SlashingRecord>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct PagedSlashingHistoryResponse {
+    pub records: Vec<SlashingRecord>,
+    pub start_next_after: Option<SlashingId>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct PagedMixnodeSlashingHistoryResponse {
+    pub mix_id: MixId,
+    pub records: Vec<SlashingRecord>,
+    pub start_next_after: Option<SlashingId>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct PagedGatewaySlashingHistoryResponse {
+    pub identity: IdentityKey,
+    pub records: Vec<SlashingRecord>,
+    pub start_next_after: Option<SlashingId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slash_percent_validation() {
+        assert!(validate_slash_percent(Percent::zero()).is_err());
+        assert!(validate_slash_percent(Percent::hundred()).is_err());
+        assert!(validate_slash_percent(Percent::from_percentage_value(1).unwrap()).is_ok());
+        assert!(validate_slash_percent(Percent::from_percentage_value(99).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn evidence_validation() {
+        let receipt = |replay_tag: &str, epoch_id, timestamp, signature: &str| ForwardingReceipt {
+            replay_tag: replay_tag.to_string(),
+            epoch_id,
+            timestamp,
+            signature: signature.to_string(),
+        };
+        let replay = |original, replayed| SlashingEvidence::ReplayedPacket { original, replayed };
+
+        let original = receipt("tag", 9, 100, "signature");
+        assert!(replay(original.clone(), receipt("tag", 9, 100, ""))
+            .validate(10)
+            .is_err());
+        assert!(replay(original.clone(), receipt("", 9, 200, "signature"))
+            .validate(10)
+            .is_err());
+        assert!(
+            replay(original.clone(), receipt("other-tag", 9, 200, "signature"))
+                .validate(10)
+                .is_err()
+        );
+        assert!(
+            replay(original.clone(), receipt("tag", 11, 200, "signature"))
+                .validate(10)
+                .is_err()
+        );
+
+        // a single receipt is not enough
+        assert!(replay(original.clone(), original.clone())
+            .validate(10)
+            .is_err());
+
+        assert!(
+            replay(original.clone(), receipt("tag", 9, 200, "signature"))
+                .validate(10)
+                .is_ok()
+        );
+        assert!(replay(original, receipt("tag", 10, 100, "signature"))
+            .validate(10)
+            .is_ok());
+
+        let failures = SlashingEvidence::NetworkMonitorFailures {
+            failed_epochs: vec![],
+        };
+        assert!(failures.validate(10).is_err());
+
+        let failures = SlashingEvidence::NetworkMonitorFailures {
+            failed_epochs: vec![8, 9, 11],
+        };
+        assert!(failures.validate(10).is_err());
+
+        let failures = SlashingEvidence::NetworkMonitorFailures {
+            failed_epochs: vec![8, 9, 10],
+        };
+        assert!(failures.validate(10).is_ok());
+    }
+}
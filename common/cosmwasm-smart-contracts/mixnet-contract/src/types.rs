@@ -3,7 +3,7 @@
 
 use crate::error::MixnetContractError;
 use crate::families::{Family, FamilyHead};
-use crate::{Layer, RewardedSetNodeStatus};
+use crate::{Layer, RewardedSetNodeStatus, DEFAULT_MIX_LAYERS};
 use contracts_common::IdentityKey;
 use cosmwasm_std::Addr;
 use cosmwasm_std::Coin;
@@ -20,6 +20,7 @@ pub type MixId = u32;
 pub type BlockHeight = u64;
 pub type EpochEventId = u32;
 pub type IntervalEventId = u32;
+pub type SlashingId = u32;
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, PartialEq)]
@@ -47,60 +48,40 @@ pub struct LayerDistribution {
     pub layer1: u64,
     pub layer2: u64,
     pub layer3: u64,
+    #[serde(default)]
+    pub layer4: u64,
 }
 
 impl LayerDistribution {
-    pub fn choose_with_fewest(&self) -> Layer {
-        let layers = [
-            (Layer::One, self.layer1),
-            (Layer::Two, self.layer2),
-            (Layer::Three, self.layer3),
-        ];
-
-        // we explicitly put 3 elements into the iterator, so the iterator is DEFINITELY
-        // not empty and thus the unwrap cannot fail
-        #[allow(clippy::unwrap_used)]
-        layers.iter().min_by_key(|x| x.1).unwrap().0
+    /// Chooses the layer with the fewest nodes out of the first `mix_layers` layers.
+    pub fn choose_with_fewest(&self, mix_layers: u8) -> Layer {
+        // if no valid layer was provided, fallback to the first one
+        Layer::all(mix_layers)
+            .min_by_key(|layer| self[*layer])
+            .unwrap_or(Layer::One)
     }
 
-    pub fn increment_layer_count(&mut self, layer: Layer) {
+    fn layer_count_mut(&mut self, layer: Layer) -> &mut u64 {
         match layer {
-            Layer::One => self.layer1 += 1,
-            Layer::Two => self.layer2 += 1,
-            Layer::Three => self.layer3 += 1,
+            Layer::One => &mut self.layer1,
+            Layer::Two => &mut self.layer2,
+            Layer::Three => &mut self.layer3,
+            Layer::Four => &mut self.layer4,
         }
     }
 
+    pub fn increment_layer_count(&mut self, layer: Layer) {
+        *self.layer_count_mut(layer) += 1
+    }
+
     pub fn decrement_layer_count(&mut self, layer: Layer) -> Result<(), MixnetContractError> {
-        match layer {
-            Layer::One => {
-                self.layer1 =
-                    self.layer1
-                        .checked_sub(1)
-                        .ok_or(MixnetContractError::OverflowSubtraction {
-                            minuend: self.layer1,
-                            subtrahend: 1,
-                        })?
-            }
-            Layer::Two => {
-                self.layer2 =
-                    self.layer2
-                        .checked_sub(1)
-                        .ok_or(MixnetContractError::OverflowSubtraction {
-                            minuend: self.layer2,
-                            subtrahend: 1,
-                        })?
-            }
-            Layer::Three => {
-                self.layer3 =
-                    self.layer3
-                        .checked_sub(1)
-                        .ok_or(MixnetContractError::OverflowSubtraction {
-                            minuend: self.layer3,
-                            subtrahend: 1,
-                        })?
-            }
-        }
+        let count = self.layer_count_mut(layer);
+        *count = count
+            .checked_sub(1)
+            .ok_or(MixnetContractError::OverflowSubtraction {
+                minuend: *count,
+                subtrahend: 1,
+            })?;
 
         Ok(())
     }
@@ -114,6 +95,7 @@ impl Index<Layer> for LayerDistribution {
             Layer::One => &self.layer1,
             Layer::Two => &self.layer2,
             Layer::Three => &self.layer3,
+            Layer::Four => &self.layer4,
         }
     }
 }
@@ -140,6 +122,14 @@ pub struct ContractStateParams {
 
     /// Minimum amount a gateway must pledge to get into the system.
     pub minimum_gateway_pledge: Coin,
+
+    /// Number of mix layers every packet traverses before reaching its egress gateway.
+    #[serde(default = "default_mix_layers")]
+    pub mix_layers: u8,
+}
+
+fn default_mix_layers() -> u8 {
+    DEFAULT_MIX_LAYERS
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
@@ -69,6 +69,12 @@ pub enum NameServiceError {
 
     #[error("name already registered: {name}")]
     NameAlreadyRegistered { name: NymName },
+
+    #[error("registration of name {name} has expired")]
+    NameExpired { name: NymName },
+
+    #[error("funds can't be attached to this message")]
+    UnexpectedFunds { source: cw_utils::PaymentError },
 }
 
 pub type Result<T, E = NameServiceError> = std::result::Result<T, E>;
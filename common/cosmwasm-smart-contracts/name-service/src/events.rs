@@ -1,4 +1,4 @@
-use cosmwasm_std::{Coin, Event};
+use cosmwasm_std::{Addr, Coin, Event};
 
 use crate::RegisteredName;
 
@@ -6,6 +6,9 @@ pub enum NameEventType {
     Register,
     DeleteId,
     DeleteName,
+    Renew,
+    UpdateAddress,
+    Transfer,
     UpdateDepositRequired,
 }
 
@@ -15,6 +18,9 @@ impl std::fmt::Display for NameEventType {
             NameEventType::Register => write!(f, "register"),
             NameEventType::DeleteId => write!(f, "delete_id"),
             NameEventType::DeleteName => write!(f, "delete_name"),
+            NameEventType::Renew => write!(f, "renew"),
+            NameEventType::UpdateAddress => write!(f, "update_address"),
+            NameEventType::Transfer => write!(f, "transfer"),
             NameEventType::UpdateDepositRequired => write!(f, "update_deposit_required"),
         }
     }
@@ -31,6 +37,8 @@ pub const ACTION: &str = "action";
 pub const NAME_ID: &str = "name_id";
 pub const NAME: &str = "name";
 pub const OWNER: &str = "owner";
+pub const PREVIOUS_OWNER: &str = "previous_owner";
+pub const EXPIRES_AT: &str = "expires_at";
 
 pub const DEPOSIT_REQUIRED: &str = "deposit_required";
 
@@ -59,6 +67,31 @@ pub fn new_delete_name_event(name: RegisteredName) -> Event {
         .add_attribute(name.name.address.event_tag(), name.name.address.to_string())
 }
 
+pub fn new_renew_event(name: RegisteredName) -> Event {
+    Event::new(NameEventType::Renew)
+        .add_attribute(ACTION, NameEventType::Renew)
+        .add_attribute(NAME_ID, name.id.to_string())
+        .add_attribute(NAME, name.name.name.to_string())
+        .add_attribute(EXPIRES_AT, name.expires_at.to_string())
+}
+
+pub fn new_update_address_event(name: RegisteredName) -> Event {
+    Event::new(NameEventType::UpdateAddress)
+        .add_attribute(ACTION, NameEventType::UpdateAddress)
+        .add_attribute(NAME_ID, name.id.to_string())
+        .add_attribute(NAME, name.name.name.to_string())
+        .add_attribute(name.name.address.event_tag(), name.name.address.to_string())
+}
+
+pub fn new_transfer_event(name: RegisteredName, previous_owner: Addr) -> Event {
+    Event::new(NameEventType::Transfer)
+        .add_attribute(ACTION, NameEventType::Transfer)
+        .add_attribute(NAME_ID, name.id.to_string())
+        .add_attribute(NAME, name.name.name.to_string())
+        .add_attribute(PREVIOUS_OWNER, previous_owner.to_string())
+        .add_attribute(OWNER, name.owner.to_string())
+}
+
 pub fn new_update_deposit_required_event(deposit_required: Coin) -> Event {
     Event::new(NameEventType::UpdateDepositRequired)
         .add_attribute(ACTION, NameEventType::UpdateDepositRequired)
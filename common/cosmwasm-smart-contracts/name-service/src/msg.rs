@@ -1,17 +1,24 @@
 use crate::{Address, NameDetails, NameId, NymName};
 use cosmwasm_std::Coin;
-use nym_contracts_common::signing::MessageSignature;
+use nym_contracts_common::{signing::MessageSignature, IdentityKey};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct InstantiateMsg {
     pub deposit_required: Coin,
+    /// For how long (in seconds) the names are registered for before they have to be renewed.
+    /// If not set, the contract default is going to be used.
+    #[serde(default)]
+    pub registration_period: Option<u64>,
 }
 
 impl InstantiateMsg {
     pub fn new(deposit_required: Coin) -> Self {
-        Self { deposit_required }
+        Self {
+            deposit_required,
+            registration_period: None,
+        }
     }
 }
 
@@ -31,6 +38,18 @@ pub enum ExecuteMsg {
     DeleteId { name_id: NameId },
     /// Delete a name entry by name
     DeleteName { name: NymName },
+    /// Extend the registration of a name by another registration period
+    Renew { name_id: NameId },
+    /// Point an existing name at a new address. As with registering, the new address has to
+    /// sign off on it
+    UpdateAddress {
+        name_id: NameId,
+        address: Address,
+        identity_key: IdentityKey,
+        owner_signature: MessageSignature,
+    },
+    /// Transfer the ownership of a name, alongside its deposit, to a new owner
+    Transfer { name_id: NameId, new_owner: String },
     /// Change the deposit required for announcing a name
     UpdateDepositRequired { deposit_required: Coin },
 }
@@ -54,6 +73,17 @@ impl ExecuteMsg {
             ExecuteMsg::DeleteName { name } => {
                 format!("deleting name: {name}")
             }
+            ExecuteMsg::Renew { name_id } => {
+                format!("renewing name with id {name_id}")
+            }
+            ExecuteMsg::UpdateAddress {
+                name_id, address, ..
+            } => {
+                format!("pointing name with id {name_id} to {address}")
+            }
+            ExecuteMsg::Transfer { name_id, new_owner } => {
+                format!("transferring name with id {name_id} to {new_owner}")
+            }
             ExecuteMsg::UpdateDepositRequired { deposit_required } => {
                 format!("updating the deposit required to {deposit_required}")
             }
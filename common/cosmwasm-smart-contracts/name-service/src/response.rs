@@ -49,4 +49,6 @@ impl PagedNamesListResponse {
 #[serde(rename_all = "snake_case")]
 pub struct ConfigResponse {
     pub deposit_required: Coin,
+    #[serde(default)]
+    pub registration_period: u64,
 }
@@ -30,3 +30,28 @@ pub fn construct_name_register_sign_payload(
     let content = ContractMessageContent::new(sender, proxy, vec![deposit], payload);
     SignableMessage::new(nonce, content)
 }
+
+pub type SignableNameUpdateAddressMsg = SignableMessage<ContractMessageContent<NameUpdateAddress>>;
+
+#[derive(Serialize)]
+pub struct NameUpdateAddress {
+    name: NameDetails,
+}
+
+impl SigningPurpose for NameUpdateAddress {
+    fn message_type() -> MessageType {
+        MessageType::new("name-update-address")
+    }
+}
+
+/// Payload signed with the identity key of the new address the name is going to point at.
+pub fn construct_name_update_address_sign_payload(
+    nonce: Nonce,
+    sender: Addr,
+    name: NameDetails,
+) -> SignableNameUpdateAddressMsg {
+    let payload = NameUpdateAddress { name };
+    let proxy = None;
+    let content = ContractMessageContent::new(sender, proxy, vec![], payload);
+    SignableMessage::new(nonce, content)
+}
@@ -24,6 +24,11 @@ pub struct RegisteredName {
 
     /// The deposit used to announce the name.
     pub deposit: Coin,
+
+    /// Unix timestamp (in seconds) after which the registration is no longer valid, unless renewed.
+    // entries registered before expiry got introduced don't have it set until the contract is migrated
+    #[serde(default)]
+    pub expires_at: u64,
 }
 
 impl RegisteredName {
@@ -31,6 +36,11 @@ impl RegisteredName {
     pub fn entry(&self) -> &NymName {
         &self.name.name
     }
+
+    /// Checks whether the registration has expired at the provided unix timestamp (in seconds).
+    pub fn is_expired(&self, current_time: u64) -> bool {
+        self.expires_at <= current_time
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug, JsonSchema)]
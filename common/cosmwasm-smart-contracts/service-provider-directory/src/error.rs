@@ -43,6 +43,12 @@ pub enum SpContractError {
         nym_address: NymAddress,
     },
 
+    #[error("invalid custom service type: {service_type}")]
+    InvalidServiceType { service_type: String },
+
+    #[error("invalid service metadata: {reason}")]
+    InvalidServiceMetadata { reason: String },
+
     #[error("failed to parse {value} into a valid SemVer version: {error_message}")]
     SemVerFailure {
         value: String,
@@ -6,6 +6,7 @@ pub enum ServiceProviderEventType {
     Announce,
     DeleteId,
     DeleteNymAddress,
+    UpdateService,
     UpdateDepositRequired,
 }
 
@@ -15,6 +16,7 @@ impl std::fmt::Display for ServiceProviderEventType {
             ServiceProviderEventType::Announce => write!(f, "announce"),
             ServiceProviderEventType::DeleteId => write!(f, "delete_id"),
             ServiceProviderEventType::DeleteNymAddress => write!(f, "delete_nym_address"),
+            ServiceProviderEventType::UpdateService => write!(f, "update_service"),
             ServiceProviderEventType::UpdateDepositRequired => write!(f, "update_deposit_required"),
         }
     }
@@ -51,6 +53,14 @@ pub fn new_delete_id_event(service: Service) -> Event {
         .add_attribute(NYM_ADDRESS, service.service.nym_address.to_string())
 }
 
+pub fn new_update_service_event(service: Service) -> Event {
+    Event::new(ServiceProviderEventType::UpdateService)
+        .add_attribute(ACTION, ServiceProviderEventType::UpdateService)
+        .add_attribute(SERVICE_ID, service.service_id.to_string())
+        .add_attribute(SERVICE_TYPE, service.service.service_type.to_string())
+        .add_attribute(NYM_ADDRESS, service.service.nym_address.to_string())
+}
+
 pub fn new_update_deposit_required_event(deposit_required: Coin) -> Event {
     Event::new(ServiceProviderEventType::UpdateDepositRequired)
         .add_attribute(ACTION, ServiceProviderEventType::UpdateDepositRequired)
@@ -1,4 +1,4 @@
-use crate::{NymAddress, ServiceDetails, ServiceId};
+use crate::{NymAddress, ServiceDetails, ServiceId, ServiceMetadata, ServiceType};
 use cosmwasm_std::Coin;
 use nym_contracts_common::signing::MessageSignature;
 use serde::{Deserialize, Serialize};
@@ -32,6 +32,12 @@ pub enum ExecuteMsg {
     DeleteNymAddress {
         nym_address: NymAddress,
     },
+    /// Replace the type and metadata of an already announced service.
+    UpdateService {
+        service_id: ServiceId,
+        service_type: ServiceType,
+        metadata: ServiceMetadata,
+    },
     UpdateDepositRequired {
         deposit_required: Coin,
     },
@@ -57,6 +63,11 @@ impl ExecuteMsg {
             ExecuteMsg::DeleteNymAddress { nym_address } => {
                 format!("deleting service with nym address {nym_address}")
             }
+            ExecuteMsg::UpdateService {
+                service_id,
+                service_type,
+                ..
+            } => format!("updating service with service id {service_id} as type {service_type}"),
             ExecuteMsg::UpdateDepositRequired { deposit_required } => {
                 format!("updating the deposit required to {deposit_required}")
             }
@@ -76,6 +87,11 @@ pub enum QueryMsg {
     ByNymAddress {
         nym_address: NymAddress,
     },
+    ByType {
+        service_type: ServiceType,
+        limit: Option<u32>,
+        start_after: Option<ServiceId>,
+    },
     All {
         limit: Option<u32>,
         start_after: Option<ServiceId>,
@@ -96,4 +112,12 @@ impl QueryMsg {
             start_after: None,
         }
     }
+
+    pub fn by_type(service_type: ServiceType) -> QueryMsg {
+        QueryMsg::ByType {
+            service_type,
+            limit: None,
+            start_after: None,
+        }
+    }
 }
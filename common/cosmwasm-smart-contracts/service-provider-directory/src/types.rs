@@ -1,4 +1,5 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use cosmwasm_std::{Addr, Coin};
 use nym_contracts_common::IdentityKey;
@@ -30,6 +31,26 @@ pub struct ServiceDetails {
     pub service_type: ServiceType,
     /// The identity key of the service.
     pub identity_key: IdentityKey,
+    /// Optional, additional information about the service.
+    #[serde(default)]
+    pub metadata: ServiceMetadata,
+}
+
+/// Additional, optional information describing the announced service.
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Debug, JsonSchema)]
+pub struct ServiceMetadata {
+    /// Human readable description of the service.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Versions of the request interface understood by the service.
+    #[serde(default)]
+    pub supported_request_versions: Vec<u8>,
+    /// URL of the policy describing which hosts the service is willing to reach.
+    #[serde(default)]
+    pub allowed_hosts_policy_url: Option<String>,
+    /// Geographical region the service is operating from.
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
 /// The types of addresses supported.
@@ -64,17 +85,50 @@ impl Display for NymAddress {
 }
 
 /// The type of services provider supported
-#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ServiceType {
     NetworkRequester,
+    /// General purpose service without any well-known interface.
+    Generic,
+    /// Service replying with the content of received messages.
+    Echo,
+    /// Any other type of service, identified by its name.
+    Custom(String),
+}
+
+impl ServiceType {
+    pub const NETWORK_REQUESTER: &'static str = "network_requester";
+    pub const GENERIC: &'static str = "generic";
+    pub const ECHO: &'static str = "echo";
+
+    /// Checks whether the name of a custom service type would clash with any of the well-known types.
+    pub fn is_reserved_name(name: &str) -> bool {
+        matches!(name, Self::NETWORK_REQUESTER | Self::GENERIC | Self::ECHO)
+    }
 }
 
 impl std::fmt::Display for ServiceType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let service_type = match self {
-            ServiceType::NetworkRequester => "network_requester",
+            ServiceType::NetworkRequester => Self::NETWORK_REQUESTER,
+            ServiceType::Generic => Self::GENERIC,
+            ServiceType::Echo => Self::ECHO,
+            ServiceType::Custom(name) => name,
         };
         write!(f, "{service_type}")
     }
 }
+
+impl FromStr for ServiceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            Self::NETWORK_REQUESTER => ServiceType::NetworkRequester,
+            Self::GENERIC => ServiceType::Generic,
+            Self::ECHO => ServiceType::Echo,
+            name => ServiceType::Custom(name.to_string()),
+        })
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::StakingPermission;
 use cosmwasm_std::{Addr, Coin, Event, Timestamp};
 
 // event types
@@ -8,9 +9,15 @@ pub const WITHDRAW_EVENT_TYPE: &str = "vested_coins_withdraw";
 pub const OWNERSHIP_TRANSFER_EVENT_TYPE: &str = "ownership_transfer";
 pub const STAKING_ADDRESS_UPDATE_EVENT_TYPE: &str = "staking_address_update";
 pub const NEW_PERIODIC_VESTING_ACCOUNT_EVENT_TYPE: &str = "new_periodic_vesting_account";
+pub const ACCOUNT_SPLIT_EVENT_TYPE: &str = "vesting_account_split";
+pub const STAKING_PERMISSIONS_GRANT_EVENT_TYPE: &str = "staking_permissions_grant";
+pub const STAKING_PERMISSIONS_REVOKE_EVENT_TYPE: &str = "staking_permissions_revoke";
 
 pub const VESTING_DELEGATION_EVENT_TYPE: &str = "vesting_delegation";
 pub const VESTING_UNDELEGATION_EVENT_TYPE: &str = "vesting_undelegation";
+pub const VESTING_REDELEGATION_EVENT_TYPE: &str = "vesting_redelegation";
+pub const VESTING_GATEWAY_DELEGATION_EVENT_TYPE: &str = "vesting_gateway_delegation";
+pub const VESTING_GATEWAY_UNDELEGATION_EVENT_TYPE: &str = "vesting_gateway_undelegation";
 pub const VESTING_GATEWAY_BONDING_EVENT_TYPE: &str = "vesting_gateway_bonding";
 pub const VESTING_GATEWAY_UNBONDING_EVENT_TYPE: &str = "vesting_gateway_unbonding";
 pub const VESTING_MIXNODE_BONDING_EVENT_TYPE: &str = "vesting_mixnode_bonding";
@@ -26,6 +33,8 @@ pub const TRACK_MIXNODE_UNBOND_EVENT_TYPE: &str = "track_mixnode_unbond";
 pub const TRACK_MIXNODE_PLEDGE_DECREASE_EVENT_TYPE: &str = "track_mixnode_pledge_decrease";
 pub const TRACK_GATEWAY_UNBOND_EVENT_TYPE: &str = "track_gateway_unbond";
 pub const TRACK_UNDELEGATION_EVENT_TYPE: &str = "track_undelegation";
+pub const TRACK_REDELEGATION_EVENT_TYPE: &str = "track_redelegation";
+pub const TRACK_GATEWAY_UNDELEGATION_EVENT_TYPE: &str = "track_gateway_undelegation";
 pub const TRACK_REWARD_EVENT_TYPE: &str = "track_reaward";
 
 // attributes that are used in multiple places
@@ -46,6 +55,10 @@ pub const NO_VALUE_VALUE: &str = "none";
 pub const START_TIME_KEY: &str = "start_time";
 pub const STAKING_ADDRESS_KEY: &str = "staking_address";
 
+// staking permissions
+pub const GRANTEE_KEY: &str = "grantee";
+pub const PERMISSIONS_KEY: &str = "permissions";
+
 // OPEN QUESTION: would it make sense to also emit amount of vesting/locked coins here?
 // however, then it would require additional storage reads.
 pub fn new_vested_coins_withdraw_event(
@@ -100,6 +113,36 @@ pub fn new_periodic_vesting_account_event(
     event.add_attribute(START_TIME_KEY, start_time.to_string())
 }
 
+pub fn new_account_split_event(from: &Addr, to: &Addr, amount: &Coin) -> Event {
+    Event::new(ACCOUNT_SPLIT_EVENT_TYPE)
+        .add_attribute(FROM_ACCOUNT_KEY, from)
+        .add_attribute(TO_ACCOUNT_KEY, to)
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+}
+
+pub fn new_staking_permissions_grant_event(
+    owner: &Addr,
+    grantee: &Addr,
+    permissions: &[StakingPermission],
+) -> Event {
+    let permissions = permissions
+        .iter()
+        .map(|permission| permission.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Event::new(STAKING_PERMISSIONS_GRANT_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(GRANTEE_KEY, grantee)
+        .add_attribute(PERMISSIONS_KEY, permissions)
+}
+
+pub fn new_staking_permissions_revoke_event(owner: &Addr, grantee: &Addr) -> Event {
+    Event::new(STAKING_PERMISSIONS_REVOKE_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(GRANTEE_KEY, grantee)
+}
+
 // In most cases the events are rather barebone as there's no point in attaching
 // bunch of data to them as it would be redundant. It is because in most cases when the event is emitted
 // a call to the mixnet contract is made that throws another event with relevant attributes already attached.
@@ -148,6 +191,18 @@ pub fn new_vesting_undelegation_event() -> Event {
     Event::new(VESTING_UNDELEGATION_EVENT_TYPE)
 }
 
+pub fn new_vesting_redelegation_event() -> Event {
+    Event::new(VESTING_REDELEGATION_EVENT_TYPE)
+}
+
+pub fn new_vesting_gateway_delegation_event() -> Event {
+    Event::new(VESTING_GATEWAY_DELEGATION_EVENT_TYPE)
+}
+
+pub fn new_vesting_gateway_undelegation_event() -> Event {
+    Event::new(VESTING_GATEWAY_UNDELEGATION_EVENT_TYPE)
+}
+
 pub fn new_track_mixnode_unbond_event() -> Event {
     Event::new(TRACK_MIXNODE_UNBOND_EVENT_TYPE)
 }
@@ -164,6 +219,14 @@ pub fn new_track_undelegation_event() -> Event {
     Event::new(TRACK_UNDELEGATION_EVENT_TYPE)
 }
 
+pub fn new_track_redelegation_event() -> Event {
+    Event::new(TRACK_REDELEGATION_EVENT_TYPE)
+}
+
+pub fn new_track_gateway_undelegation_event() -> Event {
+    Event::new(TRACK_GATEWAY_UNDELEGATION_EVENT_TYPE)
+}
+
 pub fn new_track_reward_event() -> Event {
     Event::new(TRACK_REWARD_EVENT_TYPE)
 }
@@ -9,6 +9,7 @@ use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 use mixnet_contract_common::MixId;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 pub use messages::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
@@ -160,6 +161,33 @@ pub struct AccountsResponse {
     pub start_next_after: Option<Addr>,
 }
 
+/// Staking action a third party can be allowed to perform on behalf of a vesting account.
+/// Note that none of them allow withdrawing any tokens from the account.
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Clone, Copy, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StakingPermission {
+    Delegate,
+    Undelegate,
+}
+
+impl Display for StakingPermission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StakingPermission::Delegate => write!(f, "delegate"),
+            StakingPermission::Undelegate => write!(f, "undelegate"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct StakingPermissionsResponse {
+    pub owner: Addr,
+    pub grantee: Addr,
+    pub permissions: Vec<StakingPermission>,
+}
+
 #[cfg(test)]
 mod test {
     use contracts_common::Percent;
@@ -1,4 +1,5 @@
 use contracts_common::signing::MessageSignature;
+use contracts_common::Percent;
 use cosmwasm_std::{Coin, Timestamp};
 use mixnet_contract_common::families::FamilyHead;
 use mixnet_contract_common::{
@@ -9,7 +10,7 @@ use mixnet_contract_common::{
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::PledgeCap;
+use crate::{PledgeCap, StakingPermission};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -82,6 +83,10 @@ pub enum ExecuteMsg {
     ClaimDelegatorReward {
         mix_id: MixId,
     },
+    SetDelegationAutoCompounding {
+        mix_id: MixId,
+        enabled: bool,
+    },
     UpdateMixnodeCostParams {
         new_costs: MixNodeCostParams,
     },
@@ -100,6 +105,21 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         on_behalf_of: Option<String>,
     },
+    RedelegateFromMixnode {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+    },
+    DelegateToGateway {
+        identity: IdentityKey,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+    },
+    UndelegateFromGateway {
+        identity: IdentityKey,
+        on_behalf_of: Option<String>,
+    },
     CreateAccount {
         owner_address: String,
         staking_address: Option<String>,
@@ -114,6 +134,17 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         amount: Coin,
     },
+    TrackRedelegation {
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    },
+    TrackGatewayUndelegation {
+        owner: String,
+        identity: IdentityKey,
+        amount: Coin,
+    },
     BondMixnode {
         mix_node: MixNode,
         cost_params: MixNodeCostParams,
@@ -158,6 +189,21 @@ pub enum ExecuteMsg {
         address: String,
         cap: PledgeCap,
     },
+    /// Moves the specified portion of the vesting account into a new account owned by `to_address`.
+    /// The new account follows the same vesting schedule.
+    SplitAccount {
+        to_address: String,
+        percentage: Percent,
+    },
+    /// Allows `grantee` to perform the specified staking actions on behalf of the account.
+    /// It replaces any permissions previously granted to the same address.
+    GrantStakingPermissions {
+        grantee: String,
+        permissions: Vec<StakingPermission>,
+    },
+    RevokeStakingPermissions {
+        grantee: String,
+    },
 }
 
 impl ExecuteMsg {
@@ -170,6 +216,9 @@ impl ExecuteMsg {
             ExecuteMsg::TrackReward { .. } => "VestingExecuteMsg::TrackReward",
             ExecuteMsg::ClaimOperatorReward { .. } => "VestingExecuteMsg::ClaimOperatorReward",
             ExecuteMsg::ClaimDelegatorReward { .. } => "VestingExecuteMsg::ClaimDelegatorReward",
+            ExecuteMsg::SetDelegationAutoCompounding { .. } => {
+                "VestingExecuteMsg::SetDelegationAutoCompounding"
+            }
             ExecuteMsg::UpdateMixnodeConfig { .. } => "VestingExecuteMsg::UpdateMixnodeConfig",
             ExecuteMsg::UpdateMixnodeCostParams { .. } => {
                 "VestingExecuteMsg::UpdateMixnodeCostParams"
@@ -177,9 +226,16 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateMixnetAddress { .. } => "VestingExecuteMsg::UpdateMixnetAddress",
             ExecuteMsg::DelegateToMixnode { .. } => "VestingExecuteMsg::DelegateToMixnode",
             ExecuteMsg::UndelegateFromMixnode { .. } => "VestingExecuteMsg::UndelegateFromMixnode",
+            ExecuteMsg::RedelegateFromMixnode { .. } => "VestingExecuteMsg::RedelegateFromMixnode",
+            ExecuteMsg::DelegateToGateway { .. } => "VestingExecuteMsg::DelegateToGateway",
+            ExecuteMsg::UndelegateFromGateway { .. } => "VestingExecuteMsg::UndelegateFromGateway",
             ExecuteMsg::CreateAccount { .. } => "VestingExecuteMsg::CreateAccount",
             ExecuteMsg::WithdrawVestedCoins { .. } => "VestingExecuteMsg::WithdrawVestedCoins",
             ExecuteMsg::TrackUndelegation { .. } => "VestingExecuteMsg::TrackUndelegation",
+            ExecuteMsg::TrackRedelegation { .. } => "VestingExecuteMsg::TrackRedelegation",
+            ExecuteMsg::TrackGatewayUndelegation { .. } => {
+                "VestingExecuteMsg::TrackGatewayUndelegation"
+            }
             ExecuteMsg::BondMixnode { .. } => "VestingExecuteMsg::BondMixnode",
             ExecuteMsg::PledgeMore { .. } => "VestingExecuteMsg::PledgeMore",
             ExecuteMsg::DecreasePledge { .. } => "VestingExecuteMsg::DecreasePledge",
@@ -193,6 +249,13 @@ impl ExecuteMsg {
             ExecuteMsg::TransferOwnership { .. } => "VestingExecuteMsg::TransferOwnership",
             ExecuteMsg::UpdateStakingAddress { .. } => "VestingExecuteMsg::UpdateStakingAddress",
             ExecuteMsg::UpdateLockedPledgeCap { .. } => "VestingExecuteMsg::UpdateLockedPledgeCap",
+            ExecuteMsg::SplitAccount { .. } => "VestingExecuteMsg::SplitAccount",
+            ExecuteMsg::GrantStakingPermissions { .. } => {
+                "VestingExecuteMsg::GrantStakingPermissions"
+            }
+            ExecuteMsg::RevokeStakingPermissions { .. } => {
+                "VestingExecuteMsg::RevokeStakingPermissions"
+            }
         }
     }
 }
@@ -286,4 +349,8 @@ pub enum QueryMsg {
         start_after: Option<(u32, MixId, u64)>,
         limit: Option<u32>,
     },
+    GetStakingPermissions {
+        vesting_account_address: String,
+        grantee: String,
+    },
 }
@@ -0,0 +1,11 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- the presence of the (single) row implies all the credential secrets are encrypted
+CREATE TABLE store_cipher
+(
+    id                  INTEGER NOT NULL PRIMARY KEY CHECK (id = 0),
+    exported            TEXT    NOT NULL
+);
@@ -0,0 +1,6 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+ALTER TABLE coconut_credentials ADD COLUMN spent_parts INTEGER NOT NULL DEFAULT 0;
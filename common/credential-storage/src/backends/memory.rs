@@ -37,7 +37,8 @@ impl CoconutCredentialManager {
         epoch_id: String,
     ) {
         let mut creds = self.inner.write().await;
-        let id = creds.len() as i64;
+        // credentials might have been removed so we can't just rely on the length of the vector
+        let id = creds.last().map(|c| c.id + 1).unwrap_or_default();
         creds.push(CoconutCredential {
             id,
             voucher_value,
@@ -47,6 +48,7 @@ impl CoconutCredentialManager {
             signature,
             epoch_id,
             consumed: false,
+            spent_parts: 0,
         });
     }
 
@@ -63,8 +65,38 @@ impl CoconutCredentialManager {
     /// * `id`: Database id.
     pub async fn consume_coconut_credential(&self, id: i64) {
         let mut creds = self.inner.write().await;
-        if let Some(cred) = creds.get_mut(id as usize) {
+        if let Some(cred) = creds.iter_mut().find(|c| c.id == id) {
             cred.consumed = true;
         }
     }
+
+    /// Increments the number of spent parts of the specified credential,
+    /// consuming it if all of them have been used.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    /// * `total_parts`: Number of parts the credential is divided into.
+    pub async fn spend_coconut_credential_part(&self, id: i64, total_parts: u64) {
+        let mut creds = self.inner.write().await;
+        if let Some(cred) = creds.iter_mut().find(|c| c.id == id) {
+            cred.spent_parts += 1;
+            cred.consumed = cred.spent_parts as u64 >= total_parts;
+        }
+    }
+
+    /// Retrieves all the stored credentials, including the consumed ones.
+    pub async fn get_all_coconut_credentials(&self) -> Vec<CoconutCredential> {
+        self.inner.read().await.clone()
+    }
+
+    /// Removes the specified credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    pub async fn remove_coconut_credential(&self, id: i64) {
+        let mut creds = self.inner.write().await;
+        creds.retain(|c| c.id != id);
+    }
 }
@@ -71,4 +71,98 @@ impl CoconutCredentialManager {
         .await?;
         Ok(())
     }
+
+    /// Increments the number of spent parts of the specified credential,
+    /// consuming it if all of them have been used.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    /// * `total_parts`: Number of parts the credential is divided into.
+    pub async fn spend_coconut_credential_part(
+        &self,
+        id: i64,
+        total_parts: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE coconut_credentials SET spent_parts = spent_parts + 1, consumed = (spent_parts + 1 >= ?) WHERE id = ?",
+            total_parts,
+            id
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Retrieves all the stored credentials, including the consumed ones.
+    pub async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, sqlx::Error> {
+        sqlx::query_as!(CoconutCredential, "SELECT * FROM coconut_credentials")
+            .fetch_all(&self.connection_pool)
+            .await
+    }
+
+    /// Removes the specified credential from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    pub async fn remove_coconut_credential(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM coconut_credentials WHERE id = ?", id)
+            .execute(&self.connection_pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Retrieves the exported store cipher, if the credentials have been encrypted.
+    pub async fn get_exported_cipher(&self) -> Result<Option<String>, sqlx::Error> {
+        let exported = sqlx::query!("SELECT exported FROM store_cipher WHERE id = 0")
+            .fetch_optional(&self.connection_pool)
+            .await?
+            .map(|row| row.exported);
+        Ok(exported)
+    }
+
+    /// Encrypts all the existing credentials in place, using the provided function,
+    /// and persists the exported store cipher. Everything happens within a single transaction
+    /// so the database is never left in a partially encrypted state.
+    ///
+    /// # Arguments
+    ///
+    /// * `exported_cipher`: The exported information about the cipher used for encryption.
+    /// * `encrypt`: Function encrypting the secrets of an individual credential.
+    pub async fn encrypt_existing_credentials<F, E>(
+        &self,
+        exported_cipher: String,
+        encrypt: F,
+    ) -> Result<(), E>
+    where
+        F: Fn(CoconutCredential) -> Result<CoconutCredential, E>,
+        E: From<sqlx::Error>,
+    {
+        let mut tx = self.connection_pool.begin().await?;
+
+        let credentials = sqlx::query_as!(CoconutCredential, "SELECT * FROM coconut_credentials")
+            .fetch_all(&mut tx)
+            .await?;
+
+        for credential in credentials {
+            let encrypted = encrypt(credential)?;
+            sqlx::query!(
+                "UPDATE coconut_credentials SET serial_number = ?, binding_number = ?, signature = ? WHERE id = ?",
+                encrypted.serial_number, encrypted.binding_number, encrypted.signature, encrypted.id
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            "INSERT INTO store_cipher(id, exported) VALUES (0, ?)",
+            exported_cipher
+        )
+        .execute(&mut tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
 }
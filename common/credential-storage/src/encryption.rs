@@ -0,0 +1,261 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::StorageError;
+use crate::models::CoconutCredential;
+use nym_store_cipher::{
+    Aes256Gcm, Algorithm, EncryptedData, ExportedStoreCipher, KdfInfo, KeySizeUser, Params,
+    StoreCipher, Unsigned, Version, AES256GCM_NONCE_SIZE,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Name of the environment variable holding the passphrase protecting the stored credentials.
+pub const PASSPHRASE_ENV_VAR: &str = "NYM_CREDENTIAL_STORAGE_PASSPHRASE";
+
+/// Name of the environment variable holding the path to the file protecting the stored credentials.
+#[cfg(not(target_arch = "wasm32"))]
+pub const KEY_FILE_ENV_VAR: &str = "NYM_CREDENTIAL_STORAGE_KEY_FILE";
+
+const MEMORY_COST: u32 = 19 * 1024;
+const ITERATIONS: u32 = 2;
+const PARALLELISM: u32 = 1;
+const OUTPUT_LENGTH: usize = <Aes256Gcm as KeySizeUser>::KeySize::USIZE;
+
+// use hardcoded values in case any `Default` implementation changes in the future
+fn new_default_kdf() -> Result<KdfInfo, StorageError> {
+    let kdf_salt = KdfInfo::random_salt()?;
+    Ok(KdfInfo::Argon2 {
+        params: Params::new(MEMORY_COST, ITERATIONS, PARALLELISM, Some(OUTPUT_LENGTH)).unwrap(),
+        algorithm: Algorithm::Argon2id,
+        version: Version::V0x13,
+        kdf_salt,
+    })
+}
+
+/// Secret used for deriving the key protecting the stored credentials.
+pub enum CredentialStorageKey {
+    /// User-provided passphrase.
+    Passphrase(Zeroizing<String>),
+
+    /// Path to a file whose entire content is used as the passphrase.
+    #[cfg(not(target_arch = "wasm32"))]
+    KeyFile(PathBuf),
+}
+
+impl CredentialStorageKey {
+    pub fn passphrase<S: Into<String>>(passphrase: S) -> Self {
+        CredentialStorageKey::Passphrase(Zeroizing::new(passphrase.into()))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn key_file<P: AsRef<Path>>(path: P) -> Self {
+        CredentialStorageKey::KeyFile(path.as_ref().to_path_buf())
+    }
+
+    /// Attempts to obtain the storage key from the environment, i.e. either the passphrase set in
+    /// [`PASSPHRASE_ENV_VAR`] or the key file pointed to by [`KEY_FILE_ENV_VAR`].
+    /// Secrets are deliberately not accepted as command line arguments as those are visible to
+    /// other users of the system and end up in the shell history.
+    pub fn from_env() -> Option<Self> {
+        if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+            if !passphrase.is_empty() {
+                return Some(CredentialStorageKey::passphrase(passphrase));
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = std::env::var_os(KEY_FILE_ENV_VAR) {
+            if !path.is_empty() {
+                return Some(CredentialStorageKey::key_file(path));
+            }
+        }
+
+        None
+    }
+
+    fn secret(&self) -> Result<Zeroizing<Vec<u8>>, StorageError> {
+        match self {
+            CredentialStorageKey::Passphrase(passphrase) => {
+                Ok(Zeroizing::new(passphrase.as_bytes().to_vec()))
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            CredentialStorageKey::KeyFile(path) => {
+                std::fs::read(path).map(Zeroizing::new).map_err(|source| {
+                    StorageError::KeyFileReadFailure {
+                        path: path.clone(),
+                        source,
+                    }
+                })
+            }
+        }
+    }
+}
+
+/// Cipher used for encrypting the secret parts of the stored credentials, i.e. their serial numbers,
+/// binding numbers and signatures. The remaining, public, metadata is kept as plaintext.
+pub(crate) struct CredentialCipher {
+    inner: StoreCipher,
+}
+
+impl CredentialCipher {
+    /// Derives a brand new cipher from the provided key.
+    pub(crate) fn new(key: &CredentialStorageKey) -> Result<Self, StorageError> {
+        let inner = StoreCipher::new_aes256gcm(&key.secret()?, new_default_kdf()?)?;
+        Ok(CredentialCipher { inner })
+    }
+
+    /// Recovers the cipher that has been persisted alongside the credentials.
+    pub(crate) fn import(key: &CredentialStorageKey, exported: &str) -> Result<Self, StorageError> {
+        Self::from_exported(key, serde_json::from_str(exported)?)
+    }
+
+    pub(crate) fn from_exported(
+        key: &CredentialStorageKey,
+        exported: ExportedStoreCipher,
+    ) -> Result<Self, StorageError> {
+        let inner = StoreCipher::import_aes256gcm(&key.secret()?, exported)?;
+        Ok(CredentialCipher { inner })
+    }
+
+    /// Exports the information required for recovering the cipher with the same key.
+    /// Note that it does not contain the key itself.
+    pub(crate) fn export(&self) -> Result<String, StorageError> {
+        Ok(serde_json::to_string(&self.exported()?)?)
+    }
+
+    pub(crate) fn exported(&self) -> Result<ExportedStoreCipher, StorageError> {
+        Ok(self.inner.export_aes256gcm()?)
+    }
+
+    pub(crate) fn encrypt_json_value<T: Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<EncryptedData, StorageError> {
+        Ok(self.inner.encrypt_json_value(value)?)
+    }
+
+    pub(crate) fn decrypt_json_value<T: DeserializeOwned>(
+        &self,
+        data: EncryptedData,
+    ) -> Result<T, StorageError> {
+        Ok(self.inner.decrypt_json_value(data)?)
+    }
+
+    // the encrypted field is stored as bs58(version || nonce || ciphertext)
+    fn encrypt_field(&self, plaintext: String) -> Result<String, StorageError> {
+        let encrypted = self.inner.encrypt_data(plaintext.into_bytes())?;
+
+        let mut bytes = Vec::with_capacity(1 + encrypted.nonce.len() + encrypted.ciphertext.len());
+        bytes.push(encrypted.version);
+        bytes.extend_from_slice(&encrypted.nonce);
+        bytes.extend_from_slice(&encrypted.ciphertext);
+        Ok(bs58::encode(bytes).into_string())
+    }
+
+    fn decrypt_field(&self, encoded: &str) -> Result<String, StorageError> {
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|_| StorageError::MalformedCiphertext)?;
+        if bytes.len() <= 1 + AES256GCM_NONCE_SIZE {
+            return Err(StorageError::MalformedCiphertext);
+        }
+
+        let encrypted = EncryptedData {
+            version: bytes[0],
+            nonce: bytes[1..1 + AES256GCM_NONCE_SIZE].to_vec(),
+            ciphertext: bytes[1 + AES256GCM_NONCE_SIZE..].to_vec(),
+        };
+        let plaintext = self.inner.decrypt_data(encrypted)?;
+        String::from_utf8(plaintext).map_err(|_| StorageError::MalformedCiphertext)
+    }
+
+    /// Encrypts the secret parts of the credential, i.e. the serial number, binding number and signature.
+    pub(crate) fn encrypt_secrets(
+        &self,
+        serial_number: String,
+        binding_number: String,
+        signature: String,
+    ) -> Result<(String, String, String), StorageError> {
+        Ok((
+            self.encrypt_field(serial_number)?,
+            self.encrypt_field(binding_number)?,
+            self.encrypt_field(signature)?,
+        ))
+    }
+
+    pub(crate) fn encrypt_credential(
+        &self,
+        credential: CoconutCredential,
+    ) -> Result<CoconutCredential, StorageError> {
+        let (serial_number, binding_number, signature) = self.encrypt_secrets(
+            credential.serial_number,
+            credential.binding_number,
+            credential.signature,
+        )?;
+
+        Ok(CoconutCredential {
+            serial_number,
+            binding_number,
+            signature,
+            ..credential
+        })
+    }
+
+    pub(crate) fn decrypt_credential(
+        &self,
+        credential: CoconutCredential,
+    ) -> Result<CoconutCredential, StorageError> {
+        Ok(CoconutCredential {
+            serial_number: self.decrypt_field(&credential.serial_number)?,
+            binding_number: self.decrypt_field(&credential.binding_number)?,
+            signature: self.decrypt_field(&credential.signature)?,
+            ..credential
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_credential() -> CoconutCredential {
+        CoconutCredential {
+            id: 42,
+            voucher_value: "1000000".to_string(),
+            voucher_info: "BandwidthVoucher".to_string(),
+            serial_number: "serial".to_string(),
+            binding_number: "binding".to_string(),
+            signature: "signature".to_string(),
+            epoch_id: "1".to_string(),
+            consumed: false,
+            spent_parts: 0,
+        }
+    }
+
+    #[test]
+    fn encrypted_credential_can_be_recovered_with_imported_cipher() {
+        let key = CredentialStorageKey::passphrase("my super secret passphrase");
+        let cipher = CredentialCipher::new(&key).unwrap();
+
+        let credential = dummy_credential();
+        let encrypted = cipher.encrypt_credential(credential.clone()).unwrap();
+        assert_ne!(encrypted.serial_number, credential.serial_number);
+        assert_ne!(encrypted.binding_number, credential.binding_number);
+        assert_ne!(encrypted.signature, credential.signature);
+        assert_eq!(encrypted.voucher_value, credential.voucher_value);
+
+        let imported = CredentialCipher::import(&key, &cipher.export().unwrap()).unwrap();
+        let decrypted = imported.decrypt_credential(encrypted).unwrap();
+        assert_eq!(decrypted.serial_number, credential.serial_number);
+        assert_eq!(decrypted.binding_number, credential.binding_number);
+        assert_eq!(decrypted.signature, credential.signature);
+
+        let wrong_key = CredentialStorageKey::passphrase("wrong passphrase");
+        assert!(CredentialCipher::import(&wrong_key, &cipher.export().unwrap()).is_err());
+    }
+}
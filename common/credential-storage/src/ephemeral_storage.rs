@@ -2,22 +2,42 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::backends::memory::CoconutCredentialManager;
+use crate::encryption::{CredentialCipher, CredentialStorageKey};
 use crate::error::StorageError;
 use crate::models::CoconutCredential;
 use crate::storage::Storage;
 
 use async_trait::async_trait;
+use std::sync::Arc;
 
 // note that clone here is fine as upon cloning the same underlying pool will be used
 #[derive(Clone)]
 pub struct EphemeralStorage {
     coconut_credential_manager: CoconutCredentialManager,
+    cipher: Option<Arc<CredentialCipher>>,
+}
+
+impl EphemeralStorage {
+    /// Creates new `EphemeralStorage` where the secrets of all the credentials are kept encrypted
+    /// with a key derived from the provided passphrase or key file.
+    pub fn new_encrypted(key: &CredentialStorageKey) -> Result<Self, StorageError> {
+        Ok(EphemeralStorage {
+            coconut_credential_manager: CoconutCredentialManager::new(),
+            cipher: Some(Arc::new(CredentialCipher::new(key)?)),
+        })
+    }
+
+    /// Checks whether the secrets of the stored credentials are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.cipher.is_some()
+    }
 }
 
 impl Default for EphemeralStorage {
     fn default() -> Self {
         EphemeralStorage {
             coconut_credential_manager: CoconutCredentialManager::new(),
+            cipher: None,
         }
     }
 }
@@ -35,6 +55,11 @@ impl Storage for EphemeralStorage {
         signature: String,
         epoch_id: String,
     ) -> Result<(), StorageError> {
+        let (serial_number, binding_number, signature) = match &self.cipher {
+            Some(cipher) => cipher.encrypt_secrets(serial_number, binding_number, signature)?,
+            None => (serial_number, binding_number, signature),
+        };
+
         self.coconut_credential_manager
             .insert_coconut_credential(
                 voucher_value,
@@ -56,7 +81,10 @@ impl Storage for EphemeralStorage {
             .await
             .ok_or(StorageError::NoCredential)?;
 
-        Ok(credential)
+        match &self.cipher {
+            Some(cipher) => cipher.decrypt_credential(credential),
+            None => Ok(credential),
+        }
     }
 
     async fn consume_coconut_credential(&self, id: i64) -> Result<(), StorageError> {
@@ -66,4 +94,39 @@ impl Storage for EphemeralStorage {
 
         Ok(())
     }
+
+    async fn spend_coconut_credential_part(
+        &self,
+        id: i64,
+        total_parts: u64,
+    ) -> Result<(), StorageError> {
+        self.coconut_credential_manager
+            .spend_coconut_credential_part(id, total_parts)
+            .await;
+
+        Ok(())
+    }
+
+    async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, StorageError> {
+        let credentials = self
+            .coconut_credential_manager
+            .get_all_coconut_credentials()
+            .await;
+
+        match &self.cipher {
+            Some(cipher) => credentials
+                .into_iter()
+                .map(|credential| cipher.decrypt_credential(credential))
+                .collect(),
+            None => Ok(credentials),
+        }
+    }
+
+    async fn remove_coconut_credential(&self, id: i64) -> Result<(), StorageError> {
+        self.coconut_credential_manager
+            .remove_coconut_credential(id)
+            .await;
+
+        Ok(())
+    }
 }
@@ -3,6 +3,9 @@
 
 use thiserror::Error;
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
 #[derive(Error, Debug)]
 pub enum StorageError {
     #[cfg(not(target_arch = "wasm32"))]
@@ -18,4 +21,33 @@ pub enum StorageError {
 
     #[error("No unused credential in database. You need to buy at least one")]
     NoCredential,
+
+    #[error("failed to encrypt or decrypt the stored credential data: {source}")]
+    CipherError {
+        #[from]
+        source: nym_store_cipher::Error,
+    },
+
+    #[error("failed to (de)serialize the stored cipher information: {source}")]
+    CipherInfoSerializationError {
+        #[from]
+        source: serde_json::Error,
+    },
+
+    #[error("the stored encrypted credential data is malformed")]
+    MalformedCiphertext,
+
+    #[error(
+        "the credential storage is encrypted, but no decryption key has been provided. \
+        Set either the NYM_CREDENTIAL_STORAGE_PASSPHRASE or the NYM_CREDENTIAL_STORAGE_KEY_FILE environment variable"
+    )]
+    MissingDecryptionKey,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("failed to read the credential storage key file from {path:?}: {source}")]
+    KeyFileReadFailure {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
 }
@@ -5,14 +5,18 @@
 
 use crate::ephemeral_storage::EphemeralStorage;
 
+pub use crate::encryption::CredentialStorageKey;
+
 #[cfg(not(target_arch = "wasm32"))]
 use crate::persistent_storage::PersistentStorage;
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
 
 mod backends;
+pub mod encryption;
 pub mod ephemeral_storage;
 pub mod error;
+pub mod management;
 pub mod models;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod persistent_storage;
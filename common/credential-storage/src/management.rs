@@ -0,0 +1,318 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::encryption::{CredentialCipher, CredentialStorageKey};
+use crate::error::StorageError;
+use crate::models::CoconutCredential;
+use crate::storage::Storage;
+use log::{debug, warn};
+use nym_network_defaults::DIVISIBLE_VOUCHER_SPENDS;
+use nym_store_cipher::{EncryptedData, ExportedStoreCipher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Unspent credential in the form it is included in an exported [`CredentialBundle`].
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+pub struct ExportedCredential {
+    pub voucher_value: String,
+    pub voucher_info: String,
+    pub serial_number: String,
+    pub binding_number: String,
+    pub signature: String,
+    pub epoch_id: String,
+    /// Number of already spent parts of a divisible credential.
+    #[serde(default)]
+    pub spent_parts: i64,
+}
+
+impl From<&CoconutCredential> for ExportedCredential {
+    fn from(credential: &CoconutCredential) -> Self {
+        ExportedCredential {
+            voucher_value: credential.voucher_value.clone(),
+            voucher_info: credential.voucher_info.clone(),
+            serial_number: credential.serial_number.clone(),
+            binding_number: credential.binding_number.clone(),
+            signature: credential.signature.clone(),
+            epoch_id: credential.epoch_id.clone(),
+            spent_parts: credential.spent_parts,
+        }
+    }
+}
+
+/// Encrypted set of credentials that can be moved between different machines.
+#[derive(Serialize, Deserialize)]
+pub struct CredentialBundle {
+    cipher: ExportedStoreCipher,
+    credentials: EncryptedData,
+}
+
+impl CredentialBundle {
+    /// Encrypts the provided credentials with a key derived from the provided passphrase or key file.
+    pub fn seal(
+        credentials: &[ExportedCredential],
+        key: &CredentialStorageKey,
+    ) -> Result<Self, StorageError> {
+        let cipher = CredentialCipher::new(key)?;
+
+        Ok(CredentialBundle {
+            cipher: cipher.exported()?,
+            credentials: cipher.encrypt_json_value(&credentials)?,
+        })
+    }
+
+    /// Attempts to decrypt the bundled credentials with the provided passphrase or key file.
+    pub fn open(self, key: &CredentialStorageKey) -> Result<Vec<ExportedCredential>, StorageError> {
+        let cipher = CredentialCipher::from_exported(key, self.cipher)?;
+        cipher.decrypt_json_value(self.credentials)
+    }
+}
+
+/// Stored credential alongside its expiry status with respect to the current DKG epoch.
+pub struct ListedCredential {
+    pub credential: CoconutCredential,
+    pub expired: bool,
+}
+
+/// Credentials removed from the storage during pruning.
+#[derive(Debug, Default)]
+pub struct PrunedCredentials {
+    pub consumed: usize,
+    pub expired: usize,
+}
+
+/// Returns all the stored credentials, flagging the ones issued during the past DKG epochs.
+pub async fn list_credentials<S>(
+    storage: &S,
+    current_epoch: u64,
+) -> Result<Vec<ListedCredential>, StorageError>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .map(|credential| {
+            Ok(ListedCredential {
+                expired: credential.is_expired(current_epoch)?,
+                credential,
+            })
+        })
+        .collect()
+}
+
+/// Exports all the unspent credentials from the storage into an encrypted bundle.
+/// It returns the bundle alongside the ids of the exported credentials, so that the caller
+/// could remove them afterwards as they must not be spent on both machines.
+pub async fn export_credentials<S>(
+    storage: &S,
+    key: &CredentialStorageKey,
+) -> Result<(CredentialBundle, Vec<i64>), StorageError>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    let unspent = storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .filter(|credential| !credential.consumed)
+        .collect::<Vec<_>>();
+
+    let exported = unspent
+        .iter()
+        .map(ExportedCredential::from)
+        .collect::<Vec<_>>();
+    let bundle = CredentialBundle::seal(&exported, key)?;
+
+    debug!("exported {} credentials", unspent.len());
+    Ok((bundle, unspent.into_iter().map(|c| c.id).collect()))
+}
+
+/// Imports credentials from the encrypted bundle into the storage, skipping the ones that
+/// are already present. It returns the number of newly inserted credentials.
+pub async fn import_credentials<S>(
+    storage: &S,
+    bundle: CredentialBundle,
+    key: &CredentialStorageKey,
+) -> Result<usize, StorageError>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    let known_signatures = storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .map(|credential| credential.signature)
+        .collect::<HashSet<_>>();
+
+    let mut imported = 0;
+    for credential in bundle.open(key)? {
+        if known_signatures.contains(&credential.signature) {
+            warn!("the credential is already present in the storage - skipping it");
+            continue;
+        }
+
+        storage
+            .insert_coconut_credential(
+                credential.voucher_value.clone(),
+                credential.voucher_info.clone(),
+                credential.serial_number.clone(),
+                credential.binding_number.clone(),
+                credential.signature.clone(),
+                credential.epoch_id.clone(),
+            )
+            .await?;
+
+        // make sure the already used parts of a divisible credential won't be spent again
+        if credential.spent_parts > 0 {
+            let inserted = storage
+                .get_all_coconut_credentials()
+                .await?
+                .into_iter()
+                .find(|stored| stored.signature == credential.signature)
+                .ok_or(StorageError::InconsistentData)?;
+            for _ in 0..credential.spent_parts {
+                storage
+                    .spend_coconut_credential_part(inserted.id, DIVISIBLE_VOUCHER_SPENDS)
+                    .await?;
+            }
+        }
+        imported += 1;
+    }
+
+    Ok(imported)
+}
+
+/// Removes all the consumed credentials as well as the ones issued during the past DKG epochs.
+pub async fn prune_credentials<S>(
+    storage: &S,
+    current_epoch: u64,
+) -> Result<PrunedCredentials, StorageError>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    let mut pruned = PrunedCredentials::default();
+
+    for credential in storage.get_all_coconut_credentials().await? {
+        if credential.consumed {
+            pruned.consumed += 1;
+        } else if credential.is_expired(current_epoch)? {
+            pruned.expired += 1;
+        } else {
+            continue;
+        }
+        storage.remove_coconut_credential(credential.id).await?;
+    }
+
+    Ok(pruned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ephemeral_storage::EphemeralStorage;
+
+    async fn insert_dummy_credential(storage: &EphemeralStorage, i: u64, epoch: u64) {
+        storage
+            .insert_coconut_credential(
+                "1000000".to_string(),
+                "BandwidthVoucher".to_string(),
+                format!("serial{i}"),
+                format!("binding{i}"),
+                format!("signature{i}"),
+                epoch.to_string(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn credentials_can_be_moved_between_storages() {
+        let key = CredentialStorageKey::passphrase("bundle passphrase");
+
+        let source = EphemeralStorage::default();
+        for i in 0..3 {
+            insert_dummy_credential(&source, i, 1).await;
+        }
+        source.consume_coconut_credential(0).await.unwrap();
+        source
+            .spend_coconut_credential_part(1, DIVISIBLE_VOUCHER_SPENDS)
+            .await
+            .unwrap();
+
+        let (bundle, exported_ids) = export_credentials(&source, &key).await.unwrap();
+        assert_eq!(exported_ids, vec![1, 2]);
+
+        let destination = EphemeralStorage::default();
+        insert_dummy_credential(&destination, 2, 1).await;
+
+        let wrong_key = CredentialStorageKey::passphrase("wrong passphrase");
+        let serialized = serde_json::to_string(&bundle).unwrap();
+        assert!(bundle.open(&wrong_key).is_err());
+
+        let bundle = serde_json::from_str(&serialized).unwrap();
+        let imported = import_credentials(&destination, bundle, &key)
+            .await
+            .unwrap();
+        assert_eq!(imported, 1);
+        assert_eq!(
+            destination
+                .get_all_coconut_credentials()
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let moved = destination
+            .get_all_coconut_credentials()
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|credential| credential.signature == "signature1")
+            .unwrap();
+        assert_eq!(moved.spent_parts, 1);
+        assert!(!moved.consumed);
+    }
+
+    #[tokio::test]
+    async fn listing_flags_credentials_from_past_epochs() {
+        let storage = EphemeralStorage::default();
+        insert_dummy_credential(&storage, 0, 1).await;
+        insert_dummy_credential(&storage, 1, 2).await;
+        insert_dummy_credential(&storage, 2, 3).await;
+
+        let listed = list_credentials(&storage, 2).await.unwrap();
+        let expired = listed
+            .iter()
+            .map(|listed| (listed.credential.signature.as_str(), listed.expired))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expired,
+            vec![
+                ("signature0", true),
+                ("signature1", false),
+                ("signature2", false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pruning_removes_consumed_and_expired_credentials() {
+        let storage = EphemeralStorage::default();
+        insert_dummy_credential(&storage, 0, 1).await;
+        insert_dummy_credential(&storage, 1, 1).await;
+        insert_dummy_credential(&storage, 2, 2).await;
+        storage.consume_coconut_credential(2).await.unwrap();
+        insert_dummy_credential(&storage, 3, 2).await;
+
+        let pruned = prune_credentials(&storage, 2).await.unwrap();
+        assert_eq!(pruned.consumed, 1);
+        assert_eq!(pruned.expired, 2);
+
+        let remaining = storage.get_all_coconut_credentials().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].signature, "signature3");
+    }
+}
@@ -1,6 +1,9 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::error::StorageError;
+use std::str::FromStr;
+
 #[derive(Clone)]
 pub struct CoconutCredential {
     #[allow(dead_code)]
@@ -12,4 +15,24 @@ pub struct CoconutCredential {
     pub signature: String,
     pub epoch_id: String,
     pub consumed: bool,
+    /// Number of already spent parts of a divisible credential.
+    pub spent_parts: i64,
+}
+
+impl CoconutCredential {
+    /// Amount of bandwidth held by the credential.
+    pub fn value(&self) -> Result<u64, StorageError> {
+        u64::from_str(&self.voucher_value).map_err(|_| StorageError::InconsistentData)
+    }
+
+    /// Id of the DKG epoch during which the credential has been issued.
+    pub fn epoch(&self) -> Result<u64, StorageError> {
+        u64::from_str(&self.epoch_id).map_err(|_| StorageError::InconsistentData)
+    }
+
+    /// Checks whether the credential has been issued during one of the past DKG epochs
+    /// and thus can no longer be verified with the current keys of the signers.
+    pub fn is_expired(&self, current_epoch: u64) -> Result<bool, StorageError> {
+        Ok(self.epoch()? < current_epoch)
+    }
 }
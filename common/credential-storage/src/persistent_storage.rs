@@ -2,28 +2,102 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::backends::sqlite::CoconutCredentialManager;
+use crate::encryption::{CredentialCipher, CredentialStorageKey};
 use crate::error::StorageError;
 use crate::storage::Storage;
 
 use crate::models::CoconutCredential;
 use async_trait::async_trait;
-use log::{debug, error};
+use log::{debug, error, info};
 use sqlx::ConnectOptions;
 use std::path::Path;
+use std::sync::Arc;
 
 // note that clone here is fine as upon cloning the same underlying pool will be used
 #[derive(Clone)]
 pub struct PersistentStorage {
     coconut_credential_manager: CoconutCredentialManager,
+    cipher: Option<Arc<CredentialCipher>>,
 }
 
 impl PersistentStorage {
-    /// Initialises `PersistentStorage` using the provided path.
+    /// Initialises unencrypted `PersistentStorage` using the provided path.
+    /// It fails if the underlying database has previously been encrypted.
     ///
     /// # Arguments
     ///
     /// * `database_path`: path to the database.
     pub async fn init<P: AsRef<Path>>(database_path: P) -> Result<Self, StorageError> {
+        let coconut_credential_manager = Self::connect(database_path).await?;
+        if coconut_credential_manager
+            .get_exported_cipher()
+            .await?
+            .is_some()
+        {
+            return Err(StorageError::MissingDecryptionKey);
+        }
+
+        Ok(PersistentStorage {
+            coconut_credential_manager,
+            cipher: None,
+        })
+    }
+
+    /// Initialises `PersistentStorage` using the provided path, where the secrets of all
+    /// the credentials are encrypted with a key derived from the provided passphrase or key file.
+    /// If the underlying database has not been encrypted before, all of its existing credentials
+    /// are going to get encrypted in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_path`: path to the database.
+    /// * `key`: secret used for deriving the encryption key.
+    pub async fn init_encrypted<P: AsRef<Path>>(
+        database_path: P,
+        key: &CredentialStorageKey,
+    ) -> Result<Self, StorageError> {
+        let coconut_credential_manager = Self::connect(database_path).await?;
+
+        let cipher = match coconut_credential_manager.get_exported_cipher().await? {
+            Some(exported) => CredentialCipher::import(key, &exported)?,
+            None => {
+                info!("encrypting the existing credential storage");
+                let cipher = CredentialCipher::new(key)?;
+                coconut_credential_manager
+                    .encrypt_existing_credentials(cipher.export()?, |credential| {
+                        cipher.encrypt_credential(credential)
+                    })
+                    .await?;
+                cipher
+            }
+        };
+
+        Ok(PersistentStorage {
+            coconut_credential_manager,
+            cipher: Some(Arc::new(cipher)),
+        })
+    }
+
+    /// Initialises `PersistentStorage` using the provided path, encrypting it with the provided
+    /// key if one is available. Otherwise it behaves the same way as [`Self::init`].
+    pub async fn init_with_key<P: AsRef<Path>>(
+        database_path: P,
+        key: Option<&CredentialStorageKey>,
+    ) -> Result<Self, StorageError> {
+        match key {
+            Some(key) => Self::init_encrypted(database_path, key).await,
+            None => Self::init(database_path).await,
+        }
+    }
+
+    /// Checks whether the secrets of the stored credentials are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    async fn connect<P: AsRef<Path>>(
+        database_path: P,
+    ) -> Result<CoconutCredentialManager, StorageError> {
         debug!(
             "Attempting to connect to database {:?}",
             database_path.as_ref().as_os_str()
@@ -48,9 +122,7 @@ impl PersistentStorage {
             return Err(err.into());
         }
 
-        Ok(PersistentStorage {
-            coconut_credential_manager: CoconutCredentialManager::new(connection_pool.clone()),
-        })
+        Ok(CoconutCredentialManager::new(connection_pool))
     }
 }
 
@@ -67,6 +139,11 @@ impl Storage for PersistentStorage {
         signature: String,
         epoch_id: String,
     ) -> Result<(), StorageError> {
+        let (serial_number, binding_number, signature) = match &self.cipher {
+            Some(cipher) => cipher.encrypt_secrets(serial_number, binding_number, signature)?,
+            None => (serial_number, binding_number, signature),
+        };
+
         self.coconut_credential_manager
             .insert_coconut_credential(
                 voucher_value,
@@ -88,7 +165,10 @@ impl Storage for PersistentStorage {
             .await?
             .ok_or(StorageError::NoCredential)?;
 
-        Ok(credential)
+        match &self.cipher {
+            Some(cipher) => cipher.decrypt_credential(credential),
+            None => Ok(credential),
+        }
     }
 
     async fn consume_coconut_credential(&self, id: i64) -> Result<(), StorageError> {
@@ -98,4 +178,39 @@ impl Storage for PersistentStorage {
 
         Ok(())
     }
+
+    async fn spend_coconut_credential_part(
+        &self,
+        id: i64,
+        total_parts: u64,
+    ) -> Result<(), StorageError> {
+        self.coconut_credential_manager
+            .spend_coconut_credential_part(id, total_parts as i64)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, StorageError> {
+        let credentials = self
+            .coconut_credential_manager
+            .get_all_coconut_credentials()
+            .await?;
+
+        match &self.cipher {
+            Some(cipher) => credentials
+                .into_iter()
+                .map(|credential| cipher.decrypt_credential(credential))
+                .collect(),
+            None => Ok(credentials),
+        }
+    }
+
+    async fn remove_coconut_credential(&self, id: i64) -> Result<(), StorageError> {
+        self.coconut_credential_manager
+            .remove_coconut_credential(id)
+            .await?;
+
+        Ok(())
+    }
 }
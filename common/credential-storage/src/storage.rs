@@ -38,4 +38,29 @@ pub trait Storage: Send + Sync {
     ///
     /// * `id`: Id of the credential to be consumed.
     async fn consume_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError>;
+
+    /// Records spending of another part of a divisible credential, marking it as consumed
+    /// once all of its parts have been used.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Id of the partially spent credential.
+    /// * `total_parts`: Number of parts the credential is divided into.
+    async fn spend_coconut_credential_part(
+        &self,
+        id: i64,
+        total_parts: u64,
+    ) -> Result<(), Self::StorageError>;
+
+    /// Retrieves all the stored credentials, including the consumed ones.
+    async fn get_all_coconut_credentials(
+        &self,
+    ) -> Result<Vec<CoconutCredential>, Self::StorageError>;
+
+    /// Removes the specified credential from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Id of the credential to be removed.
+    async fn remove_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError>;
 }
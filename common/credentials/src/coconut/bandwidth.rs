@@ -14,7 +14,7 @@ use nym_crypto::asymmetric::{encryption, identity};
 
 use cosmrs::tx::Hash;
 
-use super::utils::prepare_credential_for_spending;
+use super::utils::{prepare_credential_for_spending, prepare_partial_credential_for_spending};
 use crate::error::Error;
 
 pub const PUBLIC_ATTRIBUTES: u32 = 2;
@@ -238,6 +238,10 @@ impl BandwidthVoucher {
         self.voucher_value_plain.clone()
     }
 
+    pub fn get_voucher_info(&self) -> String {
+        self.voucher_info_plain.clone()
+    }
+
     pub fn get_public_attributes_plain(&self) -> Vec<String> {
         vec![
             self.voucher_value_plain.clone(),
@@ -279,6 +283,32 @@ pub fn prepare_for_spending(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_partial_for_spending(
+    voucher_value: u64,
+    voucher_info: String,
+    serial_number: PrivateAttribute,
+    binding_number: PrivateAttribute,
+    epoch_id: u64,
+    spend_index: u64,
+    signature: &Signature,
+    verification_key: &VerificationKey,
+) -> Result<Credential, Error> {
+    let params = Parameters::new(TOTAL_ATTRIBUTES)?;
+
+    prepare_partial_credential_for_spending(
+        &params,
+        voucher_value,
+        voucher_info,
+        serial_number,
+        binding_number,
+        epoch_id,
+        spend_index,
+        signature,
+        verification_key,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
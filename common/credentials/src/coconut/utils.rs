@@ -1,15 +1,27 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use nym_api_requests::coconut::BlindSignRequestBody;
+use async_trait::async_trait;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use log::{debug, warn};
+use nym_api_requests::coconut::{BlindSignRequestBody, BlindedSignatureResponse};
+use nym_coconut_dkg_common::types::NodeIndex;
 use nym_coconut_interface::{
-    aggregate_signature_shares, aggregate_verification_keys, prove_bandwidth_credential, Attribute,
-    BlindedSignature, Credential, Parameters, Signature, SignatureShare, VerificationKey,
+    aggregate_signature_shares, aggregate_verification_keys, prove_bandwidth_credential,
+    prove_partial_bandwidth_credential, Attribute, BlindedSignature, Credential, Parameters,
+    Signature, SignatureShare, VerificationKey,
 };
 use nym_crypto::asymmetric::encryption::PublicKey;
 use nym_crypto::shared_key::recompute_shared_key;
 use nym_crypto::symmetric::stream_cipher;
 use nym_validator_client::client::CoconutApiClient;
+use std::fmt::{self, Display, Formatter};
+use std::future::Future;
+use std::time::Duration;
+
+#[cfg(target_arch = "wasm32")]
+use futures::future::Either;
 
 use crate::coconut::bandwidth::{BandwidthVoucher, PRIVATE_ATTRIBUTES, PUBLIC_ATTRIBUTES};
 use crate::coconut::params::{NymApiCredentialEncryptionAlgorithm, NymApiCredentialHkdfAlgorithm};
@@ -34,12 +46,112 @@ pub async fn obtain_aggregate_verification_key(
     Ok(aggregate_verification_keys(&shares, Some(&indices))?)
 }
 
-async fn obtain_partial_credential(
+/// Default amount of time given to a single signer to issue its partial credential.
+pub const DEFAULT_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Party capable of issuing partial (blinded) credentials, i.e. a nym-api taking part in the DKG.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait CoconutSigner {
+    fn node_id(&self) -> NodeIndex;
+
+    fn verification_key(&self) -> &VerificationKey;
+
+    async fn blind_sign(
+        &self,
+        request_body: &BlindSignRequestBody,
+    ) -> Result<BlindedSignatureResponse, Error>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl CoconutSigner for CoconutApiClient {
+    fn node_id(&self) -> NodeIndex {
+        self.node_id
+    }
+
+    fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+
+    async fn blind_sign(
+        &self,
+        request_body: &BlindSignRequestBody,
+    ) -> Result<BlindedSignatureResponse, Error> {
+        Ok(self.api_client.blind_sign(request_body).await?)
+    }
+}
+
+/// Reason for which a particular signer did not contribute towards the aggregated signature.
+#[derive(Debug)]
+pub enum SignerFailure {
+    /// The signer did not respond within the allowed time.
+    Timeout { node_id: NodeIndex },
+
+    /// The request to the signer has failed, e.g. it was unreachable or rejected the request.
+    RequestFailure { node_id: NodeIndex, reason: String },
+
+    /// The signer has returned a share that could not be verified against its verification key.
+    InvalidShare { node_id: NodeIndex, reason: String },
+}
+
+impl SignerFailure {
+    pub fn node_id(&self) -> NodeIndex {
+        match self {
+            SignerFailure::Timeout { node_id }
+            | SignerFailure::RequestFailure { node_id, .. }
+            | SignerFailure::InvalidShare { node_id, .. } => *node_id,
+        }
+    }
+}
+
+impl Display for SignerFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerFailure::Timeout { node_id } => {
+                write!(f, "signer {node_id} did not respond in time")
+            }
+            SignerFailure::RequestFailure { node_id, reason } => {
+                write!(f, "request to signer {node_id} has failed: {reason}")
+            }
+            SignerFailure::InvalidShare { node_id, reason } => {
+                write!(
+                    f,
+                    "signer {node_id} has returned an invalid share: {reason}"
+                )
+            }
+        }
+    }
+}
+
+/// Aggregated credential alongside the signers that failed to provide a valid share for it.
+#[derive(Debug)]
+pub struct AggregatedCredential {
+    pub signature: Signature,
+    pub failures: Vec<SignerFailure>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn with_timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    tokio::time::timeout(timeout, future).await.ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn with_timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    futures::pin_mut!(future);
+    match futures::future::select(future, wasm_timer::Delay::new(timeout)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+async fn obtain_partial_credential<S: CoconutSigner + ?Sized>(
     params: &Parameters,
     attributes: &BandwidthVoucher,
-    client: &nym_validator_client::client::NymApiClient,
-    validator_vk: &VerificationKey,
-) -> Result<Signature, Error> {
+    signer: &S,
+    timeout: Duration,
+) -> Result<Signature, SignerFailure> {
+    let node_id = signer.node_id();
     let public_attributes = attributes.get_public_attributes();
     let public_attributes_plain = attributes.get_public_attributes_plain();
     let private_attributes = attributes.get_private_attributes();
@@ -53,9 +165,19 @@ async fn obtain_partial_credential(
         public_attributes_plain,
         (public_attributes.len() + private_attributes.len()) as u32,
     );
-    let response = client.blind_sign(&blind_sign_request_body).await?;
+    let response = with_timeout(signer.blind_sign(&blind_sign_request_body), timeout)
+        .await
+        .ok_or(SignerFailure::Timeout { node_id })?
+        .map_err(|err| SignerFailure::RequestFailure {
+            node_id,
+            reason: err.to_string(),
+        })?;
+
+    let invalid_share = |reason: String| SignerFailure::InvalidShare { node_id, reason };
+
     let encrypted_signature = response.encrypted_signature;
-    let remote_key = PublicKey::from_bytes(&response.remote_key)?;
+    let remote_key = PublicKey::from_bytes(&response.remote_key)
+        .map_err(|err| invalid_share(err.to_string()))?;
 
     let encryption_key = recompute_shared_key::<
         NymApiCredentialEncryptionAlgorithm,
@@ -68,67 +190,109 @@ async fn obtain_partial_credential(
         &encrypted_signature,
     );
 
-    let blinded_signature = BlindedSignature::from_bytes(&blinded_signature_bytes)?;
+    let blinded_signature = BlindedSignature::from_bytes(&blinded_signature_bytes)
+        .map_err(|err| invalid_share(err.to_string()))?;
+
+    // note: unblinding also verifies the resultant share against the signer's verification key
+    blinded_signature
+        .unblind(
+            params,
+            signer.verification_key(),
+            &private_attributes,
+            &public_attributes,
+            &blind_sign_request.get_commitment_hash(),
+            attributes.pedersen_commitments_openings(),
+        )
+        .map_err(|err| invalid_share(err.to_string()))
+}
 
-    let unblinded_signature = blinded_signature.unblind(
+/// Obtains the aggregated credential for the provided voucher using the default per-signer timeout.
+/// Any signers that failed to provide valid shares are logged.
+pub async fn obtain_aggregate_signature<S: CoconutSigner>(
+    params: &Parameters,
+    attributes: &BandwidthVoucher,
+    signers: &[S],
+    threshold: u64,
+) -> Result<Signature, Error> {
+    let aggregated = obtain_aggregate_signature_with_timeout(
         params,
-        validator_vk,
-        &private_attributes,
-        &public_attributes,
-        &blind_sign_request.get_commitment_hash(),
-        attributes.pedersen_commitments_openings(),
-    )?;
+        attributes,
+        signers,
+        threshold,
+        DEFAULT_SIGNER_TIMEOUT,
+    )
+    .await?;
 
-    Ok(unblinded_signature)
+    for failure in &aggregated.failures {
+        warn!("{failure}");
+    }
+    Ok(aggregated.signature)
 }
 
-pub async fn obtain_aggregate_signature(
+/// Concurrently queries all the signers for their partial credentials and aggregates them
+/// as soon as `threshold` of them have been obtained and verified.
+/// Signers that are still processing the request at that point are no longer waited for.
+pub async fn obtain_aggregate_signature_with_timeout<S: CoconutSigner>(
     params: &Parameters,
     attributes: &BandwidthVoucher,
-    coconut_api_clients: &[CoconutApiClient],
+    signers: &[S],
     threshold: u64,
-) -> Result<Signature, Error> {
-    if coconut_api_clients.is_empty() {
+    timeout: Duration,
+) -> Result<AggregatedCredential, Error> {
+    if signers.is_empty() {
         return Err(Error::NoValidatorsAvailable);
     }
     let public_attributes = attributes.get_public_attributes();
     let private_attributes = attributes.get_private_attributes();
 
-    let mut shares = Vec::with_capacity(coconut_api_clients.len());
-    let validators_partial_vks: Vec<_> = coconut_api_clients
-        .iter()
-        .map(|api_client| api_client.verification_key.clone())
-        .collect();
-    let indices: Vec<_> = coconut_api_clients
+    let mut pending = signers
         .iter()
-        .map(|api_client| api_client.node_id)
-        .collect();
-    let verification_key =
-        aggregate_verification_keys(&validators_partial_vks, Some(indices.as_ref()))?;
+        .map(|signer| async move {
+            let partial = obtain_partial_credential(params, attributes, signer, timeout).await;
+            (signer, partial)
+        })
+        .collect::<FuturesUnordered<_>>();
 
-    for coconut_api_client in coconut_api_clients.iter() {
-        if let Ok(signature) = obtain_partial_credential(
-            params,
-            attributes,
-            &coconut_api_client.api_client,
-            &coconut_api_client.verification_key,
-        )
-        .await
-        {
-            let share = SignatureShare::new(signature, coconut_api_client.node_id);
-            shares.push(share)
+    let mut shares = Vec::with_capacity(threshold as usize);
+    let mut share_keys = Vec::with_capacity(threshold as usize);
+    let mut failures = Vec::new();
+    while let Some((signer, partial)) = pending.next().await {
+        match partial {
+            Ok(signature) => {
+                debug!("obtained valid share from signer {}", signer.node_id());
+                shares.push(SignatureShare::new(signature, signer.node_id()));
+                share_keys.push(signer.verification_key().clone());
+                if shares.len() >= threshold as usize {
+                    break;
+                }
+            }
+            Err(failure) => failures.push(failure),
         }
     }
+
     if shares.len() < threshold as usize {
-        return Err(Error::NotEnoughShares);
+        return Err(Error::NotEnoughShares {
+            obtained: shares.len(),
+            threshold,
+            failures,
+        });
     }
 
+    // the key is aggregated only over the signers whose shares we're using
+    let indices = shares.iter().map(|share| share.index()).collect::<Vec<_>>();
+    let verification_key = aggregate_verification_keys(&share_keys, Some(&indices))?;
+
     let mut attributes = Vec::with_capacity(private_attributes.len() + public_attributes.len());
     attributes.extend_from_slice(&private_attributes);
     attributes.extend_from_slice(&public_attributes);
 
-    aggregate_signature_shares(params, &verification_key, &attributes, &shares)
-        .map_err(Error::SignatureAggregationError)
+    let signature = aggregate_signature_shares(params, &verification_key, &attributes, &shares)
+        .map_err(Error::SignatureAggregationError)?;
+
+    Ok(AggregatedCredential {
+        signature,
+        failures,
+    })
 }
 
 // TODO: better type flow
@@ -159,3 +323,253 @@ pub fn prepare_credential_for_spending(
         epoch_id,
     ))
 }
+
+/// Prepares a single part of a divisible credential for spending.
+// TODO: better type flow
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_partial_credential_for_spending(
+    params: &Parameters,
+    voucher_value: u64,
+    voucher_info: String,
+    serial_number: Attribute,
+    binding_number: Attribute,
+    epoch_id: u64,
+    spend_index: u64,
+    signature: &Signature,
+    verification_key: &VerificationKey,
+) -> Result<Credential, Error> {
+    let theta = prove_partial_bandwidth_credential(
+        params,
+        verification_key,
+        signature,
+        serial_number,
+        binding_number,
+        spend_index,
+    )?;
+
+    Ok(Credential::new_partial(
+        PUBLIC_ATTRIBUTES + PRIVATE_ATTRIBUTES,
+        theta,
+        voucher_value,
+        voucher_info,
+        epoch_id,
+        spend_index,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmrs::tx::Hash;
+    use nym_coconut_interface::{blind_sign, ttp_keygen, verify_credential, KeyPair};
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_crypto::shared_key::new_ephemeral_shared_key;
+    use rand::rngs::OsRng;
+
+    #[derive(Clone, Copy)]
+    enum Behaviour {
+        Honest,
+        Unresponsive,
+        Failing,
+        Malicious,
+    }
+
+    struct MockSigner {
+        node_id: NodeIndex,
+        keypair: KeyPair,
+        verification_key: VerificationKey,
+        behaviour: Behaviour,
+        client_encryption_key: encryption::PublicKey,
+    }
+
+    #[async_trait]
+    impl CoconutSigner for MockSigner {
+        fn node_id(&self) -> NodeIndex {
+            self.node_id
+        }
+
+        fn verification_key(&self) -> &VerificationKey {
+            &self.verification_key
+        }
+
+        async fn blind_sign(
+            &self,
+            request_body: &BlindSignRequestBody,
+        ) -> Result<BlindedSignatureResponse, Error> {
+            let params = Parameters::new(*request_body.total_params())?;
+            let secret_key = match self.behaviour {
+                Behaviour::Honest => self.keypair.secret_key(),
+                Behaviour::Unresponsive => {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    self.keypair.secret_key()
+                }
+                Behaviour::Failing => {
+                    return Err(Error::IOError(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionRefused,
+                        "nym-api is down",
+                    )))
+                }
+                // sign with a key that doesn't match the announced verification key
+                Behaviour::Malicious => ttp_keygen(&params, 1, 1)?.remove(0).secret_key(),
+            };
+
+            let blinded_signature = blind_sign(
+                &params,
+                &secret_key,
+                request_body.blind_sign_request(),
+                &request_body.public_attributes(),
+            )?;
+
+            let mut rng = OsRng;
+            let (keypair, shared_key) = new_ephemeral_shared_key::<
+                NymApiCredentialEncryptionAlgorithm,
+                NymApiCredentialHkdfAlgorithm,
+                _,
+            >(&mut rng, &self.client_encryption_key);
+            let zero_iv = stream_cipher::zero_iv::<NymApiCredentialEncryptionAlgorithm>();
+            let encrypted_signature = stream_cipher::encrypt::<NymApiCredentialEncryptionAlgorithm>(
+                &shared_key,
+                &zero_iv,
+                &blinded_signature.to_bytes(),
+            );
+
+            Ok(BlindedSignatureResponse::new(
+                encrypted_signature,
+                keypair.public_key().to_bytes(),
+            ))
+        }
+    }
+
+    fn setup_signers(
+        params: &Parameters,
+        behaviours: &[Behaviour],
+        threshold: u64,
+    ) -> (BandwidthVoucher, Vec<MockSigner>) {
+        let mut rng = OsRng;
+        let identity_keypair = identity::KeyPair::new(&mut rng);
+        let encryption_keypair = encryption::KeyPair::new(&mut rng);
+        let voucher = BandwidthVoucher::new(
+            params,
+            "1234".to_string(),
+            "voucher info".to_string(),
+            Hash::new([0; 32]),
+            identity::PrivateKey::from_base58_string(
+                identity_keypair.private_key().to_base58_string(),
+            )
+            .unwrap(),
+            encryption::PrivateKey::from_bytes(&encryption_keypair.private_key().to_bytes())
+                .unwrap(),
+        );
+
+        let keypairs = ttp_keygen(params, threshold, behaviours.len() as u64).unwrap();
+        let signers = keypairs
+            .into_iter()
+            .zip(behaviours)
+            .enumerate()
+            .map(|(i, (keypair, behaviour))| MockSigner {
+                node_id: i as NodeIndex + 1,
+                verification_key: keypair.verification_key(),
+                keypair,
+                behaviour: *behaviour,
+                client_encryption_key: *encryption_keypair.public_key(),
+            })
+            .collect();
+
+        (voucher, signers)
+    }
+
+    #[tokio::test]
+    async fn aggregation_succeeds_with_threshold_of_valid_shares() {
+        let params = Parameters::new(4).unwrap();
+        let behaviours = [
+            Behaviour::Failing,
+            Behaviour::Malicious,
+            Behaviour::Unresponsive,
+            Behaviour::Honest,
+            Behaviour::Honest,
+            Behaviour::Honest,
+        ];
+        let (voucher, signers) = setup_signers(&params, &behaviours, 3);
+
+        let aggregated = obtain_aggregate_signature_with_timeout(
+            &params,
+            &voucher,
+            &signers,
+            3,
+            Duration::from_millis(500),
+        )
+        .await
+        .unwrap();
+
+        // honest signers are never reported
+        assert!(aggregated.failures.iter().all(|f| f.node_id() <= 3));
+
+        let honest = &signers[3..];
+        let verification_key = aggregate_verification_keys(
+            &honest
+                .iter()
+                .map(|s| s.verification_key.clone())
+                .collect::<Vec<_>>(),
+            Some(&honest.iter().map(|s| s.node_id).collect::<Vec<_>>()),
+        )
+        .unwrap();
+        let theta = prove_bandwidth_credential(
+            &params,
+            &verification_key,
+            &aggregated.signature,
+            voucher.get_private_attributes()[0],
+            voucher.get_private_attributes()[1],
+        )
+        .unwrap();
+        assert!(verify_credential(
+            &params,
+            &verification_key,
+            &theta,
+            &voucher.get_public_attributes()
+        ));
+    }
+
+    #[tokio::test]
+    async fn misbehaving_signers_are_reported() {
+        let params = Parameters::new(4).unwrap();
+        let behaviours = [
+            Behaviour::Honest,
+            Behaviour::Unresponsive,
+            Behaviour::Malicious,
+            Behaviour::Failing,
+        ];
+        let (voucher, signers) = setup_signers(&params, &behaviours, 2);
+
+        let err = obtain_aggregate_signature_with_timeout(
+            &params,
+            &voucher,
+            &signers,
+            2,
+            Duration::from_millis(100),
+        )
+        .await
+        .unwrap_err();
+
+        let (obtained, threshold, mut failures) = match err {
+            Error::NotEnoughShares {
+                obtained,
+                threshold,
+                failures,
+            } => (obtained, threshold, failures),
+            err => panic!("unexpected error: {err}"),
+        };
+        assert_eq!(obtained, 1);
+        assert_eq!(threshold, 2);
+
+        failures.sort_by_key(|f| f.node_id());
+        assert!(matches!(failures[0], SignerFailure::Timeout { node_id: 2 }));
+        assert!(matches!(
+            failures[1],
+            SignerFailure::InvalidShare { node_id: 3, .. }
+        ));
+        assert!(matches!(
+            failures[2],
+            SignerFailure::RequestFailure { node_id: 4, .. }
+        ));
+    }
+}
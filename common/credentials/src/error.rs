@@ -1,6 +1,7 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::coconut::utils::SignerFailure;
 use nym_coconut_interface::CoconutError;
 use nym_crypto::asymmetric::encryption::KeyRecoveryError;
 use nym_validator_client::ValidatorClientError;
@@ -33,8 +34,12 @@ pub enum Error {
     #[error("Could not parse the key - {0}")]
     ParsePublicKey(#[from] KeyRecoveryError),
 
-    #[error("Could not gather enough signature shares. Try again using the recovery command")]
-    NotEnoughShares,
+    #[error("Could not gather enough signature shares (obtained {obtained}, required {threshold}). Try again using the recovery command")]
+    NotEnoughShares {
+        obtained: usize,
+        threshold: u64,
+        failures: Vec<SignerFailure>,
+    },
 
     #[error("Could not aggregate signature shares - {0}. Try again using the recovery command")]
     SignatureAggregationError(CoconutError),
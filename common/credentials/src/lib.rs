@@ -4,4 +4,7 @@
 pub mod coconut;
 pub mod error;
 
-pub use coconut::utils::{obtain_aggregate_signature, obtain_aggregate_verification_key};
+pub use coconut::utils::{
+    obtain_aggregate_signature, obtain_aggregate_signature_with_timeout,
+    obtain_aggregate_verification_key,
+};
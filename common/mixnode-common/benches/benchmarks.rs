@@ -0,0 +1,134 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use nym_mixnode_common::packet_processor::processor::{MixProcessingResult, SphinxPacketProcessor};
+use nym_sphinx_addressing::nodes::NymNodeRoutingAddress;
+use nym_sphinx_framing::packet::FramedNymPacket;
+use nym_sphinx_params::{PacketSize, PacketType};
+use nym_sphinx_types::crypto::keygen;
+use nym_sphinx_types::{
+    Delay, Destination, DestinationAddressBytes, Node, NymPacket, DESTINATION_ADDRESS_LENGTH,
+};
+use std::net::SocketAddr;
+
+// 3 mix layers + the egress gateway
+const ROUTE_LENGTH: usize = 4;
+
+const SPHINX_PACKET_SIZES: [PacketSize; 5] = [
+    PacketSize::AckPacket,
+    PacketSize::RegularPacket,
+    PacketSize::ExtendedPacket8,
+    PacketSize::ExtendedPacket16,
+    PacketSize::ExtendedPacket32,
+];
+
+const OUTFOX_PACKET_SIZES: [PacketSize; 2] =
+    [PacketSize::OutfoxAckPacket, PacketSize::OutfoxRegularPacket];
+
+struct RouteFixture {
+    processors: Vec<SphinxPacketProcessor>,
+    route: Vec<Node>,
+    destination: Destination,
+}
+
+impl RouteFixture {
+    fn new() -> Self {
+        let mut processors = Vec::with_capacity(ROUTE_LENGTH);
+        let mut route = Vec::with_capacity(ROUTE_LENGTH);
+
+        for i in 0..ROUTE_LENGTH {
+            let (private_key, public_key) = keygen();
+            let address: SocketAddr = format!("10.0.0.{}:1789", i + 1).parse().unwrap();
+            let address_bytes = NymNodeRoutingAddress::from(address).try_into().unwrap();
+
+            processors.push(SphinxPacketProcessor::new(private_key));
+            route.push(Node::new(address_bytes, public_key));
+        }
+
+        RouteFixture {
+            processors,
+            route,
+            destination: Destination::new(
+                DestinationAddressBytes::from_bytes([42u8; DESTINATION_ADDRESS_LENGTH]),
+                [0u8; 16],
+            ),
+        }
+    }
+
+    fn build_packet(&self, packet_size: PacketSize, packet_type: PacketType) -> NymPacket {
+        let payload = vec![42u8; packet_size.plaintext_size()];
+
+        match packet_type {
+            PacketType::Outfox => NymPacket::outfox_build(
+                payload,
+                &self.route,
+                &self.destination,
+                Some(packet_size.plaintext_size()),
+            )
+            .unwrap(),
+            _ => {
+                let delays = vec![Delay::new_from_millis(0); ROUTE_LENGTH];
+                NymPacket::sphinx_build(
+                    packet_size.payload_size(),
+                    payload,
+                    &self.route,
+                    &self.destination,
+                    &delays,
+                )
+                .unwrap()
+            }
+        }
+    }
+
+    /// Creates a framed packet as it would have been received by the node at the specified position
+    /// on the route, i.e. after being processed by all of the preceding hops.
+    fn packet_at_hop(
+        &self,
+        hop: usize,
+        packet_size: PacketSize,
+        packet_type: PacketType,
+    ) -> FramedNymPacket {
+        let mut packet = self.build_packet(packet_size, packet_type);
+        for processor in &self.processors[..hop] {
+            let framed = FramedNymPacket::new(packet, packet_type, false);
+            packet = match processor.process_received(framed).unwrap() {
+                MixProcessingResult::ForwardHop(mix_packet, _) => mix_packet.into_packet(),
+                MixProcessingResult::FinalHop(_) => panic!("reached final hop too early"),
+            }
+        }
+
+        FramedNymPacket::new(packet, packet_type, false)
+    }
+}
+
+fn process_forward_hops(c: &mut Criterion, packet_type: PacketType, sizes: &[PacketSize]) {
+    let fixture = RouteFixture::new();
+    let mut group = c.benchmark_group(format!("process_received_{packet_type}"));
+
+    for &packet_size in sizes {
+        // the last hop is the gateway which is responsible for the final unwrapping
+        for hop in 0..ROUTE_LENGTH - 1 {
+            let id = format!("{packet_size}/layer_{}", hop + 1);
+            group.bench_function(BenchmarkId::from_parameter(id), |b| {
+                b.iter_batched(
+                    || fixture.packet_at_hop(hop, packet_size, packet_type),
+                    |framed| black_box(fixture.processors[hop].process_received(framed).unwrap()),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+fn sphinx_processing(c: &mut Criterion) {
+    process_forward_hops(c, PacketType::Mix, &SPHINX_PACKET_SIZES)
+}
+
+fn outfox_processing(c: &mut Criterion) {
+    process_forward_hops(c, PacketType::Outfox, &OUTFOX_PACKET_SIZES)
+}
+
+criterion_group!(benches, sphinx_processing, outfox_processing);
+criterion_main!(benches);
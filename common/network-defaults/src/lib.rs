@@ -406,6 +406,11 @@ pub const BANDWIDTH_VALUE: u64 = UTOKENS_TO_BURN * BYTES_PER_UTOKEN;
 
 pub const VOUCHER_INFO: &str = "BandwidthVoucher";
 
+/// Voucher info of credentials that can be spent in parts, possibly with different gateways.
+pub const DIVISIBLE_VOUCHER_INFO: &str = "DivisibleBandwidthVoucher";
+/// Number of equally valued parts a divisible credential can be split into.
+pub const DIVISIBLE_VOUCHER_SPENDS: u64 = 10;
+
 pub const ETH_MIN_BLOCK_DEPTH: usize = 7;
 
 /// Defaults Cosmos Hub/ATOM path
@@ -6,9 +6,10 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use ff::Field;
 use group::{Curve, Group};
 use nym_coconut::{
-    aggregate_signature_shares, aggregate_verification_keys, blind_sign, elgamal_keygen,
-    prepare_blind_sign, prove_bandwidth_credential, setup, ttp_keygen, verify_credential,
-    Attribute, BlindedSignature, Parameters, Signature, SignatureShare, VerificationKey,
+    aggregate_signature_shares, aggregate_verification_keys, batch_blind_sign,
+    batch_verify_credentials, blind_sign, elgamal_keygen, prepare_blind_sign,
+    prove_bandwidth_credential, setup, ttp_keygen, verify_credential, Attribute, BlindSignRequest,
+    BlindedSignature, Parameters, Signature, SignatureShare, Theta, VerificationKey,
 };
 use rand::seq::SliceRandom;
 use std::ops::Neg;
@@ -333,6 +334,96 @@ fn bench_coconut(c: &mut Criterion) {
             })
         },
     );
+
+    // BATCH OPERATIONS
+    // compare issuing and verifying multiple credentials one by one against doing it in a batch
+    let batch_size = 10;
+
+    let blind_sign_requests: Vec<BlindSignRequest> = (0..batch_size)
+        .map(|_| {
+            prepare_blind_sign(&params, &private_attributes, &public_attributes)
+                .unwrap()
+                .1
+        })
+        .collect();
+    let sign_requests: Vec<(&BlindSignRequest, &[Attribute])> = blind_sign_requests
+        .iter()
+        .map(|request| (request, public_attributes.as_slice()))
+        .collect();
+
+    group.bench_function(
+        &format!(
+            "[Validator] compute_{}_blind_signs_sequentially_with_{}_attributes",
+            batch_size,
+            case.num_attrs(),
+        ),
+        |b| {
+            b.iter(|| {
+                for (request, public_attributes) in &sign_requests {
+                    blind_sign(&params, &keypair.secret_key(), request, public_attributes).unwrap();
+                }
+            })
+        },
+    );
+
+    group.bench_function(
+        &format!(
+            "[Validator] compute_batch_of_{}_blind_signs_with_{}_attributes",
+            batch_size,
+            case.num_attrs(),
+        ),
+        |b| b.iter(|| batch_blind_sign(&params, &keypair.secret_key(), &sign_requests).unwrap()),
+    );
+
+    let thetas: Vec<Theta> = (0..batch_size)
+        .map(|_| {
+            prove_bandwidth_credential(
+                &params,
+                &aggr_verification_key,
+                &aggregated_signature,
+                serial_number,
+                binding_number,
+            )
+            .unwrap()
+        })
+        .collect();
+    let credentials: Vec<(&Theta, &[Attribute])> = thetas
+        .iter()
+        .map(|theta| (theta, public_attributes.as_slice()))
+        .collect();
+    assert!(batch_verify_credentials(
+        &params,
+        &aggr_verification_key,
+        &credentials
+    ));
+
+    group.bench_function(
+        &format!(
+            "[Verifier] verify_{}_credentials_sequentially_{}_authorities_{}_attributes_{}_threshold",
+            batch_size,
+            case.num_authorities,
+            case.num_attrs(),
+            case.threshold_p,
+        ),
+        |b| {
+            b.iter(|| {
+                credentials.iter().all(|(theta, public_attributes)| {
+                    verify_credential(&params, &aggr_verification_key, theta, public_attributes)
+                })
+            })
+        },
+    );
+
+    group.bench_function(
+        &format!(
+            "[Verifier] batch_verify_{}_credentials_{}_authorities_{}_attributes_{}_threshold",
+            batch_size,
+            case.num_authorities,
+            case.num_attrs(),
+            case.threshold_p,
+        ),
+        |b| b.iter(|| batch_verify_credentials(&params, &aggr_verification_key, &credentials)),
+    );
 }
 criterion_group!(benches, bench_coconut);
 criterion_main!(benches);
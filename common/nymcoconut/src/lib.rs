@@ -12,6 +12,7 @@ pub use elgamal::PublicKey;
 pub use error::CoconutError;
 pub use scheme::aggregation::aggregate_signature_shares;
 pub use scheme::aggregation::aggregate_verification_keys;
+pub use scheme::issuance::batch_blind_sign;
 pub use scheme::issuance::blind_sign;
 pub use scheme::issuance::prepare_blind_sign;
 pub use scheme::issuance::BlindSignRequest;
@@ -21,9 +22,13 @@ pub use scheme::keygen::SecretKey;
 pub use scheme::keygen::VerificationKey;
 pub use scheme::setup::setup;
 pub use scheme::setup::Parameters;
+pub use scheme::verification::batch_verify_credentials;
 pub use scheme::verification::check_vk_pairing;
+pub use scheme::verification::compute_spend_zeta;
 pub use scheme::verification::prove_bandwidth_credential;
+pub use scheme::verification::prove_partial_bandwidth_credential;
 pub use scheme::verification::verify_credential;
+pub use scheme::verification::verify_partial_credential;
 pub use scheme::verification::Theta;
 pub use scheme::BlindedSignature;
 pub use scheme::Signature;
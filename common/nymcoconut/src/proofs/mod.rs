@@ -6,7 +6,7 @@
 use std::borrow::Borrow;
 use std::convert::TryInto;
 
-use bls12_381::{G1Projective, G2Projective, Scalar};
+use bls12_381::{G1Affine, G1Projective, G2Projective, Scalar};
 use digest::generic_array::typenum::Unsigned;
 use digest::Digest;
 use group::GroupEncoding;
@@ -64,6 +64,15 @@ where
     Scalar::from_bytes_wide(&bytes)
 }
 
+/// Byte representations of the parameters every [`ProofCmCs`] challenge is computed over,
+/// i.e. g1 || hs[0] || ... || hs[n], so that they could be shared between multiple proofs.
+pub(crate) fn challenge_params_bytes(params: &Parameters) -> Vec<[u8; 48]> {
+    std::iter::once(params.gen1())
+        .chain(params.gen_hs().iter())
+        .map(|point| point.to_compressed())
+        .collect()
+}
+
 fn produce_response(witness: &Scalar, challenge: &Scalar, secret: &Scalar) -> Scalar {
     witness - challenge * secret
 }
@@ -183,20 +192,38 @@ impl ProofCmCs {
         commitments: &[G1Projective],
         public_attributes: &[Attribute],
     ) -> bool {
+        // recompute h
+        let h = compute_hash(*commitment, public_attributes);
+
+        let challenge_points =
+            match self.challenge_points(params, &h, commitment, commitments, public_attributes) {
+                Some(points) => points,
+                None => return false,
+            };
+
+        let mut normalized_points = vec![G1Affine::identity(); challenge_points.len()];
+        G1Projective::batch_normalize(&challenge_points, &mut normalized_points);
+
+        self.challenge_matches(&challenge_params_bytes(params), &normalized_points)
+    }
+
+    /// Recomputes the witnesses commitments of the proof and returns all the (non-parameter) points
+    /// the challenge is computed over, i.e. h || cm || c[0] || ... || c[m] || Cw || Cw[0] || ... || Cw[m].
+    /// Returns `None` if the proof does not match the provided commitments.
+    pub(crate) fn challenge_points(
+        &self,
+        params: &Parameters,
+        commitment_hash: &G1Projective,
+        commitment: &G1Projective,
+        commitments: &[G1Projective],
+        public_attributes: &[Attribute],
+    ) -> Option<Vec<G1Projective>> {
         if self.response_attributes.len() != commitments.len() {
-            return false;
+            return None;
         }
 
-        // recompute h
-        let h = compute_hash(*commitment, public_attributes);
         let g1 = params.gen1();
 
-        let hs_bytes = params
-            .gen_hs()
-            .iter()
-            .map(|h| h.to_bytes())
-            .collect::<Vec<_>>();
-
         // recompute witnesses commitments
         // Cw = (cm * c) + (rr * g1) + (rm[0] * hs[0]) + ... + (rm[n] * hs[n])
         let commitment_attributes = (commitment
@@ -219,28 +246,31 @@ impl ProofCmCs {
             self.response_openings.iter(),
             self.response_attributes.iter()
         )
-        .map(|(cm_j, r_o_j, r_m_j)| cm_j * self.challenge + g1 * r_o_j + h * r_m_j)
-        .collect::<Vec<_>>();
-
-        let commitments_bytes = commitments
-            .iter()
-            .map(|cm| cm.to_bytes())
-            .collect::<Vec<_>>();
-
-        let commitments_attributes_bytes = commitments_attributes
-            .iter()
-            .map(|cm| cm.to_bytes())
-            .collect::<Vec<_>>();
+        .map(|(cm_j, r_o_j, r_m_j)| cm_j * self.challenge + g1 * r_o_j + commitment_hash * r_m_j);
+
+        Some(
+            [*commitment_hash, *commitment]
+                .into_iter()
+                .chain(commitments.iter().copied())
+                .chain(std::iter::once(commitment_attributes))
+                .chain(commitments_attributes)
+                .collect(),
+        )
+    }
 
+    /// Checks whether the challenge of the proof matches the one re-computed over the parameters bytes
+    /// (as returned by [`challenge_params_bytes`]) and the affine form of the [`Self::challenge_points`].
+    pub(crate) fn challenge_matches(
+        &self,
+        params_bytes: &[[u8; 48]],
+        challenge_points: &[G1Affine],
+    ) -> bool {
         // re-compute the challenge
         let challenge = compute_challenge::<ChallengeDigest, _, _>(
-            std::iter::once(params.gen1().to_bytes().as_ref())
-                .chain(hs_bytes.iter().map(|hs| hs.as_ref()))
-                .chain(std::iter::once(h.to_bytes().as_ref()))
-                .chain(std::iter::once(commitment.to_bytes().as_ref()))
-                .chain(commitments_bytes.iter().map(|cm| cm.as_ref()))
-                .chain(std::iter::once(commitment_attributes.to_bytes().as_ref()))
-                .chain(commitments_attributes_bytes.iter().map(|cm| cm.as_ref())),
+            params_bytes
+                .iter()
+                .copied()
+                .chain(challenge_points.iter().map(|point| point.to_compressed())),
         );
 
         challenge == self.challenge
@@ -349,6 +379,7 @@ impl ProofKappaZeta {
         blinding_factor: &Scalar,
         blinded_message: &G2Projective,
         blinded_serial_number: &G2Projective,
+        spend_index: Option<u64>,
     ) -> Self {
         // create the witnesses
         let witness_blinder = params.random_scalar();
@@ -372,8 +403,14 @@ impl ProofKappaZeta {
                 .map(|(wm_i, beta_i)| beta_i * wm_i)
                 .sum::<G2Projective>();
 
-        // zeta is the public value associated with the serial number
-        let commitment_zeta = params.gen2() * witness_serial_number;
+        // zeta is the public value associated with the serial number.
+        // for a partial spend, zeta = g2 * (1 / (serial_number + spend_index)),
+        // so we prove that zeta * serial_number = g2 - zeta * spend_index instead
+        let commitment_zeta = match spend_index {
+            None => params.gen2() * witness_serial_number,
+            Some(_) => blinded_serial_number * witness_serial_number,
+        };
+        let spend_index_bytes = spend_index.map(u64::to_be_bytes);
 
         let challenge = compute_challenge::<ChallengeDigest, _, _>(
             std::iter::once(params.gen2().to_bytes().as_ref())
@@ -382,7 +419,8 @@ impl ProofKappaZeta {
                 .chain(std::iter::once(verification_key.alpha.to_bytes().as_ref()))
                 .chain(beta_bytes.iter().map(|b| b.as_ref()))
                 .chain(std::iter::once(commitment_kappa.to_bytes().as_ref()))
-                .chain(std::iter::once(commitment_zeta.to_bytes().as_ref())),
+                .chain(std::iter::once(commitment_zeta.to_bytes().as_ref()))
+                .chain(spend_index_bytes.iter().map(|b| b.as_ref())),
         );
 
         // responses
@@ -410,6 +448,7 @@ impl ProofKappaZeta {
         verification_key: &VerificationKey,
         kappa: &G2Projective,
         zeta: &G2Projective,
+        spend_index: Option<u64>,
     ) -> bool {
         let beta_bytes = verification_key
             .beta_g2
@@ -430,7 +469,16 @@ impl ProofKappaZeta {
                 .sum::<G2Projective>();
 
         // zeta is the public value associated with the serial number
-        let commitment_zeta = zeta * self.challenge + params.gen2() * self.response_serial_number;
+        let commitment_zeta = match spend_index {
+            None => zeta * self.challenge + params.gen2() * self.response_serial_number,
+            // Aw = c * (g2 - zeta * spend_index) + rm[0] * zeta
+            Some(spend_index) => {
+                (G2Projective::from(params.gen2()) - zeta * Scalar::from(spend_index))
+                    * self.challenge
+                    + zeta * self.response_serial_number
+            }
+        };
+        let spend_index_bytes = spend_index.map(u64::to_be_bytes);
 
         // compute the challenge
         let challenge = compute_challenge::<ChallengeDigest, _, _>(
@@ -440,7 +488,8 @@ impl ProofKappaZeta {
                 .chain(std::iter::once(verification_key.alpha.to_bytes().as_ref()))
                 .chain(beta_bytes.iter().map(|b| b.as_ref()))
                 .chain(std::iter::once(commitment_kappa.to_bytes().as_ref()))
-                .chain(std::iter::once(commitment_zeta.to_bytes().as_ref())),
+                .chain(std::iter::once(commitment_zeta.to_bytes().as_ref()))
+                .chain(spend_index_bytes.iter().map(|b| b.as_ref())),
         );
 
         challenge == self.challenge
@@ -579,6 +628,7 @@ mod tests {
             &r,
             &kappa,
             &zeta,
+            None,
         );
 
         let proof_bytes = pi_v.to_bytes();
@@ -598,6 +648,7 @@ mod tests {
             &r,
             &kappa,
             &zeta,
+            None,
         );
 
         let proof_bytes = pi_v.to_bytes();
@@ -8,7 +8,7 @@ use bls12_381::{G1Affine, G1Projective, Scalar};
 use group::{Curve, GroupEncoding};
 
 use crate::error::{CoconutError, Result};
-use crate::proofs::ProofCmCs;
+use crate::proofs::{challenge_params_bytes, ProofCmCs};
 use crate::scheme::setup::Parameters;
 use crate::scheme::BlindedSignature;
 use crate::scheme::SecretKey;
@@ -271,6 +271,85 @@ pub fn blind_sign(
     blind_sign_request: &BlindSignRequest,
     public_attributes: &[Attribute],
 ) -> Result<BlindedSignature> {
+    check_blind_sign_request(params, blind_sign_request, public_attributes)?;
+
+    // Verify the ZK proof
+    if !blind_sign_request.verify_proof(params, public_attributes) {
+        return Err(CoconutError::Issuance(
+            "Failed to verify the proof of knowledge".to_string(),
+        ));
+    }
+
+    Ok(sign_blind_sign_request(
+        signing_secret_key,
+        blind_sign_request,
+        public_attributes,
+    ))
+}
+
+/// Issues blinded signatures for multiple requests at once, i.e. when a single client
+/// obtains several credentials in one go. It fails if any of the requests is invalid.
+///
+/// As opposed to calling [`blind_sign`] for every request, the parameters are only serialized once
+/// for all the proofs of knowledge and the points of all their challenges are converted
+/// to the affine form together, with a single field inversion.
+pub fn batch_blind_sign(
+    params: &Parameters,
+    signing_secret_key: &SecretKey,
+    requests: &[(&BlindSignRequest, &[Attribute])],
+) -> Result<Vec<BlindedSignature>> {
+    let mut challenge_points = Vec::new();
+    let mut challenge_ranges = Vec::with_capacity(requests.len());
+    for (blind_sign_request, public_attributes) in requests {
+        check_blind_sign_request(params, blind_sign_request, public_attributes)?;
+
+        let points = blind_sign_request
+            .pi_s
+            .challenge_points(
+                params,
+                &blind_sign_request.commitment_hash,
+                &blind_sign_request.commitment,
+                &blind_sign_request.private_attributes_commitments,
+                public_attributes,
+            )
+            .ok_or_else(|| {
+                CoconutError::Issuance("Failed to verify the proof of knowledge".to_string())
+            })?;
+
+        challenge_ranges.push(challenge_points.len()..challenge_points.len() + points.len());
+        challenge_points.extend(points);
+    }
+
+    let mut normalized_points = vec![G1Affine::identity(); challenge_points.len()];
+    G1Projective::batch_normalize(&challenge_points, &mut normalized_points);
+
+    // Verify the ZK proofs
+    let params_bytes = challenge_params_bytes(params);
+    for ((blind_sign_request, _), range) in requests.iter().zip(challenge_ranges) {
+        if !blind_sign_request
+            .pi_s
+            .challenge_matches(&params_bytes, &normalized_points[range])
+        {
+            return Err(CoconutError::Issuance(
+                "Failed to verify the proof of knowledge".to_string(),
+            ));
+        }
+    }
+
+    Ok(requests
+        .iter()
+        .map(|(blind_sign_request, public_attributes)| {
+            sign_blind_sign_request(signing_secret_key, blind_sign_request, public_attributes)
+        })
+        .collect())
+}
+
+// checks whether the request does not contain too many attributes and whether its commitment hash is valid
+fn check_blind_sign_request(
+    params: &Parameters,
+    blind_sign_request: &BlindSignRequest,
+    public_attributes: &[Attribute],
+) -> Result<()> {
     let num_private = blind_sign_request.private_attributes_commitments.len();
     let hs = params.gen_hs();
 
@@ -289,12 +368,17 @@ pub fn blind_sign(
         ));
     }
 
-    // Verify the ZK proof
-    if !blind_sign_request.verify_proof(params, public_attributes) {
-        return Err(CoconutError::Issuance(
-            "Failed to verify the proof of knowledge".to_string(),
-        ));
-    }
+    Ok(())
+}
+
+// note: it's caller's responsibility to ensure the request has been verified beforehand
+fn sign_blind_sign_request(
+    signing_secret_key: &SecretKey,
+    blind_sign_request: &BlindSignRequest,
+    public_attributes: &[Attribute],
+) -> BlindedSignature {
+    let num_private = blind_sign_request.private_attributes_commitments.len();
+    let h = blind_sign_request.commitment_hash;
 
     // in python implementation there are n^2 G1 multiplications, let's do it with a single one instead.
     // i.e. compute h ^ (pub_m[0] * y[m + 1] + ... + pub_m[n] * y[m + n]) directly (where m is number of PRIVATE attributes)
@@ -315,7 +399,7 @@ pub fn blind_sign(
         .chain(std::iter::once(signed_public))
         .sum();
 
-    Ok(BlindedSignature(h, sig))
+    BlindedSignature(h, sig)
 }
 
 #[cfg(test)]
@@ -354,6 +438,7 @@ pub fn sign(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scheme::keygen::keygen;
 
     #[test]
     fn blind_sign_request_bytes_roundtrip() {
@@ -385,4 +470,58 @@ mod tests {
             lambda
         );
     }
+
+    #[test]
+    fn batch_blind_sign_matches_individual_signing() {
+        let params = Parameters::new(4).unwrap();
+        let keypair = keygen(&params);
+
+        let public_attributes = params.n_random_scalars(2);
+        let requests = (0..3)
+            .map(|_| {
+                let private_attributes = params.n_random_scalars(2);
+                prepare_blind_sign(&params, &private_attributes, &public_attributes)
+                    .unwrap()
+                    .1
+            })
+            .collect::<Vec<_>>();
+
+        let batch = requests
+            .iter()
+            .map(|request| (request, public_attributes.as_slice()))
+            .collect::<Vec<_>>();
+        let signatures = batch_blind_sign(&params, &keypair.secret_key(), &batch).unwrap();
+
+        assert_eq!(signatures.len(), requests.len());
+        for (request, signature) in requests.iter().zip(signatures) {
+            let expected =
+                blind_sign(&params, &keypair.secret_key(), request, &public_attributes).unwrap();
+            assert_eq!(signature, expected);
+        }
+    }
+
+    #[test]
+    fn batch_blind_sign_rejects_batch_with_invalid_proof() {
+        let params = Parameters::new(4).unwrap();
+        let keypair = keygen(&params);
+
+        let public_attributes = params.n_random_scalars(2);
+        let (_, valid) =
+            prepare_blind_sign(&params, &params.n_random_scalars(2), &public_attributes).unwrap();
+        let (_, other) =
+            prepare_blind_sign(&params, &params.n_random_scalars(2), &public_attributes).unwrap();
+
+        // proof that does not correspond to the commitments of the request
+        let invalid = BlindSignRequest {
+            pi_s: other.pi_s,
+            ..BlindSignRequest::try_from(valid.to_bytes().as_slice()).unwrap()
+        };
+        assert!(blind_sign(&params, &keypair.secret_key(), &invalid, &public_attributes).is_err());
+
+        let batch = [
+            (&valid, public_attributes.as_slice()),
+            (&invalid, public_attributes.as_slice()),
+        ];
+        assert!(batch_blind_sign(&params, &keypair.secret_key(), &batch).is_err());
+    }
 }
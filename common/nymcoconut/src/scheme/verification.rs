@@ -5,7 +5,7 @@ use core::ops::Neg;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
-use bls12_381::{multi_miller_loop, G1Affine, G2Prepared, G2Projective, Scalar};
+use bls12_381::{multi_miller_loop, G1Affine, G1Projective, G2Prepared, G2Projective, Scalar};
 use group::{Curve, Group};
 
 use crate::error::{CoconutError, Result};
@@ -72,12 +72,18 @@ impl TryFrom<&[u8]> for Theta {
 }
 
 impl Theta {
-    fn verify_proof(&self, params: &Parameters, verification_key: &VerificationKey) -> bool {
+    fn verify_proof(
+        &self,
+        params: &Parameters,
+        verification_key: &VerificationKey,
+        spend_index: Option<u64>,
+    ) -> bool {
         self.pi_v.verify(
             params,
             verification_key,
             &self.blinded_message,
             &self.blinded_serial_number,
+            spend_index,
         )
     }
 
@@ -146,12 +152,68 @@ pub fn compute_zeta(params: &Parameters, serial_number: Attribute) -> G2Projecti
     params.gen2() * serial_number
 }
 
+/// Computes the value associated with a single partial spend of a divisible credential,
+/// i.e. g2 * (1 / (serial_number + spend_index)).
+/// Different spends of the same credential are unlinkable, while spending the same part twice
+/// results in an identical value, allowing to detect double spending.
+pub fn compute_spend_zeta(
+    params: &Parameters,
+    serial_number: Attribute,
+    spend_index: u64,
+) -> Result<G2Projective> {
+    let exponent: Option<Scalar> = (serial_number + Scalar::from(spend_index)).invert().into();
+    exponent.map(|e| params.gen2() * e).ok_or_else(|| {
+        CoconutError::Verification(format!(
+            "could not compute the blinded serial number for spend {spend_index}"
+        ))
+    })
+}
+
 pub fn prove_bandwidth_credential(
     params: &Parameters,
     verification_key: &VerificationKey,
     signature: &Signature,
     serial_number: Attribute,
     binding_number: Attribute,
+) -> Result<Theta> {
+    prove_credential(
+        params,
+        verification_key,
+        signature,
+        serial_number,
+        binding_number,
+        None,
+    )
+}
+
+/// Produces the proof for spending a single part of a divisible credential.
+/// Rather than revealing g2 * serial_number, it reveals the value computed by [`compute_spend_zeta`],
+/// so that each part of the credential could be spent independently, for example with a different gateway.
+pub fn prove_partial_bandwidth_credential(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    signature: &Signature,
+    serial_number: Attribute,
+    binding_number: Attribute,
+    spend_index: u64,
+) -> Result<Theta> {
+    prove_credential(
+        params,
+        verification_key,
+        signature,
+        serial_number,
+        binding_number,
+        Some(spend_index),
+    )
+}
+
+fn prove_credential(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    signature: &Signature,
+    serial_number: Attribute,
+    binding_number: Attribute,
+    spend_index: Option<u64>,
 ) -> Result<Theta> {
     if verification_key.beta_g2.len() < 2 {
         return Err(
@@ -180,7 +242,10 @@ pub fn prove_bandwidth_credential(
     );
 
     // zeta is a commitment to the serial number (i.e., a public value associated with the serial number)
-    let blinded_serial_number = compute_zeta(params, serial_number);
+    let blinded_serial_number = match spend_index {
+        None => compute_zeta(params, serial_number),
+        Some(spend_index) => compute_spend_zeta(params, serial_number, spend_index)?,
+    };
 
     let pi_v = ProofKappaZeta::construct(
         params,
@@ -190,6 +255,7 @@ pub fn prove_bandwidth_credential(
         &sign_blinding_factor,
         &blinded_message,
         &blinded_serial_number,
+        spend_index,
     );
 
     Ok(Theta {
@@ -245,23 +311,12 @@ pub fn check_vk_pairing(
     true
 }
 
-pub fn verify_credential(
-    params: &Parameters,
+fn compute_full_kappa(
     verification_key: &VerificationKey,
     theta: &Theta,
     public_attributes: &[Attribute],
-) -> bool {
-    if public_attributes.len() + theta.pi_v.private_attributes_len()
-        > verification_key.beta_g2.len()
-    {
-        return false;
-    }
-
-    if !theta.verify_proof(params, verification_key) {
-        return false;
-    }
-
-    let kappa = if public_attributes.is_empty() {
+) -> G2Projective {
+    if public_attributes.is_empty() {
         theta.blinded_message
     } else {
         let signed_public_attributes = public_attributes
@@ -276,7 +331,53 @@ pub fn verify_credential(
             .sum::<G2Projective>();
 
         theta.blinded_message + signed_public_attributes
-    };
+    }
+}
+
+pub fn verify_credential(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    theta: &Theta,
+    public_attributes: &[Attribute],
+) -> bool {
+    verify_credential_spend(params, verification_key, theta, public_attributes, None)
+}
+
+/// Verifies the proof produced by [`prove_partial_bandwidth_credential`] for the specified part of the credential.
+pub fn verify_partial_credential(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    theta: &Theta,
+    public_attributes: &[Attribute],
+    spend_index: u64,
+) -> bool {
+    verify_credential_spend(
+        params,
+        verification_key,
+        theta,
+        public_attributes,
+        Some(spend_index),
+    )
+}
+
+fn verify_credential_spend(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    theta: &Theta,
+    public_attributes: &[Attribute],
+    spend_index: Option<u64>,
+) -> bool {
+    if public_attributes.len() + theta.pi_v.private_attributes_len()
+        > verification_key.beta_g2.len()
+    {
+        return false;
+    }
+
+    if !theta.verify_proof(params, verification_key, spend_index) {
+        return false;
+    }
+
+    let kappa = compute_full_kappa(verification_key, theta, public_attributes);
 
     check_bilinear_pairing(
         &theta.credential.0.to_affine(),
@@ -286,6 +387,61 @@ pub fn verify_credential(
     ) && !bool::from(theta.credential.0.is_identity())
 }
 
+/// Verifies multiple credentials at once using randomised pairing batching.
+///
+/// Rather than checking e(sigma1_i, kappa_i) == e(sigma2_i, g2) for each credential separately,
+/// every equation is raised to a random, verifier-chosen, power r_i and all of them are combined into
+/// prod_i e(r_i * sigma1_i, kappa_i) * e(-sum_i r_i * sigma2_i, g2) == id.
+/// This requires n + 1 miller loops and a single final exponentiation instead of 2n miller loops
+/// and n final exponentiations. Note that it only tells whether *all* credentials are valid.
+pub fn batch_verify_credentials(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    credentials: &[(&Theta, &[Attribute])],
+) -> bool {
+    for (theta, public_attributes) in credentials {
+        if public_attributes.len() + theta.pi_v.private_attributes_len()
+            > verification_key.beta_g2.len()
+        {
+            return false;
+        }
+
+        if bool::from(theta.credential.0.is_identity()) {
+            return false;
+        }
+
+        if !theta.verify_proof(params, verification_key, None) {
+            return false;
+        }
+    }
+
+    if credentials.is_empty() {
+        return true;
+    }
+
+    let mut g1_terms = Vec::with_capacity(credentials.len() + 1);
+    let mut g2_terms = Vec::with_capacity(credentials.len() + 1);
+    let mut aggregated_sigma2 = G1Projective::identity();
+
+    for (theta, public_attributes) in credentials {
+        let r = params.random_scalar();
+        let kappa = compute_full_kappa(verification_key, theta, public_attributes);
+
+        g1_terms.push((theta.credential.0 * r).to_affine());
+        g2_terms.push(G2Prepared::from(kappa.to_affine()));
+        aggregated_sigma2 += theta.credential.1 * r;
+    }
+    g1_terms.push(aggregated_sigma2.to_affine().neg());
+
+    let mut terms = g1_terms.iter().zip(g2_terms.iter()).collect::<Vec<_>>();
+    terms.push((g1_terms.last().unwrap(), params.prepared_miller_g2()));
+
+    multi_miller_loop(&terms)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
 // Used in tests only
 #[cfg(test)]
 pub fn verify(
@@ -312,6 +468,7 @@ pub fn verify(
 
 #[cfg(test)]
 mod tests {
+    use crate::scheme::issuance::sign;
     use crate::scheme::keygen::keygen;
     use crate::scheme::setup::setup;
 
@@ -350,4 +507,66 @@ mod tests {
         let bytes = theta.to_bytes();
         assert_eq!(Theta::try_from(bytes.as_slice()).unwrap(), theta);
     }
+
+    #[test]
+    fn partial_spends() {
+        let mut params = setup(4).unwrap();
+        let keypair = keygen(&params);
+        let verification_key = keypair.verification_key();
+
+        let serial_number = params.random_scalar();
+        let binding_number = params.random_scalar();
+        let public_attributes = params.n_random_scalars(2);
+        let mut attributes = vec![serial_number, binding_number];
+        attributes.extend_from_slice(&public_attributes);
+        let signature = sign(&mut params, &keypair.secret_key(), &attributes).unwrap();
+
+        let prove = |spend_index| {
+            prove_partial_bandwidth_credential(
+                &params,
+                &verification_key,
+                &signature,
+                serial_number,
+                binding_number,
+                spend_index,
+            )
+            .unwrap()
+        };
+
+        let theta0 = prove(0);
+        let theta1 = prove(1);
+        assert!(verify_partial_credential(
+            &params,
+            &verification_key,
+            &theta0,
+            &public_attributes,
+            0
+        ));
+        assert!(verify_partial_credential(
+            &params,
+            &verification_key,
+            &theta1,
+            &public_attributes,
+            1
+        ));
+
+        // the proof is bound to the spend index
+        assert!(!verify_partial_credential(
+            &params,
+            &verification_key,
+            &theta0,
+            &public_attributes,
+            1
+        ));
+        assert!(!verify_credential(
+            &params,
+            &verification_key,
+            &theta0,
+            &public_attributes
+        ));
+
+        // different parts are unrelated, but spending the same part twice is detectable
+        assert_ne!(theta0.blinded_serial_number, theta1.blinded_serial_number);
+        assert_eq!(prove(0).blinded_serial_number, theta0.blinded_serial_number);
+    }
 }
@@ -3,8 +3,8 @@
 
 use crate::tests::helpers::tests::generate_dkg_keys;
 use crate::{
-    aggregate_verification_keys, setup, tests::helpers::*, ttp_keygen, verify_credential,
-    CoconutError, VerificationKey,
+    aggregate_verification_keys, batch_verify_credentials, setup, tests::helpers::*, ttp_keygen,
+    verify_credential, CoconutError, VerificationKey,
 };
 
 #[test]
@@ -44,6 +44,60 @@ fn keygen() -> Result<(), CoconutError> {
     Ok(())
 }
 
+#[test]
+fn batch_verification() -> Result<(), CoconutError> {
+    let params = setup(5)?;
+    let node_indices = vec![15u64, 248, 33521];
+
+    let public_attributes = params.n_random_scalars(2);
+    let other_public_attributes = params.n_random_scalars(2);
+
+    let coconut_keypairs = ttp_keygen(&params, 2, 3)?;
+    let verification_keys: Vec<VerificationKey> = coconut_keypairs
+        .iter()
+        .map(|keypair| keypair.verification_key())
+        .collect();
+    let verification_key = aggregate_verification_keys(&verification_keys, Some(&node_indices))?;
+
+    let thetas = (0..3)
+        .map(|_| {
+            theta_from_keys_and_attributes(
+                &params,
+                &coconut_keypairs,
+                &node_indices,
+                &public_attributes,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let other_theta = theta_from_keys_and_attributes(
+        &params,
+        &coconut_keypairs,
+        &node_indices,
+        &other_public_attributes,
+    )?;
+
+    let mut credentials = thetas
+        .iter()
+        .map(|theta| (theta, public_attributes.as_slice()))
+        .collect::<Vec<_>>();
+    credentials.push((&other_theta, other_public_attributes.as_slice()));
+    assert!(batch_verify_credentials(
+        &params,
+        &verification_key,
+        &credentials
+    ));
+
+    // a single credential presented with wrong attributes invalidates the whole batch
+    credentials.push((&thetas[0], other_public_attributes.as_slice()));
+    assert!(!batch_verify_credentials(
+        &params,
+        &verification_key,
+        &credentials
+    ));
+
+    Ok(())
+}
+
 #[test]
 #[ignore] // expensive test
 fn dkg() -> Result<(), CoconutError> {
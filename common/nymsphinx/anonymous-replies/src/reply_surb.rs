@@ -6,7 +6,8 @@ use nym_crypto::{generic_array::typenum::Unsigned, Digest};
 use nym_sphinx_addressing::clients::Recipient;
 use nym_sphinx_addressing::nodes::{NymNodeRoutingAddress, MAX_NODE_ADDRESS_UNPADDED_LEN};
 use nym_sphinx_params::packet_sizes::PacketSize;
-use nym_sphinx_params::{PacketType, ReplySurbKeyDigestAlgorithm, DEFAULT_NUM_MIX_HOPS};
+use nym_sphinx_params::{PacketType, ReplySurbKeyDigestAlgorithm};
+use nym_sphinx_routing::HopDelaySampler;
 use nym_sphinx_types::{NymPacket, SURBMaterial, SphinxError, SURB};
 use nym_topology::{NymTopology, NymTopologyError};
 use rand::{CryptoRng, RngCore};
@@ -89,14 +90,15 @@ impl ReplySurb {
         rng: &mut R,
         recipient: &Recipient,
         average_delay: time::Duration,
+        delay_sampler: &dyn HopDelaySampler,
         topology: &NymTopology,
+        num_mix_hops: u8,
     ) -> Result<Self, NymTopologyError>
     where
         R: RngCore + CryptoRng,
     {
-        let route =
-            topology.random_route_to_gateway(rng, DEFAULT_NUM_MIX_HOPS, recipient.gateway())?;
-        let delays = nym_sphinx_routing::generate_hop_delays(average_delay, route.len());
+        let route = topology.random_route_to_gateway(rng, num_mix_hops, recipient.gateway())?;
+        let delays = delay_sampler.sample_hop_delays(rng, average_delay, route.len());
         let destination = recipient.as_sphinx_destination();
 
         let surb_material = SURBMaterial::new(route, delays, destination);
@@ -121,6 +123,16 @@ impl ReplySurb {
             + (1 + mix_hops as usize) * PAYLOAD_KEY_SIZE
     }
 
+    /// Returns the number of mix hops this [`ReplySURB`] has been constructed with,
+    /// i.e. the inverse of [`ReplySurb::serialized_len`].
+    pub fn num_mix_hops(&self) -> u8 {
+        use nym_sphinx_types::{HEADER_SIZE, NODE_ADDRESS_LENGTH, PAYLOAD_KEY_SIZE};
+
+        let payload_keys_len = self.surb.to_bytes().len() - HEADER_SIZE - NODE_ADDRESS_LENGTH;
+        // (note extra 1 for the gateway)
+        (payload_keys_len / PAYLOAD_KEY_SIZE - 1) as u8
+    }
+
     pub fn encryption_key(&self) -> &SurbEncryptionKey {
         &self.encryption_key
     }
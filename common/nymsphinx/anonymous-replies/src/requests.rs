@@ -3,6 +3,7 @@
 
 use crate::{ReplySurb, ReplySurbError};
 use nym_sphinx_addressing::clients::{Recipient, RecipientFormattingError};
+use nym_sphinx_params::DEFAULT_NUM_MIX_HOPS;
 use rand::{CryptoRng, RngCore};
 use std::fmt::{Display, Formatter};
 use std::mem;
@@ -159,12 +160,28 @@ impl RepliableMessage {
     }
 
     pub fn into_bytes(self) -> Vec<u8> {
-        let content_tag = self.content.tag();
+        let content_tag = self.content.tag() as u8;
+
+        // if the attached reply surbs were not built for the default number of mix hops,
+        // put their hop count in the upper nibble of the content tag so that the receiver
+        // could recover them without having to know the sender's topology.
+        // for the default number of hops the byte is left unchanged for backwards compatibility
+        let mix_hops = self
+            .content
+            .reply_surbs()
+            .first()
+            .map(|surb| surb.num_mix_hops())
+            .unwrap_or(DEFAULT_NUM_MIX_HOPS);
+        let tag_byte = if mix_hops == DEFAULT_NUM_MIX_HOPS {
+            content_tag
+        } else {
+            content_tag | (mix_hops << 4)
+        };
 
         self.sender_tag
             .to_bytes()
             .into_iter()
-            .chain(std::iter::once(content_tag as u8))
+            .chain(std::iter::once(tag_byte))
             .chain(self.content.into_bytes())
             .collect()
     }
@@ -178,7 +195,17 @@ impl RepliableMessage {
         }
         let sender_tag =
             AnonymousSenderTag::from_bytes(bytes[..SENDER_TAG_SIZE].try_into().unwrap());
-        let content_tag = RepliableMessageContentTag::try_from(bytes[SENDER_TAG_SIZE])?;
+        let tag_byte = bytes[SENDER_TAG_SIZE];
+        let content_tag = RepliableMessageContentTag::try_from(tag_byte & 0x0F)?;
+
+        // the sender only includes the number of hops if it differs from the default,
+        // otherwise fallback to whatever we were told to expect
+        let encoded_mix_hops = tag_byte >> 4;
+        let num_mix_hops = if encoded_mix_hops == 0 {
+            num_mix_hops
+        } else {
+            encoded_mix_hops
+        };
 
         let content = RepliableMessageContent::try_from_bytes(
             &bytes[SENDER_TAG_SIZE + 1..],
@@ -330,6 +357,16 @@ impl RepliableMessageContent {
         }
     }
 
+    fn reply_surbs(&self) -> &[ReplySurb] {
+        match self {
+            RepliableMessageContent::Data { reply_surbs, .. } => reply_surbs,
+            RepliableMessageContent::AdditionalSurbs { reply_surbs } => reply_surbs,
+            RepliableMessageContent::Heartbeat {
+                additional_reply_surbs,
+            } => additional_reply_surbs,
+        }
+    }
+
     fn tag(&self) -> RepliableMessageContentTag {
         match self {
             RepliableMessageContent::Data { .. } => RepliableMessageContentTag::Data,
@@ -743,6 +780,80 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod repliable_message_hops {
+        use super::*;
+
+        #[test]
+        fn default_hops_keep_the_plain_content_tag() {
+            let mut rng = fixtures::test_rng();
+
+            let data = RepliableMessage {
+                sender_tag: fixtures::sender_tag(&mut rng),
+                content: fixtures::repliable_content_data(&mut rng, DEFAULT_NUM_MIX_HOPS, 100, 2),
+            };
+            let bytes = data.into_bytes();
+            assert_eq!(
+                bytes[SENDER_TAG_SIZE],
+                RepliableMessageContentTag::Data as u8
+            );
+
+            let recovered = RepliableMessage::try_from_bytes(&bytes, DEFAULT_NUM_MIX_HOPS).unwrap();
+            match recovered.content {
+                RepliableMessageContent::Data {
+                    message,
+                    reply_surbs,
+                } => {
+                    assert_eq!(message.len(), 100);
+                    assert_eq!(reply_surbs.len(), 2);
+                }
+                _ => panic!("unexpected content"),
+            }
+        }
+
+        #[test]
+        fn non_default_hops_are_recovered_regardless_of_receiver_expectation() {
+            let mut rng = fixtures::test_rng();
+            let num_mix_hops = 4;
+
+            let data = RepliableMessage {
+                sender_tag: fixtures::sender_tag(&mut rng),
+                content: fixtures::repliable_content_data(&mut rng, num_mix_hops, 100, 3),
+            };
+            let expected_size = data.serialized_size(num_mix_hops);
+            let bytes = data.into_bytes();
+            assert_eq!(expected_size, bytes.len());
+
+            let recovered = RepliableMessage::try_from_bytes(&bytes, DEFAULT_NUM_MIX_HOPS).unwrap();
+            match recovered.content {
+                RepliableMessageContent::Data {
+                    message,
+                    reply_surbs,
+                } => {
+                    assert_eq!(message.len(), 100);
+                    assert_eq!(reply_surbs.len(), 3);
+                    for surb in reply_surbs {
+                        assert_eq!(surb.num_mix_hops(), num_mix_hops)
+                    }
+                }
+                _ => panic!("unexpected content"),
+            }
+
+            let heartbeat = RepliableMessage {
+                sender_tag: fixtures::sender_tag(&mut rng),
+                content: fixtures::repliable_content_heartbeat(&mut rng, num_mix_hops, 5),
+            };
+            let bytes = heartbeat.into_bytes();
+            let recovered = RepliableMessage::try_from_bytes(&bytes, DEFAULT_NUM_MIX_HOPS).unwrap();
+            match recovered.content {
+                RepliableMessageContent::Heartbeat {
+                    additional_reply_surbs,
+                } => assert_eq!(additional_reply_surbs.len(), 5),
+                _ => panic!("unexpected content"),
+            }
+        }
+    }
+
     #[cfg(test)]
     mod repliable_message_content {
         use super::*;
@@ -0,0 +1,202 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use nym_crypto::asymmetric::{encryption, identity};
+use nym_mixnet_contract_common::Layer;
+use nym_sphinx::acknowledgements::AckKey;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::message::NymMessage;
+use nym_sphinx::params::{PacketSize, PacketType};
+use nym_sphinx::preparer::MessagePreparer;
+use nym_sphinx::receiver::{MessageReceiver, SphinxMessageReceiver};
+use nym_topology::{gateway, mix, NymTopology};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+const PACKET_SIZES: [(PacketType, PacketSize); 5] = [
+    (PacketType::Mix, PacketSize::RegularPacket),
+    (PacketType::Mix, PacketSize::ExtendedPacket8),
+    (PacketType::Mix, PacketSize::ExtendedPacket16),
+    (PacketType::Mix, PacketSize::ExtendedPacket32),
+    (PacketType::Outfox, PacketSize::OutfoxRegularPacket),
+];
+
+const MESSAGE_SIZES: [usize; 3] = [1024, 64 * 1024, 1024 * 1024];
+
+fn random_message(len: usize) -> Vec<u8> {
+    let mut message = vec![0u8; len];
+    OsRng.fill_bytes(&mut message);
+    message
+}
+
+fn mix_fixture(mix_id: u32, layer: Layer) -> mix::Node {
+    let host = format!("10.0.0.{mix_id}");
+    mix::Node {
+        mix_id,
+        owner: format!("owner{mix_id}"),
+        host: host.parse().unwrap(),
+        mix_host: format!("{host}:1789").parse().unwrap(),
+        identity_key: *identity::KeyPair::new(&mut OsRng).public_key(),
+        sphinx_key: *encryption::KeyPair::new(&mut OsRng).public_key(),
+        layer,
+        version: "1.1.23".to_string(),
+    }
+}
+
+/// Returns a topology with a single node on each mix layer and a single gateway alongside
+/// a recipient registered at said gateway.
+fn topology_fixture() -> (NymTopology, Recipient) {
+    let mut mixes = BTreeMap::new();
+    mixes.insert(1, vec![mix_fixture(1, Layer::One)]);
+    mixes.insert(2, vec![mix_fixture(2, Layer::Two)]);
+    mixes.insert(3, vec![mix_fixture(3, Layer::Three)]);
+
+    let gateway = gateway::Node {
+        owner: "gateway-owner".to_string(),
+        host: "10.0.0.4".parse().unwrap(),
+        mix_host: "10.0.0.4:1789".parse().unwrap(),
+        clients_port: 9000,
+        identity_key: *identity::KeyPair::new(&mut OsRng).public_key(),
+        sphinx_key: *encryption::KeyPair::new(&mut OsRng).public_key(),
+        version: "1.1.23".to_string(),
+    };
+
+    let recipient = Recipient::new(
+        *identity::KeyPair::new(&mut OsRng).public_key(),
+        *encryption::KeyPair::new(&mut OsRng).public_key(),
+        gateway.identity_key,
+    );
+
+    (NymTopology::new(mixes, vec![gateway]), recipient)
+}
+
+fn message_preparer(sender: Recipient) -> MessagePreparer<OsRng> {
+    MessagePreparer::new(
+        OsRng,
+        sender,
+        Duration::from_millis(50),
+        Duration::from_millis(50),
+    )
+}
+
+fn preparing_packets(c: &mut Criterion) {
+    let (topology, recipient) = topology_fixture();
+    let ack_key = AckKey::new(&mut OsRng);
+    let mut preparer = message_preparer(recipient);
+
+    let mut group = c.benchmark_group("prepare_chunk_for_sending");
+    for (packet_type, packet_size) in PACKET_SIZES {
+        group.throughput(Throughput::Bytes(packet_size.plaintext_size() as u64));
+
+        // the first fragment of a message spanning multiple packets is always a full one
+        let fragment = preparer
+            .pad_and_split_message(
+                NymMessage::new_plain(random_message(packet_size.plaintext_size())),
+                packet_size,
+            )
+            .remove(0);
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(packet_size),
+            &fragment,
+            |b, fragment| {
+                b.iter_batched(
+                    || fragment.clone(),
+                    |fragment| {
+                        black_box(
+                            preparer
+                                .prepare_chunk_for_sending(
+                                    fragment,
+                                    &topology,
+                                    &ack_key,
+                                    &recipient,
+                                    packet_type,
+                                )
+                                .unwrap(),
+                        )
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn chunking_messages(c: &mut Criterion) {
+    let (_, recipient) = topology_fixture();
+    let mut preparer = message_preparer(recipient);
+
+    let mut group = c.benchmark_group("pad_and_split_message");
+    for message_size in MESSAGE_SIZES {
+        let message = random_message(message_size);
+        group.throughput(Throughput::Bytes(message_size as u64));
+
+        for (_, packet_size) in PACKET_SIZES {
+            let id = format!("{message_size}B/{packet_size}");
+            group.bench_with_input(BenchmarkId::from_parameter(id), &message, |b, message| {
+                b.iter_batched(
+                    || NymMessage::new_plain(message.clone()),
+                    |message| black_box(preparer.pad_and_split_message(message, packet_size)),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+fn reconstructing_messages(c: &mut Criterion) {
+    let (_, recipient) = topology_fixture();
+    let mut preparer = message_preparer(recipient);
+
+    let mut group = c.benchmark_group("reconstruct_message");
+    for message_size in MESSAGE_SIZES {
+        group.throughput(Throughput::Bytes(message_size as u64));
+
+        for (_, packet_size) in PACKET_SIZES {
+            let serialized_fragments = preparer
+                .pad_and_split_message(
+                    NymMessage::new_plain(random_message(message_size)),
+                    packet_size,
+                )
+                .into_iter()
+                .map(|fragment| fragment.into_bytes())
+                .collect::<Vec<_>>();
+
+            let id = format!("{message_size}B/{packet_size}");
+            group.bench_with_input(
+                BenchmarkId::from_parameter(id),
+                &serialized_fragments,
+                |b, serialized_fragments| {
+                    b.iter_batched(
+                        SphinxMessageReceiver::new,
+                        |mut receiver| {
+                            let mut reconstructed = None;
+                            for raw_fragment in serialized_fragments {
+                                let fragment = receiver.recover_fragment(raw_fragment).unwrap();
+                                reconstructed = receiver.insert_new_fragment(fragment).unwrap();
+                            }
+                            black_box(reconstructed.unwrap())
+                        },
+                        BatchSize::SmallInput,
+                    )
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    preparing_packets,
+    chunking_messages,
+    reconstructing_messages
+);
+criterion_main!(benches);
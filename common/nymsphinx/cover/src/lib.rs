@@ -10,9 +10,8 @@ use nym_sphinx_addressing::nodes::NymNodeRoutingAddress;
 use nym_sphinx_chunking::fragment::COVER_FRAG_ID;
 use nym_sphinx_forwarding::packet::MixPacket;
 use nym_sphinx_params::packet_sizes::PacketSize;
-use nym_sphinx_params::{
-    PacketEncryptionAlgorithm, PacketHkdfAlgorithm, PacketType, DEFAULT_NUM_MIX_HOPS,
-};
+use nym_sphinx_params::{PacketEncryptionAlgorithm, PacketHkdfAlgorithm, PacketType};
+use nym_sphinx_routing::HopDelaySampler;
 use nym_sphinx_types::NymPacket;
 use nym_topology::{NymTopology, NymTopologyError};
 use rand::{CryptoRng, RngCore};
@@ -34,12 +33,15 @@ pub enum CoverMessageError {
     NymPacket(#[from] nym_sphinx_types::NymPacketError),
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn generate_loop_cover_surb_ack<R>(
     rng: &mut R,
     topology: &NymTopology,
     ack_key: &AckKey,
     full_address: &Recipient,
     average_ack_delay: time::Duration,
+    delay_sampler: &dyn HopDelaySampler,
+    num_mix_hops: u8,
     packet_type: PacketType,
 ) -> Result<SurbAck, CoverMessageError>
 where
@@ -51,7 +53,9 @@ where
         ack_key,
         COVER_FRAG_ID.to_bytes(),
         average_ack_delay,
+        delay_sampler,
         topology,
+        num_mix_hops,
         packet_type,
     )?)
 }
@@ -64,6 +68,8 @@ pub fn generate_loop_cover_packet<R>(
     full_address: &Recipient,
     average_ack_delay: time::Duration,
     average_packet_delay: time::Duration,
+    delay_sampler: &dyn HopDelaySampler,
+    num_mix_hops: u8,
     packet_size: PacketSize,
     packet_type: PacketType,
 ) -> Result<MixPacket, CoverMessageError>
@@ -77,6 +83,8 @@ where
         ack_key,
         full_address,
         average_ack_delay,
+        delay_sampler,
+        num_mix_hops,
         packet_type,
     )?
     .prepare_for_sending()?;
@@ -118,9 +126,8 @@ where
         .chain(cover_content.into_iter())
         .collect();
 
-    let route =
-        topology.random_route_to_gateway(rng, DEFAULT_NUM_MIX_HOPS, full_address.gateway())?;
-    let delays = nym_sphinx_routing::generate_hop_delays(average_packet_delay, route.len());
+    let route = topology.random_route_to_gateway(rng, num_mix_hops, full_address.gateway())?;
+    let delays = delay_sampler.sample_hop_delays(rng, average_packet_delay, route.len());
     let destination = full_address.as_sphinx_destination();
 
     let first_hop_address =
@@ -0,0 +1,167 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_sphinx_types::Delay;
+use rand::{Rng, RngCore};
+use rand_distr::{Distribution, Exp};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Strategy used for choosing how long a packet is going to be delayed at each of the mix hops.
+///
+/// Note that the same sampler should be used for all packets sent by a given client
+/// (data packets, acks, reply SURBs and cover traffic), otherwise they might become distinguishable.
+pub trait HopDelaySampler {
+    /// Samples delay for a single hop, parameterised by the provided average.
+    fn sample_hop_delay(&self, rng: &mut dyn RngCore, average_delay: Duration) -> Delay;
+
+    /// Samples delays for `num_hops` consecutive hops.
+    fn sample_hop_delays(
+        &self,
+        rng: &mut dyn RngCore,
+        average_delay: Duration,
+        num_hops: usize,
+    ) -> Vec<Delay> {
+        (0..num_hops)
+            .map(|_| self.sample_hop_delay(rng, average_delay))
+            .collect()
+    }
+}
+
+fn sample_exponential_nanos(rng: &mut dyn RngCore, average_delay: Duration) -> f64 {
+    // the error is only thrown if lambda is not positive, which is impossible for non-zero average
+    let exp = Exp::new(1.0 / average_delay.as_nanos() as f64).unwrap();
+    exp.sample(rng)
+}
+
+/// Delays drawn from an exponential distribution with the mean of the provided average.
+/// This is the sampler that has always been used by the Nym clients.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExponentialDelay;
+
+impl HopDelaySampler for ExponentialDelay {
+    fn sample_hop_delay(&self, rng: &mut dyn RngCore, average_delay: Duration) -> Delay {
+        if average_delay.is_zero() {
+            return Delay::new_from_nanos(0);
+        }
+        Delay::new_from_nanos(sample_exponential_nanos(rng, average_delay).round() as u64)
+    }
+}
+
+/// Delays drawn from an exponential distribution with the mean of the provided average
+/// that is truncated at `max_multiplier` times that average, so that no packet is ever delayed
+/// for an unreasonably long time.
+#[derive(Debug, Clone, Copy)]
+pub struct TruncatedExponentialDelay {
+    pub max_multiplier: f64,
+}
+
+impl HopDelaySampler for TruncatedExponentialDelay {
+    fn sample_hop_delay(&self, rng: &mut dyn RngCore, average_delay: Duration) -> Delay {
+        if average_delay.is_zero() || self.max_multiplier <= 0.0 {
+            return Delay::new_from_nanos(0);
+        }
+
+        // use inverse transform sampling on the truncated cdf rather than rejection sampling
+        // so that the sampling time would not depend on the drawn value:
+        // F(x) = (1 - e^(-x/avg)) / (1 - e^(-max/avg))
+        let average = average_delay.as_nanos() as f64;
+        let uniform: f64 = rng.gen();
+        let cdf_at_max = 1.0 - (-self.max_multiplier).exp();
+        let sampled = -average * (1.0 - uniform * cdf_at_max).ln();
+
+        Delay::new_from_nanos(sampled.round() as u64)
+    }
+}
+
+/// Every hop delays the packet for exactly the provided average.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConstantDelay;
+
+impl HopDelaySampler for ConstantDelay {
+    fn sample_hop_delay(&self, _rng: &mut dyn RngCore, average_delay: Duration) -> Delay {
+        Delay::new_from_nanos(average_delay.as_nanos() as u64)
+    }
+}
+
+/// Packets are not delayed at any hop. This gives up most of the mixing guarantees
+/// and should only be used for latency-sensitive traffic or testing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZeroDelay;
+
+impl HopDelaySampler for ZeroDelay {
+    fn sample_hop_delay(&self, _rng: &mut dyn RngCore, _average_delay: Duration) -> Delay {
+        Delay::new_from_nanos(0)
+    }
+}
+
+/// Configurable choice of one of the [`HopDelaySampler`] implementations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DelayDistribution {
+    #[default]
+    Exponential,
+
+    TruncatedExponential {
+        max_multiplier: f64,
+    },
+
+    Constant,
+
+    Zero,
+}
+
+impl HopDelaySampler for DelayDistribution {
+    fn sample_hop_delay(&self, rng: &mut dyn RngCore, average_delay: Duration) -> Delay {
+        match *self {
+            DelayDistribution::Exponential => ExponentialDelay.sample_hop_delay(rng, average_delay),
+            DelayDistribution::TruncatedExponential { max_multiplier } => {
+                TruncatedExponentialDelay { max_multiplier }.sample_hop_delay(rng, average_delay)
+            }
+            DelayDistribution::Constant => ConstantDelay.sample_hop_delay(rng, average_delay),
+            DelayDistribution::Zero => ZeroDelay.sample_hop_delay(rng, average_delay),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn zero_average_never_delays() {
+        let samplers = [
+            DelayDistribution::Exponential,
+            DelayDistribution::TruncatedExponential {
+                max_multiplier: 5.0,
+            },
+            DelayDistribution::Constant,
+            DelayDistribution::Zero,
+        ];
+        for sampler in samplers {
+            let delays = sampler.sample_hop_delays(&mut OsRng, Duration::ZERO, 4);
+            assert_eq!(delays.len(), 4);
+            assert!(delays.iter().all(|d| d.to_nanos() == 0));
+        }
+    }
+
+    #[test]
+    fn constant_delay_uses_average() {
+        let average = Duration::from_millis(50);
+        let delays = DelayDistribution::Constant.sample_hop_delays(&mut OsRng, average, 3);
+        assert!(delays.iter().all(|d| d.to_duration() == average));
+    }
+
+    #[test]
+    fn truncated_exponential_respects_the_bound() {
+        let average = Duration::from_millis(50);
+        let sampler = DelayDistribution::TruncatedExponential {
+            max_multiplier: 2.0,
+        };
+        let max = average.as_nanos() as u64 * 2;
+        for delay in sampler.sample_hop_delays(&mut OsRng, average, 1000) {
+            assert!(delay.to_nanos() <= max)
+        }
+    }
+}
@@ -1,12 +1,13 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
-
+pub use delays::{DelayDistribution, HopDelaySampler};
 use nym_sphinx_addressing::clients::Recipient;
-use nym_sphinx_types::{delays, Delay, Node};
+use nym_sphinx_types::Node;
 use thiserror::Error;
 
+pub mod delays;
+
 pub trait SphinxRouteMaker {
     type Error;
 
@@ -43,11 +44,3 @@ impl SphinxRouteMaker for Vec<Node> {
         }
     }
 }
-
-pub fn generate_hop_delays(average_packet_delay: Duration, num_hops: usize) -> Vec<Delay> {
-    if average_packet_delay.is_zero() {
-        vec![nym_sphinx_types::Delay::new_from_millis(0); num_hops]
-    } else {
-        delays::generate_from_average_duration(num_hops, average_packet_delay)
-    }
-}
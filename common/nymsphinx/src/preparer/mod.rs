@@ -14,6 +14,7 @@ use nym_sphinx_chunking::fragment::{Fragment, FragmentIdentifier};
 use nym_sphinx_forwarding::packet::MixPacket;
 use nym_sphinx_params::packet_sizes::PacketSize;
 use nym_sphinx_params::{PacketType, ReplySurbKeyDigestAlgorithm, DEFAULT_NUM_MIX_HOPS};
+use nym_sphinx_routing::{DelayDistribution, HopDelaySampler};
 use nym_sphinx_types::{Delay, NymPacket};
 use nym_topology::{NymTopology, NymTopologyError};
 use rand::{CryptoRng, Rng};
@@ -53,6 +54,12 @@ pub trait FragmentPreparer {
     fn average_packet_delay(&self) -> Duration;
     fn average_ack_delay(&self) -> Duration;
 
+    /// Distribution used for sampling per-hop delays of all constructed packets,
+    /// i.e. data packets, acks and reply SURBs.
+    fn delay_distribution(&self) -> DelayDistribution {
+        DelayDistribution::default()
+    }
+
     fn generate_reply_surbs(
         &mut self,
         amount: usize,
@@ -61,9 +68,17 @@ pub trait FragmentPreparer {
     ) -> Result<Vec<ReplySurb>, NymTopologyError> {
         let mut reply_surbs = Vec::with_capacity(amount);
         let packet_delay = self.average_packet_delay();
+        let delay_distribution = self.delay_distribution();
+        let num_mix_hops = self.num_mix_hops();
         for _ in 0..amount {
-            let reply_surb =
-                ReplySurb::construct(self.rng(), reply_recipient, packet_delay, topology)?;
+            let reply_surb = ReplySurb::construct(
+                self.rng(),
+                reply_recipient,
+                packet_delay,
+                &delay_distribution,
+                topology,
+                num_mix_hops,
+            )?;
             reply_surbs.push(reply_surb)
         }
 
@@ -79,6 +94,8 @@ pub trait FragmentPreparer {
         packet_type: PacketType,
     ) -> Result<SurbAck, NymTopologyError> {
         let ack_delay = self.average_ack_delay();
+        let delay_distribution = self.delay_distribution();
+        let num_mix_hops = self.num_mix_hops();
 
         SurbAck::construct(
             self.rng(),
@@ -86,7 +103,9 @@ pub trait FragmentPreparer {
             ack_key,
             fragment_id.to_bytes(),
             ack_delay,
+            &delay_distribution,
             topology,
+            num_mix_hops,
             packet_type,
         )
     }
@@ -232,8 +251,12 @@ pub trait FragmentPreparer {
         let destination = packet_recipient.as_sphinx_destination();
 
         // including set of delays
-        let delays =
-            nym_sphinx_routing::generate_hop_delays(self.average_packet_delay(), route.len());
+        let average_packet_delay = self.average_packet_delay();
+        let delays = self.delay_distribution().sample_hop_delays(
+            self.rng(),
+            average_packet_delay,
+            route.len(),
+        );
 
         // create the actual sphinx packet here. With valid route and correct payload size,
         // there's absolutely no reason for this call to fail.
@@ -307,6 +330,9 @@ pub struct MessagePreparer<R> {
     /// Average delay an acknowledgement packet is going to get delay at a single mixnode.
     average_ack_delay: Duration,
 
+    /// Distribution from which the per-hop delays of all packets are sampled.
+    delay_distribution: DelayDistribution,
+
     /// Number of mix hops each packet ('real' message, ack, reply) is expected to take.
     /// Note that it does not include gateway hops.
     num_mix_hops: u8,
@@ -327,21 +353,43 @@ where
             sender_address,
             average_packet_delay,
             average_ack_delay,
+            delay_distribution: DelayDistribution::default(),
             num_mix_hops: DEFAULT_NUM_MIX_HOPS,
         }
     }
 
+    /// Allows setting non-default distribution of the per-hop delays.
+    pub fn with_delay_distribution(mut self, delay_distribution: DelayDistribution) -> Self {
+        self.delay_distribution = delay_distribution;
+        self
+    }
+
     /// Allows setting non-default number of expected mix hops in the network.
     pub fn with_mix_hops(mut self, hops: u8) -> Self {
         self.num_mix_hops = hops;
         self
     }
 
+    /// Overwrites existing number of expected mix hops with the provided value.
+    pub fn set_mix_hops(&mut self, hops: u8) {
+        self.num_mix_hops = hops;
+    }
+
     /// Overwrites existing sender address with the provided value.
     pub fn set_sender_address(&mut self, sender_address: Recipient) {
         self.sender_address = sender_address;
     }
 
+    /// Overwrites existing average packet delay with the provided value.
+    pub fn set_average_packet_delay(&mut self, average_packet_delay: Duration) {
+        self.average_packet_delay = average_packet_delay;
+    }
+
+    /// Overwrites existing average ack delay with the provided value.
+    pub fn set_average_ack_delay(&mut self, average_ack_delay: Duration) {
+        self.average_ack_delay = average_ack_delay;
+    }
+
     pub fn generate_reply_surbs(
         &mut self,
         amount: usize,
@@ -353,7 +401,9 @@ where
                 &mut self.rng,
                 &self.sender_address,
                 self.average_packet_delay,
+                &self.delay_distribution,
                 topology,
+                self.num_mix_hops,
             )?;
             reply_surbs.push(reply_surb)
         }
@@ -449,6 +499,10 @@ impl<R: CryptoRng + Rng> FragmentPreparer for MessagePreparer<R> {
     fn average_ack_delay(&self) -> Duration {
         self.average_ack_delay
     }
+
+    fn delay_distribution(&self) -> DelayDistribution {
+        self.delay_distribution
+    }
 }
 
 /*
@@ -105,8 +105,16 @@ pub struct Socks5 {
     pub listening_port: u16,
 
     /// The mix address of the provider to which all requests are going to be sent.
+    /// It can also be a name registered in the name service that is going to be resolved on startup.
     pub provider_mix_address: String,
 
+    /// Specifies whether the network requester should be chosen automatically from the service
+    /// provider directory. If enabled, the client is also going to switch to a different network
+    /// requester once the current one stops responding. If `provider_mix_address` is set,
+    /// it's going to be tried first.
+    #[serde(default)]
+    pub auto_select_provider: bool,
+
     /// The version of the 'service provider' this client is going to use in its communication with the
     /// specified socks5 provider.
     // if in doubt, use the legacy version as initially nobody will be using the updated binaries
@@ -133,6 +141,7 @@ impl Socks5 {
         Socks5 {
             listening_port: DEFAULT_SOCKS5_LISTENING_PORT,
             provider_mix_address: provider_mix_address.into(),
+            auto_select_provider: false,
             provider_interface_version: ProviderInterfaceVersion::Legacy,
             socks5_protocol_version: Socks5ProtocolVersion::Legacy,
             send_anonymously: false,
@@ -140,9 +149,16 @@ impl Socks5 {
         }
     }
 
-    pub fn get_provider_mix_address(&self) -> Recipient {
-        Recipient::try_from_base58_string(&self.provider_mix_address)
-            .expect("malformed provider address")
+    pub fn with_auto_selected_provider(mut self, auto_select_provider: bool) -> Self {
+        self.auto_select_provider = auto_select_provider;
+        self
+    }
+
+    /// Returns the configured provider address, if it has been set to a valid nym address.
+    /// Note that it's not going to be available if the provider is meant to be selected
+    /// automatically or if it has been specified by its registered name.
+    pub fn get_provider_mix_address(&self) -> Option<Recipient> {
+        Recipient::try_from_base58_string(&self.provider_mix_address).ok()
     }
 }
 
@@ -50,6 +50,7 @@ impl From<Socks5V1_1_20_2> for Socks5 {
         Socks5 {
             listening_port: value.listening_port,
             provider_mix_address: value.provider_mix_address,
+            auto_select_provider: false,
             provider_interface_version: value.provider_interface_version,
             socks5_protocol_version: value.socks5_protocol_version,
             send_anonymously: value.send_anonymously,
@@ -1,4 +1,5 @@
 use crate::socks::types::SocksProxyError;
+use nym_client_core::client::name_resolver::NameResolutionError;
 use nym_client_core::error::ClientCoreError;
 use nym_socks5_requests::{ConnectionError, ConnectionId};
 
@@ -13,6 +14,18 @@ pub enum Socks5ClientCoreError {
     #[error("client-core error: {0}")]
     ClientCoreError(#[from] ClientCoreError),
 
+    #[error("failed to resolve the service provider address: {0}")]
+    ProviderResolution(#[from] NameResolutionError),
+
+    #[error("failed to retrieve the network requesters from any of the nym apis")]
+    ServiceDirectoryUnavailable,
+
+    #[error("there are no network requesters announced in the service provider directory")]
+    NoNetworkRequestersAvailable,
+
+    #[error("none of the announced network requesters has responded to our probes")]
+    NoResponsiveNetworkRequester,
+
     #[error("Network requester: connection id {connection_id}: {error}")]
     NetworkRequesterError {
         connection_id: ConnectionId,
@@ -5,6 +5,7 @@ use crate::config::Config;
 use crate::error::Socks5ClientCoreError;
 use crate::socks::{
     authentication::{AuthenticationMethods, Authenticator, User},
+    provider_discovery::ProviderSelection,
     server::NymSocksServer,
 };
 use futures::channel::mpsc;
@@ -88,6 +89,7 @@ where
         client_output: ClientOutput,
         client_status: ClientState,
         self_address: Recipient,
+        provider_selection: ProviderSelection,
         shutdown: TaskClient,
         packet_type: PacketType,
     ) {
@@ -118,7 +120,7 @@ where
         let mut sphinx_socks = NymSocksServer::new(
             socks5_config.listening_port,
             authenticator,
-            socks5_config.get_provider_mix_address(),
+            provider_selection,
             self_address,
             shared_lane_queue_lengths,
             socks::client::Config::new(
@@ -202,6 +204,14 @@ where
     }
 
     pub async fn start(self) -> Result<StartedSocks5Client, Socks5ClientCoreError> {
+        // the provider might have been specified by its registered name
+        // or it might have to be discovered from the service provider directory
+        let provider_selection = ProviderSelection::from_config(
+            &self.config.socks5,
+            self.config.base.client.nym_api_urls.clone(),
+        )
+        .await?;
+
         // don't create dkg client for the bandwidth controller if credentials are disabled
         let dkg_query_client = if self.config.base.client.disabled_credentials_mode {
             None
@@ -229,6 +239,7 @@ where
             client_output,
             client_state,
             self_address,
+            provider_selection,
             started_client.task_manager.subscribe(),
             packet_type,
         );
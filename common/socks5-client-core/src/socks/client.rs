@@ -11,7 +11,7 @@ use log::*;
 use nym_client_core::client::inbound_messages::{InputMessage, InputMessageSender};
 use nym_service_providers_common::interface::{ProviderInterfaceVersion, RequestVersion};
 use nym_socks5_proxy_helpers::connection_controller::{
-    ConnectionReceiver, ControllerCommand, ControllerSender,
+    ConnectionMessage, ConnectionReceiver, ConnectionSender, ControllerCommand, ControllerSender,
 };
 use nym_socks5_proxy_helpers::proxy_runner::ProxyRunner;
 use nym_socks5_requests::{
@@ -28,6 +28,7 @@ use std::io;
 use std::net::SocketAddr;
 use std::pin::Pin;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::sync::watch;
 use tokio::{self, net::TcpStream};
 
 #[pin_project(project = StateProject)]
@@ -182,6 +183,7 @@ pub(crate) struct SocksClient {
     input_sender: InputMessageSender,
     connection_id: ConnectionId,
     service_provider: Recipient,
+    provider_updates: watch::Receiver<Recipient>,
     self_address: Recipient,
     started_proxy: bool,
     lane_queue_lengths: LaneQueueLengths,
@@ -210,7 +212,7 @@ impl SocksClient {
         stream: TcpStream,
         authenticator: Authenticator,
         input_sender: InputMessageSender,
-        service_provider: &Recipient,
+        mut provider_updates: watch::Receiver<Recipient>,
         controller_sender: ControllerSender,
         self_address: &Recipient,
         lane_queue_lengths: LaneQueueLengths,
@@ -221,6 +223,7 @@ impl SocksClient {
         shutdown_listener.mark_as_success();
 
         let connection_id = Self::generate_random();
+        let service_provider = *provider_updates.borrow_and_update();
 
         SocksClient {
             config,
@@ -231,7 +234,8 @@ impl SocksClient {
             socks_version: None,
             authenticator,
             input_sender,
-            service_provider: *service_provider,
+            service_provider,
+            provider_updates,
             self_address: *self_address,
             started_proxy: false,
             lane_queue_lengths,
@@ -393,7 +397,35 @@ impl SocksClient {
         }
     }
 
-    async fn run_proxy(&mut self, conn_receiver: ConnectionReceiver, remote_proxy_target: String) {
+    /// The connection has been established through the network requester that was in use at the
+    /// time, so once we switch to a different one, the connection has to be closed so that
+    /// the application could reconnect through the new one.
+    fn close_on_provider_change(
+        &self,
+        conn_sender: ConnectionSender,
+    ) -> tokio::task::JoinHandle<()> {
+        let mut provider_updates = self.provider_updates.clone();
+        let connection_id = self.connection_id;
+        tokio::spawn(async move {
+            // if the sender is gone, the provider is never going to change
+            if provider_updates.changed().await.is_ok() {
+                info!("the network requester has changed - closing connection {connection_id}");
+                conn_sender
+                    .unbounded_send(ConnectionMessage {
+                        payload: Vec::new(),
+                        socket_closed: true,
+                    })
+                    .ok();
+            }
+        })
+    }
+
+    async fn run_proxy(
+        &mut self,
+        conn_sender: ConnectionSender,
+        conn_receiver: ConnectionReceiver,
+        remote_proxy_target: String,
+    ) {
         self.send_connect_to_mixnet(remote_proxy_target.clone())
             .await;
 
@@ -406,6 +438,7 @@ impl SocksClient {
             }
         };
         let local_stream_remote = peer_addr.to_string();
+        let provider_change_handle = self.close_on_provider_change(conn_sender);
 
         let connection_id = self.connection_id;
         let input_sender = self.input_sender.clone();
@@ -455,6 +488,7 @@ impl SocksClient {
         })
         .await
         .into_inner();
+        provider_change_handle.abort();
         // recover stream from the proxy
         self.stream.finish_proxy(stream)
     }
@@ -491,7 +525,7 @@ impl SocksClient {
                 self.controller_sender
                     .unbounded_send(ControllerCommand::Insert {
                         connection_id: self.connection_id,
-                        connection_sender: mix_sender,
+                        connection_sender: mix_sender.clone(),
                     })
                     .unwrap();
 
@@ -500,7 +534,8 @@ impl SocksClient {
                     remote_address.clone(),
                     self.connection_id
                 );
-                self.run_proxy(mix_receiver, remote_address.clone()).await;
+                self.run_proxy(mix_sender, mix_receiver, remote_address.clone())
+                    .await;
                 info!(
                     "Proxy for {} is finished (id: {})",
                     remote_address, self.connection_id
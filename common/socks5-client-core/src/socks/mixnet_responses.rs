@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::Socks5ClientCoreError;
+use crate::socks::provider_discovery::ControlResponseSender;
 use futures::channel::mpsc;
 use futures::StreamExt;
 use log::*;
@@ -19,6 +20,7 @@ pub(crate) struct MixnetResponseListener {
     buffer_requester: ReceivedBufferRequestSender,
     mix_response_receiver: ReconstructedMessagesReceiver,
     controller_sender: ControllerSender,
+    control_response_sender: ControlResponseSender,
     shutdown: TaskClient,
 }
 
@@ -41,6 +43,7 @@ impl MixnetResponseListener {
     pub(crate) fn new(
         buffer_requester: ReceivedBufferRequestSender,
         controller_sender: ControllerSender,
+        control_response_sender: ControlResponseSender,
         shutdown: TaskClient,
     ) -> Self {
         let (mix_response_sender, mix_response_receiver) = mpsc::unbounded();
@@ -52,6 +55,7 @@ impl MixnetResponseListener {
             buffer_requester,
             mix_response_receiver,
             controller_sender,
+            control_response_sender,
             shutdown,
         }
     }
@@ -60,10 +64,16 @@ impl MixnetResponseListener {
         &self,
         control_response: ControlResponse,
     ) -> Result<(), Socks5ClientCoreError> {
-        error!("received a control response which we don't know how to handle yet!");
-        error!("got: {:?}", control_response);
-
-        // I guess we'd need another channel here to forward those to where they need to go
+        // control responses are only expected if we're monitoring the network requester
+        if let Err(err) = self
+            .control_response_sender
+            .unbounded_send(control_response)
+        {
+            warn!(
+                "received a control response that nobody was waiting for: {:?}",
+                err.into_inner()
+            );
+        }
 
         Ok(())
     }
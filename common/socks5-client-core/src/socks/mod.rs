@@ -7,6 +7,7 @@ use self::types::SocksProxyError;
 pub mod authentication;
 pub(crate) mod client;
 pub(crate) mod mixnet_responses;
+pub mod provider_discovery;
 mod request;
 pub mod server;
 pub mod types;
@@ -0,0 +1,564 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config;
+use crate::error::Socks5ClientCoreError;
+use futures::channel::mpsc;
+use futures::StreamExt;
+use log::*;
+use nym_client_core::client::inbound_messages::{InputMessage, InputMessageSender};
+use nym_client_core::client::name_resolver::NameResolver;
+use nym_service_provider_directory_common::{Service, ServiceType};
+use nym_service_providers_common::interface::{
+    ControlRequest, ControlResponse, ProviderInterfaceVersion,
+};
+use nym_socks5_requests::Socks5ProviderRequest;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::params::PacketType;
+use nym_task::connections::TransmissionLane;
+use nym_task::TaskClient;
+use nym_validator_client::client::NymApiClient;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use url::Url;
+
+/// How long we're willing to wait for a response to a control request sent to a network requester.
+const CONTROL_RESPONSE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Maximum number of network requesters probed during a single search for a responsive one.
+/// (control responses are received via reply SURBs, so we can't tell which requester has sent them
+/// and thus the candidates have to be probed one at a time)
+const MAX_PROBED_PROVIDERS: usize = 5;
+
+/// Maximum amount of time spent on a single search for a responsive network requester.
+const PROVIDER_SEARCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often the currently used network requester is checked for being alive.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Number of consecutive failed health checks after which we switch to a different network requester.
+const MAX_MISSED_HEALTH_CHECKS: usize = 3;
+
+/// Control responses can only be sent back using reply SURBs.
+const CONTROL_REQUEST_SURBS: u32 = 2;
+
+pub(crate) type ControlResponseSender = mpsc::UnboundedSender<ControlResponse>;
+pub(crate) type ControlResponseReceiver = mpsc::UnboundedReceiver<ControlResponse>;
+
+/// Specifies how the network requester that all the requests are sent to is chosen.
+#[derive(Debug, Clone)]
+pub enum ProviderSelection {
+    /// Always use the specified provider.
+    Fixed(Recipient),
+
+    /// Pick a responsive network requester from the service provider directory and switch to
+    /// another one if it stops responding. The preferred provider, if any, is tried first.
+    Automatic {
+        preferred: Option<Recipient>,
+        nym_api_urls: Vec<Url>,
+    },
+}
+
+impl ProviderSelection {
+    /// Determine the provider selection based on the socks5 configuration, resolving the provider
+    /// address if it was specified by its registered name.
+    pub async fn from_config(
+        socks5_config: &config::Socks5,
+        nym_api_urls: Vec<Url>,
+    ) -> Result<Self, Socks5ClientCoreError> {
+        let configured = &socks5_config.provider_mix_address;
+        if socks5_config.auto_select_provider {
+            let preferred = if configured.is_empty() {
+                None
+            } else {
+                Some(resolve_provider(configured, &nym_api_urls).await?)
+            };
+            Ok(ProviderSelection::Automatic {
+                preferred,
+                nym_api_urls,
+            })
+        } else {
+            let provider = resolve_provider(configured, &nym_api_urls).await?;
+            Ok(ProviderSelection::Fixed(provider))
+        }
+    }
+}
+
+async fn resolve_provider(
+    name_or_address: &str,
+    nym_api_urls: &[Url],
+) -> Result<Recipient, Socks5ClientCoreError> {
+    Ok(NameResolver::new(nym_api_urls.to_vec())
+        .resolve_recipient(name_or_address)
+        .await?)
+}
+
+fn network_requester_address(service: &Service) -> Option<Recipient> {
+    let recipient = match Recipient::try_from_base58_string(service.service.nym_address.as_str()) {
+        Ok(recipient) => recipient,
+        Err(err) => {
+            warn!(
+                "service {} has announced a malformed address: {err}",
+                service.service_id
+            );
+            return None;
+        }
+    };
+
+    // make sure whoever has announced the service actually controls the address
+    if recipient.identity().to_base58_string() != service.service.identity_key {
+        warn!(
+            "service {} was announced with identity key that does not match its address",
+            service.service_id
+        );
+        return None;
+    }
+    Some(recipient)
+}
+
+/// Retrieve all network requesters announced in the service provider directory, in random order.
+pub(crate) async fn fetch_network_requesters(
+    nym_api_urls: &[Url],
+) -> Result<Vec<Recipient>, Socks5ClientCoreError> {
+    let mut nym_api_urls = nym_api_urls.to_vec();
+    nym_api_urls.shuffle(&mut thread_rng());
+
+    for nym_api in nym_api_urls {
+        let client = NymApiClient::new(nym_api.clone());
+        match client
+            .get_cached_services_by_type(&ServiceType::NetworkRequester)
+            .await
+        {
+            Ok(services) => {
+                let mut requesters = services
+                    .iter()
+                    .filter_map(network_requester_address)
+                    .collect::<Vec<_>>();
+                requesters.shuffle(&mut thread_rng());
+                debug!("found {} announced network requesters", requesters.len());
+                return Ok(requesters);
+            }
+            Err(err) => warn!("failed to query {nym_api} for the network requesters: {err}"),
+        }
+    }
+
+    Err(Socks5ClientCoreError::ServiceDirectoryUnavailable)
+}
+
+/// Probes network requesters with control requests and keeps track of the one that's currently
+/// being used, failing over to a different one once it stops responding.
+pub(crate) struct ProviderMonitor {
+    candidates: VecDeque<Recipient>,
+    input_sender: InputMessageSender,
+    control_responses: ControlResponseReceiver,
+    provider_sender: Option<watch::Sender<Recipient>>,
+    packet_type: PacketType,
+    shutdown: TaskClient,
+}
+
+impl ProviderMonitor {
+    pub(crate) fn new(
+        preferred: Option<Recipient>,
+        mut candidates: Vec<Recipient>,
+        input_sender: InputMessageSender,
+        control_responses: ControlResponseReceiver,
+        packet_type: PacketType,
+        shutdown: TaskClient,
+    ) -> Self {
+        if let Some(preferred) = preferred {
+            candidates.retain(|candidate| candidate != &preferred);
+            candidates.insert(0, preferred);
+        }
+
+        ProviderMonitor {
+            candidates: candidates.into(),
+            input_sender,
+            control_responses,
+            provider_sender: None,
+            packet_type,
+            shutdown,
+        }
+    }
+
+    async fn send_control_request(&self, provider: Recipient, request: ControlRequest) {
+        let request =
+            Socks5ProviderRequest::new_control(ProviderInterfaceVersion::new_current(), request);
+        let input_message = InputMessage::new_anonymous(
+            provider,
+            request.into_bytes(),
+            CONTROL_REQUEST_SURBS,
+            TransmissionLane::General,
+            Some(self.packet_type),
+        );
+        if self.input_sender.send(input_message).await.is_err() {
+            error!("failed to send control request to {provider} - the input channel has closed")
+        }
+    }
+
+    /// Wait for the control response matching the predicate, ignoring any other ones.
+    async fn await_control_response<F>(
+        &mut self,
+        is_expected: F,
+        timeout: Duration,
+    ) -> Option<ControlResponse>
+    where
+        F: Fn(&ControlResponse) -> bool,
+    {
+        let timeout = tokio::time::sleep(timeout);
+        tokio::pin!(timeout);
+
+        loop {
+            tokio::select! {
+                _ = &mut timeout => return None,
+                _ = self.shutdown.recv() => return None,
+                response = self.control_responses.next() => match response {
+                    Some(response) if is_expected(&response) => return Some(response),
+                    Some(response) => debug!("ignoring unexpected control response: {response:?}"),
+                    None => return None,
+                }
+            }
+        }
+    }
+
+    async fn probe(&mut self, candidate: Recipient, timeout: Duration) -> bool {
+        // get rid of any late responses from previous probes
+        while let Ok(Some(_)) = self.control_responses.try_next() {}
+
+        debug!("probing network requester {candidate}");
+        self.send_control_request(candidate, ControlRequest::SupportedRequestVersions)
+            .await;
+        match self
+            .await_control_response(
+                |res| matches!(res, ControlResponse::SupportedRequestVersions(_)),
+                timeout,
+            )
+            .await
+        {
+            Some(ControlResponse::SupportedRequestVersions(versions)) => {
+                info!(
+                    "network requester {candidate} is responsive. It supports interface version {} and provider version {}",
+                    versions.interface_version, versions.provider_version
+                );
+                true
+            }
+            _ => {
+                warn!("network requester {candidate} did not respond to our probe");
+                false
+            }
+        }
+    }
+
+    /// Go through the candidates, in order, until a responsive one is found, giving up after
+    /// probing [`MAX_PROBED_PROVIDERS`] of them or once [`PROVIDER_SEARCH_TIMEOUT`] has elapsed.
+    /// The probed candidates are moved to the back of the queue, so that any subsequent search
+    /// would start with the ones that haven't been tried yet.
+    /// The `excluded` provider is only going to be retried if there are no other options.
+    async fn find_responsive_provider(&mut self, excluded: Option<Recipient>) -> Option<Recipient> {
+        let deadline = Instant::now() + PROVIDER_SEARCH_TIMEOUT;
+        let mut probed = 0;
+        for _ in 0..self.candidates.len() {
+            if probed == MAX_PROBED_PROVIDERS {
+                warn!("none of the {probed} probed network requesters has responded");
+                return None;
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                warn!("timed out while looking for a responsive network requester");
+                return None;
+            }
+
+            let candidate = self.candidates.pop_front()?;
+            self.candidates.push_back(candidate);
+            if Some(candidate) == excluded && self.candidates.len() > 1 {
+                continue;
+            }
+            probed += 1;
+            if self
+                .probe(candidate, remaining.min(CONTROL_RESPONSE_TIMEOUT))
+                .await
+            {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
+    /// Choose the initial network requester and obtain the handle for reading the currently
+    /// selected one.
+    pub(crate) async fn select_initial_provider(
+        &mut self,
+    ) -> Result<watch::Receiver<Recipient>, Socks5ClientCoreError> {
+        if self.candidates.is_empty() {
+            return Err(Socks5ClientCoreError::NoNetworkRequestersAvailable);
+        }
+
+        let provider = self
+            .find_responsive_provider(None)
+            .await
+            .ok_or(Socks5ClientCoreError::NoResponsiveNetworkRequester)?;
+        info!("using {provider} as the network requester");
+
+        let (provider_sender, provider_receiver) = watch::channel(provider);
+        self.provider_sender = Some(provider_sender);
+        Ok(provider_receiver)
+    }
+
+    async fn check_current_provider(&mut self, provider: Recipient) -> bool {
+        self.send_control_request(provider, ControlRequest::Health)
+            .await;
+        self.await_control_response(
+            |res| matches!(res, ControlResponse::Health),
+            CONTROL_RESPONSE_TIMEOUT,
+        )
+        .await
+        .is_some()
+    }
+
+    pub(crate) async fn run(&mut self) {
+        let Some(provider_sender) = self.provider_sender.clone() else {
+            error!("the provider monitor was started without selecting the initial provider");
+            return;
+        };
+
+        let mut health_check = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+        // the first tick completes immediately and we've just probed the provider
+        health_check.tick().await;
+        let mut missed_checks = 0;
+
+        while !self.shutdown.is_shutdown() {
+            tokio::select! {
+                _ = self.shutdown.recv() => {
+                    log::trace!("ProviderMonitor: Received shutdown");
+                }
+                _ = health_check.tick() => {
+                    let provider = *provider_sender.borrow();
+                    if self.check_current_provider(provider).await {
+                        missed_checks = 0;
+                        continue;
+                    }
+                    if self.shutdown.is_shutdown() {
+                        break;
+                    }
+
+                    missed_checks += 1;
+                    warn!("network requester {provider} has missed {missed_checks} health check(s)");
+                    if missed_checks < MAX_MISSED_HEALTH_CHECKS {
+                        continue;
+                    }
+
+                    match self.find_responsive_provider(Some(provider)).await {
+                        Some(new_provider) => {
+                            info!("switching to network requester {new_provider}");
+                            provider_sender.send_replace(new_provider);
+                            missed_checks = 0;
+                        }
+                        None => warn!("could not find any other responsive network requester"),
+                    }
+                }
+            }
+        }
+        self.shutdown.recv_timeout().await;
+        log::debug!("ProviderMonitor: Exiting");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_client_core::client::inbound_messages::InputMessageReceiver;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_service_providers_common::interface::{RequestContent, SupportedVersions};
+    use std::sync::{Arc, Mutex};
+
+    fn random_recipient() -> Recipient {
+        let mut rng = thread_rng();
+        Recipient::new(
+            *identity::KeyPair::new(&mut rng).public_key(),
+            *encryption::KeyPair::new(&mut rng).public_key(),
+            *identity::KeyPair::new(&mut rng).public_key(),
+        )
+    }
+
+    fn random_recipients(n: usize) -> Vec<Recipient> {
+        (0..n).map(|_| random_recipient()).collect()
+    }
+
+    // pretends to be the network requesters, answering control requests of the responsive ones
+    // and keeping track of the order in which they got probed
+    #[derive(Clone, Default)]
+    struct FakeRequesters {
+        responsive: Arc<Mutex<Vec<Recipient>>>,
+        probed: Arc<Mutex<Vec<Recipient>>>,
+    }
+
+    impl FakeRequesters {
+        fn set_responsive(&self, requesters: &[Recipient]) {
+            *self.responsive.lock().unwrap() = requesters.to_vec();
+        }
+
+        fn probed(&self) -> Vec<Recipient> {
+            self.probed.lock().unwrap().clone()
+        }
+
+        fn spawn(
+            &self,
+            mut input_receiver: InputMessageReceiver,
+            response_sender: ControlResponseSender,
+        ) {
+            let requesters = self.clone();
+            tokio::spawn(async move {
+                while let Some(message) = input_receiver.recv().await {
+                    let message = match message {
+                        InputMessage::MessageWrapper { message, .. } => *message,
+                        message => message,
+                    };
+                    let InputMessage::Anonymous {
+                        recipient, data, ..
+                    } = message
+                    else {
+                        continue;
+                    };
+                    let Ok(request) = Socks5ProviderRequest::try_from_bytes(&data) else {
+                        continue;
+                    };
+                    let RequestContent::Control(request) = request.content else {
+                        continue;
+                    };
+
+                    let response = match request {
+                        ControlRequest::Health => ControlResponse::Health,
+                        ControlRequest::SupportedRequestVersions => {
+                            requesters.probed.lock().unwrap().push(recipient);
+                            ControlResponse::SupportedRequestVersions(SupportedVersions {
+                                interface_version: "1".to_string(),
+                                provider_version: "1".to_string(),
+                            })
+                        }
+                        ControlRequest::BinaryInfo => continue,
+                    };
+                    if requesters.responsive.lock().unwrap().contains(&recipient) {
+                        response_sender.unbounded_send(response).unwrap();
+                    }
+                }
+            });
+        }
+    }
+
+    fn setup_monitor(
+        preferred: Option<Recipient>,
+        candidates: Vec<Recipient>,
+    ) -> (ProviderMonitor, FakeRequesters) {
+        let (input_sender, input_receiver) = tokio::sync::mpsc::channel(16);
+        let (response_sender, response_receiver) = mpsc::unbounded();
+
+        let requesters = FakeRequesters::default();
+        requesters.spawn(input_receiver, response_sender);
+
+        let monitor = ProviderMonitor::new(
+            preferred,
+            candidates,
+            input_sender,
+            response_receiver,
+            PacketType::Mix,
+            TaskClient::dummy(),
+        );
+        (monitor, requesters)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn preferred_provider_is_probed_first() {
+        let candidates = random_recipients(3);
+        let preferred = candidates[2];
+        let (mut monitor, requesters) = setup_monitor(Some(preferred), candidates.clone());
+        requesters.set_responsive(&candidates);
+
+        let provider = monitor.select_initial_provider().await.unwrap();
+        assert_eq!(*provider.borrow(), preferred);
+        assert_eq!(requesters.probed(), vec![preferred]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn untried_providers_are_probed_before_the_previously_tried_ones() {
+        let candidates = random_recipients(4);
+        let (mut monitor, requesters) = setup_monitor(None, candidates.clone());
+
+        requesters.set_responsive(&[candidates[1]]);
+        let provider = monitor.select_initial_provider().await.unwrap();
+        assert_eq!(*provider.borrow(), candidates[1]);
+        assert_eq!(requesters.probed(), candidates[..2].to_vec());
+
+        // the next search continues from where the previous one has stopped
+        requesters.set_responsive(&[candidates[0], candidates[3]]);
+        assert_eq!(
+            monitor.find_responsive_provider(Some(candidates[1])).await,
+            Some(candidates[3])
+        );
+        assert_eq!(
+            requesters.probed(),
+            vec![candidates[0], candidates[1], candidates[2], candidates[3]]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn excluded_provider_is_only_retried_if_there_are_no_other_options() {
+        let candidates = random_recipients(2);
+        let (mut monitor, requesters) = setup_monitor(None, candidates.clone());
+        requesters.set_responsive(&candidates);
+        monitor.select_initial_provider().await.unwrap();
+
+        requesters.set_responsive(&[candidates[0]]);
+        assert!(monitor
+            .find_responsive_provider(Some(candidates[0]))
+            .await
+            .is_none());
+        assert_eq!(requesters.probed(), vec![candidates[0], candidates[1]]);
+
+        let only_candidate = random_recipient();
+        let (mut monitor, requesters) = setup_monitor(None, vec![only_candidate]);
+        requesters.set_responsive(&[only_candidate]);
+        monitor.select_initial_provider().await.unwrap();
+        assert_eq!(
+            monitor.find_responsive_provider(Some(only_candidate)).await,
+            Some(only_candidate)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn search_gives_up_once_the_timeout_is_reached() {
+        let candidates = random_recipients(10);
+        let (mut monitor, requesters) = setup_monitor(None, candidates.clone());
+
+        assert!(matches!(
+            monitor.select_initial_provider().await,
+            Err(Socks5ClientCoreError::NoResponsiveNetworkRequester)
+        ));
+
+        // each unresponsive probe takes the full response timeout
+        let expected_probes =
+            (PROVIDER_SEARCH_TIMEOUT.as_secs() / CONTROL_RESPONSE_TIMEOUT.as_secs()) as usize;
+        assert_eq!(requesters.probed(), candidates[..expected_probes].to_vec());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn monitor_fails_over_once_the_provider_stops_responding() {
+        let candidates = random_recipients(3);
+        let (mut monitor, requesters) = setup_monitor(None, candidates.clone());
+        requesters.set_responsive(&candidates);
+
+        let mut provider = monitor.select_initial_provider().await.unwrap();
+        assert_eq!(*provider.borrow_and_update(), candidates[0]);
+
+        requesters.set_responsive(&candidates[1..]);
+        let monitor_handle = tokio::spawn(async move { monitor.run().await });
+
+        provider.changed().await.unwrap();
+        assert_eq!(*provider.borrow(), candidates[1]);
+        assert_eq!(requesters.probed(), candidates[..2].to_vec());
+
+        monitor_handle.abort();
+    }
+}
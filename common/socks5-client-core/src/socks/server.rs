@@ -1,9 +1,13 @@
 use crate::error::Socks5ClientCoreError;
 
 use super::{
-    authentication::Authenticator, client::SocksClient, mixnet_responses::MixnetResponseListener,
+    authentication::Authenticator,
+    client::SocksClient,
+    mixnet_responses::MixnetResponseListener,
+    provider_discovery::{fetch_network_requesters, ProviderMonitor, ProviderSelection},
 };
 use crate::socks::client;
+use futures::channel::mpsc;
 use log::*;
 use nym_client_core::client::{
     inbound_messages::InputMessageSender, received_buffer::ReceivedBufferRequestSender,
@@ -16,12 +20,13 @@ use nym_task::TaskClient;
 use std::net::SocketAddr;
 use tap::TapFallible;
 use tokio::net::TcpListener;
+use tokio::sync::watch;
 
 /// A Socks5 server that listens for connections.
 pub struct NymSocksServer {
     authenticator: Authenticator,
     listening_address: SocketAddr,
-    service_provider: Recipient,
+    provider_selection: ProviderSelection,
     self_address: Recipient,
     client_config: client::Config,
     lane_queue_lengths: LaneQueueLengths,
@@ -35,7 +40,7 @@ impl NymSocksServer {
     pub(crate) fn new(
         port: u16,
         authenticator: Authenticator,
-        service_provider: Recipient,
+        provider_selection: ProviderSelection,
         self_address: Recipient,
         lane_queue_lengths: LaneQueueLengths,
         client_config: client::Config,
@@ -49,7 +54,7 @@ impl NymSocksServer {
         NymSocksServer {
             authenticator,
             listening_address: format!("{ip}:{port}").parse().unwrap(),
-            service_provider,
+            provider_selection,
             self_address,
             client_config,
             lane_queue_lengths,
@@ -82,19 +87,43 @@ impl NymSocksServer {
         });
 
         // listener for mix messages
+        let (control_response_sender, control_response_receiver) = mpsc::unbounded();
         let mut mixnet_response_listener = MixnetResponseListener::new(
             buffer_requester,
             controller_sender.clone(),
+            control_response_sender,
             self.shutdown.clone(),
         );
         tokio::spawn(async move {
             mixnet_response_listener.run().await;
         });
 
-        // TODO:, if required, there should be another task here responsible for control requests.
-        // it should get `input_sender` to send actual requests into the mixnet
-        // and some channel that connects it from `MixnetResponseListener` to receive
-        // any control responses
+        // the network requester can change over time if it was chosen automatically
+        let service_provider = match self.provider_selection.clone() {
+            ProviderSelection::Fixed(provider) => {
+                // there's nobody to update the provider, but the last value remains readable
+                watch::channel(provider).1
+            }
+            ProviderSelection::Automatic {
+                preferred,
+                nym_api_urls,
+            } => {
+                let candidates = fetch_network_requesters(&nym_api_urls).await?;
+                let mut provider_monitor = ProviderMonitor::new(
+                    preferred,
+                    candidates,
+                    input_sender.clone(),
+                    control_response_receiver,
+                    self.packet_type,
+                    self.shutdown.clone(),
+                );
+                let service_provider = provider_monitor.select_initial_provider().await?;
+                tokio::spawn(async move {
+                    provider_monitor.run().await;
+                });
+                service_provider
+            }
+        };
 
         loop {
             tokio::select! {
@@ -104,7 +133,7 @@ impl NymSocksServer {
                         stream,
                         self.authenticator.clone(),
                         input_sender.clone(),
-                        &self.service_provider,
+                        service_provider.clone(),
                         controller_sender.clone(),
                         &self.self_address,
                         self.lane_queue_lengths.clone(),
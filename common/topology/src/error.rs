@@ -24,6 +24,9 @@ pub enum NymTopologyError {
     #[error("Wanted to create a mix route with {requested} hops, while only {available} layers are available")]
     InvalidNumberOfHopsError { available: usize, requested: usize },
 
+    #[error("Wanted to create a route with {requested} hops, while the packet format supports at most {max}")]
+    RouteTooLong { requested: usize, max: usize },
+
     #[error("No mixnodes available on layer {layer}")]
     EmptyMixLayer { layer: MixLayer },
 
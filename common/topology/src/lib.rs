@@ -4,9 +4,11 @@
 use crate::filter::VersionFilterable;
 use log::warn;
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
-use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId};
+use nym_mixnet_contract_common::{
+    GatewayBond, IdentityKeyRef, MixId, DEFAULT_MIX_LAYERS, MAX_MIX_LAYERS,
+};
 use nym_sphinx_addressing::nodes::NodeIdentity;
-use nym_sphinx_types::Node as SphinxNode;
+use nym_sphinx_types::{Node as SphinxNode, MAX_PATH_LENGTH};
 use rand::prelude::SliceRandom;
 use rand::{CryptoRng, Rng};
 use std::collections::BTreeMap;
@@ -27,6 +29,10 @@ pub mod provider_trait;
 
 pub use error::NymTopologyError;
 
+// every route has to go through all mix layers and the egress gateway,
+// so the contract must never allow more layers than sphinx headers can encode
+const _: () = assert!(MAX_MIX_LAYERS as usize + 1 <= MAX_PATH_LENGTH);
+
 #[derive(Debug, Clone)]
 pub enum NetworkAddress {
     IpAddr(IpAddr),
@@ -71,11 +77,30 @@ pub type MixLayer = u8;
 pub struct NymTopology {
     mixes: BTreeMap<MixLayer, Vec<mix::Node>>,
     gateways: Vec<gateway::Node>,
+
+    // number of mix layers every constructed path has to go through, as set in the mixnet contract
+    mix_layers: u8,
 }
 
 impl NymTopology {
     pub fn new(mixes: BTreeMap<MixLayer, Vec<mix::Node>>, gateways: Vec<gateway::Node>) -> Self {
-        NymTopology { mixes, gateways }
+        NymTopology {
+            mixes,
+            gateways,
+            mix_layers: DEFAULT_MIX_LAYERS,
+        }
+    }
+
+    /// Allows setting non-default number of mix layers in the network.
+    #[must_use]
+    pub fn with_mix_layers(mut self, mix_layers: u8) -> Self {
+        self.mix_layers = mix_layers;
+        self
+    }
+
+    /// Number of mix layers every packet is expected to traverse (excluding the gateway).
+    pub fn mix_layers(&self) -> u8 {
+        self.mix_layers
     }
 
     pub fn from_detailed(
@@ -131,8 +156,7 @@ impl NymTopology {
     }
 
     pub fn mixes_in_layer(&self, layer: MixLayer) -> Vec<mix::Node> {
-        assert!(vec![1, 2, 3].contains(&layer));
-        self.mixes.get(&layer).unwrap().to_owned()
+        self.mixes.get(&layer).cloned().unwrap_or_default()
     }
 
     pub fn gateways(&self) -> &[gateway::Node] {
@@ -210,6 +234,15 @@ impl NymTopology {
     where
         R: Rng + CryptoRng + ?Sized,
     {
+        // sphinx headers can only encode a limited number of hops (note the extra one for the gateway)
+        let route_length = num_mix_hops as usize + 1;
+        if route_length > MAX_PATH_LENGTH {
+            return Err(NymTopologyError::RouteTooLong {
+                requested: route_length,
+                max: MAX_PATH_LENGTH,
+            });
+        }
+
         let gateway = self.get_gateway(gateway_identity).ok_or(
             NymTopologyError::NonExistentGatewayError {
                 identity_key: gateway_identity.to_base58_string(),
@@ -314,6 +347,7 @@ impl NymTopology {
         NymTopology {
             mixes: self.mixes.filter_by_version(expected_mix_version),
             gateways: self.gateways.clone(),
+            mix_layers: self.mix_layers,
         }
     }
 }
@@ -328,13 +362,6 @@ pub fn nym_topology_from_detailed(
         .map(|details| details.bond_information)
     {
         let layer = bond.layer as MixLayer;
-        if layer == 0 || layer > 3 {
-            warn!(
-                "{} says it's on invalid layer {}!",
-                bond.mix_node.identity_key, layer
-            );
-            continue;
-        }
         let mix_id = bond.mix_id;
         let mix_identity = bond.mix_node.identity_key.clone();
 
@@ -425,3 +452,124 @@ mod converting_mixes_to_vec {
         }
     }
 }
+
+#[cfg(test)]
+mod routes_through_layers {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_mixnet_contract_common::{Layer, MAX_MIX_LAYERS, MIN_MIX_LAYERS};
+    use nym_sphinx_addressing::nodes::NymNodeRoutingAddress;
+
+    fn mix_on_layer(layer: Layer) -> mix::Node {
+        let layer_id = u8::from(layer);
+        mix::Node {
+            mix_id: layer_id as MixId,
+            owner: "N/A".to_string(),
+            host: "3.3.3.3".parse().unwrap(),
+            mix_host: SocketAddr::new("3.3.3.3".parse().unwrap(), 1000 + layer_id as u16),
+            identity_key: identity::PublicKey::from_base58_string(
+                "3ebjp1Fb9hdcS1AR6AZihgeJiMHkB5jjJUsvqNnfQwU7",
+            )
+            .unwrap(),
+            sphinx_key: encryption::PublicKey::from_base58_string(
+                "C7cown6dYCLZpLiMFC1PaBmhvLvmJmLDJGeRTbPD45bX",
+            )
+            .unwrap(),
+            layer,
+            version: "0.x.0".to_string(),
+        }
+    }
+
+    fn gateway() -> gateway::Node {
+        gateway::Node {
+            owner: "N/A".to_string(),
+            host: "4.4.4.4".parse().unwrap(),
+            mix_host: "4.4.4.4:1789".parse().unwrap(),
+            clients_port: 9000,
+            identity_key: identity::PublicKey::from_base58_string(
+                "3ebjp1Fb9hdcS1AR6AZihgeJiMHkB5jjJUsvqNnfQwU7",
+            )
+            .unwrap(),
+            sphinx_key: encryption::PublicKey::from_base58_string(
+                "C7cown6dYCLZpLiMFC1PaBmhvLvmJmLDJGeRTbPD45bX",
+            )
+            .unwrap(),
+            version: "0.x.0".to_string(),
+        }
+    }
+
+    fn layered_topology(mix_layers: u8) -> NymTopology {
+        let mixes = Layer::all(mix_layers)
+            .map(|layer| (u8::from(layer), vec![mix_on_layer(layer)]))
+            .collect();
+        NymTopology::new(mixes, vec![gateway()]).with_mix_layers(mix_layers)
+    }
+
+    fn hop_address(node: &SphinxNode) -> SocketAddr {
+        NymNodeRoutingAddress::try_from(node.address)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn mix_routes_go_through_every_configured_layer() {
+        let mut rng = rand::thread_rng();
+
+        for mix_layers in MIN_MIX_LAYERS..=MAX_MIX_LAYERS {
+            let topology = layered_topology(mix_layers);
+            assert!(topology
+                .ensure_can_construct_path_through(topology.mix_layers())
+                .is_ok());
+
+            let route = topology
+                .random_mix_route(&mut rng, topology.mix_layers())
+                .unwrap();
+            assert_eq!(route.len(), mix_layers as usize);
+            for (hop, layer) in route.iter().zip(Layer::all(mix_layers)) {
+                assert_eq!(hop_address(hop), mix_on_layer(layer).mix_host);
+            }
+        }
+    }
+
+    #[test]
+    fn every_allowed_number_of_layers_fits_in_sphinx_path() {
+        let mut rng = rand::thread_rng();
+        let gateway = gateway();
+
+        for mix_layers in MIN_MIX_LAYERS..=MAX_MIX_LAYERS {
+            let topology = layered_topology(mix_layers);
+            let route = topology
+                .random_route_to_gateway(&mut rng, topology.mix_layers(), gateway.identity())
+                .unwrap();
+
+            assert_eq!(route.len(), mix_layers as usize + 1);
+            for (hop, layer) in route.iter().zip(Layer::all(mix_layers)) {
+                assert_eq!(hop_address(hop), mix_on_layer(layer).mix_host);
+            }
+            assert_eq!(hop_address(route.last().unwrap()), gateway.mix_host);
+        }
+
+        // anything longer can't be encoded in a sphinx header
+        let topology = layered_topology(MAX_MIX_LAYERS);
+        assert!(matches!(
+            topology.random_route_to_gateway(&mut rng, MAX_PATH_LENGTH as u8, gateway.identity()),
+            Err(NymTopologyError::RouteTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn routes_cannot_be_longer_than_number_of_layers() {
+        let mut rng = rand::thread_rng();
+        let gateway = gateway();
+
+        for mix_layers in MIN_MIX_LAYERS..MAX_MIX_LAYERS {
+            let topology = layered_topology(mix_layers);
+            assert!(topology
+                .ensure_can_construct_path_through(mix_layers + 1)
+                .is_err());
+            assert!(topology
+                .random_route_to_gateway(&mut rng, mix_layers + 1, gateway.identity())
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::support::helpers::{mix_coin, mix_coins, vesting_owner};
+use crate::support::setup::{TestSetup, MIX_DENOM};
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_multi_test::Executor;
+use nym_contracts_common::Percent;
+use nym_vesting_contract_common::{
+    ExecuteMsg as VestingExecuteMsg, OriginalVestingResponse, QueryMsg as VestingQueryMsg,
+};
+use vesting_contract::errors::ContractError as VestingContractError;
+
+fn original_vesting(test: &TestSetup, address: &str) -> OriginalVestingResponse {
+    test.app
+        .wrap()
+        .query_wasm_smart(
+            test.vesting_contract(),
+            &VestingQueryMsg::GetOriginalVesting {
+                vesting_account_address: address.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+fn spendable_coins(test: &TestSetup, address: &str) -> Coin {
+    test.app
+        .wrap()
+        .query_wasm_smart(
+            test.vesting_contract(),
+            &VestingQueryMsg::SpendableCoins {
+                vesting_account_address: address.to_string(),
+                block_time: None,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn split_vesting_account_between_beneficiaries() {
+    let mut test = TestSetup::new_simple();
+    let vesting_account = "vesting-account";
+    let beneficiary = "beneficiary";
+
+    // 1. create vesting account
+    let create_msg = VestingExecuteMsg::CreateAccount {
+        owner_address: vesting_account.to_string(),
+        staking_address: None,
+        vesting_spec: None,
+        cap: None,
+    };
+
+    test.app
+        .execute_contract(
+            vesting_owner(),
+            test.vesting_contract(),
+            &create_msg,
+            &mix_coins(1_000_000_000),
+        )
+        .unwrap();
+
+    // 2. split 40% of it to the beneficiary
+    let split_msg = VestingExecuteMsg::SplitAccount {
+        to_address: beneficiary.to_string(),
+        percentage: Percent::from_percentage_value(40).unwrap(),
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(vesting_account),
+            test.vesting_contract(),
+            &split_msg,
+            &[],
+        )
+        .unwrap();
+
+    // the beneficiary can't split it back as the original account still exists
+    let split_back_msg = VestingExecuteMsg::SplitAccount {
+        to_address: vesting_account.to_string(),
+        percentage: Percent::from_percentage_value(50).unwrap(),
+    };
+    let res = test
+        .app
+        .execute_contract(
+            Addr::unchecked(beneficiary),
+            test.vesting_contract(),
+            &split_back_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        VestingContractError::AccountAlreadyExists(vesting_account.to_string()),
+        res.downcast().unwrap()
+    );
+
+    let original = original_vesting(&test, vesting_account);
+    let split = original_vesting(&test, beneficiary);
+    assert_eq!(original.amount, mix_coin(600_000_000));
+    assert_eq!(split.amount, mix_coin(400_000_000));
+    assert_eq!(original.number_of_periods, split.number_of_periods);
+    assert_eq!(original.period_duration, split.period_duration);
+
+    // no tokens have left the contract
+    let contract_balance = test
+        .app
+        .wrap()
+        .query_balance(test.vesting_contract(), MIX_DENOM)
+        .unwrap();
+    assert_eq!(contract_balance.amount.u128(), 1_000_000_000);
+
+    // 3. go past the first vesting period
+    let period_duration = split.period_duration;
+    test.app.update_block(|current_block| {
+        current_block.height += 1000;
+        current_block.time =
+            Timestamp::from_seconds(current_block.time.seconds() + period_duration);
+    });
+
+    assert_eq!(
+        spendable_coins(&test, vesting_account),
+        mix_coin(600_000_000 / 8)
+    );
+    assert_eq!(
+        spendable_coins(&test, beneficiary),
+        mix_coin(400_000_000 / 8)
+    );
+
+    // 4. the beneficiary can withdraw its own vested tokens
+    let withdraw_msg = VestingExecuteMsg::WithdrawVestedCoins {
+        amount: mix_coin(400_000_000 / 8),
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(beneficiary),
+            test.vesting_contract(),
+            &withdraw_msg,
+            &[],
+        )
+        .unwrap();
+
+    let beneficiary_balance = test
+        .app
+        .wrap()
+        .query_balance(beneficiary, MIX_DENOM)
+        .unwrap();
+    assert_eq!(beneficiary_balance.amount.u128(), 400_000_000 / 8);
+    assert_eq!(spendable_coins(&test, beneficiary), mix_coin(0));
+
+    // but the original account is unaffected
+    assert_eq!(
+        spendable_coins(&test, vesting_account),
+        mix_coin(600_000_000 / 8)
+    );
+}
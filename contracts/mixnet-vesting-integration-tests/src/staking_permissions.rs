@@ -0,0 +1,239 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::support::helpers::{mix_coin, mix_coins, vesting_owner};
+use crate::support::setup::TestSetup;
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+use nym_contracts_common::Percent;
+use nym_mixnet_contract_common::delegation::MixNodeDelegationResponse;
+use nym_mixnet_contract_common::{MixNodeCostParams, QueryMsg as MixnetQueryMsg};
+use nym_vesting_contract_common::{
+    ExecuteMsg as VestingExecuteMsg, QueryMsg as VestingQueryMsg, StakingPermission,
+    StakingPermissionsResponse,
+};
+use vesting_contract::errors::ContractError as VestingContractError;
+
+fn create_vesting_account(test: &mut TestSetup, owner: &str) {
+    let create_msg = VestingExecuteMsg::CreateAccount {
+        owner_address: owner.to_string(),
+        staking_address: None,
+        vesting_spec: None,
+        cap: None,
+    };
+
+    test.app
+        .execute_contract(
+            vesting_owner(),
+            test.vesting_contract(),
+            &create_msg,
+            &mix_coins(10_000_000_000),
+        )
+        .unwrap();
+}
+
+#[test]
+fn delegating_with_scoped_staking_permissions() {
+    let mut test = TestSetup::new_simple();
+    let operator = "mix-operator";
+    let vesting_account = "vesting-account";
+    let third_party = "third-party";
+
+    // 1. bond a mixnode to delegate to
+    create_vesting_account(&mut test, operator);
+
+    let pledge = mix_coin(150_000_000);
+    let cost_params = MixNodeCostParams {
+        profit_margin_percent: Percent::from_percentage_value(10).unwrap(),
+        interval_operating_cost: mix_coin(40_000_000),
+    };
+    let (mix_node, owner_signature) = test.valid_mixnode_with_sig(
+        operator,
+        Some(test.vesting_contract()),
+        cost_params.clone(),
+        pledge.clone(),
+    );
+    let bond_msg = VestingExecuteMsg::BondMixnode {
+        mix_node,
+        cost_params,
+        owner_signature,
+        amount: pledge,
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(operator),
+            test.vesting_contract(),
+            &bond_msg,
+            &[],
+        )
+        .unwrap();
+    let mix_id = 1;
+
+    // 2. create the account that's going to grant the permissions
+    create_vesting_account(&mut test, vesting_account);
+
+    let delegate_msg = VestingExecuteMsg::DelegateToMixnode {
+        mix_id,
+        amount: mix_coin(100_000_000),
+        on_behalf_of: Some(vesting_account.to_string()),
+    };
+    let undelegate_msg = VestingExecuteMsg::UndelegateFromMixnode {
+        mix_id,
+        on_behalf_of: Some(vesting_account.to_string()),
+    };
+
+    // without any permissions the third party can't do anything
+    let res = test
+        .app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &delegate_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        VestingContractError::InvalidStakingAccount {
+            address: Addr::unchecked(third_party),
+            for_account: Addr::unchecked(vesting_account),
+        },
+        res.downcast().unwrap()
+    );
+
+    // 3. grant the delegation permission only
+    let grant_msg = VestingExecuteMsg::GrantStakingPermissions {
+        grantee: third_party.to_string(),
+        permissions: vec![StakingPermission::Delegate],
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(vesting_account),
+            test.vesting_contract(),
+            &grant_msg,
+            &[],
+        )
+        .unwrap();
+
+    let permissions: StakingPermissionsResponse = test
+        .app
+        .wrap()
+        .query_wasm_smart(
+            test.vesting_contract(),
+            &VestingQueryMsg::GetStakingPermissions {
+                vesting_account_address: vesting_account.to_string(),
+                grantee: third_party.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(permissions.permissions, vec![StakingPermission::Delegate]);
+
+    test.app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &delegate_msg,
+            &[],
+        )
+        .unwrap();
+
+    // the delegation gets created at the end of the epoch on behalf of the vesting account
+    test.advance_mixnet_epoch();
+    let delegation: MixNodeDelegationResponse = test
+        .app
+        .wrap()
+        .query_wasm_smart(
+            test.mixnet_contract(),
+            &MixnetQueryMsg::GetDelegationDetails {
+                mix_id,
+                delegator: vesting_account.to_string(),
+                proxy: Some(test.vesting_contract().to_string()),
+            },
+        )
+        .unwrap();
+    let delegation = delegation.delegation.unwrap();
+    assert_eq!(delegation.owner, Addr::unchecked(vesting_account));
+    assert_eq!(delegation.amount, mix_coin(100_000_000));
+
+    // but it can't undelegate nor withdraw anything
+    let res = test
+        .app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &undelegate_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        VestingContractError::InvalidStakingAccount {
+            address: Addr::unchecked(third_party),
+            for_account: Addr::unchecked(vesting_account),
+        },
+        res.downcast().unwrap()
+    );
+
+    let withdraw_msg = VestingExecuteMsg::WithdrawVestedCoins {
+        amount: mix_coin(1),
+    };
+    assert!(test
+        .app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &withdraw_msg,
+            &[],
+        )
+        .is_err());
+
+    // 4. once the permission gets extended, it can undelegate as well
+    let grant_msg = VestingExecuteMsg::GrantStakingPermissions {
+        grantee: third_party.to_string(),
+        permissions: vec![StakingPermission::Delegate, StakingPermission::Undelegate],
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(vesting_account),
+            test.vesting_contract(),
+            &grant_msg,
+            &[],
+        )
+        .unwrap();
+    test.app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &undelegate_msg,
+            &[],
+        )
+        .unwrap();
+
+    // 5. and after revoking, it's back to nothing
+    let revoke_msg = VestingExecuteMsg::RevokeStakingPermissions {
+        grantee: third_party.to_string(),
+    };
+    test.app
+        .execute_contract(
+            Addr::unchecked(vesting_account),
+            test.vesting_contract(),
+            &revoke_msg,
+            &[],
+        )
+        .unwrap();
+
+    let res = test
+        .app
+        .execute_contract(
+            Addr::unchecked(third_party),
+            test.vesting_contract(),
+            &delegate_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        VestingContractError::InvalidStakingAccount {
+            address: Addr::unchecked(third_party),
+            for_account: Addr::unchecked(vesting_account),
+        },
+        res.downcast().unwrap()
+    );
+}
@@ -2,4 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod decrease_mixnode_pledge;
+mod split_vesting_account;
+mod staking_permissions;
 mod support;
@@ -9,6 +9,10 @@ pub const INITIAL_GATEWAY_PLEDGE_AMOUNT: Uint128 = Uint128::new(100_000_000);
 /// Constant specifying minimum of coin amount required to bond a mixnode
 pub const INITIAL_MIXNODE_PLEDGE_AMOUNT: Uint128 = Uint128::new(100_000_000);
 
+/// Constant specifying the number of most recent epochs for which the rewarding results are kept
+/// for every mixnode. With the current epoch length of one hour, it's equivalent to 30 days.
+pub const MIXNODE_REWARD_HISTORY_LENGTH: u32 = 720;
+
 // retrieval limits
 // TODO: those would need to be empirically verified whether they're not way too small or way too high
 pub const GATEWAY_BOND_DEFAULT_RETRIEVAL_LIMIT: u32 = 100;
@@ -38,13 +42,24 @@ pub const REWARDED_SET_MAX_RETRIEVAL_LIMIT: u32 = 1000;
 pub const FAMILIES_DEFAULT_RETRIEVAL_LIMIT: u32 = 10;
 pub const FAMILIES_MAX_RETRIEVAL_LIMIT: u32 = 20;
 
+pub const REWARD_HISTORY_DEFAULT_RETRIEVAL_LIMIT: u32 = 100;
+pub const REWARD_HISTORY_MAX_RETRIEVAL_LIMIT: u32 = 200;
+
+pub const SLASHING_RECORDS_DEFAULT_RETRIEVAL_LIMIT: u32 = 50;
+pub const SLASHING_RECORDS_MAX_RETRIEVAL_LIMIT: u32 = 100;
+
 // storage keys
 pub const DELEGATION_PK_NAMESPACE: &str = "dl";
 pub const DELEGATION_OWNER_IDX_NAMESPACE: &str = "dlo";
 pub const DELEGATION_MIXNODE_IDX_NAMESPACE: &str = "dlm";
+pub const AUTO_COMPOUNDING_DELEGATIONS_NAMESPACE: &str = "dac";
+pub const GATEWAY_DELEGATION_PK_NAMESPACE: &str = "gdl";
+pub const GATEWAY_DELEGATION_OWNER_IDX_NAMESPACE: &str = "gdlo";
+pub const GATEWAY_DELEGATION_GATEWAY_IDX_NAMESPACE: &str = "gdlg";
 
 pub const GATEWAYS_PK_NAMESPACE: &str = "gt";
 pub const GATEWAYS_OWNER_IDX_NAMESPACE: &str = "gto";
+pub const BONDED_GATEWAYS_COUNT_KEY: &str = "bgc";
 
 pub const REWARDED_SET_KEY: &str = "rs";
 pub const CURRENT_EPOCH_STATUS_KEY: &str = "ces";
@@ -73,10 +88,22 @@ pub const UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE: &str = "umi";
 
 pub const REWARDING_PARAMS_KEY: &str = "rparams";
 pub const PENDING_REWARD_POOL_KEY: &str = "prp";
+pub const COMPOUNDING_PROGRESS_KEY: &str = "cmpp";
 pub const MIXNODES_REWARDING_PK_NAMESPACE: &str = "mnr";
+pub const MIXNODES_REWARD_HISTORY_NAMESPACE: &str = "mnrh";
+pub const GATEWAY_REWARDING_PARAMS_KEY: &str = "gparams";
+pub const GATEWAYS_REWARDING_PK_NAMESPACE: &str = "gtr";
+pub const EPOCH_GATEWAY_REWARD_KEY: &str = "egr";
 
 pub const FAMILIES_INDEX_NAMESPACE: &str = "faml2";
 pub const FAMILIES_MAP_NAMESPACE: &str = "fam2";
 pub const MEMBERS_MAP_NAMESPACE: &str = "memb2";
 
 pub const SIGNING_NONCES_NAMESPACE: &str = "sn";
+
+pub const SLASHING_ID_COUNTER_KEY: &str = "sic";
+pub const SLASHING_RECORDS_PK_NAMESPACE: &str = "slr";
+pub const MIXNODE_SLASHING_RECORDS_NAMESPACE: &str = "slrm";
+pub const GATEWAY_SLASHING_RECORDS_NAMESPACE: &str = "slrg";
+pub const PENDING_GATEWAY_SLASHINGS_NAMESPACE: &str = "pgs";
+pub const SLASHING_PROGRESS_KEY: &str = "slp";
@@ -12,6 +12,7 @@ use cosmwasm_std::{
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::{
     ContractState, ContractStateParams, ExecuteMsg, InstantiateMsg, Interval, MigrateMsg, QueryMsg,
+    DEFAULT_MIX_LAYERS,
 };
 use semver::Version;
 
@@ -40,6 +41,7 @@ fn default_initial_state(
                 denom: rewarding_denom,
                 amount: INITIAL_GATEWAY_PLEDGE_AMOUNT,
             },
+            mix_layers: DEFAULT_MIX_LAYERS,
         },
     }
 }
@@ -325,6 +327,13 @@ pub fn execute(
                 deps, info, new_config, owner,
             )
         }
+        ExecuteMsg::UpdateGatewayRewardingParams { updated_params } => {
+            crate::rewards::transactions::try_update_gateway_rewarding_params(
+                deps,
+                info,
+                updated_params,
+            )
+        }
 
         // delegation-related:
         ExecuteMsg::DelegateToMixnode { mix_id } => {
@@ -345,6 +354,50 @@ pub fn execute(
                 deps, env, info, mix_id, delegate,
             )
         }
+        ExecuteMsg::RedelegateFromMixnode {
+            from_mix_id,
+            to_mix_id,
+            amount,
+        } => crate::delegations::transactions::try_redelegate_from_mixnode(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+            amount,
+        ),
+        ExecuteMsg::RedelegateFromMixnodeOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate,
+        } => crate::delegations::transactions::try_redelegate_from_mixnode_on_behalf(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate,
+        ),
+        ExecuteMsg::DelegateToGateway { identity } => {
+            crate::delegations::transactions::try_delegate_to_gateway(deps, env, info, identity)
+        }
+        ExecuteMsg::DelegateToGatewayOnBehalf { identity, delegate } => {
+            crate::delegations::transactions::try_delegate_to_gateway_on_behalf(
+                deps, env, info, identity, delegate,
+            )
+        }
+        ExecuteMsg::UndelegateFromGateway { identity } => {
+            crate::delegations::transactions::try_remove_delegation_from_gateway(
+                deps, env, info, identity,
+            )
+        }
+        ExecuteMsg::UndelegateFromGatewayOnBehalf { identity, delegate } => {
+            crate::delegations::transactions::try_remove_delegation_from_gateway_on_behalf(
+                deps, env, info, identity, delegate,
+            )
+        }
 
         // reward-related
         ExecuteMsg::RewardMixnode {
@@ -366,6 +419,60 @@ pub fn execute(
                 deps, info, mix_id, owner,
             )
         }
+        ExecuteMsg::SetDelegationAutoCompounding { mix_id, enabled } => {
+            crate::delegations::transactions::try_set_delegation_auto_compounding(
+                deps, env, info, mix_id, enabled,
+            )
+        }
+        ExecuteMsg::SetDelegationAutoCompoundingOnBehalf {
+            mix_id,
+            enabled,
+            owner,
+        } => crate::delegations::transactions::try_set_delegation_auto_compounding_on_behalf(
+            deps, env, info, mix_id, enabled, owner,
+        ),
+        ExecuteMsg::RewardGateway {
+            identity,
+            performance,
+        } => crate::rewards::transactions::try_reward_gateway(deps, info, identity, performance),
+        ExecuteMsg::WithdrawGatewayOperatorReward {} => {
+            crate::rewards::transactions::try_withdraw_gateway_operator_reward(deps, info)
+        }
+        ExecuteMsg::WithdrawGatewayDelegatorReward { identity } => {
+            crate::rewards::transactions::try_withdraw_gateway_delegator_reward(
+                deps, info, identity,
+            )
+        }
+
+        // slashing-related
+        ExecuteMsg::SlashMixnode {
+            mix_id,
+            slash_percent,
+            evidence,
+            redistribute,
+        } => crate::slashing::transactions::try_slash_mixnode(
+            deps,
+            env,
+            info,
+            mix_id,
+            slash_percent,
+            evidence,
+            redistribute,
+        ),
+        ExecuteMsg::SlashGateway {
+            identity,
+            slash_percent,
+            evidence,
+            redistribute,
+        } => crate::slashing::transactions::try_slash_gateway(
+            deps,
+            env,
+            info,
+            identity,
+            slash_percent,
+            evidence,
+            redistribute,
+        ),
 
         // testing-only
         #[cfg(feature = "contract-testing")]
@@ -492,6 +599,12 @@ pub fn query(
         QueryMsg::GetOwnedGateway { address } => to_binary(
             &crate::gateways::queries::query_owned_gateway(deps, address)?,
         ),
+        QueryMsg::GetGatewayRewardingParams {} => to_binary(
+            &crate::rewards::queries::query_gateway_rewarding_params(deps)?,
+        ),
+        QueryMsg::GetGatewayRewardingDetails { identity } => to_binary(
+            &crate::rewards::queries::query_gateway_rewarding_details(deps, identity)?,
+        ),
 
         // delegation-related:
         QueryMsg::GetMixnodeDelegations {
@@ -528,6 +641,36 @@ pub fn query(
         QueryMsg::GetAllDelegations { start_after, limit } => to_binary(
             &crate::delegations::queries::query_all_delegations_paged(deps, start_after, limit)?,
         ),
+        QueryMsg::GetGatewayDelegations {
+            identity,
+            start_after,
+            limit,
+        } => to_binary(
+            &crate::delegations::queries::query_gateway_delegations_paged(
+                deps,
+                identity,
+                start_after,
+                limit,
+            )?,
+        ),
+        QueryMsg::GetDelegatorGatewayDelegations {
+            delegator,
+            start_after,
+            limit,
+        } => to_binary(
+            &crate::delegations::queries::query_delegator_gateway_delegations_paged(
+                deps,
+                delegator,
+                start_after,
+                limit,
+            )?,
+        ),
+        QueryMsg::GetGatewayDelegationDetails {
+            identity,
+            delegator,
+        } => to_binary(&crate::delegations::queries::query_gateway_delegation(
+            deps, identity, delegator,
+        )?),
 
         // rewards related
         QueryMsg::GetPendingOperatorReward { address } => to_binary(
@@ -567,6 +710,26 @@ pub fn query(
                 estimated_performance,
             )?,
         ),
+        QueryMsg::GetPendingGatewayOperatorReward { address } => to_binary(
+            &crate::rewards::queries::query_pending_gateway_operator_reward(deps, address)?,
+        ),
+        QueryMsg::GetPendingGatewayDelegatorReward { address, identity } => to_binary(
+            &crate::rewards::queries::query_pending_gateway_delegator_reward(
+                deps, address, identity,
+            )?,
+        ),
+        QueryMsg::GetMixnodeRewardHistory {
+            mix_id,
+            limit,
+            start_after,
+        } => to_binary(
+            &crate::rewards::queries::query_mixnode_reward_history_paged(
+                deps,
+                mix_id,
+                start_after,
+                limit,
+            )?,
+        ),
 
         // interval-related
         QueryMsg::GetPendingEpochEvents { limit, start_after } => {
@@ -594,6 +757,39 @@ pub fn query(
         QueryMsg::GetNumberOfPendingEvents {} => to_binary(
             &crate::interval::queries::query_number_of_pending_events(deps)?,
         ),
+
+        // slashing-related
+        QueryMsg::GetSlashingRecord { slashing_id } => to_binary(
+            &crate::slashing::queries::query_slashing_record(deps, slashing_id)?,
+        ),
+        QueryMsg::GetSlashingHistory { limit, start_after } => to_binary(
+            &crate::slashing::queries::query_slashing_history_paged(deps, start_after, limit)?,
+        ),
+        QueryMsg::GetMixnodeSlashingHistory {
+            mix_id,
+            limit,
+            start_after,
+        } => to_binary(
+            &crate::slashing::queries::query_mixnode_slashing_history_paged(
+                deps,
+                mix_id,
+                start_after,
+                limit,
+            )?,
+        ),
+        QueryMsg::GetGatewaySlashingHistory {
+            identity,
+            limit,
+            start_after,
+        } => to_binary(
+            &crate::slashing::queries::query_gateway_slashing_history_paged(
+                deps,
+                identity,
+                start_after,
+                limit,
+            )?,
+        ),
+
         QueryMsg::GetSigningNonce { address } => to_binary(
             &crate::signing::queries::query_current_signing_nonce(deps, address)?,
         ),
@@ -631,6 +827,7 @@ pub fn migrate(
         // If state structure changed in any contract version in the way migration is needed, it
         // should occur here, for example anything from `crate::queued_migrations::`
         crate::queued_migrations::insert_pending_pledge_changes(deps.branch())?;
+        crate::queued_migrations::initialise_gateway_rewarding(deps.branch())?;
     }
 
     // due to circular dependency on contract addresses (i.e. mixnet contract requiring vesting contract address
@@ -697,6 +894,7 @@ mod tests {
                     denom: "uatom".into(),
                     amount: INITIAL_GATEWAY_PLEDGE_AMOUNT,
                 },
+                mix_layers: DEFAULT_MIX_LAYERS,
             },
         };
 
@@ -6,7 +6,7 @@ use crate::rewards::storage as rewards_storage;
 use cosmwasm_std::{Coin, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::MixNodeRewarding;
-use mixnet_contract_common::Delegation;
+use mixnet_contract_common::{Delegation, GatewayDelegation, GatewayRewarding};
 
 pub(crate) fn undelegate(
     store: &mut dyn Storage,
@@ -17,6 +17,35 @@ pub(crate) fn undelegate(
 
     rewards_storage::MIXNODE_REWARDING.save(store, delegation.mix_id, &mix_rewarding)?;
     storage::delegations().replace(store, delegation.storage_key(), None, Some(&delegation))?;
+    storage::AUTO_COMPOUNDING_DELEGATIONS.remove(store, delegation.storage_key());
+
+    Ok(tokens)
+}
+
+pub(crate) fn undelegate_from_gateway(
+    store: &mut dyn Storage,
+    delegation: GatewayDelegation,
+    mut gateway_rewarding: GatewayRewarding,
+) -> Result<Coin, MixnetContractError> {
+    // note: this has to be checked before undelegating, as removing the final delegation
+    // moves any leftover dust to the operator
+    let gateway_still_bonded = gateway_rewarding.still_bonded();
+    let tokens = gateway_rewarding.undelegate(&delegation)?;
+
+    let identity = &delegation.gateway_identity;
+    if !gateway_still_bonded && gateway_rewarding.unique_delegations == 0 {
+        // the gateway has unbonded and this was the last delegation, so there's nobody left
+        // who might care about its rewarding information
+        rewards_storage::GATEWAY_REWARDING.remove(store, identity);
+    } else {
+        rewards_storage::GATEWAY_REWARDING.save(store, identity, &gateway_rewarding)?;
+    }
+    storage::gateway_delegations().replace(
+        store,
+        delegation.storage_key(),
+        None,
+        Some(&delegation),
+    )?;
 
     Ok(tokens)
 }
@@ -62,4 +91,25 @@ mod tests {
             .unwrap()
             .is_none());
     }
+
+    #[test]
+    fn undelegation_disables_auto_compounding() {
+        let mut test = TestSetup::new();
+
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        let delegator = "delegator";
+        test.add_immediate_delegation(delegator, 200_000_000u32, mix_id);
+
+        let storage_key =
+            Delegation::generate_storage_key(mix_id, &Addr::unchecked(delegator), None);
+        storage::AUTO_COMPOUNDING_DELEGATIONS
+            .save(test.deps_mut().storage, storage_key.clone(), &1)
+            .unwrap();
+
+        let mix_rewarding = test.mix_rewarding(mix_id);
+        let delegation = test.delegation(mix_id, delegator, &None);
+        undelegate(test.deps_mut().storage, delegation, mix_rewarding).unwrap();
+
+        assert!(!storage::AUTO_COMPOUNDING_DELEGATIONS.has(test.deps().storage, storage_key));
+    }
 }
@@ -5,6 +5,7 @@ use super::storage;
 use crate::constants::{
     DELEGATION_PAGE_DEFAULT_RETRIEVAL_LIMIT, DELEGATION_PAGE_MAX_RETRIEVAL_LIMIT,
 };
+use crate::gateways::storage as gateways_storage;
 use crate::mixnodes::storage as mixnodes_storage;
 use cosmwasm_std::Deps;
 use cosmwasm_std::Order;
@@ -12,7 +13,9 @@ use cosmwasm_std::StdResult;
 use cw_storage_plus::Bound;
 use mixnet_contract_common::delegation::{MixNodeDelegationResponse, OwnerProxySubKey};
 use mixnet_contract_common::{
-    delegation, Delegation, MixId, PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
+    delegation, Delegation, GatewayDelegation, GatewayDelegationResponse, IdentityKey, MixId,
+    PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
+    PagedDelegatorGatewayDelegationsResponse, PagedGatewayDelegationsResponse,
     PagedMixNodeDelegationsResponse,
 };
 
@@ -96,7 +99,8 @@ pub(crate) fn query_mixnode_delegation(
     let storage_key =
         Delegation::generate_storage_key(mix_id, &validated_owner, validated_proxy.as_ref());
 
-    let delegation = storage::delegations().may_load(deps.storage, storage_key)?;
+    let delegation = storage::delegations().may_load(deps.storage, storage_key.clone())?;
+    let auto_compounding = storage::AUTO_COMPOUNDING_DELEGATIONS.has(deps.storage, storage_key);
 
     let mixnode_still_bonded = mixnodes_storage::mixnode_bonds()
         .may_load(deps.storage, mix_id)?
@@ -106,6 +110,7 @@ pub(crate) fn query_mixnode_delegation(
     Ok(MixNodeDelegationResponse::new(
         delegation,
         mixnode_still_bonded,
+        auto_compounding,
     ))
 }
 
@@ -134,6 +139,98 @@ pub(crate) fn query_all_delegations_paged(
     ))
 }
 
+pub(crate) fn query_gateway_delegations_paged(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PagedGatewayDelegationsResponse> {
+    let limit = limit
+        .unwrap_or(DELEGATION_PAGE_DEFAULT_RETRIEVAL_LIMIT)
+        .min(DELEGATION_PAGE_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after
+        .map(|delegator| deps.api.addr_validate(&delegator))
+        .transpose()?
+        .map(|delegator| {
+            Bound::exclusive(GatewayDelegation::generate_storage_key(
+                &identity, &delegator,
+            ))
+        });
+
+    let delegations = storage::gateway_delegations()
+        .idx
+        .gateway
+        .prefix(identity.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<GatewayDelegation>>>()?;
+
+    let start_next_after = delegations.last().map(|del| del.owner.clone());
+
+    Ok(PagedGatewayDelegationsResponse::new(
+        delegations,
+        start_next_after,
+    ))
+}
+
+pub(crate) fn query_delegator_gateway_delegations_paged(
+    deps: Deps<'_>,
+    delegation_owner: String,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> StdResult<PagedDelegatorGatewayDelegationsResponse> {
+    let validated_owner = deps.api.addr_validate(&delegation_owner)?;
+
+    let limit = limit
+        .unwrap_or(DELEGATION_PAGE_DEFAULT_RETRIEVAL_LIMIT)
+        .min(DELEGATION_PAGE_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(|identity| {
+        Bound::exclusive(GatewayDelegation::generate_storage_key(
+            &identity,
+            &validated_owner,
+        ))
+    });
+
+    let delegations = storage::gateway_delegations()
+        .idx
+        .owner
+        .prefix(validated_owner.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = delegations.last().map(|del| del.gateway_identity.clone());
+
+    Ok(PagedDelegatorGatewayDelegationsResponse::new(
+        delegations,
+        start_next_after,
+    ))
+}
+
+// queries for delegation value of given address for particular gateway
+pub(crate) fn query_gateway_delegation(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+    delegation_owner: String,
+) -> StdResult<GatewayDelegationResponse> {
+    let validated_owner = deps.api.addr_validate(&delegation_owner)?;
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &validated_owner);
+
+    let delegation = storage::gateway_delegations().may_load(deps.storage, storage_key)?;
+    let gateway_still_bonded = gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .is_some();
+
+    Ok(GatewayDelegationResponse::new(
+        delegation,
+        gateway_still_bonded,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -679,6 +776,7 @@ mod tests {
     #[cfg(test)]
     mod querying_for_specific_mixnode_delegation {
         use super::*;
+        use cosmwasm_std::Addr;
 
         #[test]
         fn when_delegation_doesnt_exist() {
@@ -733,6 +831,25 @@ mod tests {
             assert_eq!(res.delegation.as_ref().unwrap().owner.as_str(), owner);
             assert_eq!(res.delegation.as_ref().unwrap().amount.amount.u128(), 1000);
             assert!(res.mixnode_still_bonded);
+            assert!(!res.auto_compounding);
+        }
+
+        #[test]
+        fn when_delegation_is_auto_compounding() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            let owner = "owner";
+
+            test.add_immediate_delegation(owner, 1000u32, mix_id);
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(owner), None);
+            storage::AUTO_COMPOUNDING_DELEGATIONS
+                .save(test.deps_mut().storage, storage_key, &1)
+                .unwrap();
+
+            let res = query_mixnode_delegation(test.deps(), mix_id, owner.into(), None).unwrap();
+            assert_eq!(res.delegation.as_ref().unwrap().owner.as_str(), owner);
+            assert!(res.auto_compounding);
         }
     }
 }
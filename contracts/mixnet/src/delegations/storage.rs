@@ -2,15 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::constants::{
-    DELEGATION_MIXNODE_IDX_NAMESPACE, DELEGATION_OWNER_IDX_NAMESPACE, DELEGATION_PK_NAMESPACE,
+    AUTO_COMPOUNDING_DELEGATIONS_NAMESPACE, DELEGATION_MIXNODE_IDX_NAMESPACE,
+    DELEGATION_OWNER_IDX_NAMESPACE, DELEGATION_PK_NAMESPACE,
+    GATEWAY_DELEGATION_GATEWAY_IDX_NAMESPACE, GATEWAY_DELEGATION_OWNER_IDX_NAMESPACE,
+    GATEWAY_DELEGATION_PK_NAMESPACE,
+};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
+use mixnet_contract_common::delegation::{GatewayStorageKey, OwnerProxySubKey};
+use mixnet_contract_common::{
+    Addr, BlockHeight, Delegation, GatewayDelegation, IdentityKey, MixId,
 };
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
-use mixnet_contract_common::delegation::OwnerProxySubKey;
-use mixnet_contract_common::{Addr, Delegation, MixId};
 
 // It's a composite key on node's id and delegator address
 type PrimaryKey = (MixId, OwnerProxySubKey);
 
+/// Delegations whose rewards are folded into them at the end of every epoch
+/// alongside the block height at which the compounding got enabled.
+pub(crate) const AUTO_COMPOUNDING_DELEGATIONS: Map<PrimaryKey, BlockHeight> =
+    Map::new(AUTO_COMPOUNDING_DELEGATIONS_NAMESPACE);
+
 pub(crate) struct DelegationIndex<'a> {
     pub(crate) owner: MultiIndex<'a, Addr, Delegation, PrimaryKey>,
 
@@ -40,3 +50,35 @@ pub(crate) fn delegations<'a>() -> IndexedMap<'a, PrimaryKey, Delegation, Delega
 
     IndexedMap::new(DELEGATION_PK_NAMESPACE, indexes)
 }
+
+pub(crate) struct GatewayDelegationIndex<'a> {
+    pub(crate) owner: MultiIndex<'a, Addr, GatewayDelegation, GatewayStorageKey>,
+
+    pub(crate) gateway: MultiIndex<'a, IdentityKey, GatewayDelegation, GatewayStorageKey>,
+}
+
+impl<'a> IndexList<GatewayDelegation> for GatewayDelegationIndex<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<GatewayDelegation>> + '_> {
+        let v: Vec<&dyn Index<GatewayDelegation>> = vec![&self.owner, &self.gateway];
+        Box::new(v.into_iter())
+    }
+}
+
+// It's a composite key on gateway's identity and delegator address
+pub(crate) fn gateway_delegations<'a>(
+) -> IndexedMap<'a, GatewayStorageKey, GatewayDelegation, GatewayDelegationIndex<'a>> {
+    let indexes = GatewayDelegationIndex {
+        owner: MultiIndex::new(
+            |d| d.owner.clone(),
+            GATEWAY_DELEGATION_PK_NAMESPACE,
+            GATEWAY_DELEGATION_OWNER_IDX_NAMESPACE,
+        ),
+        gateway: MultiIndex::new(
+            |d| d.gateway_identity.clone(),
+            GATEWAY_DELEGATION_PK_NAMESPACE,
+            GATEWAY_DELEGATION_GATEWAY_IDX_NAMESPACE,
+        ),
+    };
+
+    IndexedMap::new(GATEWAY_DELEGATION_PK_NAMESPACE, indexes)
+}
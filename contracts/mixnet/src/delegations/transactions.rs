@@ -2,19 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::storage;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::storage as mixnodes_storage;
+use crate::rewards::storage as rewards_storage;
 use crate::support::helpers::{
-    ensure_epoch_in_progress_state, ensure_sent_by_vesting_contract, validate_delegation_stake,
+    ensure_epoch_in_progress_state, ensure_proxy_match, ensure_sent_by_vesting_contract,
+    validate_delegation_stake,
 };
 use cosmwasm_std::{Addr, Coin, DepsMut, Env, MessageInfo, Response};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_pending_delegation_event, new_pending_undelegation_event,
+    new_delegation_auto_compounding_update_event, new_pending_delegation_event,
+    new_pending_gateway_delegation_event, new_pending_gateway_undelegation_event,
+    new_pending_redelegation_event, new_pending_undelegation_event,
 };
 use mixnet_contract_common::pending_events::PendingEpochEventKind;
-use mixnet_contract_common::{Delegation, MixId};
+use mixnet_contract_common::rewarding::helpers::truncate_reward;
+use mixnet_contract_common::{Delegation, GatewayDelegation, IdentityKey, MixId};
 
 pub(crate) fn try_delegate_to_mixnode(
     deps: DepsMut<'_>,
@@ -139,6 +145,308 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
     Ok(Response::new().add_event(cosmos_event))
 }
 
+pub(crate) fn try_redelegate_from_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+) -> Result<Response, MixnetContractError> {
+    _try_redelegate_from_mixnode(deps, env, from_mix_id, to_mix_id, amount, info.sender, None)
+}
+
+pub(crate) fn try_redelegate_from_mixnode_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    delegate: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let delegate = deps.api.addr_validate(&delegate)?;
+    _try_redelegate_from_mixnode(
+        deps,
+        env,
+        from_mix_id,
+        to_mix_id,
+        amount,
+        delegate,
+        Some(info.sender),
+    )
+}
+
+pub(crate) fn _try_redelegate_from_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    delegate: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // redelegation is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    if from_mix_id == to_mix_id {
+        return Err(MixnetContractError::RedelegationToSameMixnode {
+            mix_id: from_mix_id,
+        });
+    }
+
+    // the redelegated amount has to satisfy the same requirements as a fresh delegation
+    let contract_state = mixnet_params_storage::CONTRACT_STATE.load(deps.storage)?;
+    let amount = validate_delegation_stake(
+        vec![amount],
+        contract_state.params.minimum_mixnode_delegation,
+        contract_state.rewarding_denom,
+    )?;
+
+    // see if the delegation even exists
+    let storage_key = Delegation::generate_storage_key(from_mix_id, &delegate, proxy.as_ref());
+    let delegation = match storage::delegations().may_load(deps.storage, storage_key)? {
+        Some(delegation) => delegation,
+        None => {
+            return Err(MixnetContractError::NoMixnodeDelegationFound {
+                mix_id: from_mix_id,
+                address: delegate.into_string(),
+                proxy: proxy.map(Addr::into_string),
+            })
+        }
+    };
+
+    // and whether it's worth enough (including all the rewards it has accumulated so far)
+    // to cover the requested amount
+    let mix_rewarding = rewards_storage::MIXNODE_REWARDING
+        .may_load(deps.storage, from_mix_id)?
+        .ok_or(MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
+        ))?;
+    let reward = mix_rewarding.determine_delegation_reward(&delegation)?;
+    let available = truncate_reward(reward + delegation.dec_amount()?, &delegation.amount.denom);
+    if amount.amount > available.amount {
+        return Err(MixnetContractError::RedelegationAmountExceedsDelegation {
+            mix_id: from_mix_id,
+            requested: amount,
+            available,
+        });
+    }
+
+    // check if the target node actually exists and is still bonded
+    match mixnodes_storage::mixnode_bonds().may_load(deps.storage, to_mix_id)? {
+        None => return Err(MixnetContractError::MixNodeBondNotFound { mix_id: to_mix_id }),
+        Some(bond) if bond.is_unbonding => {
+            return Err(MixnetContractError::MixnodeIsUnbonding { mix_id: to_mix_id })
+        }
+        _ => (),
+    }
+
+    // push the event onto the queue and wait for it to be picked up at the end of the epoch
+    let cosmos_event =
+        new_pending_redelegation_event(&delegate, &proxy, &amount, from_mix_id, to_mix_id);
+
+    let epoch_event = PendingEpochEventKind::Redelegate {
+        owner: delegate,
+        from_mix_id,
+        to_mix_id,
+        amount,
+        proxy,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+pub(crate) fn try_set_delegation_auto_compounding(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+) -> Result<Response, MixnetContractError> {
+    _try_set_delegation_auto_compounding(deps, env, mix_id, enabled, info.sender, None)
+}
+
+pub(crate) fn try_set_delegation_auto_compounding_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_set_delegation_auto_compounding(deps, env, mix_id, enabled, owner, Some(info.sender))
+}
+
+pub(crate) fn _try_set_delegation_auto_compounding(
+    deps: DepsMut<'_>,
+    env: Env,
+    mix_id: MixId,
+    enabled: bool,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // see if the delegation even exists
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
+    if storage::delegations()
+        .may_load(deps.storage, storage_key.clone())?
+        .is_none()
+    {
+        return Err(MixnetContractError::NoMixnodeDelegationFound {
+            mix_id,
+            address: owner.into_string(),
+            proxy: proxy.map(Addr::into_string),
+        });
+    }
+
+    // the change is applied immediately, so it's going to be honoured at the end of the current epoch
+    if enabled {
+        storage::AUTO_COMPOUNDING_DELEGATIONS.save(deps.storage, storage_key, &env.block.height)?;
+    } else {
+        storage::AUTO_COMPOUNDING_DELEGATIONS.remove(deps.storage, storage_key);
+    }
+
+    Ok(
+        Response::new().add_event(new_delegation_auto_compounding_update_event(
+            &owner, &proxy, mix_id, enabled,
+        )),
+    )
+}
+
+pub(crate) fn try_delegate_to_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+) -> Result<Response, MixnetContractError> {
+    _try_delegate_to_gateway(deps, env, identity, info.sender, info.funds, None)
+}
+
+pub(crate) fn try_delegate_to_gateway_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let delegate = deps.api.addr_validate(&delegate)?;
+    _try_delegate_to_gateway(deps, env, identity, delegate, info.funds, Some(info.sender))
+}
+
+pub(crate) fn _try_delegate_to_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    identity: IdentityKey,
+    delegate: Addr,
+    amount: Vec<Coin>,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // delegation is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    // gateway delegations are subject to the same minimum as the mixnode ones
+    let contract_state = mixnet_params_storage::CONTRACT_STATE.load(deps.storage)?;
+    let delegation = validate_delegation_stake(
+        amount,
+        contract_state.params.minimum_mixnode_delegation,
+        contract_state.rewarding_denom,
+    )?;
+
+    // check if the target gateway actually exists
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .is_none()
+    {
+        return Err(MixnetContractError::GatewayBondNotFound { identity });
+    }
+
+    // an address can only have a single delegation towards a gateway, so make sure
+    // we're not attempting to mix liquid and vesting tokens
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &delegate);
+    if let Some(existing) = storage::gateway_delegations().may_load(deps.storage, storage_key)? {
+        ensure_proxy_match(&proxy, &existing.proxy)?;
+    }
+
+    // push the event onto the queue and wait for it to be picked up at the end of the epoch
+    let cosmos_event =
+        new_pending_gateway_delegation_event(&delegate, &proxy, &delegation, &identity);
+
+    let epoch_event = PendingEpochEventKind::DelegateToGateway {
+        owner: delegate,
+        identity,
+        amount: delegation,
+        proxy,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+pub(crate) fn try_remove_delegation_from_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+) -> Result<Response, MixnetContractError> {
+    _try_remove_delegation_from_gateway(deps, env, identity, info.sender, None)
+}
+
+pub(crate) fn try_remove_delegation_from_gateway_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let delegate = deps.api.addr_validate(&delegate)?;
+    _try_remove_delegation_from_gateway(deps, env, identity, delegate, Some(info.sender))
+}
+
+pub(crate) fn _try_remove_delegation_from_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    identity: IdentityKey,
+    delegate: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // undelegation is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    // see if the delegation even exists
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &delegate);
+    match storage::gateway_delegations().may_load(deps.storage, storage_key)? {
+        None => {
+            return Err(MixnetContractError::NoGatewayDelegationFound {
+                identity,
+                address: delegate.into_string(),
+            });
+        }
+        Some(existing) => ensure_proxy_match(&proxy, &existing.proxy)?,
+    }
+
+    // push the event onto the queue and wait for it to be picked up at the end of the epoch
+    let cosmos_event = new_pending_gateway_undelegation_event(&delegate, &proxy, &identity);
+
+    let epoch_event = PendingEpochEventKind::UndelegateFromGateway {
+        owner: delegate,
+        identity,
+        proxy,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -609,4 +917,769 @@ mod tests {
             )
         }
     }
+
+    #[cfg(test)]
+    mod redelegating_from_mixnode {
+        use super::*;
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::{performance, TestSetup};
+        use cosmwasm_std::testing::mock_info;
+        use cosmwasm_std::{coin, Uint128};
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+        use mixnet_contract_common::{EpochState, EpochStatus};
+
+        #[test]
+        fn cant_be_performed_if_epoch_transition_is_in_progress() {
+            let bad_states = vec![
+                EpochState::Rewarding {
+                    last_rewarded: 0,
+                    final_node_id: 0,
+                },
+                EpochState::ReconcilingEvents,
+                EpochState::AdvancingEpoch,
+            ];
+
+            for bad_state in bad_states {
+                let mut test = TestSetup::new();
+                let owner = "delegator";
+                let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+                let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+                test.add_immediate_delegation(owner, 100_000_000u32, from_mix_id);
+
+                let mut status = EpochStatus::new(test.rewarding_validator().sender);
+                status.state = bad_state;
+                interval_storage::save_current_epoch_status(test.deps_mut().storage, &status)
+                    .unwrap();
+
+                let env = test.env();
+                let res = try_redelegate_from_mixnode(
+                    test.deps_mut(),
+                    env,
+                    mock_info(owner, &[]),
+                    from_mix_id,
+                    to_mix_id,
+                    coin(50_000_000, TEST_COIN_DENOM),
+                );
+                assert!(matches!(
+                    res,
+                    Err(MixnetContractError::EpochAdvancementInProgress { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn cant_be_performed_towards_the_same_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, mix_id);
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                mix_id,
+                mix_id,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationToSameMixnode { mix_id })
+            )
+        }
+
+        #[test]
+        fn requires_valid_amount() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from_mix_id);
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(0, TEST_COIN_DENOM),
+            );
+            assert_eq!(res, Err(MixnetContractError::EmptyDelegation));
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(50_000_000, "some-weird-denom"),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::WrongDenom {
+                    received: "some-weird-denom".to_string(),
+                    expected: TEST_COIN_DENOM.to_string()
+                })
+            );
+        }
+
+        #[test]
+        fn cannot_be_performed_if_delegation_never_existed() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id: from_mix_id,
+                    address: owner.to_string(),
+                    proxy: None
+                })
+            );
+
+            // the same is true if the delegation is still pending
+            try_delegate_to_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[coin(100_000_000, TEST_COIN_DENOM)]),
+                from_mix_id,
+            )
+            .unwrap();
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id: from_mix_id,
+                    address: owner.to_string(),
+                    proxy: None
+                })
+            );
+        }
+
+        #[test]
+        fn cannot_move_more_than_delegation_with_rewards() {
+            let mut test = TestSetup::new();
+            let owner = "delegator";
+            let from_mix_id =
+                test.add_dummy_mixnode("mix-owner1", Some(Uint128::new(100_000_000_000)));
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            let og_amount = Uint128::new(100_000_000);
+            test.add_immediate_delegation(owner, og_amount, from_mix_id);
+
+            test.skip_to_next_epoch_end();
+            test.force_change_rewarded_set(vec![from_mix_id]);
+            let dist =
+                test.reward_with_distribution_with_state_bypass(from_mix_id, performance(100.0));
+            let worth = og_amount + truncate_reward_amount(dist.delegates);
+            assert!(worth > og_amount);
+
+            let env = test.env();
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(worth.u128() + 1, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationAmountExceedsDelegation {
+                    mix_id: from_mix_id,
+                    requested: coin(worth.u128() + 1, TEST_COIN_DENOM),
+                    available: coin(worth.u128(), TEST_COIN_DENOM),
+                })
+            );
+
+            // but the earned rewards can be moved alongside the original delegation
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(worth.u128(), TEST_COIN_DENOM),
+            );
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn can_only_be_done_towards_fully_bonded_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner", None);
+            let mix_id_unbonding = test.add_dummy_mixnode("mix-owner-unbonding", None);
+            let mix_id_unbonded = test.add_dummy_mixnode("mix-owner-unbonded", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from_mix_id);
+
+            test.immediately_unbond_mixnode(mix_id_unbonded);
+            test.start_unbonding_mixnode(mix_id_unbonding);
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                42,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixNodeBondNotFound { mix_id: 42 })
+            );
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                mix_id_unbonding,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixnodeIsUnbonding {
+                    mix_id: mix_id_unbonding
+                })
+            );
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                mix_id_unbonded,
+                coin(50_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixNodeBondNotFound {
+                    mix_id: mix_id_unbonded
+                })
+            );
+        }
+
+        #[test]
+        fn can_be_done_away_from_unbonded_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from_mix_id);
+            test.immediately_unbond_mixnode(from_mix_id);
+
+            let res = try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(100_000_000, TEST_COIN_DENOM),
+            );
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn correctly_pushes_appropriate_epoch_event() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from_mix_id);
+            test.add_immediate_delegation_with_legal_proxy(owner, 100_000_000u32, from_mix_id);
+
+            let amount1 = coin(100_000_000, TEST_COIN_DENOM);
+            let amount2 = coin(50_000_000, TEST_COIN_DENOM);
+
+            try_redelegate_from_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+                amount1.clone(),
+            )
+            .unwrap();
+            let vesting_contract = test.vesting_contract();
+            try_redelegate_from_mixnode_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(vesting_contract.as_str(), &[]),
+                from_mix_id,
+                to_mix_id,
+                amount2.clone(),
+                owner.into(),
+            )
+            .unwrap();
+
+            let events = test.pending_epoch_events();
+
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id,
+                    to_mix_id,
+                    amount: amount1,
+                    proxy: None
+                }
+            );
+
+            assert_eq!(
+                events[1].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id,
+                    to_mix_id,
+                    amount: amount2,
+                    proxy: Some(test.vesting_contract())
+                }
+            );
+        }
+
+        #[test]
+        fn fails_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let illegal_proxy = Addr::unchecked("not-vesting-contract");
+            let vesting_contract = test.vesting_contract();
+
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation_with_illegal_proxy(
+                owner,
+                100_000_000u32,
+                from_mix_id,
+                illegal_proxy.clone(),
+            );
+
+            let res = try_redelegate_from_mixnode_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(illegal_proxy.as_ref(), &[]),
+                from_mix_id,
+                to_mix_id,
+                coin(50_000_000, TEST_COIN_DENOM),
+                owner.into(),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                res,
+                MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy,
+                    vesting_contract
+                }
+            )
+        }
+    }
+
+    #[cfg(test)]
+    mod setting_auto_compounding {
+        use super::*;
+        use crate::support::tests::test_helpers::TestSetup;
+        use cosmwasm_std::testing::mock_info;
+
+        fn is_auto_compounding(
+            test: &TestSetup,
+            mix_id: MixId,
+            owner: &str,
+            proxy: Option<&Addr>,
+        ) -> bool {
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(owner), proxy);
+            storage::AUTO_COMPOUNDING_DELEGATIONS.has(test.deps().storage, storage_key)
+        }
+
+        #[test]
+        fn can_only_be_done_for_an_existing_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+            let res = try_set_delegation_auto_compounding(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                mix_id,
+                true,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id,
+                    address: owner.to_string(),
+                    proxy: None,
+                })
+            );
+
+            // delegation made with the vesting contract is a different delegation
+            test.add_immediate_delegation_with_legal_proxy(owner, 100_000_000u32, mix_id);
+            let res = try_set_delegation_auto_compounding(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                mix_id,
+                true,
+            );
+            assert!(res.is_err());
+        }
+
+        #[test]
+        fn can_be_enabled_and_disabled() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, mix_id);
+            assert!(!is_auto_compounding(&test, mix_id, owner, None));
+
+            let res = try_set_delegation_auto_compounding(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                mix_id,
+                true,
+            )
+            .unwrap();
+            assert_eq!(
+                res,
+                Response::new().add_event(new_delegation_auto_compounding_update_event(
+                    &Addr::unchecked(owner),
+                    &None,
+                    mix_id,
+                    true
+                ))
+            );
+            assert!(is_auto_compounding(&test, mix_id, owner, None));
+
+            // enabling it again doesn't change anything
+            try_set_delegation_auto_compounding(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                mix_id,
+                true,
+            )
+            .unwrap();
+            assert!(is_auto_compounding(&test, mix_id, owner, None));
+
+            try_set_delegation_auto_compounding(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                mix_id,
+                false,
+            )
+            .unwrap();
+            assert!(!is_auto_compounding(&test, mix_id, owner, None));
+        }
+
+        #[test]
+        fn on_behalf_only_affects_the_proxied_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, mix_id);
+            test.add_immediate_delegation_with_legal_proxy(owner, 100_000_000u32, mix_id);
+
+            let vesting_contract = test.vesting_contract();
+            try_set_delegation_auto_compounding_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(vesting_contract.as_str(), &[]),
+                mix_id,
+                true,
+                owner.into(),
+            )
+            .unwrap();
+
+            assert!(is_auto_compounding(
+                &test,
+                mix_id,
+                owner,
+                Some(&vesting_contract)
+            ));
+            assert!(!is_auto_compounding(&test, mix_id, owner, None));
+        }
+
+        #[test]
+        fn fails_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let illegal_proxy = Addr::unchecked("not-vesting-contract");
+            let vesting_contract = test.vesting_contract();
+
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation_with_illegal_proxy(
+                owner,
+                100_000_000u32,
+                mix_id,
+                illegal_proxy.clone(),
+            );
+
+            let res = try_set_delegation_auto_compounding_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(illegal_proxy.as_ref(), &[]),
+                mix_id,
+                true,
+                owner.into(),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                res,
+                MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy,
+                    vesting_contract
+                }
+            )
+        }
+    }
+
+    #[cfg(test)]
+    mod gateway_delegations {
+        use super::*;
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::TestSetup;
+        use cosmwasm_std::coin;
+        use cosmwasm_std::testing::mock_info;
+
+        #[test]
+        fn delegating_requires_existing_gateway() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let sender = mock_info("delegator", &[coin(100_000_000, TEST_COIN_DENOM)]);
+
+            let res = try_delegate_to_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                "non-existent-gateway".to_string(),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::GatewayBondNotFound {
+                    identity: "non-existent-gateway".to_string()
+                })
+            );
+        }
+
+        #[test]
+        fn delegating_is_subject_to_minimum_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            let mut contract_state = mixnet_params_storage::CONTRACT_STATE
+                .load(test.deps().storage)
+                .unwrap();
+            contract_state.params.minimum_mixnode_delegation =
+                Some(coin(100_000_000, TEST_COIN_DENOM));
+            mixnet_params_storage::CONTRACT_STATE
+                .save(test.deps_mut().storage, &contract_state)
+                .unwrap();
+
+            let sender = mock_info("delegator", &[coin(1_000, TEST_COIN_DENOM)]);
+            let res = try_delegate_to_gateway(test.deps_mut(), env, sender, identity);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InsufficientDelegation { .. })
+            ));
+        }
+
+        #[test]
+        fn delegating_pushes_pending_event() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            let amount = coin(100_000_000, TEST_COIN_DENOM);
+            let sender = mock_info("delegator", &[amount.clone()]);
+            try_delegate_to_gateway(test.deps_mut(), env, sender, identity.clone()).unwrap();
+
+            let events = test.pending_epoch_events();
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::DelegateToGateway {
+                    owner: Addr::unchecked("delegator"),
+                    identity,
+                    amount,
+                    proxy: None,
+                }
+            );
+        }
+
+        #[test]
+        fn undelegating_requires_existing_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            let res = try_remove_delegation_from_gateway(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("delegator", &[]),
+                identity.clone(),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoGatewayDelegationFound {
+                    identity: identity.clone(),
+                    address: "delegator".to_string(),
+                })
+            );
+
+            test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+            try_remove_delegation_from_gateway(
+                test.deps_mut(),
+                env,
+                mock_info("delegator", &[]),
+                identity.clone(),
+            )
+            .unwrap();
+
+            let events = test.pending_epoch_events();
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::UndelegateFromGateway {
+                    owner: Addr::unchecked("delegator"),
+                    identity,
+                    proxy: None,
+                }
+            );
+        }
+
+        #[test]
+        fn on_behalf_fails_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            let illegal_proxy = Addr::unchecked("not-vesting-contract");
+            let vesting_contract = test.vesting_contract();
+
+            let res = try_delegate_to_gateway_on_behalf(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(
+                    illegal_proxy.as_ref(),
+                    &[coin(100_000_000, TEST_COIN_DENOM)],
+                ),
+                identity.clone(),
+                "delegator".into(),
+            )
+            .unwrap_err();
+            assert_eq!(
+                res,
+                MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy.clone(),
+                    vesting_contract: vesting_contract.clone(),
+                }
+            );
+
+            let res = try_remove_delegation_from_gateway_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(illegal_proxy.as_ref(), &[]),
+                identity,
+                "delegator".into(),
+            )
+            .unwrap_err();
+            assert_eq!(
+                res,
+                MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy,
+                    vesting_contract
+                }
+            );
+        }
+
+        #[test]
+        fn liquid_and_vesting_delegations_cannot_be_mixed() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation_with_legal_proxy(
+                "delegator",
+                100_000_000u32,
+                &identity,
+            );
+            let vesting_contract = test.vesting_contract();
+
+            let sender = mock_info("delegator", &[coin(100_000_000, TEST_COIN_DENOM)]);
+            let res =
+                try_delegate_to_gateway(test.deps_mut(), env.clone(), sender, identity.clone());
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::ProxyMismatch { .. })
+            ));
+
+            let res = try_remove_delegation_from_gateway(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("delegator", &[]),
+                identity.clone(),
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::ProxyMismatch { .. })
+            ));
+
+            let amount = coin(100_000_000, TEST_COIN_DENOM);
+            try_delegate_to_gateway_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(vesting_contract.as_str(), &[amount.clone()]),
+                identity.clone(),
+                "delegator".into(),
+            )
+            .unwrap();
+
+            let events = test.pending_epoch_events();
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::DelegateToGateway {
+                    owner: Addr::unchecked("delegator"),
+                    identity,
+                    amount,
+                    proxy: Some(vesting_contract),
+                }
+            );
+        }
+    }
 }
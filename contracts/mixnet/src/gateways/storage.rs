@@ -1,11 +1,16 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::constants::{GATEWAYS_OWNER_IDX_NAMESPACE, GATEWAYS_PK_NAMESPACE};
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Index, IndexList, IndexedMap, UniqueIndex};
+use crate::constants::{
+    BONDED_GATEWAYS_COUNT_KEY, GATEWAYS_OWNER_IDX_NAMESPACE, GATEWAYS_PK_NAMESPACE,
+};
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, UniqueIndex};
 use mixnet_contract_common::{GatewayBond, IdentityKeyRef};
 
+// number of currently bonded gateways, used for splitting the gateway reward pool
+pub(crate) const BONDED_GATEWAYS_COUNT: Item<'_, u32> = Item::new(BONDED_GATEWAYS_COUNT_KEY);
+
 pub(crate) struct GatewayBondIndex<'a> {
     pub(crate) owner: UniqueIndex<'a, Addr, GatewayBond>,
 }
@@ -27,3 +32,17 @@ pub(crate) fn gateways<'a>() -> IndexedMap<'a, IdentityKeyRef<'a>, GatewayBond,
     };
     IndexedMap::new(GATEWAYS_PK_NAMESPACE, indexes)
 }
+
+pub(crate) fn bonded_gateways_count(storage: &dyn Storage) -> StdResult<u32> {
+    Ok(BONDED_GATEWAYS_COUNT.may_load(storage)?.unwrap_or_default())
+}
+
+pub(crate) fn increment_bonded_gateways_count(storage: &mut dyn Storage) -> StdResult<()> {
+    let count = bonded_gateways_count(storage)?;
+    BONDED_GATEWAYS_COUNT.save(storage, &(count + 1))
+}
+
+pub(crate) fn decrement_bonded_gateways_count(storage: &mut dyn Storage) -> StdResult<()> {
+    let count = bonded_gateways_count(storage)?;
+    BONDED_GATEWAYS_COUNT.save(storage, &count.saturating_sub(1))
+}
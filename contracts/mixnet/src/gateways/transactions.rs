@@ -4,10 +4,13 @@
 use super::helpers::must_get_gateway_bond_by_owner;
 use super::storage;
 use crate::gateways::signature_helpers::verify_gateway_bonding_signature;
+use crate::interval::storage as interval_storage;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::rewards::storage as rewards_storage;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
-    ensure_no_existing_bond, ensure_proxy_match, ensure_sent_by_vesting_contract, validate_pledge,
+    ensure_no_existing_bond, ensure_no_pending_gateway_slashing, ensure_proxy_match,
+    ensure_sent_by_vesting_contract, validate_pledge,
 };
 use cosmwasm_std::{wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response};
 use mixnet_contract_common::error::MixnetContractError;
@@ -15,7 +18,7 @@ use mixnet_contract_common::events::{
     new_gateway_bonding_event, new_gateway_config_update_event, new_gateway_unbonding_event,
 };
 use mixnet_contract_common::gateway::GatewayConfigUpdate;
-use mixnet_contract_common::{Gateway, GatewayBond};
+use mixnet_contract_common::{Gateway, GatewayBond, GatewayRewarding};
 use nym_contracts_common::signing::MessageSignature;
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
@@ -111,7 +114,23 @@ pub(crate) fn _try_add_gateway(
         proxy.clone(),
     );
 
+    // if the gateway has been bonded before, there might still exist rewarding information
+    // kept around for the delegators that haven't yet undelegated
+    let current_epoch =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let gateway_rewarding =
+        match rewards_storage::GATEWAY_REWARDING.may_load(deps.storage, bond.identity())? {
+            Some(mut existing) => {
+                existing.increase_operator_uint128(pledge.amount)?;
+                existing.last_rewarded_epoch = current_epoch;
+                existing
+            }
+            None => GatewayRewarding::initialise_new(&pledge, current_epoch)?,
+        };
+
     storage::gateways().save(deps.storage, bond.identity(), &bond)?;
+    storage::increment_bonded_gateways_count(deps.storage)?;
+    rewards_storage::GATEWAY_REWARDING.save(deps.storage, bond.identity(), &gateway_rewarding)?;
 
     Ok(Response::new().add_event(new_gateway_bonding_event(
         &owner,
@@ -164,21 +183,51 @@ pub(crate) fn _try_remove_gateway(
         });
     }
 
+    // the gateway can't escape the slashing by unbonding before it's applied
+    ensure_no_pending_gateway_slashing(deps.storage, gateway_bond.identity())?;
+
+    // alongside the original pledge, the operator also gets back any rewards it has accumulated
+    let returned_amount = match rewards_storage::GATEWAY_REWARDING
+        .may_load(deps.storage, gateway_bond.identity())?
+    {
+        Some(mut gateway_rewarding) => {
+            let returned_amount =
+                gateway_rewarding.remove_operator_stake(&gateway_bond.pledge_amount.denom);
+
+            // keep the rewarding information around if there are still delegations
+            // so that the delegators could claim their tokens back
+            if gateway_rewarding.unique_delegations == 0 {
+                rewards_storage::GATEWAY_REWARDING.remove(deps.storage, gateway_bond.identity());
+            } else {
+                rewards_storage::GATEWAY_REWARDING.save(
+                    deps.storage,
+                    gateway_bond.identity(),
+                    &gateway_rewarding,
+                )?;
+            }
+            returned_amount
+        }
+        // this would only happen for gateways bonded before the rewarding got introduced
+        // and that didn't get migrated
+        None => gateway_bond.pledge_amount(),
+    };
+
     // send bonded funds back to the bond owner
     let return_tokens = BankMsg::Send {
         to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
-        amount: vec![gateway_bond.pledge_amount()],
+        amount: vec![returned_amount.clone()],
     };
 
     // remove the bond
     storage::gateways().remove(deps.storage, gateway_bond.identity())?;
+    storage::decrement_bonded_gateways_count(deps.storage)?;
 
     let mut response = Response::new().add_message(return_tokens);
 
     if let Some(proxy) = &proxy {
         let msg = VestingContractExecuteMsg::TrackUnbondGateway {
             owner: owner.as_str().to_string(),
-            amount: gateway_bond.pledge_amount(),
+            amount: returned_amount.clone(),
         };
 
         let track_unbond_message = wasm_execute(proxy, &msg, vec![])?;
@@ -188,7 +237,7 @@ pub(crate) fn _try_remove_gateway(
     Ok(response.add_event(new_gateway_unbonding_event(
         &owner,
         &proxy,
-        &gateway_bond.pledge_amount,
+        &returned_amount,
         gateway_bond.identity(),
     )))
 }
@@ -256,12 +305,13 @@ pub mod tests {
     };
     use crate::interval::pending_events;
     use crate::mixnet_contract_settings::storage::minimum_gateway_pledge;
+    use crate::rewards::storage as rewards_storage;
     use crate::support::tests;
     use crate::support::tests::fixtures;
     use crate::support::tests::fixtures::{good_gateway_pledge, good_mixnode_pledge};
     use crate::support::tests::test_helpers::TestSetup;
     use cosmwasm_std::testing::mock_info;
-    use cosmwasm_std::{Addr, BankMsg, Response, Uint128};
+    use cosmwasm_std::{Addr, BankMsg, Decimal, Response, Uint128};
     use mixnet_contract_common::error::MixnetContractError;
     use mixnet_contract_common::events::new_gateway_unbonding_event;
     use mixnet_contract_common::gateway::GatewayConfigUpdate;
@@ -517,6 +567,52 @@ pub mod tests {
         assert_eq!(&Addr::unchecked("bob"), nodes[0].owner());
     }
 
+    #[test]
+    fn gateway_remove_returns_accumulated_rewards() {
+        let mut test = TestSetup::new();
+        let env = test.env();
+        let identity = test.add_dummy_gateway("fred", None);
+        let pledge = tests::fixtures::good_gateway_pledge()[0].clone();
+
+        // bonding creates the rewarding information
+        let mut gateway_rewarding = test.gateway_rewarding(&identity);
+        assert_eq!(
+            gateway_rewarding.operator,
+            Decimal::from_atomics(pledge.amount, 0).unwrap()
+        );
+
+        gateway_rewarding.operator += Decimal::from_atomics(1234u32, 0).unwrap();
+        rewards_storage::GATEWAY_REWARDING
+            .save(test.deps_mut().storage, &identity, &gateway_rewarding)
+            .unwrap();
+
+        let info = mock_info("fred", &[]);
+        let res = execute(test.deps_mut(), env, info, ExecuteMsg::UnbondGateway {}).unwrap();
+        let (_, sent) = tests::test_helpers::get_bank_send_msg(&res).unwrap();
+        assert_eq!(sent[0].amount, pledge.amount + Uint128::new(1234));
+
+        // there were no delegations so nothing is kept around
+        assert!(rewards_storage::GATEWAY_REWARDING
+            .may_load(test.deps().storage, &identity)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn gateway_rewarding_is_kept_for_remaining_delegations() {
+        let mut test = TestSetup::new();
+        let env = test.env();
+        let identity = test.add_dummy_gateway("fred", None);
+        test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+
+        let info = mock_info("fred", &[]);
+        execute(test.deps_mut(), env, info, ExecuteMsg::UnbondGateway {}).unwrap();
+
+        let gateway_rewarding = test.gateway_rewarding(&identity);
+        assert!(!gateway_rewarding.still_bonded());
+        assert_eq!(gateway_rewarding.unique_delegations, 1);
+    }
+
     #[test]
     fn gateway_remove_with_illegal_proxy() {
         let mut test = TestSetup::new();
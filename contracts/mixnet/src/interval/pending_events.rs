@@ -1,29 +1,40 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{Addr, Coin, DepsMut, Env, Response};
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, Response, Storage};
 
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_active_set_update_event, new_delegation_event, new_delegation_on_unbonded_node_event,
-    new_mixnode_cost_params_update_event, new_mixnode_unbonding_event, new_pledge_decrease_event,
-    new_pledge_increase_event, new_rewarding_params_update_event, new_undelegation_event,
+    new_gateway_delegation_event, new_gateway_delegation_on_unbonded_node_event,
+    new_gateway_undelegation_event, new_mixnode_cost_params_update_event,
+    new_mixnode_unbonding_event, new_pledge_decrease_event, new_pledge_increase_event,
+    new_redelegation_event, new_redelegation_to_unbonded_node_event,
+    new_rewarding_params_update_event, new_undelegation_event,
 };
-use mixnet_contract_common::mixnode::MixNodeCostParams;
+use mixnet_contract_common::helpers::IntoBaseDecimal;
+use mixnet_contract_common::mixnode::{MixNodeCostParams, MixNodeDetails};
 use mixnet_contract_common::pending_events::{
     PendingEpochEventData, PendingEpochEventKind, PendingIntervalEventData,
     PendingIntervalEventKind,
 };
 use mixnet_contract_common::reward_params::IntervalRewardingParamsUpdate;
-use mixnet_contract_common::{BlockHeight, Delegation, MixId};
+use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+use mixnet_contract_common::{
+    BlockHeight, Delegation, GatewayDelegation, IdentityKey, MixId, SlashingId, SlashingRecord,
+};
 
 use crate::delegations;
 use crate::delegations::storage as delegations_storage;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::helpers::change_interval_config;
 use crate::interval::storage;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::helpers::{cleanup_post_unbond_mixnode_storage, get_mixnode_details_by_id};
 use crate::mixnodes::storage as mixnodes_storage;
 use crate::rewards::storage as rewards_storage;
+use crate::slashing::models::SlashingProgress;
+use crate::slashing::storage as slashing_storage;
 use crate::support::helpers::{send_to_proxy_or_owner, VestingTracking};
 
 pub(crate) trait ContractExecutableEvent {
@@ -171,6 +182,172 @@ pub(crate) fn undelegate(
     Ok(response)
 }
 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn redelegate(
+    deps: DepsMut<'_>,
+    env: &Env,
+    created_at: BlockHeight,
+    owner: Addr,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // see if the delegation still exists (the user might have decided to undelegate it
+    // or redelegate it elsewhere within the same epoch)
+    let source_key = Delegation::generate_storage_key(from_mix_id, &owner, proxy.as_ref());
+    let source_delegation =
+        match delegations_storage::delegations().may_load(deps.storage, source_key.clone())? {
+            None => return Ok(Response::default()),
+            Some(delegation) => delegation,
+        };
+
+    // check if the target node still exists (it might have unbonded between this event getting created
+    // and being executed). If it doesn't, the tokens simply stay delegated to the original node.
+    let target_details = match get_mixnode_details_by_id(deps.storage, to_mix_id)? {
+        Some(details)
+            if details.rewarding_details.still_bonded()
+                && !details.bond_information.is_unbonding =>
+        {
+            details
+        }
+        _ => {
+            return Ok(
+                Response::new().add_event(new_redelegation_to_unbonded_node_event(
+                    created_at,
+                    &owner,
+                    &proxy,
+                    from_mix_id,
+                    to_mix_id,
+                )),
+            )
+        }
+    };
+
+    let mut source_rewarding =
+        rewards_storage::MIXNODE_REWARDING.may_load(deps.storage, from_mix_id)?.ok_or(MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
+        ))?;
+
+    // completely remove the delegation from the source node, alongside all of its rewards
+    let source_with_reward = source_rewarding.undelegate(&source_delegation)?;
+
+    // the delegation might be worth less than requested by now (for example if another redelegation
+    // from the same node got executed earlier in this epoch), so make sure we never move more than what's there
+    let redelegated = Coin {
+        denom: amount.denom,
+        amount: amount.amount.min(source_with_reward.amount),
+    };
+    let remaining = source_with_reward.amount - redelegated.amount;
+
+    // the redelegated tokens keep being auto-compounded if that was enabled for the source delegation
+    let source_auto_compounding = delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+        .may_load(deps.storage, source_key.clone())?;
+
+    // whatever is left (if anything) becomes a fresh delegation on the source node
+    if remaining.is_zero() {
+        delegations_storage::delegations().replace(
+            deps.storage,
+            source_key.clone(),
+            None,
+            Some(&source_delegation),
+        )?;
+        delegations_storage::AUTO_COMPOUNDING_DELEGATIONS.remove(deps.storage, source_key);
+    } else {
+        source_rewarding.add_base_delegation(remaining)?;
+        let updated_source = Delegation::new(
+            owner.clone(),
+            from_mix_id,
+            source_rewarding.total_unit_reward,
+            Coin {
+                denom: redelegated.denom.clone(),
+                amount: remaining,
+            },
+            env.block.height,
+            proxy.clone(),
+        );
+        delegations_storage::delegations().replace(
+            deps.storage,
+            source_key,
+            Some(&updated_source),
+            Some(&source_delegation),
+        )?;
+    }
+    rewards_storage::MIXNODE_REWARDING.save(deps.storage, from_mix_id, &source_rewarding)?;
+
+    // and add the redelegated tokens to the target node, exactly as we'd do with a normal delegation
+    // (if there's an existing delegation, we withdraw its full reward and create a new one with the sum of both)
+    let mut target_rewarding = target_details.rewarding_details;
+    let mut stored_delegation_amount = redelegated.amount;
+
+    let target_key = Delegation::generate_storage_key(to_mix_id, &owner, proxy.as_ref());
+    let old_target_delegation = if let Some(existing_delegation) =
+        delegations_storage::delegations().may_load(deps.storage, target_key.clone())?
+    {
+        let og_with_reward = target_rewarding.undelegate(&existing_delegation)?;
+        stored_delegation_amount += og_with_reward.amount;
+        Some(existing_delegation)
+    } else {
+        None
+    };
+
+    target_rewarding.add_base_delegation(stored_delegation_amount)?;
+
+    let cosmos_event = new_redelegation_event(
+        created_at,
+        &owner,
+        &proxy,
+        &redelegated,
+        from_mix_id,
+        to_mix_id,
+        target_rewarding.total_unit_reward,
+    );
+
+    let target_delegation = Delegation::new(
+        owner.clone(),
+        to_mix_id,
+        target_rewarding.total_unit_reward,
+        Coin {
+            denom: redelegated.denom.clone(),
+            amount: stored_delegation_amount,
+        },
+        env.block.height,
+        proxy.clone(),
+    );
+
+    delegations_storage::delegations().replace(
+        deps.storage,
+        target_key.clone(),
+        Some(&target_delegation),
+        old_target_delegation.as_ref(),
+    )?;
+    rewards_storage::MIXNODE_REWARDING.save(deps.storage, to_mix_id, &target_rewarding)?;
+
+    if let Some(enabled_at) = source_auto_compounding {
+        if !delegations_storage::AUTO_COMPOUNDING_DELEGATIONS.has(deps.storage, target_key.clone())
+        {
+            delegations_storage::AUTO_COMPOUNDING_DELEGATIONS.save(
+                deps.storage,
+                target_key,
+                &enabled_at,
+            )?;
+        }
+    }
+
+    let response = Response::new()
+        .add_event(cosmos_event)
+        .maybe_add_track_vesting_redelegation_message(
+            deps.storage,
+            proxy,
+            owner.into_string(),
+            from_mix_id,
+            to_mix_id,
+            redelegated,
+        )?;
+
+    Ok(response)
+}
+
 pub(crate) fn unbond_mixnode(
     deps: DepsMut<'_>,
     env: &Env,
@@ -248,7 +425,7 @@ pub(crate) fn increase_pledge(
 
     // the target node MUST exist - we have checked it at the time of putting this event onto the queue
     // we have also verified there were no preceding unbond events
-    let mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
+    let mut mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
         MixnetContractError::inconsistent_state(
             "mixnode getting processed to increase its pledge doesn't exist in the storage",
         ),
@@ -258,6 +435,11 @@ pub(crate) fn increase_pledge(
             "attempted to increase mixnode pledge while there are no associated pending changes",
         ));
     }
+    if apply_pending_slashing_before_pledge_change(deps.storage, created_at, &mix_details)? {
+        mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
+            MixnetContractError::inconsistent_state("slashed mixnode got removed from the storage"),
+        )?;
+    }
 
     let mut updated_bond = mix_details.bond_information.clone();
     let mut updated_rewarding = mix_details.rewarding_details;
@@ -285,11 +467,11 @@ pub(crate) fn decrease_pledge(
     deps: DepsMut<'_>,
     created_at: BlockHeight,
     mix_id: MixId,
-    decrease_by: Coin,
+    mut decrease_by: Coin,
 ) -> Result<Response, MixnetContractError> {
     // the target node MUST exist - we have checked it at the time of putting this event onto the queue
     // we have also verified there were no preceding unbond events
-    let mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
+    let mut mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
         MixnetContractError::inconsistent_state(
             "mixnode getting processed to increase its pledge doesn't exist in the storage",
         ),
@@ -299,6 +481,21 @@ pub(crate) fn decrease_pledge(
             "attempted to decrease mixnode pledge while there are no associated pending changes",
         ));
     }
+    if apply_pending_slashing_before_pledge_change(deps.storage, created_at, &mix_details)? {
+        mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
+            MixnetContractError::inconsistent_state("slashed mixnode got removed from the storage"),
+        )?;
+
+        // the decrease got validated against the pledge from before the slashing,
+        // so make sure the remaining pledge still doesn't go below the minimum
+        let minimum_pledge = mixnet_params_storage::minimum_mixnode_pledge(deps.storage)?;
+        let max_decrease = mix_details
+            .bond_information
+            .original_pledge
+            .amount
+            .saturating_sub(minimum_pledge.amount);
+        decrease_by.amount = decrease_by.amount.min(max_decrease);
+    }
 
     let mut updated_bond = mix_details.bond_information.clone();
     let mut updated_rewarding = mix_details.rewarding_details;
@@ -308,15 +505,13 @@ pub(crate) fn decrease_pledge(
 
     // SAFETY: the subtraction here can't overflow as before the event was pushed into the queue,
     // we checked that the new value will be higher than minimum pledge (which is also strictly positive)
+    // (and if the node got slashed in the meantime, the decrease got capped above)
     updated_bond.original_pledge.amount -= decrease_by.amount;
     updated_rewarding.decrease_operator_uint128(decrease_by.amount)?;
 
     let proxy = &mix_details.bond_information.proxy;
     let owner = &mix_details.bond_information.owner;
 
-    // send the removed tokens back to the operator
-    let return_tokens = send_to_proxy_or_owner(proxy, owner, vec![decrease_by.clone()]);
-
     // update all: bond information, rewarding details and pending pledge changes
     mixnodes_storage::mixnode_bonds().replace(
         deps.storage,
@@ -327,9 +522,19 @@ pub(crate) fn decrease_pledge(
     rewards_storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &updated_rewarding)?;
     mixnodes_storage::PENDING_MIXNODE_CHANGES.save(deps.storage, mix_id, &pending_changes)?;
 
-    let response = Response::new()
+    let response =
+        Response::new().add_event(new_pledge_decrease_event(created_at, mix_id, &decrease_by));
+
+    // the slashing might have left no room for any decrease
+    if decrease_by.amount.is_zero() {
+        return Ok(response);
+    }
+
+    // send the removed tokens back to the operator
+    let return_tokens = send_to_proxy_or_owner(proxy, owner, vec![decrease_by.clone()]);
+
+    let response = response
         .add_message(return_tokens)
-        .add_event(new_pledge_decrease_event(created_at, mix_id, &decrease_by))
         .maybe_add_track_vesting_decrease_mixnode_pledge(
             deps.storage,
             proxy.clone(),
@@ -340,6 +545,293 @@ pub(crate) fn decrease_pledge(
     Ok(response)
 }
 
+pub(crate) fn delegate_to_gateway(
+    deps: DepsMut<'_>,
+    env: &Env,
+    created_at: BlockHeight,
+    owner: Addr,
+    identity: IdentityKey,
+    amount: Coin,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // check if the target gateway is still bonded (it might have unbonded between this event
+    // getting created and being executed). If it isn't, return the tokens back to the delegator.
+    let mut gateway_rewarding =
+        match rewards_storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)? {
+            Some(gateway_rewarding) if gateway_rewarding.still_bonded() => gateway_rewarding,
+            _ => {
+                // (read the notes regarding possible epoch progressiong halting behaviour in `maybe_add_track_undelegation_message`)
+                let return_tokens = send_to_proxy_or_owner(&proxy, &owner, vec![amount.clone()]);
+                let response = Response::new()
+                    .add_message(return_tokens)
+                    .add_event(new_gateway_delegation_on_unbonded_node_event(
+                        &owner, &proxy, &identity,
+                    ))
+                    .maybe_add_track_vesting_gateway_undelegation_message(
+                        deps.storage,
+                        proxy,
+                        owner.to_string(),
+                        identity,
+                        amount,
+                    )?;
+                return Ok(response);
+            }
+        };
+
+    let new_delegation_amount = amount.clone();
+
+    // if there's an existing delegation, then withdraw the full reward and create a new delegation
+    // with the sum of both
+    let mut stored_delegation_amount = amount;
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &owner);
+    let old_delegation = if let Some(existing_delegation) =
+        delegations_storage::gateway_delegations().may_load(deps.storage, storage_key.clone())?
+    {
+        // the delegation got created with a different proxy after this event has been pushed
+        // onto the queue, so we can't merge them. return the tokens back to the delegator instead
+        if existing_delegation.proxy != proxy {
+            let return_tokens =
+                send_to_proxy_or_owner(&proxy, &owner, vec![new_delegation_amount.clone()]);
+            let response = Response::new()
+                .add_message(return_tokens)
+                .maybe_add_track_vesting_gateway_undelegation_message(
+                    deps.storage,
+                    proxy,
+                    owner.to_string(),
+                    identity,
+                    new_delegation_amount,
+                )?;
+            return Ok(response);
+        }
+
+        let og_with_reward = gateway_rewarding.undelegate(&existing_delegation)?;
+        stored_delegation_amount.amount += og_with_reward.amount;
+
+        Some(existing_delegation)
+    } else {
+        None
+    };
+
+    gateway_rewarding.add_base_delegation(stored_delegation_amount.amount)?;
+
+    let cosmos_event = new_gateway_delegation_event(
+        created_at,
+        &owner,
+        &proxy,
+        &new_delegation_amount,
+        &identity,
+        gateway_rewarding.total_unit_reward,
+    );
+
+    let delegation = GatewayDelegation::new(
+        owner,
+        identity.clone(),
+        gateway_rewarding.total_unit_reward,
+        stored_delegation_amount,
+        env.block.height,
+        proxy,
+    );
+
+    delegations_storage::gateway_delegations().replace(
+        deps.storage,
+        storage_key,
+        Some(&delegation),
+        old_delegation.as_ref(),
+    )?;
+    rewards_storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+pub(crate) fn undelegate_from_gateway(
+    deps: DepsMut<'_>,
+    created_at: BlockHeight,
+    owner: Addr,
+    identity: IdentityKey,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // see if the delegation still exists (in case of impatient user who decided to send multiple
+    // undelegation requests in an epoch)
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &owner);
+    let delegation =
+        match delegations_storage::gateway_delegations().may_load(deps.storage, storage_key)? {
+            Some(delegation) if delegation.proxy == proxy => delegation,
+            _ => return Ok(Response::default()),
+        };
+    let gateway_rewarding =
+        rewards_storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)?.ok_or(MixnetContractError::inconsistent_state(
+            "gateway rewarding got removed from the storage whilst there's still an existing delegation",
+        ))?;
+
+    // this also appropriately adjusts the storage
+    let tokens_to_return =
+        delegations::helpers::undelegate_from_gateway(deps.storage, delegation, gateway_rewarding)?;
+
+    // (read the notes regarding possible epoch progressiong halting behaviour in `maybe_add_track_undelegation_message`)
+    let return_tokens = send_to_proxy_or_owner(&proxy, &owner, vec![tokens_to_return.clone()]);
+    let response = Response::new()
+        .add_message(return_tokens)
+        .add_event(new_gateway_undelegation_event(
+            created_at, &owner, &proxy, &identity,
+        ))
+        .maybe_add_track_vesting_gateway_undelegation_message(
+            deps.storage,
+            proxy,
+            owner.to_string(),
+            identity,
+            tokens_to_return,
+        )?;
+
+    Ok(response)
+}
+
+// starts the slashing of the mixnode by reducing the operator's stake and its pledge.
+// the delegations are processed afterwards, in batches (see `slashing::helpers::continue_slashing`)
+fn start_mixnode_slashing(
+    store: &mut dyn Storage,
+    created_at: BlockHeight,
+    mix_details: &MixNodeDetails,
+    record: &SlashingRecord,
+) -> Result<(), MixnetContractError> {
+    if mix_details.pending_changes.slashing != Some(record.id) {
+        return Err(MixnetContractError::inconsistent_state(
+            "attempted to slash mixnode while there are no associated pending changes",
+        ));
+    }
+    let slash_percent = record.slash_percent;
+    let mix_id = mix_details.mix_id();
+
+    let mut updated_bond = mix_details.bond_information.clone();
+    let mut updated_rewarding = mix_details.rewarding_details.clone();
+
+    // the pledge gets reduced proportionally to the operator's stake. however, we must also ensure
+    // the invariant of the operator's stake never being smaller than its pledge is preserved
+    // (which could have been broken due to rounding)
+    let operator_slashed = updated_rewarding.slash_operator(slash_percent);
+    let pledge = updated_bond.original_pledge.amount.into_base_decimal()?;
+    let slashed_pledge = truncate_reward_amount(pledge - slash_percent * pledge);
+    updated_bond.original_pledge.amount =
+        slashed_pledge.min(truncate_reward_amount(updated_rewarding.operator));
+
+    mixnodes_storage::mixnode_bonds().replace(
+        store,
+        mix_id,
+        Some(&updated_bond),
+        Some(&mix_details.bond_information),
+    )?;
+    rewards_storage::MIXNODE_REWARDING.save(store, mix_id, &updated_rewarding)?;
+
+    let progress = SlashingProgress::new(record.id, created_at, operator_slashed);
+    slashing_storage::SLASHING_PROGRESS.save(store, &progress)?;
+    Ok(())
+}
+
+// if the node is waiting to get slashed, the slashing has to be applied before its pledge is changed
+// (regardless of the order in which both requests got submitted)
+fn apply_pending_slashing_before_pledge_change(
+    store: &mut dyn Storage,
+    created_at: BlockHeight,
+    mix_details: &MixNodeDetails,
+) -> Result<bool, MixnetContractError> {
+    let slashing_id = match mix_details.pending_changes.slashing {
+        Some(slashing_id) => slashing_id,
+        None => return Ok(false),
+    };
+    let record = slashing_storage::SLASHING_RECORDS
+        .may_load(store, slashing_id)?
+        .ok_or(MixnetContractError::SlashingRecordNotFound { slashing_id })?;
+    start_mixnode_slashing(store, created_at, mix_details, &record)?;
+    Ok(true)
+}
+
+pub(crate) fn slash_mixnode(
+    deps: DepsMut<'_>,
+    created_at: BlockHeight,
+    mix_id: MixId,
+    slashing_id: SlashingId,
+) -> Result<Response, MixnetContractError> {
+    // the target node MUST exist - we have checked it at the time of putting this event onto the queue
+    // and it could not have been unbonded since, as the pending slashing prevents it
+    let mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?.ok_or(
+        MixnetContractError::inconsistent_state(
+            "mixnode getting processed to get slashed doesn't exist in the storage",
+        ),
+    )?;
+    let record = slashing_storage::SLASHING_RECORDS
+        .may_load(deps.storage, slashing_id)?
+        .ok_or(MixnetContractError::SlashingRecordNotFound { slashing_id })?;
+
+    // the slashing has already been applied ahead of a pledge change of this node
+    if !record.status.is_pending() {
+        return Ok(Response::default());
+    }
+
+    // note: the slashing event gets emitted once all the delegations have also been processed
+    start_mixnode_slashing(deps.storage, created_at, &mix_details, &record)?;
+    Ok(Response::default())
+}
+
+pub(crate) fn slash_gateway(
+    deps: DepsMut<'_>,
+    created_at: BlockHeight,
+    identity: IdentityKey,
+    slashing_id: SlashingId,
+) -> Result<Response, MixnetContractError> {
+    // the target gateway MUST exist - we have checked it at the time of putting this event onto the queue
+    // and it could not have been unbonded since, as the pending slashing prevents it
+    let gateway_bond = gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .ok_or(MixnetContractError::inconsistent_state(
+            "gateway getting processed to get slashed doesn't exist in the storage",
+        ))?;
+    if slashing_storage::PENDING_GATEWAY_SLASHINGS.may_load(deps.storage, &identity)?
+        != Some(slashing_id)
+    {
+        return Err(MixnetContractError::inconsistent_state(
+            "attempted to slash gateway while there are no associated pending changes",
+        ));
+    }
+    let record = slashing_storage::SLASHING_RECORDS
+        .may_load(deps.storage, slashing_id)?
+        .ok_or(MixnetContractError::SlashingRecordNotFound { slashing_id })?;
+    let slash_percent = record.slash_percent;
+
+    let mut updated_bond = gateway_bond.clone();
+    let pledge = updated_bond.pledge_amount.amount.into_base_decimal()?;
+    let slashed_pledge = truncate_reward_amount(pledge - slash_percent * pledge);
+
+    let operator_slashed = match rewards_storage::GATEWAY_REWARDING
+        .may_load(deps.storage, &identity)?
+    {
+        Some(mut gateway_rewarding) => {
+            // same as with mixnodes, make sure the operator's stake is never smaller than its pledge
+            let operator_slashed = gateway_rewarding.slash_operator(slash_percent);
+            updated_bond.pledge_amount.amount =
+                slashed_pledge.min(truncate_reward_amount(gateway_rewarding.operator));
+            rewards_storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+            operator_slashed
+        }
+        // this would only happen for gateways bonded before the rewarding got introduced
+        // and that didn't get migrated
+        None => {
+            updated_bond.pledge_amount.amount = slashed_pledge;
+            pledge - slashed_pledge.into_base_decimal()?
+        }
+    };
+
+    gateways_storage::gateways().replace(
+        deps.storage,
+        &identity,
+        Some(&updated_bond),
+        Some(&gateway_bond),
+    )?;
+
+    // note: the slashing event gets emitted once all the delegations have also been processed
+    let progress = SlashingProgress::new(slashing_id, created_at, operator_slashed);
+    slashing_storage::SLASHING_PROGRESS.save(deps.storage, &progress)?;
+    Ok(Response::default())
+}
+
 impl ContractExecutableEvent for PendingEpochEventData {
     fn execute(self, deps: DepsMut<'_>, env: &Env) -> Result<Response, MixnetContractError> {
         // note that the basic validation on all those events was already performed before
@@ -356,6 +848,22 @@ impl ContractExecutableEvent for PendingEpochEventData {
                 mix_id,
                 proxy,
             } => undelegate(deps, self.created_at, owner, mix_id, proxy),
+            PendingEpochEventKind::Redelegate {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+                proxy,
+            } => redelegate(
+                deps,
+                env,
+                self.created_at,
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+                proxy,
+            ),
             PendingEpochEventKind::PledgeMore { mix_id, amount } => {
                 increase_pledge(deps, self.created_at, mix_id, amount)
             }
@@ -369,6 +877,25 @@ impl ContractExecutableEvent for PendingEpochEventData {
             PendingEpochEventKind::UpdateActiveSetSize { new_size } => {
                 update_active_set_size(deps, self.created_at, new_size)
             }
+            PendingEpochEventKind::DelegateToGateway {
+                owner,
+                identity,
+                amount,
+                proxy,
+            } => delegate_to_gateway(deps, env, self.created_at, owner, identity, amount, proxy),
+            PendingEpochEventKind::UndelegateFromGateway {
+                owner,
+                identity,
+                proxy,
+            } => undelegate_from_gateway(deps, self.created_at, owner, identity, proxy),
+            PendingEpochEventKind::SlashMixnode {
+                mix_id,
+                slashing_id,
+            } => slash_mixnode(deps, self.created_at, mix_id, slashing_id),
+            PendingEpochEventKind::SlashGateway {
+                identity,
+                slashing_id,
+            } => slash_gateway(deps, self.created_at, identity, slashing_id),
         }
     }
 }
@@ -1113,183 +1640,402 @@ mod tests {
     }
 
     #[cfg(test)]
-    mod mixnode_unbonding {
+    mod redelegating {
         use cosmwasm_std::{coin, to_binary, CosmosMsg, Uint128, WasmMsg};
 
-        use mixnet_contract_common::mixnode::{PendingMixNodeChanges, UnbondedMixnode};
+        use mixnet_contract_common::delegation::StorageKey;
         use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
 
-        use crate::mixnodes::storage as mixnodes_storage;
-        use crate::mixnodes::transactions::{_try_decrease_pledge, _try_increase_pledge};
         use crate::support::tests::fixtures::TEST_COIN_DENOM;
         use crate::support::tests::test_helpers::get_bank_send_msg;
 
         use super::*;
 
+        fn delegation_key(mix_id: MixId, owner: &str, proxy: Option<&Addr>) -> StorageKey {
+            Delegation::generate_storage_key(mix_id, &Addr::unchecked(owner), proxy)
+        }
+
         #[test]
-        fn returns_hard_error_if_mixnode_doesnt_exist() {
-            // this should have never happened so hard error MUST be thrown here
+        fn doesnt_do_anything_if_delegation_doesnt_exist() {
             let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
             let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked("delegator"),
+                from_mix_id,
+                to_mix_id,
+                coin(100_000_000, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+            assert_eq!(res, Response::default());
 
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, 1);
-            assert!(matches!(
-                res,
-                Err(MixnetContractError::InconsistentState { .. })
-            ));
+            assert_eq!(test.mix_rewarding(to_mix_id).unique_delegations, 0);
         }
 
         #[test]
-        fn returns_hard_error_if_there_are_pending_pledge_changes() {
+        fn keeps_the_delegation_on_the_source_node_if_target_has_unbonded() {
             let mut test = TestSetup::new();
-            let env = test.env();
-            let change = test.coins(1234);
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            // increase
-            let owner = "mix-owner1";
-            let pledge = Uint128::new(250_000_000);
-            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+            let source_rewarding_before = test.mix_rewarding(from_mix_id);
 
-            _try_increase_pledge(
+            test.immediately_unbond_mixnode(to_mix_id);
+
+            let env = test.env();
+            let res = redelegate(
                 test.deps_mut(),
-                env.clone(),
-                change.clone(),
+                &env,
+                123,
                 Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation, TEST_COIN_DENOM),
                 None,
             )
             .unwrap();
 
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
-            assert!(matches!(
-                res,
-                Err(MixnetContractError::InconsistentState { .. })
-            ));
+            // nothing got moved nor returned
+            assert!(res.messages.is_empty());
+            let source = test.delegation(from_mix_id, owner, &None);
+            assert_eq!(source.amount, coin(delegation, TEST_COIN_DENOM));
+            assert_eq!(test.mix_rewarding(from_mix_id), source_rewarding_before);
+            assert!(delegations_storage::delegations()
+                .may_load(test.deps().storage, delegation_key(to_mix_id, owner, None))
+                .unwrap()
+                .is_none());
+        }
 
-            // decrease
-            let owner = "mix-owner2";
-            let pledge = Uint128::new(250_000_000);
-            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+        #[test]
+        fn moves_entire_delegation_alongside_earned_rewards() {
+            let mut test = TestSetup::new();
+            let from_mix_id =
+                test.add_dummy_mixnode("mix-owner1", Some(100_000_000_000u128.into()));
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            _try_decrease_pledge(
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+
+            test.force_change_rewarded_set(vec![from_mix_id]);
+            test.skip_to_next_epoch_end();
+            let dist1 = test.reward_with_distribution_with_state_bypass(
+                from_mix_id,
+                test_helpers::performance(100.0),
+            );
+            test.skip_to_next_epoch_end();
+            let dist2 = test.reward_with_distribution_with_state_bypass(
+                from_mix_id,
+                test_helpers::performance(100.0),
+            );
+            let worth =
+                delegation + truncate_reward_amount(dist1.delegates + dist2.delegates).u128();
+
+            let env = test.env();
+            let res = redelegate(
                 test.deps_mut(),
-                env.clone(),
-                change[0].clone(),
+                &env,
+                123,
                 Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(worth, TEST_COIN_DENOM),
                 None,
             )
             .unwrap();
 
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
-            assert!(matches!(
-                res,
-                Err(MixnetContractError::InconsistentState { .. })
-            ));
-
-            // artificial
-            let owner = "mix-owner3";
-            let pledge = Uint128::new(250_000_000);
-            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
-
-            let changes = PendingMixNodeChanges {
-                pledge_change: Some(1234),
-            };
+            // no tokens ever leave the contract
+            assert!(get_bank_send_msg(&res).is_none());
 
-            mixnodes_storage::PENDING_MIXNODE_CHANGES
-                .save(test.deps_mut().storage, mix_id, &changes)
-                .unwrap();
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
-            assert!(matches!(
-                res,
-                Err(MixnetContractError::InconsistentState { .. })
-            ));
+            // the source node no longer knows anything about the delegation
+            assert!(delegations_storage::delegations()
+                .may_load(
+                    test.deps().storage,
+                    delegation_key(from_mix_id, owner, None)
+                )
+                .unwrap()
+                .is_none());
+            let source_rewarding = test.mix_rewarding(from_mix_id);
+            assert!(source_rewarding.delegates.is_zero());
+            assert_eq!(source_rewarding.unique_delegations, 0);
+
+            // while the target has received all of it
+            let target = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(target.amount, coin(worth, TEST_COIN_DENOM));
+            let target_rewarding = test.mix_rewarding(to_mix_id);
+            assert_eq!(
+                target.cumulative_reward_ratio,
+                target_rewarding.total_unit_reward
+            );
+            assert_eq!(
+                target_rewarding.delegates,
+                Decimal::from_atomics(worth, 0).unwrap()
+            );
+            assert_eq!(target_rewarding.unique_delegations, 1);
         }
 
         #[test]
-        fn returns_original_pledge_alongside_any_earned_rewards() {
+        fn leaves_remainder_as_fresh_delegation_on_the_source_node() {
             let mut test = TestSetup::new();
+            let from_mix_id =
+                test.add_dummy_mixnode("mix-owner1", Some(100_000_000_000u128.into()));
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            let owner = "mix-owner";
-            let pledge = Uint128::new(250_000_000);
-            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
-            let mix_details = mixnodes_storage::mixnode_bonds()
-                .load(test.deps().storage, mix_id)
-                .unwrap();
-            let layer = mix_details.layer;
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            let redelegated = 50_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
 
-            test.force_change_rewarded_set(vec![mix_id]);
+            test.force_change_rewarded_set(vec![from_mix_id]);
             test.skip_to_next_epoch_end();
-            let dist1 = test.reward_with_distribution_with_state_bypass(
-                mix_id,
+            let dist = test.reward_with_distribution_with_state_bypass(
+                from_mix_id,
                 test_helpers::performance(100.0),
             );
+            let worth = delegation + truncate_reward_amount(dist.delegates).u128();
+
+            let env = test.env();
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(redelegated, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            // the rewards were compounded into the remaining delegation
+            let source = test.delegation(from_mix_id, owner, &None);
+            let source_rewarding = test.mix_rewarding(from_mix_id);
+            assert_eq!(source.amount, coin(worth - redelegated, TEST_COIN_DENOM));
+            assert_eq!(
+                source.cumulative_reward_ratio,
+                source_rewarding.total_unit_reward
+            );
+            assert_eq!(source_rewarding.unique_delegations, 1);
+            assert_decimals(
+                source_rewarding.delegates,
+                Decimal::from_atomics(worth - redelegated, 0).unwrap(),
+            );
+            assert!(test.pending_delegator_reward(owner, from_mix_id).is_zero());
+
+            let target = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(target.amount, coin(redelegated, TEST_COIN_DENOM));
+            assert_eq!(test.mix_rewarding(to_mix_id).unique_delegations, 1);
+        }
+
+        #[test]
+        fn merges_with_existing_delegation_on_the_target_node() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", Some(100_000_000_000u128.into()));
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            let existing = 80_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+            test.add_immediate_delegation(owner, existing, to_mix_id);
+
+            test.force_change_rewarded_set(vec![to_mix_id]);
             test.skip_to_next_epoch_end();
-            let dist2 = test.reward_with_distribution_with_state_bypass(
-                mix_id,
+            let dist = test.reward_with_distribution_with_state_bypass(
+                to_mix_id,
                 test_helpers::performance(100.0),
             );
-
-            let expected_reward = dist1.operator + dist2.operator;
-            let truncated_reward = truncate_reward_amount(expected_reward);
-            let expected_return = pledge + truncated_reward;
+            let existing_worth = existing + truncate_reward_amount(dist.delegates).u128();
 
             let env = test.env();
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id).unwrap();
-            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
-            assert_eq!(receiver, owner);
-            assert_eq!(sent_amount[0].amount, expected_return);
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
 
-            assert!(rewards_storage::MIXNODE_REWARDING
-                .may_load(test.deps().storage, mix_id)
-                .unwrap()
-                .is_none());
-            assert!(mixnodes_storage::mixnode_bonds()
-                .may_load(test.deps().storage, mix_id)
-                .unwrap()
-                .is_none());
-            let expected = UnbondedMixnode {
-                identity_key: mix_details.identity().to_string(),
-                owner: Addr::unchecked(owner),
-                proxy: None,
-                unbonding_height: env.block.height,
-            };
+            let target = test.delegation(to_mix_id, owner, &None);
+            let target_rewarding = test.mix_rewarding(to_mix_id);
             assert_eq!(
-                expected,
-                mixnodes_storage::unbonded_mixnodes()
-                    .load(test.deps().storage, mix_id)
-                    .unwrap()
+                target.amount,
+                coin(existing_worth + delegation, TEST_COIN_DENOM)
             );
             assert_eq!(
-                mixnodes_storage::LAYERS.load(test.deps().storage).unwrap()[layer],
-                0
+                target.cumulative_reward_ratio,
+                target_rewarding.total_unit_reward
+            );
+            assert_eq!(target_rewarding.unique_delegations, 1);
+            assert!(test.pending_delegator_reward(owner, to_mix_id).is_zero());
+        }
+
+        #[test]
+        fn carries_over_auto_compounding_of_the_delegation() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            let other_mix_id = test.add_dummy_mixnode("mix-owner3", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+            let source_key = delegation_key(from_mix_id, owner, None);
+            delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+                .save(test.deps_mut().storage, source_key.clone(), &42)
+                .unwrap();
+
+            // partial redelegation: both delegations are now auto-compounding
+            let env = test.env();
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation / 2, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            let target_key = delegation_key(to_mix_id, owner, None);
+            let compounding = |test: &TestSetup, key: StorageKey| {
+                delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+                    .may_load(test.deps().storage, key)
+                    .unwrap()
+            };
+            assert_eq!(compounding(&test, source_key.clone()), Some(42));
+            assert_eq!(compounding(&test, target_key), Some(42));
+
+            // full redelegation: the flag moves to the target delegation
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                other_mix_id,
+                coin(delegation, TEST_COIN_DENOM),
+                None,
             )
+            .unwrap();
+            assert_eq!(compounding(&test, source_key), None);
+            assert_eq!(
+                compounding(&test, delegation_key(other_mix_id, owner, None)),
+                Some(42)
+            );
         }
 
         #[test]
-        fn attaches_vesting_contract_track_message_if_tokens_are_returned() {
+        fn doesnt_enable_auto_compounding_if_it_was_disabled() {
             let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            let vesting_contract = test.vesting_contract();
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
 
-            let pledge = Uint128::new(250_000_000);
-            let pledge_coin = coin(250_000_000, TEST_COIN_DENOM);
-            let owner = "mix-owner1";
-            let mix_id_vesting = test.add_dummy_mixnode_with_legal_proxy(owner, Some(pledge));
+            let env = test.env();
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            assert!(!delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+                .has(test.deps().storage, delegation_key(to_mix_id, owner, None)));
+        }
+
+        #[test]
+        fn never_moves_more_than_the_delegation_is_worth() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
 
             let env = test.env();
-            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id_vesting).unwrap();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation * 2, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+            assert!(get_bank_send_msg(&res).is_none());
 
-            assert!(mixnodes_storage::mixnode_bonds()
-                .may_load(test.deps().storage, mix_id_vesting)
+            assert!(delegations_storage::delegations()
+                .may_load(
+                    test.deps().storage,
+                    delegation_key(from_mix_id, owner, None)
+                )
                 .unwrap()
                 .is_none());
+            let target = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(target.amount.amount, Uint128::new(delegation));
+        }
 
-            // and all tokens are returned back to the proxy
-            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
-            assert_eq!(receiver, vesting_contract.as_str());
-            assert_eq!(sent_amount[0], pledge_coin);
+        #[test]
+        fn attaches_vesting_contract_track_message_for_proxied_delegation() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            // and we get appropriate track message
-            let mut found_track = true;
+            let delegation = 120_000_000u128;
+            let redelegated_coin = coin(70_000_000, TEST_COIN_DENOM);
+            let owner = "delegator";
+
+            let vesting_contract = test.vesting_contract();
+            test.add_immediate_delegation_with_legal_proxy(owner, delegation, from_mix_id);
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                redelegated_coin.clone(),
+                Some(vesting_contract.clone()),
+            )
+            .unwrap();
+
+            let target = test.delegation(to_mix_id, owner, &Some(vesting_contract.clone()));
+            assert_eq!(target.amount, redelegated_coin);
+
+            // no tokens are returned
+            assert!(get_bank_send_msg(&res).is_none());
+
+            // but we get appropriate track message
+            let mut found_track = false;
             for msg in &res.messages {
                 if let CosmosMsg::Wasm(WasmMsg::Execute {
                     contract_addr,
@@ -1299,9 +2045,11 @@ mod tests {
                 {
                     found_track = true;
                     assert_eq!(contract_addr, vesting_contract.as_str());
-                    let expected_msg = to_binary(&VestingContractExecuteMsg::TrackUnbondMixnode {
+                    let expected_msg = to_binary(&VestingContractExecuteMsg::TrackRedelegation {
                         owner: owner.to_string(),
-                        amount: pledge_coin.clone(),
+                        from_mix_id,
+                        to_mix_id,
+                        amount: redelegated_coin.clone(),
                     })
                     .unwrap();
                     assert_eq!(&expected_msg, msg);
@@ -1314,9 +2062,250 @@ mod tests {
         #[test]
         fn returns_error_for_illegal_proxy() {
             let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
 
-            let dummy_proxy = Addr::unchecked("not-vesting-contract");
-            let env = test.env();
+            let delegation = 120_000_000u128;
+            let owner = "delegator1";
+
+            let vesting_contract = test.vesting_contract();
+            let dummy_proxy = Addr::unchecked("not-vesting-contract");
+
+            test.add_immediate_delegation_with_illegal_proxy(
+                owner,
+                delegation,
+                from_mix_id,
+                dummy_proxy.clone(),
+            );
+
+            let env = test.env();
+            let res_other_proxy = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                coin(delegation, TEST_COIN_DENOM),
+                Some(dummy_proxy.clone()),
+            )
+            .unwrap_err();
+            assert_eq!(
+                res_other_proxy,
+                MixnetContractError::ProxyIsNotVestingContract {
+                    received: dummy_proxy,
+                    vesting_contract,
+                }
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod mixnode_unbonding {
+        use cosmwasm_std::{coin, to_binary, CosmosMsg, Uint128, WasmMsg};
+
+        use mixnet_contract_common::mixnode::{PendingMixNodeChanges, UnbondedMixnode};
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+
+        use crate::mixnodes::storage as mixnodes_storage;
+        use crate::mixnodes::transactions::{_try_decrease_pledge, _try_increase_pledge};
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::get_bank_send_msg;
+
+        use super::*;
+
+        #[test]
+        fn returns_hard_error_if_mixnode_doesnt_exist() {
+            // this should have never happened so hard error MUST be thrown here
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, 1);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+        }
+
+        #[test]
+        fn returns_hard_error_if_there_are_pending_pledge_changes() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let change = test.coins(1234);
+
+            // increase
+            let owner = "mix-owner1";
+            let pledge = Uint128::new(250_000_000);
+            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+
+            _try_increase_pledge(
+                test.deps_mut(),
+                env.clone(),
+                change.clone(),
+                Addr::unchecked(owner),
+                None,
+            )
+            .unwrap();
+
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+
+            // decrease
+            let owner = "mix-owner2";
+            let pledge = Uint128::new(250_000_000);
+            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+
+            _try_decrease_pledge(
+                test.deps_mut(),
+                env.clone(),
+                change[0].clone(),
+                Addr::unchecked(owner),
+                None,
+            )
+            .unwrap();
+
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+
+            // artificial
+            let owner = "mix-owner3";
+            let pledge = Uint128::new(250_000_000);
+            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+
+            let changes = PendingMixNodeChanges {
+                pledge_change: Some(1234),
+                slashing: None,
+            };
+
+            mixnodes_storage::PENDING_MIXNODE_CHANGES
+                .save(test.deps_mut().storage, mix_id, &changes)
+                .unwrap();
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+        }
+
+        #[test]
+        fn returns_original_pledge_alongside_any_earned_rewards() {
+            let mut test = TestSetup::new();
+
+            let owner = "mix-owner";
+            let pledge = Uint128::new(250_000_000);
+            let mix_id = test.add_dummy_mixnode(owner, Some(pledge));
+            let mix_details = mixnodes_storage::mixnode_bonds()
+                .load(test.deps().storage, mix_id)
+                .unwrap();
+            let layer = mix_details.layer;
+
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.skip_to_next_epoch_end();
+            let dist1 = test.reward_with_distribution_with_state_bypass(
+                mix_id,
+                test_helpers::performance(100.0),
+            );
+            test.skip_to_next_epoch_end();
+            let dist2 = test.reward_with_distribution_with_state_bypass(
+                mix_id,
+                test_helpers::performance(100.0),
+            );
+
+            let expected_reward = dist1.operator + dist2.operator;
+            let truncated_reward = truncate_reward_amount(expected_reward);
+            let expected_return = pledge + truncated_reward;
+
+            let env = test.env();
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id).unwrap();
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, owner);
+            assert_eq!(sent_amount[0].amount, expected_return);
+
+            assert!(rewards_storage::MIXNODE_REWARDING
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+            assert!(mixnodes_storage::mixnode_bonds()
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+            let expected = UnbondedMixnode {
+                identity_key: mix_details.identity().to_string(),
+                owner: Addr::unchecked(owner),
+                proxy: None,
+                unbonding_height: env.block.height,
+            };
+            assert_eq!(
+                expected,
+                mixnodes_storage::unbonded_mixnodes()
+                    .load(test.deps().storage, mix_id)
+                    .unwrap()
+            );
+            assert_eq!(
+                mixnodes_storage::LAYERS.load(test.deps().storage).unwrap()[layer],
+                0
+            )
+        }
+
+        #[test]
+        fn attaches_vesting_contract_track_message_if_tokens_are_returned() {
+            let mut test = TestSetup::new();
+
+            let vesting_contract = test.vesting_contract();
+
+            let pledge = Uint128::new(250_000_000);
+            let pledge_coin = coin(250_000_000, TEST_COIN_DENOM);
+            let owner = "mix-owner1";
+            let mix_id_vesting = test.add_dummy_mixnode_with_legal_proxy(owner, Some(pledge));
+
+            let env = test.env();
+            let res = unbond_mixnode(test.deps_mut(), &env, 123, mix_id_vesting).unwrap();
+
+            assert!(mixnodes_storage::mixnode_bonds()
+                .may_load(test.deps().storage, mix_id_vesting)
+                .unwrap()
+                .is_none());
+
+            // and all tokens are returned back to the proxy
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, vesting_contract.as_str());
+            assert_eq!(sent_amount[0], pledge_coin);
+
+            // and we get appropriate track message
+            let mut found_track = true;
+            for msg in &res.messages {
+                if let CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }) = &msg.msg
+                {
+                    found_track = true;
+                    assert_eq!(contract_addr, vesting_contract.as_str());
+                    let expected_msg = to_binary(&VestingContractExecuteMsg::TrackUnbondMixnode {
+                        owner: owner.to_string(),
+                        amount: pledge_coin.clone(),
+                    })
+                    .unwrap();
+                    assert_eq!(&expected_msg, msg);
+                    assert!(funds.is_empty())
+                }
+            }
+            assert!(found_track);
+        }
+
+        #[test]
+        fn returns_error_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+
+            let dummy_proxy = Addr::unchecked("not-vesting-contract");
+            let env = test.env();
 
             let vesting_contract = test.vesting_contract();
             let owner = "mix-owner";
@@ -2130,4 +3119,663 @@ mod tests {
         );
         assert_eq!(interval_after.epoch_length(), Duration::from_secs(1234))
     }
+
+    #[cfg(test)]
+    mod gateway_delegations {
+        use cosmwasm_std::testing::mock_info;
+        use cosmwasm_std::{coin, to_binary, CosmosMsg, Uint128, WasmMsg};
+
+        use crate::gateways::transactions::try_remove_gateway;
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::get_bank_send_msg;
+
+        use super::*;
+
+        #[test]
+        fn delegating_returns_the_tokens_if_gateway_has_unbonded() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            try_remove_gateway(test.deps_mut(), mock_info("gateway-owner", &[])).unwrap();
+
+            let env = test.env();
+            let delegation = coin(120_000_000, TEST_COIN_DENOM);
+            let res = delegate_to_gateway(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked("delegator"),
+                identity.clone(),
+                delegation.clone(),
+                None,
+            )
+            .unwrap();
+
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, "delegator");
+            assert_eq!(sent_amount[0], delegation);
+
+            let storage_key =
+                GatewayDelegation::generate_storage_key(&identity, &Addr::unchecked("delegator"));
+            assert!(delegations_storage::gateway_delegations()
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn delegating_again_merges_existing_delegation_with_its_reward() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+
+            // pretend the gateway got rewarded
+            let mut gateway_rewarding = test.gateway_rewarding(&identity);
+            let epoch = gateway_rewarding.last_rewarded_epoch + 1;
+            let reward = gateway_rewarding
+                .determine_reward_split(Decimal::from_atomics(1_000_000u32, 0).unwrap());
+            gateway_rewarding.distribute_rewards(reward, epoch);
+            rewards_storage::GATEWAY_REWARDING
+                .save(test.deps_mut().storage, &identity, &gateway_rewarding)
+                .unwrap();
+
+            let storage_key =
+                GatewayDelegation::generate_storage_key(&identity, &Addr::unchecked("delegator"));
+            let existing = delegations_storage::gateway_delegations()
+                .load(test.deps().storage, storage_key.clone())
+                .unwrap();
+            let pending_reward = gateway_rewarding
+                .pending_delegator_reward(&existing)
+                .unwrap();
+            assert!(!pending_reward.amount.is_zero());
+
+            test.add_immediate_gateway_delegation("delegator", 50_000_000u32, &identity);
+
+            let updated = delegations_storage::gateway_delegations()
+                .load(test.deps().storage, storage_key)
+                .unwrap();
+            assert_eq!(
+                updated.amount.amount,
+                Uint128::new(150_000_000) + pending_reward.amount
+            );
+
+            let gateway_rewarding = test.gateway_rewarding(&identity);
+            assert_eq!(gateway_rewarding.unique_delegations, 1);
+            assert_eq!(
+                updated.cumulative_reward_ratio,
+                gateway_rewarding.total_unit_reward
+            );
+        }
+
+        #[test]
+        fn undelegating_returns_the_tokens_and_cleans_up_after_unbonded_gateway() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+
+            // the rewarding information is kept around for as long as there are delegations
+            try_remove_gateway(test.deps_mut(), mock_info("gateway-owner", &[])).unwrap();
+            assert!(!test.gateway_rewarding(&identity).still_bonded());
+
+            let res = undelegate_from_gateway(
+                test.deps_mut(),
+                123,
+                Addr::unchecked("delegator"),
+                identity.clone(),
+                None,
+            )
+            .unwrap();
+
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, "delegator");
+            assert_eq!(sent_amount[0], coin(100_000_000, TEST_COIN_DENOM));
+
+            assert!(rewards_storage::GATEWAY_REWARDING
+                .may_load(test.deps().storage, &identity)
+                .unwrap()
+                .is_none());
+
+            // attempting to undelegate again does nothing
+            let res = undelegate_from_gateway(
+                test.deps_mut(),
+                123,
+                Addr::unchecked("delegator"),
+                identity,
+                None,
+            )
+            .unwrap();
+            assert_eq!(res, Response::default());
+        }
+
+        #[test]
+        fn vesting_delegation_is_returned_to_the_proxy_and_tracked() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation_with_legal_proxy(
+                "delegator",
+                100_000_000u32,
+                &identity,
+            );
+            let vesting_contract = test.vesting_contract();
+
+            // undelegation has to be made with the same proxy
+            let res = undelegate_from_gateway(
+                test.deps_mut(),
+                123,
+                Addr::unchecked("delegator"),
+                identity.clone(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(res, Response::default());
+
+            let res = undelegate_from_gateway(
+                test.deps_mut(),
+                123,
+                Addr::unchecked("delegator"),
+                identity.clone(),
+                Some(vesting_contract.clone()),
+            )
+            .unwrap();
+
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, vesting_contract.as_str());
+            assert_eq!(sent_amount[0], coin(100_000_000, TEST_COIN_DENOM));
+
+            // and we get appropriate track message
+            let mut found_track = false;
+            for msg in &res.messages {
+                if let CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }) = &msg.msg
+                {
+                    found_track = true;
+                    assert_eq!(contract_addr, vesting_contract.as_str());
+                    let expected_msg =
+                        to_binary(&VestingContractExecuteMsg::TrackGatewayUndelegation {
+                            owner: "delegator".to_string(),
+                            identity: identity.clone(),
+                            amount: coin(100_000_000, TEST_COIN_DENOM),
+                        })
+                        .unwrap();
+                    assert_eq!(&expected_msg, msg);
+                    assert!(funds.is_empty())
+                }
+            }
+            assert!(found_track);
+        }
+
+        #[test]
+        fn delegation_with_a_different_proxy_is_returned() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+            let vesting_contract = test.vesting_contract();
+
+            let env = test.env();
+            let delegation = coin(50_000_000, TEST_COIN_DENOM);
+            let res = delegate_to_gateway(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked("delegator"),
+                identity.clone(),
+                delegation.clone(),
+                Some(vesting_contract.clone()),
+            )
+            .unwrap();
+
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, vesting_contract.as_str());
+            assert_eq!(sent_amount[0], delegation);
+
+            // the existing delegation is left untouched
+            let storage_key =
+                GatewayDelegation::generate_storage_key(&identity, &Addr::unchecked("delegator"));
+            let existing = delegations_storage::gateway_delegations()
+                .load(test.deps().storage, storage_key)
+                .unwrap();
+            assert_eq!(existing.amount, coin(100_000_000, TEST_COIN_DENOM));
+            assert!(existing.proxy.is_none());
+        }
+    }
+
+    #[cfg(test)]
+    mod slashing {
+        use super::*;
+        use crate::interval::transactions::perform_pending_epoch_actions;
+        use crate::rewards::models::RewardPoolChange;
+        use crate::slashing::helpers::{continue_slashing, slashing_in_progress};
+        use crate::slashing::transactions::{try_slash_gateway, try_slash_mixnode};
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::performance;
+        use cosmwasm_std::{coin, BankMsg, CosmosMsg, Uint128};
+        use mixnet_contract_common::{SlashingEvidence, SlashingStatus};
+
+        fn evidence() -> SlashingEvidence {
+            SlashingEvidence::NetworkMonitorFailures {
+                failed_epochs: vec![0],
+            }
+        }
+
+        fn submit_slashing(test: &mut TestSetup, mix_id: MixId, redistribute: bool) {
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_slash_mixnode(
+                test.deps_mut(),
+                env,
+                sender,
+                mix_id,
+                Percent::from_percentage_value(10).unwrap(),
+                evidence(),
+                redistribute,
+            )
+            .unwrap();
+        }
+
+        fn submit_gateway_slashing(test: &mut TestSetup, identity: &str) {
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_slash_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                identity.to_string(),
+                Percent::from_percentage_value(10).unwrap(),
+                evidence(),
+                false,
+            )
+            .unwrap();
+        }
+
+        // starts the slashing and processes all of the delegations
+        fn execute_slashing(
+            test: &mut TestSetup,
+            mix_id: MixId,
+            slashing_id: SlashingId,
+        ) -> Response {
+            let mut res = slash_mixnode(test.deps_mut(), 123, mix_id, slashing_id).unwrap();
+            let (mut sub_response, _, finished) = continue_slashing(test.deps_mut(), None).unwrap();
+            assert!(finished);
+            res.messages.append(&mut sub_response.messages);
+            res.events.append(&mut sub_response.events);
+            res
+        }
+
+        fn get_burn_msg(res: &Response) -> Option<Vec<Coin>> {
+            for msg in &res.messages {
+                if let CosmosMsg::Bank(BankMsg::Burn { amount }) = &msg.msg {
+                    return Some(amount.clone());
+                }
+            }
+            None
+        }
+
+        #[test]
+        fn returns_hard_error_if_mixnode_doesnt_exist() {
+            // this should have never happened so hard error MUST be thrown here
+            let mut test = TestSetup::new();
+
+            let res = slash_mixnode(test.deps_mut(), 123, 1, 1);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+        }
+
+        #[test]
+        fn returns_hard_error_if_there_is_no_pending_slashing() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            submit_slashing(&mut test, mix_id, false);
+
+            let mut pending_changes = mixnodes_storage::PENDING_MIXNODE_CHANGES
+                .load(test.deps().storage, mix_id)
+                .unwrap();
+            pending_changes.slashing = None;
+            mixnodes_storage::PENDING_MIXNODE_CHANGES
+                .save(test.deps_mut().storage, mix_id, &pending_changes)
+                .unwrap();
+
+            let res = slash_mixnode(test.deps_mut(), 123, mix_id, 1);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InconsistentState { .. })
+            ));
+        }
+
+        #[test]
+        fn reduces_pledge_and_delegations_and_burns_the_tokens() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_delegation("delegator", 50_000_000u128, mix_id);
+
+            submit_slashing(&mut test, mix_id, false);
+            let res = execute_slashing(&mut test, mix_id, 1);
+
+            let bond = test.mix_bond(mix_id);
+            assert_eq!(bond.original_pledge, coin(90_000_000, TEST_COIN_DENOM));
+
+            let rewarding = test.mix_rewarding(mix_id);
+            assert_eq!(
+                rewarding.operator,
+                Decimal::from_atomics(90_000_000u32, 0).unwrap()
+            );
+            assert_eq!(
+                rewarding.delegates,
+                Decimal::from_atomics(45_000_000u32, 0).unwrap()
+            );
+            assert_eq!(rewarding.unique_delegations, 1);
+
+            let delegation = test.delegation(mix_id, "delegator", &None);
+            assert_eq!(delegation.amount, coin(45_000_000, TEST_COIN_DENOM));
+
+            assert_eq!(
+                get_burn_msg(&res).unwrap(),
+                vec![coin(15_000_000, TEST_COIN_DENOM)]
+            );
+
+            let pending_changes = mixnodes_storage::PENDING_MIXNODE_CHANGES
+                .load(test.deps().storage, mix_id)
+                .unwrap();
+            assert!(pending_changes.slashing.is_none());
+            assert!(!slashing_in_progress(test.deps().storage).unwrap());
+
+            let record = slashing_storage::SLASHING_RECORDS
+                .load(test.deps().storage, 1)
+                .unwrap();
+            assert_eq!(
+                record.status,
+                SlashingStatus::Executed {
+                    absolute_epoch_id: test.current_interval().current_epoch_absolute_id(),
+                    operator_slashed: coin(10_000_000, TEST_COIN_DENOM),
+                    delegates_slashed: coin(5_000_000, TEST_COIN_DENOM),
+                }
+            );
+        }
+
+        #[test]
+        fn slashes_unclaimed_rewards() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_delegation("delegator", 50_000_000u128, mix_id);
+
+            test.skip_to_next_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.reward_with_distribution_with_state_bypass(mix_id, performance(100.0));
+
+            let operator_before = test.mix_rewarding(mix_id).operator;
+            let delegator_reward = test.pending_delegator_reward("delegator", mix_id);
+            assert!(!delegator_reward.is_zero());
+
+            submit_slashing(&mut test, mix_id, false);
+            execute_slashing(&mut test, mix_id, 1);
+
+            let rewarding = test.mix_rewarding(mix_id);
+            let ten_percent = Percent::from_percentage_value(10).unwrap();
+            assert_eq!(
+                rewarding.operator,
+                operator_before - ten_percent * operator_before
+            );
+
+            // the remaining reward got folded into the delegation
+            let delegation = test.delegation(mix_id, "delegator", &None);
+            let delegation_value =
+                Decimal::from_atomics(50_000_000u32, 0).unwrap() + delegator_reward;
+            assert_eq!(
+                delegation.amount.amount,
+                truncate_reward_amount(delegation_value - ten_percent * delegation_value)
+            );
+            assert!(test.pending_delegator_reward("delegator", mix_id).is_zero());
+
+            // and the operator can still withdraw its (reduced) rewards
+            let bond = test.mix_bond(mix_id);
+            assert!(bond.original_pledge.amount.u128() <= 90_000_000);
+            assert!(rewarding
+                .pending_detailed_operator_reward(&bond.original_pledge)
+                .is_ok());
+        }
+
+        #[test]
+        fn redistributes_tokens_into_reward_pool() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_delegation("delegator", 50_000_000u128, mix_id);
+
+            submit_slashing(&mut test, mix_id, true);
+            let res = execute_slashing(&mut test, mix_id, 1);
+            assert!(res.messages.is_empty());
+
+            let pool_change = rewards_storage::PENDING_REWARD_POOL_CHANGE
+                .load(test.deps().storage)
+                .unwrap();
+            assert_eq!(
+                pool_change,
+                RewardPoolChange {
+                    removed: Decimal::zero(),
+                    added: Decimal::from_atomics(15_000_000u32, 0).unwrap(),
+                }
+            );
+        }
+
+        #[test]
+        fn removes_delegations_slashed_down_to_zero() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_delegation("delegator1", 1u128, mix_id);
+            test.add_immediate_delegation("delegator2", 50_000_000u128, mix_id);
+
+            submit_slashing(&mut test, mix_id, false);
+            execute_slashing(&mut test, mix_id, 1);
+
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked("delegator1"), None);
+            assert!(delegations_storage::delegations()
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_none());
+
+            let rewarding = test.mix_rewarding(mix_id);
+            assert_eq!(rewarding.unique_delegations, 1);
+            assert_eq!(
+                rewarding.delegates,
+                Decimal::from_atomics(45_000_000u32, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn processes_delegations_in_batches() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            for i in 0..5 {
+                test.add_immediate_delegation(&format!("delegator{i}"), 10_000_000u128, mix_id);
+            }
+            submit_slashing(&mut test, mix_id, false);
+
+            // the event itself and two delegations
+            let env = test.env();
+            let (res, executed) =
+                perform_pending_epoch_actions(test.deps_mut(), &env, Some(3)).unwrap();
+            assert_eq!(executed, 3);
+            assert!(get_burn_msg(&res).is_none());
+            assert!(slashing_in_progress(test.deps().storage).unwrap());
+            assert!(slashing_storage::SLASHING_RECORDS
+                .load(test.deps().storage, 1)
+                .unwrap()
+                .status
+                .is_pending());
+
+            // the operator is already slashed, but only some delegations are
+            assert_eq!(
+                test.mix_bond(mix_id).original_pledge,
+                coin(90_000_000, TEST_COIN_DENOM)
+            );
+            assert_eq!(
+                test.delegation(mix_id, "delegator0", &None).amount,
+                coin(9_000_000, TEST_COIN_DENOM)
+            );
+            assert_eq!(
+                test.delegation(mix_id, "delegator4", &None).amount,
+                coin(10_000_000, TEST_COIN_DENOM)
+            );
+
+            // the remaining three delegations
+            let (res, executed) =
+                perform_pending_epoch_actions(test.deps_mut(), &env, Some(10)).unwrap();
+            assert_eq!(executed, 3);
+            assert_eq!(
+                get_burn_msg(&res).unwrap(),
+                vec![coin(15_000_000, TEST_COIN_DENOM)]
+            );
+            assert!(!slashing_in_progress(test.deps().storage).unwrap());
+            for i in 0..5 {
+                assert_eq!(
+                    test.delegation(mix_id, &format!("delegator{i}"), &None)
+                        .amount,
+                    coin(9_000_000, TEST_COIN_DENOM)
+                );
+            }
+        }
+
+        #[test]
+        fn no_further_events_are_executed_until_all_delegations_are_slashed() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_delegation("delegator1", 10_000_000u128, mix_id);
+            test.add_immediate_delegation("delegator2", 10_000_000u128, mix_id);
+            submit_slashing(&mut test, mix_id, false);
+            test.add_delegation("delegator3", 10_000_000u128, mix_id);
+
+            let env = test.env();
+            let (_, executed) =
+                perform_pending_epoch_actions(test.deps_mut(), &env, Some(2)).unwrap();
+            assert_eq!(executed, 2);
+            assert_eq!(test.pending_epoch_events().len(), 1);
+
+            // the new delegation is only made after the slashing has been fully applied
+            let (_, executed) = perform_pending_epoch_actions(test.deps_mut(), &env, None).unwrap();
+            assert_eq!(executed, 2);
+            assert!(test.pending_epoch_events().is_empty());
+            assert_eq!(
+                test.delegation(mix_id, "delegator3", &None).amount,
+                coin(10_000_000, TEST_COIN_DENOM)
+            );
+        }
+
+        #[test]
+        fn takes_precedence_over_pending_pledge_change() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000)));
+            test.set_pending_pledge_change(mix_id, None);
+            submit_slashing(&mut test, mix_id, false);
+
+            // the pledge change was submitted first, but the slashing still gets applied before it
+            increase_pledge(
+                test.deps_mut(),
+                123,
+                mix_id,
+                coin(10_000_000, TEST_COIN_DENOM),
+            )
+            .unwrap();
+            let (res, _, finished) = continue_slashing(test.deps_mut(), None).unwrap();
+            assert!(finished);
+            assert_eq!(
+                get_burn_msg(&res).unwrap(),
+                vec![coin(10_000_000, TEST_COIN_DENOM)]
+            );
+            assert_eq!(
+                test.mix_bond(mix_id).original_pledge,
+                coin(100_000_000, TEST_COIN_DENOM)
+            );
+
+            // and the slashing event itself no longer does anything
+            let res = slash_mixnode(test.deps_mut(), 123, mix_id, 1).unwrap();
+            assert_eq!(res, Response::default());
+            assert!(!slashing_in_progress(test.deps().storage).unwrap());
+            assert_eq!(
+                test.mix_bond(mix_id).original_pledge,
+                coin(100_000_000, TEST_COIN_DENOM)
+            );
+        }
+
+        #[test]
+        fn caps_pending_pledge_decrease_after_slashing() {
+            let mut test = TestSetup::new();
+            let minimum_pledge = test.make_mix_pledge(None)[0].amount;
+            let mix_id =
+                test.add_dummy_mixnode("mix-owner", Some(minimum_pledge * Uint128::new(2)));
+            test.set_pending_pledge_change(mix_id, None);
+            submit_slashing(&mut test, mix_id, false);
+
+            // decreasing by the whole difference would have been valid before the slashing
+            decrease_pledge(
+                test.deps_mut(),
+                123,
+                mix_id,
+                coin(minimum_pledge.u128(), TEST_COIN_DENOM),
+            )
+            .unwrap();
+            continue_slashing(test.deps_mut(), None).unwrap();
+
+            assert_eq!(
+                test.mix_bond(mix_id).original_pledge,
+                coin(minimum_pledge.u128(), TEST_COIN_DENOM)
+            );
+        }
+
+        #[test]
+        fn slashes_gateway_and_its_delegations() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", Some(Uint128::new(100_000_000)));
+            test.add_immediate_gateway_delegation("delegator1", 1u128, &identity);
+            test.add_immediate_gateway_delegation("delegator2", 50_000_000u128, &identity);
+
+            submit_gateway_slashing(&mut test, &identity);
+            let res = slash_gateway(test.deps_mut(), 123, identity.clone(), 1).unwrap();
+            assert_eq!(res, Response::default());
+            let (res, processed, finished) = continue_slashing(test.deps_mut(), None).unwrap();
+            assert_eq!(processed, 2);
+            assert!(finished);
+
+            let bond = gateways_storage::gateways()
+                .load(test.deps().storage, &identity)
+                .unwrap();
+            assert_eq!(bond.pledge_amount, coin(90_000_000, TEST_COIN_DENOM));
+
+            let rewarding = test.gateway_rewarding(&identity);
+            assert_eq!(
+                rewarding.operator,
+                Decimal::from_atomics(90_000_000u32, 0).unwrap()
+            );
+            assert_eq!(
+                rewarding.delegates,
+                Decimal::from_atomics(45_000_000u32, 0).unwrap()
+            );
+            assert_eq!(rewarding.unique_delegations, 1);
+
+            // the tiny delegation got wiped out
+            let storage_key =
+                GatewayDelegation::generate_storage_key(&identity, &Addr::unchecked("delegator1"));
+            assert!(delegations_storage::gateway_delegations()
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_none());
+            let storage_key =
+                GatewayDelegation::generate_storage_key(&identity, &Addr::unchecked("delegator2"));
+            let delegation = delegations_storage::gateway_delegations()
+                .load(test.deps().storage, storage_key)
+                .unwrap();
+            assert_eq!(delegation.amount, coin(45_000_000, TEST_COIN_DENOM));
+
+            assert_eq!(
+                get_burn_msg(&res).unwrap(),
+                vec![coin(15_000_000, TEST_COIN_DENOM)]
+            );
+            assert!(slashing_storage::PENDING_GATEWAY_SLASHINGS
+                .may_load(test.deps().storage, &identity)
+                .unwrap()
+                .is_none());
+        }
+    }
 }
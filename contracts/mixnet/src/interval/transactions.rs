@@ -5,18 +5,21 @@ use super::storage;
 use crate::interval::helpers::change_interval_config;
 use crate::interval::pending_events::ContractExecutableEvent;
 use crate::interval::storage::push_new_interval_event;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::transactions::update_mixnode_layer;
 use crate::rewards;
 use crate::rewards::storage as rewards_storage;
+use crate::slashing;
 use crate::support::helpers::{
     ensure_can_advance_epoch, ensure_epoch_in_progress_state, ensure_is_authorized, ensure_is_owner,
 };
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_advance_epoch_event, new_epoch_transition_start_event,
-    new_pending_epoch_events_execution_event, new_pending_interval_config_update_event,
-    new_pending_interval_events_execution_event, new_reconcile_pending_events,
+    new_advance_epoch_event, new_delegator_rewards_compounding_event,
+    new_epoch_transition_start_event, new_pending_epoch_events_execution_event,
+    new_pending_interval_config_update_event, new_pending_interval_events_execution_event,
+    new_reconcile_pending_events,
 };
 use mixnet_contract_common::pending_events::PendingIntervalEventKind;
 use mixnet_contract_common::{EpochState, EpochStatus, LayerAssignment, MixId};
@@ -27,33 +30,34 @@ use std::collections::BTreeSet;
 // however, it should also be called when advancing epoch itself in case somebody
 // managed to sneak in a transaction between those two operations
 // (but then the amount of work is going to be minimal)
+//
+// note: delegations of a slashed node are processed in batches with each of them counting
+// towards the limit (and towards the returned value) alongside the executed events.
+// no further event is executed until all of them got slashed.
 pub(crate) fn perform_pending_epoch_actions(
     mut deps: DepsMut<'_>,
     env: &Env,
     limit: Option<u32>,
 ) -> Result<(Response, u32), MixnetContractError> {
+    // finish applying the slashing that got started during the previous call (if applicable)
+    let (mut response, mut executed, slashing_finished) =
+        slashing::helpers::continue_slashing(deps.branch(), limit)?;
+    if !slashing_finished {
+        return Ok((response, executed));
+    }
+
     let last_executed = storage::LAST_PROCESSED_EPOCH_EVENT.load(deps.storage)?;
     let last_inserted = storage::EPOCH_EVENT_ID_COUNTER.load(deps.storage)?;
 
     // no pending events
     if last_executed == last_inserted {
-        return Ok((Response::new(), 0));
+        return Ok((response, executed));
     }
 
-    let pending = last_inserted - last_executed;
-    let last = limit
-        .map(|limit| {
-            if limit >= pending {
-                last_inserted
-            } else {
-                last_executed + limit
-            }
-        })
-        .unwrap_or(last_inserted);
-
-    let mut response = Response::new();
+    let mut last = last_executed;
     // no need to use the [cosmwasm] range iterator as we know the exact keys in order
-    for event_id in last_executed + 1..=last {
+    while last < last_inserted && limit.map(|limit| executed < limit).unwrap_or(true) {
+        let event_id = last + 1;
         let event = storage::PENDING_EPOCH_EVENTS.load(deps.storage, event_id)?;
         let mut sub_response = event.execute(deps.branch(), env)?;
         response.messages.append(&mut sub_response.messages);
@@ -62,11 +66,25 @@ pub(crate) fn perform_pending_epoch_actions(
         // response.data.append(&mut sub_response.data);
 
         storage::PENDING_EPOCH_EVENTS.remove(deps.storage, event_id);
+        last = event_id;
+        executed += 1;
+
+        // if the event has started slashing a node, process its delegations before moving on
+        let (mut sub_response, slashed, slashing_finished) =
+            slashing::helpers::continue_slashing(deps.branch(), limit.map(|l| l - executed))?;
+        response.messages.append(&mut sub_response.messages);
+        response.attributes.append(&mut sub_response.attributes);
+        response.events.append(&mut sub_response.events);
+        executed += slashed;
+
+        if !slashing_finished {
+            break;
+        }
     }
 
     storage::LAST_PROCESSED_EPOCH_EVENT.save(deps.storage, &last)?;
 
-    Ok((response, last - last_executed))
+    Ok((response, executed))
 }
 
 pub(crate) fn perform_pending_interval_actions(
@@ -159,12 +177,30 @@ pub fn try_reconcile_epoch_events(
         response
             .events
             .push(new_pending_interval_events_execution_event(executed));
+
+        limit = limit.map(|l| l - executed)
+    }
+
+    // once all the events got resolved, fold the rewards into auto-compounding delegations
+    // (each compounded delegation counts towards the provided limit)
+    let (compounded, compounding_finished) =
+        rewards::helpers::compound_delegator_rewards(deps.storage, limit)?;
+    if compounded > 0 {
+        response
+            .events
+            .push(new_delegator_rewards_compounding_event(
+                compounded,
+                compounding_finished,
+            ));
     }
 
     // if there are no more events to clear, go into the next state
     let pending_events = super::queries::query_number_of_pending_events(deps.as_ref())?;
     // we can only progress if there are no epoch events AND if the interval has finished, that there are no interval events
-    let progress = if pending_events.epoch_events == 0 {
+    // and all the auto-compounding delegations have been processed (as well as all delegations of any slashed node)
+    let slashing_finished = !slashing::helpers::slashing_in_progress(deps.storage)?;
+    let progress = if pending_events.epoch_events == 0 && compounding_finished && slashing_finished
+    {
         if interval.is_current_interval_over(&env) {
             pending_events.interval_events == 0
         } else {
@@ -298,6 +334,18 @@ pub fn try_advance_epoch(
         rewards::helpers::apply_reward_pool_changes(deps.storage)?;
     }
 
+    // nodes can only be assigned to layers that currently exist in the network
+    let mix_layers = mixnet_params_storage::CONTRACT_STATE
+        .load(deps.storage)?
+        .params
+        .mix_layers;
+    if let Some(invalid) = layer_assignments
+        .iter()
+        .find(|assignment| u8::from(assignment.layer()) > mix_layers)
+    {
+        return Err(MixnetContractError::InvalidLayer(invalid.layer().into()));
+    }
+
     let updated_interval = current_interval.advance_epoch();
     let num_nodes = layer_assignments.len();
 
@@ -1090,6 +1138,56 @@ mod tests {
             )
         }
 
+        #[test]
+        fn epoch_state_is_not_updated_until_all_rewards_are_compounded() {
+            let mut test = TestSetup::new();
+            let rewarding_validator = test.rewarding_validator();
+            let env = test.env();
+
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            for i in 0..3 {
+                let delegator = format!("delegator{i}");
+                test.add_immediate_delegation(&delegator, 100_000_000u32, mix_id);
+                crate::delegations::transactions::try_set_delegation_auto_compounding(
+                    test.deps_mut(),
+                    env.clone(),
+                    mock_info(&delegator, &[]),
+                    mix_id,
+                    true,
+                )
+                .unwrap();
+            }
+
+            test.skip_to_current_epoch_end();
+            let env = test.env();
+            push_n_dummy_epoch_actions(&mut test, 2);
+            test.set_epoch_reconciliation_state();
+
+            // both events and a single delegation got processed
+            try_reconcile_epoch_events(
+                test.deps_mut(),
+                env.clone(),
+                rewarding_validator.clone(),
+                Some(3),
+            )
+            .unwrap();
+            assert!(test.pending_epoch_events().is_empty());
+            assert_eq!(
+                EpochState::ReconcilingEvents,
+                storage::current_epoch_status(test.deps().storage)
+                    .unwrap()
+                    .state
+            );
+
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator, Some(3)).unwrap();
+            assert_eq!(
+                EpochState::AdvancingEpoch,
+                storage::current_epoch_status(test.deps().storage)
+                    .unwrap()
+                    .state
+            );
+        }
+
         #[test]
         fn epoch_state_is_correctly_updated_if_even_with_leftover_interval_events_if_interval_is_not_over(
         ) {
@@ -1565,6 +1663,62 @@ mod tests {
             )
         }
 
+        #[test]
+        fn layers_must_be_within_configured_number_of_layers() {
+            let mut test = TestSetup::new();
+            test.add_dummy_mixnode("1", Some(Uint128::new(100000000)));
+            test.add_dummy_mixnode("2", Some(Uint128::new(100000000)));
+            test.add_dummy_mixnode("3", Some(Uint128::new(100000000)));
+            test.add_dummy_mixnode("4", Some(Uint128::new(100000000)));
+            let current_active_set = test.rewarding_params().active_set_size;
+
+            test.skip_to_current_epoch_end();
+            test.set_epoch_advancement_state();
+
+            let layer_assignments = vec![
+                LayerAssignment::new(1, Layer::One),
+                LayerAssignment::new(2, Layer::Two),
+                LayerAssignment::new(3, Layer::Three),
+                LayerAssignment::new(4, Layer::Four),
+            ];
+
+            // by default the network only consists of 3 layers
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            let res = try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender.clone(),
+                layer_assignments.clone(),
+                current_active_set,
+            );
+            assert_eq!(res, Err(MixnetContractError::InvalidLayer(4)));
+
+            let mut state = mixnet_params_storage::CONTRACT_STATE
+                .load(test.deps().storage)
+                .unwrap();
+            state.params.mix_layers = 4;
+            mixnet_params_storage::CONTRACT_STATE
+                .save(test.deps_mut().storage, &state)
+                .unwrap();
+
+            let env = test.env();
+            let res = try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender,
+                layer_assignments,
+                current_active_set,
+            );
+            assert!(res.is_ok());
+
+            let mixnode_4 = query_mixnode_details(test.deps.as_ref(), 4).unwrap();
+            assert_eq!(
+                mixnode_4.mixnode_details.unwrap().bond_information.layer,
+                Layer::Four
+            );
+        }
+
         #[test]
         fn can_only_be_performed_by_specified_rewarding_validator() {
             let mut test = TestSetup::new();
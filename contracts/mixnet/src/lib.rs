@@ -15,6 +15,7 @@ mod mixnodes;
 mod queued_migrations;
 mod rewards;
 pub mod signing;
+mod slashing;
 mod support;
 
 #[cfg(feature = "contract-testing")]
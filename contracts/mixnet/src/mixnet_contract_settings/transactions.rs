@@ -9,7 +9,7 @@ use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_rewarding_validator_address_update_event, new_settings_update_event,
 };
-use mixnet_contract_common::ContractStateParams;
+use mixnet_contract_common::{ContractStateParams, MAX_MIX_LAYERS, MIN_MIX_LAYERS};
 
 pub fn try_update_rewarding_validator_address(
     deps: DepsMut<'_>,
@@ -48,6 +48,12 @@ pub(crate) fn try_update_contract_settings(
         return Err(MixnetContractError::Unauthorized);
     }
 
+    if !(MIN_MIX_LAYERS..=MAX_MIX_LAYERS).contains(&params.mix_layers) {
+        return Err(MixnetContractError::InvalidNumberOfMixLayers(
+            params.mix_layers,
+        ));
+    }
+
     let response = Response::new().add_event(new_settings_update_event(&state.params, &params));
 
     state.params = params;
@@ -124,6 +130,7 @@ pub mod tests {
                 denom,
                 amount: INITIAL_GATEWAY_PLEDGE_AMOUNT + Uint128::new(1234),
             },
+            mix_layers: 4,
         };
 
         let initial_params = storage::CONTRACT_STATE
@@ -151,6 +158,20 @@ pub mod tests {
         let current_state = storage::CONTRACT_STATE.load(deps.as_ref().storage).unwrap();
         assert_eq!(current_state.params, new_params);
 
+        // the number of mix layers must be within the supported range
+        for invalid_layers in [0, MIN_MIX_LAYERS - 1, MAX_MIX_LAYERS + 1] {
+            let info = mock_info("creator", &[]);
+            let mut invalid_params = new_params.clone();
+            invalid_params.mix_layers = invalid_layers;
+            let res = try_update_contract_settings(deps.as_mut(), info, invalid_params);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::InvalidNumberOfMixLayers(
+                    invalid_layers
+                ))
+            );
+        }
+
         // // error is thrown if rewarded set is smaller than the active set
         // let info = mock_info("creator", &[]);
         // let mut new_params = current_state.params.clone();
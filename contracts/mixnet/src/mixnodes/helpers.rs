@@ -154,6 +154,9 @@ pub(crate) fn cleanup_post_unbond_mixnode_storage(
         rewards_storage::MIXNODE_REWARDING.save(storage, mix_id, &zeroed)?;
     }
 
+    // the rewarding history is of no use once the node is gone
+    rewards_storage::remove_reward_history(storage, mix_id)?;
+
     let identity = current_details.bond_information.identity().to_owned();
     let owner = current_details.bond_information.owner().to_owned();
     let proxy = current_details.bond_information.proxy.to_owned();
@@ -179,7 +182,9 @@ pub(crate) mod tests {
         mix_node_cost_params_fixture, mix_node_fixture, TEST_COIN_DENOM,
     };
     use crate::support::tests::test_helpers::TestSetup;
-    use cosmwasm_std::{coin, Uint128};
+    use cosmwasm_std::{coin, Order, Uint128};
+    use mixnet_contract_common::rewarding::EpochRewardRecord;
+    use mixnet_contract_common::Percent;
 
     pub(crate) struct DummyMixnode {
         pub mix_id: MixId,
@@ -499,12 +504,40 @@ pub(crate) mod tests {
             .load(test.deps().storage, mix_id_leftover)
             .unwrap();
 
+        let reward_record = |absolute_epoch_id| EpochRewardRecord {
+            absolute_epoch_id,
+            operator: Decimal::one(),
+            delegates: Decimal::one(),
+            performance: Percent::hundred(),
+            work_factor: Decimal::one(),
+        };
+        for epoch_id in 0..3 {
+            for id in [mix_id, mix_id_leftover] {
+                rewards_storage::save_reward_record(
+                    test.deps_mut().storage,
+                    id,
+                    reward_record(epoch_id),
+                )
+                .unwrap();
+            }
+        }
+        let reward_history = |test: &TestSetup, id: MixId| {
+            rewards_storage::MIXNODE_REWARD_HISTORY
+                .prefix(id)
+                .keys(test.deps().storage, None, None, Order::Ascending)
+                .count()
+        };
+
         let env = test.env();
         let details1 = get_mixnode_details_by_id(test.deps().storage, mix_id)
             .unwrap()
             .unwrap();
         cleanup_post_unbond_mixnode_storage(test.deps_mut().storage, &env, &details1).unwrap();
 
+        // reward history is gone, but only of the unbonded node
+        assert_eq!(reward_history(&test, mix_id), 0);
+        assert_eq!(reward_history(&test, mix_id_leftover), 3);
+
         // bond information is gone
         let bond = storage::mixnode_bonds()
             .may_load(test.deps().storage, mix_id)
@@ -537,6 +570,9 @@ pub(crate) mod tests {
             .unwrap();
         cleanup_post_unbond_mixnode_storage(test.deps_mut().storage, &env, &details2).unwrap();
 
+        // reward history is gone even though the rewarding details are kept for the delegators
+        assert_eq!(reward_history(&test, mix_id_leftover), 0);
+
         // bond information is gone
         let bond = storage::mixnode_bonds()
             .may_load(test.deps().storage, mix_id_leftover)
@@ -7,6 +7,7 @@ use crate::constants::{
     PENDING_MIXNODE_CHANGES_NAMESPACE, UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE,
     UNBONDED_MIXNODES_OWNER_IDX_NAMESPACE, UNBONDED_MIXNODES_PK_NAMESPACE,
 };
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use cosmwasm_std::{StdResult, Storage};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
 use mixnet_contract_common::error::MixnetContractError;
@@ -98,9 +99,13 @@ pub fn decrement_layer_count(
 pub(crate) fn assign_layer(store: &mut dyn Storage) -> StdResult<Layer> {
     // load current distribution
     let mut layers = LAYERS.load(store)?;
+    let mix_layers = mixnet_params_storage::CONTRACT_STATE
+        .load(store)?
+        .params
+        .mix_layers;
 
     // choose the one with fewest nodes
-    let fewest = layers.choose_with_fewest();
+    let fewest = layers.choose_with_fewest(mix_layers);
 
     // increment the existing count
     layers.increment_layer_count(fewest);
@@ -125,6 +130,7 @@ mod tests {
     use super::*;
     use crate::support::tests::test_helpers;
     use cosmwasm_std::testing::mock_dependencies;
+    use mixnet_contract_common::{MAX_MIX_LAYERS, MIN_MIX_LAYERS};
 
     #[test]
     fn decrementing_layer() {
@@ -146,6 +152,7 @@ mod tests {
                     layer1: 3,
                     layer2: 2,
                     layer3: 1,
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -175,6 +182,7 @@ mod tests {
             layer1: 3,
             layer2: 2,
             layer3: 1,
+            ..Default::default()
         };
         LAYERS.save(deps.as_mut().storage, &layers).unwrap();
 
@@ -191,6 +199,35 @@ mod tests {
         assert_eq!(3, LAYERS.load(deps.as_ref().storage).unwrap().layer3);
     }
 
+    #[test]
+    fn assigning_layer_respects_configured_number_of_layers() {
+        for mix_layers in MIN_MIX_LAYERS..=MAX_MIX_LAYERS {
+            let mut deps = test_helpers::init_contract();
+
+            let mut state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_ref().storage)
+                .unwrap();
+            state.params.mix_layers = mix_layers;
+            mixnet_params_storage::CONTRACT_STATE
+                .save(deps.as_mut().storage, &state)
+                .unwrap();
+
+            // nodes get spread evenly across all configured layers and never beyond them
+            for _ in 0..mix_layers * 2 {
+                let layer = assign_layer(deps.as_mut().storage).unwrap();
+                assert!(u8::from(layer) <= mix_layers);
+            }
+
+            let distribution = LAYERS.load(deps.as_ref().storage).unwrap();
+            for layer in Layer::all(mix_layers) {
+                assert_eq!(2, distribution[layer]);
+            }
+            for layer in Layer::all(MAX_MIX_LAYERS).skip(mix_layers as usize) {
+                assert_eq!(0, distribution[layer]);
+            }
+        }
+    }
+
     #[test]
     fn next_id() {
         let mut deps = test_helpers::init_contract();
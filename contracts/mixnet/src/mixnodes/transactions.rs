@@ -25,8 +25,8 @@ use crate::mixnodes::signature_helpers::verify_mixnode_bonding_signature;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
     ensure_bonded, ensure_epoch_in_progress_state, ensure_is_authorized, ensure_no_existing_bond,
-    ensure_no_pending_pledge_changes, ensure_proxy_match, ensure_sent_by_vesting_contract,
-    validate_pledge,
+    ensure_no_pending_pledge_changes, ensure_no_pending_slashing, ensure_proxy_match,
+    ensure_sent_by_vesting_contract, validate_pledge,
 };
 
 use super::storage;
@@ -267,6 +267,8 @@ pub fn _try_decrease_pledge(
     ensure_proxy_match(&proxy, &mix_details.bond_information.proxy)?;
     ensure_bonded(&mix_details.bond_information)?;
     ensure_no_pending_pledge_changes(&pending_changes)?;
+    // the decrease would have been validated against the pledge from before the slashing
+    ensure_no_pending_slashing(&pending_changes)?;
 
     let minimum_pledge = mixnet_params_storage::minimum_mixnode_pledge(deps.storage)?;
 
@@ -353,6 +355,8 @@ pub(crate) fn _try_remove_mixnode(
 
     // if there are any pending requests to change the pledge, wait for them to resolve before allowing the unbonding
     ensure_no_pending_pledge_changes(&pending_changes)?;
+    // and the same is true for slashing, otherwise the operator could just escape it
+    ensure_no_pending_slashing(&pending_changes)?;
 
     // set `is_unbonding` field
     // clippy beta 1.70.0-beta.1 false positive
@@ -601,6 +605,7 @@ pub mod tests {
             layer1: 1,
             layer2: 1,
             layer3: 0,
+            ..Default::default()
         };
         assert_eq!(expected, storage::LAYERS.load(test.deps().storage).unwrap())
     }
@@ -870,6 +875,7 @@ pub mod tests {
         let mix_id = test.add_dummy_mixnode(owner, None);
         let pending_change = PendingMixNodeChanges {
             pledge_change: Some(1234),
+            slashing: None,
         };
         storage::PENDING_MIXNODE_CHANGES
             .save(test.deps_mut().storage, mix_id, &pending_change)
@@ -1425,6 +1431,7 @@ pub mod tests {
             let mix_id = test.add_dummy_mixnode(owner, None);
             let pending_change = PendingMixNodeChanges {
                 pledge_change: Some(1234),
+                slashing: None,
             };
             storage::PENDING_MIXNODE_CHANGES
                 .save(test.deps_mut().storage, mix_id, &pending_change)
@@ -1776,6 +1783,7 @@ pub mod tests {
             let mix_id = test.add_dummy_mixnode(owner, Some(stake));
             let pending_change = PendingMixNodeChanges {
                 pledge_change: Some(1234),
+                slashing: None,
             };
             storage::PENDING_MIXNODE_CHANGES
                 .save(test.deps_mut().storage, mix_id, &pending_change)
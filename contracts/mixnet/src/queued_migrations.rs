@@ -1,12 +1,16 @@
 // Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::mixnodes::storage as mixnodes_storage;
-use cosmwasm_std::DepsMut;
+use crate::rewards::storage as rewards_storage;
+use cosmwasm_std::{DepsMut, Order};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::PendingMixNodeChanges;
-use mixnet_contract_common::PendingEpochEventKind;
+use mixnet_contract_common::{
+    GatewayBond, GatewayRewarding, GatewayRewardingParams, PendingEpochEventKind,
+};
 use std::collections::BTreeMap;
 
 pub fn insert_pending_pledge_changes(deps: DepsMut<'_>) -> Result<(), MixnetContractError> {
@@ -34,9 +38,48 @@ pub fn insert_pending_pledge_changes(deps: DepsMut<'_>) -> Result<(), MixnetCont
             mix_id,
             &PendingMixNodeChanges {
                 pledge_change: Some(event_id),
+                slashing: None,
             },
         )?;
     }
 
     Ok(())
 }
+
+pub fn initialise_gateway_rewarding(deps: DepsMut<'_>) -> Result<(), MixnetContractError> {
+    if rewards_storage::GATEWAY_REWARDING_PARAMS
+        .may_load(deps.storage)?
+        .is_none()
+    {
+        let rewarding_params = rewards_storage::REWARDING_PARAMS.load(deps.storage)?;
+        rewards_storage::GATEWAY_REWARDING_PARAMS.save(
+            deps.storage,
+            &GatewayRewardingParams::new_disabled(rewarding_params.interval.stake_saturation_point),
+        )?;
+    }
+
+    let current_epoch =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let bonds = gateways_storage::gateways()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|(_, bond)| bond))
+        .collect::<Result<Vec<GatewayBond>, _>>()?;
+    gateways_storage::BONDED_GATEWAYS_COUNT.save(deps.storage, &(bonds.len() as u32))?;
+
+    for bond in bonds {
+        if rewards_storage::GATEWAY_REWARDING
+            .may_load(deps.storage, bond.identity())?
+            .is_none()
+        {
+            let gateway_rewarding =
+                GatewayRewarding::initialise_new(&bond.pledge_amount, current_epoch)?;
+            rewards_storage::GATEWAY_REWARDING.save(
+                deps.storage,
+                bond.identity(),
+                &gateway_rewarding,
+            )?;
+        }
+    }
+
+    Ok(())
+}
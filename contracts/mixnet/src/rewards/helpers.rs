@@ -4,7 +4,10 @@
 use super::storage;
 use crate::delegations::storage as delegations_storage;
 use crate::interval::storage as interval_storage;
-use cosmwasm_std::{Coin, Storage};
+use crate::rewards::models::CompoundingProgress;
+use cosmwasm_std::{Coin, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::delegation::StorageKey;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::helpers::IntoBaseDecimal;
 use mixnet_contract_common::mixnode::{MixNodeDetails, MixNodeRewarding};
@@ -86,6 +89,77 @@ pub(crate) fn withdraw_delegator_reward(
     Ok(reward)
 }
 
+fn compound_delegator_reward(
+    store: &mut dyn Storage,
+    storage_key: StorageKey,
+) -> Result<(), MixnetContractError> {
+    let delegation =
+        match delegations_storage::delegations().may_load(store, storage_key.clone())? {
+            Some(delegation) => delegation,
+            None => {
+                // this shouldn't ever happen, but if the delegation is gone, there's nothing to compound
+                delegations_storage::AUTO_COMPOUNDING_DELEGATIONS.remove(store, storage_key);
+                return Ok(());
+            }
+        };
+
+    let mix_id = delegation.mix_id;
+    let mut mix_rewarding = storage::MIXNODE_REWARDING.may_load(store, mix_id)?.ok_or(MixnetContractError::inconsistent_state(
+        "mixnode rewarding got removed from the storage whilst there's still an existing delegation"
+    ))?;
+
+    let mut updated_delegation = delegation.clone();
+    mix_rewarding.compound_delegator_reward(&mut updated_delegation)?;
+
+    delegations_storage::delegations().replace(
+        store,
+        storage_key,
+        Some(&updated_delegation),
+        Some(&delegation),
+    )?;
+    storage::MIXNODE_REWARDING.save(store, mix_id, &mix_rewarding)?;
+    Ok(())
+}
+
+/// Folds the pending rewards into all auto-compounding delegations, carrying on from wherever
+/// the previous call (within the same epoch) has stopped.
+/// Returns the number of processed delegations and whether all of them have been compounded
+/// for the current epoch.
+pub(crate) fn compound_delegator_rewards(
+    store: &mut dyn Storage,
+    limit: Option<u32>,
+) -> Result<(u32, bool), MixnetContractError> {
+    let epoch_id = interval_storage::current_interval(store)?.current_epoch_absolute_id();
+    let mut progress = match storage::COMPOUNDING_PROGRESS.may_load(store)? {
+        Some(progress) if progress.epoch_id == epoch_id => progress,
+        _ => CompoundingProgress::new(epoch_id),
+    };
+
+    if progress.finished {
+        return Ok((0, true));
+    }
+
+    let limit = limit.map(|l| l as usize).unwrap_or(usize::MAX);
+    let start = progress.last_processed.clone().map(Bound::exclusive);
+
+    // retrieve one key more than we're going to process to know whether we're done
+    let mut keys = delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+        .keys(store, start, None, Order::Ascending)
+        .take(limit.saturating_add(1))
+        .collect::<StdResult<Vec<_>>>()?;
+    progress.finished = keys.len() <= limit;
+    keys.truncate(limit);
+
+    let processed = keys.len() as u32;
+    for storage_key in keys {
+        compound_delegator_reward(store, storage_key.clone())?;
+        progress.last_processed = Some(storage_key);
+    }
+
+    storage::COMPOUNDING_PROGRESS.save(store, &progress)?;
+    Ok((processed, progress.finished))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,4 +369,126 @@ mod tests {
             delegation_post.cumulative_reward_ratio
         )
     }
+
+    #[cfg(test)]
+    mod compounding_delegator_rewards {
+        use super::*;
+        use cosmwasm_std::{Addr, Decimal};
+
+        fn enable_auto_compounding(test: &mut TestSetup, mix_id: MixId, delegator: &str) {
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(delegator), None);
+            delegations_storage::AUTO_COMPOUNDING_DELEGATIONS
+                .save(test.deps_mut().storage, storage_key, &1)
+                .unwrap();
+        }
+
+        #[test]
+        fn folds_rewards_into_auto_compounding_delegations() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000_000)));
+            let og_amount = Uint128::new(200_000_000);
+            for delegator in ["alice", "bob", "carol"] {
+                test.add_immediate_delegation(delegator, og_amount, mix_id);
+            }
+            enable_auto_compounding(&mut test, mix_id, "alice");
+            enable_auto_compounding(&mut test, mix_id, "bob");
+
+            test.skip_to_next_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.reward_with_distribution_with_state_bypass(mix_id, performance(100.0));
+
+            let alice_reward = test.pending_delegator_reward("alice", mix_id);
+            let carol_reward = test.pending_delegator_reward("carol", mix_id);
+            assert!(!alice_reward.is_zero());
+
+            let res = compound_delegator_rewards(test.deps_mut().storage, None).unwrap();
+            assert_eq!(res, (2, true));
+
+            let alice = test.delegation(mix_id, "alice", &None);
+            assert_eq!(
+                alice.amount.amount,
+                og_amount + truncate_reward_amount(alice_reward)
+            );
+            assert_eq!(
+                test.pending_delegator_reward("alice", mix_id),
+                Decimal::zero()
+            );
+
+            // carol hasn't opted in, so her rewards are still waiting to be withdrawn
+            let carol = test.delegation(mix_id, "carol", &None);
+            assert_eq!(carol.amount.amount, og_amount);
+            assert_eq!(test.pending_delegator_reward("carol", mix_id), carol_reward);
+
+            // and the node's delegates pool still consists of all delegations and their rewards
+            let mix_rewarding = test.mix_rewarding(mix_id);
+            let mut total = Decimal::zero();
+            for delegator in ["alice", "bob", "carol"] {
+                let delegation = test.delegation(mix_id, delegator, &None);
+                total += delegation.dec_amount().unwrap()
+                    + mix_rewarding
+                        .determine_delegation_reward(&delegation)
+                        .unwrap();
+            }
+            assert_decimals(total, mix_rewarding.delegates);
+        }
+
+        #[test]
+        fn obeys_the_limit_and_resumes_where_it_stopped() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(100_000_000_000)));
+            for i in 0..5 {
+                let delegator = format!("delegator{i}");
+                test.add_immediate_delegation(&delegator, 200_000_000u32, mix_id);
+                enable_auto_compounding(&mut test, mix_id, &delegator);
+            }
+
+            test.skip_to_next_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.reward_with_distribution_with_state_bypass(mix_id, performance(100.0));
+
+            let store = test.deps_mut().storage;
+            assert_eq!(
+                compound_delegator_rewards(store, Some(2)).unwrap(),
+                (2, false)
+            );
+            assert_eq!(
+                compound_delegator_rewards(store, Some(2)).unwrap(),
+                (2, false)
+            );
+            assert_eq!(
+                compound_delegator_rewards(store, Some(2)).unwrap(),
+                (1, true)
+            );
+
+            // the rewards can't be compounded twice in the same epoch
+            assert_eq!(compound_delegator_rewards(store, None).unwrap(), (0, true));
+            for i in 0..5 {
+                let delegation = test.delegation(mix_id, &format!("delegator{i}"), &None);
+                assert!(delegation.amount.amount > Uint128::new(200_000_000));
+            }
+
+            // but they're compounded again once the next epoch is over
+            test.skip_to_next_epoch_end();
+            test.reward_with_distribution_with_state_bypass(mix_id, performance(100.0));
+            let store = test.deps_mut().storage;
+            assert_eq!(
+                compound_delegator_rewards(store, Some(5)).unwrap(),
+                (5, true)
+            );
+        }
+
+        #[test]
+        fn is_finished_if_there_are_no_auto_compounding_delegations() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation("alice", 200_000_000u32, mix_id);
+
+            let store = test.deps_mut().storage;
+            assert_eq!(
+                compound_delegator_rewards(store, Some(0)).unwrap(),
+                (0, true)
+            );
+        }
+    }
 }
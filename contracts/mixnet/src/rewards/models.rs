@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use cosmwasm_std::Decimal;
+use mixnet_contract_common::delegation::StorageKey;
+use mixnet_contract_common::EpochId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
@@ -10,9 +12,31 @@ pub(crate) struct RewardPoolChange {
     /// upon the current interval finishing.
     pub removed: Decimal,
 
-    // this will be used once coconut credentials are in use;
+    // this is currently used for redistributing slashed tokens
+    // (and will also be used once coconut credentials are in use);
     /// Indicates amount that shall get added to the reward pool and not touch the staking supply
     /// upon the current interval finishing.
-    #[allow(unused)]
     pub added: Decimal,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub(crate) struct CompoundingProgress {
+    /// Absolute id of the epoch at the end of which the rewards are being compounded.
+    pub epoch_id: EpochId,
+
+    /// Storage key of the last delegation that had its rewards compounded.
+    pub last_processed: Option<StorageKey>,
+
+    /// Indicates whether all auto-compounding delegations have been processed in this epoch.
+    pub finished: bool,
+}
+
+impl CompoundingProgress {
+    pub fn new(epoch_id: EpochId) -> Self {
+        CompoundingProgress {
+            epoch_id,
+            last_processed: None,
+            finished: false,
+        }
+    }
+}
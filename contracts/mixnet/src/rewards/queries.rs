@@ -2,19 +2,28 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::storage;
+use crate::constants::{
+    REWARD_HISTORY_DEFAULT_RETRIEVAL_LIMIT, REWARD_HISTORY_MAX_RETRIEVAL_LIMIT,
+};
 use crate::delegations::storage as delegations_storage;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::mixnodes;
 use crate::mixnodes::storage as mixnodes_storage;
-use cosmwasm_std::{coin, Coin, Decimal, Deps, StdResult};
+use cosmwasm_std::{coin, Coin, Decimal, Deps, Order, StdResult};
+use cw_storage_plus::Bound;
 use mixnet_contract_common::helpers::into_base_decimal;
 use mixnet_contract_common::mixnode::MixNodeDetails;
 use mixnet_contract_common::reward_params::{NodeRewardParams, Performance, RewardingParams};
 use mixnet_contract_common::rewarding::helpers::truncate_reward;
 use mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    EpochRewardRecord, EstimatedCurrentEpochRewardResponse, PagedMixnodeRewardHistoryResponse,
+    PendingGatewayRewardResponse, PendingRewardResponse,
+};
+use mixnet_contract_common::{
+    Delegation, EpochId, GatewayDelegation, GatewayRewardingDetailsResponse,
+    GatewayRewardingParams, IdentityKey, MixId,
 };
-use mixnet_contract_common::{Delegation, MixId};
 
 pub(crate) fn query_rewarding_params(deps: Deps<'_>) -> StdResult<RewardingParams> {
     storage::REWARDING_PARAMS.load(deps.storage)
@@ -134,7 +143,7 @@ pub(crate) fn query_estimated_current_epoch_operator_reward(
         return Ok(zero_reward(amount_staked, current_value));
     }
 
-    let rewarding_params = storage::REWARDING_PARAMS.load(deps.storage)?;
+    let rewarding_params = storage::mixnode_rewarding_params(deps.storage)?;
     let interval = interval_storage::current_interval(deps.storage)?;
 
     let node_reward_params = NodeRewardParams::new(estimated_performance, node_status.is_active());
@@ -203,7 +212,7 @@ pub(crate) fn query_estimated_current_epoch_delegator_reward(
         return Ok(zero_reward(amount_staked, current_value));
     }
 
-    let rewarding_params = storage::REWARDING_PARAMS.load(deps.storage)?;
+    let rewarding_params = storage::mixnode_rewarding_params(deps.storage)?;
     let interval = interval_storage::current_interval(deps.storage)?;
 
     let node_reward_params = NodeRewardParams::new(estimated_performance, node_status.is_active());
@@ -229,6 +238,108 @@ pub(crate) fn query_estimated_current_epoch_delegator_reward(
     })
 }
 
+pub(crate) fn query_gateway_rewarding_params(deps: Deps<'_>) -> StdResult<GatewayRewardingParams> {
+    storage::GATEWAY_REWARDING_PARAMS.load(deps.storage)
+}
+
+pub(crate) fn query_gateway_rewarding_details(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+) -> StdResult<GatewayRewardingDetailsResponse> {
+    let rewarding_details = storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)?;
+    Ok(GatewayRewardingDetailsResponse {
+        identity,
+        rewarding_details,
+    })
+}
+
+pub(crate) fn query_pending_gateway_operator_reward(
+    deps: Deps<'_>,
+    owner: String,
+) -> StdResult<PendingGatewayRewardResponse> {
+    let owner_address = deps.api.addr_validate(&owner)?;
+    let bond = match gateways_storage::gateways()
+        .idx
+        .owner
+        .item(deps.storage, owner_address)?
+    {
+        Some(record) => record.1,
+        None => return Ok(PendingGatewayRewardResponse::default()),
+    };
+
+    let gateway_rewarding =
+        match storage::GATEWAY_REWARDING.may_load(deps.storage, bond.identity())? {
+            Some(gateway_rewarding) => gateway_rewarding,
+            None => return Ok(PendingGatewayRewardResponse::default()),
+        };
+
+    let pledge_dec = into_base_decimal(bond.pledge_amount.amount)?;
+    Ok(PendingGatewayRewardResponse {
+        amount_earned: Some(gateway_rewarding.pending_operator_reward(&bond.pledge_amount)),
+        amount_earned_detailed: Some(gateway_rewarding.operator - pledge_dec),
+        amount_staked: Some(bond.pledge_amount),
+        gateway_still_bonded: true,
+    })
+}
+
+pub(crate) fn query_pending_gateway_delegator_reward(
+    deps: Deps<'_>,
+    owner: String,
+    identity: IdentityKey,
+) -> StdResult<PendingGatewayRewardResponse> {
+    let owner_address = deps.api.addr_validate(&owner)?;
+
+    let gateway_rewarding = match storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)? {
+        Some(gateway_rewarding) => gateway_rewarding,
+        None => return Ok(PendingGatewayRewardResponse::default()),
+    };
+
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &owner_address);
+    let delegation =
+        match delegations_storage::gateway_delegations().may_load(deps.storage, storage_key)? {
+            Some(delegation) => delegation,
+            None => return Ok(PendingGatewayRewardResponse::default()),
+        };
+
+    let detailed_reward = gateway_rewarding.determine_delegation_reward(&delegation)?;
+    let delegator_reward = gateway_rewarding.pending_delegator_reward(&delegation)?;
+
+    Ok(PendingGatewayRewardResponse {
+        amount_staked: Some(delegation.amount),
+        amount_earned: Some(delegator_reward),
+        amount_earned_detailed: Some(detailed_reward),
+        gateway_still_bonded: gateway_rewarding.still_bonded(),
+    })
+}
+
+pub(crate) fn query_mixnode_reward_history_paged(
+    deps: Deps<'_>,
+    mix_id: MixId,
+    start_after: Option<EpochId>,
+    limit: Option<u32>,
+) -> StdResult<PagedMixnodeRewardHistoryResponse> {
+    let limit = limit
+        .unwrap_or(REWARD_HISTORY_DEFAULT_RETRIEVAL_LIMIT)
+        .min(REWARD_HISTORY_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let records = storage::MIXNODE_REWARD_HISTORY
+        .prefix(mix_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|row| row.1))
+        .collect::<StdResult<Vec<EpochRewardRecord>>>()?;
+
+    let start_next_after = records.last().map(|record| record.absolute_epoch_id);
+
+    Ok(PagedMixnodeRewardHistoryResponse {
+        mix_id,
+        records,
+        start_next_after,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1172,4 +1283,127 @@ mod tests {
             assert_eq!(ress[2], expected3);
         }
     }
+
+    #[cfg(test)]
+    mod querying_for_mixnode_reward_history {
+        use super::*;
+        use crate::constants::MIXNODE_REWARD_HISTORY_LENGTH;
+        use crate::rewards::transactions::try_reward_mixnode;
+        use crate::support::tests::test_helpers::performance;
+        use mixnet_contract_common::rewarding::RewardDistribution;
+
+        fn dummy_record(absolute_epoch_id: EpochId) -> EpochRewardRecord {
+            EpochRewardRecord {
+                absolute_epoch_id,
+                operator: Decimal::one(),
+                delegates: Decimal::one(),
+                performance: performance(100.0),
+                work_factor: Decimal::one(),
+            }
+        }
+
+        #[test]
+        fn for_non_existent_node() {
+            let test = TestSetup::new();
+            let res = query_mixnode_reward_history_paged(test.deps(), 42, None, None).unwrap();
+            assert!(res.records.is_empty());
+            assert!(res.start_next_after.is_none());
+        }
+
+        #[test]
+        fn contains_results_of_every_rewarding() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000)));
+            test.add_immediate_delegation("delegator", 100_000_000u32, mix_id);
+            test.force_change_rewarded_set(vec![mix_id]);
+
+            let mut expected = Vec::new();
+            for perf in [100.0, 0.0, 42.0] {
+                test.skip_to_next_epoch_end();
+                let dist = if perf == 0.0 {
+                    // zero performance rewarding doesn't emit the usual rewarding event
+                    test.start_epoch_transition();
+                    let env = test.env();
+                    let sender = test.rewarding_validator();
+                    try_reward_mixnode(test.deps_mut(), env, sender, mix_id, performance(perf))
+                        .unwrap();
+                    test.set_epoch_in_progress_state();
+                    RewardDistribution::default()
+                } else {
+                    test.reward_with_distribution_with_state_bypass(mix_id, performance(perf))
+                };
+                let work_factor = test.rewarding_params().active_node_work();
+                expected.push(EpochRewardRecord {
+                    absolute_epoch_id: test.current_interval().current_epoch_absolute_id(),
+                    operator: dist.operator,
+                    delegates: dist.delegates,
+                    performance: performance(perf),
+                    work_factor,
+                })
+            }
+
+            let res = query_mixnode_reward_history_paged(test.deps(), mix_id, None, None).unwrap();
+            assert_eq!(res.mix_id, mix_id);
+            assert_eq!(res.records, expected);
+            assert!(res.records[1].operator.is_zero());
+            assert!(res.records[1].delegates.is_zero());
+
+            // and it's paged
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id, None, Some(2)).unwrap();
+            assert_eq!(res.records, expected[..2]);
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id, res.start_next_after, None)
+                    .unwrap();
+            assert_eq!(res.records, expected[2..]);
+        }
+
+        #[test]
+        fn only_most_recent_epochs_are_retained() {
+            let mut test = TestSetup::new();
+            let mix_id = 1;
+
+            for epoch in 0..MIXNODE_REWARD_HISTORY_LENGTH {
+                storage::save_reward_record(test.deps_mut().storage, mix_id, dummy_record(epoch))
+                    .unwrap();
+            }
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id, None, Some(1)).unwrap();
+            assert_eq!(res.records[0].absolute_epoch_id, 0);
+
+            storage::save_reward_record(
+                test.deps_mut().storage,
+                mix_id,
+                dummy_record(MIXNODE_REWARD_HISTORY_LENGTH),
+            )
+            .unwrap();
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id, None, Some(1)).unwrap();
+            assert_eq!(res.records[0].absolute_epoch_id, 1);
+
+            // gaps in the history (e.g. node not being in the rewarded set) are also handled
+            storage::save_reward_record(
+                test.deps_mut().storage,
+                mix_id,
+                dummy_record(MIXNODE_REWARD_HISTORY_LENGTH + 100),
+            )
+            .unwrap();
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id, None, Some(1)).unwrap();
+            assert_eq!(res.records[0].absolute_epoch_id, 101);
+
+            // and records of other nodes are not affected
+            storage::save_reward_record(test.deps_mut().storage, mix_id + 1, dummy_record(0))
+                .unwrap();
+            storage::save_reward_record(
+                test.deps_mut().storage,
+                mix_id,
+                dummy_record(MIXNODE_REWARD_HISTORY_LENGTH + 101),
+            )
+            .unwrap();
+            let res =
+                query_mixnode_reward_history_paged(test.deps(), mix_id + 1, None, None).unwrap();
+            assert_eq!(res.records.len(), 1);
+        }
+    }
 }
@@ -2,15 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::constants::{
-    MIXNODES_REWARDING_PK_NAMESPACE, PENDING_REWARD_POOL_KEY, REWARDING_PARAMS_KEY,
+    COMPOUNDING_PROGRESS_KEY, EPOCH_GATEWAY_REWARD_KEY, GATEWAYS_REWARDING_PK_NAMESPACE,
+    GATEWAY_REWARDING_PARAMS_KEY, MIXNODES_REWARDING_PK_NAMESPACE,
+    MIXNODES_REWARD_HISTORY_NAMESPACE, MIXNODE_REWARD_HISTORY_LENGTH, PENDING_REWARD_POOL_KEY,
+    REWARDING_PARAMS_KEY,
 };
-use crate::rewards::models::RewardPoolChange;
-use cosmwasm_std::{Decimal, StdResult, Storage};
-use cw_storage_plus::{Item, Map};
+use crate::gateways::storage as gateways_storage;
+use crate::rewards::models::{CompoundingProgress, RewardPoolChange};
+use cosmwasm_std::{Decimal, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::MixNodeRewarding;
 use mixnet_contract_common::reward_params::RewardingParams;
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::rewarding::EpochRewardRecord;
+use mixnet_contract_common::{
+    EpochId, GatewayRewarding, GatewayRewardingParams, IdentityKeyRef, MixId,
+};
 
 // current parameters used for rewarding purposes
 pub(crate) const REWARDING_PARAMS: Item<'_, RewardingParams> = Item::new(REWARDING_PARAMS_KEY);
@@ -20,6 +27,25 @@ pub(crate) const PENDING_REWARD_POOL_CHANGE: Item<'_, RewardPoolChange> =
 pub const MIXNODE_REWARDING: Map<MixId, MixNodeRewarding> =
     Map::new(MIXNODES_REWARDING_PK_NAMESPACE);
 
+// keeps the results of rewarding of the last `MIXNODE_REWARD_HISTORY_LENGTH` epochs of every mixnode
+pub(crate) const MIXNODE_REWARD_HISTORY: Map<(MixId, EpochId), EpochRewardRecord> =
+    Map::new(MIXNODES_REWARD_HISTORY_NAMESPACE);
+
+// current parameters used for rewarding gateways
+pub(crate) const GATEWAY_REWARDING_PARAMS: Item<'_, GatewayRewardingParams> =
+    Item::new(GATEWAY_REWARDING_PARAMS_KEY);
+
+pub const GATEWAY_REWARDING: Map<IdentityKeyRef<'_>, GatewayRewarding> =
+    Map::new(GATEWAYS_REWARDING_PK_NAMESPACE);
+
+// reward of a single gateway with perfect performance and saturation, fixed for the given (absolute) epoch
+pub(crate) const EPOCH_GATEWAY_REWARD: Item<'_, (EpochId, Decimal)> =
+    Item::new(EPOCH_GATEWAY_REWARD_KEY);
+
+// keeps track of how far we got with compounding delegator rewards at the end of the current epoch
+pub(crate) const COMPOUNDING_PROGRESS: Item<'_, CompoundingProgress> =
+    Item::new(COMPOUNDING_PROGRESS_KEY);
+
 pub fn reward_accounting(
     storage: &mut dyn Storage,
     amount: Decimal,
@@ -30,10 +56,87 @@ pub fn reward_accounting(
     Ok(PENDING_REWARD_POOL_CHANGE.save(storage, &pending_changes)?)
 }
 
+/// Determines the reward of a single gateway for the provided epoch by splitting the gateway
+/// reward pool between all bonded gateways. The value is fixed the first time it's requested
+/// in the epoch, so that gateways (un)bonding halfway through the rewarding could not
+/// change the share of the others and push the total above the pool.
+pub(crate) fn epoch_reward_per_gateway(
+    storage: &mut dyn Storage,
+    absolute_epoch_id: EpochId,
+    params: &GatewayRewardingParams,
+) -> StdResult<Decimal> {
+    if let Some((epoch_id, reward)) = EPOCH_GATEWAY_REWARD.may_load(storage)? {
+        if epoch_id == absolute_epoch_id {
+            return Ok(reward);
+        }
+    }
+
+    let epoch_reward_budget = REWARDING_PARAMS.load(storage)?.interval.epoch_reward_budget;
+    let bonded_gateways = gateways_storage::bonded_gateways_count(storage)?;
+    let reward = params.epoch_reward_per_gateway(epoch_reward_budget, bonded_gateways);
+    EPOCH_GATEWAY_REWARD.save(storage, &(absolute_epoch_id, reward))?;
+    Ok(reward)
+}
+
+/// Rewarding parameters used for determining the mixnode rewards, i.e. with the gateway reward pool
+/// taken out of the epoch reward budget.
+pub(crate) fn mixnode_rewarding_params(storage: &dyn Storage) -> StdResult<RewardingParams> {
+    let mut rewarding_params = REWARDING_PARAMS.load(storage)?;
+    let gateway_params = GATEWAY_REWARDING_PARAMS.load(storage)?;
+    rewarding_params.interval.epoch_reward_budget =
+        gateway_params.mixnode_epoch_reward_budget(rewarding_params.interval.epoch_reward_budget);
+    Ok(rewarding_params)
+}
+
+/// Saves the rewarding results of the mixnode for the particular epoch and prunes
+/// any of its records that fell outside the retained history window.
+pub(crate) fn save_reward_record(
+    storage: &mut dyn Storage,
+    mix_id: MixId,
+    record: EpochRewardRecord,
+) -> StdResult<()> {
+    MIXNODE_REWARD_HISTORY.save(storage, (mix_id, record.absolute_epoch_id), &record)?;
+
+    if let Some(oldest_retained) = record
+        .absolute_epoch_id
+        .checked_sub(MIXNODE_REWARD_HISTORY_LENGTH - 1)
+    {
+        // in the usual case there's going to be at most a single stale record here
+        let stale = MIXNODE_REWARD_HISTORY
+            .prefix(mix_id)
+            .keys(
+                storage,
+                None,
+                Some(Bound::exclusive(oldest_retained)),
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()?;
+        for epoch_id in stale {
+            MIXNODE_REWARD_HISTORY.remove(storage, (mix_id, epoch_id));
+        }
+    }
+    Ok(())
+}
+
+/// Removes all the rewarding records of the mixnode, for example once it has unbonded.
+pub(crate) fn remove_reward_history(storage: &mut dyn Storage, mix_id: MixId) -> StdResult<()> {
+    let epochs = MIXNODE_REWARD_HISTORY
+        .prefix(mix_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for epoch_id in epochs {
+        MIXNODE_REWARD_HISTORY.remove(storage, (mix_id, epoch_id));
+    }
+    Ok(())
+}
+
 pub(crate) fn initialise_storage(
     storage: &mut dyn Storage,
     reward_params: RewardingParams,
 ) -> StdResult<()> {
+    let gateway_params =
+        GatewayRewardingParams::new_disabled(reward_params.interval.stake_saturation_point);
+    GATEWAY_REWARDING_PARAMS.save(storage, &gateway_params)?;
     REWARDING_PARAMS.save(storage, &reward_params)?;
     PENDING_REWARD_POOL_CHANGE.save(storage, &RewardPoolChange::default())
 }
@@ -1,24 +1,32 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{wasm_execute, Addr, Decimal, DepsMut, Env, MessageInfo, Response};
 
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_active_set_update_event, new_mix_rewarding_event,
+    new_active_set_update_event, new_already_rewarded_gateway_rewarding_event,
+    new_gateway_rewarding_event, new_gateway_rewarding_params_update_event,
+    new_mix_rewarding_event, new_not_found_gateway_rewarding_event,
     new_not_found_mix_operator_rewarding_event, new_pending_active_set_update_event,
     new_pending_rewarding_params_update_event, new_rewarding_params_update_event,
-    new_withdraw_delegator_reward_event, new_withdraw_operator_reward_event,
-    new_zero_uptime_mix_operator_rewarding_event,
+    new_withdraw_delegator_reward_event, new_withdraw_gateway_delegator_reward_event,
+    new_withdraw_gateway_operator_reward_event, new_withdraw_operator_reward_event,
+    new_zero_uptime_gateway_rewarding_event, new_zero_uptime_mix_operator_rewarding_event,
 };
 use mixnet_contract_common::pending_events::{PendingEpochEventKind, PendingIntervalEventKind};
 use mixnet_contract_common::reward_params::{
     IntervalRewardingParamsUpdate, NodeRewardParams, Performance,
 };
-use mixnet_contract_common::{Delegation, EpochState, MixId};
+use mixnet_contract_common::rewarding::EpochRewardRecord;
+use mixnet_contract_common::{
+    Delegation, EpochState, GatewayDelegation, GatewayRewardingParams, IdentityKey, MixId,
+};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
 use crate::delegations::storage as delegations_storage;
+use crate::gateways::helpers::must_get_gateway_bond_by_owner;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::interval::storage::{push_new_epoch_event, push_new_interval_event};
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
@@ -99,11 +107,25 @@ pub(crate) fn try_reward_mixnode(
             absolute_epoch_id,
         })?;
 
+    let rewarding_params = storage::mixnode_rewarding_params(deps.storage)?;
+    let work_factor = rewarding_params.node_work(node_status.is_active());
+
     // no need to calculate anything as rewards are going to be 0 for everything
-    // however, we still need to update last_rewarded_epoch field
+    // however, we still need to update last_rewarded_epoch field (and keep the history record)
     if node_performance.is_zero() {
         mix_rewarding.last_rewarded_epoch = absolute_epoch_id;
         storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
+        storage::save_reward_record(
+            deps.storage,
+            mix_id,
+            EpochRewardRecord {
+                absolute_epoch_id,
+                operator: Decimal::zero(),
+                delegates: Decimal::zero(),
+                performance: node_performance,
+                work_factor,
+            },
+        )?;
         return Ok(
             Response::new().add_event(new_zero_uptime_mix_operator_rewarding_event(
                 interval, mix_id,
@@ -111,7 +133,6 @@ pub(crate) fn try_reward_mixnode(
         );
     }
 
-    let rewarding_params = storage::REWARDING_PARAMS.load(deps.storage)?;
     let node_reward_params = NodeRewardParams::new(node_performance, node_status.is_active());
 
     // calculate each step separate for easier accounting
@@ -126,6 +147,17 @@ pub(crate) fn try_reward_mixnode(
     // persist changes happened to the storage
     storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
     storage::reward_accounting(deps.storage, node_reward)?;
+    storage::save_reward_record(
+        deps.storage,
+        mix_id,
+        EpochRewardRecord {
+            absolute_epoch_id,
+            operator: reward_distribution.operator,
+            delegates: reward_distribution.delegates,
+            performance: node_performance,
+            work_factor,
+        },
+    )?;
 
     Ok(Response::new().add_event(new_mix_rewarding_event(
         interval,
@@ -376,6 +408,197 @@ pub(crate) fn try_update_rewarding_params(
     }
 }
 
+pub(crate) fn try_reward_gateway(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    identity: IdentityKey,
+    performance: Performance,
+) -> Result<Response, MixnetContractError> {
+    // gateways are rewarded by the same entity and at the same time as mixnodes,
+    // i.e. after the epoch is over, but before the pending events are reconciled
+    // (so that any changes to the delegations would only affect the next epoch).
+    // note that rewarding the final mixnode moves the epoch into reconciliation,
+    // so gateways have to be rewarded before that happens
+    let current_epoch_status = ensure_can_advance_epoch(&info.sender, deps.storage)?;
+    if !matches!(current_epoch_status.state, EpochState::Rewarding { .. }) {
+        return Err(MixnetContractError::EpochNotInGatewayRewardingState {
+            current_state: current_epoch_status.state,
+        });
+    }
+
+    let interval = interval_storage::current_interval(deps.storage)?;
+    let absolute_epoch_id = interval.current_epoch_absolute_id();
+
+    let mut gateway_rewarding =
+        match storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)? {
+            Some(gateway_rewarding) if gateway_rewarding.still_bonded() => gateway_rewarding,
+            // don't fail if the gateway has unbonded as we don't want to fail the underlying transaction
+            _ => {
+                return Ok(Response::new()
+                    .add_event(new_not_found_gateway_rewarding_event(interval, &identity)));
+            }
+        };
+
+    // unlike mixnodes, gateways are not tracked by the epoch state, so it's not an error to attempt
+    // to reward the same gateway multiple times, say if the rewarding validator has crashed mid-way.
+    // note: freshly bonded gateways are also marked as already rewarded in the epoch they bonded in.
+    if absolute_epoch_id == gateway_rewarding.last_rewarded_epoch {
+        return Ok(
+            Response::new().add_event(new_already_rewarded_gateway_rewarding_event(
+                interval, &identity,
+            )),
+        );
+    }
+
+    // no need to calculate anything as rewards are going to be 0 for everything
+    // however, we still need to update last_rewarded_epoch field
+    if performance.is_zero() {
+        gateway_rewarding.last_rewarded_epoch = absolute_epoch_id;
+        storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+        return Ok(
+            Response::new().add_event(new_zero_uptime_gateway_rewarding_event(interval, &identity))
+        );
+    }
+
+    let prior_delegates = gateway_rewarding.delegates;
+    let prior_unit_reward = gateway_rewarding.total_unit_reward;
+
+    let rewarding_params = storage::GATEWAY_REWARDING_PARAMS.load(deps.storage)?;
+    let epoch_reward_per_gateway =
+        storage::epoch_reward_per_gateway(deps.storage, absolute_epoch_id, &rewarding_params)?;
+    let gateway_reward =
+        gateway_rewarding.gateway_reward(&rewarding_params, epoch_reward_per_gateway, performance);
+    let reward_distribution = gateway_rewarding.determine_reward_split(gateway_reward);
+    gateway_rewarding.distribute_rewards(reward_distribution, absolute_epoch_id);
+
+    // persist changes happened to the storage
+    storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+    storage::reward_accounting(deps.storage, gateway_reward)?;
+
+    Ok(Response::new().add_event(new_gateway_rewarding_event(
+        interval,
+        &identity,
+        reward_distribution,
+        prior_delegates,
+        prior_unit_reward,
+    )))
+}
+
+pub(crate) fn try_withdraw_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+    let bond = must_get_gateway_bond_by_owner(deps.storage, &owner)?;
+
+    // gateways bonded with the vesting tokens get their rewards back alongside their pledge when unbonding
+    ensure_proxy_match(&None, &bond.proxy)?;
+
+    let mut gateway_rewarding = storage::GATEWAY_REWARDING
+        .may_load(deps.storage, bond.identity())?
+        .ok_or(MixnetContractError::inconsistent_state(
+            "gateway rewarding does not exist for a bonded gateway",
+        ))?;
+    let reward = gateway_rewarding.withdraw_operator_reward(&bond.pledge_amount)?;
+    storage::GATEWAY_REWARDING.save(deps.storage, bond.identity(), &gateway_rewarding)?;
+
+    let mut response = Response::new();
+
+    // if the reward is zero, don't send anything - there's no point
+    if !reward.amount.is_zero() {
+        let return_tokens = send_to_proxy_or_owner(&None, &owner, vec![reward.clone()]);
+        response = response.add_message(return_tokens);
+    }
+
+    Ok(
+        response.add_event(new_withdraw_gateway_operator_reward_event(
+            &owner,
+            &None,
+            reward,
+            bond.identity(),
+        )),
+    )
+}
+
+pub(crate) fn try_withdraw_gateway_delegator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    identity: IdentityKey,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+
+    // see if the delegation even exists
+    let storage_key = GatewayDelegation::generate_storage_key(&identity, &owner);
+    let mut delegation = match delegations_storage::gateway_delegations()
+        .may_load(deps.storage, storage_key.clone())?
+    {
+        None => {
+            return Err(MixnetContractError::NoGatewayDelegationFound {
+                identity,
+                address: owner.into_string(),
+            });
+        }
+        Some(delegation) => delegation,
+    };
+
+    // delegations made with the vesting tokens get their rewards back alongside the delegation when undelegating
+    ensure_proxy_match(&None, &delegation.proxy)?;
+
+    // if the gateway has already unbonded, the expected path of getting your tokens back is via undelegation
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .is_none()
+    {
+        return Err(MixnetContractError::GatewayBondNotFound { identity });
+    }
+
+    let mut gateway_rewarding =
+        storage::GATEWAY_REWARDING.may_load(deps.storage, &identity)?.ok_or(MixnetContractError::inconsistent_state(
+            "gateway rewarding got removed from the storage whilst there's still an existing delegation"
+        ))?;
+
+    let old_delegation = delegation.clone();
+    let reward = gateway_rewarding.withdraw_delegator_reward(&mut delegation)?;
+
+    storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+    delegations_storage::gateway_delegations().replace(
+        deps.storage,
+        storage_key,
+        Some(&delegation),
+        Some(&old_delegation),
+    )?;
+
+    let mut response = Response::new();
+
+    // if the reward is zero, don't send anything - there's no point
+    if !reward.amount.is_zero() {
+        let return_tokens = send_to_proxy_or_owner(&None, &owner, vec![reward.clone()]);
+        response = response.add_message(return_tokens);
+    }
+
+    Ok(
+        response.add_event(new_withdraw_gateway_delegator_reward_event(
+            &owner, reward, &identity,
+        )),
+    )
+}
+
+pub(crate) fn try_update_gateway_rewarding_params(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    updated_params: GatewayRewardingParams,
+) -> Result<Response, MixnetContractError> {
+    ensure_is_owner(info.sender, deps.storage)?;
+    updated_params.validate()?;
+
+    // unlike the mixnode rewarding parameters, the change is applied immediately
+    // as it doesn't affect any of the pending events
+    // (though if the gateways are already being rewarded, the pool share only changes from the next epoch)
+    storage::GATEWAY_REWARDING_PARAMS.save(deps.storage, &updated_params)?;
+
+    Ok(Response::new().add_event(new_gateway_rewarding_params_update_event(updated_params)))
+}
+
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_std::testing::mock_info;
@@ -2402,4 +2625,380 @@ pub mod tests {
             );
         }
     }
+
+    #[cfg(test)]
+    mod gateway_rewarding {
+        use cosmwasm_std::{Decimal, Uint128};
+
+        use mixnet_contract_common::events::{
+            MixnetEventType, ALREADY_REWARDED_VALUE, BOND_NOT_FOUND_VALUE, NO_REWARD_REASON_KEY,
+            ZERO_PERFORMANCE_VALUE,
+        };
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+        use mixnet_contract_common::{GatewayRewardingParams, Percent};
+
+        use crate::gateways::transactions::try_remove_gateway;
+        use crate::support::tests::test_helpers::{
+            assert_eq_with_leeway, find_attribute, get_bank_send_msg, TestSetup,
+        };
+
+        use super::*;
+
+        fn enable_gateway_rewarding(test: &mut TestSetup) -> GatewayRewardingParams {
+            let params = GatewayRewardingParams {
+                reward_pool_share: Percent::from_percentage_value(10).unwrap(),
+                stake_saturation_point: Decimal::from_atomics(1_000_000_000_000u64, 0).unwrap(),
+            };
+            storage::GATEWAY_REWARDING_PARAMS
+                .save(test.deps_mut().storage, &params)
+                .unwrap();
+            params
+        }
+
+        fn full_gateway_reward(
+            test: &TestSetup,
+            params: &GatewayRewardingParams,
+            bonded_gateways: u32,
+        ) -> Decimal {
+            let epoch_reward_budget = test.rewarding_params().interval.epoch_reward_budget;
+            params.epoch_reward_per_gateway(epoch_reward_budget, bonded_gateways)
+        }
+
+        fn no_reward_reason(res: &Response) -> String {
+            find_attribute(
+                Some(MixnetEventType::GatewayRewarding),
+                NO_REWARD_REASON_KEY,
+                res,
+            )
+        }
+
+        #[test]
+        fn can_only_be_performed_by_the_rewarding_validator_during_rewarding() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.skip_to_next_epoch_end();
+            let performance = test_helpers::performance(100.0);
+
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                mock_info("random", &[]),
+                identity.clone(),
+                performance,
+            );
+            assert!(res.is_err());
+
+            // epoch is still in progress
+            let sender = test.rewarding_validator();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                identity.clone(),
+                performance,
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::EpochNotInGatewayRewardingState { .. })
+            ));
+
+            // nor once the pending events are being reconciled
+            test.set_epoch_reconciliation_state();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                identity.clone(),
+                performance,
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::EpochNotInGatewayRewardingState {
+                    current_state: EpochState::ReconcilingEvents
+                })
+            ));
+
+            test.set_epoch_rewarding_state();
+            let res = try_reward_gateway(test.deps_mut(), sender, identity, performance);
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn gateways_are_rewarded_at_most_once_per_epoch() {
+            let mut test = TestSetup::new();
+            let params = enable_gateway_rewarding(&mut test);
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            let sender = test.rewarding_validator();
+            let performance = test_helpers::performance(100.0);
+
+            // gateways don't get rewarded for the epoch they bonded in
+            test.set_epoch_rewarding_state();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                identity.clone(),
+                performance,
+            )
+            .unwrap();
+            assert_eq!(no_reward_reason(&res), ALREADY_REWARDED_VALUE);
+
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let before = test.gateway_rewarding(&identity);
+            let expected_reward =
+                before.gateway_reward(&params, full_gateway_reward(&test, &params, 1), performance);
+            assert!(!expected_reward.is_zero());
+
+            try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                identity.clone(),
+                performance,
+            )
+            .unwrap();
+            let after = test.gateway_rewarding(&identity);
+            assert_eq!(after.operator, before.operator + expected_reward);
+            assert_eq!(
+                after.last_rewarded_epoch,
+                test.current_interval().current_epoch_absolute_id()
+            );
+
+            let res =
+                try_reward_gateway(test.deps_mut(), sender, identity.clone(), performance).unwrap();
+            assert_eq!(no_reward_reason(&res), ALREADY_REWARDED_VALUE);
+            assert_eq!(test.gateway_rewarding(&identity), after);
+        }
+
+        #[test]
+        fn zero_performance_and_unbonded_gateways_get_no_reward() {
+            let mut test = TestSetup::new();
+            enable_gateway_rewarding(&mut test);
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            let sender = test.rewarding_validator();
+
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let before = test.gateway_rewarding(&identity);
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                identity.clone(),
+                test_helpers::performance(0.0),
+            )
+            .unwrap();
+            assert_eq!(no_reward_reason(&res), ZERO_PERFORMANCE_VALUE);
+            let after = test.gateway_rewarding(&identity);
+            assert_eq!(after.operator, before.operator);
+            assert_ne!(after.last_rewarded_epoch, before.last_rewarded_epoch);
+
+            try_remove_gateway(test.deps_mut(), mock_info("gateway-owner", &[])).unwrap();
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                sender,
+                identity,
+                test_helpers::performance(100.0),
+            )
+            .unwrap();
+            assert_eq!(no_reward_reason(&res), BOND_NOT_FOUND_VALUE);
+        }
+
+        #[test]
+        fn rewards_are_split_proportionally_and_can_be_withdrawn() {
+            let mut test = TestSetup::new();
+            let params = enable_gateway_rewarding(&mut test);
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            test.add_immediate_gateway_delegation("delegator", 100_000_000u32, &identity);
+            let sender = test.rewarding_validator();
+            let performance = test_helpers::performance(100.0);
+
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let before = test.gateway_rewarding(&identity);
+            let expected = before.calculate_epoch_reward(
+                &params,
+                full_gateway_reward(&test, &params, 1),
+                performance,
+            );
+            try_reward_gateway(test.deps_mut(), sender, identity.clone(), performance).unwrap();
+
+            assert!(!expected.operator.is_zero());
+            assert!(!expected.delegates.is_zero());
+            test.set_epoch_in_progress_state();
+
+            let res = try_withdraw_gateway_delegator_reward(
+                test.deps_mut(),
+                mock_info("delegator", &[]),
+                identity.clone(),
+            )
+            .unwrap();
+            let (receiver, sent) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, "delegator");
+            assert_eq_with_leeway(
+                sent[0].amount,
+                truncate_reward_amount(expected.delegates),
+                Uint128::new(1),
+            );
+
+            let res = try_withdraw_gateway_operator_reward(
+                test.deps_mut(),
+                mock_info("gateway-owner", &[]),
+            )
+            .unwrap();
+            let (receiver, sent) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, "gateway-owner");
+            assert_eq!(sent[0].amount, truncate_reward_amount(expected.operator));
+
+            // nothing left to withdraw
+            let res = try_withdraw_gateway_operator_reward(
+                test.deps_mut(),
+                mock_info("gateway-owner", &[]),
+            )
+            .unwrap();
+            assert!(get_bank_send_msg(&res).is_none());
+        }
+
+        #[test]
+        fn gateway_pool_is_split_between_all_bonded_gateways() {
+            let mut test = TestSetup::new();
+            let params = enable_gateway_rewarding(&mut test);
+            let gateways = (0..4)
+                .map(|i| {
+                    test.add_dummy_gateway(
+                        &format!("gateway-owner{i}"),
+                        Some(Uint128::new(1_000_000_000_000)),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let sender = test.rewarding_validator();
+            let performance = test_helpers::performance(100.0);
+
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let pool = full_gateway_reward(&test, &params, 1);
+            let per_gateway = full_gateway_reward(&test, &params, 4);
+            assert!(per_gateway * Decimal::from_atomics(4u32, 0).unwrap() <= pool);
+
+            let before = test.gateway_rewarding(&gateways[0]);
+            try_reward_gateway(
+                test.deps_mut(),
+                sender.clone(),
+                gateways[0].clone(),
+                performance,
+            )
+            .unwrap();
+            assert_eq!(
+                test.gateway_rewarding(&gateways[0]).operator,
+                before.operator + per_gateway
+            );
+
+            // gateway unbonding halfway through the rewarding doesn't increase the share of the others
+            try_remove_gateway(test.deps_mut(), mock_info("gateway-owner0", &[])).unwrap();
+            let mut total = per_gateway;
+            for identity in &gateways[1..] {
+                let before = test.gateway_rewarding(identity);
+                try_reward_gateway(
+                    test.deps_mut(),
+                    sender.clone(),
+                    identity.clone(),
+                    performance,
+                )
+                .unwrap();
+                let reward = test.gateway_rewarding(identity).operator - before.operator;
+                assert_eq!(reward, per_gateway);
+                total += reward;
+            }
+            assert!(total <= pool);
+
+            // but it does in the following epoch
+            test.skip_to_next_epoch_end();
+            test.set_epoch_rewarding_state();
+            let before = test.gateway_rewarding(&gateways[1]);
+            try_reward_gateway(test.deps_mut(), sender, gateways[1].clone(), performance).unwrap();
+            assert_eq!(
+                test.gateway_rewarding(&gateways[1]).operator - before.operator,
+                full_gateway_reward(&test, &params, 3)
+            );
+        }
+
+        #[test]
+        fn gateway_pool_is_taken_out_of_the_mixnode_budget() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.force_change_rewarded_set(vec![mix_id]);
+            let params = enable_gateway_rewarding(&mut test);
+            let identity =
+                test.add_dummy_gateway("gateway-owner", Some(Uint128::new(1_000_000_000_000)));
+            let sender = test.rewarding_validator();
+            let performance = test_helpers::performance(100.0);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+
+            let rewarding_params = test.rewarding_params();
+            let epoch_reward_budget = rewarding_params.interval.epoch_reward_budget;
+            let mut mixnode_params = rewarding_params;
+            mixnode_params.interval.epoch_reward_budget =
+                params.mixnode_epoch_reward_budget(epoch_reward_budget);
+            let node_reward_params = NodeRewardParams::new(performance, true);
+            let mix_rewarding = test.mix_rewarding(mix_id);
+            let expected_node_reward =
+                mix_rewarding.node_reward(&mixnode_params, node_reward_params);
+            assert!(
+                expected_node_reward
+                    < mix_rewarding.node_reward(&rewarding_params, node_reward_params)
+            );
+
+            try_reward_gateway(test.deps_mut(), sender, identity, performance).unwrap();
+            let gateway_reward = full_gateway_reward(&test, &params, 1);
+            let dist = test.reward_with_distribution(mix_id, performance);
+            assert_eq!(dist.operator + dist.delegates, expected_node_reward);
+
+            let emitted = storage::PENDING_REWARD_POOL_CHANGE
+                .load(test.deps().storage)
+                .unwrap()
+                .removed;
+            assert_eq!(emitted, gateway_reward + expected_node_reward);
+
+            // even with the entire gateway pool and mixnode budget being paid out, the total emission
+            // can't exceed the epoch reward budget
+            assert!(
+                gateway_reward + params.mixnode_epoch_reward_budget(epoch_reward_budget)
+                    <= epoch_reward_budget
+            );
+        }
+
+        #[test]
+        fn updating_params_can_only_be_done_by_the_owner_with_valid_values() {
+            let mut test = TestSetup::new();
+            let params = GatewayRewardingParams {
+                reward_pool_share: Percent::from_percentage_value(10).unwrap(),
+                stake_saturation_point: Decimal::zero(),
+            };
+
+            let res = try_update_gateway_rewarding_params(
+                test.deps_mut(),
+                mock_info("random", &[]),
+                params,
+            );
+            assert_eq!(res, Err(MixnetContractError::Unauthorized));
+
+            let owner = test.owner();
+            let res = try_update_gateway_rewarding_params(test.deps_mut(), owner.clone(), params);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::ZeroGatewayStakeSaturationPoint)
+            );
+
+            let params = GatewayRewardingParams {
+                stake_saturation_point: Decimal::one(),
+                ..params
+            };
+            try_update_gateway_rewarding_params(test.deps_mut(), owner, params).unwrap();
+            assert_eq!(
+                storage::GATEWAY_REWARDING_PARAMS
+                    .load(test.deps().storage)
+                    .unwrap(),
+                params
+            );
+        }
+    }
 }
@@ -0,0 +1,340 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::delegations::storage as delegations_storage;
+use crate::interval::storage as interval_storage;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::mixnodes::storage as mixnodes_storage;
+use crate::rewards::storage as rewards_storage;
+use crate::slashing::models::SlashingProgress;
+use crate::support::helpers::{decode_ed25519_identity_key, VestingTracking};
+use cosmwasm_std::{
+    coins, Addr, Api, BankMsg, Decimal, DepsMut, Order, Response, StdResult, Storage,
+};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::error::MixnetContractError;
+use mixnet_contract_common::events::{new_gateway_slashing_event, new_mixnode_slashing_event};
+use mixnet_contract_common::helpers::IntoBaseDecimal;
+use mixnet_contract_common::rewarding::helpers::truncate_reward;
+use mixnet_contract_common::{
+    Delegation, ForwardingReceipt, GatewayDelegation, IdentityKeyRef, MixId, Percent, SlashedNode,
+    SlashingEvidence, SlashingStatus,
+};
+
+fn decode_evidence_field(field: &str, value: &str) -> Result<Vec<u8>, MixnetContractError> {
+    bs58::decode(value)
+        .into_vec()
+        .map_err(|err| MixnetContractError::InvalidSlashingEvidence {
+            reason: format!("the {field} is not a valid base58 string: {err}"),
+        })
+}
+
+/// Ensures the provided forwarding receipt has actually been signed by the node being slashed.
+fn verify_forwarding_receipt(
+    api: &dyn Api,
+    receipt: &ForwardingReceipt,
+    public_key: &[u8],
+) -> Result<(), MixnetContractError> {
+    decode_evidence_field("replay tag", &receipt.replay_tag)?;
+    let signature = decode_evidence_field("receipt signature", &receipt.signature)?;
+
+    let valid = api
+        .ed25519_verify(&receipt.signed_plaintext(), &signature, public_key)
+        .map_err(|err| MixnetContractError::InvalidSlashingEvidence {
+            reason: format!("failed to verify the receipt signature: {err}"),
+        })?;
+    if !valid {
+        return Err(MixnetContractError::InvalidSlashingEvidence {
+            reason: "the forwarding receipt has not been signed by the node".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensures the provided evidence has actually been produced by the node being slashed,
+/// i.e. that both forwarding receipts of the replayed packet have been signed with its identity key.
+pub(crate) fn verify_slashing_evidence(
+    api: &dyn Api,
+    evidence: &SlashingEvidence,
+    identity_key: IdentityKeyRef<'_>,
+) -> Result<(), MixnetContractError> {
+    match evidence {
+        SlashingEvidence::ReplayedPacket { original, replayed } => {
+            let public_key = decode_ed25519_identity_key(identity_key)?;
+            verify_forwarding_receipt(api, original, &public_key)?;
+            verify_forwarding_receipt(api, replayed, &public_key)
+        }
+        // those are based on the network monitor results, i.e. there's nothing
+        // to verify on chain beyond the basic validation
+        SlashingEvidence::NetworkMonitorFailures { .. } => Ok(()),
+    }
+}
+
+/// Applies the slashing to (up to `limit` of) the remaining delegations made towards the specified
+/// mixnode. Delegations that got slashed down to zero are removed from the storage and returned
+/// alongside the number of processed delegations and an indication whether all of them got processed.
+fn slash_mixnode_delegations(
+    store: &mut dyn Storage,
+    mix_id: MixId,
+    slash_percent: Percent,
+    progress: &mut SlashingProgress,
+    limit: usize,
+) -> Result<(Vec<Delegation>, u32, bool), MixnetContractError> {
+    let start = progress.last_processed.clone().map(Bound::exclusive);
+
+    // retrieve one delegation more than we're going to process to know whether we're done
+    let mut delegations = delegations_storage::delegations()
+        .prefix(mix_id)
+        .range(store, start, None, Order::Ascending)
+        .take(limit.saturating_add(1))
+        .map(|res| res.map(|row| row.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    let finished = delegations.len() <= limit;
+    delegations.truncate(limit);
+
+    let processed = delegations.len() as u32;
+    if processed == 0 {
+        return Ok((Vec::new(), 0, finished));
+    }
+
+    let mut mix_rewarding = rewards_storage::MIXNODE_REWARDING.load(store, mix_id)?;
+    let mut removed = Vec::new();
+    for mut delegation in delegations {
+        let old_delegation = delegation.clone();
+        progress.delegates_slashed +=
+            mix_rewarding.slash_delegation(&mut delegation, slash_percent)?;
+        progress.last_processed = Some(delegation.proxy_storage_key());
+
+        let storage_key = delegation.storage_key();
+        if delegation.amount.amount.is_zero() {
+            // there's nothing left to return to the delegator
+            mix_rewarding.remove_delegation_decimal(Decimal::zero())?;
+            delegations_storage::delegations().replace(
+                store,
+                storage_key.clone(),
+                None,
+                Some(&old_delegation),
+            )?;
+            delegations_storage::AUTO_COMPOUNDING_DELEGATIONS.remove(store, storage_key);
+            removed.push(delegation);
+        } else {
+            delegations_storage::delegations().replace(
+                store,
+                storage_key,
+                Some(&delegation),
+                Some(&old_delegation),
+            )?;
+        }
+    }
+    rewards_storage::MIXNODE_REWARDING.save(store, mix_id, &mix_rewarding)?;
+
+    Ok((removed, processed, finished))
+}
+
+/// Applies the slashing to (up to `limit` of) the remaining delegations made towards the specified
+/// gateway. Delegations that got slashed down to zero are removed from the storage and returned
+/// alongside the number of processed delegations and an indication whether all of them got processed.
+fn slash_gateway_delegations(
+    store: &mut dyn Storage,
+    identity: IdentityKeyRef<'_>,
+    slash_percent: Percent,
+    progress: &mut SlashingProgress,
+    limit: usize,
+) -> Result<(Vec<GatewayDelegation>, u32, bool), MixnetContractError> {
+    let start = progress
+        .last_processed
+        .clone()
+        .map(|owner| Bound::exclusive(Addr::unchecked(owner)));
+
+    // retrieve one delegation more than we're going to process to know whether we're done
+    let mut delegations = delegations_storage::gateway_delegations()
+        .prefix(identity.to_string())
+        .range(store, start, None, Order::Ascending)
+        .take(limit.saturating_add(1))
+        .map(|res| res.map(|row| row.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    let finished = delegations.len() <= limit;
+    delegations.truncate(limit);
+
+    let processed = delegations.len() as u32;
+    if processed == 0 {
+        return Ok((Vec::new(), 0, finished));
+    }
+
+    let mut gateway_rewarding = rewards_storage::GATEWAY_REWARDING.load(store, identity)?;
+    let mut removed = Vec::new();
+    for mut delegation in delegations {
+        let old_delegation = delegation.clone();
+        progress.delegates_slashed +=
+            gateway_rewarding.slash_delegation(&mut delegation, slash_percent)?;
+        progress.last_processed = Some(delegation.owner.to_string());
+
+        let storage_key = delegation.storage_key();
+        if delegation.amount.amount.is_zero() {
+            // there's nothing left to return to the delegator
+            gateway_rewarding.remove_delegation_decimal(Decimal::zero())?;
+            delegations_storage::gateway_delegations().replace(
+                store,
+                storage_key,
+                None,
+                Some(&old_delegation),
+            )?;
+            removed.push(delegation);
+        } else {
+            delegations_storage::gateway_delegations().replace(
+                store,
+                storage_key,
+                Some(&delegation),
+                Some(&old_delegation),
+            )?;
+        }
+    }
+    rewards_storage::GATEWAY_REWARDING.save(store, identity, &gateway_rewarding)?;
+
+    Ok((removed, processed, finished))
+}
+
+/// Continues applying the slashing that is currently in progress (if any) to the delegations
+/// of the slashed node. Each processed delegation counts towards the provided limit.
+/// Once all of them got slashed, the slashing gets finalised: the record is marked as executed,
+/// the pending marker is removed from the node and the slashed tokens are either burned
+/// or returned to the reward pool.
+/// Returns the number of processed delegations and whether there's no slashing in progress anymore.
+pub(crate) fn continue_slashing(
+    deps: DepsMut<'_>,
+    limit: Option<u32>,
+) -> Result<(Response, u32, bool), MixnetContractError> {
+    let mut progress = match storage::SLASHING_PROGRESS.may_load(deps.storage)? {
+        Some(progress) => progress,
+        None => return Ok((Response::new(), 0, true)),
+    };
+    let mut record = storage::SLASHING_RECORDS
+        .may_load(deps.storage, progress.slashing_id)?
+        .ok_or(MixnetContractError::SlashingRecordNotFound {
+            slashing_id: progress.slashing_id,
+        })?;
+
+    let limit = limit.map(|l| l as usize).unwrap_or(usize::MAX);
+    let mut response = Response::new();
+
+    let (processed, finished) = match &record.node {
+        SlashedNode::Mixnode { mix_id } => {
+            let (removed, processed, finished) = slash_mixnode_delegations(
+                deps.storage,
+                *mix_id,
+                record.slash_percent,
+                &mut progress,
+                limit,
+            )?;
+
+            // let the vesting contract know about any vesting delegations that got completely wiped out
+            for delegation in removed {
+                response = response.maybe_add_track_vesting_undelegation_message(
+                    deps.storage,
+                    delegation.proxy,
+                    delegation.owner.into_string(),
+                    *mix_id,
+                    delegation.amount,
+                )?;
+            }
+            (processed, finished)
+        }
+        SlashedNode::Gateway { identity } => {
+            let (removed, processed, finished) = slash_gateway_delegations(
+                deps.storage,
+                identity,
+                record.slash_percent,
+                &mut progress,
+                limit,
+            )?;
+
+            for delegation in removed {
+                response = response.maybe_add_track_vesting_gateway_undelegation_message(
+                    deps.storage,
+                    delegation.proxy,
+                    delegation.owner.into_string(),
+                    identity.clone(),
+                    delegation.amount,
+                )?;
+            }
+            (processed, finished)
+        }
+    };
+
+    if !finished {
+        storage::SLASHING_PROGRESS.save(deps.storage, &progress)?;
+        return Ok((response, processed, false));
+    }
+    storage::SLASHING_PROGRESS.remove(deps.storage);
+
+    let denom = mixnet_params_storage::rewarding_denom(deps.storage)?;
+    let absolute_epoch_id =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let operator_slashed = truncate_reward(progress.operator_slashed, &denom);
+    let delegates_slashed = truncate_reward(progress.delegates_slashed, &denom);
+    record.status = SlashingStatus::Executed {
+        absolute_epoch_id,
+        operator_slashed: operator_slashed.clone(),
+        delegates_slashed: delegates_slashed.clone(),
+    };
+    storage::SLASHING_RECORDS.save(deps.storage, record.id, &record)?;
+
+    let cosmos_event = match &record.node {
+        SlashedNode::Mixnode { mix_id } => {
+            let mut pending_changes = mixnodes_storage::PENDING_MIXNODE_CHANGES
+                .may_load(deps.storage, *mix_id)?
+                .unwrap_or_default();
+            pending_changes.slashing = None;
+            mixnodes_storage::PENDING_MIXNODE_CHANGES.save(
+                deps.storage,
+                *mix_id,
+                &pending_changes,
+            )?;
+
+            new_mixnode_slashing_event(
+                progress.created_at,
+                *mix_id,
+                record.id,
+                &operator_slashed,
+                &delegates_slashed,
+                record.redistribute,
+            )
+        }
+        SlashedNode::Gateway { identity } => {
+            storage::PENDING_GATEWAY_SLASHINGS.remove(deps.storage, identity);
+
+            new_gateway_slashing_event(
+                progress.created_at,
+                identity,
+                record.id,
+                &operator_slashed,
+                &delegates_slashed,
+                record.redistribute,
+            )
+        }
+    };
+    response = response.add_event(cosmos_event);
+
+    let total_slashed = operator_slashed.amount + delegates_slashed.amount;
+    if !total_slashed.is_zero() {
+        if record.redistribute {
+            // the tokens never leave the contract, they just get moved back into the reward pool
+            // once the current interval finishes
+            let mut pending_pool_change =
+                rewards_storage::PENDING_REWARD_POOL_CHANGE.load(deps.storage)?;
+            pending_pool_change.added += total_slashed.into_base_decimal()?;
+            rewards_storage::PENDING_REWARD_POOL_CHANGE.save(deps.storage, &pending_pool_change)?;
+        } else {
+            response = response.add_message(BankMsg::Burn {
+                amount: coins(total_slashed.u128(), &denom),
+            });
+        }
+    }
+
+    Ok((response, processed, true))
+}
+
+pub(crate) fn slashing_in_progress(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(storage::SLASHING_PROGRESS.may_load(storage)?.is_some())
+}
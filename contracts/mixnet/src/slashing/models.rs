@@ -0,0 +1,40 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use cosmwasm_std::Decimal;
+use mixnet_contract_common::{BlockHeight, SlashingId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub(crate) struct SlashingProgress {
+    /// Id of the slashing that is currently being applied.
+    pub slashing_id: SlashingId,
+
+    /// Block height at which the associated epoch event has been created.
+    pub created_at: BlockHeight,
+
+    /// Amount removed from the operator's stake.
+    pub operator_slashed: Decimal,
+
+    /// Amount removed from the delegations processed so far.
+    pub delegates_slashed: Decimal,
+
+    /// Sub-key (i.e. without the node part) of the last delegation that got slashed.
+    pub last_processed: Option<String>,
+}
+
+impl SlashingProgress {
+    pub fn new(
+        slashing_id: SlashingId,
+        created_at: BlockHeight,
+        operator_slashed: Decimal,
+    ) -> Self {
+        SlashingProgress {
+            slashing_id,
+            created_at,
+            operator_slashed,
+            delegates_slashed: Decimal::zero(),
+            last_processed: None,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::constants::{
+    SLASHING_RECORDS_DEFAULT_RETRIEVAL_LIMIT, SLASHING_RECORDS_MAX_RETRIEVAL_LIMIT,
+};
+use cosmwasm_std::{Deps, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::{
+    IdentityKey, MixId, PagedGatewaySlashingHistoryResponse, PagedMixnodeSlashingHistoryResponse,
+    PagedSlashingHistoryResponse, SlashingId, SlashingRecord, SlashingRecordResponse,
+};
+
+fn load_records(
+    storage: &dyn Storage,
+    ids: impl Iterator<Item = StdResult<SlashingId>>,
+) -> StdResult<Vec<SlashingRecord>> {
+    ids.map(|id| storage::SLASHING_RECORDS.load(storage, id?))
+        .collect()
+}
+
+pub fn query_slashing_record(
+    deps: Deps<'_>,
+    slashing_id: SlashingId,
+) -> StdResult<SlashingRecordResponse> {
+    let record = storage::SLASHING_RECORDS.may_load(deps.storage, slashing_id)?;
+
+    Ok(SlashingRecordResponse {
+        slashing_id,
+        record,
+    })
+}
+
+pub fn query_slashing_history_paged(
+    deps: Deps<'_>,
+    start_after: Option<SlashingId>,
+    limit: Option<u32>,
+) -> StdResult<PagedSlashingHistoryResponse> {
+    let limit = limit
+        .unwrap_or(SLASHING_RECORDS_DEFAULT_RETRIEVAL_LIMIT)
+        .min(SLASHING_RECORDS_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let records = storage::SLASHING_RECORDS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|row| row.1))
+        .collect::<StdResult<Vec<SlashingRecord>>>()?;
+
+    let start_next_after = records.last().map(|record| record.id);
+
+    Ok(PagedSlashingHistoryResponse {
+        records,
+        start_next_after,
+    })
+}
+
+pub fn query_mixnode_slashing_history_paged(
+    deps: Deps<'_>,
+    mix_id: MixId,
+    start_after: Option<SlashingId>,
+    limit: Option<u32>,
+) -> StdResult<PagedMixnodeSlashingHistoryResponse> {
+    let limit = limit
+        .unwrap_or(SLASHING_RECORDS_DEFAULT_RETRIEVAL_LIMIT)
+        .min(SLASHING_RECORDS_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let ids = storage::MIXNODE_SLASHING_RECORDS
+        .prefix(mix_id)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit);
+    let records = load_records(deps.storage, ids)?;
+
+    let start_next_after = records.last().map(|record| record.id);
+
+    Ok(PagedMixnodeSlashingHistoryResponse {
+        mix_id,
+        records,
+        start_next_after,
+    })
+}
+
+pub fn query_gateway_slashing_history_paged(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+    start_after: Option<SlashingId>,
+    limit: Option<u32>,
+) -> StdResult<PagedGatewaySlashingHistoryResponse> {
+    let limit = limit
+        .unwrap_or(SLASHING_RECORDS_DEFAULT_RETRIEVAL_LIMIT)
+        .min(SLASHING_RECORDS_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let ids = storage::GATEWAY_SLASHING_RECORDS
+        .prefix(&identity)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit);
+    let records = load_records(deps.storage, ids)?;
+
+    let start_next_after = records.last().map(|record| record.id);
+
+    Ok(PagedGatewaySlashingHistoryResponse {
+        identity,
+        records,
+        start_next_after,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slashing::transactions::{try_slash_gateway, try_slash_mixnode};
+    use crate::support::tests::test_helpers::TestSetup;
+    use mixnet_contract_common::{Percent, SlashedNode, SlashingEvidence};
+
+    fn evidence() -> SlashingEvidence {
+        SlashingEvidence::NetworkMonitorFailures {
+            failed_epochs: vec![0],
+        }
+    }
+
+    fn slash(test: &mut TestSetup, mix_id: MixId) {
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            sender,
+            mix_id,
+            Percent::from_percentage_value(5).unwrap(),
+            evidence(),
+            false,
+        )
+        .unwrap();
+    }
+
+    fn slash_gateway(test: &mut TestSetup, identity: &str) {
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        try_slash_gateway(
+            test.deps_mut(),
+            env,
+            sender,
+            identity.to_string(),
+            Percent::from_percentage_value(5).unwrap(),
+            evidence(),
+            false,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn slashing_record_query() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let res = query_slashing_record(test.deps(), 1).unwrap();
+        assert!(res.record.is_none());
+
+        slash(&mut test, mix_id);
+        let res = query_slashing_record(test.deps(), 1).unwrap();
+        assert_eq!(res.slashing_id, 1);
+        assert_eq!(res.record.unwrap().node, SlashedNode::Mixnode { mix_id });
+    }
+
+    #[test]
+    fn slashing_history_is_paged_and_filtered_by_mixnode() {
+        let mut test = TestSetup::new();
+        let mix_id1 = test.add_dummy_mixnode("mix-owner1", None);
+        let mix_id2 = test.add_dummy_mixnode("mix-owner2", None);
+        let mix_id3 = test.add_dummy_mixnode("mix-owner3", None);
+
+        slash(&mut test, mix_id1);
+        slash(&mut test, mix_id2);
+        slash(&mut test, mix_id3);
+
+        let res = query_slashing_history_paged(test.deps(), None, Some(2)).unwrap();
+        assert_eq!(res.records.len(), 2);
+        assert_eq!(res.start_next_after, Some(2));
+
+        let res = query_slashing_history_paged(test.deps(), res.start_next_after, None).unwrap();
+        assert_eq!(res.records.len(), 1);
+        assert_eq!(
+            res.records[0].node,
+            SlashedNode::Mixnode { mix_id: mix_id3 }
+        );
+
+        let res = query_mixnode_slashing_history_paged(test.deps(), mix_id2, None, None).unwrap();
+        assert_eq!(res.records.len(), 1);
+        assert_eq!(res.records[0].id, 2);
+        assert_eq!(res.start_next_after, Some(2));
+
+        let res =
+            query_mixnode_slashing_history_paged(test.deps(), mix_id2, Some(2), None).unwrap();
+        assert!(res.records.is_empty());
+    }
+
+    #[test]
+    fn gateway_slashing_history_is_filtered_by_gateway() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        let gateway1 = test.add_dummy_gateway("gateway-owner1", None);
+        let gateway2 = test.add_dummy_gateway("gateway-owner2", None);
+
+        slash_gateway(&mut test, &gateway1);
+        slash(&mut test, mix_id);
+        slash_gateway(&mut test, &gateway2);
+
+        let res = query_gateway_slashing_history_paged(test.deps(), gateway2.clone(), None, None)
+            .unwrap();
+        assert_eq!(res.records.len(), 1);
+        assert_eq!(res.records[0].id, 3);
+        assert_eq!(
+            res.records[0].node,
+            SlashedNode::Gateway { identity: gateway2 }
+        );
+
+        let res = query_mixnode_slashing_history_paged(test.deps(), mix_id, None, None).unwrap();
+        assert_eq!(res.records.len(), 1);
+        assert_eq!(res.records[0].id, 2);
+    }
+}
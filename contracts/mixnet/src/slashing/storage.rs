@@ -0,0 +1,54 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::constants::{
+    GATEWAY_SLASHING_RECORDS_NAMESPACE, MIXNODE_SLASHING_RECORDS_NAMESPACE,
+    PENDING_GATEWAY_SLASHINGS_NAMESPACE, SLASHING_ID_COUNTER_KEY, SLASHING_PROGRESS_KEY,
+    SLASHING_RECORDS_PK_NAMESPACE,
+};
+use crate::slashing::models::SlashingProgress;
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use mixnet_contract_common::{IdentityKeyRef, MixId, SlashedNode, SlashingId, SlashingRecord};
+
+pub(crate) const SLASHING_ID_COUNTER: Item<SlashingId> = Item::new(SLASHING_ID_COUNTER_KEY);
+
+// keeps the full history of all slashing requests, including the ones that are still pending
+pub(crate) const SLASHING_RECORDS: Map<SlashingId, SlashingRecord> =
+    Map::new(SLASHING_RECORDS_PK_NAMESPACE);
+
+// secondary indices of the slashing records for the particular nodes
+// (the records can target either a mixnode or a gateway, so a `MultiIndex` can't be used here)
+pub(crate) const MIXNODE_SLASHING_RECORDS: Map<(MixId, SlashingId), ()> =
+    Map::new(MIXNODE_SLASHING_RECORDS_NAMESPACE);
+pub(crate) const GATEWAY_SLASHING_RECORDS: Map<(IdentityKeyRef<'_>, SlashingId), ()> =
+    Map::new(GATEWAY_SLASHING_RECORDS_NAMESPACE);
+
+// gateways don't have any pending changes associated with them, so the equivalent of
+// `PendingMixNodeChanges::slashing` is kept separately
+pub(crate) const PENDING_GATEWAY_SLASHINGS: Map<IdentityKeyRef<'_>, SlashingId> =
+    Map::new(PENDING_GATEWAY_SLASHINGS_NAMESPACE);
+
+// the slashing whose delegations are currently being processed (if any)
+pub(crate) const SLASHING_PROGRESS: Item<SlashingProgress> = Item::new(SLASHING_PROGRESS_KEY);
+
+pub(crate) fn next_slashing_id_counter(store: &mut dyn Storage) -> StdResult<SlashingId> {
+    let id: SlashingId = SLASHING_ID_COUNTER.may_load(store)?.unwrap_or_default() + 1;
+    SLASHING_ID_COUNTER.save(store, &id)?;
+    Ok(id)
+}
+
+pub(crate) fn save_new_slashing_record(
+    store: &mut dyn Storage,
+    record: &SlashingRecord,
+) -> StdResult<()> {
+    SLASHING_RECORDS.save(store, record.id, record)?;
+    match &record.node {
+        SlashedNode::Mixnode { mix_id } => {
+            MIXNODE_SLASHING_RECORDS.save(store, (*mix_id, record.id), &())
+        }
+        SlashedNode::Gateway { identity } => {
+            GATEWAY_SLASHING_RECORDS.save(store, (identity.as_str(), record.id), &())
+        }
+    }
+}
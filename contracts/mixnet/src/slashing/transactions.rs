@@ -0,0 +1,601 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::gateways::storage as gateways_storage;
+use crate::interval::storage as interval_storage;
+use crate::mixnodes::helpers::get_mixnode_details_by_id;
+use crate::mixnodes::storage as mixnodes_storage;
+use crate::slashing::helpers::verify_slashing_evidence;
+use crate::support::helpers::{
+    ensure_bonded, ensure_epoch_in_progress_state, ensure_is_authorized_or_owner,
+    ensure_no_pending_gateway_slashing, ensure_no_pending_slashing,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Storage};
+use mixnet_contract_common::error::MixnetContractError;
+use mixnet_contract_common::events::{
+    new_pending_gateway_slashing_event, new_pending_mixnode_slashing_event,
+};
+use mixnet_contract_common::pending_events::PendingEpochEventKind;
+use mixnet_contract_common::slashing::validate_slash_percent;
+use mixnet_contract_common::{
+    IdentityKey, MixId, Percent, SlashedNode, SlashingEvidence, SlashingId, SlashingRecord,
+    SlashingStatus,
+};
+
+// performs all the checks common to slashing of both mixnodes and gateways
+fn ensure_can_slash(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+    slash_percent: Percent,
+    evidence: &SlashingEvidence,
+) -> Result<(), MixnetContractError> {
+    ensure_is_authorized_or_owner(&info.sender, storage)?;
+
+    // slashing is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(storage)?;
+
+    validate_slash_percent(slash_percent)?;
+    let current_epoch = interval_storage::current_interval(storage)?.current_epoch_absolute_id();
+    evidence.validate(current_epoch)
+}
+
+fn save_pending_slashing_record(
+    storage: &mut dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    node: SlashedNode,
+    slash_percent: Percent,
+    evidence: SlashingEvidence,
+    redistribute: bool,
+) -> Result<SlashingId, MixnetContractError> {
+    let slashing_id = storage::next_slashing_id_counter(storage)?;
+    let record = SlashingRecord {
+        id: slashing_id,
+        node,
+        submitted_by: info.sender.clone(),
+        submitted_at_height: env.block.height,
+        evidence,
+        slash_percent,
+        redistribute,
+        status: SlashingStatus::Pending,
+    };
+    storage::save_new_slashing_record(storage, &record)?;
+    Ok(slashing_id)
+}
+
+pub(crate) fn try_slash_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    mix_id: MixId,
+    slash_percent: Percent,
+    evidence: SlashingEvidence,
+    redistribute: bool,
+) -> Result<Response, MixnetContractError> {
+    ensure_can_slash(deps.storage, &info, slash_percent, &evidence)?;
+
+    let mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?
+        .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+    let mut pending_changes = mix_details.pending_changes;
+
+    ensure_bonded(&mix_details.bond_information)?;
+    // note: pending pledge changes are fine as the slashing is always going to be applied first
+    ensure_no_pending_slashing(&pending_changes)?;
+    verify_slashing_evidence(
+        deps.api,
+        &evidence,
+        &mix_details.bond_information.mix_node.identity_key,
+    )?;
+
+    let slashing_id = save_pending_slashing_record(
+        deps.storage,
+        &env,
+        &info,
+        SlashedNode::Mixnode { mix_id },
+        slash_percent,
+        evidence,
+        redistribute,
+    )?;
+
+    // push the event to execute it at the end of the epoch
+    let epoch_event = PendingEpochEventKind::SlashMixnode {
+        mix_id,
+        slashing_id,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    // mark the node so that the operator could not unbond nor withdraw any of its pledge
+    // before the slashing takes place
+    pending_changes.slashing = Some(slashing_id);
+    mixnodes_storage::PENDING_MIXNODE_CHANGES.save(deps.storage, mix_id, &pending_changes)?;
+
+    let cosmos_event = new_pending_mixnode_slashing_event(
+        &info.sender,
+        mix_id,
+        slashing_id,
+        slash_percent,
+        redistribute,
+    );
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+pub(crate) fn try_slash_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+    slash_percent: Percent,
+    evidence: SlashingEvidence,
+    redistribute: bool,
+) -> Result<Response, MixnetContractError> {
+    ensure_can_slash(deps.storage, &info, slash_percent, &evidence)?;
+
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .is_none()
+    {
+        return Err(MixnetContractError::GatewayBondNotFound { identity });
+    }
+    ensure_no_pending_gateway_slashing(deps.storage, &identity)?;
+    verify_slashing_evidence(deps.api, &evidence, &identity)?;
+
+    let slashing_id = save_pending_slashing_record(
+        deps.storage,
+        &env,
+        &info,
+        SlashedNode::Gateway {
+            identity: identity.clone(),
+        },
+        slash_percent,
+        evidence,
+        redistribute,
+    )?;
+
+    // mark the gateway so that the operator could not unbond before the slashing takes place
+    storage::PENDING_GATEWAY_SLASHINGS.save(deps.storage, &identity, &slashing_id)?;
+
+    let cosmos_event = new_pending_gateway_slashing_event(
+        &info.sender,
+        &identity,
+        slashing_id,
+        slash_percent,
+        redistribute,
+    );
+
+    // push the event to execute it at the end of the epoch
+    let epoch_event = PendingEpochEventKind::SlashGateway {
+        identity,
+        slashing_id,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::tests::test_helpers::TestSetup;
+    use cosmwasm_std::testing::mock_info;
+    use mixnet_contract_common::{EpochState, ForwardingReceipt};
+    use nym_crypto::asymmetric::identity;
+
+    fn evidence() -> SlashingEvidence {
+        SlashingEvidence::NetworkMonitorFailures {
+            failed_epochs: vec![0],
+        }
+    }
+
+    fn signed_receipt(
+        keypair: &identity::KeyPair,
+        replay_tag: &[u8],
+        timestamp: u64,
+    ) -> ForwardingReceipt {
+        let replay_tag = bs58::encode(replay_tag).into_string();
+        let epoch_id = 0;
+        let signature = keypair
+            .private_key()
+            .sign(&ForwardingReceipt::plaintext(
+                &replay_tag,
+                epoch_id,
+                timestamp,
+            ))
+            .to_base58_string();
+
+        ForwardingReceipt {
+            replay_tag,
+            epoch_id,
+            timestamp,
+            signature,
+        }
+    }
+
+    fn ten_percent() -> Percent {
+        Percent::from_percentage_value(10).unwrap()
+    }
+
+    #[test]
+    fn can_only_be_done_by_rewarding_validator_or_owner() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env.clone(),
+            mock_info("random-guy", &[]),
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(res, Err(MixnetContractError::Unauthorized));
+
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env.clone(),
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert!(res.is_ok());
+
+        // the pending slashing blocks the subsequent one
+        let owner = test.owner();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            owner,
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::PendingSlashing { slashing_id: 1 })
+        );
+    }
+
+    #[test]
+    fn can_be_submitted_with_pending_pledge_change() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        test.set_pending_pledge_change(mix_id, None);
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn replayed_packet_has_to_be_signed_by_the_node() {
+        let mut test = TestSetup::new();
+        let (mix_id, keypair) = test.add_dummy_mixnode_with_keypair("mix-owner", None);
+        let other_keypair = identity::KeyPair::new(&mut test.rng);
+
+        let replay_tag = b"replay tag";
+        let original = signed_receipt(&keypair, replay_tag, 100);
+        let replay_evidence = |replayed: ForwardingReceipt| SlashingEvidence::ReplayedPacket {
+            original: original.clone(),
+            replayed,
+        };
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let mut bad_receipts = Vec::new();
+        for bad_signature in [
+            "not-base58!".to_string(),
+            bs58::encode(b"too short").into_string(),
+        ] {
+            let mut receipt = signed_receipt(&keypair, replay_tag, 200);
+            receipt.signature = bad_signature;
+            bad_receipts.push(receipt);
+        }
+        bad_receipts.push(signed_receipt(&other_keypair, replay_tag, 200));
+        // receipt of a different packet
+        bad_receipts.push(signed_receipt(&keypair, b"other replay tag", 200));
+        // a single receipt is not a proof of a replay
+        bad_receipts.push(original.clone());
+
+        for bad_receipt in bad_receipts {
+            let res = try_slash_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                rewarding_validator.clone(),
+                mix_id,
+                ten_percent(),
+                replay_evidence(bad_receipt),
+                false,
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::InvalidSlashingEvidence { .. })
+            ));
+        }
+
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            replay_evidence(signed_receipt(&keypair, replay_tag, 200)),
+            false,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn can_only_be_done_if_epoch_is_in_progress() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        test.set_epoch_reconciliation_state();
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert!(matches!(
+            res,
+            Err(MixnetContractError::EpochAdvancementInProgress {
+                current_state: EpochState::ReconcilingEvents
+            })
+        ));
+    }
+
+    #[test]
+    fn requires_valid_percentage_and_evidence() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env.clone(),
+            rewarding_validator.clone(),
+            mix_id,
+            Percent::hundred(),
+            evidence(),
+            false,
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::InvalidSlashingPercentage {
+                value: Percent::hundred()
+            })
+        );
+
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            SlashingEvidence::NetworkMonitorFailures {
+                failed_epochs: vec![],
+            },
+            false,
+        );
+        assert!(matches!(
+            res,
+            Err(MixnetContractError::InvalidSlashingEvidence { .. })
+        ));
+    }
+
+    #[test]
+    fn requires_bonded_node() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env.clone(),
+            rewarding_validator.clone(),
+            mix_id + 1,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::MixNodeBondNotFound { mix_id: mix_id + 1 })
+        );
+
+        let mut bond = mixnodes_storage::mixnode_bonds()
+            .load(test.deps().storage, mix_id)
+            .unwrap();
+        bond.is_unbonding = true;
+        mixnodes_storage::mixnode_bonds()
+            .save(test.deps_mut().storage, mix_id, &bond)
+            .unwrap();
+
+        let res = try_slash_mixnode(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            mix_id,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(res, Err(MixnetContractError::MixnodeIsUnbonding { mix_id }));
+    }
+
+    #[test]
+    fn stores_pending_record_and_event() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        try_slash_mixnode(
+            test.deps_mut(),
+            env.clone(),
+            rewarding_validator.clone(),
+            mix_id,
+            ten_percent(),
+            evidence(),
+            true,
+        )
+        .unwrap();
+
+        let record = storage::SLASHING_RECORDS
+            .load(test.deps().storage, 1)
+            .unwrap();
+        assert_eq!(record.node, SlashedNode::Mixnode { mix_id });
+        assert_eq!(record.submitted_by, rewarding_validator.sender);
+        assert_eq!(record.submitted_at_height, env.block.height);
+        assert_eq!(record.slash_percent, ten_percent());
+        assert!(record.redistribute);
+        assert!(record.status.is_pending());
+
+        let events = test.pending_epoch_events();
+        assert_eq!(
+            events[0].kind,
+            PendingEpochEventKind::SlashMixnode {
+                mix_id,
+                slashing_id: 1
+            }
+        );
+
+        let pending_changes = mixnodes_storage::PENDING_MIXNODE_CHANGES
+            .load(test.deps().storage, mix_id)
+            .unwrap();
+        assert_eq!(pending_changes.slashing, Some(1));
+        assert!(pending_changes.pledge_change.is_none());
+    }
+
+    #[test]
+    fn gateway_slashing_requires_bonded_gateway() {
+        let mut test = TestSetup::new();
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_gateway(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            "non-existent-gateway".to_string(),
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::GatewayBondNotFound {
+                identity: "non-existent-gateway".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn gateway_slashing_stores_pending_record_and_event() {
+        let mut test = TestSetup::new();
+        let identity = test.add_dummy_gateway("gateway-owner", None);
+
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        try_slash_gateway(
+            test.deps_mut(),
+            env.clone(),
+            rewarding_validator.clone(),
+            identity.clone(),
+            ten_percent(),
+            evidence(),
+            false,
+        )
+        .unwrap();
+
+        let record = storage::SLASHING_RECORDS
+            .load(test.deps().storage, 1)
+            .unwrap();
+        assert_eq!(
+            record.node,
+            SlashedNode::Gateway {
+                identity: identity.clone()
+            }
+        );
+        assert!(record.status.is_pending());
+
+        let events = test.pending_epoch_events();
+        assert_eq!(
+            events[0].kind,
+            PendingEpochEventKind::SlashGateway {
+                identity: identity.clone(),
+                slashing_id: 1
+            }
+        );
+        assert_eq!(
+            storage::PENDING_GATEWAY_SLASHINGS
+                .load(test.deps().storage, &identity)
+                .unwrap(),
+            1
+        );
+
+        // the pending slashing blocks the subsequent one
+        let res = try_slash_gateway(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            identity,
+            ten_percent(),
+            evidence(),
+            false,
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::PendingSlashing { slashing_id: 1 })
+        );
+    }
+
+    #[test]
+    fn gateway_replayed_packet_has_to_be_signed_by_the_gateway() {
+        let mut test = TestSetup::new();
+        let identity = test.add_dummy_gateway("gateway-owner", None);
+        let other_keypair = identity::KeyPair::new(&mut test.rng);
+
+        let replay_tag = b"replay tag";
+        let env = test.env();
+        let rewarding_validator = test.rewarding_validator();
+        let res = try_slash_gateway(
+            test.deps_mut(),
+            env,
+            rewarding_validator,
+            identity,
+            ten_percent(),
+            SlashingEvidence::ReplayedPacket {
+                original: signed_receipt(&other_keypair, replay_tag, 100),
+                replayed: signed_receipt(&other_keypair, replay_tag, 200),
+            },
+            false,
+        );
+        assert!(matches!(
+            res,
+            Err(MixnetContractError::InvalidSlashingEvidence { .. })
+        ));
+    }
+}
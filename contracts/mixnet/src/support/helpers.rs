@@ -4,10 +4,13 @@
 use crate::gateways::storage as gateways_storage;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::storage as mixnodes_storage;
+use crate::slashing::storage as slashing_storage;
 use cosmwasm_std::{wasm_execute, Addr, BankMsg, Coin, CosmosMsg, MessageInfo, Response, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::PendingMixNodeChanges;
-use mixnet_contract_common::{EpochState, EpochStatus, IdentityKeyRef, MixId, MixNodeBond};
+use mixnet_contract_common::{
+    EpochState, EpochStatus, IdentityKey, IdentityKeyRef, MixId, MixNodeBond,
+};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
 // helper trait to attach `Msg` to a response if it's provided
@@ -40,6 +43,25 @@ where
         amount: Coin,
     ) -> Result<Self, MixnetContractError>;
 
+    fn maybe_add_track_vesting_redelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError>;
+
+    fn maybe_add_track_vesting_gateway_undelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        identity: IdentityKey,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError>;
+
     fn maybe_add_track_vesting_unbond_mixnode_message(
         self,
         storage: &dyn Storage,
@@ -97,6 +119,74 @@ impl VestingTracking for Response {
         }
     }
 
+    fn maybe_add_track_vesting_redelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError> {
+        // if there's a proxy set (i.e. the vesting contract), send the track message
+        if let Some(proxy) = proxy {
+            let vesting_contract = mixnet_params_storage::vesting_contract_address(storage)?;
+
+            // exactly the same possible halting behaviour as in `maybe_add_track_vesting_undelegation_message`.
+            if proxy != vesting_contract {
+                return Err(MixnetContractError::ProxyIsNotVestingContract {
+                    received: proxy,
+                    vesting_contract,
+                });
+            }
+
+            let msg = VestingContractExecuteMsg::TrackRedelegation {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+            };
+            let track_redelegate_message = wasm_execute(proxy, &msg, vec![])?;
+            Ok(self.add_message(track_redelegate_message))
+        } else {
+            // there's no proxy so nothing to do
+            Ok(self)
+        }
+    }
+
+    fn maybe_add_track_vesting_gateway_undelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        identity: IdentityKey,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError> {
+        // if there's a proxy set (i.e. the vesting contract), send the track message
+        if let Some(proxy) = proxy {
+            let vesting_contract = mixnet_params_storage::vesting_contract_address(storage)?;
+
+            // exactly the same possible halting behaviour as in `maybe_add_track_vesting_undelegation_message`.
+            if proxy != vesting_contract {
+                return Err(MixnetContractError::ProxyIsNotVestingContract {
+                    received: proxy,
+                    vesting_contract,
+                });
+            }
+
+            let msg = VestingContractExecuteMsg::TrackGatewayUndelegation {
+                owner,
+                identity,
+                amount,
+            };
+            let track_undelegate_message = wasm_execute(proxy, &msg, vec![])?;
+            Ok(self.add_message(track_undelegate_message))
+        } else {
+            // there's no proxy so nothing to do
+            Ok(self)
+        }
+    }
+
     fn maybe_add_track_vesting_unbond_mixnode_message(
         self,
         storage: &dyn Storage,
@@ -304,6 +394,18 @@ pub(crate) fn ensure_is_authorized(
     Ok(())
 }
 
+// the owner of the contract is expected to be the multisig
+pub(crate) fn ensure_is_authorized_or_owner(
+    sender: &Addr,
+    storage: &dyn Storage,
+) -> Result<(), MixnetContractError> {
+    let state = crate::mixnet_contract_settings::storage::CONTRACT_STATE.load(storage)?;
+    if sender != &state.rewarding_validator_address && sender != &state.owner {
+        return Err(MixnetContractError::Unauthorized);
+    }
+    Ok(())
+}
+
 pub(crate) fn ensure_can_advance_epoch(
     sender: &Addr,
     storage: &dyn Storage,
@@ -387,6 +489,27 @@ pub(crate) fn ensure_no_pending_pledge_changes(
     Ok(())
 }
 
+pub(crate) fn ensure_no_pending_slashing(
+    pending_changes: &PendingMixNodeChanges,
+) -> Result<(), MixnetContractError> {
+    if let Some(slashing_id) = pending_changes.slashing {
+        return Err(MixnetContractError::PendingSlashing { slashing_id });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_no_pending_gateway_slashing(
+    storage: &dyn Storage,
+    identity: IdentityKeyRef<'_>,
+) -> Result<(), MixnetContractError> {
+    if let Some(slashing_id) =
+        slashing_storage::PENDING_GATEWAY_SLASHINGS.may_load(storage, identity)?
+    {
+        return Err(MixnetContractError::PendingSlashing { slashing_id });
+    }
+    Ok(())
+}
+
 // check if the target address has already bonded a mixnode or gateway,
 // in either case, return an appropriate error
 pub(crate) fn ensure_no_existing_bond(
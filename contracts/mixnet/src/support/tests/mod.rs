@@ -63,9 +63,10 @@ pub mod test_helpers {
     use mixnet_contract_common::rewarding::RewardDistribution;
     use mixnet_contract_common::{
         construct_family_join_permit, Delegation, EpochEventId, EpochState, EpochStatus, Gateway,
-        GatewayBondingPayload, IdentityKey, IdentityKeyRef, InitialRewardingParams, InstantiateMsg,
-        Interval, MixId, MixNode, MixNodeBond, MixnodeBondingPayload, Percent,
-        RewardedSetNodeStatus, SignableGatewayBondingMsg, SignableMixNodeBondingMsg,
+        GatewayBondingPayload, GatewayRewarding, IdentityKey, IdentityKeyRef,
+        InitialRewardingParams, InstantiateMsg, Interval, MixId, MixNode, MixNodeBond,
+        MixnodeBondingPayload, Percent, RewardedSetNodeStatus, SignableGatewayBondingMsg,
+        SignableMixNodeBondingMsg,
     };
     use nym_contracts_common::signing::{
         ContractMessageContent, MessageSignature, SignableMessage, SigningAlgorithm, SigningPurpose,
@@ -630,6 +631,55 @@ pub mod test_helpers {
             .unwrap();
         }
 
+        pub fn add_immediate_gateway_delegation(
+            &mut self,
+            delegator: &str,
+            amount: impl Into<Uint128>,
+            identity: &str,
+        ) {
+            let denom = rewarding_denom(self.deps().storage).unwrap();
+            let amount = Coin {
+                denom,
+                amount: amount.into(),
+            };
+            let env = self.env();
+            pending_events::delegate_to_gateway(
+                self.deps_mut(),
+                &env,
+                env.block.height,
+                Addr::unchecked(delegator),
+                identity.to_string(),
+                amount,
+                None,
+            )
+            .unwrap();
+        }
+
+        pub fn add_immediate_gateway_delegation_with_legal_proxy(
+            &mut self,
+            delegator: &str,
+            amount: impl Into<Uint128>,
+            identity: &str,
+        ) {
+            let denom = rewarding_denom(self.deps().storage).unwrap();
+            let amount = Coin {
+                denom,
+                amount: amount.into(),
+            };
+            let env = self.env();
+            let proxy = self.vesting_contract();
+            pending_events::delegate_to_gateway(
+                self.deps_mut(),
+                &env,
+                env.block.height,
+                Addr::unchecked(delegator),
+                identity.to_string(),
+                amount,
+                Some(proxy),
+            )
+            .unwrap();
+        }
+
         pub fn add_immediate_delegation_with_legal_proxy(
             &mut self,
             delegator: &str,
@@ -752,6 +802,21 @@ pub mod test_helpers {
             .unwrap();
         }
 
+        pub fn set_epoch_rewarding_state(&mut self) {
+            let being_advanced_by = self.rewarding_validator.sender.clone();
+            interval_storage::save_current_epoch_status(
+                self.deps_mut().storage,
+                &EpochStatus {
+                    being_advanced_by,
+                    state: EpochState::Rewarding {
+                        last_rewarded: 0,
+                        final_node_id: MixId::MAX,
+                    },
+                },
+            )
+            .unwrap();
+        }
+
         pub fn set_epoch_reconciliation_state(&mut self) {
             let being_advanced_by = self.rewarding_validator.sender.clone();
             interval_storage::save_current_epoch_status(
@@ -939,6 +1004,12 @@ pub mod test_helpers {
                 .unwrap()
         }
 
+        pub fn gateway_rewarding(&self, identity: &str) -> GatewayRewarding {
+            rewards_storage::GATEWAY_REWARDING
+                .load(self.deps().storage, identity)
+                .unwrap()
+        }
+
         #[allow(unused)]
         pub fn mix_bond(&self, mix_id: MixId) -> MixNodeBond {
             mixnode_bonds().load(self.deps().storage, mix_id).unwrap()
@@ -982,6 +1053,9 @@ pub mod test_helpers {
             .unwrap();
         let interval = interval_storage::current_interval(deps.storage).unwrap();
         let mut simulator = Simulator::new(rewarding_params, interval);
+        simulator.gateway_rewarding_params = rewards_storage::GATEWAY_REWARDING_PARAMS
+            .load(deps.storage)
+            .unwrap();
         simulator.nodes.insert(
             0,
             SimulatedNode {
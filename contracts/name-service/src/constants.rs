@@ -5,6 +5,9 @@ pub const MAX_NUMBER_OF_NAMES_FOR_ADDRESS: u32 = 100;
 pub const NAME_DEFAULT_RETRIEVAL_LIMIT: u32 = 100;
 pub const NAME_MAX_RETRIEVAL_LIMIT: u32 = 150;
 
+// Names have to be renewed once a year, unless configured otherwise.
+pub const DEFAULT_REGISTRATION_PERIOD_SECS: u64 = 365 * 24 * 60 * 60;
+
 // Storage keys
 pub const CONFIG_KEY: &str = "config";
 pub const ADMIN_KEY: &str = "admin";
@@ -1,4 +1,5 @@
 use crate::{
+    constants::DEFAULT_REGISTRATION_PERIOD_SECS,
     state::{self, Config},
     NameServiceError, Result,
 };
@@ -23,6 +24,9 @@ pub fn instantiate(
 
     let config = Config {
         deposit_required: msg.deposit_required,
+        registration_period: msg
+            .registration_period
+            .unwrap_or(DEFAULT_REGISTRATION_PERIOD_SECS),
     };
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     state::save_config(deps.storage, &config)?;
@@ -34,7 +38,7 @@ pub fn instantiate(
 
 pub fn migrate(
     deps: DepsMut<'_>,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, NameServiceError> {
     // Note: don't remove this particular bit of code as we have to ALWAYS check whether we have to
@@ -60,6 +64,7 @@ pub fn migrate(
 
         // If state structure changed in any contract version in the way migration is needed, it
         // should occur here, for example anything from `crate::queued_migrations::`
+        crate::queued_migrations::set_name_expiry(deps.storage, &env, &storage_version)?;
     }
 
     Ok(Response::new())
@@ -78,20 +83,38 @@ pub fn execute(
         } => execute::register(deps, env, info, name, owner_signature),
         ExecuteMsg::DeleteId { name_id } => execute::delete_id(deps, info, name_id),
         ExecuteMsg::DeleteName { name } => execute::delete_name(deps, info, name),
+        ExecuteMsg::Renew { name_id } => execute::renew(deps, env, info, name_id),
+        ExecuteMsg::UpdateAddress {
+            name_id,
+            address,
+            identity_key,
+            owner_signature,
+        } => execute::update_address(
+            deps,
+            env,
+            info,
+            name_id,
+            address,
+            identity_key,
+            owner_signature,
+        ),
+        ExecuteMsg::Transfer { name_id, new_owner } => {
+            execute::transfer(deps, env, info, name_id, new_owner)
+        }
         ExecuteMsg::UpdateDepositRequired { deposit_required } => {
             execute::update_deposit_required(deps, info, deposit_required)
         }
     }
 }
 
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary> {
     let response = match msg {
-        QueryMsg::NameId { name_id } => to_binary(&query::query_id(deps, name_id)?),
+        QueryMsg::NameId { name_id } => to_binary(&query::query_id(deps, env, name_id)?),
         QueryMsg::ByOwner { owner } => to_binary(&query::query_owner(deps, owner)?),
-        QueryMsg::ByAddress { address } => to_binary(&query::query_address(deps, address)?),
-        QueryMsg::ByName { name } => to_binary(&query::query_name(deps, name)?),
+        QueryMsg::ByAddress { address } => to_binary(&query::query_address(deps, env, address)?),
+        QueryMsg::ByName { name } => to_binary(&query::query_name(deps, env, name)?),
         QueryMsg::All { limit, start_after } => {
-            to_binary(&query::query_all_paged(deps, limit, start_after)?)
+            to_binary(&query::query_all_paged(deps, env, limit, start_after)?)
         }
         QueryMsg::SigningNonce { address } => {
             to_binary(&query::query_current_signing_nonce(deps, address)?)
@@ -112,7 +135,7 @@ mod tests {
             assert_config, assert_current_nonce, assert_empty, assert_name, assert_names,
             assert_not_found,
         },
-        fixture::new_name_details_with_sign,
+        fixture::{default_expiry, new_name_details_with_sign},
         helpers::{get_attribute, nyms, test_rng},
     };
 
@@ -129,6 +152,7 @@ mod tests {
         let mut deps = mock_dependencies();
         let msg = InstantiateMsg {
             deposit_required: Coin::new(100u128, DENOM),
+            registration_period: None,
         };
         let info = mock_info("creator", &[]);
         let admin = info.sender.clone();
@@ -331,6 +355,7 @@ mod tests {
             owner: Addr::unchecked(owner),
             block_height: 12345,
             deposit,
+            expires_at: default_expiry(),
         };
         assert_names(deps.as_ref(), &[expected_name.clone()]);
         assert_name(deps.as_ref(), &expected_name);
@@ -371,6 +396,7 @@ mod tests {
             owner: Addr::unchecked(steve),
             block_height: 12345,
             deposit,
+            expires_at: default_expiry(),
         };
         assert_names(deps.as_ref(), &[expected_name]);
 
@@ -9,15 +9,15 @@ use nym_contracts_common::{
 };
 use nym_name_service_common::{
     events::{
-        new_delete_id_event, new_delete_name_event, new_register_event,
-        new_update_deposit_required_event,
+        new_delete_id_event, new_delete_name_event, new_register_event, new_renew_event,
+        new_transfer_event, new_update_address_event, new_update_deposit_required_event,
+    },
+    signing_types::{
+        construct_name_register_sign_payload, construct_name_update_address_sign_payload,
     },
-    signing_types::construct_name_register_sign_payload,
     Address, NameDetails, NameId, NymName, RegisteredName,
 };
 
-use super::query;
-
 fn ensure_correct_deposit(will_deposit: Uint128, deposit_required: Uint128) -> Result<()> {
     match will_deposit.cmp(&deposit_required) {
         std::cmp::Ordering::Less => Err(NameServiceError::InsufficientDeposit {
@@ -33,8 +33,9 @@ fn ensure_correct_deposit(will_deposit: Uint128, deposit_required: Uint128) -> R
 }
 
 fn ensure_max_names_per_owner(deps: Deps, owner: Addr) -> Result<()> {
-    let current_entries = query::query_owner(deps, owner.to_string())?;
-    if current_entries.names.len() < MAX_NUMBER_OF_NAMES_PER_OWNER as usize {
+    // note: expired names still count towards the limit until they're removed
+    let current_entries = state::names::load_owner(deps.storage, owner.clone())?;
+    if current_entries.len() < MAX_NUMBER_OF_NAMES_PER_OWNER as usize {
         Ok(())
     } else {
         Err(NameServiceError::ReachedMaxNamesForOwner {
@@ -45,8 +46,8 @@ fn ensure_max_names_per_owner(deps: Deps, owner: Addr) -> Result<()> {
 }
 
 fn ensure_max_names_per_address(deps: Deps, address: Address) -> Result<()> {
-    let current_entries = query::query_address(deps, address.clone())?;
-    if current_entries.names.len() < MAX_NUMBER_OF_NAMES_FOR_ADDRESS as usize {
+    let current_entries = state::names::load_address(deps.storage, &address)?;
+    if current_entries.len() < MAX_NUMBER_OF_NAMES_FOR_ADDRESS as usize {
         Ok(())
     } else {
         Err(NameServiceError::ReachedMaxNamesForAddress {
@@ -64,15 +65,38 @@ fn ensure_name_exists(deps: Deps, name_id: NameId) -> Result<()> {
     }
 }
 
-fn ensure_name_not_exists(deps: Deps, name: &NymName) -> Result<()> {
-    if state::names::has_name(deps.storage, name) {
-        println!("name already exists");
-        Err(NameServiceError::NameAlreadyRegistered { name: name.clone() })
+/// Removes the registration of the name if it has already expired, so that it could be registered
+/// again. It fails if the name is still actively being used.
+fn remove_expired_name(
+    deps: DepsMut,
+    current_time: u64,
+    name: &NymName,
+) -> Result<Option<RegisteredName>> {
+    match state::names::load_name(deps.storage, name) {
+        Ok(existing) if existing.is_expired(current_time) => {
+            state::names::remove_id(deps.storage, existing.id)?;
+            Ok(Some(existing))
+        }
+        Ok(_) => Err(NameServiceError::NameAlreadyRegistered { name: name.clone() }),
+        Err(NameServiceError::NameNotFound { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn ensure_not_expired(name: &RegisteredName, current_time: u64) -> Result<()> {
+    if name.is_expired(current_time) {
+        Err(NameServiceError::NameExpired {
+            name: name.entry().clone(),
+        })
     } else {
         Ok(())
     }
 }
 
+fn ensure_no_funds(info: &MessageInfo) -> Result<()> {
+    cw_utils::nonpayable(info).map_err(|source| NameServiceError::UnexpectedFunds { source })
+}
+
 fn ensure_sender_authorized(info: MessageInfo, names: &RegisteredName) -> Result<()> {
     if info.sender == names.owner {
         Ok(())
@@ -112,6 +136,27 @@ fn verify_register_signature(
     }
 }
 
+fn verify_update_address_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    name: NameDetails,
+    signature: MessageSignature,
+) -> Result<()> {
+    // recover the public key of the new address
+    let public_key = decode_ed25519_identity_key(&name.identity_key)?;
+
+    // reconstruct the payload
+    let nonce = state::get_signing_nonce(deps.storage, sender.clone())?;
+
+    let msg = construct_name_update_address_sign_payload(nonce, sender, name);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(NameServiceError::InvalidEd25519Signature)
+    }
+}
+
 fn decode_ed25519_identity_key(encoded: &IdentityKey) -> Result<[u8; 32]> {
     let mut public_key = [0u8; 32];
     let used = bs58::decode(encoded)
@@ -128,14 +173,23 @@ fn decode_ed25519_identity_key(encoded: &IdentityKey) -> Result<[u8; 32]> {
 }
 
 /// Register a new name. It will be assigned a new name id.
+/// If the name has been registered before, but the registration has expired, the old entry is
+/// removed and its deposit returned to the previous owner.
 pub fn register(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     name: NameDetails,
     owner_signature: MessageSignature,
 ) -> Result<Response> {
-    ensure_name_not_exists(deps.as_ref(), &name.name)?;
+    let mut response = Response::new();
+    if let Some(expired) = remove_expired_name(deps.branch(), env.block.time.seconds(), &name.name)?
+    {
+        response = response
+            .add_message(return_deposit(&expired))
+            .add_event(new_delete_id_event(expired));
+    }
+
     ensure_max_names_per_owner(deps.as_ref(), info.sender.clone())?;
     ensure_max_names_per_address(deps.as_ref(), name.address.clone())?;
 
@@ -158,16 +212,18 @@ pub fn register(
     state::increment_signing_nonce(deps.storage, info.sender.clone())?;
 
     let id = state::next_name_id_counter(deps.storage)?;
+    let expires_at = env.block.time.seconds() + state::registration_period(deps.storage)?;
     let new_name = RegisteredName {
         id,
         name,
         owner: info.sender,
         block_height: env.block.height,
         deposit,
+        expires_at,
     };
     state::names::save(deps.storage, &new_name)?;
 
-    Ok(Response::new().add_event(new_register_event(new_name)))
+    Ok(response.add_event(new_register_event(new_name)))
 }
 
 /// Delete an exsisting name.
@@ -186,7 +242,7 @@ pub fn delete_id(deps: DepsMut, info: MessageInfo, name_id: NameId) -> Result<Re
 
 /// Delete an existing name by name.
 pub(crate) fn delete_name(deps: DepsMut, info: MessageInfo, name: NymName) -> Result<Response> {
-    let name_to_delete = query::query_name(deps.as_ref(), name)?;
+    let name_to_delete = state::names::load_name(deps.storage, &name)?;
     ensure_sender_authorized(info, &name_to_delete)?;
 
     state::names::remove_id(deps.storage, name_to_delete.id)?;
@@ -197,6 +253,87 @@ pub(crate) fn delete_name(deps: DepsMut, info: MessageInfo, name: NymName) -> Re
         .add_event(new_delete_name_event(name_to_delete)))
 }
 
+/// Renew the registration of a name, so that it would remain valid for another registration
+/// period, counting from now.
+pub(crate) fn renew(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name_id: NameId,
+) -> Result<Response> {
+    ensure_no_funds(&info)?;
+    ensure_name_exists(deps.as_ref(), name_id)?;
+    let mut name = state::names::load_id(deps.storage, name_id)?;
+    ensure_sender_authorized(info, &name)?;
+
+    name.expires_at = env.block.time.seconds() + state::registration_period(deps.storage)?;
+    state::names::save(deps.storage, &name)?;
+
+    Ok(Response::new().add_event(new_renew_event(name)))
+}
+
+/// Point an existing name at a new address. The owner of the new address has to sign off on it,
+/// the same way as when registering a name.
+pub(crate) fn update_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name_id: NameId,
+    address: Address,
+    identity_key: IdentityKey,
+    owner_signature: MessageSignature,
+) -> Result<Response> {
+    ensure_no_funds(&info)?;
+    ensure_name_exists(deps.as_ref(), name_id)?;
+    let mut name = state::names::load_id(deps.storage, name_id)?;
+    ensure_sender_authorized(info.clone(), &name)?;
+    ensure_not_expired(&name, env.block.time.seconds())?;
+    if name.name.address != address {
+        ensure_max_names_per_address(deps.as_ref(), address.clone())?;
+    }
+
+    let details = NameDetails {
+        name: name.name.name.clone(),
+        address,
+        identity_key,
+    };
+    verify_update_address_signature(
+        deps.as_ref(),
+        info.sender.clone(),
+        details.clone(),
+        owner_signature,
+    )?;
+    state::increment_signing_nonce(deps.storage, info.sender)?;
+
+    name.name = details;
+    state::names::save(deps.storage, &name)?;
+
+    Ok(Response::new().add_event(new_update_address_event(name)))
+}
+
+/// Transfer the ownership of a name to a new owner. The deposit stays with the name, i.e. it is
+/// going to be returned to the new owner once the name is deleted.
+pub(crate) fn transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name_id: NameId,
+    new_owner: String,
+) -> Result<Response> {
+    ensure_no_funds(&info)?;
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    ensure_name_exists(deps.as_ref(), name_id)?;
+    let mut name = state::names::load_id(deps.storage, name_id)?;
+    ensure_sender_authorized(info, &name)?;
+    ensure_not_expired(&name, env.block.time.seconds())?;
+    ensure_max_names_per_owner(deps.as_ref(), new_owner.clone())?;
+
+    let previous_owner = std::mem::replace(&mut name.owner, new_owner);
+    state::names::save(deps.storage, &name)?;
+
+    Ok(Response::new().add_event(new_transfer_event(name, previous_owner)))
+}
+
 /// Update the deposit required to register new names
 pub(crate) fn update_deposit_required(
     deps: DepsMut,
@@ -1,4 +1,4 @@
-use cosmwasm_std::Deps;
+use cosmwasm_std::{Deps, Env};
 use nym_contracts_common::{signing::Nonce, ContractBuildInformation};
 use nym_name_service_common::{
     response::{ConfigResponse, NamesListResponse, PagedNamesListResponse},
@@ -7,39 +7,65 @@ use nym_name_service_common::{
 
 use crate::{
     state::{self, names::PagedLoad},
-    Result,
+    NameServiceError, Result,
 };
 
-pub fn query_id(deps: Deps, name_id: NameId) -> Result<RegisteredName> {
-    state::names::load_id(deps.storage, name_id)
+// expired registrations no longer resolve to anything, even if they haven't been removed yet
+fn active_names(env: &Env, names: Vec<RegisteredName>) -> Vec<RegisteredName> {
+    let current_time = env.block.time.seconds();
+    names
+        .into_iter()
+        .filter(|name| !name.is_expired(current_time))
+        .collect()
 }
 
+pub fn query_id(deps: Deps, env: Env, name_id: NameId) -> Result<RegisteredName> {
+    let name = state::names::load_id(deps.storage, name_id)?;
+    if name.is_expired(env.block.time.seconds()) {
+        return Err(NameServiceError::NotFound { name_id });
+    }
+    Ok(name)
+}
+
+/// Unlike other queries, this one also returns the expired names of the owner,
+/// so that they could still be renewed or deleted.
 pub fn query_owner(deps: Deps, owner: String) -> Result<NamesListResponse> {
     let owner = deps.api.addr_validate(&owner)?;
     let names = state::names::load_owner(deps.storage, owner)?;
     Ok(NamesListResponse::new(names))
 }
 
-pub fn query_address(deps: Deps, address: Address) -> Result<NamesListResponse> {
+pub fn query_address(deps: Deps, env: Env, address: Address) -> Result<NamesListResponse> {
     let names = state::names::load_address(deps.storage, &address)?;
-    Ok(NamesListResponse::new(names))
+    Ok(NamesListResponse::new(active_names(&env, names)))
 }
 
-pub fn query_name(deps: Deps, name: NymName) -> Result<RegisteredName> {
-    state::names::load_name(deps.storage, &name)
+pub fn query_name(deps: Deps, env: Env, name: NymName) -> Result<RegisteredName> {
+    let registered_name = state::names::load_name(deps.storage, &name)?;
+    if registered_name.is_expired(env.block.time.seconds()) {
+        return Err(NameServiceError::NameNotFound { name });
+    }
+    Ok(registered_name)
 }
 
 pub fn query_all_paged(
     deps: Deps,
+    env: Env,
     limit: Option<u32>,
     start_after: Option<NameId>,
 ) -> Result<PagedNamesListResponse> {
+    // note: the paging is based on all the stored names, so a page might contain fewer names
+    // than the limit if some of them have expired
     let PagedLoad {
         names,
         limit,
         start_next_after,
     } = state::names::load_all_paged(deps.storage, limit, start_after)?;
-    Ok(PagedNamesListResponse::new(names, limit, start_next_after))
+    Ok(PagedNamesListResponse::new(
+        active_names(&env, names),
+        limit,
+        start_next_after,
+    ))
 }
 
 pub fn query_current_signing_nonce(deps: Deps<'_>, address: String) -> Result<Nonce> {
@@ -0,0 +1,169 @@
+use cosmwasm_std::Addr;
+use nym_name_service_common::{
+    error::NameServiceError, msg::QueryMsg, response::NamesListResponse, Address, NymName,
+    RegisteredName,
+};
+
+use crate::{
+    constants::DEFAULT_REGISTRATION_PERIOD_SECS,
+    test_helpers::{fixture::default_expiry, helpers::nyms},
+};
+
+use super::test_setup::TestSetup;
+
+#[test]
+fn registered_name_has_expiry() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(
+        &NymName::new("steves-server").unwrap(),
+        &Address::new("nym-address"),
+        &owner,
+        &nyms(100),
+    );
+    assert_eq!(setup.query_id(1).expires_at, default_expiry());
+}
+
+#[test]
+fn active_name_cant_be_registered_again() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    setup.sign_and_register(
+        &name,
+        &Address::new("nym-address"),
+        &Addr::unchecked("owner"),
+        &nyms(100),
+    );
+
+    // just before the expiry the name is still taken
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS - 1);
+    let owner2 = Addr::unchecked("owner2");
+    let reg_name = setup.new_signed_name(&name, &Address::new("nym-address2"), &owner2, &nyms(100));
+    let register_resp: NameServiceError = setup
+        .try_register(&reg_name, &owner2)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        register_resp,
+        NameServiceError::NameAlreadyRegistered { name }
+    );
+}
+
+#[test]
+fn expired_name_can_be_registered_by_someone_else() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+    assert_eq!(setup.balance(&owner), nyms(150));
+
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS);
+    let owner2 = Addr::unchecked("owner2");
+    let reg_name = setup.new_signed_name(&name, &Address::new("nym-address2"), &owner2, &nyms(100));
+    setup.register(&reg_name, &owner2);
+
+    // the previous owner got their deposit back and the contract only holds the new one
+    assert_eq!(setup.balance(&owner), nyms(250));
+    assert_eq!(setup.balance(&owner2), nyms(150));
+    assert_eq!(setup.contract_balance(), nyms(100));
+
+    let names = setup.query_all().names;
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].id, 2);
+    assert_eq!(names[0].owner, owner2);
+    assert_eq!(names[0].name.address, Address::new("nym-address2"));
+}
+
+#[test]
+fn renew_extends_registration() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS - 10);
+    setup.renew(1, &owner);
+    assert_eq!(
+        setup.query_id(1).expires_at,
+        default_expiry() + DEFAULT_REGISTRATION_PERIOD_SECS - 10
+    );
+
+    // after the original expiry the name is still not available to others
+    setup.advance_time(20);
+    let owner2 = Addr::unchecked("owner2");
+    let reg_name = setup.new_signed_name(&name, &Address::new("nym-address2"), &owner2, &nyms(100));
+    assert!(setup.try_register(&reg_name, &owner2).is_err());
+}
+
+#[test]
+fn only_owner_can_renew() {
+    let mut setup = TestSetup::new();
+    setup.sign_and_register(
+        &NymName::new("steves-server").unwrap(),
+        &Address::new("nym-address"),
+        &Addr::unchecked("owner"),
+        &nyms(100),
+    );
+
+    let renew_resp: NameServiceError = setup
+        .try_renew(1, &Addr::unchecked("owner2"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        renew_resp,
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("owner2")
+        }
+    );
+    assert_eq!(setup.query_id(1).expires_at, default_expiry());
+}
+
+#[test]
+fn expired_names_are_not_returned_by_queries() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let address = Address::new("nym-address");
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &address, &owner, &nyms(100));
+    setup.sign_and_register(
+        &NymName::new("steves-other-server").unwrap(),
+        &address,
+        &owner,
+        &nyms(100),
+    );
+    setup.advance_time(10);
+    setup.renew(2, &owner);
+
+    // only the first registration has expired
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS - 10);
+
+    assert!(setup
+        .try_query::<RegisteredName>(&QueryMsg::NameId { name_id: 1 })
+        .is_err());
+    assert!(setup
+        .try_query::<RegisteredName>(&QueryMsg::ByName { name: name.clone() })
+        .is_err());
+
+    let by_address: NamesListResponse = setup.query(&QueryMsg::ByAddress {
+        address: address.clone(),
+    });
+    assert_eq!(by_address.names.len(), 1);
+    assert_eq!(by_address.names[0].id, 2);
+
+    let all = setup.query_all();
+    assert_eq!(all.names.len(), 1);
+    assert_eq!(all.names[0].id, 2);
+    assert_eq!(all.start_next_after, Some(2));
+
+    // but the owner can still see it, so that it could be renewed
+    let by_owner: NamesListResponse = setup.query(&QueryMsg::ByOwner {
+        owner: owner.to_string(),
+    });
+    assert_eq!(by_owner.names.len(), 2);
+
+    setup.renew(1, &owner);
+    assert_eq!(setup.query_id(1).name.name, name);
+    assert_eq!(setup.query_all().names.len(), 2);
+}
@@ -1,11 +1,14 @@
 //! Integration tests using cw-multi-test.
 
 mod delete;
+mod expiry;
 mod name_id;
 mod query;
 mod register;
 mod test_name;
 mod test_setup;
+mod transfer;
+mod update_address;
 
 #[test]
 fn instantiate_contract() {
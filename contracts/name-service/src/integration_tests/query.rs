@@ -5,7 +5,7 @@ use nym_name_service_common::{
 };
 
 use crate::{
-    constants::NAME_DEFAULT_RETRIEVAL_LIMIT,
+    constants::{DEFAULT_REGISTRATION_PERIOD_SECS, NAME_DEFAULT_RETRIEVAL_LIMIT},
     test_helpers::{fixture::new_name, helpers::nyms},
 };
 
@@ -17,6 +17,7 @@ fn query_config() {
         TestSetup::new().query_config(),
         ConfigResponse {
             deposit_required: nyms(100),
+            registration_period: DEFAULT_REGISTRATION_PERIOD_SECS,
         }
     );
 }
@@ -7,7 +7,10 @@ use rstest::rstest;
 
 use crate::{
     constants::NAME_DEFAULT_RETRIEVAL_LIMIT,
-    test_helpers::{fixture::new_name, helpers::nyms},
+    test_helpers::{
+        fixture::{default_expiry, new_name},
+        helpers::nyms,
+    },
 };
 
 use super::test_setup::TestSetup;
@@ -62,6 +65,7 @@ fn basic_register(mut setup: TestSetup) {
                 owner: owner.clone(),
                 block_height: 12345,
                 deposit: nyms(100),
+                expires_at: default_expiry(),
             }],
             per_page: NAME_DEFAULT_RETRIEVAL_LIMIT as usize,
             start_next_after: Some(1),
@@ -77,6 +81,7 @@ fn basic_register(mut setup: TestSetup) {
             owner: owner.clone(),
             block_height: 12345,
             deposit: nyms(100),
+            expires_at: default_expiry(),
         }
     );
 
@@ -1,16 +1,22 @@
-use cosmwasm_std::{coins, Addr, Coin, Uint128};
+use cosmwasm_std::{coins, Addr, Coin, StdResult, Uint128};
 use cw_multi_test::{App, AppBuilder, AppResponse, ContractWrapper, Executor};
 use nym_contracts_common::signing::Nonce;
 use nym_name_service_common::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     response::{ConfigResponse, PagedNamesListResponse},
-    signing_types::{construct_name_register_sign_payload, SignableNameRegisterMsg},
+    signing_types::{
+        construct_name_register_sign_payload, construct_name_update_address_sign_payload,
+        SignableNameRegisterMsg,
+    },
     Address, NameDetails, NameId, NymName, RegisteredName,
 };
 use rand_chacha::ChaCha20Rng;
 use serde::de::DeserializeOwned;
 
-use crate::test_helpers::helpers::{get_app_attribute, test_rng};
+use crate::test_helpers::{
+    helpers::{get_app_attribute, test_rng},
+    signing::ed25519_sign_message,
+};
 
 use super::test_name::{SignedTestName, TestName};
 
@@ -60,6 +66,7 @@ impl TestSetup {
             Addr::unchecked("admin"),
             &InstantiateMsg {
                 deposit_required: Coin::new(100, DENOM),
+                registration_period: None,
             },
             &[],
             "contract_label",
@@ -73,10 +80,11 @@ impl TestSetup {
     }
 
     pub fn query<T: DeserializeOwned>(&self, query_msg: &QueryMsg) -> T {
-        self.app
-            .wrap()
-            .query_wasm_smart(&self.addr, query_msg)
-            .unwrap()
+        self.try_query(query_msg).unwrap()
+    }
+
+    pub fn try_query<T: DeserializeOwned>(&self, query_msg: &QueryMsg) -> StdResult<T> {
+        self.app.wrap().query_wasm_smart(&self.addr, query_msg)
     }
 
     pub fn query_config(&self) -> ConfigResponse {
@@ -199,6 +207,73 @@ impl TestSetup {
             .unwrap()
     }
 
+    pub fn try_renew(&mut self, name_id: NameId, owner: &Addr) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::Renew { name_id },
+            &[],
+        )
+    }
+
+    pub fn renew(&mut self, name_id: NameId, owner: &Addr) -> AppResponse {
+        let renew_resp = self.try_renew(name_id, owner).unwrap();
+        assert_eq!(
+            get_app_attribute(&renew_resp, "wasm-renew", "action"),
+            "renew"
+        );
+        renew_resp
+    }
+
+    // Point the name at the address of the provided (new) name, signed with its keys
+    pub fn try_update_address(
+        &mut self,
+        name_id: NameId,
+        new_name: &TestName,
+        owner: &Addr,
+    ) -> anyhow::Result<AppResponse> {
+        let nonce = self.query_signing_nonce(owner.to_string());
+        let payload = construct_name_update_address_sign_payload(
+            nonce,
+            owner.clone(),
+            new_name.details().clone(),
+        );
+        let owner_signature = ed25519_sign_message(payload, new_name.keys.private_key());
+        self.app.execute_contract(
+            owner.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::UpdateAddress {
+                name_id,
+                address: new_name.details().address.clone(),
+                identity_key: new_name.identity_key().clone(),
+                owner_signature,
+            },
+            &[],
+        )
+    }
+
+    pub fn try_transfer(
+        &mut self,
+        name_id: NameId,
+        owner: &Addr,
+        new_owner: &Addr,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::Transfer {
+                name_id,
+                new_owner: new_owner.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(seconds));
+    }
+
     pub fn balance(&self, address: impl Into<String>) -> Coin {
         self.app.wrap().query_balance(address, DENOM).unwrap()
     }
@@ -0,0 +1,72 @@
+use cosmwasm_std::Addr;
+use nym_name_service_common::{error::NameServiceError, Address, NymName};
+
+use crate::{constants::DEFAULT_REGISTRATION_PERIOD_SECS, test_helpers::helpers::nyms};
+
+use super::test_setup::TestSetup;
+
+#[test]
+fn transfer_moves_name_and_deposit_to_new_owner() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let new_owner = Addr::unchecked("owner2");
+    setup.sign_and_register(
+        &NymName::new("steves-server").unwrap(),
+        &Address::new("nym-address"),
+        &owner,
+        &nyms(100),
+    );
+
+    setup.try_transfer(1, &owner, &new_owner).unwrap();
+    assert_eq!(setup.query_id(1).owner, new_owner);
+
+    // the previous owner can no longer manage the name...
+    assert!(setup.try_delete(1, owner.clone()).is_err());
+    assert!(setup.try_transfer(1, &owner, &owner).is_err());
+
+    // ...while the new one gets the deposit back upon deleting it
+    setup.delete(1, new_owner.clone());
+    assert_eq!(setup.balance(&owner), nyms(150));
+    assert_eq!(setup.balance(&new_owner), nyms(350));
+    assert_eq!(setup.contract_balance(), nyms(0));
+}
+
+#[test]
+fn only_owner_can_transfer() {
+    let mut setup = TestSetup::new();
+    setup.sign_and_register(
+        &NymName::new("steves-server").unwrap(),
+        &Address::new("nym-address"),
+        &Addr::unchecked("owner"),
+        &nyms(100),
+    );
+
+    let transfer_resp: NameServiceError = setup
+        .try_transfer(1, &Addr::unchecked("owner2"), &Addr::unchecked("owner2"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        transfer_resp,
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("owner2")
+        }
+    );
+    assert_eq!(setup.query_id(1).owner, Addr::unchecked("owner"));
+}
+
+#[test]
+fn expired_name_cant_be_transferred() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS);
+    let transfer_resp: NameServiceError = setup
+        .try_transfer(1, &owner, &Addr::unchecked("owner2"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(transfer_resp, NameServiceError::NameExpired { name });
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::Addr;
+use nym_name_service_common::{error::NameServiceError, Address, NymName};
+
+use crate::{
+    constants::DEFAULT_REGISTRATION_PERIOD_SECS,
+    test_helpers::{fixture::default_expiry, helpers::nyms},
+};
+
+use super::test_setup::TestSetup;
+
+#[test]
+fn update_address_keeps_the_name() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 1);
+
+    let new_address = setup.new_name(&name, &Address::new("new-nym-address"));
+    setup.try_update_address(1, &new_address, &owner).unwrap();
+
+    let updated = setup.query_id(1);
+    assert_eq!(updated.name, new_address.details().clone());
+    assert_eq!(updated.owner, owner);
+    assert_eq!(updated.expires_at, default_expiry());
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 2);
+
+    // no deposit is moved around
+    assert_eq!(setup.contract_balance(), nyms(100));
+    assert_eq!(setup.balance(&owner), nyms(150));
+}
+
+#[test]
+fn update_address_requires_valid_signature() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+
+    // the signature is made with a key that's different from the announced one
+    let mut new_address = setup.new_name(&name, &Address::new("new-nym-address"));
+    let other = setup.new_name(&name, &Address::new("new-nym-address"));
+    new_address.keys = other.keys;
+
+    let update_resp: NameServiceError = setup
+        .try_update_address(1, &new_address, &owner)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(update_resp, NameServiceError::InvalidEd25519Signature);
+    assert_eq!(setup.query_id(1).name.address, Address::new("nym-address"));
+}
+
+#[test]
+fn only_owner_can_update_address() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    setup.sign_and_register(
+        &name,
+        &Address::new("nym-address"),
+        &Addr::unchecked("owner"),
+        &nyms(100),
+    );
+
+    let new_address = setup.new_name(&name, &Address::new("new-nym-address"));
+    let update_resp: NameServiceError = setup
+        .try_update_address(1, &new_address, &Addr::unchecked("owner2"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        update_resp,
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("owner2")
+        }
+    );
+}
+
+#[test]
+fn expired_name_cant_be_updated() {
+    let mut setup = TestSetup::new();
+    let name = NymName::new("steves-server").unwrap();
+    let owner = Addr::unchecked("owner");
+    setup.sign_and_register(&name, &Address::new("nym-address"), &owner, &nyms(100));
+
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS);
+    let new_address = setup.new_name(&name, &Address::new("new-nym-address"));
+    let update_resp: NameServiceError = setup
+        .try_update_address(1, &new_address, &owner)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(update_resp, NameServiceError::NameExpired { name });
+}
@@ -12,6 +12,7 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
 
 mod contract;
+mod queued_migrations;
 mod state;
 
 pub mod constants;
@@ -0,0 +1,77 @@
+use cosmwasm_std::{Env, Storage};
+use semver::Version;
+
+use crate::{state, Result};
+
+/// Version of the contract in which the name expiry got introduced.
+const NAME_EXPIRY_VERSION: Version = Version::new(0, 1, 1);
+
+/// Names registered before the expiry got introduced are given a full registration period,
+/// starting from the time of the migration.
+pub fn set_name_expiry(
+    store: &mut dyn Storage,
+    env: &Env,
+    storage_version: &Version,
+) -> Result<()> {
+    if storage_version >= &NAME_EXPIRY_VERSION {
+        return Ok(());
+    }
+
+    let expires_at = env.block.time.seconds() + state::registration_period(store)?;
+    for mut name in state::names::load_all(store)? {
+        if name.expires_at == 0 {
+            name.expires_at = expires_at;
+            state::names::save(store, &name)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+
+    use crate::{
+        constants::DEFAULT_REGISTRATION_PERIOD_SECS,
+        test_helpers::{fixture::name_fixture, transactions::instantiate_test_contract},
+    };
+
+    use nym_name_service_common::RegisteredName;
+
+    use super::*;
+
+    #[test]
+    fn names_without_expiry_get_a_full_registration_period() {
+        let mut deps = instantiate_test_contract();
+        let legacy_name = RegisteredName {
+            expires_at: 0,
+            ..name_fixture(1)
+        };
+        state::names::save(deps.as_mut().storage, &legacy_name).unwrap();
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(1000);
+        set_name_expiry(deps.as_mut().storage, &env, &Version::new(0, 1, 0)).unwrap();
+
+        let migrated = state::names::load_id(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(
+            migrated.expires_at,
+            env.block.time.seconds() + DEFAULT_REGISTRATION_PERIOD_SECS
+        );
+    }
+
+    #[test]
+    fn is_skipped_if_contract_already_had_expiry() {
+        let mut deps = instantiate_test_contract();
+        let legacy_name = RegisteredName {
+            expires_at: 0,
+            ..name_fixture(1)
+        };
+        state::names::save(deps.as_mut().storage, &legacy_name).unwrap();
+
+        set_name_expiry(deps.as_mut().storage, &mock_env(), &NAME_EXPIRY_VERSION).unwrap();
+
+        let not_migrated = state::names::load_id(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(not_migrated.expires_at, 0);
+    }
+}
@@ -3,19 +3,29 @@ use cw_storage_plus::Item;
 use nym_name_service_common::response::ConfigResponse;
 use serde::{Deserialize, Serialize};
 
-use crate::{constants::CONFIG_KEY, Result};
+use crate::{
+    constants::{CONFIG_KEY, DEFAULT_REGISTRATION_PERIOD_SECS},
+    Result,
+};
 
 const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub(crate) struct Config {
     pub deposit_required: Coin,
+    #[serde(default = "default_registration_period")]
+    pub registration_period: u64,
+}
+
+fn default_registration_period() -> u64 {
+    DEFAULT_REGISTRATION_PERIOD_SECS
 }
 
 impl From<Config> for ConfigResponse {
     fn from(config: Config) -> Self {
         ConfigResponse {
             deposit_required: config.deposit_required,
+            registration_period: config.registration_period,
         }
     }
 }
@@ -32,3 +42,10 @@ pub(crate) fn load_config(store: &dyn Storage) -> Result<Config> {
 pub(crate) fn deposit_required(store: &dyn Storage) -> Result<Coin> {
     Ok(CONFIG.load(store).map(|config| config.deposit_required)?)
 }
+
+/// Return for how long (in seconds) the names are registered for.
+pub(crate) fn registration_period(store: &dyn Storage) -> Result<u64> {
+    Ok(CONFIG
+        .load(store)
+        .map(|config| config.registration_period)?)
+}
@@ -5,6 +5,6 @@ pub mod names;
 pub mod nonce;
 
 pub(crate) use admin::{assert_admin, set_admin};
-pub(crate) use config::{deposit_required, load_config, save_config, Config};
+pub(crate) use config::{deposit_required, load_config, registration_period, save_config, Config};
 pub(crate) use name_id_counter::next_name_id_counter;
 pub(crate) use nonce::{get_signing_nonce, increment_signing_nonce};
@@ -21,6 +21,7 @@ mod tests {
 
     use crate::test_helpers::{
         assert::assert_names,
+        fixture::default_expiry,
         helpers::{nyms, test_rng},
         transactions::{delete_name_id, instantiate_test_contract, register_name},
     };
@@ -51,6 +52,7 @@ mod tests {
                     owner: Addr::unchecked("steve"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    expires_at: default_expiry(),
                 },
                 RegisteredName {
                     id: 2,
@@ -58,6 +60,7 @@ mod tests {
                     owner: Addr::unchecked("steve"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    expires_at: default_expiry(),
                 },
                 RegisteredName {
                     id: 3,
@@ -65,6 +68,7 @@ mod tests {
                     owner: Addr::unchecked("steve"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    expires_at: default_expiry(),
                 },
             ],
         );
@@ -89,6 +93,7 @@ mod tests {
                 owner: Addr::unchecked("steve"),
                 block_height: 12345,
                 deposit: nyms(100),
+                expires_at: default_expiry(),
             }],
         );
 
@@ -72,6 +72,7 @@ pub fn has_name_id(store: &dyn Storage, name_id: NameId) -> bool {
     names().has(store, name_id)
 }
 
+#[cfg(test)]
 pub fn has_name(store: &dyn Storage, name: &NymName) -> bool {
     load_name(store, name).is_ok()
 }
@@ -123,6 +124,15 @@ pub struct PagedLoad {
     pub start_next_after: Option<NameId>,
 }
 
+/// Loads every single registered name, without any paging.
+pub fn load_all(store: &dyn Storage) -> Result<Vec<RegisteredName>> {
+    names()
+        .range(store, None, None, Order::Ascending)
+        .map(|res| res.map(|(_, name)| name))
+        .collect::<StdResult<Vec<_>>>()
+        .map_err(NameServiceError::from)
+}
+
 pub fn load_all_paged(
     store: &dyn Storage,
     limit: Option<u32>,
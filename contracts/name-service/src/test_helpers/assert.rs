@@ -6,13 +6,22 @@ use nym_name_service_common::{
     NameId, RegisteredName,
 };
 
-use crate::{constants::NAME_DEFAULT_RETRIEVAL_LIMIT, NameServiceError};
+use crate::{
+    constants::{DEFAULT_REGISTRATION_PERIOD_SECS, NAME_DEFAULT_RETRIEVAL_LIMIT},
+    NameServiceError,
+};
 
 pub fn assert_config(deps: Deps, admin: &Addr, deposit_required: Coin) {
     crate::state::assert_admin(deps, admin).unwrap();
     let res = crate::contract::query(deps, mock_env(), QueryMsg::Config {}).unwrap();
     let config: ConfigResponse = from_binary(&res).unwrap();
-    assert_eq!(config, ConfigResponse { deposit_required });
+    assert_eq!(
+        config,
+        ConfigResponse {
+            deposit_required,
+            registration_period: DEFAULT_REGISTRATION_PERIOD_SECS,
+        }
+    );
 }
 
 pub fn assert_names(deps: Deps, expected_names: &[RegisteredName]) {
@@ -1,9 +1,11 @@
-use cosmwasm_std::{Addr, Coin, DepsMut};
+use cosmwasm_std::{testing::mock_env, Addr, Coin, DepsMut};
 use nym_contracts_common::{signing::MessageSignature, IdentityKeyRef};
 use nym_crypto::asymmetric::identity;
 use nym_name_service_common::{Address, NameDetails, NameId, NymName, RegisteredName};
 use rand_chacha::rand_core::{CryptoRng, RngCore};
 
+use crate::constants::DEFAULT_REGISTRATION_PERIOD_SECS;
+
 use super::{
     helpers::nyms,
     signing::{ed25519_sign_message, name_register_sign_payload},
@@ -26,9 +28,15 @@ pub fn new_name(
         owner: owner.clone(),
         block_height: 12345,
         deposit: nyms(100),
+        expires_at: default_expiry(),
     }
 }
 
+// Expiry of names registered at the default mock block time
+pub fn default_expiry() -> u64 {
+    mock_env().block.time.seconds() + DEFAULT_REGISTRATION_PERIOD_SECS
+}
+
 pub fn name_fixture(id: NameId) -> RegisteredName {
     new_name(
         id,
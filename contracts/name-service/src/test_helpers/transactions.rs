@@ -16,6 +16,7 @@ pub fn instantiate_test_contract() -> OwnedDeps<MemoryStorage, MockApi, MockQuer
     let mut deps = mock_dependencies();
     let msg = InstantiateMsg {
         deposit_required: coin(100, "unym"),
+        registration_period: None,
     };
     let env = mock_env();
     let info = mock_info("creator", &[]);
@@ -5,6 +5,13 @@ pub const MAX_NUMBER_OF_ALIASES_FOR_NYM_ADDRESS: u32 = 100;
 pub const SERVICE_DEFAULT_RETRIEVAL_LIMIT: u32 = 100;
 pub const SERVICE_MAX_RETRIEVAL_LIMIT: u32 = 150;
 
+// Bounds on the metadata that can be attached to an announced service.
+pub const MAX_CUSTOM_SERVICE_TYPE_LENGTH: usize = 32;
+pub const MAX_DESCRIPTION_LENGTH: usize = 256;
+pub const MAX_POLICY_URL_LENGTH: usize = 256;
+pub const MAX_REGION_LENGTH: usize = 64;
+pub const MAX_SUPPORTED_REQUEST_VERSIONS: usize = 16;
+
 // Storage keys
 pub const CONFIG_KEY: &str = "config";
 pub const ADMIN_KEY: &str = "admin";
@@ -13,5 +20,6 @@ pub const SERVICE_ID_COUNTER_KEY: &str = "sidc";
 pub const SERVICES_PK_NAMESPACE: &str = "sernames";
 pub const SERVICES_ANNOUNCER_IDX_NAMESPACE: &str = "serown";
 pub const SERVICES_NYM_ADDRESS_IDX_NAMESPACE: &str = "sernyma";
+pub const SERVICES_TYPE_IDX_NAMESPACE: &str = "sertype";
 
 pub const SIGNING_NONCES_NAMESPACE: &str = "sn";
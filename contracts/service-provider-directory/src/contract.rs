@@ -62,6 +62,7 @@ pub fn migrate(
 
         // If state structure changed in any contract version in the way migration is needed, it
         // should occur here, for example anything from `crate::queued_migrations::`
+        crate::queued_migrations::index_service_types(deps.storage, &storage_version)?;
     }
 
     Ok(Response::new())
@@ -82,6 +83,11 @@ pub fn execute(
         ExecuteMsg::DeleteNymAddress { nym_address } => {
             execute::delete_nym_address(deps, info, nym_address)
         }
+        ExecuteMsg::UpdateService {
+            service_id,
+            service_type,
+            metadata,
+        } => execute::update_service(deps, info, service_id, service_type, metadata),
         ExecuteMsg::UpdateDepositRequired { deposit_required } => {
             execute::update_deposit_required(deps, info, deposit_required)
         }
@@ -95,6 +101,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary> {
         QueryMsg::ByNymAddress { nym_address } => {
             to_binary(&query::query_nym_address(deps, nym_address)?)
         }
+        QueryMsg::ByType {
+            service_type,
+            limit,
+            start_after,
+        } => to_binary(&query::query_service_type_paged(
+            deps,
+            service_type,
+            limit,
+            start_after,
+        )?),
         QueryMsg::All { limit, start_after } => {
             to_binary(&query::query_all_paged(deps, limit, start_after)?)
         }
@@ -1,5 +1,9 @@
 use crate::{
-    constants::{MAX_NUMBER_OF_ALIASES_FOR_NYM_ADDRESS, MAX_NUMBER_OF_PROVIDERS_PER_ANNOUNCER},
+    constants::{
+        MAX_CUSTOM_SERVICE_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH,
+        MAX_NUMBER_OF_ALIASES_FOR_NYM_ADDRESS, MAX_NUMBER_OF_PROVIDERS_PER_ANNOUNCER,
+        MAX_POLICY_URL_LENGTH, MAX_REGION_LENGTH, MAX_SUPPORTED_REQUEST_VERSIONS,
+    },
     state, Result, SpContractError,
 };
 use cosmwasm_std::{Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
@@ -8,9 +12,12 @@ use nym_contracts_common::{
     IdentityKey,
 };
 use nym_service_provider_directory_common::{
-    events::{new_announce_event, new_delete_id_event, new_update_deposit_required_event},
+    events::{
+        new_announce_event, new_delete_id_event, new_update_deposit_required_event,
+        new_update_service_event,
+    },
     signing_types::construct_service_provider_announce_sign_payload,
-    NymAddress, Service, ServiceDetails, ServiceId,
+    NymAddress, Service, ServiceDetails, ServiceId, ServiceMetadata, ServiceType,
 };
 
 use super::query;
@@ -71,6 +78,47 @@ fn ensure_sender_authorized(info: MessageInfo, service: &Service) -> Result<()>
     }
 }
 
+fn ensure_valid_service_type(service_type: &ServiceType) -> Result<()> {
+    if let ServiceType::Custom(name) = service_type {
+        if name.is_empty()
+            || name.len() > MAX_CUSTOM_SERVICE_TYPE_LENGTH
+            || ServiceType::is_reserved_name(name)
+        {
+            return Err(SpContractError::InvalidServiceType {
+                service_type: name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn ensure_field_length(field: &str, value: &Option<String>, max_length: usize) -> Result<()> {
+    match value {
+        Some(value) if value.len() > max_length => Err(SpContractError::InvalidServiceMetadata {
+            reason: format!("{field} is longer than {max_length} characters"),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn ensure_valid_metadata(metadata: &ServiceMetadata) -> Result<()> {
+    ensure_field_length("description", &metadata.description, MAX_DESCRIPTION_LENGTH)?;
+    ensure_field_length(
+        "allowed hosts policy url",
+        &metadata.allowed_hosts_policy_url,
+        MAX_POLICY_URL_LENGTH,
+    )?;
+    ensure_field_length("region", &metadata.region, MAX_REGION_LENGTH)?;
+    if metadata.supported_request_versions.len() > MAX_SUPPORTED_REQUEST_VERSIONS {
+        return Err(SpContractError::InvalidServiceMetadata {
+            reason: format!(
+                "more than {MAX_SUPPORTED_REQUEST_VERSIONS} supported request versions specified"
+            ),
+        });
+    }
+    Ok(())
+}
+
 fn return_deposit(service_to_delete: &Service) -> BankMsg {
     BankMsg::Send {
         to_address: service_to_delete.announcer.to_string(),
@@ -125,6 +173,8 @@ pub fn announce(
 ) -> Result<Response> {
     ensure_max_services_per_announcer(deps.as_ref(), info.sender.clone())?;
     ensure_max_aliases_per_nym_address(deps.as_ref(), service.nym_address.clone())?;
+    ensure_valid_service_type(&service.service_type)?;
+    ensure_valid_metadata(&service.metadata)?;
 
     let deposit_required = state::deposit_required(deps.storage)?;
     let denom = deposit_required.denom.clone();
@@ -194,6 +244,28 @@ pub(crate) fn delete_nym_address(
     Ok(response)
 }
 
+/// Update the type and metadata of an existing service. The address and the identity key are
+/// covered by the announce signature, so changing those requires announcing the service again.
+pub(crate) fn update_service(
+    deps: DepsMut,
+    info: MessageInfo,
+    service_id: ServiceId,
+    service_type: ServiceType,
+    metadata: ServiceMetadata,
+) -> Result<Response> {
+    ensure_service_exists(deps.as_ref(), service_id)?;
+    let mut service = state::load_id(deps.storage, service_id)?;
+    ensure_sender_authorized(info, &service)?;
+    ensure_valid_service_type(&service_type)?;
+    ensure_valid_metadata(&metadata)?;
+
+    service.service.service_type = service_type;
+    service.service.metadata = metadata;
+    state::save(deps.storage, &service)?;
+
+    Ok(Response::new().add_event(new_update_service_event(service)))
+}
+
 /// Update the deposit required to announce new services
 pub(crate) fn update_deposit_required(
     deps: DepsMut,
@@ -2,7 +2,7 @@ use cosmwasm_std::Deps;
 use nym_contracts_common::{signing::Nonce, ContractBuildInformation};
 use nym_service_provider_directory_common::{
     response::{ConfigResponse, PagedServicesListResponse, ServicesListResponse},
-    NymAddress, Service, ServiceId,
+    NymAddress, Service, ServiceId, ServiceType,
 };
 
 use crate::{
@@ -25,6 +25,24 @@ pub fn query_nym_address(deps: Deps, nym_address: NymAddress) -> Result<Services
     Ok(ServicesListResponse::new(services))
 }
 
+pub fn query_service_type_paged(
+    deps: Deps,
+    service_type: ServiceType,
+    limit: Option<u32>,
+    start_after: Option<ServiceId>,
+) -> Result<PagedServicesListResponse> {
+    let PagedLoad {
+        services,
+        limit,
+        start_next_after,
+    } = state::load_service_type_paged(deps.storage, &service_type, limit, start_after)?;
+    Ok(PagedServicesListResponse::new(
+        services,
+        limit,
+        start_next_after,
+    ))
+}
+
 pub fn query_all_paged(
     deps: Deps,
     limit: Option<u32>,
@@ -57,6 +57,7 @@ fn basic_announce(mut setup: TestSetup) {
                     nym_address: nym_address.clone(),
                     service_type: ServiceType::NetworkRequester,
                     identity_key: service.identity_key().to_string(),
+                    metadata: Default::default(),
                 },
                 announcer: announcer.clone(),
                 block_height: 12345,
@@ -6,6 +6,7 @@ mod query;
 mod service_id;
 mod test_service;
 mod test_setup;
+mod update;
 
 #[test]
 fn instantiate_contract() {
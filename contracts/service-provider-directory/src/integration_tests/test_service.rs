@@ -20,6 +20,7 @@ impl TestService {
             nym_address,
             service_type: ServiceType::NetworkRequester,
             identity_key: keys.public_key().to_base58_string(),
+            metadata: Default::default(),
         };
         Self {
             service,
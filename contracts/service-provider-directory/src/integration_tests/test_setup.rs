@@ -8,7 +8,7 @@ use nym_service_provider_directory_common::{
     signing_types::{
         construct_service_provider_announce_sign_payload, SignableServiceProviderAnnounceMsg,
     },
-    NymAddress, Service, ServiceDetails, ServiceId,
+    NymAddress, Service, ServiceDetails, ServiceId, ServiceMetadata, ServiceType,
 };
 use rand_chacha::ChaCha20Rng;
 use serde::de::DeserializeOwned;
@@ -110,6 +110,10 @@ impl TestSetup {
         self.query(&QueryMsg::All { limit, start_after })
     }
 
+    pub fn query_by_type(&self, service_type: ServiceType) -> PagedServicesListResponse {
+        self.query(&QueryMsg::by_type(service_type))
+    }
+
     pub fn query_signing_nonce(&self, address: String) -> Nonce {
         self.query(&QueryMsg::SigningNonce { address })
     }
@@ -210,6 +214,42 @@ impl TestSetup {
         delete_resp
     }
 
+    pub fn try_update_service(
+        &mut self,
+        service_id: ServiceId,
+        service_type: ServiceType,
+        metadata: ServiceMetadata,
+        announcer: &Addr,
+    ) -> Result<AppResponse> {
+        self.app.execute_contract(
+            announcer.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::UpdateService {
+                service_id,
+                service_type,
+                metadata,
+            },
+            &[],
+        )
+    }
+
+    pub fn update_service(
+        &mut self,
+        service_id: ServiceId,
+        service_type: ServiceType,
+        metadata: ServiceMetadata,
+        announcer: &Addr,
+    ) -> AppResponse {
+        let update_resp = self
+            .try_update_service(service_id, service_type, metadata, announcer)
+            .unwrap();
+        assert_eq!(
+            get_app_attribute(&update_resp, "wasm-update_service", "action"),
+            "update_service"
+        );
+        update_resp
+    }
+
     pub fn delete_nym_address(
         &mut self,
         nym_address: &NymAddress,
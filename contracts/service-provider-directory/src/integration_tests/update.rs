@@ -0,0 +1,168 @@
+use cosmwasm_std::Addr;
+use nym_service_provider_directory_common::{NymAddress, ServiceMetadata, ServiceType};
+
+use crate::{
+    constants::{MAX_CUSTOM_SERVICE_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH},
+    test_helpers::helpers::nyms,
+    SpContractError,
+};
+
+use super::test_setup::TestSetup;
+
+fn test_metadata() -> ServiceMetadata {
+    ServiceMetadata {
+        description: Some("echoes everything back".to_string()),
+        supported_request_versions: vec![3],
+        allowed_hosts_policy_url: None,
+        region: Some("eu".to_string()),
+    }
+}
+
+#[test]
+fn update_service_type_and_metadata() {
+    let mut setup = TestSetup::new();
+    let announcer = Addr::unchecked("announcer");
+    let service =
+        setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+    assert_eq!(
+        setup
+            .query_by_type(ServiceType::NetworkRequester)
+            .services
+            .len(),
+        1
+    );
+    assert!(setup.query_by_type(ServiceType::Echo).services.is_empty());
+
+    setup.update_service(1, ServiceType::Echo, test_metadata(), &announcer);
+
+    let updated = setup.query_id(1);
+    assert_eq!(updated.service.service_type, ServiceType::Echo);
+    assert_eq!(updated.service.metadata, test_metadata());
+    // the rest of the announced details remain unchanged
+    assert_eq!(updated.service.nym_address, service.service.nym_address);
+    assert_eq!(updated.service.identity_key, service.service.identity_key);
+    assert_eq!(updated.announcer, announcer);
+
+    // the service type index has been updated as well
+    assert!(setup
+        .query_by_type(ServiceType::NetworkRequester)
+        .services
+        .is_empty());
+    assert_eq!(
+        setup.query_by_type(ServiceType::Echo).services,
+        vec![updated]
+    );
+}
+
+#[test]
+fn query_services_by_custom_type() {
+    let mut setup = TestSetup::new();
+    let announcer = Addr::unchecked("announcer");
+    setup.sign_and_announce_net_req(&NymAddress::new("nymAddress1"), &announcer, &nyms(100));
+    setup.sign_and_announce_net_req(&NymAddress::new("nymAddress2"), &announcer, &nyms(100));
+
+    let custom = ServiceType::Custom("ipfs".to_string());
+    setup.update_service(2, custom.clone(), ServiceMetadata::default(), &announcer);
+
+    let by_custom = setup.query_by_type(custom);
+    assert_eq!(by_custom.services.len(), 1);
+    assert_eq!(by_custom.services[0].service_id, 2);
+    assert_eq!(by_custom.start_next_after, Some(2));
+
+    let by_network_requester = setup.query_by_type(ServiceType::NetworkRequester);
+    assert_eq!(by_network_requester.services.len(), 1);
+    assert_eq!(by_network_requester.services[0].service_id, 1);
+}
+
+#[test]
+fn only_announcer_can_update_service() {
+    let mut setup = TestSetup::new();
+    setup.sign_and_announce_net_req(
+        &NymAddress::new("nymAddress"),
+        &Addr::unchecked("announcer"),
+        &nyms(100),
+    );
+
+    let update_resp: SpContractError = setup
+        .try_update_service(
+            1,
+            ServiceType::Echo,
+            test_metadata(),
+            &Addr::unchecked("not_announcer"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        update_resp,
+        SpContractError::Unauthorized {
+            sender: Addr::unchecked("not_announcer")
+        }
+    );
+    assert_eq!(
+        setup.query_id(1).service.service_type,
+        ServiceType::NetworkRequester
+    );
+}
+
+#[test]
+fn cant_update_service_that_does_not_exist() {
+    let mut setup = TestSetup::new();
+    let update_resp: SpContractError = setup
+        .try_update_service(
+            1,
+            ServiceType::Echo,
+            test_metadata(),
+            &Addr::unchecked("announcer"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(update_resp, SpContractError::NotFound { service_id: 1 });
+}
+
+#[test]
+fn invalid_updates_are_rejected() {
+    let mut setup = TestSetup::new();
+    let announcer = Addr::unchecked("announcer");
+    setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+
+    for name in [
+        String::new(),
+        "echo".to_string(),
+        "x".repeat(MAX_CUSTOM_SERVICE_TYPE_LENGTH + 1),
+    ] {
+        let update_resp: SpContractError = setup
+            .try_update_service(
+                1,
+                ServiceType::Custom(name.clone()),
+                ServiceMetadata::default(),
+                &announcer,
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(
+            update_resp,
+            SpContractError::InvalidServiceType { service_type: name }
+        );
+    }
+
+    let metadata = ServiceMetadata {
+        description: Some("x".repeat(MAX_DESCRIPTION_LENGTH + 1)),
+        ..Default::default()
+    };
+    let update_resp: SpContractError = setup
+        .try_update_service(1, ServiceType::Echo, metadata, &announcer)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        update_resp,
+        SpContractError::InvalidServiceMetadata { .. }
+    ));
+    assert_eq!(
+        setup.query_id(1).service.service_type,
+        ServiceType::NetworkRequester
+    );
+}
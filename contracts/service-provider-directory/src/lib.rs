@@ -16,6 +16,7 @@ use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
 
 mod constants;
 mod contract;
+mod queued_migrations;
 mod state;
 
 #[cfg(test)]
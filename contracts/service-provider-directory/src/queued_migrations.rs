@@ -0,0 +1,21 @@
+use cosmwasm_std::Storage;
+use semver::Version;
+
+use crate::{state, Result};
+
+/// Version of the contract in which the service type index got introduced.
+const SERVICE_TYPE_INDEX_VERSION: Version = Version::new(0, 1, 1);
+
+/// Re-saves all the existing services so that they would get included in the (newly introduced)
+/// service type index.
+pub fn index_service_types(store: &mut dyn Storage, storage_version: &Version) -> Result<()> {
+    // services announced since then are already indexed
+    if storage_version >= &SERVICE_TYPE_INDEX_VERSION {
+        return Ok(());
+    }
+
+    for service in state::load_all(store)? {
+        state::save(store, &service)?;
+    }
+    Ok(())
+}
@@ -9,5 +9,6 @@ pub(crate) use config::{deposit_required, load_config, save_config, Config};
 pub(crate) use nonce::{get_signing_nonce, increment_signing_nonce};
 pub(crate) use service_id_counter::next_service_id_counter;
 pub(crate) use services::{
-    has_service, load_all_paged, load_announcer, load_id, load_nym_address, remove, save, PagedLoad,
+    has_service, load_all, load_all_paged, load_announcer, load_id, load_nym_address,
+    load_service_type_paged, remove, save, PagedLoad,
 };
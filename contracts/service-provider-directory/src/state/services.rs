@@ -1,12 +1,13 @@
 use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
-use nym_service_provider_directory_common::{NymAddress, Service, ServiceId};
+use nym_service_provider_directory_common::{NymAddress, Service, ServiceId, ServiceType};
 
 use crate::{
     constants::{
         MAX_NUMBER_OF_ALIASES_FOR_NYM_ADDRESS, MAX_NUMBER_OF_PROVIDERS_PER_ANNOUNCER,
         SERVICES_ANNOUNCER_IDX_NAMESPACE, SERVICES_NYM_ADDRESS_IDX_NAMESPACE,
-        SERVICES_PK_NAMESPACE, SERVICE_DEFAULT_RETRIEVAL_LIMIT, SERVICE_MAX_RETRIEVAL_LIMIT,
+        SERVICES_PK_NAMESPACE, SERVICES_TYPE_IDX_NAMESPACE, SERVICE_DEFAULT_RETRIEVAL_LIMIT,
+        SERVICE_MAX_RETRIEVAL_LIMIT,
     },
     Result, SpContractError,
 };
@@ -14,11 +15,13 @@ use crate::{
 struct ServiceIndex<'a> {
     pub(crate) nym_address: MultiIndex<'a, String, Service, ServiceId>,
     pub(crate) announcer: MultiIndex<'a, Addr, Service, ServiceId>,
+    pub(crate) service_type: MultiIndex<'a, String, Service, ServiceId>,
 }
 
 impl<'a> IndexList<Service> for ServiceIndex<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Service>> + '_> {
-        let v: Vec<&dyn Index<Service>> = vec![&self.nym_address, &self.announcer];
+        let v: Vec<&dyn Index<Service>> =
+            vec![&self.nym_address, &self.announcer, &self.service_type];
         Box::new(v.into_iter())
     }
 }
@@ -35,6 +38,11 @@ fn services<'a>() -> IndexedMap<'a, ServiceId, Service, ServiceIndex<'a>> {
             SERVICES_PK_NAMESPACE,
             SERVICES_ANNOUNCER_IDX_NAMESPACE,
         ),
+        service_type: MultiIndex::new(
+            |d| d.service.service_type.to_string(),
+            SERVICES_PK_NAMESPACE,
+            SERVICES_TYPE_IDX_NAMESPACE,
+        ),
     };
     IndexedMap::new(SERVICES_PK_NAMESPACE, indexes)
 }
@@ -91,6 +99,45 @@ pub struct PagedLoad {
     pub start_next_after: Option<ServiceId>,
 }
 
+pub fn load_service_type_paged(
+    store: &dyn Storage,
+    service_type: &ServiceType,
+    limit: Option<u32>,
+    start_after: Option<ServiceId>,
+) -> Result<PagedLoad> {
+    let limit = limit
+        .unwrap_or(SERVICE_DEFAULT_RETRIEVAL_LIMIT)
+        .min(SERVICE_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let services = services()
+        .idx
+        .service_type
+        .prefix(service_type.to_string())
+        .range(store, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<Service>>>()?;
+
+    let start_next_after = services.last().map(|service| service.service_id);
+
+    Ok(PagedLoad {
+        services,
+        limit,
+        start_next_after,
+    })
+}
+
+/// Loads every single stored service, without any paging.
+pub fn load_all(store: &dyn Storage) -> Result<Vec<Service>> {
+    let services = services()
+        .range(store, None, None, Order::Ascending)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<Service>>>()?;
+    Ok(services)
+}
+
 pub fn load_all_paged(
     store: &dyn Storage,
     limit: Option<u32>,
@@ -23,6 +23,7 @@ pub fn new_service(
             nym_address: nym_address.clone(),
             service_type: ServiceType::NetworkRequester,
             identity_key: identity_key.to_string(),
+            metadata: Default::default(),
         },
         announcer: announcer.clone(),
         block_height: 12345,
@@ -59,6 +60,7 @@ where
             nym_address: NymAddress::new(nym_address),
             service_type: ServiceType::NetworkRequester,
             identity_key: keypair.public_key().to_base58_string(),
+            metadata: Default::default(),
         },
         keypair,
     )
@@ -1,14 +1,15 @@
 use crate::errors::ContractError;
 pub use crate::queries::*;
-use crate::storage::{ADMIN, MIXNET_CONTRACT_ADDRESS, MIX_DENOM};
+use crate::storage::{load_staking_permissions, ADMIN, MIXNET_CONTRACT_ADDRESS, MIX_DENOM};
 pub use crate::transactions::*;
 use crate::vesting::Account;
 use cosmwasm_std::{
     entry_point, to_binary, Addr, Coin, Deps, DepsMut, Env, MessageInfo, QueryResponse, Response,
-    Uint128,
+    Storage, Uint128,
 };
 use semver::Version;
 use vesting_contract_common::messages::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use vesting_contract_common::StakingPermission;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crate:nym-vesting-contract";
@@ -105,6 +106,9 @@ pub fn execute(
         ExecuteMsg::ClaimDelegatorReward { mix_id } => {
             try_claim_delegator_reward(deps, info, mix_id)
         }
+        ExecuteMsg::SetDelegationAutoCompounding { mix_id, enabled } => {
+            try_set_delegation_auto_compounding(deps, info, mix_id, enabled)
+        }
         ExecuteMsg::UpdateMixnodeConfig { new_config } => {
             try_update_mixnode_config(new_config, info, deps)
         }
@@ -123,6 +127,21 @@ pub fn execute(
             mix_id,
             on_behalf_of,
         } => try_undelegate_from_mixnode(mix_id, on_behalf_of, info, deps),
+        ExecuteMsg::RedelegateFromMixnode {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            on_behalf_of,
+        } => try_redelegate_from_mixnode(from_mix_id, to_mix_id, amount, on_behalf_of, info, deps),
+        ExecuteMsg::DelegateToGateway {
+            identity,
+            amount,
+            on_behalf_of,
+        } => try_delegate_to_gateway(identity, amount, on_behalf_of, info, deps),
+        ExecuteMsg::UndelegateFromGateway {
+            identity,
+            on_behalf_of,
+        } => try_undelegate_from_gateway(identity, on_behalf_of, info, deps),
         ExecuteMsg::CreateAccount {
             owner_address,
             staking_address,
@@ -145,6 +164,17 @@ pub fn execute(
             mix_id,
             amount,
         } => try_track_undelegation(&owner, mix_id, amount, info, deps),
+        ExecuteMsg::TrackRedelegation {
+            owner,
+            from_mix_id,
+            to_mix_id,
+            amount,
+        } => try_track_redelegation(&owner, from_mix_id, to_mix_id, amount, info, env, deps),
+        ExecuteMsg::TrackGatewayUndelegation {
+            owner,
+            identity,
+            amount,
+        } => try_track_gateway_undelegation(&owner, identity, amount, info, deps),
         ExecuteMsg::BondMixnode {
             mix_node,
             cost_params,
@@ -186,6 +216,17 @@ pub fn execute(
         ExecuteMsg::UpdateStakingAddress { to_address } => {
             try_update_staking_address(to_address, info, deps)
         }
+        ExecuteMsg::SplitAccount {
+            to_address,
+            percentage,
+        } => try_split_account(to_address, percentage, info, env, deps),
+        ExecuteMsg::GrantStakingPermissions {
+            grantee,
+            permissions,
+        } => try_grant_staking_permissions(grantee, permissions, info, deps),
+        ExecuteMsg::RevokeStakingPermissions { grantee } => {
+            try_revoke_staking_permissions(grantee, info, deps)
+        }
     }
 }
 
@@ -309,6 +350,14 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<QueryResponse, C
         QueryMsg::GetAllDelegations { start_after, limit } => {
             to_binary(&try_get_all_delegations(deps, start_after, limit)?)
         }
+        QueryMsg::GetStakingPermissions {
+            vesting_account_address,
+            grantee,
+        } => to_binary(&try_get_staking_permissions(
+            deps,
+            &vesting_account_address,
+            &grantee,
+        )?),
     };
 
     Ok(query_res?)
@@ -330,15 +379,28 @@ pub(crate) fn validate_funds(funds: &[Coin], mix_denom: String) -> Result<Coin,
     Ok(funds[0].clone())
 }
 
+/// Ensures the address is either the staking address of the account, which is allowed
+/// to perform any staking action, or it has been granted all of the required permissions.
 pub(crate) fn ensure_staking_permission(
     addr: &Addr,
     account: &Account,
+    required: &[StakingPermission],
+    storage: &dyn Storage,
 ) -> Result<(), ContractError> {
     if let Some(staking_address) = account.staking_address() {
         if staking_address == addr {
             return Ok(());
         }
     }
+
+    let granted = load_staking_permissions((account.storage_key(), addr.clone()), storage)?;
+    if required
+        .iter()
+        .all(|permission| granted.contains(permission))
+    {
+        return Ok(());
+    }
+
     Err(ContractError::InvalidStakingAccount {
         address: addr.clone(),
         for_account: account.owner_address(),
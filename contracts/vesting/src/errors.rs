@@ -1,6 +1,7 @@
 use crate::storage::AccountStorageKey;
+use contracts_common::Percent;
 use cosmwasm_std::{Addr, Coin, OverflowError, StdError, Uint128};
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::{IdentityKey, MixId};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -49,6 +50,9 @@ pub enum ContractError {
     #[error("VESTING ({}): No delegations found for account {0}, mix_identity {1}", line!())]
     NoSuchDelegation(Addr, MixId),
 
+    #[error("VESTING ({}): No delegation found for account {0} towards gateway {1}", line!())]
+    NoSuchGatewayDelegation(Addr, IdentityKey),
+
     #[error("VESTING ({}): Only mixnet contract can perform this operation, got {0}", line!())]
     NotMixnetContract(Addr),
 
@@ -97,6 +101,23 @@ pub enum ContractError {
         cap: u32,
     },
 
+    #[error("VESTING: Attempted to split vesting account with an invalid percentage of {value}. It has to be greater than 0 and lower than 100%")]
+    InvalidSplitPercentage { value: Percent },
+
+    #[error("VESTING: Could not split the vesting account as the amount of withdrawn tokens ({withdrawn}) would exceed the amount of vested tokens ({vested}) of the remaining account")]
+    WithdrawnExceedsVestedAfterSplit { withdrawn: Uint128, vested: Uint128 },
+
+    #[error("VESTING: At least a single staking permission must be granted")]
+    EmptyStakingPermissions,
+
+    #[error(
+        "VESTING: {grantee} can't be granted staking permissions for the account owned by {owner}"
+    )]
+    InvalidStakingPermissionsGrantee { grantee: Addr, owner: Addr },
+
+    #[error("VESTING: {grantee} has no staking permissions for the account owned by {owner}")]
+    NoStakingPermissions { grantee: Addr, owner: Addr },
+
     #[error("VESTING: Failed to parse {value} into a valid SemVer version: {error_message}")]
     SemVerFailure {
         value: String,
@@ -12,8 +12,8 @@ use cw_storage_plus::Bound;
 use mixnet_contract_common::MixId;
 use vesting_contract_common::{
     AccountVestingCoins, AccountsResponse, AllDelegationsResponse, BaseVestingAccountInfo,
-    DelegationTimesResponse, OriginalVestingResponse, Period, PledgeData, VestingCoinsResponse,
-    VestingDelegation,
+    DelegationTimesResponse, OriginalVestingResponse, Period, PledgeData,
+    StakingPermissionsResponse, VestingCoinsResponse, VestingDelegation,
 };
 
 /// Get current vesting period for a given [crate::vesting::Account].
@@ -284,6 +284,24 @@ pub fn try_get_delegation_times(
     })
 }
 
+pub fn try_get_staking_permissions(
+    deps: Deps<'_>,
+    vesting_account_address: &str,
+    grantee: &str,
+) -> Result<StakingPermissionsResponse, ContractError> {
+    let account = account_from_address(vesting_account_address, deps.storage, deps.api)?;
+    let grantee = deps.api.addr_validate(grantee)?;
+
+    let permissions =
+        storage::load_staking_permissions((account.storage_key(), grantee.clone()), deps.storage)?;
+
+    Ok(StakingPermissionsResponse {
+        owner: account.owner_address(),
+        grantee,
+        permissions,
+    })
+}
+
 pub fn try_get_all_delegations(
     deps: Deps<'_>,
     start_after: Option<(u32, MixId, BlockTimestampSecs)>,
@@ -4,7 +4,7 @@ use cosmwasm_std::{Addr, Api, Storage, Uint128};
 use cosmwasm_std::{Coin, Order};
 use cw_storage_plus::{Item, Map};
 use mixnet_contract_common::{IdentityKey, MixId};
-use vesting_contract_common::PledgeData;
+use vesting_contract_common::{PledgeData, StakingPermission};
 
 pub(crate) type BlockTimestampSecs = u64;
 pub(crate) type AccountStorageKey = u32;
@@ -42,6 +42,16 @@ pub const _OLD_DELEGATIONS: Map<'_, (AccountStorageKey, IdentityKey, BlockTimest
 pub const DELEGATIONS: Map<'_, (AccountStorageKey, MixId, BlockTimestampSecs), Uint128> =
     Map::new("dlg_v2");
 
+/// Storage map containing information about tokens delegated towards particular gateways
+/// in the mixnet contract with given vesting account.
+pub const GATEWAY_DELEGATIONS: Map<'_, (AccountStorageKey, IdentityKey), Uint128> =
+    Map::new("gdlg");
+
+/// Storage map containing staking actions that particular addresses are allowed to perform
+/// on behalf of given vesting account.
+pub const STAKING_PERMISSIONS: Map<'_, (AccountStorageKey, Addr), Vec<StakingPermission>> =
+    Map::new("stkp");
+
 /// Explicit contract admin that is allowed, among other things, to create new vesting accounts.
 pub const ADMIN: Item<'_, Addr> = Item::new("adm");
 
@@ -95,6 +105,26 @@ pub fn count_subdelegations_for_mix(
         .count() as u32
 }
 
+pub fn save_gateway_delegation(
+    key: (AccountStorageKey, IdentityKey),
+    amount: Uint128,
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    let existing_delegation_amount = GATEWAY_DELEGATIONS
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    GATEWAY_DELEGATIONS.save(storage, key, &(existing_delegation_amount + amount))?;
+    Ok(())
+}
+
+pub fn remove_gateway_delegation(
+    key: (AccountStorageKey, IdentityKey),
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    GATEWAY_DELEGATIONS.remove(storage, key);
+    Ok(())
+}
+
 pub fn load_withdrawn(
     key: AccountStorageKey,
     storage: &dyn Storage,
@@ -199,6 +229,32 @@ pub fn remove_gateway_pledge(
     Ok(())
 }
 
+pub fn load_staking_permissions(
+    key: (AccountStorageKey, Addr),
+    storage: &dyn Storage,
+) -> Result<Vec<StakingPermission>, ContractError> {
+    Ok(STAKING_PERMISSIONS
+        .may_load(storage, key)?
+        .unwrap_or_default())
+}
+
+pub fn save_staking_permissions(
+    key: (AccountStorageKey, Addr),
+    permissions: &[StakingPermission],
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    STAKING_PERMISSIONS.save(storage, key, &permissions.to_vec())?;
+    Ok(())
+}
+
+pub fn remove_staking_permissions(
+    key: (AccountStorageKey, Addr),
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    STAKING_PERMISSIONS.remove(storage, key);
+    Ok(())
+}
+
 pub fn save_account(account: &Account, storage: &mut dyn Storage) -> Result<(), ContractError> {
     ACCOUNTS.save(storage, account.owner_address(), account)?;
     Ok(())
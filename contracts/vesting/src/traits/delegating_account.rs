@@ -1,6 +1,6 @@
 use crate::errors::ContractError;
 use cosmwasm_std::{Coin, Env, Response, Storage, Uint128};
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::{IdentityKey, MixId};
 
 pub trait DelegatingAccount {
     fn try_claim_delegator_reward(
@@ -9,6 +9,13 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    fn try_set_delegation_auto_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     fn try_delegate_to_mixnode(
         &self,
         mix_id: MixId,
@@ -23,6 +30,27 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    fn try_redelegate_from_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_delegate_to_gateway(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_undelegate_from_gateway(
+        &self,
+        identity: IdentityKey,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     // track_delegation performs internal vesting accounting necessary when
     // delegating from a vesting account. It accepts the current block height, the
     // delegation amount and balance of all coins whose denomination exists in
@@ -44,4 +72,24 @@ pub trait DelegatingAccount {
         amount: Coin,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+
+    // track_redelegation performs internal vesting accounting necessary when
+    // a vesting account moves (part of) its delegation between mixnodes.
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
+
+    // track_gateway_undelegation performs internal vesting accounting necessary when
+    // a vesting delegation towards a gateway gets removed.
+    fn track_gateway_undelegation(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
@@ -1,4 +1,6 @@
 use crate::errors::ContractError;
+use crate::vesting::Account;
+use contracts_common::Percent;
 use cosmwasm_std::{Addr, Coin, Env, Storage, Timestamp, Uint128};
 use vesting_contract_common::OriginalVestingResponse;
 
@@ -73,6 +75,16 @@ pub trait VestingAccount {
         to_address: &Addr,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+    /// Moves the provided portion of the account into a new account owned by `to_address`
+    /// and returns the newly created account.
+    /// See [/vesting-contract/struct.Account.html/method.split] for impl
+    fn split(
+        &mut self,
+        to_address: &Addr,
+        percentage: Percent,
+        env: &Env,
+        storage: &mut dyn Storage,
+    ) -> Result<Account, ContractError>;
     /// See [/vesting-contract/struct.Account.html/method.update_staking_address] for impl
     fn update_staking_address(
         &mut self,
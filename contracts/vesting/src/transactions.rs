@@ -4,26 +4,31 @@
 use crate::contract::{ensure_staking_permission, validate_funds};
 use crate::errors::ContractError;
 use crate::storage::{
-    account_from_address, save_account, ADMIN, MIXNET_CONTRACT_ADDRESS, MIX_DENOM,
+    account_from_address, load_staking_permissions, remove_staking_permissions, save_account,
+    save_staking_permissions, ADMIN, MIXNET_CONTRACT_ADDRESS, MIX_DENOM,
 };
 use crate::traits::{
     DelegatingAccount, GatewayBondingAccount, MixnodeBondingAccount, NodeFamilies, VestingAccount,
 };
 use crate::vesting::{populate_vesting_periods, Account};
 use contracts_common::signing::MessageSignature;
+use contracts_common::Percent;
 use cosmwasm_std::{coin, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Timestamp};
 use mixnet_contract_common::families::FamilyHead;
 use mixnet_contract_common::{
-    Gateway, GatewayConfigUpdate, MixId, MixNode, MixNodeConfigUpdate, MixNodeCostParams,
+    Gateway, GatewayConfigUpdate, IdentityKey, MixId, MixNode, MixNodeConfigUpdate,
+    MixNodeCostParams,
 };
 use vesting_contract_common::events::{
-    new_ownership_transfer_event, new_periodic_vesting_account_event,
-    new_staking_address_update_event, new_track_gateway_unbond_event,
-    new_track_mixnode_pledge_decrease_event, new_track_mixnode_unbond_event,
-    new_track_reward_event, new_track_undelegation_event, new_vested_coins_withdraw_event,
+    new_account_split_event, new_ownership_transfer_event, new_periodic_vesting_account_event,
+    new_staking_address_update_event, new_staking_permissions_grant_event,
+    new_staking_permissions_revoke_event, new_track_gateway_unbond_event,
+    new_track_gateway_undelegation_event, new_track_mixnode_pledge_decrease_event,
+    new_track_mixnode_unbond_event, new_track_redelegation_event, new_track_reward_event,
+    new_track_undelegation_event, new_vested_coins_withdraw_event,
 };
 use vesting_contract_common::messages::VestingSpecification;
-use vesting_contract_common::PledgeCap;
+use vesting_contract_common::{PledgeCap, StakingPermission};
 
 pub fn try_create_family(
     info: MessageInfo,
@@ -181,6 +186,98 @@ pub fn try_transfer_ownership(
     }
 }
 
+/// Split the vesting account by moving the specified portion of it into a new account.
+pub fn try_split_account(
+    to_address: String,
+    percentage: Percent,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if account_from_address(&to_address, deps.storage, deps.api).is_ok() {
+        return Err(ContractError::AccountAlreadyExists(to_address));
+    }
+
+    let address = info.sender;
+    let to_address = deps.api.addr_validate(&to_address)?;
+    let mut account = account_from_address(address.as_str(), deps.storage, deps.api)?;
+    if address != account.owner_address() {
+        return Err(ContractError::NotOwner(account.owner_address().to_string()));
+    }
+
+    let new_account = account.split(&to_address, percentage, &env, deps.storage)?;
+    Ok(Response::new().add_event(new_account_split_event(
+        &address,
+        &to_address,
+        &new_account.coin(),
+    )))
+}
+
+/// Grant the address permissions to perform specified staking actions on behalf of the vesting account.
+pub fn try_grant_staking_permissions(
+    grantee: String,
+    mut permissions: Vec<StakingPermission>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if permissions.is_empty() {
+        return Err(ContractError::EmptyStakingPermissions);
+    }
+    permissions.sort();
+    permissions.dedup();
+
+    let address = info.sender;
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let account = account_from_address(address.as_str(), deps.storage, deps.api)?;
+    if address != account.owner_address() {
+        return Err(ContractError::NotOwner(account.owner_address().to_string()));
+    }
+    if grantee == account.owner_address() {
+        return Err(ContractError::InvalidStakingPermissionsGrantee {
+            grantee,
+            owner: account.owner_address(),
+        });
+    }
+
+    save_staking_permissions(
+        (account.storage_key(), grantee.clone()),
+        &permissions,
+        deps.storage,
+    )?;
+    Ok(
+        Response::new().add_event(new_staking_permissions_grant_event(
+            &address,
+            &grantee,
+            &permissions,
+        )),
+    )
+}
+
+/// Revoke all staking permissions previously granted to the address.
+pub fn try_revoke_staking_permissions(
+    grantee: String,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let address = info.sender;
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let account = account_from_address(address.as_str(), deps.storage, deps.api)?;
+    if address != account.owner_address() {
+        return Err(ContractError::NotOwner(account.owner_address().to_string()));
+    }
+
+    let key = (account.storage_key(), grantee.clone());
+    if load_staking_permissions(key.clone(), deps.storage)?.is_empty() {
+        return Err(ContractError::NoStakingPermissions {
+            grantee,
+            owner: account.owner_address(),
+        });
+    }
+
+    remove_staking_permissions(key, deps.storage)?;
+    Ok(Response::new().add_event(new_staking_permissions_revoke_event(&address, &grantee)))
+}
+
 /// Set or update staking address for a vesting account.
 pub fn try_update_staking_address(
     to_address: Option<String>,
@@ -360,6 +457,48 @@ pub fn try_track_undelegation(
     Ok(Response::new().add_event(new_track_undelegation_event()))
 }
 
+/// Track gateway undelegation, invoked by the mixnet contract after sucessful undelegation, message contains coins returned with any accrued rewards.
+pub fn try_track_gateway_undelegation(
+    address: &str,
+    identity: IdentityKey,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+
+    account.track_gateway_undelegation(identity, amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_gateway_undelegation_event()))
+}
+
+/// Track redelegation, invoked by the mixnet contract after sucessful redelegation, message contains the amount that was moved between the nodes.
+pub fn try_track_redelegation(
+    address: &str,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+
+    account.track_redelegation(
+        env.block.time.seconds(),
+        from_mix_id,
+        to_mix_id,
+        amount,
+        deps.storage,
+    )?;
+    Ok(Response::new().add_event(new_track_redelegation_event()))
+}
+
 /// Delegate to mixnode, sends [mixnet_contract_common::ExecuteMsg::DelegateToMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS]..
 pub fn try_delegate_to_mixnode(
     mix_id: MixId,
@@ -383,7 +522,12 @@ pub fn try_delegate_to_mixnode(
     let account = match on_behalf_of {
         Some(account_owner) => {
             let account = account_from_address(&account_owner, deps.storage, deps.api)?;
-            ensure_staking_permission(&info.sender, &account)?;
+            ensure_staking_permission(
+                &info.sender,
+                &account,
+                &[StakingPermission::Delegate],
+                deps.storage,
+            )?;
             account
         }
         // you're the owner, you can do what you want
@@ -413,6 +557,18 @@ pub fn try_claim_delegator_reward(
     account.try_claim_delegator_reward(mix_id, deps.storage)
 }
 
+/// Opts in or out of automatic reward compounding, sends [mixnet_contract_common::ExecuteMsg::SetDelegationAutoCompoundingOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_set_delegation_auto_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+
+    account.try_set_delegation_auto_compounding(mix_id, enabled, deps.storage)
+}
+
 /// Undelegates from a mixnode, sends [mixnet_contract_common::ExecuteMsg::UndelegateFromMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
 pub fn try_undelegate_from_mixnode(
     mix_id: MixId,
@@ -423,7 +579,12 @@ pub fn try_undelegate_from_mixnode(
     let account = match on_behalf_of {
         Some(account_owner) => {
             let account = account_from_address(&account_owner, deps.storage, deps.api)?;
-            ensure_staking_permission(&info.sender, &account)?;
+            ensure_staking_permission(
+                &info.sender,
+                &account,
+                &[StakingPermission::Undelegate],
+                deps.storage,
+            )?;
             account
         }
         // you're the owner, you can do what you want
@@ -433,6 +594,91 @@ pub fn try_undelegate_from_mixnode(
     account.try_undelegate_from_mixnode(mix_id, deps.storage)
 }
 
+/// Moves delegation between mixnodes, sends [mixnet_contract_common::ExecuteMsg::RedelegateFromMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_redelegate_from_mixnode(
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let mix_denom = MIX_DENOM.load(deps.storage)?;
+    let amount = validate_funds(&[amount], mix_denom)?;
+
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            // moving the stake around is equivalent to undelegating and delegating it again
+            ensure_staking_permission(
+                &info.sender,
+                &account,
+                &[StakingPermission::Undelegate, StakingPermission::Delegate],
+                deps.storage,
+            )?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_redelegate_from_mixnode(from_mix_id, to_mix_id, amount, deps.storage)
+}
+
+/// Delegate to gateway, sends [mixnet_contract_common::ExecuteMsg::DelegateToGatewayOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_delegate_to_gateway(
+    identity: IdentityKey,
+    amount: Coin,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let mix_denom = MIX_DENOM.load(deps.storage)?;
+    let amount = validate_funds(&[amount], mix_denom)?;
+
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            ensure_staking_permission(
+                &info.sender,
+                &account,
+                &[StakingPermission::Delegate],
+                deps.storage,
+            )?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_delegate_to_gateway(identity, amount, deps.storage)
+}
+
+/// Undelegates from a gateway, sends [mixnet_contract_common::ExecuteMsg::UndelegateFromGatewayOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_undelegate_from_gateway(
+    identity: IdentityKey,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            ensure_staking_permission(
+                &info.sender,
+                &account,
+                &[StakingPermission::Undelegate],
+                deps.storage,
+            )?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_undelegate_from_gateway(identity, deps.storage)
+}
+
 /// Creates a new periodic vesting account, and deposits funds to vest into the contract.
 ///
 /// Callable by ADMIN only, see [instantiate].
@@ -1,13 +1,15 @@
 use crate::contract::MAX_PER_MIX_DELEGATIONS;
 use crate::errors::ContractError;
-use crate::storage::save_delegation;
 use crate::storage::MIXNET_CONTRACT_ADDRESS;
+use crate::storage::{save_delegation, save_gateway_delegation};
 use crate::traits::DelegatingAccount;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
 use mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::{IdentityKey, MixId};
 use vesting_contract_common::events::{
-    new_vesting_delegation_event, new_vesting_undelegation_event,
+    new_vesting_delegation_event, new_vesting_gateway_delegation_event,
+    new_vesting_gateway_undelegation_event, new_vesting_redelegation_event,
+    new_vesting_undelegation_event,
 };
 
 use super::Account;
@@ -29,6 +31,30 @@ impl DelegatingAccount for Account {
         Ok(Response::new().add_message(compound_delegator_reward_msg))
     }
 
+    fn try_set_delegation_auto_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(mix_id, storage) {
+            return Err(ContractError::NoSuchDelegation(
+                self.owner_address(),
+                mix_id,
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::SetDelegationAutoCompoundingOnBehalf {
+            mix_id,
+            enabled,
+            owner: self.owner_address().into_string(),
+        };
+        let set_auto_compounding =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new().add_message(set_auto_compounding))
+    }
+
     fn try_delegate_to_mixnode(
         &self,
         mix_id: MixId,
@@ -95,6 +121,98 @@ impl DelegatingAccount for Account {
             .add_event(new_vesting_undelegation_event()))
     }
 
+    fn try_redelegate_from_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(from_mix_id, storage) {
+            return Err(ContractError::NoSuchDelegation(
+                self.owner_address(),
+                from_mix_id,
+            ));
+        }
+
+        let num_subdelegations = self.num_subdelegations_for_mix(to_mix_id, storage);
+        if num_subdelegations >= MAX_PER_MIX_DELEGATIONS {
+            return Err(ContractError::TooManyDelegations {
+                address: self.owner_address.clone(),
+                acc_id: self.storage_key(),
+                mix_id: to_mix_id,
+                num: num_subdelegations,
+                cap: MAX_PER_MIX_DELEGATIONS,
+            });
+        }
+
+        let msg = MixnetExecuteMsg::RedelegateFromMixnodeOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate: self.owner_address().into_string(),
+        };
+        let redelegate_from_mixnode =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(redelegate_from_mixnode)
+            .add_event(new_vesting_redelegation_event()))
+    }
+
+    fn try_delegate_to_gateway(
+        &self,
+        identity: IdentityKey,
+        coin: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let current_balance = self.ensure_valid_additional_stake(&coin, storage)?;
+
+        let msg = MixnetExecuteMsg::DelegateToGatewayOnBehalf {
+            identity: identity.clone(),
+            delegate: self.owner_address().into_string(),
+        };
+        let delegate_to_gateway = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![coin.clone()],
+        )?;
+
+        // unlike mixnode delegations, there's only ever a single delegation towards given gateway
+        // (as they're merged in the mixnet contract) so there's no need to keep track of the timestamps
+        save_gateway_delegation((self.storage_key(), identity), coin.amount, storage)?;
+        let new_balance = Uint128::new(current_balance.u128() - coin.amount.u128());
+        self.save_balance(new_balance, storage)?;
+
+        Ok(Response::new()
+            .add_message(delegate_to_gateway)
+            .add_event(new_vesting_gateway_delegation_event()))
+    }
+
+    fn try_undelegate_from_gateway(
+        &self,
+        identity: IdentityKey,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if self.gateway_delegation(&identity, storage)?.is_none() {
+            return Err(ContractError::NoSuchGatewayDelegation(
+                self.owner_address(),
+                identity,
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UndelegateFromGatewayOnBehalf {
+            identity,
+            delegate: self.owner_address().into_string(),
+        };
+        let undelegate_from_gateway =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(undelegate_from_gateway)
+            .add_event(new_vesting_gateway_undelegation_event()))
+    }
+
     fn track_delegation(
         &self,
         block_timestamp_secs: u64,
@@ -124,4 +242,50 @@ impl DelegatingAccount for Account {
         self.save_balance(new_balance, storage)?;
         Ok(())
     }
+
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        // we only keep track of the delegated principal, so any rewards that got moved alongside it
+        // are not accounted for here (in the same way they're not tracked before being withdrawn)
+        let tracked = self.total_delegations_for_mix(from_mix_id, storage)?;
+        let moved = tracked.min(amount.amount);
+        let remaining = tracked - moved;
+
+        self.remove_delegations_for_mix(from_mix_id, storage)?;
+        if !remaining.is_zero() {
+            save_delegation(
+                (self.storage_key(), from_mix_id, block_timestamp_secs),
+                remaining,
+                storage,
+            )?;
+        }
+        if !moved.is_zero() {
+            save_delegation(
+                (self.storage_key(), to_mix_id, block_timestamp_secs),
+                moved,
+                storage,
+            )?;
+        }
+
+        // note: the balance is unaffected as no tokens have left or entered the account
+        Ok(())
+    }
+
+    fn track_gateway_undelegation(
+        &self,
+        identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        self.remove_gateway_delegation(&identity, storage)?;
+        let new_balance = Uint128::new(self.load_balance(storage)?.u128() + amount.amount.u128());
+        self.save_balance(new_balance, storage)?;
+        Ok(())
+    }
 }
@@ -3,12 +3,13 @@ use crate::errors::ContractError;
 use crate::storage::{
     count_subdelegations_for_mix, decrease_bond_pledge, load_balance, load_bond_pledge,
     load_delegation_timestamps, load_gateway_pledge, load_withdrawn, remove_bond_pledge,
-    remove_delegation, remove_gateway_pledge, save_account, save_balance, save_bond_pledge,
-    save_gateway_pledge, save_withdrawn, AccountStorageKey, BlockTimestampSecs, DELEGATIONS, KEY,
+    remove_delegation, remove_gateway_delegation, remove_gateway_pledge, save_account,
+    save_balance, save_bond_pledge, save_gateway_pledge, save_withdrawn, AccountStorageKey,
+    BlockTimestampSecs, DELEGATIONS, GATEWAY_DELEGATIONS, KEY,
 };
 use crate::traits::VestingAccount;
 use cosmwasm_std::{Addr, Coin, Order, Storage, Timestamp, Uint128};
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::{IdentityKeyRef, MixId};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use vesting_contract_common::{Period, PledgeCap, PledgeData};
@@ -313,15 +314,37 @@ impl Account {
             .fold(Uint128::zero(), |acc, (_key, val)| acc + val))
     }
 
+    pub fn gateway_delegation(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        storage: &dyn Storage,
+    ) -> Result<Option<Uint128>, ContractError> {
+        Ok(GATEWAY_DELEGATIONS.may_load(storage, (self.storage_key(), identity.to_string()))?)
+    }
+
+    pub fn remove_gateway_delegation(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        remove_gateway_delegation((self.storage_key(), identity.to_string()), storage)
+    }
+
     // TODO: this should get reworked... somehow... (maybe with a memoized value?)
     // as it's an unbounded iteration that could fail if an account has made a lot of delegations
     // (I guess in order of thousands)
     pub fn total_delegations(&self, storage: &dyn Storage) -> Result<Uint128, ContractError> {
-        Ok(DELEGATIONS
+        let mixnode_delegations = DELEGATIONS
             .sub_prefix(self.storage_key())
             .range(storage, None, None, Order::Ascending)
             .filter_map(|x| x.ok())
-            .fold(Uint128::zero(), |acc, (_key, val)| acc + val))
+            .fold(Uint128::zero(), |acc, (_key, val)| acc + val);
+        let gateway_delegations = GATEWAY_DELEGATIONS
+            .prefix(self.storage_key())
+            .range(storage, None, None, Order::Ascending)
+            .filter_map(|x| x.ok())
+            .fold(Uint128::zero(), |acc, (_key, val)| acc + val);
+        Ok(mixnode_delegations + gateway_delegations)
     }
 
     pub fn total_pledged(&self, storage: &dyn Storage) -> Result<Uint128, ContractError> {
@@ -1,6 +1,7 @@
 use crate::errors::ContractError;
 use crate::storage::{delete_account, save_account, MIX_DENOM};
 use crate::traits::VestingAccount;
+use contracts_common::Percent;
 use cosmwasm_std::{Addr, Coin, Env, Storage, Timestamp, Uint128};
 use std::cmp::min;
 use vesting_contract_common::{OriginalVestingResponse, Period};
@@ -154,6 +155,63 @@ impl VestingAccount for Account {
         Ok(())
     }
 
+    fn split(
+        &mut self,
+        to_address: &Addr,
+        percentage: Percent,
+        env: &Env,
+        storage: &mut dyn Storage,
+    ) -> Result<Account, ContractError> {
+        if percentage.is_zero() || percentage >= Percent::hundred() {
+            return Err(ContractError::InvalidSplitPercentage { value: percentage });
+        }
+
+        let split_amount = percentage * self.coin.amount;
+        // both parts have to be large enough to be vested over all the periods
+        if split_amount.u128() < self.num_vesting_periods() as u128 {
+            return Err(ContractError::ImprobableVestingAmount(split_amount.u128()));
+        }
+
+        // the tokens moved to the new account must be present in the contract,
+        // i.e. they can't be currently staked
+        let balance = self.load_balance(storage)?;
+        if balance < split_amount {
+            return Err(ContractError::InsufficientBalance(
+                self.owner_address().to_string(),
+                balance.u128(),
+            ));
+        }
+
+        let mut remaining = self.clone();
+        remaining.coin.amount -= split_amount;
+        remaining.tokens_per_period()?;
+
+        // withdrawn tokens stay with the original account, so make sure it hasn't already
+        // withdrawn more than what it's going to vest after the split
+        let withdrawn = self.load_withdrawn(storage)?;
+        let vested = remaining.get_vested_coins(None, env, storage)?.amount;
+        if withdrawn > vested {
+            return Err(ContractError::WithdrawnExceedsVestedAfterSplit { withdrawn, vested });
+        }
+
+        *self = remaining;
+        self.save_balance(balance - split_amount, storage)?;
+        save_account(self, storage)?;
+
+        Account::new(
+            to_address.to_owned(),
+            None,
+            Coin {
+                amount: split_amount,
+                denom: self.coin.denom.clone(),
+            },
+            self.start_time,
+            self.periods.clone(),
+            self.pledge_cap.clone(),
+            storage,
+        )
+    }
+
     fn update_staking_address(
         &mut self,
         to_address: Option<Addr>,
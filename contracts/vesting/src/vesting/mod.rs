@@ -55,6 +55,7 @@ mod tests {
     use vesting_contract_common::messages::{ExecuteMsg, VestingSpecification};
     use vesting_contract_common::Period;
     use vesting_contract_common::PledgeCap;
+    use vesting_contract_common::StakingPermission;
 
     #[test]
     fn test_account_creation() {
@@ -329,6 +330,281 @@ mod tests {
         assert_eq!(vesting2, amount2);
     }
 
+    #[test]
+    fn test_account_split() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        let split_msg = |percentage: u64| ExecuteMsg::SplitAccount {
+            to_address: "beneficiary".to_string(),
+            percentage: Percent::from_percentage_value(percentage).unwrap(),
+        };
+
+        // only the owner can split the account
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("beneficiary", &[]),
+            split_msg(25),
+        );
+        assert!(response.is_err());
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            split_msg(100),
+        );
+        assert_eq!(
+            Err(ContractError::InvalidSplitPercentage {
+                value: Percent::hundred()
+            }),
+            response
+        );
+
+        // can't split into an existing account
+        let msg = ExecuteMsg::SplitAccount {
+            to_address: "owner".to_string(),
+            percentage: Percent::from_percentage_value(25).unwrap(),
+        };
+        let response = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg);
+        assert_eq!(
+            Err(ContractError::AccountAlreadyExists("owner".to_string())),
+            response
+        );
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            split_msg(25),
+        )
+        .unwrap();
+
+        let original = load_account(Addr::unchecked("owner"), &deps.storage)
+            .unwrap()
+            .unwrap();
+        let split = load_account(Addr::unchecked("beneficiary"), &deps.storage)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(original.coin.amount, Uint128::new(750_000_000_000));
+        assert_eq!(split.coin.amount, Uint128::new(250_000_000_000));
+        assert_eq!(
+            original.load_balance(&deps.storage).unwrap(),
+            Uint128::new(750_000_000_000)
+        );
+        assert_eq!(
+            split.load_balance(&deps.storage).unwrap(),
+            Uint128::new(250_000_000_000)
+        );
+        assert_eq!(split.staking_address(), None);
+        assert_eq!(original.periods(), split.periods());
+        assert_eq!(account.storage_key(), original.storage_key());
+        assert_ne!(original.storage_key(), split.storage_key());
+
+        // and both accounts vest proportionally
+        env.block.time = Timestamp::from_seconds(split.periods()[1].start_time);
+        let vested_original = original
+            .get_vested_coins(None, &env, &deps.storage)
+            .unwrap();
+        let vested_split = split.get_vested_coins(None, &env, &deps.storage).unwrap();
+        assert_eq!(vested_original.amount, Uint128::new(750_000_000_000 / 8));
+        assert_eq!(vested_split.amount, Uint128::new(250_000_000_000 / 8));
+    }
+
+    #[test]
+    fn test_account_split_after_withdrawal() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        // withdraw everything that has vested after the first period
+        env.block.time = Timestamp::from_seconds(account.periods()[1].start_time);
+        let msg = ExecuteMsg::WithdrawVestedCoins {
+            amount: coin(1_000_000_000_000 / 8, TEST_COIN_DENOM),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+        // the remaining account would have vested fewer tokens than it has already withdrawn
+        let msg = ExecuteMsg::SplitAccount {
+            to_address: "beneficiary".to_string(),
+            percentage: Percent::from_percentage_value(50).unwrap(),
+        };
+        let response = execute(deps.as_mut(), env, mock_info("owner", &[]), msg);
+        assert_eq!(
+            Err(ContractError::WithdrawnExceedsVestedAfterSplit {
+                withdrawn: Uint128::new(125_000_000_000),
+                vested: Uint128::new(62_500_000_000),
+            }),
+            response
+        );
+    }
+
+    #[test]
+    fn test_scoped_staking_permissions() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let _account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        let delegate_msg = ExecuteMsg::DelegateToMixnode {
+            on_behalf_of: Some("owner".to_string()),
+            mix_id: 42,
+            amount: coin(500, TEST_COIN_DENOM),
+        };
+        let undelegate_msg = ExecuteMsg::UndelegateFromMixnode {
+            on_behalf_of: Some("owner".to_string()),
+            mix_id: 42,
+        };
+        let redelegate_msg = ExecuteMsg::RedelegateFromMixnode {
+            from_mix_id: 42,
+            to_mix_id: 123,
+            amount: coin(100, TEST_COIN_DENOM),
+            on_behalf_of: Some("owner".to_string()),
+        };
+        let third_party = mock_info("third-party", &[]);
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            delegate_msg.clone(),
+        );
+        assert_eq!(
+            Err(ContractError::InvalidStakingAccount {
+                address: Addr::unchecked("third-party"),
+                for_account: Addr::unchecked("owner"),
+            }),
+            response
+        );
+
+        let grant_msg = |permissions: Vec<StakingPermission>| ExecuteMsg::GrantStakingPermissions {
+            grantee: "third-party".to_string(),
+            permissions,
+        };
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            grant_msg(vec![]),
+        );
+        assert_eq!(Err(ContractError::EmptyStakingPermissions), response);
+
+        let msg = ExecuteMsg::GrantStakingPermissions {
+            grantee: "owner".to_string(),
+            permissions: vec![StakingPermission::Delegate],
+        };
+        let response = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg);
+        assert!(response.is_err());
+
+        // only the owner can grant the permissions
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            grant_msg(vec![StakingPermission::Delegate]),
+        );
+        assert!(response.is_err());
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            grant_msg(vec![StakingPermission::Delegate]),
+        )
+        .unwrap();
+
+        // delegating is fine now, but nothing else
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            delegate_msg.clone(),
+        );
+        assert!(response.is_ok());
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            undelegate_msg.clone(),
+        );
+        assert!(response.is_err());
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            redelegate_msg.clone(),
+        );
+        assert!(response.is_err());
+
+        // permissions get replaced, sorted and deduplicated
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            grant_msg(vec![
+                StakingPermission::Undelegate,
+                StakingPermission::Delegate,
+                StakingPermission::Delegate,
+            ]),
+        )
+        .unwrap();
+        let permissions = try_get_staking_permissions(deps.as_ref(), "owner", "third-party")
+            .unwrap()
+            .permissions;
+        assert_eq!(
+            permissions,
+            vec![StakingPermission::Delegate, StakingPermission::Undelegate]
+        );
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            redelegate_msg,
+        );
+        assert!(response.is_ok());
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            third_party.clone(),
+            undelegate_msg,
+        );
+        assert!(response.is_ok());
+
+        // the permissions never allow withdrawing tokens
+        let msg = ExecuteMsg::WithdrawVestedCoins {
+            amount: coin(1, TEST_COIN_DENOM),
+        };
+        let response = execute(deps.as_mut(), env.clone(), third_party.clone(), msg);
+        assert!(response.is_err());
+
+        let revoke_msg = ExecuteMsg::RevokeStakingPermissions {
+            grantee: "third-party".to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            revoke_msg.clone(),
+        )
+        .unwrap();
+
+        let response = execute(deps.as_mut(), env.clone(), third_party, delegate_msg);
+        assert!(response.is_err());
+
+        let response = execute(deps.as_mut(), env, mock_info("owner", &[]), revoke_msg);
+        assert_eq!(
+            Err(ContractError::NoStakingPermissions {
+                grantee: Addr::unchecked("third-party"),
+                owner: Addr::unchecked("owner"),
+            }),
+            response
+        );
+    }
+
     #[test]
     fn test_period_logic() {
         let mut deps = init_contract();
@@ -647,6 +923,211 @@ mod tests {
         assert_eq!(Uint128::new(90_000_000_000), total_delegations);
     }
 
+    #[test]
+    fn test_setting_delegation_auto_compounding() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        // can't opt in if there's nothing delegated
+        let err = account
+            .try_set_delegation_auto_compounding(1, true, &deps.storage)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoSuchDelegation(account.owner_address(), 1)
+        );
+
+        account
+            .try_delegate_to_mixnode(
+                1,
+                coin(90_000_000_000, TEST_COIN_DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        let res = account
+            .try_set_delegation_auto_compounding(1, true, &deps.storage)
+            .unwrap();
+        assert_eq!(res.messages.len(), 1);
+    }
+
+    #[test]
+    fn test_redelegations() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+        let delegation = coin(90_000_000_000, TEST_COIN_DENOM);
+
+        // can't redelegate if there's nothing delegated
+        let err = account
+            .try_redelegate_from_mixnode(1, 2, delegation.clone(), &deps.storage)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoSuchDelegation(account.owner_address(), 1)
+        );
+
+        account
+            .try_delegate_to_mixnode(1, delegation, &env, &mut deps.storage)
+            .unwrap();
+        let balance = account.load_balance(&deps.storage).unwrap();
+
+        let ok = account.try_redelegate_from_mixnode(
+            1,
+            2,
+            coin(30_000_000_000, TEST_COIN_DENOM),
+            &deps.storage,
+        );
+        assert!(ok.is_ok());
+
+        // only the tracking performed on behalf of the mixnet contract actually moves anything
+        env.block.time = env.block.time.plus_seconds(42);
+        account
+            .track_redelegation(
+                env.block.time.seconds(),
+                1,
+                2,
+                coin(30_000_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(60_000_000_000)
+        );
+        assert_eq!(
+            account.total_delegations_for_mix(2, &deps.storage).unwrap(),
+            Uint128::new(30_000_000_000)
+        );
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+
+        // moving earned rewards alongside the delegation doesn't inflate the tracked amount
+        account
+            .track_redelegation(
+                env.block.time.seconds(),
+                1,
+                3,
+                coin(65_000_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+        assert!(!account.any_delegation_for_mix(1, &deps.storage));
+        assert_eq!(
+            account.total_delegations_for_mix(3, &deps.storage).unwrap(),
+            Uint128::new(60_000_000_000)
+        );
+        assert_eq!(
+            account.total_delegations(&deps.storage).unwrap(),
+            Uint128::new(90_000_000_000)
+        );
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+    }
+
+    #[test]
+    fn test_redelegation_tracking_is_restricted_to_mixnet_contract() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        let msg = ExecuteMsg::TrackRedelegation {
+            owner: account.owner_address().to_string(),
+            from_mix_id: 1,
+            to_mix_id: 2,
+            amount: coin(1_000_000, TEST_COIN_DENOM),
+        };
+        let res = execute(deps.as_mut(), env, mock_info("not-mixnet", &[]), msg);
+        assert_eq!(
+            res,
+            Err(ContractError::NotMixnetContract(Addr::unchecked(
+                "not-mixnet"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_gateway_delegations() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+        let identity = "gateway".to_string();
+        let delegation = coin(40_000_000_000, TEST_COIN_DENOM);
+
+        let res = account.try_undelegate_from_gateway(identity.clone(), &deps.storage);
+        assert_eq!(
+            res,
+            Err(ContractError::NoSuchGatewayDelegation(
+                account.owner_address(),
+                identity.clone()
+            ))
+        );
+
+        account
+            .try_delegate_to_gateway(identity.clone(), delegation.clone(), &mut deps.storage)
+            .unwrap();
+        account
+            .try_delegate_to_mixnode(1, delegation.clone(), &env, &mut deps.storage)
+            .unwrap();
+
+        // gateway delegations count towards the total staked amount
+        assert_eq!(
+            account.total_delegations(&deps.storage).unwrap(),
+            Uint128::new(80_000_000_000)
+        );
+        assert_eq!(
+            account.load_balance(&deps.storage).unwrap(),
+            Uint128::new(920_000_000_000)
+        );
+
+        assert!(account
+            .try_undelegate_from_gateway(identity.clone(), &deps.storage)
+            .is_ok());
+
+        let msg = ExecuteMsg::TrackGatewayUndelegation {
+            owner: account.owner_address().to_string(),
+            identity: identity.clone(),
+            amount: delegation.clone(),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("not-mixnet", &[]),
+            msg.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::NotMixnetContract(Addr::unchecked(
+                "not-mixnet"
+            )))
+        );
+
+        let mixnet_contract = MIXNET_CONTRACT_ADDRESS.load(&deps.storage).unwrap();
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(mixnet_contract.as_str(), &[]),
+            msg,
+        )
+        .unwrap();
+
+        assert!(account
+            .gateway_delegation(&identity, &deps.storage)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            account.total_delegations(&deps.storage).unwrap(),
+            Uint128::new(40_000_000_000)
+        );
+        assert_eq!(
+            account.load_balance(&deps.storage).unwrap(),
+            Uint128::new(960_000_000_000)
+        );
+    }
+
     #[test]
     fn test_mixnode_bonds() {
         let mut deps = init_contract();
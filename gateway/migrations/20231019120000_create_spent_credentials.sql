@@ -0,0 +1,11 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE spent_credential
+(
+    blinded_serial_number_bs58 TEXT    NOT NULL PRIMARY KEY UNIQUE,
+    client_address_bs58        TEXT    NOT NULL,
+    timestamp                  INTEGER NOT NULL
+);
@@ -0,0 +1,11 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- credentials can only be spent during the DKG epoch they were issued in,
+-- so we only need to remember the serial numbers until that epoch is over.
+-- (any existing entries are going to be pruned the next time a credential is redeemed)
+ALTER TABLE spent_credential ADD COLUMN epoch_id INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX spent_credential_epoch_id ON spent_credential (epoch_id);
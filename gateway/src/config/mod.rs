@@ -280,6 +280,11 @@ pub struct Debug {
     // existing nodes whilst everyone else is upgrading and getting the code for handling the new field.
     // It shall be disabled in the subsequent releases.
     pub use_legacy_framed_packet_version: bool,
+
+    /// Specifies whether, before accepting a bandwidth credential, the gateway should report its
+    /// blinded serial number to the nym-apis to detect credentials being double spent
+    /// across different gateways before the chain catches up.
+    pub report_spent_credentials: bool,
 }
 
 impl Default for Debug {
@@ -294,6 +299,7 @@ impl Default for Debug {
             message_retrieval_limit: DEFAULT_MESSAGE_RETRIEVAL_LIMIT,
             // TODO: remember to change it in one of future releases!!
             use_legacy_framed_packet_version: true,
+            report_spent_credentials: false,
         }
     }
 }
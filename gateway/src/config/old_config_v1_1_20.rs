@@ -175,6 +175,7 @@ impl From<DebugV1_1_20> for Debug {
             stored_messages_filename_length: value.stored_messages_filename_length,
             message_retrieval_limit: value.message_retrieval_limit,
             use_legacy_framed_packet_version: value.use_legacy_framed_packet_version,
+            report_spent_credentials: false,
         }
     }
 }
@@ -15,7 +15,7 @@ impl Bandwidth {
 
 impl From<Credential> for Bandwidth {
     fn from(credential: Credential) -> Self {
-        let token_value = credential.voucher_value();
+        let token_value = credential.spend_value();
         let bandwidth_bytes = token_value * nym_network_defaults::BYTES_PER_UTOKEN;
         Bandwidth {
             value: bandwidth_bytes,
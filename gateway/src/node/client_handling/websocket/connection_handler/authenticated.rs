@@ -46,6 +46,12 @@ pub(crate) enum RequestHandlingError {
     #[error("Provided bandwidth credential did not verify correctly on {0}")]
     InvalidBandwidthCredential(String),
 
+    #[error("Provided bandwidth credential has already been spent")]
+    BandwidthCredentialAlreadySpent,
+
+    #[error("Provided bandwidth credential has been issued in epoch {epoch_id}, but the current epoch is {current_epoch}")]
+    ExpiredBandwidthCredential { epoch_id: u64, current_epoch: u64 },
+
     #[error("This gateway is only accepting coconut credentials for bandwidth")]
     OnlyCoconutCredentials,
 
@@ -206,16 +212,42 @@ where
             iv,
         )?;
 
+        // credentials can only be spent during the epoch they were issued in,
+        // which also means we no longer have to remember the ones from any past epochs
+        let current_epoch = self.inner.coconut_verifier.current_epoch_id().await?;
+        let credential_epoch = *credential.epoch_id();
+        if credential_epoch < current_epoch {
+            return Err(RequestHandlingError::ExpiredBandwidthCredential {
+                epoch_id: credential_epoch,
+                current_epoch,
+            });
+        }
+        self.inner
+            .storage
+            .remove_expired_spent_credentials(current_epoch)
+            .await?;
+
+        // check our local record first as it's much cheaper than doing anything else
+        let blinded_serial_number = credential.blinded_serial_number();
+        if self
+            .inner
+            .storage
+            .contains_spent_credential(&blinded_serial_number)
+            .await?
+        {
+            return Err(RequestHandlingError::BandwidthCredentialAlreadySpent);
+        }
+
         // Get the latest coconut signers and their VK
         let credential_api_clients = self
             .inner
             .coconut_verifier
-            .all_coconut_api_clients(*credential.epoch_id())
+            .all_coconut_api_clients(credential_epoch)
             .await?;
         let current_api_clients = self
             .inner
             .coconut_verifier
-            .all_current_coconut_api_clients()
+            .all_coconut_api_clients(current_epoch)
             .await?;
         if credential_api_clients.is_empty() || current_api_clients.is_empty() {
             return Err(RequestHandlingError::NotEnoughNymAPIs {
@@ -233,10 +265,43 @@ where
             ));
         }
 
-        self.inner
+        // mark the credential as spent before doing anything else,
+        // so that it couldn't be concurrently redeemed by another connection
+        if !self
+            .inner
+            .storage
+            .insert_spent_credential(
+                &blinded_serial_number,
+                credential_epoch,
+                self.client.address,
+            )
+            .await?
+        {
+            return Err(RequestHandlingError::BandwidthCredentialAlreadySpent);
+        }
+
+        if self
+            .inner
+            .coconut_verifier
+            .spent_elsewhere(&current_api_clients, &credential)
+            .await
+        {
+            return Err(RequestHandlingError::BandwidthCredentialAlreadySpent);
+        }
+
+        if let Err(err) = self
+            .inner
             .coconut_verifier
             .release_funds(current_api_clients, &credential)
-            .await?;
+            .await
+        {
+            // allow the client to retry redeeming the credential
+            self.inner
+                .storage
+                .remove_spent_credential(&blinded_serial_number)
+                .await?;
+            return Err(err);
+        }
 
         let bandwidth = Bandwidth::from(credential);
         let bandwidth_value = bandwidth.value();
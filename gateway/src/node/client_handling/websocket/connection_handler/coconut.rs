@@ -3,7 +3,9 @@
 
 use super::authenticated::RequestHandlingError;
 use log::*;
+use nym_api_requests::coconut::SpentCredentialReportBody;
 use nym_coconut_interface::Credential;
+use nym_crypto::asymmetric::identity;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 use nym_validator_client::{
     nyxd::{
@@ -13,6 +15,7 @@ use nym_validator_client::{
     },
     Client, CoconutApiClient,
 };
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 const ONE_HOUR_SEC: u64 = 3600;
@@ -21,10 +24,16 @@ const MAX_FEEGRANT_UNYM: u128 = 10000;
 pub(crate) struct CoconutVerifier {
     nyxd_client: Client<DirectSigningNyxdClient>,
     mix_denom_base: String,
+    identity_keypair: Arc<identity::KeyPair>,
+    report_spent_credentials: bool,
 }
 
 impl CoconutVerifier {
-    pub fn new(nyxd_client: Client<DirectSigningNyxdClient>) -> Self {
+    pub fn new(
+        nyxd_client: Client<DirectSigningNyxdClient>,
+        identity_keypair: Arc<identity::KeyPair>,
+        report_spent_credentials: bool,
+    ) -> Self {
         let mix_denom_base = nyxd_client
             .nyxd
             .current_chain_details()
@@ -35,14 +44,13 @@ impl CoconutVerifier {
         CoconutVerifier {
             nyxd_client,
             mix_denom_base,
+            identity_keypair,
+            report_spent_credentials,
         }
     }
 
-    pub async fn all_current_coconut_api_clients(
-        &self,
-    ) -> Result<Vec<CoconutApiClient>, RequestHandlingError> {
-        let epoch_id = self.nyxd_client.nyxd.get_current_epoch().await?.epoch_id;
-        self.all_coconut_api_clients(epoch_id).await
+    pub async fn current_epoch_id(&self) -> Result<u64, RequestHandlingError> {
+        Ok(self.nyxd_client.nyxd.get_current_epoch().await?.epoch_id)
     }
 
     pub async fn all_coconut_api_clients(
@@ -52,6 +60,50 @@ impl CoconutVerifier {
         Ok(CoconutApiClient::all_coconut_api_clients(&self.nyxd_client, epoch_id).await?)
     }
 
+    /// Reports the blinded serial number of the credential to the provided nym-apis (if enabled)
+    /// in order to find out whether it has already been spent at a different gateway.
+    pub async fn spent_elsewhere(
+        &self,
+        api_clients: &[CoconutApiClient],
+        credential: &Credential,
+    ) -> bool {
+        if !self.report_spent_credentials {
+            return false;
+        }
+
+        // the report is signed with our identity key so that the nym-apis could check
+        // it's coming from a bonded gateway
+        let blinded_serial_number = credential.blinded_serial_number();
+        let epoch_id = *credential.epoch_id();
+        let signature =
+            self.identity_keypair
+                .private_key()
+                .sign(&SpentCredentialReportBody::plaintext(
+                    &blinded_serial_number,
+                    epoch_id,
+                ));
+        let req = SpentCredentialReportBody::new(
+            blinded_serial_number,
+            epoch_id,
+            self.identity_keypair.public_key().to_base58_string(),
+            signature.to_base58_string(),
+        );
+        for client in api_clients {
+            match client.api_client.report_spent_credential(&req).await {
+                Ok(res) if res.already_spent => return true,
+                Ok(_) => (),
+                // an unreachable api shouldn't prevent us from accepting valid credentials,
+                // the chain will still catch any double spending
+                Err(err) => warn!(
+                    "failed to report spent credential to {}: {err}",
+                    client.api_client.nym_api_client.current_url()
+                ),
+            }
+        }
+
+        false
+    }
+
     pub async fn release_funds(
         &self,
         api_clients: Vec<CoconutApiClient>,
@@ -65,10 +117,7 @@ impl CoconutVerifier {
             .nyxd_client
             .nyxd
             .spend_credential(
-                Coin::new(
-                    credential.voucher_value().into(),
-                    self.mix_denom_base.clone(),
-                ),
+                Coin::new(credential.spend_value().into(), self.mix_denom_base.clone()),
                 credential.blinded_serial_number(),
                 self.nyxd_client.nyxd.address().to_string(),
                 None,
@@ -265,7 +265,11 @@ impl<St> Gateway<St> {
 
         let coconut_verifier = {
             let nyxd_client = self.random_nyxd_client();
-            CoconutVerifier::new(nyxd_client)
+            CoconutVerifier::new(
+                nyxd_client,
+                Arc::clone(&self.identity_keypair),
+                self.config.debug.report_spent_credentials,
+            )
         };
 
         let mix_forwarding_channel = self.start_packet_forwarder(shutdown.subscribe());
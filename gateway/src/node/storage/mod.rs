@@ -6,18 +6,21 @@ use crate::node::storage::error::StorageError;
 use crate::node::storage::inboxes::InboxManager;
 use crate::node::storage::models::{PersistedSharedKeys, StoredMessage};
 use crate::node::storage::shared_keys::SharedKeysManager;
+use crate::node::storage::spent_credentials::SpentCredentialsManager;
 use async_trait::async_trait;
 use log::{debug, error};
 use nym_gateway_requests::registration::handshake::SharedKeys;
 use nym_sphinx::DestinationAddressBytes;
 use sqlx::ConnectOptions;
 use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 mod bandwidth;
 pub(crate) mod error;
 mod inboxes;
 mod models;
 mod shared_keys;
+mod spent_credentials;
 
 #[async_trait]
 pub(crate) trait Storage: Send + Sync {
@@ -134,6 +137,52 @@ pub(crate) trait Storage: Send + Sync {
         client_address: DestinationAddressBytes,
         amount: i64,
     ) -> Result<(), StorageError>;
+
+    /// Marks the credential with the provided blinded serial number as spent.
+    /// Returns `false` if it has already been spent before.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number`: base58-encoded blinded serial number of the credential.
+    /// * `epoch_id`: id of the DKG epoch the credential has been issued in.
+    /// * `client_address`: address of the client spending the credential.
+    async fn insert_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+        epoch_id: u64,
+        client_address: DestinationAddressBytes,
+    ) -> Result<bool, StorageError>;
+
+    /// Checks whether the credential with the provided blinded serial number has already been spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number`: base58-encoded blinded serial number of the credential.
+    async fn contains_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+    ) -> Result<bool, StorageError>;
+
+    /// Removes the spent marker of the credential with the provided blinded serial number.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number`: base58-encoded blinded serial number of the credential.
+    async fn remove_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+    ) -> Result<(), StorageError>;
+
+    /// Removes the spent markers of all credentials issued before the provided epoch,
+    /// as those credentials can no longer be redeemed anyway.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_epoch`: id of the current DKG epoch.
+    async fn remove_expired_spent_credentials(
+        &self,
+        current_epoch: u64,
+    ) -> Result<(), StorageError>;
 }
 
 // note that clone here is fine as upon cloning the same underlying pool will be used
@@ -142,6 +191,7 @@ pub(crate) struct PersistentStorage {
     shared_key_manager: SharedKeysManager,
     inbox_manager: InboxManager,
     bandwidth_manager: BandwidthManager,
+    spent_credentials_manager: SpentCredentialsManager,
 }
 
 impl PersistentStorage {
@@ -187,7 +237,8 @@ impl PersistentStorage {
         Ok(PersistentStorage {
             shared_key_manager: SharedKeysManager::new(connection_pool.clone()),
             inbox_manager: InboxManager::new(connection_pool.clone(), message_retrieval_limit),
-            bandwidth_manager: BandwidthManager::new(connection_pool),
+            bandwidth_manager: BandwidthManager::new(connection_pool.clone()),
+            spent_credentials_manager: SpentCredentialsManager::new(connection_pool),
         })
     }
 }
@@ -304,6 +355,64 @@ impl Storage for PersistentStorage {
             .await?;
         Ok(())
     }
+
+    async fn insert_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+        epoch_id: u64,
+        client_address: DestinationAddressBytes,
+    ) -> Result<bool, StorageError> {
+        // the unwrap is fine as the current time is always after the unix epoch
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let inserted = self
+            .spent_credentials_manager
+            .insert_spent_credential(
+                blinded_serial_number,
+                epoch_id as i64,
+                &client_address.as_base58_string(),
+                now,
+            )
+            .await?;
+        Ok(inserted)
+    }
+
+    async fn contains_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+    ) -> Result<bool, StorageError> {
+        let spent = self
+            .spent_credentials_manager
+            .contains_spent_credential(blinded_serial_number)
+            .await?;
+        Ok(spent)
+    }
+
+    async fn remove_spent_credential(
+        &self,
+        blinded_serial_number: &str,
+    ) -> Result<(), StorageError> {
+        self.spent_credentials_manager
+            .remove_spent_credential(blinded_serial_number)
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_expired_spent_credentials(
+        &self,
+        current_epoch: u64,
+    ) -> Result<(), StorageError> {
+        let removed = self
+            .spent_credentials_manager
+            .remove_spent_credentials_before_epoch(current_epoch as i64)
+            .await?;
+        if removed > 0 {
+            debug!("removed {removed} spent credentials from past epochs");
+        }
+        Ok(())
+    }
 }
 
 /// In-memory implementation of `Storage`. The intention is primarily in testing environments.
@@ -393,4 +502,113 @@ impl Storage for InMemStorage {
     ) -> Result<(), StorageError> {
         todo!()
     }
+
+    async fn insert_spent_credential(
+        &self,
+        _blinded_serial_number: &str,
+        _epoch_id: u64,
+        _client_address: DestinationAddressBytes,
+    ) -> Result<bool, StorageError> {
+        todo!()
+    }
+
+    async fn contains_spent_credential(
+        &self,
+        _blinded_serial_number: &str,
+    ) -> Result<bool, StorageError> {
+        todo!()
+    }
+
+    async fn remove_spent_credential(
+        &self,
+        _blinded_serial_number: &str,
+    ) -> Result<(), StorageError> {
+        todo!()
+    }
+
+    async fn remove_expired_spent_credentials(
+        &self,
+        _current_epoch: u64,
+    ) -> Result<(), StorageError> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    async fn test_storage() -> PersistentStorage {
+        let mut database_path = std::env::temp_dir();
+        database_path.push(format!(
+            "gateway-storage-test-{}.sqlite",
+            rand::thread_rng().next_u64()
+        ));
+        PersistentStorage::init(database_path, 100).await.unwrap()
+    }
+
+    fn client_address() -> DestinationAddressBytes {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        DestinationAddressBytes::from_bytes(bytes)
+    }
+
+    #[tokio::test]
+    async fn credential_can_only_be_spent_once() {
+        let storage = test_storage().await;
+        let serial_number = "blinded-serial-number";
+
+        assert!(!storage
+            .contains_spent_credential(serial_number)
+            .await
+            .unwrap());
+        assert!(storage
+            .insert_spent_credential(serial_number, 1, client_address())
+            .await
+            .unwrap());
+        assert!(storage
+            .contains_spent_credential(serial_number)
+            .await
+            .unwrap());
+
+        // regardless of who is trying to spend it again
+        assert!(!storage
+            .insert_spent_credential(serial_number, 1, client_address())
+            .await
+            .unwrap());
+
+        // unless the redemption failed and the marker got removed
+        storage
+            .remove_spent_credential(serial_number)
+            .await
+            .unwrap();
+        assert!(storage
+            .insert_spent_credential(serial_number, 1, client_address())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn only_credentials_from_past_epochs_are_pruned() {
+        let storage = test_storage().await;
+        storage
+            .insert_spent_credential("old", 1, client_address())
+            .await
+            .unwrap();
+        storage
+            .insert_spent_credential("current", 2, client_address())
+            .await
+            .unwrap();
+
+        storage.remove_expired_spent_credentials(2).await.unwrap();
+        assert!(!storage.contains_spent_credential("old").await.unwrap());
+        assert!(storage.contains_spent_credential("current").await.unwrap());
+
+        // and the current ones still can't be spent again
+        assert!(!storage
+            .insert_spent_credential("current", 2, client_address())
+            .await
+            .unwrap());
+    }
 }
@@ -0,0 +1,105 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+#[derive(Clone)]
+pub(crate) struct SpentCredentialsManager {
+    connection_pool: sqlx::SqlitePool,
+}
+
+impl SpentCredentialsManager {
+    /// Creates new instance of the `SpentCredentialsManager` with the provided sqlite connection pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_pool`: database connection pool to use.
+    pub(crate) fn new(connection_pool: sqlx::SqlitePool) -> Self {
+        SpentCredentialsManager { connection_pool }
+    }
+
+    /// Attempts to insert the blinded serial number of the provided credential.
+    /// Returns `false` if it has already been spent before.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    /// * `epoch_id`: id of the DKG epoch the credential has been issued in.
+    /// * `client_address_bs58`: base58-encoded address of the client that spent the credential.
+    /// * `timestamp`: unix timestamp of when the credential was spent.
+    pub(crate) async fn insert_spent_credential(
+        &self,
+        blinded_serial_number_bs58: &str,
+        epoch_id: i64,
+        client_address_bs58: &str,
+        timestamp: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let res = sqlx::query!(
+            r#"
+                INSERT OR IGNORE INTO spent_credential(blinded_serial_number_bs58, epoch_id, client_address_bs58, timestamp)
+                VALUES (?, ?, ?, ?)
+            "#,
+            blinded_serial_number_bs58,
+            epoch_id,
+            client_address_bs58,
+            timestamp
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(res.rows_affected() == 1)
+    }
+
+    /// Checks whether the credential with the provided blinded serial number has already been spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    pub(crate) async fn contains_spent_credential(
+        &self,
+        blinded_serial_number_bs58: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let exists = sqlx::query!(
+            "SELECT EXISTS (SELECT 1 FROM spent_credential WHERE blinded_serial_number_bs58 = ?) AS 'exists'",
+            blinded_serial_number_bs58
+        )
+        .fetch_one(&self.connection_pool)
+        .await?
+        .exists;
+
+        Ok(exists == 1)
+    }
+
+    /// Removes the blinded serial number of the provided credential, for example if we failed
+    /// to redeem it so that the client could retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    pub(crate) async fn remove_spent_credential(
+        &self,
+        blinded_serial_number_bs58: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM spent_credential WHERE blinded_serial_number_bs58 = ?",
+            blinded_serial_number_bs58
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes the blinded serial numbers of all credentials issued before the provided epoch.
+    /// Returns the number of removed entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch_id`: id of the earliest DKG epoch whose credentials should be kept.
+    pub(crate) async fn remove_spent_credentials_before_epoch(
+        &self,
+        epoch_id: i64,
+    ) -> Result<u64, sqlx::Error> {
+        let res = sqlx::query!("DELETE FROM spent_credential WHERE epoch_id < ?", epoch_id)
+            .execute(&self.connection_pool)
+            .await?;
+        Ok(res.rows_affected())
+    }
+}
@@ -0,0 +1,11 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE reported_spent_credential
+(
+    blinded_serial_number_bs58 VARCHAR NOT NULL PRIMARY KEY,
+    gateway_cosmos_addr        VARCHAR NOT NULL,
+    timestamp                  INTEGER NOT NULL
+);
@@ -0,0 +1,16 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE signed_monitor_report
+(
+    id        INTEGER PRIMARY KEY AUTOINCREMENT,
+    signer    VARCHAR NOT NULL,
+    timestamp INTEGER NOT NULL,
+    -- JSON serialization of the report, i.e. the exact bytes that got signed
+    report    VARCHAR NOT NULL,
+    signature VARCHAR NOT NULL
+);
+
+CREATE INDEX signed_monitor_report_timestamp ON signed_monitor_report(`timestamp`);
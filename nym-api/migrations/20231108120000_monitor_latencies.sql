@@ -0,0 +1,14 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- latency percentiles (in milliseconds) of test packets sent through given node during particular monitor run.
+-- they're null if no packet has been received
+ALTER TABLE mixnode_status ADD COLUMN latency_p50_ms INTEGER;
+ALTER TABLE mixnode_status ADD COLUMN latency_p90_ms INTEGER;
+ALTER TABLE mixnode_status ADD COLUMN latency_p99_ms INTEGER;
+
+ALTER TABLE gateway_status ADD COLUMN latency_p50_ms INTEGER;
+ALTER TABLE gateway_status ADD COLUMN latency_p90_ms INTEGER;
+ALTER TABLE gateway_status ADD COLUMN latency_p99_ms INTEGER;
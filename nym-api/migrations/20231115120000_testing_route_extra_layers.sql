@@ -0,0 +1,8 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- mixnode on the additional (fourth) layer of the test route.
+-- it's null if the network consisted of fewer layers at the time of the monitor run
+ALTER TABLE testing_route ADD COLUMN layer4_mix_id INTEGER;
@@ -0,0 +1,34 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- hourly aggregates of the network monitor results, kept after the raw statuses get purged.
+-- `timestamp` is the unix timestamp of the beginning of the hour,
+-- latencies are null if no test packet got received during that hour
+-- and stake (decimal string of unym) is null if it wasn't known at the time of the rollup
+CREATE TABLE mixnode_hourly_rollup
+(
+    mixnode_details_id INTEGER NOT NULL,
+    timestamp          INTEGER NOT NULL,
+    reliability        REAL    NOT NULL,
+    latency_p50_ms     REAL,
+    latency_p90_ms     REAL,
+    latency_p99_ms     REAL,
+    stake              VARCHAR,
+
+    UNIQUE (mixnode_details_id, timestamp)
+);
+
+CREATE TABLE gateway_hourly_rollup
+(
+    gateway_details_id INTEGER NOT NULL,
+    timestamp          INTEGER NOT NULL,
+    reliability        REAL    NOT NULL,
+    latency_p50_ms     REAL,
+    latency_p90_ms     REAL,
+    latency_p99_ms     REAL,
+    stake              VARCHAR,
+
+    UNIQUE (gateway_details_id, timestamp)
+);
@@ -0,0 +1,15 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- the reports are now authenticated with the identity key of the reporting gateway.
+-- the existing (unauthenticated) reports are only relevant for a short while anyway, so just drop them
+DROP TABLE reported_spent_credential;
+
+CREATE TABLE reported_spent_credential
+(
+    blinded_serial_number_bs58 VARCHAR NOT NULL PRIMARY KEY,
+    gateway_identity           VARCHAR NOT NULL,
+    timestamp                  INTEGER NOT NULL
+);
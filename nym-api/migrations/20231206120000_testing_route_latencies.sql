@@ -0,0 +1,10 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- latency percentiles (in milliseconds) of all test packets sent through given route during particular monitor run.
+-- they're null if no packet has been received
+ALTER TABLE testing_route ADD COLUMN latency_p50_ms INTEGER;
+ALTER TABLE testing_route ADD COLUMN latency_p90_ms INTEGER;
+ALTER TABLE testing_route ADD COLUMN latency_p99_ms INTEGER;
@@ -0,0 +1,9 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- reports are only relevant until the DKG epoch of the credential is over
+ALTER TABLE reported_spent_credential ADD COLUMN epoch_id INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX reported_spent_credential_epoch_id ON reported_spent_credential (epoch_id);
@@ -33,6 +33,53 @@ impl VerifyCredentialBody {
     }
 }
 
+#[derive(Serialize, Deserialize, Getters, CopyGetters)]
+pub struct SpentCredentialReportBody {
+    #[getset(get = "pub")]
+    blinded_serial_number: String,
+    /// Id of the DKG epoch the credential has been issued in.
+    #[getset(get = "pub")]
+    epoch_id: u64,
+    /// Base58-encoded identity key of the bonded gateway reporting the credential.
+    #[getset(get = "pub")]
+    gateway_identity: String,
+    /// Base58-encoded signature on the [`Self::plaintext`] created with the gateway's identity key.
+    #[getset(get = "pub")]
+    signature: String,
+}
+
+impl SpentCredentialReportBody {
+    pub fn new(
+        blinded_serial_number: String,
+        epoch_id: u64,
+        gateway_identity: String,
+        signature: String,
+    ) -> SpentCredentialReportBody {
+        SpentCredentialReportBody {
+            blinded_serial_number,
+            epoch_id,
+            gateway_identity,
+            signature,
+        }
+    }
+
+    /// Message that has to be signed by the reporting gateway.
+    pub fn plaintext(blinded_serial_number: &str, epoch_id: u64) -> Vec<u8> {
+        format!("report-spent-credential:{epoch_id}:{blinded_serial_number}").into_bytes()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpentCredentialReportResponse {
+    pub already_spent: bool,
+}
+
+impl SpentCredentialReportResponse {
+    pub fn new(already_spent: bool) -> Self {
+        SpentCredentialReportResponse { already_spent }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VerifyCredentialResponse {
     pub verification_result: bool,
@@ -135,6 +182,31 @@ impl BlindedSignatureResponse {
     }
 }
 
+/// Multiple blind sign requests, each for a separate deposit, issued in a single API call.
+#[derive(Clone, Serialize, Deserialize, Debug, Getters)]
+pub struct BatchBlindSignRequestBody {
+    #[getset(get = "pub")]
+    requests: Vec<BlindSignRequestBody>,
+}
+
+impl BatchBlindSignRequestBody {
+    pub fn new(requests: Vec<BlindSignRequestBody>) -> BatchBlindSignRequestBody {
+        BatchBlindSignRequestBody { requests }
+    }
+}
+
+/// Responses to the batched blind sign requests, in the same order as the requests.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchBlindedSignatureResponse {
+    pub responses: Vec<BlindedSignatureResponse>,
+}
+
+impl BatchBlindedSignatureResponse {
+    pub fn new(responses: Vec<BlindedSignatureResponse>) -> BatchBlindedSignatureResponse {
+        BatchBlindedSignatureResponse { responses }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct VerificationKeyResponse {
     pub key: VerificationKey,
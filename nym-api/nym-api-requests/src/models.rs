@@ -11,7 +11,7 @@ use nym_mixnet_contract_common::{
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::{fmt, time::Duration};
+use std::{fmt, str::FromStr, time::Duration};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct RequestError {
@@ -309,6 +309,119 @@ pub struct GatewayStatusReportResponse {
     pub last_day: Uptime,
 }
 
+/// Percentiles of the latency (in milliseconds) of test packets sent through given node.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct LatencyPercentiles {
+    pub p50_ms: u32,
+    pub p90_ms: u32,
+    pub p99_ms: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct MixnodeLatencyResponse {
+    pub mix_id: MixId,
+    pub most_recent: Option<LatencyPercentiles>,
+    pub last_day: Option<LatencyPercentiles>,
+}
+
+#[derive(Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GatewayLatencyResponse {
+    pub identity: String,
+    pub most_recent: Option<LatencyPercentiles>,
+    pub last_day: Option<LatencyPercentiles>,
+}
+
+/// Granularity of the data points of the node status time series.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeSeriesResolution {
+    Hourly,
+    Daily,
+    Weekly,
+}
+
+impl TimeSeriesResolution {
+    /// Duration (in seconds) of the period covered by a single data point.
+    pub const fn bucket_secs(&self) -> i64 {
+        match self {
+            TimeSeriesResolution::Hourly => 3600,
+            TimeSeriesResolution::Daily => 86400,
+            TimeSeriesResolution::Weekly => 7 * 86400,
+        }
+    }
+
+    /// Chooses the resolution for the provided time range (in seconds),
+    /// so that the number of returned data points would stay reasonable.
+    pub fn for_range(range_secs: i64) -> Self {
+        if range_secs <= 3 * TimeSeriesResolution::Daily.bucket_secs() {
+            TimeSeriesResolution::Hourly
+        } else if range_secs <= 90 * TimeSeriesResolution::Daily.bucket_secs() {
+            TimeSeriesResolution::Daily
+        } else {
+            TimeSeriesResolution::Weekly
+        }
+    }
+}
+
+impl fmt::Display for TimeSeriesResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeSeriesResolution::Hourly => write!(f, "hourly"),
+            TimeSeriesResolution::Daily => write!(f, "daily"),
+            TimeSeriesResolution::Weekly => write!(f, "weekly"),
+        }
+    }
+}
+
+impl FromStr for TimeSeriesResolution {
+    type Err = RequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hourly" => Ok(TimeSeriesResolution::Hourly),
+            "daily" => Ok(TimeSeriesResolution::Daily),
+            "weekly" => Ok(TimeSeriesResolution::Weekly),
+            other => Err(RequestError::new(format!(
+                "'{other}' is not a valid resolution, expected one of 'hourly', 'daily' or 'weekly'"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct NodeStatusDataPoint {
+    /// Unix timestamp of the beginning of the period covered by this data point.
+    pub timestamp: i64,
+
+    /// Average reliability of the node during the period.
+    pub reliability: f32,
+
+    /// Average latency percentiles of the node during the period, if any of its test packets got received.
+    pub latency: Option<LatencyPercentiles>,
+
+    /// The most recent known stake of the node during the period,
+    /// i.e. the total stake for mixnodes and the pledge amount for gateways.
+    pub stake: Option<Decimal>,
+}
+
+#[derive(Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct MixnodeStatusTimeSeriesResponse {
+    pub mix_id: MixId,
+    pub since: i64,
+    pub until: i64,
+    pub resolution: TimeSeriesResolution,
+    pub data_points: Vec<NodeStatusDataPoint>,
+}
+
+#[derive(Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GatewayStatusTimeSeriesResponse {
+    pub identity: String,
+    pub since: i64,
+    pub until: i64,
+    pub resolution: TimeSeriesResolution,
+    pub data_points: Vec<NodeStatusDataPoint>,
+}
+
 #[derive(Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct HistoricalUptimeResponse {
     pub date: String,
@@ -337,3 +450,40 @@ pub struct CirculatingSupplyResponse {
     pub vesting_tokens: Coin,
     pub circulating_supply: Coin,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct MonitorReportMixnodeResult {
+    pub mix_id: MixId,
+    pub reliability: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct MonitorReportGatewayResult {
+    pub identity: String,
+    pub reliability: u8,
+}
+
+/// Results of a single network monitor run, as observed by a particular nym-api.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct MonitorReport {
+    /// Unix timestamp of the monitor run.
+    pub timestamp: i64,
+    pub mixnode_results: Vec<MonitorReportMixnodeResult>,
+    pub gateway_results: Vec<MonitorReportGatewayResult>,
+}
+
+/// Monitor report signed with the ed25519 identity key of the monitor that produced it.
+/// The signature is created over the JSON serialization of the `report` field.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct SignedMonitorReport {
+    /// Base58-encoded ed25519 public key of the signer.
+    pub signer: String,
+    pub report: MonitorReport,
+    /// Base58-encoded ed25519 signature on the report.
+    pub signature: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SignedMonitorReportsResponse {
+    pub reports: Vec<SignedMonitorReport>,
+}
@@ -12,6 +12,7 @@ use nym_coconut_dkg_common::types::{
 use nym_coconut_dkg_common::verification_key::{ContractVKShare, VerificationKeyShare};
 use nym_contracts_common::dealings::ContractSafeBytes;
 use nym_dkg::Threshold;
+use nym_mixnet_contract_common::{GatewayBond, IdentityKey};
 use nym_validator_client::nyxd::cosmwasm_client::types::ExecuteResult;
 use nym_validator_client::nyxd::{AccountId, Fee, TxResponse};
 
@@ -25,6 +26,7 @@ pub trait Client {
         &self,
         blinded_serial_number: String,
     ) -> Result<SpendCredentialResponse>;
+    async fn get_gateway_bond(&self, identity: IdentityKey) -> Result<Option<GatewayBond>>;
     async fn get_current_epoch(&self) -> Result<Epoch>;
     async fn group_member(&self, addr: String) -> Result<MemberResponse>;
     async fn get_current_epoch_threshold(&self) -> Result<Option<Threshold>>;
@@ -102,6 +102,18 @@ pub enum CoconutError {
 
     #[error("There was a problem with the proposal id: {reason}")]
     ProposalIdError { reason: String },
+
+    #[error("Invalid number of requests in the batch: {size}. It must be between 1 and {max}")]
+    InvalidBatchSize { size: usize, max: usize },
+
+    #[error("All requests within a batch must use the same number of parameters")]
+    InconsistentBatchParameters,
+
+    #[error("Gateway {identity} is not bonded")]
+    UnbondedGateway { identity: String },
+
+    #[error("The reported credential has been issued in epoch {epoch_id}, but the current epoch is {current_epoch}")]
+    ExpiredCredentialReport { epoch_id: u64, current_epoch: u64 },
 }
 
 impl<'r, 'o: 'r> Responder<'r, 'o> for CoconutError {
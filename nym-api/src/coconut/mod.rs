@@ -10,7 +10,9 @@ use crate::support::storage::NymApiStorage;
 use getset::{CopyGetters, Getters};
 use keypair::KeyPair;
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchBlindSignRequestBody, BatchBlindedSignatureResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, SpentCredentialReportBody, SpentCredentialReportResponse,
+    VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_coconut_bandwidth_contract_common::spend_credential::{
     funds_from_cosmos_msgs, SpendCredentialStatus,
@@ -24,7 +26,7 @@ use nym_config::defaults::NYM_API_VERSION;
 use nym_credentials::coconut::params::{
     NymApiCredentialEncryptionAlgorithm, NymApiCredentialHkdfAlgorithm,
 };
-use nym_crypto::asymmetric::encryption;
+use nym_crypto::asymmetric::{encryption, identity};
 use nym_crypto::shared_key::new_ephemeral_shared_key;
 use nym_crypto::symmetric::stream_cipher;
 use nym_validator_client::nym_api::routes::{BANDWIDTH, COCONUT_ROUTES};
@@ -46,6 +48,9 @@ pub(crate) mod keypair;
 #[cfg(test)]
 pub(crate) mod tests;
 
+/// Maximum number of blind sign requests that can be included in a single batch.
+pub(crate) const MAX_BATCH_BLIND_SIGN_REQUESTS: usize = 32;
+
 pub struct State {
     client: Arc<dyn LocalClient + Send + Sync>,
     mix_denom: String,
@@ -182,7 +187,12 @@ impl InternalSignRequest {
             rocket.manage(state).mount(
                 // this format! is so ugly...
                 format!("/{}/{}/{}", NYM_API_VERSION, COCONUT_ROUTES, BANDWIDTH),
-                routes![post_blind_sign, verify_bandwidth_credential],
+                routes![
+                    post_blind_sign,
+                    post_batch_blind_sign,
+                    verify_bandwidth_credential,
+                    report_spent_credential
+                ],
             )
         })
     }
@@ -238,6 +248,85 @@ pub async fn post_blind_sign(
     Ok(Json(response))
 }
 
+fn batch_blind_sign(
+    requests: &[InternalSignRequest],
+    key_pair: &CoconutKeyPair,
+) -> Result<Vec<BlindedSignature>> {
+    // all requests within a batch are expected to have been created with the same parameters
+    let total_params = match requests.first() {
+        Some(request) => request.total_params(),
+        None => return Ok(Vec::new()),
+    };
+    if requests.iter().any(|r| r.total_params() != total_params) {
+        return Err(CoconutError::InconsistentBatchParameters);
+    }
+
+    let params = Parameters::new(total_params)?;
+    let sign_requests = requests
+        .iter()
+        .map(|r| (r.blind_sign_request(), r.public_attributes().as_slice()))
+        .collect::<Vec<_>>();
+    Ok(nym_coconut_interface::batch_blind_sign(
+        &params,
+        &key_pair.secret_key(),
+        &sign_requests,
+    )?)
+}
+
+#[post("/batch-blind-sign", data = "<batch_request_body>")]
+pub async fn post_batch_blind_sign(
+    batch_request_body: Json<BatchBlindSignRequestBody>,
+    state: &RocketState<State>,
+) -> Result<Json<BatchBlindedSignatureResponse>> {
+    let requests = batch_request_body.requests();
+    debug!("received batch of {} blind sign requests", requests.len());
+    if requests.is_empty() || requests.len() > MAX_BATCH_BLIND_SIGN_REQUESTS {
+        return Err(CoconutError::InvalidBatchSize {
+            size: requests.len(),
+            max: MAX_BATCH_BLIND_SIGN_REQUESTS,
+        });
+    }
+
+    let mut responses = Vec::with_capacity(requests.len());
+    let mut pending = Vec::new();
+    let mut internal_requests = Vec::new();
+    for (idx, request_body) in requests.iter().enumerate() {
+        if let Some(response) = state.signed_before(request_body.tx_hash()).await? {
+            responses.push(Some(response));
+            continue;
+        }
+        let tx = state.client.get_tx(request_body.tx_hash()).await?;
+        let encryption_key = extract_encryption_key(request_body, tx).await?;
+        let internal_request = InternalSignRequest::new(
+            *request_body.total_params(),
+            request_body.public_attributes(),
+            request_body.blind_sign_request().clone(),
+        );
+        pending.push((idx, encryption_key));
+        internal_requests.push(internal_request);
+        responses.push(None);
+    }
+
+    let blinded_signatures = if internal_requests.is_empty() {
+        Vec::new()
+    } else if let Some(keypair) = state.key_pair.get().await.as_ref() {
+        batch_blind_sign(&internal_requests, keypair)?
+    } else {
+        return Err(CoconutError::KeyPairNotDerivedYet);
+    };
+
+    for ((idx, encryption_key), blinded_signature) in pending.iter().zip(blinded_signatures) {
+        let response = state
+            .encrypt_and_store(requests[*idx].tx_hash(), encryption_key, &blinded_signature)
+            .await?;
+        responses[*idx] = Some(response);
+    }
+
+    Ok(Json(BatchBlindedSignatureResponse::new(
+        responses.into_iter().flatten().collect(),
+    )))
+}
+
 #[post("/verify-bandwidth-credential", data = "<verify_credential_body>")]
 pub async fn verify_bandwidth_credential(
     verify_credential_body: Json<VerifyCredentialBody>,
@@ -282,7 +371,7 @@ pub async fn verify_bandwidth_credential(
 
     vote_yes &= Coin::from(proposed_release_funds)
         == Coin::new(
-            verify_credential_body.credential().voucher_value() as u128,
+            verify_credential_body.credential().spend_value() as u128,
             state.mix_denom.clone(),
         );
 
@@ -303,3 +392,71 @@ pub async fn verify_bandwidth_credential(
 
     Ok(Json(VerifyCredentialResponse::new(vote_yes)))
 }
+
+#[post("/report-spent-credential", data = "<report_body>")]
+pub async fn report_spent_credential(
+    report_body: Json<SpentCredentialReportBody>,
+    state: &RocketState<State>,
+) -> Result<Json<SpentCredentialReportResponse>> {
+    let blinded_serial_number = report_body.blinded_serial_number();
+    let epoch_id = *report_body.epoch_id();
+    let gateway_identity = report_body.gateway_identity();
+
+    // make sure the report actually comes from a bonded gateway,
+    // otherwise anyone could mark arbitrary credentials as spent
+    let identity_key = identity::PublicKey::from_base58_string(gateway_identity)?;
+    let signature = identity::Signature::from_base58_string(report_body.signature())?;
+    identity_key.verify(
+        &SpentCredentialReportBody::plaintext(blinded_serial_number, epoch_id),
+        &signature,
+    )?;
+    if state
+        .client
+        .get_gateway_bond(gateway_identity.clone())
+        .await?
+        .is_none()
+    {
+        return Err(CoconutError::UnbondedGateway {
+            identity: gateway_identity.clone(),
+        });
+    }
+
+    // credentials can only be spent during the epoch they were issued in,
+    // so we only have to keep track of the ones from the current epoch
+    let current_epoch = state.client.get_current_epoch().await?.epoch_id;
+    if epoch_id < current_epoch {
+        return Err(CoconutError::ExpiredCredentialReport {
+            epoch_id,
+            current_epoch,
+        });
+    }
+    state
+        .storage
+        .remove_expired_reported_spent_credentials(current_epoch)
+        .await?;
+
+    // the credential has already made its way onto the chain
+    let on_chain = state
+        .client
+        .get_spent_credential(blinded_serial_number.clone())
+        .await?
+        .spend_credential
+        .is_some();
+    if on_chain {
+        return Ok(Json(SpentCredentialReportResponse::new(true)));
+    }
+
+    // otherwise check if any other gateway is about to accept it.
+    // note that the same gateway might be retrying the redemption (for example if releasing
+    // the funds has failed the first time), in which case the credential is not considered spent
+    let reporter = state
+        .storage
+        .insert_reported_spent_credential(blinded_serial_number, epoch_id, gateway_identity)
+        .await?;
+    let already_spent = &reporter != gateway_identity;
+    if already_spent {
+        debug!("blinded serial number {blinded_serial_number} has already been reported by gateway {reporter}");
+    }
+
+    Ok(Json(SpentCredentialReportResponse::new(already_spent)))
+}
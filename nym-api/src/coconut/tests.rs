@@ -5,7 +5,9 @@ use super::InternalSignRequest;
 use crate::coconut::error::{CoconutError, Result};
 use cosmwasm_std::{to_binary, Addr, CosmosMsg, Decimal, WasmMsg};
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchBlindSignRequestBody, BatchBlindedSignatureResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, SpentCredentialReportBody, SpentCredentialReportResponse,
+    VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_coconut::tests::helpers::theta_from_keys_and_attributes;
 use nym_coconut::{prepare_blind_sign, ttp_keygen, Base58, BlindedSignature, Parameters};
@@ -25,7 +27,8 @@ use nym_credentials::coconut::params::{
 use nym_crypto::shared_key::recompute_shared_key;
 use nym_crypto::symmetric::stream_cipher;
 use nym_validator_client::nym_api::routes::{
-    API_VERSION, BANDWIDTH, COCONUT_BLIND_SIGN, COCONUT_ROUTES, COCONUT_VERIFY_BANDWIDTH_CREDENTIAL,
+    API_VERSION, BANDWIDTH, COCONUT_BATCH_BLIND_SIGN, COCONUT_BLIND_SIGN,
+    COCONUT_REPORT_SPENT_CREDENTIAL, COCONUT_ROUTES, COCONUT_VERIFY_BANDWIDTH_CREDENTIAL,
 };
 use nym_validator_client::nyxd::Coin;
 use nym_validator_client::nyxd::{tx::Hash, AccountId, DeliverTx, Event, Fee, Tag, TxResponse};
@@ -46,6 +49,7 @@ use nym_coconut_dkg_common::verification_key::{ContractVKShare, VerificationKeyS
 use nym_contracts_common::dealings::ContractSafeBytes;
 use nym_crypto::asymmetric::{encryption, identity};
 use nym_dkg::Threshold;
+use nym_mixnet_contract_common::{Gateway, GatewayBond, IdentityKey};
 use nym_validator_client::nyxd::cosmwasm_client::logs::Log;
 use nym_validator_client::nyxd::cosmwasm_client::types::ExecuteResult;
 use rand_07::rngs::OsRng;
@@ -65,6 +69,7 @@ pub(crate) struct DummyClient {
     tx_db: Arc<RwLock<HashMap<String, TxResponse>>>,
     proposal_db: Arc<RwLock<HashMap<u64, ProposalResponse>>>,
     spent_credential_db: Arc<RwLock<HashMap<String, SpendCredentialResponse>>>,
+    gateway_bond_db: Arc<RwLock<HashMap<String, GatewayBond>>>,
 
     epoch: Arc<RwLock<Epoch>>,
     dealer_details: Arc<RwLock<HashMap<String, (DealerDetails, bool)>>>,
@@ -82,6 +87,7 @@ impl DummyClient {
             tx_db: Arc::new(RwLock::new(HashMap::new())),
             proposal_db: Arc::new(RwLock::new(HashMap::new())),
             spent_credential_db: Arc::new(RwLock::new(HashMap::new())),
+            gateway_bond_db: Arc::new(RwLock::new(HashMap::new())),
             epoch: Arc::new(RwLock::new(Epoch::default())),
             dealer_details: Arc::new(RwLock::new(HashMap::new())),
             threshold: Arc::new(RwLock::new(None)),
@@ -113,7 +119,15 @@ impl DummyClient {
         self
     }
 
-    pub fn _with_epoch(mut self, epoch: &Arc<RwLock<Epoch>>) -> Self {
+    pub fn with_gateway_bond_db(
+        mut self,
+        gateway_bond_db: &Arc<RwLock<HashMap<String, GatewayBond>>>,
+    ) -> Self {
+        self.gateway_bond_db = Arc::clone(gateway_bond_db);
+        self
+    }
+
+    pub fn with_epoch(mut self, epoch: &Arc<RwLock<Epoch>>) -> Self {
         self.epoch = Arc::clone(epoch);
         self
     }
@@ -208,6 +222,10 @@ impl super::client::Client for DummyClient {
             })
     }
 
+    async fn get_gateway_bond(&self, identity: IdentityKey) -> Result<Option<GatewayBond>> {
+        Ok(self.gateway_bond_db.read().unwrap().get(&identity).cloned())
+    }
+
     async fn get_current_epoch(&self) -> Result<Epoch> {
         Ok(*self.epoch.read().unwrap())
     }
@@ -825,6 +843,131 @@ async fn blind_sign_correct() {
     assert!(blinded_signature_response.is_ok());
 }
 
+#[tokio::test]
+async fn batch_blind_sign_correct() {
+    let tx_hash =
+        Hash::from_str("7C41AF8266D91DE55E1C8F4712E6A952A165ED3D8C27C7B00428CBD0DE00A52B").unwrap();
+
+    let params = Parameters::new(4).unwrap();
+    let mut rng = OsRng;
+    let identity_keypair = identity::KeyPair::new(&mut rng);
+    let encryption_keypair = encryption::KeyPair::new(&mut rng);
+    let voucher = BandwidthVoucher::new(
+        &params,
+        "1234".to_string(),
+        VOUCHER_INFO.to_string(),
+        tx_hash,
+        identity::PrivateKey::from_base58_string(identity_keypair.private_key().to_base58_string())
+            .unwrap(),
+        encryption::PrivateKey::from_bytes(&encryption_keypair.private_key().to_bytes()).unwrap(),
+    );
+
+    let key_pair = ttp_keygen(&params, 1, 1).unwrap().remove(0);
+    let mut db_dir = std::env::temp_dir();
+    db_dir.push(format!(
+        "batch-{}",
+        &key_pair.verification_key().to_bs58()[..8]
+    ));
+    let storage = NymApiStorage::init(db_dir).await.unwrap();
+    let tx_db = Arc::new(RwLock::new(HashMap::new()));
+
+    let mut tx_entry = tx_entry_fixture(&tx_hash.to_string());
+    tx_entry.tx_result.events.push(Event {
+        type_str: format!("wasm-{}", DEPOSITED_FUNDS_EVENT_TYPE),
+        attributes: vec![],
+    });
+    tx_entry.tx_result.events.get_mut(0).unwrap().attributes = vec![
+        Tag {
+            key: DEPOSIT_VALUE.parse().unwrap(),
+            value: "1234".parse().unwrap(),
+        },
+        Tag {
+            key: DEPOSIT_INFO.parse().unwrap(),
+            value: VOUCHER_INFO.parse().unwrap(),
+        },
+        Tag {
+            key: DEPOSIT_IDENTITY_KEY.parse().unwrap(),
+            value: identity_keypair
+                .public_key()
+                .to_base58_string()
+                .parse()
+                .unwrap(),
+        },
+        Tag {
+            key: DEPOSIT_ENCRYPTION_KEY.parse().unwrap(),
+            value: encryption_keypair
+                .public_key()
+                .to_base58_string()
+                .parse()
+                .unwrap(),
+        },
+    ];
+    tx_db
+        .write()
+        .unwrap()
+        .insert(tx_hash.to_string(), tx_entry.clone());
+    let nyxd_client =
+        DummyClient::new(AccountId::from_str(TEST_REWARDING_VALIDATOR_ADDRESS).unwrap())
+            .with_tx_db(&tx_db);
+    let comm_channel = DummyCommunicationChannel::new(key_pair.verification_key());
+    let staged_key_pair = crate::coconut::KeyPair::new();
+    staged_key_pair.set(Some(key_pair)).await;
+
+    let rocket = rocket::build().attach(InternalSignRequest::stage(
+        nyxd_client,
+        TEST_COIN_DENOM.to_string(),
+        staged_key_pair,
+        comm_channel,
+        storage.clone(),
+    ));
+    let client = Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance");
+
+    let request_body = BlindSignRequestBody::new(
+        voucher.blind_sign_request(),
+        tx_hash.to_string(),
+        voucher
+            .sign(voucher.blind_sign_request())
+            .to_base58_string(),
+        &voucher.get_public_attributes(),
+        voucher.get_public_attributes_plain(),
+        4,
+    );
+
+    // the same deposit included twice must result in the same signature being returned
+    let batch_body = BatchBlindSignRequestBody::new(vec![request_body.clone(), request_body]);
+    let response = client
+        .post(format!(
+            "/{}/{}/{}/{}",
+            API_VERSION, COCONUT_ROUTES, BANDWIDTH, COCONUT_BATCH_BLIND_SIGN
+        ))
+        .json(&batch_body)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let batch_response = serde_json::from_str::<BatchBlindedSignatureResponse>(
+        &response.into_string().await.unwrap(),
+    )
+    .unwrap();
+    assert_eq!(batch_response.responses.len(), 2);
+    assert_eq!(
+        batch_response.responses[0].to_bytes(),
+        batch_response.responses[1].to_bytes()
+    );
+
+    let empty_batch = BatchBlindSignRequestBody::new(vec![]);
+    let response = client
+        .post(format!(
+            "/{}/{}/{}/{}",
+            API_VERSION, COCONUT_ROUTES, BANDWIDTH, COCONUT_BATCH_BLIND_SIGN
+        ))
+        .json(&empty_batch)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
 #[tokio::test]
 async fn verification_of_bandwidth_credential() {
     // Setup variables
@@ -1146,3 +1289,165 @@ async fn verification_of_bandwidth_credential() {
         .to_string()
     );
 }
+
+#[tokio::test]
+async fn reporting_spent_credentials() {
+    let validator_address = AccountId::from_str(TEST_REWARDING_VALIDATOR_ADDRESS).unwrap();
+    let spent_credential_db = Arc::new(RwLock::new(HashMap::new()));
+    let gateway_bond_db = Arc::new(RwLock::new(HashMap::new()));
+    let epoch = Arc::new(RwLock::new(Epoch::default()));
+    let nyxd_client = DummyClient::new(validator_address)
+        .with_spent_credential_db(&spent_credential_db)
+        .with_gateway_bond_db(&gateway_bond_db)
+        .with_epoch(&epoch);
+    let mut db_dir = std::env::temp_dir();
+    let params = Parameters::new(4).unwrap();
+    let key_pair = ttp_keygen(&params, 1, 1).unwrap().remove(0);
+    db_dir.push(&key_pair.verification_key().to_bs58()[..8]);
+    let storage = NymApiStorage::init(db_dir).await.unwrap();
+    let comm_channel = DummyCommunicationChannel::new(key_pair.verification_key());
+    let staged_key_pair = crate::coconut::KeyPair::new();
+    staged_key_pair.set(Some(key_pair)).await;
+    let rocket = rocket::build().attach(InternalSignRequest::stage(
+        nyxd_client,
+        TEST_COIN_DENOM.to_string(),
+        staged_key_pair,
+        comm_channel,
+        storage,
+    ));
+    let client = Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance");
+
+    let mut rng = OsRng;
+    let bonded_gateway = identity::KeyPair::new(&mut rng);
+    let other_bonded_gateway = identity::KeyPair::new(&mut rng);
+    let unbonded_gateway = identity::KeyPair::new(&mut rng);
+    for gateway in [&bonded_gateway, &other_bonded_gateway] {
+        let identity_key = gateway.public_key().to_base58_string();
+        gateway_bond_db.write().unwrap().insert(
+            identity_key.clone(),
+            GatewayBond::new(
+                Coin::new(100_000_000, TEST_COIN_DENOM).into(),
+                Addr::unchecked("owner"),
+                1,
+                Gateway {
+                    host: "1.1.1.1".to_string(),
+                    mix_port: 1789,
+                    clients_port: 9000,
+                    location: "Neverland".to_string(),
+                    sphinx_key: "sphinx".to_string(),
+                    identity_key,
+                    version: "1.1.32".to_string(),
+                },
+                None,
+            ),
+        );
+    }
+
+    let fresh_serial_number = "fresh-blinded-serial-number".to_string();
+    let on_chain_serial_number = "on-chain-blinded-serial-number".to_string();
+    spent_credential_db.write().unwrap().insert(
+        fresh_serial_number.clone(),
+        SpendCredentialResponse::new(None),
+    );
+    spent_credential_db.write().unwrap().insert(
+        on_chain_serial_number.clone(),
+        SpendCredentialResponse::new(Some(SpendCredential::new(
+            Coin::new(1234, TEST_COIN_DENOM).into(),
+            on_chain_serial_number.clone(),
+            Addr::unchecked("unimportant"),
+        ))),
+    );
+
+    let signed_epoch_report =
+        |gateway: &identity::KeyPair, blinded_serial_number: &String, epoch_id: u64| {
+            let signature = gateway
+                .private_key()
+                .sign(&SpentCredentialReportBody::plaintext(
+                    blinded_serial_number,
+                    epoch_id,
+                ));
+            SpentCredentialReportBody::new(
+                blinded_serial_number.clone(),
+                epoch_id,
+                gateway.public_key().to_base58_string(),
+                signature.to_base58_string(),
+            )
+        };
+    let signed_report = |gateway: &identity::KeyPair, blinded_serial_number: &String| {
+        signed_epoch_report(gateway, blinded_serial_number, 0)
+    };
+
+    let send_report = |req: SpentCredentialReportBody| {
+        let client = &client;
+        async move {
+            let response = client
+                .post(format!(
+                    "/{}/{}/{}/{}",
+                    API_VERSION, COCONUT_ROUTES, BANDWIDTH, COCONUT_REPORT_SPENT_CREDENTIAL
+                ))
+                .json(&req)
+                .dispatch()
+                .await;
+            let status = response.status();
+            let already_spent = serde_json::from_str::<SpentCredentialReportResponse>(
+                &response.into_string().await.unwrap(),
+            )
+            .map(|res| res.already_spent)
+            .ok();
+            (status, already_spent)
+        }
+    };
+
+    // reports of unbonded gateways and reports with invalid signatures are rejected
+    let (status, _) = send_report(signed_report(&unbonded_gateway, &fresh_serial_number)).await;
+    assert_eq!(status, Status::BadRequest);
+
+    let forged = SpentCredentialReportBody::new(
+        fresh_serial_number.clone(),
+        0,
+        bonded_gateway.public_key().to_base58_string(),
+        signed_report(&other_bonded_gateway, &fresh_serial_number)
+            .signature()
+            .clone(),
+    );
+    let (status, _) = send_report(forged).await;
+    assert_eq!(status, Status::BadRequest);
+
+    // the first report of a credential is accepted
+    let (status, already_spent) =
+        send_report(signed_report(&bonded_gateway, &fresh_serial_number)).await;
+    assert_eq!(status, Status::Ok);
+    assert_eq!(already_spent, Some(false));
+
+    // the same gateway retrying the redemption is also accepted
+    let (_, already_spent) =
+        send_report(signed_report(&bonded_gateway, &fresh_serial_number)).await;
+    assert_eq!(already_spent, Some(false));
+
+    // but any other gateway is not
+    let (_, already_spent) =
+        send_report(signed_report(&other_bonded_gateway, &fresh_serial_number)).await;
+    assert_eq!(already_spent, Some(true));
+
+    // and credentials that are already on chain are always rejected
+    let (_, already_spent) =
+        send_report(signed_report(&bonded_gateway, &on_chain_serial_number)).await;
+    assert_eq!(already_spent, Some(true));
+
+    // once the epoch is over, credentials issued in it can no longer be reported...
+    epoch.write().unwrap().epoch_id = 1;
+    let (status, _) = send_report(signed_report(&other_bonded_gateway, &fresh_serial_number)).await;
+    assert_eq!(status, Status::BadRequest);
+
+    // ...and the old reports are forgotten, so they no longer count towards new ones
+    let (status, already_spent) = send_report(signed_epoch_report(
+        &other_bonded_gateway,
+        &fresh_serial_number,
+        1,
+    ))
+    .await;
+    assert_eq!(status, Status::Ok);
+    assert_eq!(already_spent, Some(false));
+}
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::epoch_operations::RewardedSetUpdater;
+use crate::network_monitor::reports::AggregatedReliability;
+use crate::node_status_api::ONE_DAY;
 use cosmwasm_std::{Decimal, Fraction};
 use nym_mixnet_contract_common::reward_params::Performance;
-use nym_mixnet_contract_common::{ExecuteMsg, Interval, MixId};
+use nym_mixnet_contract_common::{ExecuteMsg, IdentityKey, Interval, MixId};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct MixnodeWithPerformance {
@@ -22,6 +24,22 @@ impl From<MixnodeWithPerformance> for ExecuteMsg {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct GatewayWithPerformance {
+    pub(crate) identity: IdentityKey,
+
+    pub(crate) performance: Performance,
+}
+
+impl From<GatewayWithPerformance> for ExecuteMsg {
+    fn from(gateway_reward: GatewayWithPerformance) -> Self {
+        ExecuteMsg::RewardGateway {
+            identity: gateway_reward.identity,
+            performance: gateway_reward.performance,
+        }
+    }
+}
+
 pub(super) fn stake_to_f64(stake: Decimal) -> f64 {
     let max = f64::MAX.round() as u128;
 
@@ -57,14 +75,112 @@ impl RewardedSetUpdater {
         }
     }
 
+    // returns `None` if there's nothing to aggregate our own results with,
+    // in which case the local results are used for all the nodes
+    async fn load_aggregated_reliability(
+        &self,
+        interval: &Interval,
+    ) -> Option<AggregatedReliability> {
+        if !self.monitor_reports.has_peers() {
+            // without any peers, the aggregate would have only consisted of our own results
+            return None;
+        }
+
+        let end = interval.current_epoch_end_unix_timestamp();
+        let start = end - ONE_DAY.as_secs() as i64;
+        let aggregated = self.monitor_reports.aggregate(start, end).await;
+        if aggregated.is_none() {
+            log::warn!("none of the peer nym-apis could be reached - using local monitor results for all nodes");
+        }
+        aggregated
+    }
+
     pub(crate) async fn load_nodes_performance(
         &self,
         interval: &Interval,
         nodes: &[MixId],
     ) -> Vec<MixnodeWithPerformance> {
+        let aggregated = self.load_aggregated_reliability(interval).await;
+
+        let mut fallbacks = 0;
         let mut with_performance = Vec::with_capacity(nodes.len());
         for mix_id in nodes {
-            with_performance.push(self.load_performance(interval, *mix_id).await)
+            let uptime = aggregated
+                .as_ref()
+                .and_then(|aggregated| aggregated.mixnode(*mix_id));
+            let performance = match uptime {
+                Some(uptime) => MixnodeWithPerformance {
+                    mix_id: *mix_id,
+                    performance: uptime.into(),
+                },
+                None => {
+                    fallbacks += 1;
+                    self.load_performance(interval, *mix_id).await
+                }
+            };
+            with_performance.push(performance)
+        }
+
+        if aggregated.is_some() && fallbacks > 0 {
+            log::info!(
+                "{fallbacks} out of {} mixnodes did not have enough monitor reports - used local monitor results instead",
+                nodes.len()
+            );
+        }
+        with_performance
+    }
+
+    pub(crate) async fn load_gateway_performance(
+        &self,
+        interval: &Interval,
+        identity: IdentityKey,
+    ) -> GatewayWithPerformance {
+        let uptime = self
+            .storage
+            .get_average_gateway_uptime_in_the_last_24hrs(
+                &identity,
+                interval.current_epoch_end_unix_timestamp(),
+            )
+            .await
+            .unwrap_or_default();
+
+        GatewayWithPerformance {
+            identity,
+            performance: uptime.into(),
+        }
+    }
+
+    pub(crate) async fn load_gateways_performance(
+        &self,
+        interval: &Interval,
+        gateways: Vec<IdentityKey>,
+    ) -> Vec<GatewayWithPerformance> {
+        let aggregated = self.load_aggregated_reliability(interval).await;
+
+        let total = gateways.len();
+        let mut fallbacks = 0;
+        let mut with_performance = Vec::with_capacity(total);
+        for identity in gateways {
+            let uptime = aggregated
+                .as_ref()
+                .and_then(|aggregated| aggregated.gateway(&identity));
+            let performance = match uptime {
+                Some(uptime) => GatewayWithPerformance {
+                    identity,
+                    performance: uptime.into(),
+                },
+                None => {
+                    fallbacks += 1;
+                    self.load_gateway_performance(interval, identity).await
+                }
+            };
+            with_performance.push(performance)
+        }
+
+        if aggregated.is_some() && fallbacks > 0 {
+            log::info!(
+                "{fallbacks} out of {total} gateways did not have enough monitor reports - used local monitor results instead"
+            );
         }
         with_performance
     }
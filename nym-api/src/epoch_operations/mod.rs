@@ -12,12 +12,13 @@
 // 3. Eventually this whole procedure is going to get expanded to allow for distribution of rewarded set generation
 //    and hence this might be a good place for it.
 
+use crate::network_monitor::reports::MonitorReportsAggregator;
 use crate::node_status_api::ONE_DAY;
 use crate::nym_contract_cache::cache::NymContractCache;
 use crate::support::nyxd::Client;
 use crate::support::storage::NymApiStorage;
 use error::RewardingError;
-pub(crate) use helpers::MixnodeWithPerformance;
+pub(crate) use helpers::{GatewayWithPerformance, MixnodeWithPerformance};
 use nym_mixnet_contract_common::{CurrentIntervalResponse, Interval};
 use nym_task::{TaskClient, TaskManager};
 use std::collections::HashSet;
@@ -35,6 +36,7 @@ pub struct RewardedSetUpdater {
     nyxd_client: Client,
     nym_contract_cache: NymContractCache,
     storage: NymApiStorage,
+    monitor_reports: MonitorReportsAggregator,
 }
 
 impl RewardedSetUpdater {
@@ -48,11 +50,13 @@ impl RewardedSetUpdater {
         nyxd_client: Client,
         nym_contract_cache: NymContractCache,
         storage: NymApiStorage,
+        monitor_reports: MonitorReportsAggregator,
     ) -> Self {
         RewardedSetUpdater {
             nyxd_client,
             nym_contract_cache,
             storage,
+            monitor_reports,
         }
     }
 
@@ -70,7 +74,8 @@ impl RewardedSetUpdater {
     ///     until that is done.
     ///     - ability to send transactions (by other users) that get resolved once given epoch/interval rolls over,
     ///     such as `BondMixnode` or `DelegateToMixnode` will temporarily be frozen until the entire procedure is finished.
-    /// 3. it obtains the current rewarded set and for each node in there (**SORTED BY MIX_ID!!**),
+    /// 3. it sends `RewardGateway` message for each bonded gateway with its measured performance.
+    ///    Afterwards, it obtains the current rewarded set and for each node in there (**SORTED BY MIX_ID!!**),
     ///    it sends (in a single batch) `RewardMixnode` message with the measured performance.
     ///    Once the final message gets executed, the mixnet contract automatically transitions
     ///    the state to `ReconcilingEvents`.
@@ -126,6 +131,11 @@ impl RewardedSetUpdater {
             }
         }
 
+        // gateways are rewarded independently of the rewarded set, but it has to happen
+        // before the final mixnode is rewarded as that moves the epoch into event reconciliation
+        log::info!("Rewarding the bonded gateways...");
+        self.reward_current_gateways(interval).await?;
+
         // Reward all the nodes in the still current, soon to be previous rewarded set
         log::info!("Rewarding the current rewarded set...");
         self.reward_current_rewarded_set(interval).await?;
@@ -263,12 +273,14 @@ impl RewardedSetUpdater {
         nyxd_client: Client,
         nym_contract_cache: &NymContractCache,
         storage: &NymApiStorage,
+        monitor_reports: MonitorReportsAggregator,
         shutdown: &TaskManager,
     ) {
         let mut rewarded_set_updater = RewardedSetUpdater::new(
             nyxd_client,
             nym_contract_cache.to_owned(),
             storage.to_owned(),
+            monitor_reports,
         );
         let shutdown_listener = shutdown.subscribe();
         tokio::spawn(async move { rewarded_set_updater.run(shutdown_listener).await });
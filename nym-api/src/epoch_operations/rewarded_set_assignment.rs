@@ -36,7 +36,8 @@ impl RewardedSetUpdater {
         set: &[MixnodeWithStakeAndPerformance],
     ) -> Result<Vec<LayerAssignment>, RewardingError> {
         let mut assignments = Vec::with_capacity(set.len());
-        let target_layer_count = set.len() / 3;
+        let mix_layers = self.nym_contract_cache.mix_layers().await;
+        let target_layer_count = set.len() / mix_layers.max(1) as usize;
 
         let mix_to_family = self.nym_contract_cache.mix_to_family().await.to_vec();
 
@@ -58,9 +59,9 @@ impl RewardedSetUpdater {
         }
 
         let mut layers = HashMap::new();
-        layers.insert(Layer::One, Vec::with_capacity(target_layer_count));
-        layers.insert(Layer::Two, Vec::with_capacity(target_layer_count));
-        layers.insert(Layer::Three, Vec::with_capacity(target_layer_count));
+        for layer in Layer::all(mix_layers) {
+            layers.insert(layer, Vec::with_capacity(target_layer_count));
+        }
 
         // Assign all members of a family to same layer
         for (_head, members) in families.iter_mut() {
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::epoch_operations::error::RewardingError;
-use crate::epoch_operations::helpers::MixnodeWithPerformance;
+use crate::epoch_operations::helpers::{GatewayWithPerformance, MixnodeWithPerformance};
 use crate::RewardedSetUpdater;
 use nym_mixnet_contract_common::{EpochState, Interval, MixId};
 
@@ -86,4 +86,62 @@ impl RewardedSetUpdater {
 
         self.load_nodes_performance(&interval, &rewarded_set).await
     }
+
+    pub(super) async fn reward_current_gateways(
+        &self,
+        current_interval: Interval,
+    ) -> Result<(), RewardingError> {
+        let epoch_status = self.nyxd_client.get_current_epoch_status().await?;
+        match epoch_status.state {
+            EpochState::Rewarding { .. } => {
+                // note: the contract ignores any gateways that have already been rewarded in this epoch,
+                // so it's safe to attempt it again if we crashed mid-way
+                let to_reward = self.gateways_to_reward(current_interval).await;
+                if to_reward.is_empty() {
+                    warn!("There are no gateways to reward in this epoch");
+                    return Ok(());
+                }
+
+                if let Err(err) = self
+                    .nyxd_client
+                    .send_gateway_rewarding_messages(&to_reward)
+                    .await
+                {
+                    error!(
+                        "failed to perform gateway rewarding for epoch {}! Error encountered: {err}",
+                        current_interval.current_epoch_absolute_id(),
+                    );
+                    return Err(err.into());
+                }
+
+                log::info!("rewarded {} gateways...", to_reward.len());
+                Ok(())
+            }
+            EpochState::ReconcilingEvents | EpochState::AdvancingEpoch => {
+                warn!("we seem to have crashed mid epoch operations... gateways can no longer be rewarded as the mixnode rewarding has already finished! (or this could be a false positive if there were no mixnodes to reward)");
+                Ok(())
+            }
+            EpochState::InProgress => {
+                // hard error, this shouldn't have happened!
+                error!("tried to perform gateway rewarding while the epoch is still in progress!");
+                Err(RewardingError::InvalidEpochState {
+                    current_state: EpochState::InProgress,
+                    operation: "gateway rewarding".to_string(),
+                })
+            }
+        }
+    }
+
+    async fn gateways_to_reward(&self, interval: Interval) -> Vec<GatewayWithPerformance> {
+        let mut gateways = self
+            .nym_contract_cache
+            .gateways_all()
+            .await
+            .into_iter()
+            .map(|bond| bond.gateway.identity_key)
+            .collect::<Vec<_>>();
+        gateways.sort();
+
+        self.load_gateways_performance(&interval, gateways).await
+    }
 }
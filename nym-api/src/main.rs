@@ -5,6 +5,8 @@
 extern crate rocket;
 
 use crate::epoch_operations::RewardedSetUpdater;
+use crate::network_monitor::reports::MonitorReportsAggregator;
+use crate::node_status_api::rollups::NodeStatusRollupUpdater;
 use crate::node_status_api::uptime_updater::HistoricalUptimeUpdater;
 use crate::support::cli;
 use crate::support::cli::CliArgs;
@@ -24,6 +26,7 @@ use nym_sphinx::receiver::SphinxMessageReceiver;
 use nym_task::TaskManager;
 use rand::rngs::OsRng;
 use std::error::Error;
+use std::sync::Arc;
 use support::{http, nyxd};
 
 mod circulating_supply_api;
@@ -125,21 +128,47 @@ async fn start_nym_api_tasks(
         // if network monitor is enabled, the storage MUST BE available
         let storage = maybe_storage.unwrap();
 
+        let report_signing_keys = Arc::new(
+            network_monitor::reports::load_or_generate_identity_keypair(&config.network_monitor)?,
+        );
+        info!(
+            "Network monitor reports are going to be signed with {}",
+            report_signing_keys.public_key()
+        );
+
         network_monitor::start::<SphinxMessageReceiver>(
             &config.network_monitor,
             nym_contract_cache_state,
             storage,
             nyxd_client.clone(),
+            Arc::clone(&report_signing_keys),
             &shutdown,
         )
         .await;
 
         HistoricalUptimeUpdater::start(storage, &shutdown);
+        NodeStatusRollupUpdater::start(
+            storage,
+            nym_contract_cache_state,
+            nyxd_client.clone(),
+            &shutdown,
+        );
 
         // start 'rewarding' if its enabled
         if config.rewarding.enabled {
             epoch_operations::ensure_rewarding_permission(&nyxd_client).await?;
-            RewardedSetUpdater::start(nyxd_client, nym_contract_cache_state, storage, &shutdown);
+            let monitor_reports = MonitorReportsAggregator::new(
+                &config.rewarding,
+                *report_signing_keys.public_key(),
+                storage.to_owned(),
+            );
+            RewardedSetUpdater::start(
+                nyxd_client,
+                nym_contract_cache_state,
+                storage,
+                monitor_reports,
+                &shutdown,
+            );
         }
     }
 
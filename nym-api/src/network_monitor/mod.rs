@@ -26,6 +26,7 @@ use std::sync::Arc;
 
 pub(crate) mod gateways_reader;
 pub(crate) mod monitor;
+pub(crate) mod reports;
 pub(crate) mod test_packet;
 pub(crate) mod test_route;
 
@@ -36,12 +37,14 @@ pub(crate) fn setup<'a>(
     nym_contract_cache_state: &NymContractCache,
     storage: &NymApiStorage,
     nyxd_client: nyxd::Client,
+    report_signing_keys: Arc<identity::KeyPair>,
 ) -> NetworkMonitorBuilder<'a> {
     NetworkMonitorBuilder::new(
         config,
         nyxd_client,
         storage.to_owned(),
         nym_contract_cache_state.to_owned(),
+        report_signing_keys,
     )
 }
 
@@ -50,6 +53,7 @@ pub(crate) struct NetworkMonitorBuilder<'a> {
     nyxd_client: nyxd::Client,
     node_status_storage: NymApiStorage,
     validator_cache: NymContractCache,
+    report_signing_keys: Arc<identity::KeyPair>,
 }
 
 impl<'a> NetworkMonitorBuilder<'a> {
@@ -58,12 +62,14 @@ impl<'a> NetworkMonitorBuilder<'a> {
         nyxd_client: nyxd::Client,
         node_status_storage: NymApiStorage,
         validator_cache: NymContractCache,
+        report_signing_keys: Arc<identity::KeyPair>,
     ) -> Self {
         NetworkMonitorBuilder {
             config,
             nyxd_client,
             node_status_storage,
             validator_cache,
+            report_signing_keys,
         }
     }
 
@@ -128,6 +134,7 @@ impl<'a> NetworkMonitorBuilder<'a> {
             received_processor,
             summary_producer,
             self.node_status_storage,
+            self.report_signing_keys,
             PacketType::Mix,
         );
 
@@ -221,9 +228,16 @@ pub(crate) async fn start<R: MessageReceiver + Send + 'static>(
     nym_contract_cache_state: &NymContractCache,
     storage: &NymApiStorage,
     nyxd_client: nyxd::Client,
+    report_signing_keys: Arc<identity::KeyPair>,
     shutdown: &TaskManager,
 ) {
-    let monitor_builder = setup(config, nym_contract_cache_state, storage, nyxd_client);
+    let monitor_builder = setup(
+        config,
+        nym_contract_cache_state,
+        storage,
+        nyxd_client,
+        report_signing_keys,
+    );
     info!("Starting network monitor...");
     let runnables: NetworkMonitorRunnables<R> = monitor_builder.build().await;
     runnables.spawn_tasks(shutdown);
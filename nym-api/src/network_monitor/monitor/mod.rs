@@ -5,16 +5,20 @@ use crate::network_monitor::monitor::preparer::PacketPreparer;
 use crate::network_monitor::monitor::processor::ReceivedProcessor;
 use crate::network_monitor::monitor::sender::PacketSender;
 use crate::network_monitor::monitor::summary_producer::{SummaryProducer, TestSummary};
-use crate::network_monitor::test_packet::NodeTestMessage;
+use crate::network_monitor::reports;
+use crate::network_monitor::test_packet::ReceivedTestMessage;
 use crate::network_monitor::test_route::TestRoute;
 use crate::storage::NymApiStorage;
 use crate::support::config;
 use log::{debug, error, info};
+use nym_crypto::asymmetric::identity;
 use nym_sphinx::params::PacketType;
 use nym_sphinx::receiver::MessageReceiver;
 use nym_task::TaskClient;
 use std::collections::{HashMap, HashSet};
 use std::process;
+use std::sync::Arc;
+use time::OffsetDateTime;
 use tokio::time::{sleep, Duration, Instant};
 
 pub(crate) mod gateway_clients_cache;
@@ -32,6 +36,10 @@ pub(super) struct Monitor<R: MessageReceiver + Send + 'static> {
     received_processor: ReceivedProcessor<R>,
     summary_producer: SummaryProducer,
     node_status_storage: NymApiStorage,
+
+    /// Keys used for signing the reports of each monitor run.
+    report_signing_keys: Arc<identity::KeyPair>,
+
     run_interval: Duration,
     gateway_ping_interval: Duration,
     packet_delivery_timeout: Duration,
@@ -57,6 +65,7 @@ impl<R: MessageReceiver + Send> Monitor<R> {
         received_processor: ReceivedProcessor<R>,
         summary_producer: SummaryProducer,
         node_status_storage: NymApiStorage,
+        report_signing_keys: Arc<identity::KeyPair>,
         packet_type: PacketType,
     ) -> Self {
         Monitor {
@@ -66,6 +75,7 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             received_processor,
             summary_producer,
             node_status_storage,
+            report_signing_keys,
             run_interval: config.debug.run_interval,
             gateway_ping_interval: config.debug.gateway_ping_interval,
             packet_delivery_timeout: config.debug.packet_delivery_timeout,
@@ -79,6 +89,13 @@ impl<R: MessageReceiver + Send> Monitor<R> {
     // while it might have been cleaner to put this into a separate `Notifier` structure,
     // I don't see much point considering it's only a single, small, method
     async fn submit_new_node_statuses(&mut self, test_summary: TestSummary) {
+        let report = reports::new_report(
+            OffsetDateTime::now_utc().unix_timestamp(),
+            &test_summary.mixnode_results,
+            &test_summary.gateway_results,
+        );
+        let signed_report = reports::sign_report(&self.report_signing_keys, report);
+
         // indicate our run has completed successfully and should be used in any future
         // uptime calculations
         if let Err(err) = self
@@ -86,11 +103,7 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             .insert_monitor_run_results(
                 test_summary.mixnode_results,
                 test_summary.gateway_results,
-                test_summary
-                    .route_results
-                    .into_iter()
-                    .map(|result| result.route)
-                    .collect(),
+                test_summary.route_results,
             )
             .await
         {
@@ -99,15 +112,27 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             // TODO: slightly more graceful shutdown here
             process::exit(1);
         }
+
+        // failing to store the signed report is not critical, other monitors will just
+        // have to do without our results of this particular run
+        if let Err(err) = self
+            .node_status_storage
+            .insert_signed_monitor_report(&signed_report)
+            .await
+        {
+            error!("Failed to store signed monitor report - {err}");
+        }
     }
 
     fn analyse_received_test_route_packets(
         &self,
-        packets: &[NodeTestMessage],
+        packets: &[ReceivedTestMessage],
     ) -> HashMap<u64, usize> {
         let mut received = HashMap::new();
         for packet in packets {
-            *received.entry(packet.ext.route_id).or_insert(0usize) += 1usize
+            *received
+                .entry(packet.message.ext.route_id)
+                .or_insert(0usize) += 1usize
         }
 
         received
@@ -255,7 +280,8 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             .await;
 
         info!("Sending packets to all gateways...");
-        self.packet_sender
+        let send_times = self
+            .packet_sender
             .send_packets(prepared_packets.packets)
             .await;
 
@@ -276,6 +302,7 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             prepared_packets.tested_mixnodes,
             prepared_packets.tested_gateways,
             received,
+            send_times,
             prepared_packets.invalid_mixnodes,
             prepared_packets.invalid_gateways,
             routes,
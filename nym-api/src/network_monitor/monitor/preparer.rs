@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::sender::GatewayPackets;
+use crate::network_monitor::test_packet::{NymApiTestMessageExt, TestPacket, TestPacketId};
 use crate::network_monitor::test_route::TestRoute;
 use crate::nym_contract_cache::cache::NymContractCache;
 use log::info;
@@ -13,6 +14,7 @@ use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::forwarding::packet::MixPacket;
 use nym_sphinx::params::{PacketSize, PacketType};
+use nym_sphinx::preparer::PreparedFragment;
 use nym_topology::{gateway, mix};
 use rand_07::{rngs::ThreadRng, seq::SliceRandom, thread_rng, Rng};
 use std::collections::{HashMap, HashSet};
@@ -108,7 +110,7 @@ impl PacketPreparer {
         let rng = thread_rng();
         NodeTester::new(
             rng,
-            // the topology here contains 3 mixnodes and 1 gateway so its cheap to clone it
+            // the topology here contains a single mixnode per layer and 1 gateway so its cheap to clone it
             test_route.topology().clone(),
             self_address,
             PacketSize::RegularPacket,
@@ -116,6 +118,7 @@ impl PacketPreparer {
             DEFAULT_AVERAGE_ACK_DELAY,
             self.ack_key.clone(),
         )
+        .with_mix_hops(test_route.num_mix_layers())
     }
 
     // when we're testing mixnodes, the recipient is going to stay constant, so we can specify it ahead of time
@@ -153,22 +156,15 @@ impl PacketPreparer {
                 continue;
             }
 
-            let mut layer1_count = 0;
-            let mut layer2_count = 0;
-            let mut layer3_count = 0;
-
+            let mix_layers = self.validator_cache.mix_layers().await;
+            let mut layer_counts: HashMap<Layer, usize> = HashMap::new();
             for mix in mixnodes {
-                match mix.layer {
-                    Layer::One => layer1_count += 1,
-                    Layer::Two => layer2_count += 1,
-                    Layer::Three => layer3_count += 1,
-                }
+                *layer_counts.entry(mix.layer).or_default() += 1;
             }
 
-            if layer1_count >= minimum_full_routes
-                && layer2_count >= minimum_full_routes
-                && layer3_count >= minimum_full_routes
-            {
+            if Layer::all(mix_layers).all(|layer| {
+                layer_counts.get(&layer).copied().unwrap_or_default() >= minimum_full_routes
+            }) {
                 break;
             }
 
@@ -209,6 +205,8 @@ impl PacketPreparer {
         blacklist: &mut HashSet<String>,
     ) -> Option<Vec<TestRoute>> {
         let (mixnodes, gateways) = self.all_mixnodes_and_gateways().await;
+        let mix_layers = self.validator_cache.mix_layers().await;
+
         // separate mixes into layers for easier selection
         let mut layered_mixes = HashMap::new();
         for mix in mixnodes {
@@ -217,29 +215,23 @@ impl PacketPreparer {
             mixes.push(mix)
         }
 
-        // get all nodes from each layer...
-        let l1 = layered_mixes.get(&Layer::One)?;
-        let l2 = layered_mixes.get(&Layer::Two)?;
-        let l3 = layered_mixes.get(&Layer::Three)?;
-
-        // try to choose n nodes from each of them (+ gateways)...
+        // try to choose n nodes from each layer (+ gateways)...
         let mut rng = thread_rng();
 
-        let rand_l1 = l1.choose_multiple(&mut rng, n).collect::<Vec<_>>();
-        let rand_l2 = l2.choose_multiple(&mut rng, n).collect::<Vec<_>>();
-        let rand_l3 = l3.choose_multiple(&mut rng, n).collect::<Vec<_>>();
+        let mut rand_layers = Vec::with_capacity(mix_layers as usize);
+        for layer in Layer::all(mix_layers) {
+            let mixes = layered_mixes.get(&layer)?;
+            rand_layers.push(mixes.choose_multiple(&mut rng, n).collect::<Vec<_>>());
+        }
         let rand_gateways = gateways.choose_multiple(&mut rng, n).collect::<Vec<_>>();
 
         // the unwrap on `min()` is fine as we know the iterator is not empty
-        let most_available = *[
-            rand_l1.len(),
-            rand_l2.len(),
-            rand_l3.len(),
-            rand_gateways.len(),
-        ]
-        .iter()
-        .min()
-        .unwrap();
+        let most_available = rand_layers
+            .iter()
+            .map(|layer| layer.len())
+            .chain(std::iter::once(rand_gateways.len()))
+            .min()
+            .unwrap();
 
         if most_available == 0 {
             error!("Cannot construct test routes. No nodes or gateways available");
@@ -248,28 +240,22 @@ impl PacketPreparer {
 
         trace!("Generating test routes...");
         let mut routes = Vec::new();
-        for i in 0..most_available {
-            let Ok(node_1) = self.try_parse_mix_bond(rand_l1[i]) else {
-                blacklist.insert(rand_l1[i].identity().to_owned());
-                continue;
-            };
-
-            let Ok(node_2) = self.try_parse_mix_bond(rand_l2[i]) else {
-                blacklist.insert(rand_l2[i].identity().to_owned());
-                continue;
-            };
-
-            let Ok(node_3) = self.try_parse_mix_bond(rand_l3[i]) else {
-                blacklist.insert(rand_l3[i].identity().to_owned());
-                continue;
-            };
+        'routes: for i in 0..most_available {
+            let mut nodes = Vec::with_capacity(rand_layers.len());
+            for rand_layer in &rand_layers {
+                let Ok(node) = self.try_parse_mix_bond(rand_layer[i]) else {
+                    blacklist.insert(rand_layer[i].identity().to_owned());
+                    continue 'routes;
+                };
+                nodes.push(node)
+            }
 
             let Ok(gateway) = self.try_parse_gateway_bond(rand_gateways[i]) else {
                 blacklist.insert(rand_gateways[i].identity().to_owned());
                 continue;
             };
 
-            routes.push(TestRoute::new(rng.gen(), node_1, node_2, node_3, gateway))
+            routes.push(TestRoute::new(rng.gen(), nodes, gateway))
         }
         info!(
             "The following routes will be used for testing: {:#?}",
@@ -296,6 +282,7 @@ impl PacketPreparer {
         let mut tester = self.ephemeral_mix_tester(route);
         let topology = route.topology();
         let plaintexts = route.self_test_messages(num);
+        let packet_ids = route.self_test_packet_ids(num);
 
         // the unwrap here is fine as:
         // 1. the topology is definitely valid (otherwise we wouldn't be here)
@@ -305,6 +292,8 @@ impl PacketPreparer {
             .into_iter()
             .map(|p| tester.wrap_plaintext_data(p, topology, None).unwrap())
             .map(MixPacket::from)
+            .zip(packet_ids)
+            .map(|(mix_packet, id)| TestPacket::new(id, mix_packet))
             .collect();
 
         GatewayPackets::new(
@@ -357,6 +346,18 @@ impl PacketPreparer {
         (parsed_nodes, invalid_nodes)
     }
 
+    // the tester creates the node's packets in order of their message ids, i.e. `1..=total_msgs`
+    fn identify_test_packets(
+        route_ext: NymApiTestMessageExt,
+        tested_node: TestableNode,
+        prepared: Vec<PreparedFragment>,
+    ) -> impl Iterator<Item = TestPacket> {
+        let total_msgs = prepared.len() as u32;
+        TestPacketId::node_packets(route_ext, tested_node, total_msgs)
+            .zip(prepared)
+            .map(|(id, fragment)| TestPacket::new(id, fragment.into()))
+    }
+
     pub(super) async fn prepare_test_packets(
         &mut self,
         test_nonce: u64,
@@ -391,20 +392,26 @@ impl PacketPreparer {
             let mut mix_tester = self.ephemeral_mix_tester(test_route);
 
             // generate test packets for mixnodes
-            //
-            // the unwrap here is fine as:
-            // 1. the topology is definitely valid (otherwise we wouldn't be here)
-            // 2. the recipient is specified (by calling **mix**_tester)
-            // 3. the test message is not too long, i.e. when serialized it will fit in a single sphinx packet
-            let mixnode_test_packets = mix_tester
-                .mixnodes_test_packets(
-                    &mixnodes,
+            let mut mix_packets = Vec::with_capacity(mixnodes.len() * self.per_node_test_packets);
+            for mixnode in &mixnodes {
+                // the unwrap here is fine as:
+                // 1. the topology is definitely valid (otherwise we wouldn't be here)
+                // 2. the recipient is specified (by calling **mix**_tester)
+                // 3. the test message is not too long, i.e. when serialized it will fit in a single sphinx packet
+                let mixnode_test_packets = mix_tester
+                    .mixnode_test_packets(
+                        mixnode,
+                        route_ext,
+                        self.per_node_test_packets as u32,
+                        None,
+                    )
+                    .unwrap();
+                mix_packets.extend(Self::identify_test_packets(
                     route_ext,
-                    self.per_node_test_packets as u32,
-                    None,
-                )
-                .unwrap();
-            let mix_packets = mixnode_test_packets.into_iter().map(Into::into).collect();
+                    mixnode.into(),
+                    mixnode_test_packets,
+                ));
+            }
 
             let gateway_packets = all_gateway_packets
                 .entry(gateway_identity.to_bytes())
@@ -430,7 +437,8 @@ impl PacketPreparer {
                     )
                     .unwrap();
                 let gateway_mix_packets =
-                    gateway_test_packets.into_iter().map(Into::into).collect();
+                    Self::identify_test_packets(route_ext, gateway.into(), gateway_test_packets)
+                        .collect();
 
                 // and push it into existing struct (if it's a "core" gateway being tested against another route)
                 // or create a new one
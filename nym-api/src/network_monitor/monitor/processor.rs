@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::gateways_reader::GatewayMessages;
-use crate::network_monitor::test_packet::{NodeTestMessage, NymApiTestMessageExt};
+use crate::network_monitor::test_packet::{NymApiTestMessageExt, ReceivedTestMessage};
 use crate::network_monitor::ROUTE_TESTING_TEST_NONCE;
 use futures::channel::mpsc;
 use futures::lock::{Mutex, MutexGuard};
@@ -54,7 +54,7 @@ struct ReceivedProcessorInner<R: MessageReceiver> {
 
     /// Vector containing all received (and decrypted) packets in the current test run.
     // TODO: perhaps a different structure would be better here
-    received_packets: Vec<NodeTestMessage>,
+    received_packets: Vec<ReceivedTestMessage>,
 }
 
 impl<R: MessageReceiver> ReceivedProcessorInner<R> {
@@ -74,7 +74,8 @@ impl<R: MessageReceiver> ReceivedProcessorInner<R> {
             });
         }
 
-        self.received_packets.push(test_msg);
+        self.received_packets
+            .push(ReceivedTestMessage::new(test_msg));
         Ok(())
     }
 
@@ -111,7 +112,7 @@ impl<R: MessageReceiver> ReceivedProcessorInner<R> {
         }
     }
 
-    fn finish_run(&mut self) -> Vec<NodeTestMessage> {
+    fn finish_run(&mut self) -> Vec<ReceivedTestMessage> {
         self.test_nonce = None;
         mem::take(&mut self.received_packets)
     }
@@ -220,7 +221,7 @@ impl<R: MessageReceiver + Send + 'static> ReceivedProcessor<R> {
             .expect("processing task has died!");
     }
 
-    pub(super) async fn return_received(&mut self) -> Vec<NodeTestMessage> {
+    pub(super) async fn return_received(&mut self) -> Vec<ReceivedTestMessage> {
         // ask for the lock back
         self.permit_changer
             .as_mut()
@@ -6,6 +6,7 @@ use crate::network_monitor::monitor::gateway_clients_cache::{
 };
 use crate::network_monitor::monitor::gateways_pinger::GatewayPinger;
 use crate::network_monitor::monitor::receiver::{GatewayClientUpdate, GatewayClientUpdateSender};
+use crate::network_monitor::test_packet::{PacketSendTimes, TestPacket, TestPacketId};
 use crate::support::nyxd;
 use futures::channel::mpsc;
 use futures::stream::{self, FuturesUnordered, StreamExt};
@@ -24,9 +25,9 @@ use pin_project::pin_project;
 use std::mem;
 use std::num::NonZeroUsize;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::Poll;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const TIME_CHUNK_SIZE: Duration = Duration::from_millis(50);
 
@@ -39,14 +40,14 @@ pub(crate) struct GatewayPackets {
     pub(crate) pub_key: identity::PublicKey,
 
     /// All the packets that are going to get sent to the gateway.
-    pub(crate) packets: Vec<MixPacket>,
+    pub(crate) packets: Vec<TestPacket>,
 }
 
 impl GatewayPackets {
     pub(crate) fn new(
         clients_address: String,
         pub_key: identity::PublicKey,
-        packets: Vec<MixPacket>,
+        packets: Vec<TestPacket>,
     ) -> Self {
         GatewayPackets {
             clients_address,
@@ -63,7 +64,7 @@ impl GatewayPackets {
         }
     }
 
-    pub(super) fn push_packets(&mut self, mut packets: Vec<MixPacket>) {
+    pub(super) fn push_packets(&mut self, mut packets: Vec<TestPacket>) {
         if self.packets.is_empty() {
             self.packets = packets
         } else if self.packets.len() > packets.len() {
@@ -205,33 +206,58 @@ impl PacketSender {
         )
     }
 
+    // records the time at which the packets are handed over to the gateway
+    // and strips them of their ids
+    fn mark_as_sent(
+        packets: Vec<TestPacket>,
+        send_times: &Mutex<PacketSendTimes>,
+    ) -> Vec<MixPacket> {
+        let now = Instant::now();
+        let (ids, mix_packets): (Vec<TestPacketId>, Vec<MixPacket>) = packets
+            .into_iter()
+            .map(|packet| (packet.id, packet.mix_packet))
+            .unzip();
+
+        let mut send_times = send_times
+            .lock()
+            .expect("packet send times lock got poisoned");
+        for id in ids {
+            send_times.insert(id, now);
+        }
+
+        mix_packets
+    }
+
     async fn attempt_to_send_packets(
         client: &mut GatewayClient<nyxd::Client, PersistentStorage>,
-        mut mix_packets: Vec<MixPacket>,
+        mut packets: Vec<TestPacket>,
         max_sending_rate: usize,
+        send_times: &Mutex<PacketSendTimes>,
     ) -> Result<(), GatewayClientError> {
         let gateway_id = client.gateway_identity().to_base58_string();
         info!(
             "Got {} packets to send to gateway {}",
-            mix_packets.len(),
+            packets.len(),
             gateway_id
         );
 
-        if mix_packets.len() <= max_sending_rate {
+        if packets.len() <= max_sending_rate {
             debug!("Everything is going to get sent as one.");
-            client.batch_send_mix_packets(mix_packets).await?;
+            client
+                .batch_send_mix_packets(Self::mark_as_sent(packets, send_times))
+                .await?;
         } else {
             let packets_per_time_chunk =
                 (max_sending_rate as f64 * TIME_CHUNK_SIZE.as_secs_f64()) as usize;
 
             let total_expected_time =
-                Duration::from_secs_f64(mix_packets.len() as f64 / max_sending_rate as f64);
+                Duration::from_secs_f64(packets.len() as f64 / max_sending_rate as f64);
             info!(
                 "With our rate of {} packets/s it should take around {:?} to send it all to {} ...",
                 max_sending_rate, total_expected_time, gateway_id
             );
 
-            fn split_off_vec(vec: &mut Vec<MixPacket>, at: usize) -> Option<Vec<MixPacket>> {
+            fn split_off_vec(vec: &mut Vec<TestPacket>, at: usize) -> Option<Vec<TestPacket>> {
                 if vec.is_empty() {
                     None
                 } else {
@@ -245,9 +271,10 @@ impl PacketSender {
             // TODO future consideration: perhaps allow gateway client to take the packets by reference?
             // this way we won't have to do reallocations in here as they're unavoidable when
             // splitting a vector into multiple vectors
-            while let Some(retained) = split_off_vec(&mut mix_packets, packets_per_time_chunk) {
-                trace!("Sending {} packets...", mix_packets.len());
+            while let Some(retained) = split_off_vec(&mut packets, packets_per_time_chunk) {
+                trace!("Sending {} packets...", packets.len());
 
+                let mut mix_packets = Self::mark_as_sent(packets, send_times);
                 if mix_packets.len() == 1 {
                     client.send_mix_packet(mix_packets.pop().unwrap()).await?;
                 } else {
@@ -256,7 +283,7 @@ impl PacketSender {
 
                 tokio::time::sleep(TIME_CHUNK_SIZE).await;
 
-                mix_packets = retained;
+                packets = retained;
             }
             debug!("Done sending");
         }
@@ -334,6 +361,7 @@ impl PacketSender {
         fresh_gateway_client_data: Arc<FreshGatewayClientData>,
         client: Option<GatewayClientHandle>,
         max_sending_rate: usize,
+        send_times: Arc<Mutex<PacketSendTimes>>,
     ) -> Option<GatewayClientHandle> {
         let existing_client = client.is_some();
 
@@ -380,7 +408,12 @@ impl PacketSender {
 
         match tokio::time::timeout(
             timeout,
-            Self::attempt_to_send_packets(unwrapped_client, packets.packets, max_sending_rate),
+            Self::attempt_to_send_packets(
+                unwrapped_client,
+                packets.packets,
+                max_sending_rate,
+                &send_times,
+            ),
         )
         .await
         {
@@ -443,7 +476,9 @@ impl PacketSender {
         }
     }
 
-    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) {
+    /// Sends all the provided packets to their respective gateways and returns
+    /// the times at which each of them has actually been sent.
+    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) -> PacketSendTimes {
         // we know that each of the elements in the packets array will only ever access a single,
         // unique element from the existing clients
 
@@ -454,6 +489,7 @@ impl PacketSender {
             None
         };
         let max_sending_rate = self.max_sending_rate;
+        let send_times = Arc::new(Mutex::new(PacketSendTimes::new()));
 
         let guard = self.active_gateway_clients.lock().await;
         // this clippy warning is a false positive as we cannot get rid of the collect by moving
@@ -470,6 +506,7 @@ impl PacketSender {
                     packets,
                     Arc::clone(&self.fresh_gateway_client_data),
                     existing_client,
+                    Arc::clone(&send_times),
                 )
             })
             .collect::<Vec<_>>();
@@ -484,13 +521,14 @@ impl PacketSender {
         let used_clients = ForEachConcurrentClientUse::new(
             stream::iter(stream_data.into_iter()),
             max_concurrent_clients,
-            |(packets, fresh_data, client)| async move {
+            |(packets, fresh_data, client, send_times)| async move {
                 Self::send_gateway_packets(
                     gateway_connection_timeout,
                     packets,
                     fresh_data,
                     client,
                     max_sending_rate,
+                    send_times,
                 )
                 .await
             },
@@ -501,6 +539,11 @@ impl PacketSender {
         .collect();
 
         self.merge_client_handles(used_clients).await;
+
+        let mut guard = send_times
+            .lock()
+            .expect("packet send times lock got poisoned");
+        mem::take(&mut *guard)
     }
 }
 
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::preparer::InvalidNode;
-use crate::network_monitor::test_packet::NodeTestMessage;
+use crate::network_monitor::test_packet::{PacketSendTimes, ReceivedTestMessage};
 use crate::network_monitor::test_route::TestRoute;
+use nym_api_requests::models::LatencyPercentiles;
 use nym_mixnet_contract_common::MixId;
 use nym_node_tester_utils::node::{NodeType, TestableNode};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 // just some approximate measures to print to stdout (well, technically stderr since it's being printed via log)
 const EXCEPTIONAL_THRESHOLD: u8 = 95; // 95 - 100
@@ -20,21 +22,49 @@ const UNRELIABLE_THRESHOLD: u8 = 1; // 1 - 60
 // from the average result, remove this data and recalculate scores.
 // const ALLOWED_RELIABILITY_DEVIATION: f32 = 5.0;
 
+/// Computes the (nearest-rank) latency percentiles of the provided samples.
+pub(crate) fn latency_percentiles(mut samples: Vec<Duration>) -> Option<LatencyPercentiles> {
+    if samples.is_empty() {
+        return None;
+    }
+    samples.sort_unstable();
+
+    let percentile = |p: usize| {
+        // equivalent of `ceil(p * n / 100)`
+        let rank = (p * samples.len() + 99) / 100;
+        samples[rank.max(1) - 1].as_millis() as u32
+    };
+
+    Some(LatencyPercentiles {
+        p50_ms: percentile(50),
+        p90_ms: percentile(90),
+        p99_ms: percentile(99),
+    })
+}
+
 #[derive(Debug)]
 pub(crate) struct MixnodeResult {
     pub(crate) mix_id: MixId,
     pub(crate) identity: String,
     pub(crate) owner: String,
     pub(crate) reliability: u8,
+    pub(crate) latency: Option<LatencyPercentiles>,
 }
 
 impl MixnodeResult {
-    pub(crate) fn new(mix_id: MixId, identity: String, owner: String, reliability: u8) -> Self {
+    pub(crate) fn new(
+        mix_id: MixId,
+        identity: String,
+        owner: String,
+        reliability: u8,
+        latency: Option<LatencyPercentiles>,
+    ) -> Self {
         MixnodeResult {
             mix_id,
             identity,
             owner,
             reliability,
+            latency,
         }
     }
 }
@@ -44,14 +74,21 @@ pub(crate) struct GatewayResult {
     pub(crate) identity: String,
     pub(crate) owner: String,
     pub(crate) reliability: u8,
+    pub(crate) latency: Option<LatencyPercentiles>,
 }
 
 impl GatewayResult {
-    pub(crate) fn new(identity: String, owner: String, reliability: u8) -> Self {
+    pub(crate) fn new(
+        identity: String,
+        owner: String,
+        reliability: u8,
+        latency: Option<LatencyPercentiles>,
+    ) -> Self {
         GatewayResult {
             identity,
             owner,
             reliability,
+            latency,
         }
     }
 }
@@ -60,11 +97,20 @@ impl GatewayResult {
 pub(crate) struct RouteResult {
     pub(crate) route: TestRoute,
     performance: f32,
+    pub(crate) latency: Option<LatencyPercentiles>,
 }
 
 impl RouteResult {
-    pub(crate) fn new(route: TestRoute, performance: f32) -> Self {
-        RouteResult { route, performance }
+    pub(crate) fn new(
+        route: TestRoute,
+        performance: f32,
+        latency: Option<LatencyPercentiles>,
+    ) -> Self {
+        RouteResult {
+            route,
+            performance,
+            latency,
+        }
     }
 }
 
@@ -175,11 +221,19 @@ impl Display for TestReport {
 
         writeln!(f, "Routes used for testing:")?;
         for route_result in &self.route_results {
-            writeln!(
+            write!(
                 f,
                 "{:?}, reliability: {:.2}",
                 route_result.route, route_result.performance
             )?;
+            match route_result.latency {
+                Some(latency) => writeln!(
+                    f,
+                    ", latency: p50 {}ms, p90 {}ms, p99 {}ms",
+                    latency.p50_ms, latency.p90_ms, latency.p99_ms
+                )?,
+                None => writeln!(f)?,
+            }
         }
 
         writeln!(
@@ -282,7 +336,8 @@ impl SummaryProducer {
         &self,
         tested_mixnodes: Vec<TestableNode>,
         tested_gateways: Vec<TestableNode>,
-        received_packets: Vec<NodeTestMessage>,
+        received_packets: Vec<ReceivedTestMessage>,
+        mut send_times: PacketSendTimes,
         invalid_mixnodes: Vec<InvalidNode>,
         invalid_gateways: Vec<InvalidNode>,
         test_routes: &[TestRoute],
@@ -315,26 +370,54 @@ impl SummaryProducer {
             raw_results.insert(invalid_gateway.into(), 0);
         }
 
+        let mut node_latencies: HashMap<TestableNode, Vec<Duration>> = HashMap::new();
+        let mut route_latencies: HashMap<u64, Vec<Duration>> = HashMap::new();
+
         for received in received_packets {
-            *raw_results.entry(received.tested_node).or_default() += 1usize;
-            *raw_route_results.entry(received.ext.route_id).or_default() += 1usize;
+            let latency = received.take_latency(&mut send_times);
+            let route_id = received.message.ext.route_id;
+            let tested_node = received.message.tested_node;
+
+            *raw_route_results.entry(route_id).or_default() += 1usize;
+            *raw_results.entry(tested_node.clone()).or_default() += 1usize;
+
+            // we should always know when the packet has been sent, but in case we don't,
+            // it still counts towards the reliability
+            if let Some(latency) = latency {
+                route_latencies.entry(route_id).or_default().push(latency);
+                node_latencies.entry(tested_node).or_default().push(latency);
+            }
         }
 
+        // whatever is left belongs to the packets that never made it back
+        // (and it's dropped alongside the map at the end of the run)
+        debug!(
+            "{} test packets did not arrive before the delivery timeout",
+            send_times.len()
+        );
+
         let mut mixnode_results = Vec::new();
         let mut gateway_results = Vec::new();
 
         for (node, received) in raw_results {
             let performance = received as f32 / per_node_expected as f32 * 100.0;
             let reliability = performance.round() as u8;
+            let latency = node_latencies.remove(&node).and_then(latency_percentiles);
 
             match node.typ {
                 NodeType::Mixnode { mix_id } => {
-                    let res =
-                        MixnodeResult::new(mix_id, node.encoded_identity, node.owner, reliability);
+                    let res = MixnodeResult::new(
+                        mix_id,
+                        node.encoded_identity,
+                        node.owner,
+                        reliability,
+                        latency,
+                    );
                     mixnode_results.push(res)
                 }
                 NodeType::Gateway => {
-                    let res = GatewayResult::new(node.encoded_identity, node.owner, reliability);
+                    let res =
+                        GatewayResult::new(node.encoded_identity, node.owner, reliability, latency);
                     gateway_results.push(res)
                 }
             }
@@ -344,13 +427,14 @@ impl SummaryProducer {
             .into_iter()
             .filter_map(|(id, received)| {
                 let performance = received as f32 / per_route_expected as f32 * 100.0;
+                let latency = route_latencies.remove(&id).and_then(latency_percentiles);
 
                 // this might be suboptimal as we're going through the entire slice every time
                 // but realistically this slice will never have more than ~ 10 elements AT MOST
                 test_routes
                     .iter()
                     .find(|route| route.id() == id)
-                    .map(|route| RouteResult::new(route.clone(), performance))
+                    .map(|route| RouteResult::new(route.clone(), performance, latency))
             })
             .collect();
 
@@ -361,3 +445,25 @@ impl SummaryProducer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_percentiles_use_nearest_rank() {
+        assert!(latency_percentiles(vec![]).is_none());
+
+        let single = latency_percentiles(vec![Duration::from_millis(42)]).unwrap();
+        assert_eq!(single.p50_ms, 42);
+        assert_eq!(single.p90_ms, 42);
+        assert_eq!(single.p99_ms, 42);
+
+        // 1ms, 2ms, ..., 100ms in reverse order
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let percentiles = latency_percentiles(samples).unwrap();
+        assert_eq!(percentiles.p50_ms, 50);
+        assert_eq!(percentiles.p90_ms, 90);
+        assert_eq!(percentiles.p99_ms, 99);
+    }
+}
@@ -0,0 +1,366 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeResult};
+use crate::node_status_api::models::Uptime;
+use crate::storage::NymApiStorage;
+use crate::support::config;
+use log::{error, warn};
+use nym_api_requests::models::{
+    MonitorReport, MonitorReportGatewayResult, MonitorReportMixnodeResult, SignedMonitorReport,
+};
+use nym_crypto::asymmetric::identity;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::NymApiClient;
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+/// Serializes the report into the exact representation that gets signed.
+pub(crate) fn serialize_report(report: &MonitorReport) -> String {
+    // the report only consists of integers and strings, so the serialization can't possibly fail
+    serde_json::to_string(report).expect("failed to serialize monitor report")
+}
+
+pub(crate) fn new_report(
+    timestamp: i64,
+    mixnode_results: &[MixnodeResult],
+    gateway_results: &[GatewayResult],
+) -> MonitorReport {
+    MonitorReport {
+        timestamp,
+        mixnode_results: mixnode_results
+            .iter()
+            .map(|result| MonitorReportMixnodeResult {
+                mix_id: result.mix_id,
+                reliability: result.reliability,
+            })
+            .collect(),
+        gateway_results: gateway_results
+            .iter()
+            .map(|result| MonitorReportGatewayResult {
+                identity: result.identity.clone(),
+                reliability: result.reliability,
+            })
+            .collect(),
+    }
+}
+
+pub(crate) fn sign_report(keys: &identity::KeyPair, report: MonitorReport) -> SignedMonitorReport {
+    let signature = keys
+        .private_key()
+        .sign(serialize_report(&report).as_bytes());
+
+    SignedMonitorReport {
+        signer: keys.public_key().to_base58_string(),
+        report,
+        signature: signature.to_base58_string(),
+    }
+}
+
+pub(crate) fn verify_report(signed_report: &SignedMonitorReport) -> bool {
+    let Ok(signer) = identity::PublicKey::from_base58_string(&signed_report.signer) else {
+        return false;
+    };
+    let Ok(signature) = identity::Signature::from_base58_string(&signed_report.signature) else {
+        return false;
+    };
+
+    signer
+        .verify(
+            serialize_report(&signed_report.report).as_bytes(),
+            &signature,
+        )
+        .is_ok()
+}
+
+/// Loads the identity keys used for signing monitor reports or generates fresh ones if they don't exist yet.
+pub(crate) fn load_or_generate_identity_keypair(
+    config: &config::NetworkMonitor,
+) -> io::Result<identity::KeyPair> {
+    let paths = config.storage_paths.identity_key_pair_path();
+    if paths.private_key_path.exists() && paths.public_key_path.exists() {
+        return nym_pemstore::load_keypair(&paths);
+    }
+
+    let mut rng = rand_07::rngs::OsRng;
+    let keypair = identity::KeyPair::new(&mut rng);
+    nym_pemstore::store_keypair(&keypair, &paths)?;
+    Ok(keypair)
+}
+
+/// Returns the median of the provided values as long as there are at least `threshold` of them.
+fn median_with_threshold(mut values: Vec<f32>, threshold: usize) -> Option<f32> {
+    if values.is_empty() || values.len() < threshold {
+        return None;
+    }
+
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(values[mid])
+    } else {
+        Some((values[mid - 1] + values[mid]) / 2.)
+    }
+}
+
+#[derive(Default)]
+struct MonitorAverages<'a> {
+    // (sum, count) of all reliabilities reported by particular monitor
+    per_monitor: HashMap<&'a str, (f32, usize)>,
+}
+
+impl<'a> MonitorAverages<'a> {
+    fn add(&mut self, monitor: &'a str, reliability: u8) {
+        let entry = self.per_monitor.entry(monitor).or_default();
+        entry.0 += reliability as f32;
+        entry.1 += 1;
+    }
+
+    fn median(&self, threshold: usize) -> Option<Uptime> {
+        let averages = self
+            .per_monitor
+            .values()
+            .map(|(sum, count)| sum / *count as f32)
+            .collect();
+
+        median_with_threshold(averages, threshold).map(|median| Uptime::new(median.round()))
+    }
+}
+
+/// Node reliabilities aggregated from reports of multiple network monitors.
+#[derive(Debug, Default)]
+pub(crate) struct AggregatedReliability {
+    mixnodes: HashMap<MixId, Uptime>,
+    gateways: HashMap<String, Uptime>,
+}
+
+impl AggregatedReliability {
+    /// Aggregates the provided (already verified) reports. For each node, results of every monitor
+    /// are averaged first, so that a monitor running more frequently would not carry more weight,
+    /// and then the median of those averages is taken, as long as at least `threshold` distinct
+    /// monitors have reported on that node.
+    pub(crate) fn from_reports(reports: &[SignedMonitorReport], threshold: usize) -> Self {
+        let mut mixnodes: HashMap<MixId, MonitorAverages> = HashMap::new();
+        let mut gateways: HashMap<&str, MonitorAverages> = HashMap::new();
+
+        for signed_report in reports {
+            let monitor = signed_report.signer.as_str();
+            for result in &signed_report.report.mixnode_results {
+                mixnodes
+                    .entry(result.mix_id)
+                    .or_default()
+                    .add(monitor, result.reliability)
+            }
+            for result in &signed_report.report.gateway_results {
+                gateways
+                    .entry(result.identity.as_str())
+                    .or_default()
+                    .add(monitor, result.reliability)
+            }
+        }
+
+        AggregatedReliability {
+            mixnodes: mixnodes
+                .into_iter()
+                .filter_map(|(mix_id, averages)| {
+                    averages.median(threshold).map(|uptime| (mix_id, uptime))
+                })
+                .collect(),
+            gateways: gateways
+                .into_iter()
+                .filter_map(|(identity, averages)| {
+                    averages
+                        .median(threshold)
+                        .map(|uptime| (identity.to_string(), uptime))
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn mixnode(&self, mix_id: MixId) -> Option<Uptime> {
+        self.mixnodes.get(&mix_id).copied()
+    }
+
+    pub(crate) fn gateway(&self, identity: &str) -> Option<Uptime> {
+        self.gateways.get(identity).copied()
+    }
+}
+
+/// Combines signed reports of the local network monitor with the ones obtained from peer nym-apis.
+pub(crate) struct MonitorReportsAggregator {
+    storage: NymApiStorage,
+    peers: Vec<NymApiClient>,
+    trusted_monitors: HashSet<String>,
+    minimum_reports: usize,
+}
+
+impl MonitorReportsAggregator {
+    pub(crate) fn new(
+        config: &config::Rewarding,
+        local_monitor: identity::PublicKey,
+        storage: NymApiStorage,
+    ) -> Self {
+        let mut trusted_monitors: HashSet<_> = config.trusted_monitors.iter().cloned().collect();
+        trusted_monitors.insert(local_monitor.to_base58_string());
+
+        MonitorReportsAggregator {
+            storage,
+            peers: config
+                .peer_monitors
+                .iter()
+                .map(|url| NymApiClient::new(url.clone()))
+                .collect(),
+            trusted_monitors,
+            minimum_reports: config.debug.minimum_monitor_reports,
+        }
+    }
+
+    pub(crate) fn has_peers(&self) -> bool {
+        !self.peers.is_empty()
+    }
+
+    /// Gathers all valid reports created within the specified interval.
+    /// Returns `None` if none of the peers could have been reached,
+    /// i.e. if there's nothing to aggregate our local results with.
+    async fn gather_reports(&self, since: i64, until: i64) -> Option<Vec<SignedMonitorReport>> {
+        let mut reports = match self.storage.get_signed_monitor_reports(since, until).await {
+            Ok(reports) => reports,
+            Err(err) => {
+                error!("failed to load local monitor reports - {err}");
+                Vec::new()
+            }
+        };
+
+        let mut responsive_peers = 0;
+        for peer in &self.peers {
+            match peer.get_signed_monitor_reports(Some(since)).await {
+                Ok(res) => {
+                    responsive_peers += 1;
+                    reports.extend(res.reports)
+                }
+                Err(err) => warn!(
+                    "failed to obtain monitor reports from {} - {err}",
+                    peer.nym_api_client.current_url()
+                ),
+            }
+        }
+
+        if responsive_peers == 0 {
+            return None;
+        }
+
+        // peers might have returned reports outside the requested interval, reports of untrusted
+        // monitors or the same report more than once
+        let mut seen = HashSet::new();
+        reports.retain(|signed_report| {
+            let timestamp = signed_report.report.timestamp;
+            if timestamp < since || timestamp > until {
+                return false;
+            }
+            if !self.trusted_monitors.contains(&signed_report.signer) {
+                return false;
+            }
+            // make sure a forged report can't shadow the genuine one with the same timestamp
+            if !verify_report(signed_report) {
+                warn!(
+                    "monitor report from {} created at {timestamp} has invalid signature",
+                    signed_report.signer
+                );
+                return false;
+            }
+            seen.insert((signed_report.signer.clone(), timestamp))
+        });
+
+        Some(reports)
+    }
+
+    /// Aggregates reports of all trusted monitors created within the specified interval.
+    /// Returns `None` if none of the peers could have been reached.
+    pub(crate) async fn aggregate(&self, since: i64, until: i64) -> Option<AggregatedReliability> {
+        let reports = self.gather_reports(since, until).await?;
+        Some(AggregatedReliability::from_reports(
+            &reports,
+            self.minimum_reports,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_keys() -> identity::KeyPair {
+        let mut rng = rand_07::rngs::OsRng;
+        identity::KeyPair::new(&mut rng)
+    }
+
+    fn reliability(uptime: Option<Uptime>) -> Option<u8> {
+        uptime.map(Into::into)
+    }
+
+    fn mix_report(timestamp: i64, results: &[(MixId, u8)]) -> MonitorReport {
+        MonitorReport {
+            timestamp,
+            mixnode_results: results
+                .iter()
+                .map(|(mix_id, reliability)| MonitorReportMixnodeResult {
+                    mix_id: *mix_id,
+                    reliability: *reliability,
+                })
+                .collect(),
+            gateway_results: vec![],
+        }
+    }
+
+    #[test]
+    fn median_with_threshold_works() {
+        assert_eq!(median_with_threshold(vec![], 0), None);
+        assert_eq!(median_with_threshold(vec![10.], 2), None);
+        assert_eq!(median_with_threshold(vec![10.], 1), Some(10.));
+        assert_eq!(median_with_threshold(vec![90., 10., 50.], 3), Some(50.));
+        assert_eq!(
+            median_with_threshold(vec![90., 10., 50., 60.], 3),
+            Some(55.)
+        );
+    }
+
+    #[test]
+    fn signed_reports_can_be_verified() {
+        let keys = test_keys();
+        let signed = sign_report(&keys, mix_report(123, &[(1, 100), (2, 50)]));
+        assert!(verify_report(&signed));
+
+        let mut tampered = signed.clone();
+        tampered.report.mixnode_results[1].reliability = 100;
+        assert!(!verify_report(&tampered));
+
+        let mut wrong_signer = signed;
+        wrong_signer.signer = test_keys().public_key().to_base58_string();
+        assert!(!verify_report(&wrong_signer));
+    }
+
+    #[test]
+    fn aggregation_uses_median_of_per_monitor_averages() {
+        let monitor1 = test_keys();
+        let monitor2 = test_keys();
+        let monitor3 = test_keys();
+
+        let reports = vec![
+            // monitor1 averages to 90 for node 1
+            sign_report(&monitor1, mix_report(1, &[(1, 80), (2, 100)])),
+            sign_report(&monitor1, mix_report(2, &[(1, 100)])),
+            // lying monitor, which shouldn't be able to skew the result
+            sign_report(&monitor2, mix_report(1, &[(1, 0), (2, 0)])),
+            sign_report(&monitor3, mix_report(1, &[(1, 70), (2, 90)])),
+        ];
+
+        let aggregated = AggregatedReliability::from_reports(&reports, 3);
+        assert_eq!(reliability(aggregated.mixnode(1)), Some(70));
+        assert_eq!(reliability(aggregated.mixnode(2)), Some(90));
+
+        // not enough monitors reported on the nodes
+        let aggregated = AggregatedReliability::from_reports(&reports, 4);
+        assert!(aggregated.mixnode(1).is_none());
+        assert!(aggregated.mixnode(2).is_none());
+    }
+}
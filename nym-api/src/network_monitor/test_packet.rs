@@ -2,12 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use nym_node_tester_utils::error::NetworkTestingError;
+use nym_node_tester_utils::node::TestableNode;
 use nym_node_tester_utils::TestMessage;
+use nym_sphinx::forwarding::packet::MixPacket;
 use nym_topology::mix;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 pub(crate) type NodeTestMessage = TestMessage<NymApiTestMessageExt>;
 
+/// Times at which particular test packets have been sent to their gateways.
+pub(crate) type PacketSendTimes = HashMap<TestPacketId, Instant>;
+
 #[derive(Serialize, Deserialize, Clone, Copy, Hash)]
 pub(crate) struct NymApiTestMessageExt {
     pub(crate) route_id: u64,
@@ -30,3 +37,77 @@ impl NymApiTestMessageExt {
         NodeTestMessage::mix_plaintexts(node, test_packets, *self)
     }
 }
+
+/// Uniquely identifies a single test packet sent during particular test run.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub(crate) struct TestPacketId {
+    route_id: u64,
+    test_nonce: u64,
+    tested_node: TestableNode,
+    msg_id: u32,
+}
+
+impl TestPacketId {
+    /// Ids of all test packets created for the provided node,
+    /// i.e. the ones with message ids in the range `1..=total_msgs`.
+    pub(crate) fn node_packets(
+        ext: NymApiTestMessageExt,
+        tested_node: TestableNode,
+        total_msgs: u32,
+    ) -> impl Iterator<Item = Self> {
+        (1..=total_msgs).map(move |msg_id| TestPacketId {
+            route_id: ext.route_id,
+            test_nonce: ext.test_nonce,
+            tested_node: tested_node.clone(),
+            msg_id,
+        })
+    }
+}
+
+impl<'a> From<&'a NodeTestMessage> for TestPacketId {
+    fn from(message: &'a NodeTestMessage) -> Self {
+        TestPacketId {
+            route_id: message.ext.route_id,
+            test_nonce: message.ext.test_nonce,
+            tested_node: message.tested_node.clone(),
+            msg_id: message.msg_id,
+        }
+    }
+}
+
+/// Test mix packet alongside the id of the message it contains.
+pub(crate) struct TestPacket {
+    pub(crate) id: TestPacketId,
+    pub(crate) mix_packet: MixPacket,
+}
+
+impl TestPacket {
+    pub(crate) fn new(id: TestPacketId, mix_packet: MixPacket) -> Self {
+        TestPacket { id, mix_packet }
+    }
+}
+
+/// Test message alongside the time at which it got received.
+pub(crate) struct ReceivedTestMessage {
+    pub(crate) message: NodeTestMessage,
+    pub(crate) received_at: Instant,
+}
+
+impl ReceivedTestMessage {
+    pub(crate) fn new(message: NodeTestMessage) -> Self {
+        ReceivedTestMessage {
+            message,
+            received_at: Instant::now(),
+        }
+    }
+
+    /// Time it took the message to traverse the mixnet, as measured from the moment
+    /// its packet has been handed over to the gateway.
+    /// The send time of the packet is removed from the provided map, so that once all received
+    /// messages are processed, only the entries of the lost packets remain.
+    pub(crate) fn take_latency(&self, send_times: &mut PacketSendTimes) -> Option<Duration> {
+        send_times
+            .remove(&TestPacketId::from(&self.message))
+            .map(|sent_at| self.received_at.saturating_duration_since(sent_at))
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::network_monitor::test_packet::NymApiTestMessageExt;
+use crate::network_monitor::test_packet::{NymApiTestMessageExt, TestPacketId};
 use crate::network_monitor::ROUTE_TESTING_TEST_NONCE;
 use nym_crypto::asymmetric::identity;
 use nym_topology::{gateway, mix, NymTopology};
@@ -14,24 +14,19 @@ pub(crate) struct TestRoute {
 }
 
 impl TestRoute {
-    pub(crate) fn new(
-        id: u64,
-        l1_mix: mix::Node,
-        l2_mix: mix::Node,
-        l3_mix: mix::Node,
-        gateway: gateway::Node,
-    ) -> Self {
-        let layered_mixes = [
-            (1u8, vec![l1_mix]),
-            (2u8, vec![l2_mix]),
-            (3u8, vec![l3_mix]),
-        ]
-        .into_iter()
-        .collect();
+    /// Creates a new test route going through the provided mixnodes, where the first one
+    /// is placed on layer 1, the second one on layer 2, etc.
+    pub(crate) fn new(id: u64, mixes: Vec<mix::Node>, gateway: gateway::Node) -> Self {
+        let mix_layers = mixes.len() as u8;
+        let layered_mixes = mixes
+            .into_iter()
+            .zip(1u8..)
+            .map(|(mix, layer)| (layer, vec![mix]))
+            .collect();
 
         TestRoute {
             id,
-            nodes: NymTopology::new(layered_mixes, vec![gateway]),
+            nodes: NymTopology::new(layered_mixes, vec![gateway]).with_mix_layers(mix_layers),
         }
     }
 
@@ -43,16 +38,17 @@ impl TestRoute {
         &self.nodes.gateways()[0]
     }
 
-    pub(crate) fn layer_one_mix(&self) -> &mix::Node {
-        &self.nodes.mixes().get(&1).unwrap()[0]
+    pub(crate) fn num_mix_layers(&self) -> u8 {
+        self.nodes.mix_layers()
     }
 
-    pub(crate) fn layer_two_mix(&self) -> &mix::Node {
-        &self.nodes.mixes().get(&2).unwrap()[0]
+    /// Returns all mixnodes of this route ordered by their layer.
+    pub(crate) fn mixes(&self) -> impl Iterator<Item = &mix::Node> {
+        self.nodes.mixes().values().map(|layer| &layer[0])
     }
 
-    pub(crate) fn layer_three_mix(&self) -> &mix::Node {
-        &self.nodes.mixes().get(&3).unwrap()[0]
+    pub(crate) fn layer_one_mix(&self) -> &mix::Node {
+        &self.nodes.mixes().get(&1).unwrap()[0]
     }
 
     pub(crate) fn gateway_clients_address(&self) -> String {
@@ -83,18 +79,101 @@ impl TestRoute {
             .mix_plaintexts(mix, count as u32)
             .unwrap()
     }
+
+    /// Ids of the packets containing messages created with [`Self::self_test_messages`].
+    pub(crate) fn self_test_packet_ids(&self, count: usize) -> Vec<TestPacketId> {
+        TestPacketId::node_packets(
+            NymApiTestMessageExt::new(self.id, ROUTE_TESTING_TEST_NONCE),
+            self.layer_one_mix().into(),
+            count as u32,
+        )
+        .collect()
+    }
 }
 
 impl Debug for TestRoute {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Route {}: [G] {} => [M1] {} => [M2] {} => [M3] {}",
+            "Route {}: [G] {}",
             self.id,
             self.gateway().identity().to_base58_string(),
-            self.layer_one_mix().identity_key.to_base58_string(),
-            self.layer_two_mix().identity_key.to_base58_string(),
-            self.layer_three_mix().identity_key.to_base58_string()
-        )
+        )?;
+        for (layer, mix) in self.mixes().enumerate() {
+            write!(
+                f,
+                " => [M{}] {}",
+                layer + 1,
+                mix.identity_key.to_base58_string()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::encryption;
+    use nym_mixnet_contract_common::{Layer, MixId, MAX_MIX_LAYERS, MIN_MIX_LAYERS};
+
+    fn mix(mix_id: MixId, layer: Layer) -> mix::Node {
+        mix::Node {
+            mix_id,
+            owner: "N/A".to_string(),
+            host: "3.3.3.3".parse().unwrap(),
+            mix_host: "3.3.3.3:1789".parse().unwrap(),
+            identity_key: identity::PublicKey::from_base58_string(
+                "3ebjp1Fb9hdcS1AR6AZihgeJiMHkB5jjJUsvqNnfQwU7",
+            )
+            .unwrap(),
+            sphinx_key: encryption::PublicKey::from_base58_string(
+                "C7cown6dYCLZpLiMFC1PaBmhvLvmJmLDJGeRTbPD45bX",
+            )
+            .unwrap(),
+            layer,
+            version: "0.x.0".to_string(),
+        }
+    }
+
+    fn gateway() -> gateway::Node {
+        gateway::Node {
+            owner: "N/A".to_string(),
+            host: "4.4.4.4".parse().unwrap(),
+            mix_host: "4.4.4.4:1789".parse().unwrap(),
+            clients_port: 9000,
+            identity_key: identity::PublicKey::from_base58_string(
+                "3ebjp1Fb9hdcS1AR6AZihgeJiMHkB5jjJUsvqNnfQwU7",
+            )
+            .unwrap(),
+            sphinx_key: encryption::PublicKey::from_base58_string(
+                "C7cown6dYCLZpLiMFC1PaBmhvLvmJmLDJGeRTbPD45bX",
+            )
+            .unwrap(),
+            version: "0.x.0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_route_contains_mixnode_on_each_layer() {
+        for mix_layers in MIN_MIX_LAYERS..=MAX_MIX_LAYERS {
+            let mixes = Layer::all(mix_layers)
+                .map(|layer| mix(100 + u8::from(layer) as MixId, layer))
+                .collect();
+            let route = TestRoute::new(42, mixes, gateway());
+
+            assert_eq!(route.num_mix_layers(), mix_layers);
+            assert_eq!(route.layer_one_mix().mix_id, 101);
+            let mix_ids = route.mixes().map(|mix| mix.mix_id).collect::<Vec<_>>();
+            let expected = (1..=mix_layers as MixId)
+                .map(|layer| 100 + layer)
+                .collect::<Vec<_>>();
+            assert_eq!(mix_ids, expected);
+
+            route
+                .topology()
+                .ensure_can_construct_path_through(mix_layers)
+                .unwrap();
+        }
     }
 }
@@ -1,22 +1,26 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::node_status_api::models::ErrorResponse;
+use crate::node_status_api::models::{ErrorResponse, NymApiStorageError};
+use crate::node_status_api::rollups::{bucket_start, downsample};
+use crate::node_status_api::ONE_DAY;
 use crate::storage::NymApiStorage;
 use crate::support::caching::Cache;
 use crate::{NodeStatusCache, NymContractCache};
 use cosmwasm_std::Decimal;
 use nym_api_requests::models::{
     AllInclusionProbabilitiesResponse, ComputeRewardEstParam, GatewayBondAnnotated,
-    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayUptimeHistoryResponse,
-    GatewayUptimeResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayCoreStatusResponse, GatewayLatencyResponse, GatewayStatusReportResponse,
+    GatewayStatusTimeSeriesResponse, GatewayUptimeHistoryResponse, GatewayUptimeResponse,
+    InclusionProbabilityResponse, MixNodeBondAnnotated, MixnodeCoreStatusResponse,
+    MixnodeLatencyResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
+    MixnodeStatusTimeSeriesResponse, MixnodeUptimeHistoryResponse, RewardEstimationResponse,
+    SignedMonitorReportsResponse, StakeSaturationResponse, TimeSeriesResolution, UptimeResponse,
 };
 use nym_mixnet_contract_common::{MixId, RewardedSetNodeStatus};
 use rocket::http::Status;
 use rocket::State;
+use time::OffsetDateTime;
 
 use super::reward_estimate::compute_reward_estimate;
 
@@ -101,6 +105,154 @@ pub(crate) async fn _gateway_core_status_count(
     })
 }
 
+pub(crate) async fn _get_mixnode_latency(
+    storage: &NymApiStorage,
+    mix_id: MixId,
+) -> Result<MixnodeLatencyResponse, ErrorResponse> {
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let to_error_response =
+        |err: NymApiStorageError| ErrorResponse::new(err.to_string(), Status::InternalServerError);
+
+    let most_recent = storage
+        .get_most_recent_mixnode_latency(mix_id)
+        .await
+        .map_err(to_error_response)?;
+    let last_day = storage
+        .get_average_mixnode_latency_in_the_last_24hrs(mix_id, now)
+        .await
+        .map_err(to_error_response)?;
+
+    Ok(MixnodeLatencyResponse {
+        mix_id,
+        most_recent,
+        last_day,
+    })
+}
+
+pub(crate) async fn _get_gateway_latency(
+    storage: &NymApiStorage,
+    identity: &str,
+) -> Result<GatewayLatencyResponse, ErrorResponse> {
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let to_error_response =
+        |err: NymApiStorageError| ErrorResponse::new(err.to_string(), Status::InternalServerError);
+
+    let most_recent = storage
+        .get_most_recent_gateway_latency(identity)
+        .await
+        .map_err(to_error_response)?;
+    let last_day = storage
+        .get_average_gateway_latency_in_the_last_24hrs(identity, now)
+        .await
+        .map_err(to_error_response)?;
+
+    Ok(GatewayLatencyResponse {
+        identity: identity.to_string(),
+        most_recent,
+        last_day,
+    })
+}
+
+// maximum number of data points that could be returned by a single time series query
+const MAX_TIME_SERIES_DATA_POINTS: i64 = 2000;
+
+// default range of the time series query if no lower bound was specified
+const DEFAULT_TIME_SERIES_RANGE: i64 = 30 * ONE_DAY.as_secs() as i64;
+
+// determines the (bucket aligned) time range and resolution of a time series query
+fn time_series_query_params(
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<&str>,
+) -> Result<(i64, i64, TimeSeriesResolution), ErrorResponse> {
+    let until = until.unwrap_or_else(|| OffsetDateTime::now_utc().unix_timestamp());
+    let since = since.unwrap_or(until - DEFAULT_TIME_SERIES_RANGE);
+    if since >= until {
+        return Err(ErrorResponse::new(
+            "the start of the requested range must be before its end",
+            Status::BadRequest,
+        ));
+    }
+
+    let resolution = match resolution {
+        Some(raw) => raw
+            .parse::<TimeSeriesResolution>()
+            .map_err(|err| ErrorResponse::new(err.message(), Status::BadRequest))?,
+        None => TimeSeriesResolution::for_range(until - since),
+    };
+
+    let since = bucket_start(since, resolution);
+    if (until - since) / resolution.bucket_secs() > MAX_TIME_SERIES_DATA_POINTS {
+        return Err(ErrorResponse::new(
+            format!("the requested range would result in more than {MAX_TIME_SERIES_DATA_POINTS} data points. Please use coarser resolution"),
+            Status::BadRequest,
+        ));
+    }
+
+    Ok((since, until, resolution))
+}
+
+pub(crate) async fn _get_mixnode_status_time_series(
+    storage: &NymApiStorage,
+    mix_id: MixId,
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<&str>,
+) -> Result<MixnodeStatusTimeSeriesResponse, ErrorResponse> {
+    let (since, until, resolution) = time_series_query_params(since, until, resolution)?;
+
+    let rollups = storage
+        .get_mixnode_status_rollups(mix_id, since, until)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    Ok(MixnodeStatusTimeSeriesResponse {
+        mix_id,
+        since,
+        until,
+        resolution,
+        data_points: downsample(&rollups, resolution),
+    })
+}
+
+pub(crate) async fn _get_gateway_status_time_series(
+    storage: &NymApiStorage,
+    identity: &str,
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<&str>,
+) -> Result<GatewayStatusTimeSeriesResponse, ErrorResponse> {
+    let (since, until, resolution) = time_series_query_params(since, until, resolution)?;
+
+    let rollups = storage
+        .get_gateway_status_rollups(identity, since, until)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    Ok(GatewayStatusTimeSeriesResponse {
+        identity: identity.to_string(),
+        since,
+        until,
+        resolution,
+        data_points: downsample(&rollups, resolution),
+    })
+}
+
+pub(crate) async fn _get_signed_monitor_reports(
+    storage: &NymApiStorage,
+    since: Option<i64>,
+) -> Result<SignedMonitorReportsResponse, ErrorResponse> {
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let since = since.unwrap_or(now - ONE_DAY.as_secs() as i64);
+
+    let reports = storage
+        .get_signed_monitor_reports(since, now)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    Ok(SignedMonitorReportsResponse { reports })
+}
+
 pub(crate) async fn _mixnode_report(
     cache: &NodeStatusCache,
     mix_id: MixId,
@@ -424,3 +576,58 @@ pub(crate) async fn _get_gateways_detailed_unfiltered(
         .unwrap_or_default()
         .into_inner()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+    const DAY: i64 = 24 * HOUR;
+
+    #[test]
+    fn time_series_range_must_not_be_empty() {
+        let ts = 1_700_000_000;
+        assert!(time_series_query_params(Some(ts), Some(ts), None).is_err());
+        assert!(time_series_query_params(Some(ts + 1), Some(ts), None).is_err());
+        assert!(time_series_query_params(Some(ts), Some(ts + 1), None).is_ok());
+    }
+
+    #[test]
+    fn time_series_range_is_aligned_to_the_resolution() {
+        let since = 1_700_000_000;
+        let until = since + 2 * DAY;
+
+        let (aligned_since, aligned_until, resolution) =
+            time_series_query_params(Some(since), Some(until), None).unwrap();
+        assert_eq!(aligned_since, 1_699_999_200);
+        assert_eq!(aligned_until, until);
+        assert_eq!(resolution, TimeSeriesResolution::Hourly);
+
+        let (aligned_since, _, resolution) =
+            time_series_query_params(Some(since), Some(until), Some("daily")).unwrap();
+        assert_eq!(aligned_since, 1_699_920_000);
+        assert_eq!(resolution, TimeSeriesResolution::Daily);
+    }
+
+    #[test]
+    fn time_series_range_cannot_exceed_maximum_number_of_data_points() {
+        let since = 10 * HOUR;
+
+        let until = since + MAX_TIME_SERIES_DATA_POINTS * HOUR;
+        assert!(time_series_query_params(Some(since), Some(until), Some("hourly")).is_ok());
+
+        let until = until + HOUR;
+        assert!(time_series_query_params(Some(since), Some(until), Some("hourly")).is_err());
+
+        // but it's fine with coarser resolution
+        assert!(time_series_query_params(Some(since), Some(until), Some("daily")).is_ok());
+        assert!(time_series_query_params(Some(since), Some(until), None).is_ok());
+    }
+
+    #[test]
+    fn time_series_resolution_must_be_valid() {
+        let since = 1_700_000_000;
+        let until = since + DAY;
+        assert!(time_series_query_params(Some(since), Some(until), Some("minutely")).is_err());
+    }
+}
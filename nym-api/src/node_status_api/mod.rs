@@ -20,6 +20,7 @@ pub(crate) mod helpers;
 pub(crate) mod local_guard;
 pub(crate) mod models;
 pub(crate) mod reward_estimate;
+pub(crate) mod rollups;
 pub(crate) mod routes;
 pub(crate) mod uptime_updater;
 pub(crate) mod utils;
@@ -37,6 +38,7 @@ pub(crate) fn node_status_routes(
             settings: routes::gateway_report,
             routes::gateway_uptime_history,
             routes::gateway_core_status_count,
+            routes::get_signed_monitor_reports,
             routes::mixnode_report,
             routes::mixnode_uptime_history,
             routes::mixnode_core_status_count,
@@ -47,6 +49,10 @@ pub(crate) fn node_status_routes(
             routes::get_mixnode_inclusion_probability,
             routes::get_mixnode_avg_uptime,
             routes::get_gateway_avg_uptime,
+            routes::get_mixnode_latency,
+            routes::get_gateway_latency,
+            routes::get_mixnode_status_time_series,
+            routes::get_gateway_status_time_series,
             routes::get_mixnode_inclusion_probabilities,
             routes::get_mixnodes_detailed,
             routes::get_mixnodes_detailed_unfiltered,
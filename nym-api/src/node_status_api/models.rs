@@ -330,6 +330,7 @@ impl From<HistoricalUptime> for HistoricalUptimeResponse {
     }
 }
 
+#[derive(Debug)]
 pub(crate) struct ErrorResponse {
     error_message: RequestError,
     status: Status,
@@ -0,0 +1,445 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node_status_api::models::NymApiStorageError;
+use crate::node_status_api::{ONE_DAY, ONE_HOUR};
+use crate::nym_contract_cache::cache::NymContractCache;
+use crate::storage::models::NodeStatusRollup;
+use crate::storage::NymApiStorage;
+use crate::support::nyxd;
+use cosmwasm_std::Decimal;
+use log::{error, warn};
+use nym_api_requests::models::{LatencyPercentiles, NodeStatusDataPoint, TimeSeriesResolution};
+use nym_mixnet_contract_common::{IdentityKey, MixId};
+use nym_task::{TaskClient, TaskManager};
+use std::str::FromStr;
+use time::OffsetDateTime;
+use tokio::time::interval;
+
+/// Maximum number of hours the rollups are going to be (re)created for in a single run,
+/// so that after a longer downtime we would not attempt to process data that has already been purged.
+const MAX_ROLLUP_BACKFILL: i64 = ONE_DAY.as_secs() as i64;
+
+/// Returns the timestamp of the beginning of the bucket (of the given resolution) containing the
+/// provided timestamp. Note that the weekly buckets are aligned to the unix epoch.
+pub(crate) fn bucket_start(timestamp: i64, resolution: TimeSeriesResolution) -> i64 {
+    timestamp - timestamp.rem_euclid(resolution.bucket_secs())
+}
+
+#[derive(Default)]
+struct BucketAccumulator {
+    timestamp: i64,
+    reliability_sum: f32,
+    count: usize,
+    latency_sums: (f32, f32, f32),
+    latency_count: usize,
+    stake: Option<Decimal>,
+}
+
+impl BucketAccumulator {
+    fn new(timestamp: i64) -> Self {
+        BucketAccumulator {
+            timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, rollup: &NodeStatusRollup) {
+        self.reliability_sum += rollup.reliability;
+        self.count += 1;
+
+        if let (Some(p50), Some(p90), Some(p99)) = (
+            rollup.latency_p50_ms,
+            rollup.latency_p90_ms,
+            rollup.latency_p99_ms,
+        ) {
+            self.latency_sums.0 += p50;
+            self.latency_sums.1 += p90;
+            self.latency_sums.2 += p99;
+            self.latency_count += 1;
+        }
+
+        // stake is not a rate, so rather than averaging it, just use the most recent value
+        if let Some(stake) = rollup
+            .stake
+            .as_deref()
+            .and_then(|stake| Decimal::from_str(stake).ok())
+        {
+            self.stake = Some(stake)
+        }
+    }
+
+    fn finish(self) -> NodeStatusDataPoint {
+        let latency = if self.latency_count > 0 {
+            let count = self.latency_count as f32;
+            Some(LatencyPercentiles {
+                p50_ms: (self.latency_sums.0 / count).round() as u32,
+                p90_ms: (self.latency_sums.1 / count).round() as u32,
+                p99_ms: (self.latency_sums.2 / count).round() as u32,
+            })
+        } else {
+            None
+        };
+
+        NodeStatusDataPoint {
+            timestamp: self.timestamp,
+            reliability: self.reliability_sum / self.count as f32,
+            latency,
+            stake: self.stake,
+        }
+    }
+}
+
+/// Downsamples the hourly rollups (ordered by their timestamps) into data points of the requested resolution.
+/// Periods without any rollups do not produce any data points.
+pub(crate) fn downsample(
+    rollups: &[NodeStatusRollup],
+    resolution: TimeSeriesResolution,
+) -> Vec<NodeStatusDataPoint> {
+    let mut data_points = Vec::new();
+    let mut current: Option<BucketAccumulator> = None;
+
+    for rollup in rollups {
+        let bucket = bucket_start(rollup.timestamp, resolution);
+        let mut accumulator = match current.take() {
+            Some(accumulator) if accumulator.timestamp == bucket => accumulator,
+            Some(finished) => {
+                data_points.push(finished.finish());
+                BucketAccumulator::new(bucket)
+            }
+            None => BucketAccumulator::new(bucket),
+        };
+        accumulator.add(rollup);
+        current = Some(accumulator);
+    }
+
+    if let Some(last) = current {
+        data_points.push(last.finish())
+    }
+
+    data_points
+}
+
+/// Periodically aggregates the network monitor results into hourly rollups, alongside the
+/// stake of each node at the time of the rollup, so that the historical data would remain available
+/// after the raw statuses get purged.
+pub(crate) struct NodeStatusRollupUpdater {
+    storage: NymApiStorage,
+    nym_contract_cache: NymContractCache,
+    nyxd_client: nyxd::Client,
+}
+
+impl NodeStatusRollupUpdater {
+    pub(crate) fn new(
+        storage: NymApiStorage,
+        nym_contract_cache: NymContractCache,
+        nyxd_client: nyxd::Client,
+    ) -> Self {
+        NodeStatusRollupUpdater {
+            storage,
+            nym_contract_cache,
+            nyxd_client,
+        }
+    }
+
+    async fn current_stakes(&self) -> (Vec<(MixId, String)>, Vec<(IdentityKey, String)>) {
+        let mixnode_stakes = self
+            .nym_contract_cache
+            .mixnodes_all()
+            .await
+            .into_iter()
+            .map(|mix| (mix.mix_id(), mix.total_stake().to_string()))
+            .collect();
+
+        // the gateway bonds in the cache only include the pledge, so to account for the delegations
+        // we have to look at the rewarding details instead
+        let mut gateway_stakes = Vec::new();
+        for gateway in self.nym_contract_cache.gateways_all().await {
+            let identity = gateway.identity().to_owned();
+            match self
+                .nyxd_client
+                .get_gateway_rewarding_details(identity.clone())
+                .await
+            {
+                Ok(Some(rewarding)) => {
+                    gateway_stakes.push((identity, rewarding.gateway_bond().to_string()))
+                }
+                Ok(None) => warn!("Gateway {identity} does not have any rewarding details"),
+                Err(err) => {
+                    warn!("Failed to obtain rewarding details of gateway {identity} - {err}")
+                }
+            }
+        }
+
+        (mixnode_stakes, gateway_stakes)
+    }
+
+    async fn update_rollups(&self) -> Result<(), NymApiStorageError> {
+        let one_hour = ONE_HOUR.as_secs() as i64;
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let last_complete_hour = bucket_start(now, TimeSeriesResolution::Hourly) - one_hour;
+
+        // always recreate the most recent rollup in case any statuses got submitted after it was created
+        let earliest = last_complete_hour - MAX_ROLLUP_BACKFILL;
+        let mut hour_start = match self.storage.get_latest_node_status_rollup().await? {
+            Some(latest) => latest.max(earliest),
+            None => earliest,
+        };
+
+        while hour_start <= last_complete_hour {
+            // we only know the current stakes, so the backfilled rollups are left without them
+            // rather than being attributed stakes from a different point in time
+            let (mixnode_stakes, gateway_stakes) = if hour_start == last_complete_hour {
+                self.current_stakes().await
+            } else {
+                (Vec::new(), Vec::new())
+            };
+
+            self.storage
+                .rollup_node_statuses(hour_start, mixnode_stakes, gateway_stakes)
+                .await?;
+            hour_start += one_hour;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn run(&self, mut shutdown: TaskClient) {
+        // make sure we know the stakes before creating any rollups
+        self.nym_contract_cache.wait_for_initial_values().await;
+
+        let mut interval = interval(ONE_HOUR);
+        while !shutdown.is_shutdown() {
+            tokio::select! {
+                biased;
+                _ = shutdown.recv() => {
+                    trace!("NodeStatusRollupUpdater: Received shutdown");
+                }
+                _ = interval.tick() => {
+                    if let Err(err) = self.update_rollups().await {
+                        error!("We failed to update hourly node status rollups - {err}");
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn start(
+        storage: &NymApiStorage,
+        nym_contract_cache: &NymContractCache,
+        nyxd_client: nyxd::Client,
+        shutdown: &TaskManager,
+    ) {
+        let rollup_updater = NodeStatusRollupUpdater::new(
+            storage.to_owned(),
+            nym_contract_cache.to_owned(),
+            nyxd_client,
+        );
+        let shutdown_listener = shutdown.subscribe();
+        tokio::spawn(async move { rollup_updater.run(shutdown_listener).await });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeResult};
+
+    fn rollup(timestamp: i64, reliability: f32, latency: Option<f32>) -> NodeStatusRollup {
+        NodeStatusRollup {
+            timestamp,
+            reliability,
+            latency_p50_ms: latency,
+            latency_p90_ms: latency.map(|latency| latency * 2.),
+            latency_p99_ms: latency.map(|latency| latency * 3.),
+            stake: None,
+        }
+    }
+
+    #[test]
+    fn bucket_start_is_aligned_to_resolution() {
+        let ts = 1_700_000_000;
+        assert_eq!(
+            bucket_start(ts, TimeSeriesResolution::Hourly),
+            1_699_999_200
+        );
+        assert_eq!(bucket_start(ts, TimeSeriesResolution::Daily), 1_699_920_000);
+        assert_eq!(
+            bucket_start(ts, TimeSeriesResolution::Weekly),
+            1_699_488_000
+        );
+        assert_eq!(bucket_start(3600, TimeSeriesResolution::Hourly), 3600);
+    }
+
+    #[test]
+    fn hourly_rollups_are_returned_unchanged() {
+        let rollups = vec![rollup(0, 100., Some(10.)), rollup(3600, 50., None)];
+        let points = downsample(&rollups, TimeSeriesResolution::Hourly);
+
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].timestamp, 0);
+        assert_eq!(points[0].reliability, 100.);
+        assert_eq!(
+            points[0].latency,
+            Some(LatencyPercentiles {
+                p50_ms: 10,
+                p90_ms: 20,
+                p99_ms: 30
+            })
+        );
+        assert_eq!(points[1].timestamp, 3600);
+        assert_eq!(points[1].latency, None);
+    }
+
+    #[test]
+    fn rollups_are_averaged_within_buckets() {
+        let day = ONE_DAY.as_secs() as i64;
+        let rollups = vec![
+            rollup(0, 100., Some(10.)),
+            rollup(3600, 80., None),
+            rollup(7200, 60., Some(20.)),
+            // no data for the second day
+            rollup(2 * day + 3600, 40., None),
+        ];
+        let points = downsample(&rollups, TimeSeriesResolution::Daily);
+
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].timestamp, 0);
+        assert_eq!(points[0].reliability, 80.);
+        // hours without any received packets do not affect the latency
+        assert_eq!(
+            points[0].latency,
+            Some(LatencyPercentiles {
+                p50_ms: 15,
+                p90_ms: 30,
+                p99_ms: 45
+            })
+        );
+        assert_eq!(points[1].timestamp, 2 * day);
+        assert_eq!(points[1].reliability, 40.);
+        assert_eq!(points[1].latency, None);
+
+        let points = downsample(&rollups, TimeSeriesResolution::Weekly);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].reliability, 70.);
+    }
+
+    #[test]
+    fn most_recent_stake_is_used() {
+        let mut first = rollup(0, 100., None);
+        first.stake = Some("100".to_string());
+        let mut second = rollup(3600, 100., None);
+        second.stake = Some("150.5".to_string());
+        let third = rollup(7200, 100., None);
+
+        let points = downsample(&[first, second, third], TimeSeriesResolution::Daily);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].stake, Some(Decimal::from_str("150.5").unwrap()));
+    }
+
+    async fn storage_with_statuses(hour_start: i64) -> NymApiStorage {
+        let mut db_dir = std::env::temp_dir();
+        db_dir.push(format!(
+            "nym-api-rollups-{}",
+            OffsetDateTime::now_utc().unix_timestamp_nanos()
+        ));
+        let storage = NymApiStorage::init(db_dir).await.unwrap();
+
+        let mixnode = MixnodeResult::new(42, "mix".to_string(), "owner".to_string(), 100, None);
+        let gateway = GatewayResult::new("gateway".to_string(), "owner".to_string(), 100, None);
+        storage
+            .manager
+            .submit_mixnode_statuses(hour_start + 60, vec![mixnode])
+            .await
+            .unwrap();
+        storage
+            .manager
+            .submit_gateway_statuses(hour_start + 60, vec![gateway])
+            .await
+            .unwrap();
+        storage
+    }
+
+    async fn stakes(storage: &NymApiStorage, hour_start: i64) -> (Option<String>, Option<String>) {
+        let until = hour_start + ONE_HOUR.as_secs() as i64;
+        let mixnode = storage
+            .get_mixnode_status_rollups(42, hour_start, until)
+            .await
+            .unwrap();
+        let gateway = storage
+            .get_gateway_status_rollups("gateway", hour_start, until)
+            .await
+            .unwrap();
+        assert_eq!(mixnode.len(), 1);
+        assert_eq!(gateway.len(), 1);
+        (mixnode[0].stake.clone(), gateway[0].stake.clone())
+    }
+
+    #[tokio::test]
+    async fn rerunning_rollup_does_not_wipe_existing_stake() {
+        let hour_start = bucket_start(1_700_000_000, TimeSeriesResolution::Hourly);
+        let storage = storage_with_statuses(hour_start).await;
+
+        storage
+            .rollup_node_statuses(
+                hour_start,
+                vec![(42, "100".to_string())],
+                vec![("gateway".to_string(), "200".to_string())],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            stakes(&storage, hour_start).await,
+            (Some("100".to_string()), Some("200".to_string()))
+        );
+
+        // stakes are not known this time around (e.g. the contract cache is not ready)
+        storage
+            .rollup_node_statuses(hour_start, Vec::new(), Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            stakes(&storage, hour_start).await,
+            (Some("100".to_string()), Some("200".to_string()))
+        );
+
+        // and the stakes recorded for the hour are not overwritten by the more recent values
+        storage
+            .rollup_node_statuses(
+                hour_start,
+                vec![(42, "300".to_string())],
+                vec![("gateway".to_string(), "400".to_string())],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            stakes(&storage, hour_start).await,
+            (Some("100".to_string()), Some("200".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn rerunning_rollup_sets_missing_stake() {
+        let hour_start = bucket_start(1_700_000_000, TimeSeriesResolution::Hourly);
+        let storage = storage_with_statuses(hour_start).await;
+
+        storage
+            .rollup_node_statuses(hour_start, Vec::new(), Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(stakes(&storage, hour_start).await, (None, None));
+
+        storage
+            .rollup_node_statuses(
+                hour_start,
+                vec![(42, "100".to_string())],
+                vec![("gateway".to_string(), "200".to_string())],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            stakes(&storage, hour_start).await,
+            (Some("100".to_string()), Some("200".to_string()))
+        );
+    }
+}
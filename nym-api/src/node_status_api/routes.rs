@@ -6,22 +6,25 @@ use super::NodeStatusCache;
 use crate::node_status_api::helpers::{
     _compute_mixnode_reward_estimation, _gateway_core_status_count, _gateway_report,
     _gateway_uptime_history, _get_active_set_detailed, _get_gateway_avg_uptime,
-    _get_gateways_detailed_unfiltered, _get_mixnode_avg_uptime,
-    _get_mixnode_inclusion_probabilities, _get_mixnode_inclusion_probability,
-    _get_mixnode_reward_estimation, _get_mixnode_stake_saturation, _get_mixnode_status,
+    _get_gateway_latency, _get_gateway_status_time_series, _get_gateways_detailed_unfiltered,
+    _get_mixnode_avg_uptime, _get_mixnode_inclusion_probabilities,
+    _get_mixnode_inclusion_probability, _get_mixnode_latency, _get_mixnode_reward_estimation,
+    _get_mixnode_stake_saturation, _get_mixnode_status, _get_mixnode_status_time_series,
     _get_mixnodes_detailed, _get_mixnodes_detailed_unfiltered, _get_rewarded_set_detailed,
-    _mixnode_core_status_count, _mixnode_report, _mixnode_uptime_history,
+    _get_signed_monitor_reports, _mixnode_core_status_count, _mixnode_report,
+    _mixnode_uptime_history,
 };
 use crate::node_status_api::models::ErrorResponse;
 use crate::storage::NymApiStorage;
 use crate::NymContractCache;
 use nym_api_requests::models::{
     AllInclusionProbabilitiesResponse, ComputeRewardEstParam, GatewayBondAnnotated,
-    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayUptimeHistoryResponse,
-    GatewayUptimeResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayCoreStatusResponse, GatewayLatencyResponse, GatewayStatusReportResponse,
+    GatewayStatusTimeSeriesResponse, GatewayUptimeHistoryResponse, GatewayUptimeResponse,
+    InclusionProbabilityResponse, MixNodeBondAnnotated, MixnodeCoreStatusResponse,
+    MixnodeLatencyResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
+    MixnodeStatusTimeSeriesResponse, MixnodeUptimeHistoryResponse, RewardEstimationResponse,
+    SignedMonitorReportsResponse, StakeSaturationResponse, UptimeResponse,
 };
 use nym_mixnet_contract_common::MixId;
 use rocket::serde::json::Json;
@@ -58,6 +61,15 @@ pub(crate) async fn gateway_core_status_count(
     ))
 }
 
+#[openapi(tag = "status")]
+#[get("/network-monitor/reports?<since>")]
+pub(crate) async fn get_signed_monitor_reports(
+    storage: &State<NymApiStorage>,
+    since: Option<i64>,
+) -> Result<Json<SignedMonitorReportsResponse>, ErrorResponse> {
+    Ok(Json(_get_signed_monitor_reports(storage, since).await?))
+}
+
 #[openapi(tag = "status")]
 #[get("/mixnode/<mix_id>/report")]
 pub(crate) async fn mixnode_report(
@@ -172,6 +184,52 @@ pub(crate) async fn get_gateway_avg_uptime(
     Ok(Json(_get_gateway_avg_uptime(cache, identity).await?))
 }
 
+#[openapi(tag = "status")]
+#[get("/mixnode/<mix_id>/latency")]
+pub(crate) async fn get_mixnode_latency(
+    storage: &State<NymApiStorage>,
+    mix_id: MixId,
+) -> Result<Json<MixnodeLatencyResponse>, ErrorResponse> {
+    Ok(Json(_get_mixnode_latency(storage, mix_id).await?))
+}
+
+#[openapi(tag = "status")]
+#[get("/gateway/<identity>/latency")]
+pub(crate) async fn get_gateway_latency(
+    storage: &State<NymApiStorage>,
+    identity: &str,
+) -> Result<Json<GatewayLatencyResponse>, ErrorResponse> {
+    Ok(Json(_get_gateway_latency(storage, identity).await?))
+}
+
+#[openapi(tag = "status")]
+#[get("/mixnode/<mix_id>/timeseries?<since>&<until>&<resolution>")]
+pub(crate) async fn get_mixnode_status_time_series(
+    storage: &State<NymApiStorage>,
+    mix_id: MixId,
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<&str>,
+) -> Result<Json<MixnodeStatusTimeSeriesResponse>, ErrorResponse> {
+    Ok(Json(
+        _get_mixnode_status_time_series(storage, mix_id, since, until, resolution).await?,
+    ))
+}
+
+#[openapi(tag = "status")]
+#[get("/gateway/<identity>/timeseries?<since>&<until>&<resolution>")]
+pub(crate) async fn get_gateway_status_time_series(
+    storage: &State<NymApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+    until: Option<i64>,
+    resolution: Option<&str>,
+) -> Result<Json<GatewayStatusTimeSeriesResponse>, ErrorResponse> {
+    Ok(Json(
+        _get_gateway_status_time_series(storage, identity, since, until, resolution).await?,
+    ))
+}
+
 #[openapi(tag = "status")]
 #[get("/mixnodes/inclusion_probability")]
 pub(crate) async fn get_mixnode_inclusion_probabilities(
@@ -3,8 +3,8 @@
 
 use crate::support::caching::Cache;
 use nym_mixnet_contract_common::{
-    families::FamilyHead, GatewayBond, IdentityKey, Interval, MixId, MixNodeDetails,
-    RewardingParams,
+    families::FamilyHead, ContractStateParams, GatewayBond, IdentityKey, Interval, MixId,
+    MixNodeDetails, RewardingParams,
 };
 use nym_name_service_common::RegisteredName;
 use nym_service_provider_directory_common::Service;
@@ -22,6 +22,7 @@ pub(crate) struct ValidatorCacheData {
 
     pub(crate) current_reward_params: Cache<Option<RewardingParams>>,
     pub(crate) current_interval: Cache<Option<Interval>>,
+    pub(crate) contract_state_params: Cache<Option<ContractStateParams>>,
 
     pub(crate) mix_to_family: Cache<Vec<(IdentityKey, FamilyHead)>>,
 
@@ -40,6 +41,7 @@ impl ValidatorCacheData {
             gateways_blacklist: Cache::default(),
             current_interval: Cache::default(),
             current_reward_params: Cache::default(),
+            contract_state_params: Cache::default(),
             mix_to_family: Cache::default(),
             service_providers: Cache::default(),
             registered_names: Cache::default(),
@@ -2,8 +2,8 @@ use crate::support::caching::Cache;
 use data::ValidatorCacheData;
 use nym_api_requests::models::MixnodeStatus;
 use nym_mixnet_contract_common::{
-    families::FamilyHead, GatewayBond, IdentityKey, Interval, MixId, MixNodeBond, MixNodeDetails,
-    RewardingParams,
+    families::FamilyHead, ContractStateParams, GatewayBond, IdentityKey, Interval, MixId,
+    MixNodeBond, MixNodeDetails, RewardingParams, DEFAULT_MIX_LAYERS,
 };
 use nym_name_service_common::RegisteredName;
 use nym_service_provider_directory_common::Service;
@@ -51,6 +51,7 @@ impl NymContractCache {
         active_set: Vec<MixNodeDetails>,
         rewarding_params: RewardingParams,
         current_interval: Interval,
+        contract_state_params: ContractStateParams,
         mix_to_family: Vec<(IdentityKey, FamilyHead)>,
         services: Option<Vec<Service>>,
         names: Option<Vec<RegisteredName>>,
@@ -63,6 +64,9 @@ impl NymContractCache {
                 cache.active_set.update(active_set);
                 cache.current_reward_params.update(Some(rewarding_params));
                 cache.current_interval.update(Some(current_interval));
+                cache
+                    .contract_state_params
+                    .update(Some(contract_state_params));
                 cache.mix_to_family.update(mix_to_family);
                 // Just return empty lists when these are not available
                 cache.service_providers.update(services.unwrap_or_default());
@@ -267,6 +271,21 @@ impl NymContractCache {
         }
     }
 
+    /// Returns the number of mix layers in the network as currently set in the mixnet contract.
+    pub(crate) async fn mix_layers(&self) -> u8 {
+        match time::timeout(Duration::from_millis(100), self.inner.read()).await {
+            Ok(cache) => cache
+                .contract_state_params
+                .as_ref()
+                .map(|params| params.mix_layers)
+                .unwrap_or(DEFAULT_MIX_LAYERS),
+            Err(err) => {
+                error!("{err}");
+                DEFAULT_MIX_LAYERS
+            }
+        }
+    }
+
     pub async fn mixnode_details(&self, mix_id: MixId) -> (Option<MixNodeDetails>, MixnodeStatus) {
         // it might not be the most optimal to possibly iterate the entire vector to find (or not)
         // the relevant value. However, the vectors are relatively small (< 10_000 elements, < 1000 for active set)
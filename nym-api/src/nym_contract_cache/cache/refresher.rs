@@ -40,6 +40,7 @@ impl NymContractCacheRefresher {
     async fn refresh(&self) -> Result<()> {
         let rewarding_params = self.nyxd_client.get_current_rewarding_parameters().await?;
         let current_interval = self.nyxd_client.get_current_interval().await?.interval;
+        let contract_state_params = self.nyxd_client.get_mixnet_contract_settings().await?;
 
         let mixnodes = self.nyxd_client.get_mixnodes().await?;
         let gateways = self.nyxd_client.get_gateways().await?;
@@ -69,6 +70,7 @@ impl NymContractCacheRefresher {
                 active_set,
                 rewarding_params,
                 current_interval,
+                contract_state_params,
                 mix_to_family,
                 services,
                 names,
@@ -26,9 +26,11 @@ pub(crate) fn nym_contract_cache_routes(settings: &OpenApiSettings) -> (Vec<Rout
         routes::get_blacklisted_mixnodes,
         routes::get_blacklisted_gateways,
         routes::get_interval_reward_params,
+        routes::get_mix_layers,
         routes::get_current_epoch,
         routes::get_services,
-        routes::get_registered_names
+        routes::get_registered_names,
+        routes::get_mixnode_reward_history
     ]
 }
 
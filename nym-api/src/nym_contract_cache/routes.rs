@@ -4,20 +4,26 @@
 use crate::{
     node_status_api::{
         helpers::{_get_active_set_detailed, _get_mixnodes_detailed, _get_rewarded_set_detailed},
+        models::ErrorResponse,
         NodeStatusCache,
     },
     nym_contract_cache::cache::NymContractCache,
+    support::nyxd,
 };
 use nym_api_requests::models::MixNodeBondAnnotated;
 use nym_mixnet_contract_common::{
-    mixnode::MixNodeDetails, reward_params::RewardingParams, GatewayBond, Interval, MixId,
+    mixnode::MixNodeDetails, reward_params::RewardingParams,
+    rewarding::PagedMixnodeRewardHistoryResponse, EpochId, GatewayBond, Interval, MixId,
 };
 
 use nym_name_service_common::response::NamesListResponse;
 use nym_service_provider_directory_common::response::ServicesListResponse;
+use nym_service_provider_directory_common::ServiceType;
+use rocket::http::Status;
 use rocket::{serde::json::Json, State};
 use rocket_okapi::openapi;
 use std::collections::HashSet;
+use std::str::FromStr;
 
 #[openapi(tag = "contract-cache")]
 #[get("/mixnodes")]
@@ -122,6 +128,12 @@ pub async fn get_interval_reward_params(
     Json(cache.interval_reward_params().await.value)
 }
 
+#[openapi(tag = "contract-cache")]
+#[get("/epoch/mix_layers")]
+pub async fn get_mix_layers(cache: &State<NymContractCache>) -> Json<u8> {
+    Json(cache.mix_layers().await)
+}
+
 #[openapi(tag = "contract-cache")]
 #[get("/epoch/current")]
 pub async fn get_current_epoch(cache: &State<NymContractCache>) -> Json<Option<Interval>> {
@@ -129,10 +141,29 @@ pub async fn get_current_epoch(cache: &State<NymContractCache>) -> Json<Option<I
 }
 
 #[openapi(tag = "contract-cache")]
-#[get("/services")]
-pub async fn get_services(cache: &State<NymContractCache>) -> Json<ServicesListResponse> {
-    let services = cache.services().await.value;
-    Json(services.as_slice().into())
+#[get("/services?<service_type>&<owner>")]
+pub async fn get_services(
+    cache: &State<NymContractCache>,
+    service_type: Option<String>,
+    owner: Option<String>,
+) -> Json<ServicesListResponse> {
+    // parsing never fails as unknown type names are treated as custom service types
+    let service_type = service_type.map(|raw| ServiceType::from_str(&raw).unwrap());
+    let services = cache
+        .services()
+        .await
+        .value
+        .into_iter()
+        .filter(|service| match &service_type {
+            Some(service_type) => &service.service.service_type == service_type,
+            None => true,
+        })
+        .filter(|service| match &owner {
+            Some(owner) => service.announcer.as_str() == owner,
+            None => true,
+        })
+        .collect();
+    Json(ServicesListResponse::new(services))
 }
 
 #[openapi(tag = "contract-cache")]
@@ -141,3 +172,20 @@ pub async fn get_registered_names(cache: &State<NymContractCache>) -> Json<Names
     let names = cache.names().await.value;
     Json(names.as_slice().into())
 }
+
+// the history is not cached as it's only ever requested for particular nodes,
+// so the query goes directly to the contract
+#[openapi(tag = "contract-cache")]
+#[get("/mixnode/<mix_id>/reward-history?<start_after>&<limit>")]
+pub async fn get_mixnode_reward_history(
+    client: &State<nyxd::Client>,
+    mix_id: MixId,
+    start_after: Option<EpochId>,
+    limit: Option<u32>,
+) -> Result<Json<PagedMixnodeRewardHistoryResponse>, ErrorResponse> {
+    client
+        .get_mixnode_reward_history(mix_id, start_after, limit)
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
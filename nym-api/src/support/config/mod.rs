@@ -53,6 +53,7 @@ const DEFAULT_CIRCULATING_SUPPLY_CACHE_INTERVAL: Duration = Duration::from_secs(
 const DEFAULT_MONITOR_THRESHOLD: u8 = 60;
 const DEFAULT_MIN_MIXNODE_RELIABILITY: u8 = 50;
 const DEFAULT_MIN_GATEWAY_RELIABILITY: u8 = 20;
+const DEFAULT_MINIMUM_MONITOR_REPORTS: usize = 2;
 
 /// Derive default path to nym-api's config directory.
 /// It should get resolved to `$HOME/.nym/nym-api/<id>/config`
@@ -449,6 +450,15 @@ pub struct Rewarding {
     /// Specifies whether rewarding service is enabled in this process.
     pub enabled: bool,
 
+    /// Addresses of other nym-apis running the network monitor whose signed reports should be
+    /// aggregated alongside our own results when determining node performance.
+    /// If empty, only the results of the local network monitor are used.
+    pub peer_monitors: Vec<Url>,
+
+    /// Base58-encoded identity keys of network monitors whose reports are accepted during aggregation.
+    /// Reports signed by any other key are discarded. Our own monitor is always trusted.
+    pub trusted_monitors: Vec<String>,
+
     // this should really be a thing too...
     // pub paths: RewardingPathfinder,
     #[serde(default)]
@@ -460,6 +470,8 @@ impl Default for Rewarding {
     fn default() -> Self {
         Rewarding {
             enabled: false,
+            peer_monitors: Vec::new(),
+            trusted_monitors: Vec::new(),
             debug: Default::default(),
         }
     }
@@ -472,12 +484,18 @@ pub struct RewardingDebug {
     /// distribute rewards for given interval.
     /// Note, only values in range 0-100 are valid
     pub minimum_interval_monitor_threshold: u8,
+
+    /// Specifies the minimum number of distinct network monitors that must have reported on given node
+    /// for their aggregated (median) result to be used for rewarding. Otherwise, only the results
+    /// of the local network monitor are used.
+    pub minimum_monitor_reports: usize,
 }
 
 impl Default for RewardingDebug {
     fn default() -> Self {
         RewardingDebug {
             minimum_interval_monitor_threshold: DEFAULT_MONITOR_THRESHOLD,
+            minimum_monitor_reports: DEFAULT_MINIMUM_MONITOR_REPORTS,
         }
     }
 }
@@ -95,6 +95,8 @@ impl From<ConfigV1_1_21> for Config {
                 enabled: value.network_monitor.enabled,
                 storage_paths: NetworkMonitorPaths {
                     credentials_database_path: value.network_monitor.credentials_database_path,
+                    private_identity_key_path: Default::default(),
+                    public_identity_key_path: Default::default(),
                 },
                 debug: NetworkMonitorDebug {
                     min_mixnode_reliability: value.network_monitor.min_mixnode_reliability,
@@ -136,10 +138,13 @@ impl From<ConfigV1_1_21> for Config {
             },
             rewarding: Rewarding {
                 enabled: value.rewarding.enabled,
+                peer_monitors: Vec::new(),
+                trusted_monitors: Vec::new(),
                 debug: RewardingDebug {
                     minimum_interval_monitor_threshold: value
                         .rewarding
                         .minimum_interval_monitor_threshold,
+                    ..Default::default()
                 },
             },
             coconut_signer: CoconutSigner {
@@ -6,6 +6,10 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 pub const DEFAULT_NETWORK_MONITOR_CREDENTIALS_DATABASE_FILENAME: &str = "credentials_database.db";
+pub const DEFAULT_NETWORK_MONITOR_PRIVATE_IDENTITY_KEY_FILENAME: &str =
+    "network_monitor_private_identity.pem";
+pub const DEFAULT_NETWORK_MONITOR_PUBLIC_IDENTITY_KEY_FILENAME: &str =
+    "network_monitor_public_identity.pem";
 
 pub const DEFAULT_NODE_STATUS_API_DATABASE_FILENAME: &str = "db.sqlite";
 
@@ -39,6 +43,14 @@ pub struct NetworkMonitorPaths {
     // TODO: this should contain the path to the database holding the results, but changing it would break backwards compatibility
     /// Path to the database containing bandwidth credentials of this client.
     pub credentials_database_path: PathBuf,
+
+    /// Path to the private identity key used for signing network monitor reports.
+    #[serde(default)]
+    pub private_identity_key_path: PathBuf,
+
+    /// Path to the public identity key used for verifying signatures on network monitor reports.
+    #[serde(default)]
+    pub public_identity_key_path: PathBuf,
 }
 
 impl NetworkMonitorPaths {
@@ -48,8 +60,36 @@ impl NetworkMonitorPaths {
         NetworkMonitorPaths {
             credentials_database_path: data_dir
                 .join(DEFAULT_NETWORK_MONITOR_CREDENTIALS_DATABASE_FILENAME),
+            private_identity_key_path: data_dir
+                .join(DEFAULT_NETWORK_MONITOR_PRIVATE_IDENTITY_KEY_FILENAME),
+            public_identity_key_path: data_dir
+                .join(DEFAULT_NETWORK_MONITOR_PUBLIC_IDENTITY_KEY_FILENAME),
         }
     }
+
+    pub fn identity_key_pair_path(&self) -> nym_pemstore::KeyPairPath {
+        // configs created before monitor reports got signed do not specify the key paths,
+        // in that case put the keys alongside the credentials database
+        let with_fallback = |path: &Path, default_filename: &str| {
+            if path.as_os_str().is_empty() {
+                self.credentials_database_path
+                    .with_file_name(default_filename)
+            } else {
+                path.to_path_buf()
+            }
+        };
+
+        nym_pemstore::KeyPairPath::new(
+            with_fallback(
+                &self.private_identity_key_path,
+                DEFAULT_NETWORK_MONITOR_PRIVATE_IDENTITY_KEY_FILENAME,
+            ),
+            with_fallback(
+                &self.public_identity_key_path,
+                DEFAULT_NETWORK_MONITOR_PUBLIC_IDENTITY_KEY_FILENAME,
+            ),
+        )
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -36,6 +36,12 @@ enabled = {{ network_monitor.enabled }}
 # Path to the database containing bandwidth credentials of this client.
 credentials_database_path = '{{ network_monitor.storage_paths.credentials_database_path }}'
 
+# Path to the private identity key used for signing network monitor reports.
+private_identity_key_path = '{{ network_monitor.storage_paths.private_identity_key_path }}'
+
+# Path to the public identity key used for verifying signatures on network monitor reports.
+public_identity_key_path = '{{ network_monitor.storage_paths.public_identity_key_path }}'
+
 [network_monitor.debug]
 
 # Indicates whether this validator api is running in a disabled credentials mode, thus attempting
@@ -98,6 +104,23 @@ caching_interval = '{{ circulating_supply_cacher.debug.caching_interval }}'
 # Specifies whether rewarding service is enabled in this process.
 enabled = {{ rewarding.enabled }}
 
+# Addresses of other nym-apis running the network monitor whose signed reports should be
+# aggregated alongside our own results when determining node performance.
+# If empty, only the results of the local network monitor are used.
+peer_monitors = [
+    {{#each rewarding.peer_monitors }}
+        '{{this}}',
+    {{/each}}
+]
+
+# Base58-encoded identity keys of network monitors whose reports are accepted during aggregation.
+# Reports signed by any other key are discarded. Our own monitor is always trusted.
+trusted_monitors = [
+    {{#each rewarding.trusted_monitors }}
+        '{{this}}',
+    {{/each}}
+]
+
 [rewarding.debug]
 
 # Specifies the minimum percentage of monitor test run data present in order to
@@ -105,6 +128,11 @@ enabled = {{ rewarding.enabled }}
 # Note, only values in range 0-100 are valid
 minimum_interval_monitor_threshold = {{ rewarding.debug.minimum_interval_monitor_threshold }}
 
+# Specifies the minimum number of distinct network monitors that must have reported on given node
+# for their aggregated (median) result to be used for rewarding. Otherwise, only the results
+# of the local network monitor are used.
+minimum_monitor_reports = {{ rewarding.debug.minimum_monitor_reports }}
+
 [coconut_signer]
 
 # Specifies whether coconut signing protocol is enabled in this process.
@@ -20,7 +20,7 @@ pub(crate) mod openapi;
 pub(crate) async fn setup_rocket(
     config: &Config,
     mix_denom: String,
-    _nyxd_client: nyxd::Client,
+    nyxd_client: nyxd::Client,
     coconut_keypair: coconut::keypair::KeyPair,
 ) -> anyhow::Result<Rocket<Ignite>> {
     let openapi_settings = rocket_okapi::settings::OpenApiSettings::default();
@@ -39,6 +39,7 @@ pub(crate) async fn setup_rocket(
     let rocket = rocket
         .mount("/swagger", make_swagger_ui(&openapi::get_docs()))
         .attach(setup_cors()?)
+        .manage(nyxd_client.clone())
         .attach(NymContractCache::stage())
         .attach(NodeStatusCache::stage())
         .attach(CirculatingSupplyCache::stage(mix_denom.clone()));
@@ -55,9 +56,9 @@ pub(crate) async fn setup_rocket(
     };
 
     let rocket = if config.coconut_signer.enabled {
-        let comm_channel = QueryCommunicationChannel::new(_nyxd_client.clone());
+        let comm_channel = QueryCommunicationChannel::new(nyxd_client.clone());
         rocket.attach(InternalSignRequest::stage(
-            _nyxd_client.clone(),
+            nyxd_client.clone(),
             mix_denom,
             coconut_keypair,
             comm_channel,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::coconut::error::CoconutError;
-use crate::epoch_operations::MixnodeWithPerformance;
+use crate::epoch_operations::{GatewayWithPerformance, MixnodeWithPerformance};
 use crate::support::config::Config;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -21,9 +21,10 @@ use nym_contracts_common::dealings::ContractSafeBytes;
 use nym_mixnet_contract_common::families::{Family, FamilyHead};
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::reward_params::RewardingParams;
+use nym_mixnet_contract_common::rewarding::PagedMixnodeRewardHistoryResponse;
 use nym_mixnet_contract_common::{
-    CurrentIntervalResponse, EpochStatus, ExecuteMsg, GatewayBond, IdentityKey, LayerAssignment,
-    MixId, RewardedSetNodeStatus,
+    ContractStateParams, CurrentIntervalResponse, EpochStatus, ExecuteMsg, GatewayBond,
+    GatewayRewarding, IdentityKey, LayerAssignment, MixId, RewardedSetNodeStatus,
 };
 use nym_name_service_common::msg::QueryMsg as NameServiceQueryMsg;
 use nym_service_provider_directory_common::msg::QueryMsg as SpQueryMsg;
@@ -171,12 +172,53 @@ impl Client {
         Ok(self.0.read().await.nyxd.get_current_epoch_status().await?)
     }
 
+    pub(crate) async fn get_mixnet_contract_settings(
+        &self,
+    ) -> Result<ContractStateParams, ValidatorClientError> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_mixnet_contract_settings()
+            .await?)
+    }
+
     pub(crate) async fn get_current_rewarding_parameters(
         &self,
     ) -> Result<RewardingParams, ValidatorClientError> {
         Ok(self.0.read().await.get_rewarding_parameters().await?)
     }
 
+    pub(crate) async fn get_mixnode_reward_history(
+        &self,
+        mix_id: MixId,
+        start_after: Option<nym_mixnet_contract_common::EpochId>,
+        limit: Option<u32>,
+    ) -> Result<PagedMixnodeRewardHistoryResponse, ValidatorClientError> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_mixnode_reward_history_paged(mix_id, start_after, limit)
+            .await?)
+    }
+
+    pub(crate) async fn get_gateway_rewarding_details(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<Option<GatewayRewarding>, ValidatorClientError> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_gateway_rewarding_details(identity)
+            .await?
+            .rewarding_details)
+    }
+
     pub(crate) async fn get_rewarded_set_mixnodes(
         &self,
     ) -> Result<Vec<(MixId, RewardedSetNodeStatus)>, ValidatorClientError> {
@@ -283,6 +325,46 @@ impl Client {
         Ok(())
     }
 
+    pub(crate) async fn send_gateway_rewarding_messages(
+        &self,
+        gateways: &[GatewayWithPerformance],
+    ) -> Result<(), ValidatorClientError> {
+        #[inline]
+        #[allow(unused_variables)]
+        fn generate_reward_messages(
+            eligible_gateways: &[GatewayWithPerformance],
+        ) -> Vec<(ExecuteMsg, Vec<Coin>)> {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "no-reward")] {
+                    vec![]
+                } else {
+                    eligible_gateways
+                        .iter()
+                        .map(|gateway| gateway.clone().into())
+                        .zip(std::iter::repeat(Vec::new()))
+                        .collect()
+                }
+            }
+        }
+
+        let contract = self.0.read().await.get_mixnet_contract_address();
+
+        let msgs = generate_reward_messages(gateways);
+
+        self.0
+            .write()
+            .await
+            .nyxd
+            .execute_multiple(
+                &contract,
+                msgs,
+                Default::default(),
+                format!("rewarding {} gateways", gateways.len()),
+            )
+            .await?;
+        Ok(())
+    }
+
     pub(crate) async fn advance_current_epoch(
         &self,
         new_rewarded_set: Vec<LayerAssignment>,
@@ -351,6 +433,20 @@ impl crate::coconut::client::Client for Client {
             .await?)
     }
 
+    async fn get_gateway_bond(
+        &self,
+        identity: IdentityKey,
+    ) -> crate::coconut::error::Result<Option<GatewayBond>> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_gateway_bond(identity)
+            .await?
+            .gateway)
+    }
+
     async fn get_current_epoch(&self) -> crate::coconut::error::Result<Epoch> {
         Ok(self.0.read().await.nyxd.get_current_epoch().await?)
     }
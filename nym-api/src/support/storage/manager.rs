@@ -4,7 +4,8 @@ use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeRe
 use crate::node_status_api::models::{HistoricalUptime, Uptime};
 use crate::node_status_api::utils::{ActiveGatewayStatuses, ActiveMixnodeStatuses};
 use crate::support::storage::models::{
-    ActiveGateway, ActiveMixnode, NodeStatus, RewardingReport, TestingRoute,
+    ActiveGateway, ActiveMixnode, NodeLatency, NodeStatus, NodeStatusRollup, RewardingReport,
+    StoredMonitorReport, TestingRoute,
 };
 use nym_mixnet_contract_common::{EpochId, IdentityKey, MixId};
 use std::convert::TryFrom;
@@ -485,14 +486,22 @@ impl StorageManager {
             .await?
             .id;
 
+            let latency = mixnode_result.latency;
+            let latency_p50_ms = latency.map(|latency| latency.p50_ms);
+            let latency_p90_ms = latency.map(|latency| latency.p90_ms);
+            let latency_p99_ms = latency.map(|latency| latency.p99_ms);
+
             // insert the actual status
             sqlx::query!(
                     r#"
-                        INSERT INTO mixnode_status (mixnode_details_id, reliability, timestamp) VALUES (?, ?, ?);
+                        INSERT INTO mixnode_status (mixnode_details_id, reliability, timestamp, latency_p50_ms, latency_p90_ms, latency_p99_ms) VALUES (?, ?, ?, ?, ?, ?);
                     "#,
                     mixnode_id,
                     mixnode_result.reliability,
-                    timestamp
+                    timestamp,
+                    latency_p50_ms,
+                    latency_p90_ms,
+                    latency_p99_ms,
                 )
                 .execute(&mut tx)
                 .await?;
@@ -534,14 +543,22 @@ impl StorageManager {
             .await?
             .id;
 
+            let latency = gateway_result.latency;
+            let latency_p50_ms = latency.map(|latency| latency.p50_ms);
+            let latency_p90_ms = latency.map(|latency| latency.p90_ms);
+            let latency_p99_ms = latency.map(|latency| latency.p99_ms);
+
             // insert the actual status
             sqlx::query!(
                     r#"
-                        INSERT INTO gateway_status (gateway_details_id, reliability, timestamp) VALUES (?, ?, ?);
+                        INSERT INTO gateway_status (gateway_details_id, reliability, timestamp, latency_p50_ms, latency_p90_ms, latency_p99_ms) VALUES (?, ?, ?, ?, ?, ?);
                     "#,
                     gateway_id,
                     gateway_result.reliability,
-                    timestamp
+                    timestamp,
+                    latency_p50_ms,
+                    latency_p90_ms,
+                    latency_p99_ms,
                 )
                 .execute(&mut tx)
                 .await?;
@@ -561,17 +578,26 @@ impl StorageManager {
         &self,
         testing_route: TestingRoute,
     ) -> Result<(), sqlx::Error> {
+        let latency = testing_route.latency;
+        let latency_p50_ms = latency.map(|latency| latency.p50_ms);
+        let latency_p90_ms = latency.map(|latency| latency.p90_ms);
+        let latency_p99_ms = latency.map(|latency| latency.p99_ms);
+
         sqlx::query!(
             r#"
                 INSERT INTO testing_route
-                (gateway_id, layer1_mix_id, layer2_mix_id, layer3_mix_id, monitor_run_id)
-                VALUES (?, ?, ?, ?, ?);
+                (gateway_id, layer1_mix_id, layer2_mix_id, layer3_mix_id, layer4_mix_id, monitor_run_id, latency_p50_ms, latency_p90_ms, latency_p99_ms)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);
             "#,
             testing_route.gateway_db_id,
             testing_route.layer1_mix_db_id,
             testing_route.layer2_mix_db_id,
             testing_route.layer3_mix_db_id,
+            testing_route.layer4_mix_db_id,
             testing_route.monitor_run_db_id,
+            latency_p50_ms,
+            latency_p90_ms,
+            latency_p99_ms,
         )
         .execute(&self.connection_pool)
         .await?;
@@ -597,6 +623,7 @@ impl StorageManager {
                     SELECT monitor_run_id 
                     FROM testing_route 
                     WHERE testing_route.layer1_mix_id = ? OR testing_route.layer2_mix_id = ? OR testing_route.layer3_mix_id = ?
+                        OR testing_route.layer4_mix_id = ?
                 ) testing_route
                 JOIN 
                 (
@@ -609,6 +636,7 @@ impl StorageManager {
             db_mixnode_id,
             db_mixnode_id,
             db_mixnode_id,
+            db_mixnode_id,
             since,
         ).fetch_one(&self.connection_pool)
             .await?
@@ -1013,4 +1041,474 @@ impl StorageManager {
 
         Ok(blinded_signature_response)
     }
+
+    /// Records the blinded serial number of a credential that a gateway is about to accept,
+    /// unless it has already been reported before.
+    /// Returns the identity of the gateway that has reported the serial number first.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    /// * `epoch_id`: id of the DKG epoch the credential has been issued in.
+    /// * `gateway_identity`: base58-encoded identity key of the gateway that reported the credential.
+    /// * `timestamp`: unix timestamp of the report.
+    pub(crate) async fn insert_reported_spent_credential(
+        &self,
+        blinded_serial_number_bs58: &str,
+        epoch_id: i64,
+        gateway_identity: &str,
+        timestamp: i64,
+    ) -> Result<String, sqlx::Error> {
+        sqlx::query!(
+            r#"
+                INSERT OR IGNORE INTO reported_spent_credential(blinded_serial_number_bs58, epoch_id, gateway_identity, timestamp)
+                VALUES (?, ?, ?, ?)
+            "#,
+            blinded_serial_number_bs58,
+            epoch_id,
+            gateway_identity,
+            timestamp
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        let reporter = sqlx::query!(
+            "SELECT gateway_identity FROM reported_spent_credential WHERE blinded_serial_number_bs58 = ?",
+            blinded_serial_number_bs58
+        )
+        .fetch_one(&self.connection_pool)
+        .await?
+        .gateway_identity;
+
+        Ok(reporter)
+    }
+
+    /// Removes the reports of all credentials issued before the provided epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch_id`: id of the earliest DKG epoch whose reports should be kept.
+    pub(crate) async fn remove_reported_spent_credentials_before_epoch(
+        &self,
+        epoch_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM reported_spent_credential WHERE epoch_id < ?",
+            epoch_id
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Inserts a signed network monitor report into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer`: base58-encoded identity key of the monitor that signed the report.
+    /// * `timestamp`: unix timestamp of the monitor run the report refers to.
+    /// * `report`: JSON serialization of the report that got signed.
+    /// * `signature`: base58-encoded signature on the report.
+    pub(crate) async fn insert_signed_monitor_report(
+        &self,
+        signer: &str,
+        timestamp: i64,
+        report: &str,
+        signature: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO signed_monitor_report(signer, timestamp, report, signature) VALUES (?, ?, ?, ?)",
+            signer,
+            timestamp,
+            report,
+            signature
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Obtains all signed network monitor reports created within the specified interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(crate) async fn get_signed_monitor_reports(
+        &self,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<StoredMonitorReport>, sqlx::Error> {
+        sqlx::query_as!(
+            StoredMonitorReport,
+            r#"
+                SELECT signer, report, signature
+                FROM signed_monitor_report
+                WHERE timestamp >= ? AND timestamp <= ?
+                ORDER BY timestamp ASC
+            "#,
+            since,
+            until
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Removes all signed network monitor reports that are older than the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp`: timestamp specifying the purge cutoff.
+    pub(crate) async fn purge_old_signed_monitor_reports(
+        &self,
+        timestamp: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM signed_monitor_report WHERE timestamp < ?",
+            timestamp
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Gets the latency percentiles of the specified mixnode from the most recent monitor run
+    /// in which any of its test packets got received.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    pub(crate) async fn get_most_recent_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<Option<NodeLatency>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeLatency,
+            r#"
+                SELECT
+                    s.latency_p50_ms as "p50_ms?: f32",
+                    s.latency_p90_ms as "p90_ms?: f32",
+                    s.latency_p99_ms as "p99_ms?: f32"
+                FROM mixnode_status s
+                JOIN mixnode_details d ON s.mixnode_details_id = d.id
+                WHERE d.mix_id = ? AND s.latency_p50_ms IS NOT NULL
+                ORDER BY s.timestamp DESC
+                LIMIT 1
+            "#,
+            mix_id
+        )
+        .fetch_optional(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the latency percentiles of the specified gateway from the most recent monitor run
+    /// in which any of its test packets got received.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    pub(crate) async fn get_most_recent_gateway_latency(
+        &self,
+        identity: &str,
+    ) -> Result<Option<NodeLatency>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeLatency,
+            r#"
+                SELECT
+                    s.latency_p50_ms as "p50_ms?: f32",
+                    s.latency_p90_ms as "p90_ms?: f32",
+                    s.latency_p99_ms as "p99_ms?: f32"
+                FROM gateway_status s
+                JOIN gateway_details d ON s.gateway_details_id = d.id
+                WHERE d.identity = ? AND s.latency_p50_ms IS NOT NULL
+                ORDER BY s.timestamp DESC
+                LIMIT 1
+            "#,
+            identity
+        )
+        .fetch_optional(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the average of each latency percentile of the specified mixnode within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(crate) async fn get_average_mixnode_latency(
+        &self,
+        mix_id: MixId,
+        since: i64,
+        until: i64,
+    ) -> Result<NodeLatency, sqlx::Error> {
+        sqlx::query_as!(
+            NodeLatency,
+            r#"
+                SELECT
+                    AVG(s.latency_p50_ms) as "p50_ms?: f32",
+                    AVG(s.latency_p90_ms) as "p90_ms?: f32",
+                    AVG(s.latency_p99_ms) as "p99_ms?: f32"
+                FROM mixnode_status s
+                JOIN mixnode_details d ON s.mixnode_details_id = d.id
+                WHERE d.mix_id = ? AND s.timestamp >= ? AND s.timestamp <= ?
+            "#,
+            mix_id,
+            since,
+            until
+        )
+        .fetch_one(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the average of each latency percentile of the specified gateway within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(crate) async fn get_average_gateway_latency(
+        &self,
+        identity: &str,
+        since: i64,
+        until: i64,
+    ) -> Result<NodeLatency, sqlx::Error> {
+        sqlx::query_as!(
+            NodeLatency,
+            r#"
+                SELECT
+                    AVG(s.latency_p50_ms) as "p50_ms?: f32",
+                    AVG(s.latency_p90_ms) as "p90_ms?: f32",
+                    AVG(s.latency_p99_ms) as "p99_ms?: f32"
+                FROM gateway_status s
+                JOIN gateway_details d ON s.gateway_details_id = d.id
+                WHERE d.identity = ? AND s.timestamp >= ? AND s.timestamp <= ?
+            "#,
+            identity,
+            since,
+            until
+        )
+        .fetch_one(&self.connection_pool)
+        .await
+    }
+
+    /// Aggregates all mixnode statuses submitted within the provided time interval into hourly rollups,
+    /// overwriting the reliability and latencies of any existing rollups for the same hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour_start`: unix timestamp of the beginning of the hour the rollup is created for.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn rollup_mixnode_statuses(
+        &self,
+        hour_start: i64,
+        since: i64,
+        until: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"
+                INSERT INTO mixnode_hourly_rollup (mixnode_details_id, timestamp, reliability, latency_p50_ms, latency_p90_ms, latency_p99_ms)
+                SELECT mixnode_details_id, ?, AVG(reliability), AVG(latency_p50_ms), AVG(latency_p90_ms), AVG(latency_p99_ms)
+                FROM mixnode_status
+                WHERE timestamp >= ? AND timestamp < ?
+                GROUP BY mixnode_details_id
+                ON CONFLICT (mixnode_details_id, timestamp) DO UPDATE SET
+                    reliability = excluded.reliability,
+                    latency_p50_ms = excluded.latency_p50_ms,
+                    latency_p90_ms = excluded.latency_p90_ms,
+                    latency_p99_ms = excluded.latency_p99_ms
+            "#,
+            hour_start,
+            since,
+            until
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Aggregates all gateway statuses submitted within the provided time interval into hourly rollups,
+    /// overwriting the reliability and latencies of any existing rollups for the same hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour_start`: unix timestamp of the beginning of the hour the rollup is created for.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn rollup_gateway_statuses(
+        &self,
+        hour_start: i64,
+        since: i64,
+        until: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"
+                INSERT INTO gateway_hourly_rollup (gateway_details_id, timestamp, reliability, latency_p50_ms, latency_p90_ms, latency_p99_ms)
+                SELECT gateway_details_id, ?, AVG(reliability), AVG(latency_p50_ms), AVG(latency_p90_ms), AVG(latency_p99_ms)
+                FROM gateway_status
+                WHERE timestamp >= ? AND timestamp < ?
+                GROUP BY gateway_details_id
+                ON CONFLICT (gateway_details_id, timestamp) DO UPDATE SET
+                    reliability = excluded.reliability,
+                    latency_p50_ms = excluded.latency_p50_ms,
+                    latency_p90_ms = excluded.latency_p90_ms,
+                    latency_p99_ms = excluded.latency_p99_ms
+            "#,
+            hour_start,
+            since,
+            until
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Sets the stake of the mixnodes in their rollups for the specified hour,
+    /// unless it has already been set before.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour_start`: unix timestamp of the beginning of the hour of the rollups.
+    /// * `stakes`: mix-ids of the mixnodes alongside their current total stakes.
+    pub(crate) async fn set_mixnode_rollup_stakes(
+        &self,
+        hour_start: i64,
+        stakes: Vec<(MixId, String)>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        for (mix_id, stake) in stakes {
+            sqlx::query!(
+                r#"
+                    UPDATE mixnode_hourly_rollup
+                    SET stake = COALESCE(stake, ?)
+                    WHERE timestamp = ? AND mixnode_details_id = (SELECT id FROM mixnode_details WHERE mix_id = ?)
+                "#,
+                stake,
+                hour_start,
+                mix_id
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+        tx.commit().await
+    }
+
+    /// Sets the stake of the gateways in their rollups for the specified hour,
+    /// unless it has already been set before.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour_start`: unix timestamp of the beginning of the hour of the rollups.
+    /// * `stakes`: identity keys of the gateways alongside their full bonds (pledge and delegations).
+    pub(crate) async fn set_gateway_rollup_stakes(
+        &self,
+        hour_start: i64,
+        stakes: Vec<(IdentityKey, String)>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        for (identity, stake) in stakes {
+            sqlx::query!(
+                r#"
+                    UPDATE gateway_hourly_rollup
+                    SET stake = COALESCE(stake, ?)
+                    WHERE timestamp = ? AND gateway_details_id = (SELECT id FROM gateway_details WHERE identity = ?)
+                "#,
+                stake,
+                hour_start,
+                identity
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+        tx.commit().await
+    }
+
+    /// Gets the timestamp of the most recent hourly mixnode rollup.
+    pub(crate) async fn get_latest_rollup_timestamp(&self) -> Result<Option<i64>, sqlx::Error> {
+        let latest = sqlx::query!(
+            r#"SELECT MAX(timestamp) as "timestamp?: i64" FROM mixnode_hourly_rollup"#
+        )
+        .fetch_one(&self.connection_pool)
+        .await?
+        .timestamp;
+
+        Ok(latest)
+    }
+
+    /// Gets the hourly rollups of the specified mixnode within the provided time interval,
+    /// ordered by their timestamps.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn get_mixnode_hourly_rollups(
+        &self,
+        mix_id: MixId,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<NodeStatusRollup>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeStatusRollup,
+            r#"
+                SELECT
+                    r.timestamp as "timestamp!: i64",
+                    r.reliability as "reliability!: f32",
+                    r.latency_p50_ms as "latency_p50_ms?: f32",
+                    r.latency_p90_ms as "latency_p90_ms?: f32",
+                    r.latency_p99_ms as "latency_p99_ms?: f32",
+                    r.stake as "stake?: String"
+                FROM mixnode_hourly_rollup r
+                JOIN mixnode_details d ON r.mixnode_details_id = d.id
+                WHERE d.mix_id = ? AND r.timestamp >= ? AND r.timestamp < ?
+                ORDER BY r.timestamp
+            "#,
+            mix_id,
+            since,
+            until
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the hourly rollups of the specified gateway within the provided time interval,
+    /// ordered by their timestamps.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn get_gateway_hourly_rollups(
+        &self,
+        identity: &str,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<NodeStatusRollup>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeStatusRollup,
+            r#"
+                SELECT
+                    r.timestamp as "timestamp!: i64",
+                    r.reliability as "reliability!: f32",
+                    r.latency_p50_ms as "latency_p50_ms?: f32",
+                    r.latency_p90_ms as "latency_p90_ms?: f32",
+                    r.latency_p99_ms as "latency_p99_ms?: f32",
+                    r.stake as "stake?: String"
+                FROM gateway_hourly_rollup r
+                JOIN gateway_details d ON r.gateway_details_id = d.id
+                WHERE d.identity = ? AND r.timestamp >= ? AND r.timestamp < ?
+                ORDER BY r.timestamp
+            "#,
+            identity,
+            since,
+            until
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
 }
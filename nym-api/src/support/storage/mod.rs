@@ -1,16 +1,19 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeResult};
-use crate::network_monitor::test_route::TestRoute;
+use crate::network_monitor::monitor::summary_producer::{
+    GatewayResult, MixnodeResult, RouteResult,
+};
+use crate::network_monitor::reports;
 use crate::node_status_api::models::{
     GatewayStatusReport, GatewayUptimeHistory, MixnodeStatusReport, MixnodeUptimeHistory,
     NymApiStorageError, Uptime,
 };
 use crate::node_status_api::{ONE_DAY, ONE_HOUR};
 use crate::storage::manager::StorageManager;
-use crate::storage::models::{NodeStatus, TestingRoute};
-use nym_mixnet_contract_common::MixId;
+use crate::storage::models::{NodeStatus, NodeStatusRollup, TestingRoute};
+use nym_api_requests::models::{LatencyPercentiles, SignedMonitorReport};
+use nym_mixnet_contract_common::{IdentityKey, MixId};
 use rocket::fairing::AdHoc;
 use sqlx::ConnectOptions;
 use std::path::Path;
@@ -317,6 +320,157 @@ impl NymApiStorage {
             .await
     }
 
+    /// Obtains the latency percentiles of the specified mixnode from the most recent monitor run
+    /// in which any of its test packets got received.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    pub(crate) async fn get_most_recent_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<Option<LatencyPercentiles>, NymApiStorageError> {
+        Ok(self
+            .manager
+            .get_most_recent_mixnode_latency(mix_id)
+            .await?
+            .and_then(|latency| latency.percentiles()))
+    }
+
+    /// Obtains the latency percentiles of the specified gateway from the most recent monitor run
+    /// in which any of its test packets got received.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    pub(crate) async fn get_most_recent_gateway_latency(
+        &self,
+        identity: &str,
+    ) -> Result<Option<LatencyPercentiles>, NymApiStorageError> {
+        Ok(self
+            .manager
+            .get_most_recent_gateway_latency(identity)
+            .await?
+            .and_then(|latency| latency.percentiles()))
+    }
+
+    /// Obtains the latency percentiles of the specified mixnode averaged over the 24h preceding
+    /// the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `end_ts_secs`: unix timestamp indicating the upper bound of the selection.
+    pub(crate) async fn get_average_mixnode_latency_in_the_last_24hrs(
+        &self,
+        mix_id: MixId,
+        end_ts_secs: i64,
+    ) -> Result<Option<LatencyPercentiles>, NymApiStorageError> {
+        let start = end_ts_secs - 86400;
+        Ok(self
+            .manager
+            .get_average_mixnode_latency(mix_id, start, end_ts_secs)
+            .await?
+            .percentiles())
+    }
+
+    /// Obtains the latency percentiles of the specified gateway averaged over the 24h preceding
+    /// the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    /// * `end_ts_secs`: unix timestamp indicating the upper bound of the selection.
+    pub(crate) async fn get_average_gateway_latency_in_the_last_24hrs(
+        &self,
+        identity: &str,
+        end_ts_secs: i64,
+    ) -> Result<Option<LatencyPercentiles>, NymApiStorageError> {
+        let start = end_ts_secs - 86400;
+        Ok(self
+            .manager
+            .get_average_gateway_latency(identity, start, end_ts_secs)
+            .await?
+            .percentiles())
+    }
+
+    /// Aggregates statuses of all mixnodes and gateways submitted during the hour starting at
+    /// `hour_start` into the hourly rollups and sets the provided stakes of the nodes for that hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour_start`: unix timestamp of the beginning of the hour.
+    /// * `mixnode_stakes`: total stakes of the mixnodes.
+    /// * `gateway_stakes`: total bonds (pledge and delegations) of the gateways.
+    pub(crate) async fn rollup_node_statuses(
+        &self,
+        hour_start: i64,
+        mixnode_stakes: Vec<(MixId, String)>,
+        gateway_stakes: Vec<(IdentityKey, String)>,
+    ) -> Result<(), NymApiStorageError> {
+        let hour_end = hour_start + ONE_HOUR.as_secs() as i64;
+
+        self.manager
+            .rollup_mixnode_statuses(hour_start, hour_start, hour_end)
+            .await?;
+        self.manager
+            .rollup_gateway_statuses(hour_start, hour_start, hour_end)
+            .await?;
+
+        self.manager
+            .set_mixnode_rollup_stakes(hour_start, mixnode_stakes)
+            .await?;
+        self.manager
+            .set_gateway_rollup_stakes(hour_start, gateway_stakes)
+            .await?;
+        Ok(())
+    }
+
+    /// Obtains the timestamp of the beginning of the hour of the most recent node status rollup.
+    pub(crate) async fn get_latest_node_status_rollup(
+        &self,
+    ) -> Result<Option<i64>, NymApiStorageError> {
+        Ok(self.manager.get_latest_rollup_timestamp().await?)
+    }
+
+    /// Obtains the hourly status rollups of the specified mixnode within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn get_mixnode_status_rollups(
+        &self,
+        mix_id: MixId,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<NodeStatusRollup>, NymApiStorageError> {
+        Ok(self
+            .manager
+            .get_mixnode_hourly_rollups(mix_id, since, until)
+            .await?)
+    }
+
+    /// Obtains the hourly status rollups of the specified gateway within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the (exclusive) upper bound interval of the selection.
+    pub(crate) async fn get_gateway_status_rollups(
+        &self,
+        identity: &str,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<NodeStatusRollup>, NymApiStorageError> {
+        Ok(self
+            .manager
+            .get_gateway_hourly_rollups(identity, since, until)
+            .await?)
+    }
+
     /// Based on the data available in the validator API, determines the average uptime of particular
     /// mixnode during the specified time interval.
     ///
@@ -471,37 +625,37 @@ impl NymApiStorage {
     /// # Arguments
     ///
     /// * `monitor_run_id` id (as saved in the database) of the associated network monitor test run.
-    /// * `test_route`: one of the test routes used during network testing.
+    /// * `route_result`: result of one of the test routes used during network testing.
     async fn insert_test_route(
         &self,
         monitor_run_db_id: i64,
-        test_route: TestRoute,
+        route_result: RouteResult,
     ) -> Result<(), NymApiStorageError> {
+        let test_route = route_result.route;
+
         // we MUST have those entries in the database, otherwise the route wouldn't have been chosen
         // in the first place
-        let layer1_mix_db_id = self
-            .manager
-            .get_mixnode_database_id(test_route.layer_one_mix().mix_id)
-            .await?
-            .ok_or_else(|| NymApiStorageError::DatabaseInconsistency {
-                reason: format!("could not get db id for layer1 mixnode from network monitor run {monitor_run_db_id}"),
-            })?;
-
-        let layer2_mix_db_id = self
-            .manager
-            .get_mixnode_database_id(test_route.layer_two_mix().mix_id)
-            .await?
-            .ok_or_else(|| NymApiStorageError::DatabaseInconsistency {
-                reason: format!("could not get db id for layer2 mixnode from network monitor run {monitor_run_db_id}"),
-            })?;
+        let mut mix_db_ids = Vec::with_capacity(test_route.num_mix_layers() as usize);
+        for (layer, mix) in test_route.mixes().enumerate() {
+            let mix_db_id = self
+                .manager
+                .get_mixnode_database_id(mix.mix_id)
+                .await?
+                .ok_or_else(|| NymApiStorageError::DatabaseInconsistency {
+                    reason: format!("could not get db id for layer{} mixnode from network monitor run {monitor_run_db_id}", layer + 1),
+                })?;
+            mix_db_ids.push(mix_db_id);
+        }
 
-        let layer3_mix_db_id = self
-            .manager
-            .get_mixnode_database_id(test_route.layer_three_mix().mix_id)
-            .await?
-            .ok_or_else(|| NymApiStorageError::DatabaseInconsistency {
-                reason: format!("could not get db id for layer3 mixnode from network monitor run {monitor_run_db_id}"),
-            })?;
+        let (layer1_mix_db_id, layer2_mix_db_id, layer3_mix_db_id) =
+            match mix_db_ids[..] {
+                [l1, l2, l3, ..] => (l1, l2, l3),
+                _ => {
+                    return Err(NymApiStorageError::DatabaseInconsistency {
+                        reason: format!("test route from network monitor run {monitor_run_db_id} has fewer than 3 mix layers"),
+                    })
+                }
+            };
 
         let gateway_db_id = self
             .manager
@@ -519,7 +673,9 @@ impl NymApiStorage {
                 layer1_mix_db_id,
                 layer2_mix_db_id,
                 layer3_mix_db_id,
+                layer4_mix_db_id: mix_db_ids.get(3).copied(),
                 monitor_run_db_id,
+                latency: route_result.latency,
             })
             .await?;
         Ok(())
@@ -593,7 +749,7 @@ impl NymApiStorage {
         &self,
         mixnode_results: Vec<MixnodeResult>,
         gateway_results: Vec<GatewayResult>,
-        test_routes: Vec<TestRoute>,
+        route_results: Vec<RouteResult>,
     ) -> Result<(), NymApiStorageError> {
         info!("Submitting new node results to the database. There are {} mixnode results and {} gateway results", mixnode_results.len(), gateway_results.len());
 
@@ -609,8 +765,8 @@ impl NymApiStorage {
             .submit_gateway_statuses(now, gateway_results)
             .await?;
 
-        for test_route in test_routes {
-            self.insert_test_route(monitor_run_id, test_route).await?;
+        for route_result in route_results {
+            self.insert_test_route(monitor_run_id, route_result).await?;
         }
 
         Ok(())
@@ -703,16 +859,17 @@ impl NymApiStorage {
             .map_err(|err| err.into())
     }
 
-    /// Removes all ipv4 and ipv6 statuses for all mixnodes and gateways that are older than the
-    /// provided timestamp. This method is called at every reward cycle.
+    /// Removes all ipv4 and ipv6 statuses for all mixnodes and gateways alongside any signed monitor
+    /// reports that are older than the provided timestamp. This method is called at every reward cycle.
     ///
     /// # Arguments
     ///
     /// * `until`: timestamp specifying the purge cutoff.
     pub(crate) async fn purge_old_statuses(&self, until: i64) -> Result<(), NymApiStorageError> {
         self.manager.purge_old_mixnode_statuses(until).await?;
+        self.manager.purge_old_gateway_statuses(until).await?;
         self.manager
-            .purge_old_gateway_statuses(until)
+            .purge_old_signed_monitor_reports(until)
             .await
             .map_err(|err| err.into())
     }
@@ -737,4 +894,98 @@ impl NymApiStorage {
             .await
             .map_err(|err| err.into())
     }
+
+    /// Records the blinded serial number of a credential that a gateway is about to accept,
+    /// unless it has already been reported before.
+    /// Returns the identity of the gateway that has reported the serial number first.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    /// * `epoch_id`: id of the DKG epoch the credential has been issued in.
+    /// * `gateway_identity`: base58-encoded identity key of the gateway that reported the credential.
+    pub(crate) async fn insert_reported_spent_credential(
+        &self,
+        blinded_serial_number_bs58: &str,
+        epoch_id: u64,
+        gateway_identity: &str,
+    ) -> Result<String, NymApiStorageError> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        self.manager
+            .insert_reported_spent_credential(
+                blinded_serial_number_bs58,
+                epoch_id as i64,
+                gateway_identity,
+                now,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Removes the reports of credentials issued before the current epoch,
+    /// as those credentials can no longer be redeemed anyway.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_epoch`: id of the current DKG epoch.
+    pub(crate) async fn remove_expired_reported_spent_credentials(
+        &self,
+        current_epoch: u64,
+    ) -> Result<(), NymApiStorageError> {
+        self.manager
+            .remove_reported_spent_credentials_before_epoch(current_epoch as i64)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Inserts the signed report of a network monitor run.
+    ///
+    /// # Arguments
+    ///
+    /// * `signed_report`: the report alongside its signature and the identity of the signer.
+    pub(crate) async fn insert_signed_monitor_report(
+        &self,
+        signed_report: &SignedMonitorReport,
+    ) -> Result<(), NymApiStorageError> {
+        let report = reports::serialize_report(&signed_report.report);
+        self.manager
+            .insert_signed_monitor_report(
+                &signed_report.signer,
+                signed_report.report.timestamp,
+                &report,
+                &signed_report.signature,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Obtains all signed network monitor reports created within the specified interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(crate) async fn get_signed_monitor_reports(
+        &self,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<SignedMonitorReport>, NymApiStorageError> {
+        self.manager
+            .get_signed_monitor_reports(since, until)
+            .await?
+            .into_iter()
+            .map(|stored| {
+                let report = serde_json::from_str(&stored.report).map_err(|err| {
+                    NymApiStorageError::DatabaseInconsistency {
+                        reason: format!("stored monitor report is malformed - {err}"),
+                    }
+                })?;
+                Ok(SignedMonitorReport {
+                    signer: stored.signer,
+                    report,
+                    signature: stored.signature,
+                })
+            })
+            .collect()
+    }
 }
@@ -1,6 +1,7 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use nym_api_requests::models::LatencyPercentiles;
 use nym_mixnet_contract_common::MixId;
 
 // Internally used struct to catch results from the database to calculate uptimes for given mixnode/gateway
@@ -19,6 +20,33 @@ impl NodeStatus {
     }
 }
 
+// Internally used struct to catch (possibly averaged) latency percentiles of given node from the database
+pub(crate) struct NodeLatency {
+    pub(crate) p50_ms: Option<f32>,
+    pub(crate) p90_ms: Option<f32>,
+    pub(crate) p99_ms: Option<f32>,
+}
+
+impl NodeLatency {
+    pub(crate) fn percentiles(&self) -> Option<LatencyPercentiles> {
+        Some(LatencyPercentiles {
+            p50_ms: self.p50_ms?.round() as u32,
+            p90_ms: self.p90_ms?.round() as u32,
+            p99_ms: self.p99_ms?.round() as u32,
+        })
+    }
+}
+
+// Internally used struct to catch hourly rollups of node statuses from the database
+pub(crate) struct NodeStatusRollup {
+    pub(crate) timestamp: i64,
+    pub(crate) reliability: f32,
+    pub(crate) latency_p50_ms: Option<f32>,
+    pub(crate) latency_p90_ms: Option<f32>,
+    pub(crate) latency_p99_ms: Option<f32>,
+    pub(crate) stake: Option<String>,
+}
+
 // Internally used structs to catch results from the database to find active mixnodes
 pub(crate) struct ActiveMixnode {
     pub(crate) id: i64,
@@ -38,7 +66,9 @@ pub(crate) struct TestingRoute {
     pub(crate) layer1_mix_db_id: i64,
     pub(crate) layer2_mix_db_id: i64,
     pub(crate) layer3_mix_db_id: i64,
+    pub(crate) layer4_mix_db_id: Option<i64>,
     pub(crate) monitor_run_db_id: i64,
+    pub(crate) latency: Option<LatencyPercentiles>,
 }
 
 // for now let's leave it here to have a data model to use with existing database tables
@@ -49,3 +79,10 @@ pub(crate) struct RewardingReport {
 
     pub(crate) eligible_mixnodes: u32,
 }
+
+// Internally used struct to catch signed network monitor reports from the database
+pub(crate) struct StoredMonitorReport {
+    pub(crate) signer: String,
+    pub(crate) report: String,
+    pub(crate) signature: String,
+}
@@ -76,6 +76,11 @@ gateway_details = '{{ storage_paths.gateway_details }}'
 # The mix address of the provider to which all requests are going to be sent.
 provider_mix_address = '{{ core.socks5.provider_mix_address }}'
 
+# Specifies whether the network requester should be chosen automatically from the service provider directory.
+# If enabled, the client is also going to switch to a different network requester once the current one
+# stops responding. If `provider_mix_address` is set, it's going to be tried first.
+auto_select_provider = {{ core.socks5.auto_select_provider }}
+
 # The port on which the client will be listening for incoming requests
 listening_port = {{ core.socks5.listening_port }}
 
@@ -148,10 +148,10 @@ fn print_saved_config(config: &Config) {
         "Gateway listener: {}",
         config.get_base().get_gateway_listener()
     );
-    log::info!(
-        "Service provider address: {}",
-        config.get_socks5().get_provider_mix_address()
-    );
+    match config.get_socks5().get_provider_mix_address() {
+        Some(provider) => log::info!("Service provider address: {provider}"),
+        None => log::info!("Service provider is going to be chosen on startup"),
+    }
     log::info!(
         "Service provider port: {}",
         config.get_socks5().get_listening_port()
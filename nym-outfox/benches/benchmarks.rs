@@ -0,0 +1,166 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::scalar::Scalar;
+use nym_outfox::constants::{DEFAULT_HOPS, MAGIC_SLICE, MIN_PACKET_SIZE};
+use nym_outfox::format::MixCreationParameters;
+use nym_outfox::lion::{lion_transform_decrypt, lion_transform_encrypt};
+use nym_outfox::packet::OutfoxPacket;
+use sphinx_packet::constants::NODE_ADDRESS_LENGTH;
+use sphinx_packet::crypto::{keygen, PrivateKey};
+use sphinx_packet::route::{Destination, DestinationAddressBytes, Node, NodeAddressBytes};
+use std::iter::repeat_with;
+
+// plaintext sizes of `OutfoxAckPacket` and `OutfoxRegularPacket` respectively
+// (we can't import `PacketSize` here without introducing a circular dependency)
+const PLAINTEXT_SIZES: [(&str, usize); 2] = [("ack", MIN_PACKET_SIZE), ("regular", 2 * 1024)];
+
+fn randombytes(n: usize) -> Vec<u8> {
+    repeat_with(|| fastrand::u8(..)).take(n).collect()
+}
+
+fn mix_keypair() -> (Vec<u8>, [u8; 32]) {
+    let secret = randombytes(32);
+    let scalar = Scalar::from_bytes_mod_order(secret.clone().try_into().unwrap());
+    let public = (&ED25519_BASEPOINT_TABLE * &scalar).to_montgomery();
+    (secret, public.to_bytes())
+}
+
+fn route_fixture() -> (Vec<PrivateKey>, [Node; DEFAULT_HOPS], Destination) {
+    let mut keys = Vec::with_capacity(DEFAULT_HOPS);
+    let nodes = std::array::from_fn(|i| {
+        let (private, public) = keygen();
+        keys.push(private);
+        Node::new(
+            NodeAddressBytes::from_bytes([i as u8; NODE_ADDRESS_LENGTH]),
+            public,
+        )
+    });
+    let destination = Destination::new(
+        DestinationAddressBytes::from_bytes([42u8; NODE_ADDRESS_LENGTH]),
+        [0u8; 16],
+    );
+
+    (keys, nodes, destination)
+}
+
+fn lion_transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lion_transform");
+    let key = randombytes(32);
+
+    for (name, size) in PLAINTEXT_SIZES {
+        let message = randombytes(size + MAGIC_SLICE.len());
+        group.throughput(Throughput::Bytes(message.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("encrypt", name), &message, |b, message| {
+            b.iter_batched_ref(
+                || message.clone(),
+                |message| lion_transform_encrypt(black_box(message), &key).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_with_input(BenchmarkId::new("decrypt", name), &message, |b, message| {
+            b.iter_batched_ref(
+                || message.clone(),
+                |message| lion_transform_decrypt(black_box(message), &key).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn mix_layer_encoding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("outfox_mix_layer");
+    let user_secret = randombytes(32);
+    let (mix_secret, mix_public) = mix_keypair();
+    let routing = [0u8; 32];
+
+    for (name, size) in PLAINTEXT_SIZES {
+        let creation_params = MixCreationParameters::new((size + MAGIC_SLICE.len()) as u16);
+
+        for layer in 0..DEFAULT_HOPS {
+            let (_, stage_params) = creation_params.get_stage_params(layer);
+            let buffer = randombytes(stage_params.incoming_packet_length());
+            let mut encoded = buffer.clone();
+            stage_params
+                .encode_mix_layer(&mut encoded, &user_secret, &mix_public, &routing)
+                .unwrap();
+
+            let id = format!("{name}/layer_{layer}");
+            group.throughput(Throughput::Bytes(buffer.len() as u64));
+
+            group.bench_with_input(BenchmarkId::new("encode", &id), &buffer, |b, buffer| {
+                b.iter_batched_ref(
+                    || buffer.clone(),
+                    |buffer| {
+                        stage_params
+                            .encode_mix_layer(
+                                black_box(buffer),
+                                &user_secret,
+                                &mix_public,
+                                &routing,
+                            )
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
+
+            group.bench_with_input(BenchmarkId::new("decode", &id), &encoded, |b, encoded| {
+                b.iter_batched_ref(
+                    || encoded.clone(),
+                    |encoded| {
+                        stage_params
+                            .decode_mix_layer(black_box(encoded), &mix_secret)
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+fn full_packet(c: &mut Criterion) {
+    let mut group = c.benchmark_group("outfox_packet");
+    let (keys, route, destination) = route_fixture();
+
+    for (name, size) in PLAINTEXT_SIZES {
+        let payload = randombytes(size);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("build", name), &payload, |b, payload| {
+            b.iter(|| {
+                black_box(OutfoxPacket::build(payload, &route, &destination, Some(size)).unwrap())
+            })
+        });
+
+        let packet_bytes = OutfoxPacket::build(&payload, &route, &destination, Some(size))
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("decode_first_layer", name),
+            &packet_bytes,
+            |b, packet_bytes| {
+                b.iter_batched(
+                    || OutfoxPacket::try_from(packet_bytes.as_slice()).unwrap(),
+                    |mut packet| black_box(packet.decode_next_layer(&keys[0]).unwrap()),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, lion_transform, mix_layer_encoding, full_packet);
+criterion_main!(benches);
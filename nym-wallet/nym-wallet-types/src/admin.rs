@@ -16,6 +16,7 @@ pub struct TauriContractStateParams {
     minimum_mixnode_pledge: DecCoin,
     minimum_gateway_pledge: DecCoin,
     minimum_mixnode_delegation: Option<DecCoin>,
+    mix_layers: u8,
 }
 
 impl TauriContractStateParams {
@@ -32,6 +33,7 @@ impl TauriContractStateParams {
                 .minimum_mixnode_delegation
                 .map(|min_del| reg.attempt_convert_to_display_dec_coin(min_del.into()))
                 .transpose()?,
+            mix_layers: state_params.mix_layers,
         })
     }
 
@@ -51,6 +53,7 @@ impl TauriContractStateParams {
             minimum_gateway_pledge: reg
                 .attempt_convert_to_base_coin(self.minimum_gateway_pledge)?
                 .into(),
+            mix_layers: self.mix_layers,
         })
     }
 }
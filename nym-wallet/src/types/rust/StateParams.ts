@@ -5,4 +5,5 @@ export interface TauriContractStateParams {
   minimum_mixnode_pledge: DecCoin;
   minimum_gateway_pledge: DecCoin;
   minimum_mixnode_delegation: DecCoin | null;
+  mix_layers: number;
 }
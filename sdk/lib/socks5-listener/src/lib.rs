@@ -178,12 +178,11 @@ pub fn reset_client_data(root_directory: char_p::Ref<'_>) {
 
 #[ffi_export]
 pub fn existing_service_provider(storage_directory: char_p::Ref<'_>) -> Option<char_p_boxed> {
-    if let Ok(config) = Config::read_from_default_path(storage_directory.to_str(), SOCKS5_CONFIG_ID)
-    {
-        Some(config.core.socks5.provider_mix_address.try_into().unwrap())
-    } else {
-        None
-    }
+    // note: the provider might have been specified by its registered name, in which case
+    // it's returned as-is and only resolved once the client is started
+    let config =
+        Config::read_from_default_path(storage_directory.to_str(), SOCKS5_CONFIG_ID).ok()?;
+    config.core.socks5.provider_mix_address.try_into().ok()
 }
 
 fn _reset_client_data(root_directory: String) {
@@ -1,10 +1,12 @@
 use nym_client_core::client::base_client::storage::gateway_details::{
     GatewayDetailsStore, PersistedGatewayDetails,
 };
+use nym_gateway_requests::registration::handshake::SharedKeys;
 use nym_sdk::mixnet::{
     self, EmptyReplyStorage, EphemeralCredentialStorage, KeyManager, KeyStore, MixnetClientStorage,
 };
 use nym_topology::provider_trait::async_trait;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() {
@@ -101,6 +103,25 @@ impl KeyStore for MockKeyStore {
 
         Ok(())
     }
+
+    async fn load_standby_gateway_key(
+        &self,
+        _gateway_id: &str,
+    ) -> Result<Arc<SharedKeys>, Self::StorageError> {
+        println!("loading stored standby gateway key");
+
+        Err(MyError)
+    }
+
+    async fn store_standby_gateway_key(
+        &self,
+        _gateway_id: &str,
+        _key: Arc<SharedKeys>,
+    ) -> Result<(), Self::StorageError> {
+        println!("storing standby gateway key");
+
+        Ok(())
+    }
 }
 
 struct MockGatewayDetailsStore;
@@ -173,6 +194,18 @@ impl GatewayDetailsStore for MockGatewayDetailsStore {
 //     async fn consume_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError> {
 //         todo!()
 //     }
+//
+//     async fn spend_coconut_credential_part(&self, id: i64, total_parts: u64) -> Result<(), Self::StorageError> {
+//         todo!()
+//     }
+//
+//     async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, Self::StorageError> {
+//         todo!()
+//     }
+//
+//     async fn remove_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError> {
+//         todo!()
+//     }
 // }
 
 #[derive(thiserror::Error, Debug)]
@@ -48,12 +48,18 @@ pub enum Error {
     #[error("bad validator details: {0}")]
     BadValidatorDetails(#[from] nym_validator_client::ValidatorClientError),
 
+    #[error("failed to resolve the nym address: {0}")]
+    NameResolutionError(#[from] nym_client_core::client::name_resolver::NameResolutionError),
+
     #[error("socks5 configuration set: {}, but expected to be {}", set, !set)]
     Socks5Config { set: bool },
 
     #[error("socks5 channel could not be started")]
     Socks5NotStarted,
 
+    #[error("failed to determine the socks5 service provider: {0}")]
+    Socks5ProviderSelection(#[from] nym_socks5_client_core::error::Socks5ClientCoreError),
+
     #[error(
         "deposited funds were not converted to a deposit - {reason}; the voucher blob can be used for \
     later retry"
@@ -49,12 +49,14 @@ pub use nym_client_core::{
             persistence::{InMemEphemeralKeys, KeyStore, OnDiskKeys},
             KeyManager,
         },
+        name_resolver::{NameResolutionError, NameResolver},
+        reachability::ReachableAddresses,
         replies::reply_storage::{
             fs_backend::Backend as ReplyStorage, CombinedReplyStorage, Empty as EmptyReplyStorage,
             ReplyStorageBackend,
         },
     },
-    config::GatewayEndpointConfig,
+    config::{GatewayEndpointConfig, TrafficProfile},
 };
 pub use nym_credential_storage::{
     ephemeral_storage::EphemeralStorage as EphemeralCredentialStorage, models::CoconutCredential,
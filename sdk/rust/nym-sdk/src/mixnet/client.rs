@@ -14,7 +14,8 @@ use nym_client_core::client::base_client::storage::{
 };
 use nym_client_core::client::base_client::BaseClient;
 use nym_client_core::client::key_manager::persistence::KeyStore;
-use nym_client_core::config::DebugConfig;
+use nym_client_core::client::name_resolver::NameResolver;
+use nym_client_core::config::{DebugConfig, TrafficProfile};
 use nym_client_core::init::GatewaySetup;
 use nym_client_core::{
     client::{base_client::BaseClientBuilder, replies::reply_storage::ReplyStorageBackend},
@@ -22,6 +23,7 @@ use nym_client_core::{
 };
 use nym_network_defaults::NymNetworkDetails;
 use nym_socks5_client_core::config::Socks5;
+use nym_socks5_client_core::socks::provider_discovery::ProviderSelection;
 use nym_task::manager::TaskStatus;
 use nym_topology::provider_trait::TopologyProvider;
 use nym_validator_client::nyxd::QueryNyxdClient;
@@ -153,6 +155,16 @@ where
         self
     }
 
+    /// Use one of the predefined traffic profiles, which determine the rates of real and cover
+    /// traffic as well as the packet delays.
+    /// Note that it modifies the current debug configuration, so it should be called after
+    /// [`MixnetClientBuilder::debug_config`] if both are used.
+    #[must_use]
+    pub fn traffic_profile(mut self, traffic_profile: TrafficProfile) -> Self {
+        traffic_profile.apply_to_debug_config(&mut self.config.debug_config);
+        self
+    }
+
     /// Configure the SOCKS5 mode.
     #[must_use]
     pub fn socks5_config(mut self, socks5_config: Socks5) -> Self {
@@ -423,6 +435,12 @@ where
             .ok_or(Error::Socks5Config { set: false })?;
         let debug_config = self.config.debug_config;
         let packet_type = self.config.debug_config.traffic.packet_type;
+
+        // the provider might have been specified by its registered name
+        // or it might have to be discovered from the service provider directory
+        let provider_selection =
+            ProviderSelection::from_config(&socks5_config, self.get_api_endpoints()).await?;
+
         let (mut started_client, nym_address) = self.connect_to_mixnet_common().await?;
         let (socks5_status_tx, mut socks5_status_rx) = mpsc::channel(128);
 
@@ -437,6 +455,7 @@ where
             client_output,
             client_state.clone(),
             nym_address,
+            provider_selection,
             started_client.task_manager.subscribe(),
             packet_type,
         );
@@ -489,6 +508,7 @@ where
         if self.socks5_config.is_some() {
             return Err(Error::Socks5Config { set: true });
         }
+        let name_resolver = NameResolver::new(self.get_api_endpoints());
         let (mut started_client, nym_address) = self.connect_to_mixnet_common().await?;
         let client_input = started_client.client_input.register_producer();
         let mut client_output = started_client.client_output.register_consumer();
@@ -504,6 +524,7 @@ where
             reconstructed_receiver,
             task_manager: started_client.task_manager,
             packet_type: None,
+            name_resolver,
         })
     }
 }
@@ -1,8 +1,11 @@
+use nym_client_core::client::name_resolver::NameResolver;
 use nym_client_core::client::{
     base_client::{ClientInput, ClientOutput, ClientState},
     inbound_messages::InputMessage,
+    reachability::ReachableAddresses,
     received_buffer::ReconstructedMessagesReceiver,
 };
+use nym_client_core::config::TrafficProfile;
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::{params::PacketType, receiver::ReconstructedMessage};
 use nym_task::{
@@ -40,6 +43,9 @@ pub struct MixnetClient {
     /// The task manager that controlls all the spawned tasks that the clients uses to do it's job.
     pub(crate) task_manager: TaskManager,
     pub(crate) packet_type: Option<PacketType>,
+
+    /// Resolver for names registered in the name service, used in place of nym addresses.
+    pub(crate) name_resolver: NameResolver,
 }
 
 impl MixnetClient {
@@ -71,6 +77,13 @@ impl MixnetClient {
         &self.nym_address
     }
 
+    /// Get all the addresses this client can currently be reached at. If the client has any standby
+    /// gateways configured, and it failed over to one of them, the active address is going to differ
+    /// from the one returned by [`Self::nym_address`].
+    pub fn reachable_addresses(&self) -> ReachableAddresses {
+        self.client_state.reachable_addresses.current_addresses()
+    }
+
     /// Get a shallow clone of [`MixnetClientSender`]. Useful if you want split the send and
     /// receive logic in different locations.
     pub fn sender(&self) -> MixnetClientSender {
@@ -111,6 +124,37 @@ impl MixnetClient {
         self.client_state.topology_accessor.release_manual_control()
     }
 
+    /// Switch all traffic streams of this client to the provided traffic profile.
+    pub fn switch_traffic_profile(&self, traffic_profile: TrafficProfile) {
+        self.client_state
+            .traffic_shaping
+            .switch_profile(traffic_profile)
+    }
+
+    /// Resolve the provided string into a Nym address. Apart from the base58 encoded address
+    /// itself, a name registered in the name service can be used in its place. Names are looked
+    /// up via the nym-api and are rejected if they have expired or if the registered identity key
+    /// does not match the address they point to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use nym_sdk::mixnet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = mixnet::MixnetClient::connect_new().await.unwrap();
+    ///     let recipient = client.resolve_recipient("steves-server").await.unwrap();
+    ///     client.send_str(recipient, "hi").await;
+    /// }
+    /// ```
+    pub async fn resolve_recipient(&self, name_or_address: &str) -> Result<Recipient> {
+        Ok(self
+            .name_resolver
+            .resolve_recipient(name_or_address)
+            .await?)
+    }
+
     /// Sends stringy data to the supplied Nym address
     ///
     /// # Example
@@ -10,6 +10,7 @@ use nym_client_core::config;
 use nym_client_core::config::disk_persistence::keys_paths::ClientKeysPaths;
 use nym_client_core::config::disk_persistence::CommonClientPaths;
 use nym_credential_storage::persistent_storage::PersistentStorage as PersistentCredentialStorage;
+use nym_credential_storage::CredentialStorageKey;
 use std::path::{Path, PathBuf};
 
 /// Set of storage paths that the client will use if it is setup to persist keys, credentials, and
@@ -95,11 +96,22 @@ impl StoragePaths {
         ))
     }
 
-    /// Instantiates default coconut credential storage.
+    /// Instantiates default coconut credential storage. If the credential storage key is set in the
+    /// environment, the stored credentials are going to be encrypted with it.
     pub async fn persistent_credential_storage(
         &self,
     ) -> Result<PersistentCredentialStorage, Error> {
-        PersistentCredentialStorage::init(&self.credential_database_path)
+        let key = CredentialStorageKey::from_env();
+        self.persistent_credential_storage_with_key(key.as_ref())
+            .await
+    }
+
+    /// Instantiates coconut credential storage encrypted with the provided key.
+    pub async fn persistent_credential_storage_with_key(
+        &self,
+        key: Option<&CredentialStorageKey>,
+    ) -> Result<PersistentCredentialStorage, Error> {
+        PersistentCredentialStorage::init_with_key(&self.credential_database_path, key)
             .await
             .map_err(|source| Error::CredentialStorageError {
                 source: Box::new(source),
@@ -1,4 +1,5 @@
 use nym_client_core::client::base_client::ClientState;
+use nym_client_core::config::TrafficProfile;
 use nym_socks5_client_core::config::Socks5;
 use nym_sphinx::addressing::clients::Recipient;
 use nym_task::{connections::LaneQueueLengths, TaskManager};
@@ -26,7 +27,8 @@ pub struct Socks5MixnetClient {
 
 impl Socks5MixnetClient {
     /// Create a new client and connect to a service provider over the mixnet via SOCKS5 using
-    /// ephemeral in-memory keys that are discarded at application close.
+    /// ephemeral in-memory keys that are discarded at application close. The provider can be
+    /// specified either by its nym address or by its name registered in the name service.
     ///
     /// # Examples
     ///
@@ -49,6 +51,31 @@ impl Socks5MixnetClient {
             .await
     }
 
+    /// Create a new client and connect to a network requester over the mixnet via SOCKS5 using
+    /// ephemeral in-memory keys that are discarded at application close. The network requester is
+    /// chosen automatically from the service provider directory and is replaced with a different
+    /// one if it stops responding.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use nym_sdk::mixnet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = mixnet::Socks5MixnetClient::connect_new_with_auto_selected_provider().await;
+    /// }
+    ///
+    /// ```
+    pub async fn connect_new_with_auto_selected_provider() -> Result<Self> {
+        MixnetClientBuilder::new_ephemeral()
+            .socks5_config(Socks5::new("").with_auto_selected_provider(true))
+            .build()
+            .await?
+            .connect_to_mixnet_via_socks5()
+            .await
+    }
+
     /// Get the nym address for this client, if it is available. The nym address is composed of the
     /// client identity, the client encryption key, and the gateway identity.
     pub fn nym_address(&self) -> &Recipient {
@@ -85,6 +112,13 @@ impl Socks5MixnetClient {
         self.client_state.topology_accessor.release_manual_control()
     }
 
+    /// Switch all traffic streams of this client to the provided traffic profile.
+    pub fn switch_traffic_profile(&self, traffic_profile: TrafficProfile) {
+        self.client_state
+            .traffic_shaping
+            .switch_profile(traffic_profile)
+    }
+
     /// Disconnect from the mixnet. Currently it is not supported to reconnect a disconnected
     /// client.
     pub async fn disconnect(&mut self) {
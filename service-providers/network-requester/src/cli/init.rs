@@ -81,7 +81,11 @@ pub struct InitResults {
 }
 
 impl InitResults {
-    fn new(config: &Config, address: &Recipient, gateway: &GatewayEndpointConfig) -> Self {
+    pub(crate) fn new(
+        config: &Config,
+        address: &Recipient,
+        gateway: &GatewayEndpointConfig,
+    ) -> Self {
         Self {
             client_core: nym_client_core::init::InitResults::new(&config.base, address, gateway),
             client_address: address.to_string(),
@@ -24,6 +24,7 @@ use nym_sphinx::params::PacketSize;
 mod init;
 mod run;
 mod sign;
+mod switch_gateway;
 
 lazy_static::lazy_static! {
     pub static ref PRETTY_BUILD_INFORMATION: String =
@@ -59,6 +60,9 @@ pub(crate) enum Commands {
     /// Sign to prove ownership of this network requester
     Sign(sign::Sign),
 
+    /// Register the already initialised network requester with a different gateway, keeping all of its keys
+    SwitchGateway(switch_gateway::SwitchGateway),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -123,6 +127,7 @@ pub(crate) async fn execute(args: Cli) -> Result<(), NetworkRequesterError> {
         Commands::Init(m) => init::execute(m).await?,
         Commands::Run(m) => run::execute(m).await?,
         Commands::Sign(m) => sign::execute(m).await?,
+        Commands::SwitchGateway(m) => switch_gateway::execute(m).await?,
         Commands::Completions(s) => s.generate(&mut Cli::command(), bin_name),
         Commands::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
@@ -13,6 +13,12 @@ pub(crate) async fn execute(
         nym_cli_commands::validator::mixnet::delegators::rewards::MixnetDelegatorsRewardCommands::Claim(args) => {
             nym_cli_commands::validator::mixnet::delegators::rewards::claim_delegator_reward::claim_delegator_reward(args, create_signing_client(global_args, network_details)?).await
         }
+        nym_cli_commands::validator::mixnet::delegators::rewards::MixnetDelegatorsRewardCommands::AutoCompound(args) => {
+            nym_cli_commands::validator::mixnet::delegators::rewards::set_auto_compounding::set_auto_compounding(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::delegators::rewards::MixnetDelegatorsRewardCommands::VestingAutoCompound(args) => {
+            nym_cli_commands::validator::mixnet::delegators::rewards::vesting_set_auto_compounding::vesting_set_auto_compounding(args, create_signing_client(global_args, network_details)?).await
+        }
         _ => unreachable!(),
     }
     Ok(())
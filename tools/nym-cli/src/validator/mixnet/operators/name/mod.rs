@@ -23,6 +23,30 @@ pub(crate) async fn execute(
             };
             res
         },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Renew(renew) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::renew::renew(renew, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully renewed the name"),
+                Err(_) => println!("Failed to renew name")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::UpdateAddress(update_address) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::update_address::update_address(update_address, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully updated the name address"),
+                Err(_) => println!("Failed to update name address")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Transfer(transfer) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::transfer::transfer(transfer, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully transferred the name"),
+                Err(_) => println!("Failed to transfer name")
+            };
+            res
+        },
     };
     Ok(res?)
 }
@@ -9,6 +9,7 @@ pub(crate) async fn execute(
     match service.command {
         nym_cli_commands::validator::mixnet::operators::service::MixnetOperatorsServiceCommands::Announce(announce) => nym_cli_commands::validator::mixnet::operators::service::announce::announce(announce, create_signing_client(global_args, network_details)?).await,
         nym_cli_commands::validator::mixnet::operators::service::MixnetOperatorsServiceCommands::Delete(delete) => nym_cli_commands::validator::mixnet::operators::service::delete::delete(delete, create_signing_client(global_args, network_details)?).await,
+        nym_cli_commands::validator::mixnet::operators::service::MixnetOperatorsServiceCommands::Update(update) => nym_cli_commands::validator::mixnet::operators::service::update::update(update, create_signing_client(global_args, network_details)?).await,
         nym_cli_commands::validator::mixnet::operators::service::MixnetOperatorsServiceCommands::CreateServiceAnnounceSignPayload(args) => nym_cli_commands::validator::mixnet::operators::service::announce_sign_payload::create_payload(args, create_signing_client(global_args, network_details)?).await,
     }
     Ok(())